@@ -133,6 +133,7 @@ impl App {
             Node {
                 id,
                 name: Some(format!("Cube {}", id)),
+                material: None,
                 op: CsgOp::Cube {
                     size: Vec3::new(size, size, size),
                 },
@@ -157,6 +158,7 @@ impl App {
             Node {
                 id,
                 name: Some(format!("Cylinder {}", id)),
+                material: None,
                 op: CsgOp::Cylinder {
                     radius,
                     height,
@@ -183,6 +185,7 @@ impl App {
             Node {
                 id,
                 name: Some(format!("Sphere {}", id)),
+                material: None,
                 op: CsgOp::Sphere {
                     radius,
                     segments: 32,
@@ -252,6 +255,7 @@ impl App {
                             .nodes
                             .get(&old_root)
                             .and_then(|n| n.name.clone()),
+                        material: None,
                         op: CsgOp::Translate {
                             child: old_root,
                             offset: Vec3::new(dx, dy, dz),
@@ -480,19 +484,34 @@ fn evaluate_node(doc: &Document, node_id: NodeId) -> Result<Option<vcad_kernel::
 
     let solid = match &node.op {
         CsgOp::Empty => Some(Solid::empty()),
-        CsgOp::Cube { size } => Some(Solid::cube(size.x, size.y, size.z)),
+        CsgOp::Cube { size } => Some(Solid::cube(size.x, size.y, size.z)?),
         CsgOp::Cylinder {
             radius,
             height,
             segments,
-        } => Some(Solid::cylinder(*radius, *height, *segments)),
-        CsgOp::Sphere { radius, segments } => Some(Solid::sphere(*radius, *segments)),
+        } => Some(Solid::cylinder(*radius, *height, *segments)?),
+        CsgOp::Sphere { radius, segments } => Some(Solid::sphere(*radius, *segments)?),
         CsgOp::Cone {
             radius_bottom,
             radius_top,
             height,
             segments,
-        } => Some(Solid::cone(*radius_bottom, *radius_top, *height, *segments)),
+        } => Some(Solid::cone(
+            *radius_bottom,
+            *radius_top,
+            *height,
+            *segments,
+        )?),
+        CsgOp::PolygonPrism {
+            sides,
+            circumradius,
+            height,
+        } => Some(Solid::polygon_prism(*sides, *circumradius, *height)?),
+        CsgOp::Pyramid {
+            base_sides,
+            circumradius,
+            height,
+        } => Some(Solid::pyramid(*base_sides, *circumradius, *height)?),
         CsgOp::Union { left, right } => {
             let l = evaluate_node(doc, *left)?;
             let r = evaluate_node(doc, *right)?;
@@ -202,7 +202,7 @@ fn export_step(doc: &vcad_ir::Document, output: &PathBuf) -> Result<()> {
 
     // Try to create a solid from the IR
     let solid = match &root_node.op {
-        vcad_ir::CsgOp::Cube { size } => Solid::cube(size.x, size.y, size.z),
+        vcad_ir::CsgOp::Cube { size } => Solid::cube(size.x, size.y, size.z)?,
         vcad_ir::CsgOp::Cylinder {
             radius,
             height,
@@ -211,9 +211,9 @@ fn export_step(doc: &vcad_ir::Document, output: &PathBuf) -> Result<()> {
             *radius,
             *height,
             if *segments == 0 { 32 } else { *segments },
-        ),
+        )?,
         vcad_ir::CsgOp::Sphere { radius, segments } => {
-            Solid::sphere(*radius, if *segments == 0 { 32 } else { *segments })
+            Solid::sphere(*radius, if *segments == 0 { 32 } else { *segments })?
         }
         vcad_ir::CsgOp::Cone {
             radius_bottom,
@@ -225,7 +225,7 @@ fn export_step(doc: &vcad_ir::Document, output: &PathBuf) -> Result<()> {
             *radius_top,
             *height,
             if *segments == 0 { 32 } else { *segments },
-        ),
+        )?,
         vcad_ir::CsgOp::StepImport { path } => {
             // Re-read from the original STEP file
             Solid::from_step(path)?
@@ -280,6 +280,7 @@ fn import_step(input: &PathBuf, output: &PathBuf, name: Option<String>) -> Resul
             vcad_ir::Node {
                 id: node_id,
                 name: Some(node_name),
+                material: None,
                 op: vcad_ir::CsgOp::StepImport {
                     path: input.to_string_lossy().into_owned(),
                 },
@@ -374,12 +375,18 @@ fn export_urdf(doc: &vcad_ir::Document, output: &PathBuf) -> Result<()> {
     vcad_kernel_urdf::write_urdf(doc, output)?;
 
     // Count parts and joints
-    let num_parts = doc.part_defs.as_ref().map(|p| p.len()).unwrap_or(doc.roots.len());
+    let num_parts = doc
+        .part_defs
+        .as_ref()
+        .map(|p| p.len())
+        .unwrap_or(doc.roots.len());
     let num_joints = doc.joints.as_ref().map(|j| j.len()).unwrap_or(0);
 
     println!(
         "Exported URDF with {} links, {} joints to {}",
-        num_parts, num_joints, output.display()
+        num_parts,
+        num_joints,
+        output.display()
     );
     Ok(())
 }
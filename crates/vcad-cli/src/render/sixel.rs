@@ -70,8 +70,8 @@ pub fn buffer_to_braille(buffer: &RenderBuffer) -> (u16, u16, String) {
             let braille_char = char::from_u32(0x2800 + dots as u32).unwrap_or(' ');
 
             // Get average color
-            if count > 0 {
-                let r = (total_r / count) as u8;
+            if let Some(avg_r) = total_r.checked_div(count) {
+                let r = avg_r as u8;
                 let g = (total_g / count) as u8;
                 let b = (total_b / count) as u8;
 
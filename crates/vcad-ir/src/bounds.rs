@@ -0,0 +1,499 @@
+//! Analytic bounding-box estimation for IR documents.
+//!
+//! Computes a conservative axis-aligned bounding box for any node in the
+//! DAG from operation parameters alone — no kernel evaluation or
+//! tessellation. Used by feature-tree UIs that want per-node extents
+//! without building geometry.
+//!
+//! The estimate is exact for primitives, translations, and scales, and
+//! conservative (never smaller than the real geometry) for rotations,
+//! revolves, and circular patterns. Extrude ignores twist and taper.
+//! Nodes whose extent cannot be known from parameters ([`CsgOp::Text2D`],
+//! [`CsgOp::StepImport`], [`CsgOp::Empty`]) report no bounds.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CsgOp, Document, NodeId, SketchSegment2D, Vec3};
+
+/// Axis-aligned bounding box in document coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Bounds {
+    /// Minimum corner.
+    pub min: Vec3,
+    /// Maximum corner.
+    pub max: Vec3,
+}
+
+impl Bounds {
+    /// Bounding box of a set of points. Returns `None` for an empty set.
+    fn from_points(points: impl IntoIterator<Item = [f64; 3]>) -> Option<Bounds> {
+        let mut iter = points.into_iter();
+        let first = iter.next()?;
+        let mut min = first;
+        let mut max = first;
+        for p in iter {
+            for a in 0..3 {
+                min[a] = min[a].min(p[a]);
+                max[a] = max[a].max(p[a]);
+            }
+        }
+        Some(Bounds {
+            min: Vec3::new(min[0], min[1], min[2]),
+            max: Vec3::new(max[0], max[1], max[2]),
+        })
+    }
+
+    /// The eight corner points of the box.
+    fn corners(&self) -> [[f64; 3]; 8] {
+        let (lo, hi) = (
+            [self.min.x, self.min.y, self.min.z],
+            [self.max.x, self.max.y, self.max.z],
+        );
+        let mut corners = [[0.0; 3]; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            *corner = [
+                if i & 1 == 0 { lo[0] } else { hi[0] },
+                if i & 2 == 0 { lo[1] } else { hi[1] },
+                if i & 4 == 0 { lo[2] } else { hi[2] },
+            ];
+        }
+        corners
+    }
+
+    /// Smallest box containing both boxes.
+    fn union(&self, other: &Bounds) -> Bounds {
+        Bounds {
+            min: Vec3::new(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            max: Vec3::new(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
+        }
+    }
+
+    /// Overlap of both boxes, or `None` if they are disjoint.
+    fn intersection(&self, other: &Bounds) -> Option<Bounds> {
+        let min = Vec3::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Vec3::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+        if min.x <= max.x && min.y <= max.y && min.z <= max.z {
+            Some(Bounds { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// Box expanded by `r` in every direction.
+    fn expanded(&self, r: f64) -> Bounds {
+        Bounds {
+            min: Vec3::new(self.min.x - r, self.min.y - r, self.min.z - r),
+            max: Vec3::new(self.max.x + r, self.max.y + r, self.max.z + r),
+        }
+    }
+}
+
+fn normalized(v: &Vec3) -> Option<[f64; 3]> {
+    let len = (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
+    if len < 1e-12 {
+        None
+    } else {
+        Some([v.x / len, v.y / len, v.z / len])
+    }
+}
+
+/// Rotate a point by Euler angles in degrees, applied as X, then Y, then Z
+/// (matching the kernel's `rotate` convention).
+fn rotate_point(p: [f64; 3], angles: &Vec3) -> [f64; 3] {
+    let (sx, cx) = angles.x.to_radians().sin_cos();
+    let (sy, cy) = angles.y.to_radians().sin_cos();
+    let (sz, cz) = angles.z.to_radians().sin_cos();
+
+    // X rotation
+    let p = [p[0], cx * p[1] - sx * p[2], sx * p[1] + cx * p[2]];
+    // Y rotation
+    let p = [cy * p[0] + sy * p[2], p[1], -sy * p[0] + cy * p[2]];
+    // Z rotation
+    [cz * p[0] - sz * p[1], sz * p[0] + cz * p[1], p[2]]
+}
+
+/// Conservative bounds of `inner` swept a full turn around the axis
+/// through `axis_origin` along `axis_dir`.
+fn swept_around_axis(inner: &Bounds, axis_origin: &Vec3, axis_dir: &Vec3) -> Option<Bounds> {
+    let dir = normalized(axis_dir)?;
+    let origin = [axis_origin.x, axis_origin.y, axis_origin.z];
+
+    let mut t_min = f64::MAX;
+    let mut t_max = f64::MIN;
+    let mut r_max: f64 = 0.0;
+    for c in inner.corners() {
+        let rel = [c[0] - origin[0], c[1] - origin[1], c[2] - origin[2]];
+        let t = rel[0] * dir[0] + rel[1] * dir[1] + rel[2] * dir[2];
+        let radial = [
+            rel[0] - t * dir[0],
+            rel[1] - t * dir[1],
+            rel[2] - t * dir[2],
+        ];
+        let r = (radial[0] * radial[0] + radial[1] * radial[1] + radial[2] * radial[2]).sqrt();
+        t_min = t_min.min(t);
+        t_max = t_max.max(t);
+        r_max = r_max.max(r);
+    }
+
+    let ends = [t_min, t_max].map(|t| {
+        [
+            origin[0] + t * dir[0],
+            origin[1] + t * dir[1],
+            origin[2] + t * dir[2],
+        ]
+    });
+    Some(Bounds::from_points(ends)?.expanded(r_max))
+}
+
+/// 2D bounding box of sketch segments in sketch-local coordinates.
+/// Arcs are bounded conservatively by `center ± radius`.
+fn sketch_bounds_2d(segments: &[SketchSegment2D]) -> Option<([f64; 2], [f64; 2])> {
+    let mut min = [f64::MAX; 2];
+    let mut max = [f64::MIN; 2];
+    let mut include = |x: f64, y: f64| {
+        min[0] = min[0].min(x);
+        min[1] = min[1].min(y);
+        max[0] = max[0].max(x);
+        max[1] = max[1].max(y);
+    };
+
+    for seg in segments {
+        match seg {
+            SketchSegment2D::Line { start, end } => {
+                include(start.x, start.y);
+                include(end.x, end.y);
+            }
+            SketchSegment2D::Arc {
+                start, end, center, ..
+            } => {
+                include(start.x, start.y);
+                include(end.x, end.y);
+                let r = ((start.x - center.x).powi(2) + (start.y - center.y).powi(2)).sqrt();
+                include(center.x - r, center.y - r);
+                include(center.x + r, center.y + r);
+            }
+        }
+    }
+
+    if min[0] > max[0] {
+        None
+    } else {
+        Some((min, max))
+    }
+}
+
+/// Estimate a conservative axis-aligned bounding box for a node.
+///
+/// Returns `None` when the node (or the subtree it depends on) has no
+/// extent that can be derived from parameters alone — empty geometry,
+/// text, imports, missing nodes, or an intersection of disjoint bounds.
+pub fn estimate_bounds(doc: &Document, node_id: NodeId) -> Option<Bounds> {
+    let node = doc.nodes.get(&node_id)?;
+
+    match &node.op {
+        CsgOp::Cube { size } => Bounds::from_points([[0.0; 3], [size.x, size.y, size.z]]),
+        CsgOp::Cylinder { radius, height, .. } => {
+            Bounds::from_points([[-radius, -radius, 0.0], [*radius, *radius, *height]])
+        }
+        CsgOp::Sphere { radius, .. } => {
+            Bounds::from_points([[-radius; 3], [*radius, *radius, *radius]])
+        }
+        CsgOp::Cone {
+            radius_bottom,
+            radius_top,
+            height,
+            ..
+        } => {
+            let r = radius_bottom.max(*radius_top);
+            Bounds::from_points([[-r, -r, 0.0], [r, r, *height]])
+        }
+        CsgOp::PolygonPrism {
+            circumradius,
+            height,
+            ..
+        }
+        | CsgOp::Pyramid {
+            circumradius,
+            height,
+            ..
+        } => Bounds::from_points([
+            [-circumradius, -circumradius, 0.0],
+            [*circumradius, *circumradius, *height],
+        ]),
+        CsgOp::Empty => None,
+
+        CsgOp::Union { left, right } => {
+            match (estimate_bounds(doc, *left), estimate_bounds(doc, *right)) {
+                (Some(a), Some(b)) => Some(a.union(&b)),
+                (a, b) => a.or(b),
+            }
+        }
+        CsgOp::Difference { left, .. } => estimate_bounds(doc, *left),
+        CsgOp::Intersection { left, right } => {
+            estimate_bounds(doc, *left)?.intersection(&estimate_bounds(doc, *right)?)
+        }
+
+        CsgOp::Translate { child, offset } => {
+            let b = estimate_bounds(doc, *child)?;
+            Some(Bounds {
+                min: Vec3::new(b.min.x + offset.x, b.min.y + offset.y, b.min.z + offset.z),
+                max: Vec3::new(b.max.x + offset.x, b.max.y + offset.y, b.max.z + offset.z),
+            })
+        }
+        CsgOp::Rotate { child, angles } => {
+            let b = estimate_bounds(doc, *child)?;
+            Bounds::from_points(b.corners().map(|c| rotate_point(c, angles)))
+        }
+        CsgOp::Scale { child, factor } => {
+            let b = estimate_bounds(doc, *child)?;
+            Bounds::from_points([
+                [b.min.x * factor.x, b.min.y * factor.y, b.min.z * factor.z],
+                [b.max.x * factor.x, b.max.y * factor.y, b.max.z * factor.z],
+            ])
+        }
+
+        CsgOp::Sketch2D {
+            origin,
+            x_dir,
+            y_dir,
+            segments,
+        } => {
+            let (min, max) = sketch_bounds_2d(segments)?;
+            let corners = [
+                (min[0], min[1]),
+                (max[0], min[1]),
+                (max[0], max[1]),
+                (min[0], max[1]),
+            ];
+            Bounds::from_points(corners.map(|(u, v)| {
+                [
+                    origin.x + u * x_dir.x + v * y_dir.x,
+                    origin.y + u * x_dir.y + v * y_dir.y,
+                    origin.z + u * x_dir.z + v * y_dir.z,
+                ]
+            }))
+        }
+        CsgOp::Extrude {
+            sketch, direction, ..
+        } => {
+            let base = estimate_bounds(doc, *sketch)?;
+            let shifted = Bounds {
+                min: Vec3::new(
+                    base.min.x + direction.x,
+                    base.min.y + direction.y,
+                    base.min.z + direction.z,
+                ),
+                max: Vec3::new(
+                    base.max.x + direction.x,
+                    base.max.y + direction.y,
+                    base.max.z + direction.z,
+                ),
+            };
+            Some(base.union(&shifted))
+        }
+        CsgOp::Revolve {
+            sketch,
+            axis_origin,
+            axis_dir,
+            ..
+        } => {
+            let profile = estimate_bounds(doc, *sketch)?;
+            swept_around_axis(&profile, axis_origin, axis_dir)
+        }
+
+        CsgOp::LinearPattern {
+            child,
+            direction,
+            count,
+            spacing,
+        } => {
+            let base = estimate_bounds(doc, *child)?;
+            let dir = normalized(direction)?;
+            let span = spacing * (count.saturating_sub(1)) as f64;
+            let shifted = Bounds {
+                min: Vec3::new(
+                    base.min.x + span * dir[0],
+                    base.min.y + span * dir[1],
+                    base.min.z + span * dir[2],
+                ),
+                max: Vec3::new(
+                    base.max.x + span * dir[0],
+                    base.max.y + span * dir[1],
+                    base.max.z + span * dir[2],
+                ),
+            };
+            Some(base.union(&shifted))
+        }
+        CsgOp::CircularPattern {
+            child,
+            axis_origin,
+            axis_dir,
+            ..
+        } => {
+            let base = estimate_bounds(doc, *child)?;
+            swept_around_axis(&base, axis_origin, axis_dir)
+        }
+
+        CsgOp::Shell { child, .. } | CsgOp::Fillet { child, .. } | CsgOp::Chamfer { child, .. } => {
+            estimate_bounds(doc, *child)
+        }
+
+        // Extent depends on glyph metrics / file contents we don't have here
+        CsgOp::Text2D { .. } | CsgOp::StepImport { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Node;
+
+    fn doc_with(nodes: Vec<Node>) -> Document {
+        let mut doc = Document::new();
+        for node in nodes {
+            doc.nodes.insert(node.id, node);
+        }
+        doc
+    }
+
+    fn node(id: NodeId, op: CsgOp) -> Node {
+        Node {
+            id,
+            name: None,
+            op,
+            material: None,
+        }
+    }
+
+    #[test]
+    fn test_primitive_bounds() {
+        let doc = doc_with(vec![
+            node(
+                0,
+                CsgOp::Cube {
+                    size: Vec3::new(10.0, 20.0, 5.0),
+                },
+            ),
+            node(
+                1,
+                CsgOp::Cylinder {
+                    radius: 4.0,
+                    height: 12.0,
+                    segments: 0,
+                },
+            ),
+            node(
+                2,
+                CsgOp::Sphere {
+                    radius: 3.0,
+                    segments: 0,
+                },
+            ),
+        ]);
+
+        let cube = estimate_bounds(&doc, 0).unwrap();
+        assert_eq!(cube.min, Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(cube.max, Vec3::new(10.0, 20.0, 5.0));
+
+        let cyl = estimate_bounds(&doc, 1).unwrap();
+        assert_eq!(cyl.min, Vec3::new(-4.0, -4.0, 0.0));
+        assert_eq!(cyl.max, Vec3::new(4.0, 4.0, 12.0));
+
+        let sphere = estimate_bounds(&doc, 2).unwrap();
+        assert_eq!(sphere.min, Vec3::new(-3.0, -3.0, -3.0));
+        assert_eq!(sphere.max, Vec3::new(3.0, 3.0, 3.0));
+    }
+
+    #[test]
+    fn test_boolean_and_transform_bounds() {
+        let doc = doc_with(vec![
+            node(
+                0,
+                CsgOp::Cube {
+                    size: Vec3::new(10.0, 10.0, 10.0),
+                },
+            ),
+            node(
+                1,
+                CsgOp::Translate {
+                    child: 0,
+                    offset: Vec3::new(5.0, 0.0, 0.0),
+                },
+            ),
+            node(2, CsgOp::Union { left: 0, right: 1 }),
+            node(3, CsgOp::Difference { left: 0, right: 1 }),
+            node(4, CsgOp::Intersection { left: 0, right: 1 }),
+        ]);
+
+        let union = estimate_bounds(&doc, 2).unwrap();
+        assert_eq!(union.min, Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(union.max, Vec3::new(15.0, 10.0, 10.0));
+
+        // Difference keeps the left operand's bounds
+        let diff = estimate_bounds(&doc, 3).unwrap();
+        assert_eq!(diff.max, Vec3::new(10.0, 10.0, 10.0));
+
+        let inter = estimate_bounds(&doc, 4).unwrap();
+        assert_eq!(inter.min, Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(inter.max, Vec3::new(10.0, 10.0, 10.0));
+    }
+
+    #[test]
+    fn test_rotate_bounds_conservative() {
+        let doc = doc_with(vec![
+            node(
+                0,
+                CsgOp::Cube {
+                    size: Vec3::new(10.0, 10.0, 10.0),
+                },
+            ),
+            node(
+                1,
+                CsgOp::Rotate {
+                    child: 0,
+                    angles: Vec3::new(0.0, 0.0, 45.0),
+                },
+            ),
+        ]);
+
+        let b = estimate_bounds(&doc, 1).unwrap();
+        // A 45° z-rotation of a 10mm cube spans ±10/√2 around its diagonal
+        let half_diag = 10.0 * std::f64::consts::SQRT_2 / 2.0;
+        assert!((b.min.x - (-half_diag)).abs() < 1e-9);
+        assert!((b.max.x - half_diag).abs() < 1e-9);
+        assert_eq!(b.min.z, 0.0);
+        assert_eq!(b.max.z, 10.0);
+    }
+
+    #[test]
+    fn test_unknown_bounds() {
+        let doc = doc_with(vec![
+            node(0, CsgOp::Empty),
+            node(
+                1,
+                CsgOp::StepImport {
+                    path: "part.step".to_string(),
+                },
+            ),
+        ]);
+        assert!(estimate_bounds(&doc, 0).is_none());
+        assert!(estimate_bounds(&doc, 1).is_none());
+        assert!(estimate_bounds(&doc, 99).is_none());
+    }
+}
@@ -23,6 +23,8 @@
 //! Y r h ["name"]                # Cylinder
 //! S r ["name"]                  # Sphere
 //! K rb rt h ["name"]            # Cone
+//! PP n r h ["name"]             # Regular polygonal prism
+//! PY n r h ["name"]             # Pyramid over a regular polygon base
 //! U a b ["name"]                # Union
 //! D a b ["name"]                # Difference
 //! I a b ["name"]                # Intersection
@@ -55,11 +57,11 @@
 //! ```text
 //! PDEF id "name" rootNodeId [material]
 //! INST id partDefId "name" tx ty tz rx ry rz sx sy sz [material]
-//! JFIX id parentInst childInst px py pz cx cy cz
-//! JREV id parentInst childInst px py pz cx cy cz ax ay az [min max]
-//! JSLD id parentInst childInst px py pz cx cy cz ax ay az [min max]
-//! JCYL id parentInst childInst px py pz cx cy cz ax ay az
-//! JBAL id parentInst childInst px py pz cx cy cz
+//! JFIX id parentInst childInst px py pz cx cy cz ["name"]
+//! JREV id parentInst childInst px py pz cx cy cz ax ay az [min max] ["name"]
+//! JSLD id parentInst childInst px py pz cx cy cz ax ay az [min max] ["name"]
+//! JCYL id parentInst childInst px py pz cx cy cz ax ay az ["name"]
+//! JBAL id parentInst childInst px py pz cx cy cz ["name"]
 //! GROUND instanceId
 //! ```
 //!
@@ -327,6 +329,11 @@ pub fn to_compact(doc: &Document) -> Result<String, CompactParseError> {
 
 /// Format a joint to compact format.
 fn format_joint(output: &mut String, joint: &Joint) {
+    let name_suffix = joint
+        .name
+        .as_deref()
+        .map(|n| format!(" {}", format_quoted_string(n)))
+        .unwrap_or_default();
     let parent = joint
         .parent_instance_id
         .as_deref()
@@ -340,7 +347,7 @@ fn format_joint(output: &mut String, joint: &Joint) {
         JointKind::Fixed => {
             writeln!(
                 output,
-                "JFIX {} {} {} {} {} {} {} {} {}",
+                "JFIX {} {} {} {} {} {} {} {} {}{}",
                 escape_id(&joint.id),
                 parent,
                 child,
@@ -349,7 +356,8 @@ fn format_joint(output: &mut String, joint: &Joint) {
                 pa.z,
                 ca.x,
                 ca.y,
-                ca.z
+                ca.z,
+                name_suffix
             )
             .unwrap();
         }
@@ -374,7 +382,7 @@ fn format_joint(output: &mut String, joint: &Joint) {
             if let Some((min, max)) = limits {
                 write!(output, " {} {}", min, max).unwrap();
             }
-            writeln!(output).unwrap();
+            writeln!(output, "{}", name_suffix).unwrap();
         }
         JointKind::Slider { axis, limits } => {
             write!(
@@ -397,12 +405,12 @@ fn format_joint(output: &mut String, joint: &Joint) {
             if let Some((min, max)) = limits {
                 write!(output, " {} {}", min, max).unwrap();
             }
-            writeln!(output).unwrap();
+            writeln!(output, "{}", name_suffix).unwrap();
         }
         JointKind::Cylindrical { axis } => {
             writeln!(
                 output,
-                "JCYL {} {} {} {} {} {} {} {} {} {} {} {}",
+                "JCYL {} {} {} {} {} {} {} {} {} {} {} {}{}",
                 escape_id(&joint.id),
                 parent,
                 child,
@@ -414,14 +422,15 @@ fn format_joint(output: &mut String, joint: &Joint) {
                 ca.z,
                 axis.x,
                 axis.y,
-                axis.z
+                axis.z,
+                name_suffix
             )
             .unwrap();
         }
         JointKind::Ball => {
             writeln!(
                 output,
-                "JBAL {} {} {} {} {} {} {} {} {}",
+                "JBAL {} {} {} {} {} {} {} {} {}{}",
                 escape_id(&joint.id),
                 parent,
                 child,
@@ -430,7 +439,8 @@ fn format_joint(output: &mut String, joint: &Joint) {
                 pa.z,
                 ca.x,
                 ca.y,
-                ca.z
+                ca.z,
+                name_suffix
             )
             .unwrap();
         }
@@ -811,6 +821,7 @@ pub fn from_compact(s: &str) -> Result<Document, CompactParseError> {
                         id: node_id,
                         name,
                         op,
+                        material: None,
                     },
                 );
 
@@ -1004,11 +1015,14 @@ fn parse_part_def(
     let default_material = parts.get(4).map(|s| parse_string_arg(s));
 
     let part_defs = doc.part_defs.get_or_insert_with(HashMap::new);
+    // The writer falls back to the id when there is no name, so a name
+    // equal to the id round-trips back to None
+    let name = (name != id).then_some(name);
     part_defs.insert(
         id.clone(),
         PartDef {
             id,
-            name: Some(name),
+            name,
             root,
             default_material,
         },
@@ -1053,10 +1067,12 @@ fn parse_instance(
     let material = parts.get(13).map(|s| parse_string_arg(s));
 
     let instances = doc.instances.get_or_insert_with(Vec::new);
+    // Same fallback convention as PDEF: name == id means unnamed
+    let name = (name != id).then_some(name);
     instances.push(Instance {
         id,
         part_def_id,
-        name: Some(name),
+        name,
         transform: Some(transform),
         material,
     });
@@ -1073,6 +1089,10 @@ fn parse_joint(
 ) -> Result<(), CompactParseError> {
     let joints = doc.joints.get_or_insert_with(Vec::new);
 
+    // Optional trailing quoted name (after any optional limits)
+    let (parts, name) = extract_trailing_name(parts);
+    let parts = &parts[..];
+
     match opcode {
         "JFIX" => {
             if parts.len() < 10 {
@@ -1083,7 +1103,7 @@ fn parse_joint(
             }
             joints.push(Joint {
                 id: parse_string_arg(parts[1]),
-                name: None,
+                name: name.clone(),
                 parent_instance_id: parse_optional_parent(parts[2]),
                 child_instance_id: parse_string_arg(parts[3]),
                 parent_anchor: Vec3::new(
@@ -1114,7 +1134,7 @@ fn parse_joint(
             };
             joints.push(Joint {
                 id: parse_string_arg(parts[1]),
-                name: None,
+                name: name.clone(),
                 parent_instance_id: parse_optional_parent(parts[2]),
                 child_instance_id: parse_string_arg(parts[3]),
                 parent_anchor: Vec3::new(
@@ -1152,7 +1172,7 @@ fn parse_joint(
             };
             joints.push(Joint {
                 id: parse_string_arg(parts[1]),
-                name: None,
+                name: name.clone(),
                 parent_instance_id: parse_optional_parent(parts[2]),
                 child_instance_id: parse_string_arg(parts[3]),
                 parent_anchor: Vec3::new(
@@ -1185,7 +1205,7 @@ fn parse_joint(
             }
             joints.push(Joint {
                 id: parse_string_arg(parts[1]),
-                name: None,
+                name: name.clone(),
                 parent_instance_id: parse_optional_parent(parts[2]),
                 child_instance_id: parse_string_arg(parts[3]),
                 parent_anchor: Vec3::new(
@@ -1217,7 +1237,7 @@ fn parse_joint(
             }
             joints.push(Joint {
                 id: parse_string_arg(parts[1]),
-                name: None,
+                name: name.clone(),
                 parent_instance_id: parse_optional_parent(parts[2]),
                 child_instance_id: parse_string_arg(parts[3]),
                 parent_anchor: Vec3::new(
@@ -1532,7 +1552,9 @@ fn parse_ao(doc: &mut Document, parts: &[&str], line: usize) -> Result<(), Compa
     }
 
     let scene = doc.scene.get_or_insert_with(SceneSettings::default);
-    let pp = scene.post_processing.get_or_insert_with(PostProcessing::default);
+    let pp = scene
+        .post_processing
+        .get_or_insert_with(PostProcessing::default);
 
     pp.ambient_occlusion = Some(AmbientOcclusion {
         enabled: parse_u32(parts[1], line)? != 0,
@@ -1553,7 +1575,9 @@ fn parse_bloom(doc: &mut Document, parts: &[&str], line: usize) -> Result<(), Co
     }
 
     let scene = doc.scene.get_or_insert_with(SceneSettings::default);
-    let pp = scene.post_processing.get_or_insert_with(PostProcessing::default);
+    let pp = scene
+        .post_processing
+        .get_or_insert_with(PostProcessing::default);
 
     pp.bloom = Some(Bloom {
         enabled: parse_u32(parts[1], line)? != 0,
@@ -1578,7 +1602,9 @@ fn parse_vignette(
     }
 
     let scene = doc.scene.get_or_insert_with(SceneSettings::default);
-    let pp = scene.post_processing.get_or_insert_with(PostProcessing::default);
+    let pp = scene
+        .post_processing
+        .get_or_insert_with(PostProcessing::default);
 
     pp.vignette = Some(Vignette {
         enabled: parse_u32(parts[1], line)? != 0,
@@ -1603,7 +1629,9 @@ fn parse_tone_mapping(
     }
 
     let scene = doc.scene.get_or_insert_with(SceneSettings::default);
-    let pp = scene.post_processing.get_or_insert_with(PostProcessing::default);
+    let pp = scene
+        .post_processing
+        .get_or_insert_with(PostProcessing::default);
 
     pp.tone_mapping = Some(match parts[1] {
         "none" => ToneMapping::None,
@@ -1637,7 +1665,9 @@ fn parse_exposure(
     }
 
     let scene = doc.scene.get_or_insert_with(SceneSettings::default);
-    let pp = scene.post_processing.get_or_insert_with(PostProcessing::default);
+    let pp = scene
+        .post_processing
+        .get_or_insert_with(PostProcessing::default);
     pp.exposure = Some(parse_f64(parts[1], line)?);
 
     Ok(())
@@ -1789,6 +1819,34 @@ where
             })
         }
 
+        "PP" => {
+            if parts.len() != 4 {
+                return Err(CompactParseError {
+                    line: line_num,
+                    message: format!("PP requires 3 args, got {}", parts.len() - 1),
+                });
+            }
+            Ok(CsgOp::PolygonPrism {
+                sides: parse_u32(parts[1], line_num)?,
+                circumradius: parse_f64(parts[2], line_num)?,
+                height: parse_f64(parts[3], line_num)?,
+            })
+        }
+
+        "PY" => {
+            if parts.len() != 4 {
+                return Err(CompactParseError {
+                    line: line_num,
+                    message: format!("PY requires 3 args, got {}", parts.len() - 1),
+                });
+            }
+            Ok(CsgOp::Pyramid {
+                base_sides: parse_u32(parts[1], line_num)?,
+                circumradius: parse_f64(parts[2], line_num)?,
+                height: parse_f64(parts[3], line_num)?,
+            })
+        }
+
         "U" => {
             if parts.len() != 3 {
                 return Err(CompactParseError {
@@ -2195,14 +2253,11 @@ fn format_op(
         .unwrap_or_default();
 
     match op {
-        CsgOp::Cube { size } => Ok(format!(
-            "C {} {} {}{}",
-            size.x, size.y, size.z, name_suffix
-        )),
+        CsgOp::Cube { size } => Ok(format!("C {} {} {}{}", size.x, size.y, size.z, name_suffix)),
 
-        CsgOp::Cylinder {
-            radius, height, ..
-        } => Ok(format!("Y {} {}{}", radius, height, name_suffix)),
+        CsgOp::Cylinder { radius, height, .. } => {
+            Ok(format!("Y {} {}{}", radius, height, name_suffix))
+        }
 
         CsgOp::Sphere { radius, .. } => Ok(format!("S {}{}", radius, name_suffix)),
 
@@ -2216,6 +2271,24 @@ fn format_op(
             radius_bottom, radius_top, height, name_suffix
         )),
 
+        CsgOp::PolygonPrism {
+            sides,
+            circumradius,
+            height,
+        } => Ok(format!(
+            "PP {} {} {}{}",
+            sides, circumradius, height, name_suffix
+        )),
+
+        CsgOp::Pyramid {
+            base_sides,
+            circumradius,
+            height,
+        } => Ok(format!(
+            "PY {} {} {}{}",
+            base_sides, circumradius, height, name_suffix
+        )),
+
         CsgOp::Empty => Ok(format!("C 0 0 0{}", name_suffix)),
 
         CsgOp::Union { left, right } => {
@@ -2402,7 +2475,9 @@ fn format_op(
             Ok(lines.join("\n"))
         }
 
-        CsgOp::Extrude { sketch, direction, .. } => {
+        CsgOp::Extrude {
+            sketch, direction, ..
+        } => {
             let sk = id_map.get(sketch).ok_or_else(|| CompactParseError {
                 line: 0,
                 message: format!("unknown node {}", sketch),
@@ -2450,7 +2525,6 @@ fn format_op(
     }
 }
 
-
 fn parse_f64(s: &str, line: usize) -> Result<f64, CompactParseError> {
     s.parse().map_err(|_| CompactParseError {
         line,
@@ -2562,6 +2636,7 @@ mod tests {
             Node {
                 id: 0,
                 name: None,
+                material: None,
                 op: CsgOp::Cube {
                     size: Vec3::new(10.0, 20.0, 30.0),
                 },
@@ -2599,6 +2674,7 @@ mod tests {
             Node {
                 id: 0,
                 name: None,
+                material: None,
                 op: CsgOp::Cube {
                     size: Vec3::new(50.0, 30.0, 5.0),
                 },
@@ -2611,6 +2687,7 @@ mod tests {
             Node {
                 id: 1,
                 name: None,
+                material: None,
                 op: CsgOp::Cylinder {
                     radius: 5.0,
                     height: 10.0,
@@ -2625,6 +2702,7 @@ mod tests {
             Node {
                 id: 2,
                 name: None,
+                material: None,
                 op: CsgOp::Translate {
                     child: 1,
                     offset: Vec3::new(25.0, 15.0, 0.0),
@@ -2638,6 +2716,7 @@ mod tests {
             Node {
                 id: 3,
                 name: None,
+                material: None,
                 op: CsgOp::Difference { left: 0, right: 2 },
             },
         );
@@ -2837,7 +2916,9 @@ mod tests {
 
         // Extrude is node 1 (sequential)
         match &doc.nodes[&1].op {
-            CsgOp::Extrude { sketch, direction, .. } => {
+            CsgOp::Extrude {
+                sketch, direction, ..
+            } => {
                 assert_eq!(*sketch, 0);
                 assert_eq!(*direction, Vec3::new(0.0, 0.0, 20.0));
             }
@@ -3285,13 +3366,7 @@ CAM cam1 100 100 100 0 0 0 60 "Front View"
 CAM cam2 0 100 0 0 0 0"#;
 
         let doc = from_compact(compact).unwrap();
-        let cams = doc
-            .scene
-            .as_ref()
-            .unwrap()
-            .camera_presets
-            .as_ref()
-            .unwrap();
+        let cams = doc.scene.as_ref().unwrap().camera_presets.as_ref().unwrap();
         assert_eq!(cams.len(), 2);
 
         assert_eq!(cams[0].id, "cam1");
@@ -3329,6 +3404,7 @@ CAM cam2 0 100 0 0 0 0"#;
             Node {
                 id: 0,
                 name: Some("Cube".to_string()),
+                material: None,
                 op: CsgOp::Cube {
                     size: Vec3::new(10.0, 10.0, 10.0),
                 },
@@ -3455,4 +3531,116 @@ CAM cam2 0 100 0 0 0 0"#;
         ));
         assert!(matches!(scene.background, Some(Background::Solid { .. })));
     }
+
+    #[test]
+    fn test_labels_roundtrip() {
+        let mut doc = Document::new();
+
+        doc.nodes.insert(
+            0,
+            Node {
+                id: 0,
+                name: Some("Base Plate".to_string()),
+                material: None,
+                op: CsgOp::Cube {
+                    size: Vec3::new(50.0, 30.0, 5.0),
+                },
+            },
+        );
+        doc.nodes.insert(
+            1,
+            Node {
+                id: 1,
+                name: None,
+                material: None,
+                op: CsgOp::Translate {
+                    child: 0,
+                    offset: Vec3::new(10.0, 0.0, 0.0),
+                },
+            },
+        );
+
+        let mut part_defs = HashMap::new();
+        part_defs.insert(
+            "arm".to_string(),
+            PartDef {
+                id: "arm".to_string(),
+                name: Some("Robot Arm".to_string()),
+                root: 1,
+                default_material: None,
+            },
+        );
+        part_defs.insert(
+            "base".to_string(),
+            PartDef {
+                id: "base".to_string(),
+                name: None,
+                root: 1,
+                default_material: None,
+            },
+        );
+        doc.part_defs = Some(part_defs);
+
+        doc.instances = Some(vec![
+            Instance {
+                id: "ee".to_string(),
+                part_def_id: "arm".to_string(),
+                name: Some("End Effector".to_string()),
+                transform: Some(Transform3D::default()),
+                material: None,
+            },
+            Instance {
+                id: "anon".to_string(),
+                part_def_id: "base".to_string(),
+                name: None,
+                transform: Some(Transform3D::default()),
+                material: None,
+            },
+        ]);
+
+        doc.joints = Some(vec![
+            Joint {
+                id: "j1".to_string(),
+                name: Some("Shoulder".to_string()),
+                parent_instance_id: Some("anon".to_string()),
+                child_instance_id: "ee".to_string(),
+                parent_anchor: Vec3::new(0.0, 0.0, 5.0),
+                child_anchor: Vec3::new(0.0, 0.0, 0.0),
+                kind: JointKind::Revolute {
+                    axis: Vec3::new(0.0, 0.0, 1.0),
+                    limits: Some((-45.0, 45.0)),
+                },
+                state: 0.0,
+            },
+            Joint {
+                id: "j2".to_string(),
+                name: None,
+                parent_instance_id: None,
+                child_instance_id: "anon".to_string(),
+                parent_anchor: Vec3::new(0.0, 0.0, 0.0),
+                child_anchor: Vec3::new(0.0, 0.0, 0.0),
+                kind: JointKind::Fixed,
+                state: 0.0,
+            },
+        ]);
+
+        let restored = from_compact(&to_compact(&doc).unwrap()).unwrap();
+
+        assert_eq!(restored.nodes[&0].name, Some("Base Plate".to_string()));
+        assert_eq!(restored.nodes[&1].name, None);
+
+        let pdefs = restored.part_defs.unwrap();
+        assert_eq!(pdefs["arm"].name, Some("Robot Arm".to_string()));
+        assert_eq!(pdefs["base"].name, None);
+
+        let insts = restored.instances.unwrap();
+        let ee = insts.iter().find(|i| i.id == "ee").unwrap();
+        assert_eq!(ee.name, Some("End Effector".to_string()));
+        let anon = insts.iter().find(|i| i.id == "anon").unwrap();
+        assert_eq!(anon.name, None);
+
+        let joints = restored.joints.unwrap();
+        assert_eq!(joints[0].name, Some("Shoulder".to_string()));
+        assert_eq!(joints[1].name, None);
+    }
 }
@@ -0,0 +1,173 @@
+//! Node-level diff and patch for IR documents.
+//!
+//! Computes the minimal set of node edits that turns one [`Document`]'s
+//! graph into another's. Used for ML edit-based training ("edit the model"
+//! tasks) where the target is a small change to an existing document.
+//!
+//! The diff covers the node graph only; materials, roots, and assembly
+//! fields are not compared.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Document, Node, NodeId};
+
+/// A single node-level edit between two documents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum IrEdit {
+    /// A node that exists only in the target document.
+    AddNode {
+        /// The node to insert.
+        node: Node,
+    },
+    /// A node that exists only in the source document.
+    RemoveNode {
+        /// Id of the node to remove.
+        id: NodeId,
+    },
+    /// A node that exists in both documents with different contents.
+    ModifyNode {
+        /// The new contents for the node.
+        node: Node,
+    },
+}
+
+/// Compute the node edits that turn `a`'s graph into `b`'s.
+///
+/// Edits are ordered removes, then modifies, then adds, each sorted by
+/// node id, so the result is deterministic.
+pub fn diff(a: &Document, b: &Document) -> Vec<IrEdit> {
+    let mut removes: Vec<NodeId> = a
+        .nodes
+        .keys()
+        .filter(|id| !b.nodes.contains_key(id))
+        .copied()
+        .collect();
+    removes.sort_unstable();
+
+    let mut modifies: Vec<NodeId> = a
+        .nodes
+        .iter()
+        .filter(|(id, node)| b.nodes.get(id).is_some_and(|other| other != *node))
+        .map(|(id, _)| *id)
+        .collect();
+    modifies.sort_unstable();
+
+    let mut adds: Vec<NodeId> = b
+        .nodes
+        .keys()
+        .filter(|id| !a.nodes.contains_key(id))
+        .copied()
+        .collect();
+    adds.sort_unstable();
+
+    let mut edits = Vec::new();
+    edits.extend(removes.into_iter().map(|id| IrEdit::RemoveNode { id }));
+    edits.extend(modifies.into_iter().map(|id| IrEdit::ModifyNode {
+        node: b.nodes[&id].clone(),
+    }));
+    edits.extend(adds.into_iter().map(|id| IrEdit::AddNode {
+        node: b.nodes[&id].clone(),
+    }));
+    edits
+}
+
+/// Apply node edits to a document in place.
+///
+/// Adds and modifies insert or replace the node under its id; removes
+/// of unknown ids are no-ops, so a diff can be re-applied safely.
+pub fn apply_diff(doc: &mut Document, edits: &[IrEdit]) {
+    for edit in edits {
+        match edit {
+            IrEdit::AddNode { node } | IrEdit::ModifyNode { node } => {
+                doc.nodes.insert(node.id, node.clone());
+            }
+            IrEdit::RemoveNode { id } => {
+                doc.nodes.remove(id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CsgOp, Vec3};
+
+    fn cube_doc() -> Document {
+        let mut doc = Document::new();
+        doc.nodes.insert(
+            0,
+            Node {
+                id: 0,
+                name: None,
+                material: None,
+                op: CsgOp::Cube {
+                    size: Vec3::new(10.0, 10.0, 10.0),
+                },
+            },
+        );
+        doc
+    }
+
+    #[test]
+    fn diff_added_translate_is_single_add() {
+        let a = cube_doc();
+        let mut b = a.clone();
+        b.nodes.insert(
+            1,
+            Node {
+                id: 1,
+                name: None,
+                material: None,
+                op: CsgOp::Translate {
+                    child: 0,
+                    offset: Vec3::new(5.0, 0.0, 0.0),
+                },
+            },
+        );
+
+        let edits = diff(&a, &b);
+        assert_eq!(edits.len(), 1);
+        assert!(matches!(&edits[0], IrEdit::AddNode { node } if node.id == 1));
+
+        let mut patched = a.clone();
+        apply_diff(&mut patched, &edits);
+        assert_eq!(patched.nodes, b.nodes);
+    }
+
+    #[test]
+    fn diff_roundtrip_modify_and_remove() {
+        let mut a = cube_doc();
+        a.nodes.insert(
+            1,
+            Node {
+                id: 1,
+                name: None,
+                material: None,
+                op: CsgOp::Sphere {
+                    radius: 4.0,
+                    segments: 0,
+                },
+            },
+        );
+
+        let mut b = cube_doc();
+        if let Some(node) = b.nodes.get_mut(&0) {
+            node.op = CsgOp::Cube {
+                size: Vec3::new(20.0, 10.0, 10.0),
+            };
+        }
+
+        let edits = diff(&a, &b);
+        assert_eq!(edits.len(), 2);
+        assert!(matches!(&edits[0], IrEdit::RemoveNode { id: 1 }));
+        assert!(matches!(&edits[1], IrEdit::ModifyNode { node } if node.id == 0));
+
+        apply_diff(&mut a, &edits);
+        assert_eq!(a.nodes, b.nodes);
+
+        // Identical documents produce an empty diff
+        assert!(diff(&a, &b).is_empty());
+    }
+}
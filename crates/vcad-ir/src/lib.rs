@@ -9,7 +9,14 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub mod bounds;
 pub mod compact;
+pub mod diff;
+pub mod random;
+
+pub use bounds::{estimate_bounds, Bounds};
+pub use diff::{apply_diff, diff, IrEdit};
+pub use random::random_document;
 
 // ============================================================================
 // Assembly types (for kinematics)
@@ -257,6 +264,24 @@ pub enum CsgOp {
         /// Number of circular segments (0 = auto).
         segments: u32,
     },
+    /// Regular polygonal prism along the Z axis, base in the XY plane.
+    PolygonPrism {
+        /// Number of lateral faces (polygon sides, at least 3).
+        sides: u32,
+        /// Circumradius of the base polygon.
+        circumradius: f64,
+        /// Height of the prism.
+        height: f64,
+    },
+    /// Pyramid over a regular polygon base, apex on the Z axis.
+    Pyramid {
+        /// Number of base polygon sides (at least 3).
+        base_sides: u32,
+        /// Circumradius of the base polygon.
+        circumradius: f64,
+        /// Height to the apex.
+        height: f64,
+    },
     /// Empty geometry (identity for union).
     Empty,
     /// Boolean union of two geometries.
@@ -419,6 +444,39 @@ pub enum CsgOp {
     },
 }
 
+impl CsgOp {
+    /// Ids of the child nodes this operation references, in operand order.
+    ///
+    /// Leaf operations (primitives, sketches, text, imports) return an
+    /// empty vector.
+    pub fn children(&self) -> Vec<NodeId> {
+        match self {
+            CsgOp::Union { left, right }
+            | CsgOp::Difference { left, right }
+            | CsgOp::Intersection { left, right } => vec![*left, *right],
+            CsgOp::Translate { child, .. }
+            | CsgOp::Rotate { child, .. }
+            | CsgOp::Scale { child, .. }
+            | CsgOp::LinearPattern { child, .. }
+            | CsgOp::CircularPattern { child, .. }
+            | CsgOp::Shell { child, .. }
+            | CsgOp::Fillet { child, .. }
+            | CsgOp::Chamfer { child, .. } => vec![*child],
+            CsgOp::Extrude { sketch, .. } | CsgOp::Revolve { sketch, .. } => vec![*sketch],
+            CsgOp::Cube { .. }
+            | CsgOp::Cylinder { .. }
+            | CsgOp::Sphere { .. }
+            | CsgOp::Cone { .. }
+            | CsgOp::PolygonPrism { .. }
+            | CsgOp::Pyramid { .. }
+            | CsgOp::Empty
+            | CsgOp::Sketch2D { .. }
+            | CsgOp::Text2D { .. }
+            | CsgOp::StepImport { .. } => Vec::new(),
+        }
+    }
+}
+
 /// A node in the IR graph.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Node {
@@ -428,6 +486,9 @@ pub struct Node {
     pub name: Option<String>,
     /// The operation this node represents.
     pub op: CsgOp,
+    /// Optional material id tag, used for per-material volume breakdowns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub material: Option<String>,
 }
 
 /// PBR material definition.
@@ -796,6 +857,7 @@ mod tests {
             Node {
                 id: cube_id,
                 name: Some("box".to_string()),
+                material: None,
                 op: CsgOp::Cube {
                     size: Vec3::new(10.0, 20.0, 30.0),
                 },
@@ -809,6 +871,7 @@ mod tests {
             Node {
                 id: cyl_id,
                 name: Some("hole".to_string()),
+                material: None,
                 op: CsgOp::Cylinder {
                     radius: 3.0,
                     height: 40.0,
@@ -824,6 +887,7 @@ mod tests {
             Node {
                 id: diff_id,
                 name: Some("box_with_hole".to_string()),
+                material: None,
                 op: CsgOp::Difference {
                     left: cube_id,
                     right: cyl_id,
@@ -870,6 +934,7 @@ mod tests {
             Node {
                 id: 1,
                 name: None,
+                material: None,
                 op: CsgOp::Sphere {
                     radius: 5.0,
                     segments: 0,
@@ -882,6 +947,7 @@ mod tests {
             Node {
                 id: 2,
                 name: None,
+                material: None,
                 op: CsgOp::Cube {
                     size: Vec3::new(8.0, 8.0, 8.0),
                 },
@@ -893,6 +959,7 @@ mod tests {
             Node {
                 id: 3,
                 name: Some("rounded_cube".to_string()),
+                material: None,
                 op: CsgOp::Intersection { left: 1, right: 2 },
             },
         );
@@ -941,6 +1008,7 @@ mod tests {
             Node {
                 id: sketch_id,
                 name: Some("rectangle".to_string()),
+                material: None,
                 op: CsgOp::Sketch2D {
                     origin: Vec3::new(0.0, 0.0, 0.0),
                     x_dir: Vec3::new(1.0, 0.0, 0.0),
@@ -974,6 +1042,7 @@ mod tests {
             Node {
                 id: extrude_id,
                 name: Some("extruded_block".to_string()),
+                material: None,
                 op: CsgOp::Extrude {
                     sketch: sketch_id,
                     direction: Vec3::new(0.0, 0.0, 20.0),
@@ -996,7 +1065,9 @@ mod tests {
             _ => panic!("expected Sketch2D"),
         }
         match &restored.nodes[&extrude_id].op {
-            CsgOp::Extrude { sketch, direction, .. } => {
+            CsgOp::Extrude {
+                sketch, direction, ..
+            } => {
                 assert_eq!(*sketch, sketch_id);
                 assert_eq!(direction.z, 20.0);
             }
@@ -1029,6 +1100,7 @@ mod tests {
             Node {
                 id: cube_id,
                 name: Some("base_cube".to_string()),
+                material: None,
                 op: CsgOp::Cube {
                     size: Vec3::new(10.0, 10.0, 10.0),
                 },
@@ -1042,6 +1114,7 @@ mod tests {
             Node {
                 id: cyl_id,
                 name: Some("arm_cylinder".to_string()),
+                material: None,
                 op: CsgOp::Cylinder {
                     radius: 2.0,
                     height: 20.0,
@@ -1,10 +1,18 @@
 //! Public API types and entry point for boolean operations.
 
-use vcad_kernel_primitives::BRepSolid;
+use std::collections::HashMap;
+
+use thiserror::Error;
+use vcad_kernel_geom::{GeometryStore, Plane, SurfaceKind};
+use vcad_kernel_math::{geometry_tolerance, Point3, Transform};
+use vcad_kernel_primitives::{make_cube, BRepSolid};
 use vcad_kernel_tessellate::{tessellate_brep, TriangleMesh};
+use vcad_kernel_topo::{FaceId, ShellType, Topology};
 
-use crate::bbox;
+use crate::classify::FaceClassification;
 use crate::pipeline::{brep_boolean, non_overlapping_boolean};
+use crate::ssi::IntersectionCurve;
+use crate::{bbox, diagnostics, ssi};
 
 /// CSG boolean operation type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -15,6 +23,170 @@ pub enum BooleanOp {
     Difference,
     /// Intersection: keep only the overlapping region.
     Intersection,
+    /// Symmetric difference: keep the regions inside exactly one solid.
+    SymmetricDifference,
+}
+
+/// Tuning parameters for the boolean pipeline.
+///
+/// The defaults suit models in millimeters; callers working in meters or
+/// micrometers can scale `weld_tol` to their units. [`Default`] picks the
+/// weld tolerance up from the module geometry tolerance, so
+/// [`vcad_kernel_math::set_geometry_tolerance`] still applies to calls that
+/// don't pass explicit params.
+#[derive(Debug, Clone, Copy)]
+pub struct BooleanParams {
+    /// Vertex weld distance used when sewing result faces, in mm.
+    pub weld_tol: f64,
+    /// Sample count used when trimming intersection curves to face
+    /// boundaries.
+    pub trim_samples: u32,
+    /// Segment count used for tessellation during face classification and
+    /// curved-face splitting.
+    pub classify_segments: u32,
+    /// Geometric coincidence tolerance used by the trim and classify stages,
+    /// in mm.
+    ///
+    /// Captured from [`vcad_kernel_math::geometry_tolerance`] at construction
+    /// and passed explicitly into the pipeline's parallel stages: the global
+    /// tolerance is thread-local, so rayon worker threads would otherwise see
+    /// their own default rather than the caller's value.
+    pub geom_tol: f64,
+}
+
+impl Default for BooleanParams {
+    fn default() -> Self {
+        Self {
+            weld_tol: geometry_tolerance(),
+            trim_samples: 64,
+            classify_segments: 32,
+            geom_tol: geometry_tolerance(),
+        }
+    }
+}
+
+/// Iteration budget applied to the split loops by [`boolean_op`].
+///
+/// Generous enough for any realistic model; its only purpose is to turn a
+/// non-converging split loop into an abort instead of a hung thread.
+pub const DEFAULT_MAX_SPLIT_ITERATIONS: u64 = 1_000_000;
+
+/// Errors from boolean operations.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BooleanError {
+    /// The face-split loop exceeded its iteration budget without converging.
+    #[error("boolean split loop exceeded {max_iterations} iterations without converging")]
+    Timeout {
+        /// The iteration cap that was exceeded.
+        max_iterations: u64,
+    },
+    /// The operation is genuinely empty (e.g. intersection of disjoint
+    /// solids) and has no B-rep topology to return.
+    #[error("boolean produced an empty result with no B-rep topology")]
+    EmptyResult,
+    /// The solids' bounding boxes overlap, but surface-surface intersection
+    /// found no curve for any candidate face pair.
+    #[error("no intersection curves found between overlapping solids")]
+    NoIntersectionCurves,
+    /// A candidate face pair combines surface kinds with no dedicated
+    /// intersection routine, and the generic fallback found nothing.
+    #[error("unsupported surface pair {a:?} × {b:?} (faces {face_a:?}, {face_b:?})")]
+    UnhandledSurfacePair {
+        /// Surface kind of the face from solid A.
+        a: SurfaceKind,
+        /// Surface kind of the face from solid B.
+        b: SurfaceKind,
+        /// The offending face of solid A.
+        face_a: FaceId,
+        /// The offending face of solid B.
+        face_b: FaceId,
+    },
+    /// The requested operation is not supported by this entry point.
+    #[error("{op:?} is not supported by {context}")]
+    UnsupportedOperation {
+        /// The rejected operation.
+        op: BooleanOp,
+        /// The entry point that rejected it.
+        context: &'static str,
+    },
+    /// Sewing finished but left open boundary edges, so the result is not
+    /// a closed solid.
+    #[error("sewn result has {boundary_edges} open boundary half-edges")]
+    OpenResult {
+        /// Number of half-edges with no twin in the result shell.
+        boundary_edges: usize,
+    },
+}
+
+/// Which input solid a boolean result face came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolidSource {
+    /// The first operand (the target).
+    A,
+    /// The second operand (the tool).
+    B,
+}
+
+/// Provenance of one face of a boolean result, as reported by
+/// [`boolean_op_tagged`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaceProvenance {
+    /// Which input solid contributed the face.
+    pub source: SolidSource,
+    /// The face of that input solid it came from; split sub-faces inherit
+    /// their parent face. `None` when the lineage could not be traced.
+    pub original_face: Option<FaceId>,
+}
+
+/// One surface-surface intersection found during a traced boolean, as
+/// reported by [`boolean_op_debug`].
+#[derive(Debug, Clone)]
+pub struct SsiCurve {
+    /// The face of solid A in the candidate pair.
+    pub face_a: FaceId,
+    /// The face of solid B in the candidate pair.
+    pub face_b: FaceId,
+    /// The intersection curve of the two faces' surfaces (never `Empty`).
+    pub curve: IntersectionCurve,
+}
+
+/// One input face that the split stage subdivided, as reported by
+/// [`boolean_op_debug`].
+#[derive(Debug, Clone)]
+pub struct FaceSplit {
+    /// The pre-split face. Working copies share face ids with the input
+    /// solids, so this names a face of the original operand.
+    pub input_face: FaceId,
+    /// The sub-faces it was split into, in the working copy.
+    pub sub_faces: Vec<FaceId>,
+}
+
+/// Structured intermediate data from each stage of one boolean pipeline
+/// run, as returned by [`boolean_op_debug`].
+///
+/// Face ids in the split, classification, and selection fields refer to the
+/// pipeline's working copies of the operands; faces that survived splitting
+/// keep their input ids, split sub-faces get fresh ones.
+#[derive(Debug, Clone, Default)]
+pub struct BooleanTrace {
+    /// Face pairs whose AABBs overlapped (stage 1).
+    pub candidate_pairs: Vec<(FaceId, FaceId)>,
+    /// Non-empty surface-surface intersections per candidate pair (stage 2).
+    pub ssi_curves: Vec<SsiCurve>,
+    /// Faces of A subdivided by the split stage (stage 3).
+    pub splits_a: Vec<FaceSplit>,
+    /// Faces of B subdivided by the split stage (stage 3).
+    pub splits_b: Vec<FaceSplit>,
+    /// Classification of every A face against B after splitting (stage 4).
+    pub classifications_a: Vec<(FaceId, FaceClassification)>,
+    /// Classification of every B face against A after splitting (stage 4).
+    pub classifications_b: Vec<(FaceId, FaceClassification)>,
+    /// A faces selected for the result (stage 5).
+    pub selected_a: Vec<FaceId>,
+    /// B faces selected for the result (stage 5).
+    pub selected_b: Vec<FaceId>,
+    /// Whether the selected B faces are sewn with reversed orientation.
+    pub reverse_b: bool,
 }
 
 /// Result of a boolean operation.
@@ -73,15 +245,476 @@ pub fn boolean_op(
     op: BooleanOp,
     segments: u32,
 ) -> BooleanResult {
+    // The default budget is unreachable for well-formed input; hitting it
+    // means the split loop was not converging, so an empty result is
+    // preferable to hanging the thread (and on WASM, the tab).
+    boolean_op_with_limit(solid_a, solid_b, op, segments, DEFAULT_MAX_SPLIT_ITERATIONS)
+        .unwrap_or_else(|_| {
+            BooleanResult::Mesh(TriangleMesh {
+                vertices: Vec::new(),
+                indices: Vec::new(),
+                normals: Vec::new(),
+            })
+        })
+}
+
+/// Like [`boolean_op`], but aborts with [`BooleanError::Timeout`] once the
+/// split-application loops have performed `max_iterations` face splits
+/// without converging, instead of hanging the thread.
+pub fn boolean_op_with_limit(
+    solid_a: &BRepSolid,
+    solid_b: &BRepSolid,
+    op: BooleanOp,
+    segments: u32,
+    max_iterations: u64,
+) -> Result<BooleanResult, BooleanError> {
+    let params = BooleanParams {
+        classify_segments: segments,
+        ..BooleanParams::default()
+    };
+
     // Check if solids overlap at all
     let aabb_a = bbox::solid_aabb(solid_a);
     let aabb_b = bbox::solid_aabb(solid_b);
 
     if !aabb_a.overlaps(&aabb_b) {
         // No overlap — shortcut
-        return non_overlapping_boolean(solid_a, solid_b, op, segments);
+        return Ok(non_overlapping_boolean(solid_a, solid_b, op, &params));
     }
 
     // Solids overlap — use classification pipeline
-    brep_boolean(solid_a, solid_b, op, segments)
+    brep_boolean(
+        solid_a,
+        solid_b,
+        op,
+        &params,
+        None,
+        max_iterations,
+        None,
+        None,
+    )
+}
+
+/// Like [`boolean_op`], but fails loudly instead of degrading to a mesh.
+///
+/// Returns the B-rep result only when the pipeline produced a closed solid;
+/// otherwise reports which stage failed — no intersection curves, an
+/// unsupported surface pair, sewing left open edges — so tools can surface
+/// an actionable diagnostic instead of silently losing topology (and with
+/// it STEP exportability).
+pub fn try_boolean_op(
+    solid_a: &BRepSolid,
+    solid_b: &BRepSolid,
+    op: BooleanOp,
+    segments: u32,
+) -> Result<BRepSolid, BooleanError> {
+    let params = BooleanParams {
+        classify_segments: segments,
+        ..BooleanParams::default()
+    };
+
+    let aabb_a = bbox::solid_aabb(solid_a);
+    let aabb_b = bbox::solid_aabb(solid_b);
+    if !aabb_a.overlaps(&aabb_b) {
+        return match non_overlapping_boolean(solid_a, solid_b, op, &params) {
+            BooleanResult::BRep(brep) => Ok(*brep),
+            BooleanResult::Mesh(_) => Err(BooleanError::EmptyResult),
+        };
+    }
+
+    // Pre-flight SSI scan: when no candidate pair intersects, report the
+    // stage that failed — naming the offending pair if an unsupported
+    // surface combination was the likely cause
+    let pairs = bbox::find_candidate_face_pairs(solid_a, solid_b);
+    let mut any_curve = false;
+    let mut unhandled: Option<BooleanError> = None;
+    for (face_a, face_b) in &pairs {
+        let Some(face_data_a) = solid_a.topology.faces.get(*face_a) else {
+            continue;
+        };
+        let Some(face_data_b) = solid_b.topology.faces.get(*face_b) else {
+            continue;
+        };
+        let Some(surf_a) = solid_a.geometry.surfaces.get(face_data_a.surface_index) else {
+            continue;
+        };
+        let Some(surf_b) = solid_b.geometry.surfaces.get(face_data_b.surface_index) else {
+            continue;
+        };
+
+        let curve = ssi::intersect_surfaces(surf_a.as_ref(), surf_b.as_ref());
+        if matches!(curve, ssi::IntersectionCurve::Empty) {
+            let (kind_a, kind_b) = (surf_a.surface_type(), surf_b.surface_type());
+            if unhandled.is_none() && !ssi::has_dedicated_intersection(kind_a, kind_b) {
+                unhandled = Some(BooleanError::UnhandledSurfacePair {
+                    a: kind_a,
+                    b: kind_b,
+                    face_a: *face_a,
+                    face_b: *face_b,
+                });
+            }
+        } else {
+            any_curve = true;
+        }
+    }
+    if !any_curve {
+        return Err(unhandled.unwrap_or(BooleanError::NoIntersectionCurves));
+    }
+
+    let brep = match brep_boolean(
+        solid_a,
+        solid_b,
+        op,
+        &params,
+        None,
+        DEFAULT_MAX_SPLIT_ITERATIONS,
+        None,
+        None,
+    )? {
+        BooleanResult::BRep(brep) => *brep,
+        BooleanResult::Mesh(_) => return Err(BooleanError::EmptyResult),
+    };
+
+    let diag = diagnostics::analyze_result(&brep);
+    if diag.open_boundary_half_edges > 0 {
+        return Err(BooleanError::OpenResult {
+            boundary_edges: diag.open_boundary_half_edges,
+        });
+    }
+
+    Ok(brep)
+}
+
+/// Like [`boolean_op`], but with explicit pipeline tuning.
+///
+/// Threads `params` through trimming, classification, and sewing so the
+/// vertex weld distance and sampling densities can be scaled to the model's
+/// units instead of relying on the millimeter defaults.
+pub fn boolean_op_with_params(
+    solid_a: &BRepSolid,
+    solid_b: &BRepSolid,
+    op: BooleanOp,
+    params: &BooleanParams,
+) -> BooleanResult {
+    let aabb_a = bbox::solid_aabb(solid_a);
+    let aabb_b = bbox::solid_aabb(solid_b);
+    if !aabb_a.overlaps(&aabb_b) {
+        return non_overlapping_boolean(solid_a, solid_b, op, params);
+    }
+
+    brep_boolean(
+        solid_a,
+        solid_b,
+        op,
+        params,
+        None,
+        DEFAULT_MAX_SPLIT_ITERATIONS,
+        None,
+        None,
+    )
+    .unwrap_or_else(|_| {
+        BooleanResult::Mesh(TriangleMesh {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            normals: Vec::new(),
+        })
+    })
+}
+
+/// Like [`boolean_op`], but resolves split circles from a chord tolerance
+/// instead of the fixed segment count.
+///
+/// Each circular intersection curve is polygonized with enough segments to
+/// keep its chord sagitta below `chord_tolerance` at its own radius, so a
+/// small hole in a large plate is resolved as accurately as a large bore
+/// while planar geometry stays untouched. Non-circular splits and face
+/// classification use the default segment count.
+pub fn boolean_op_adaptive(
+    solid_a: &BRepSolid,
+    solid_b: &BRepSolid,
+    op: BooleanOp,
+    chord_tolerance: f64,
+) -> BooleanResult {
+    let params = BooleanParams::default();
+
+    let aabb_a = bbox::solid_aabb(solid_a);
+    let aabb_b = bbox::solid_aabb(solid_b);
+    if !aabb_a.overlaps(&aabb_b) {
+        return non_overlapping_boolean(solid_a, solid_b, op, &params);
+    }
+
+    brep_boolean(
+        solid_a,
+        solid_b,
+        op,
+        &params,
+        Some(chord_tolerance),
+        DEFAULT_MAX_SPLIT_ITERATIONS,
+        None,
+        None,
+    )
+    .unwrap_or_else(|_| {
+        BooleanResult::Mesh(TriangleMesh {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            normals: Vec::new(),
+        })
+    })
+}
+
+/// Imprint the intersection curves of `solid_b` onto `solid_a`'s faces.
+///
+/// Runs the first three pipeline stages (AABB filter, surface-surface
+/// intersection, face splitting) and skips classification and sewing, so
+/// all of A's material is kept — it just comes back with extra edges and
+/// sub-faces where B touches it, ready for sub-face selection in assembly
+/// and fixture workflows. `segments` controls how finely circular
+/// intersection curves are polygonized, like the [`boolean_op`] parameter.
+pub fn imprint(solid_a: &BRepSolid, solid_b: &BRepSolid, segments: u32) -> BRepSolid {
+    let params = BooleanParams {
+        classify_segments: segments,
+        ..BooleanParams::default()
+    };
+    crate::pipeline::imprint_splits(solid_a, solid_b, &params)
+}
+
+/// Like [`boolean_op`], but also reports where each result face came from.
+///
+/// The provenance map is keyed by face ids of the result solid: `source`
+/// says whether the face was contributed by A or B, and `original_face`
+/// names the input face it descends from — split sub-faces inherit their
+/// parent. Downstream tools use this for coloring result faces by operand
+/// or filleting only the edges a cut introduced. Fails like
+/// [`try_boolean_op`] when the pipeline degrades to a mesh, since a mesh
+/// has no faces to tag; symmetric difference is built from nested sews and
+/// is not supported here.
+pub fn boolean_op_tagged(
+    solid_a: &BRepSolid,
+    solid_b: &BRepSolid,
+    op: BooleanOp,
+    segments: u32,
+) -> Result<(BRepSolid, HashMap<FaceId, FaceProvenance>), BooleanError> {
+    let params = BooleanParams {
+        classify_segments: segments,
+        ..BooleanParams::default()
+    };
+
+    if op == BooleanOp::SymmetricDifference {
+        return Err(BooleanError::UnsupportedOperation {
+            op,
+            context: "boolean_op_tagged",
+        });
+    }
+
+    let aabb_a = bbox::solid_aabb(solid_a);
+    let aabb_b = bbox::solid_aabb(solid_b);
+    if !aabb_a.overlaps(&aabb_b) {
+        // Mirror non_overlapping_boolean, but keep the sew face maps
+        let faces_a: Vec<FaceId> = solid_a.topology.faces.keys().collect();
+        let faces_b: Vec<FaceId> = match op {
+            BooleanOp::Union => solid_b.topology.faces.keys().collect(),
+            BooleanOp::Difference => Vec::new(),
+            _ => return Err(BooleanError::EmptyResult),
+        };
+        let (result, sources) = crate::sew::sew_faces_mapped(
+            solid_a,
+            &faces_a,
+            solid_b,
+            &faces_b,
+            false,
+            params.weld_tol,
+        );
+        let provenance = sources
+            .into_iter()
+            .map(|(new_face, (from_b, src_face))| {
+                (
+                    new_face,
+                    FaceProvenance {
+                        source: if from_b {
+                            SolidSource::B
+                        } else {
+                            SolidSource::A
+                        },
+                        original_face: Some(src_face),
+                    },
+                )
+            })
+            .collect();
+        return Ok((result, provenance));
+    }
+
+    let mut provenance = HashMap::new();
+    match brep_boolean(
+        solid_a,
+        solid_b,
+        op,
+        &params,
+        None,
+        DEFAULT_MAX_SPLIT_ITERATIONS,
+        Some(&mut provenance),
+        None,
+    )? {
+        BooleanResult::BRep(brep) => Ok((*brep, provenance)),
+        BooleanResult::Mesh(_) => Err(BooleanError::EmptyResult),
+    }
+}
+
+/// Run a boolean and return what each pipeline stage did, instead of the
+/// result solid.
+///
+/// The returned [`BooleanTrace`] holds the candidate face pairs, the SSI
+/// curve found for each intersecting pair, the splits applied to both
+/// operands, every face classification, and the final face selection — the
+/// data the pipeline's debug logging prints, but as structs, so tools can
+/// pinpoint which stage went wrong for a given input without re-running
+/// the stages by hand. If the pipeline aborts partway (split timeout), the
+/// trace still holds the stages that completed; for non-overlapping
+/// operands it is empty, since the shortcut path runs no stages. Symmetric
+/// difference records the selection of its first (`A \ B`) sew pass.
+pub fn boolean_op_debug(
+    solid_a: &BRepSolid,
+    solid_b: &BRepSolid,
+    op: BooleanOp,
+    segments: u32,
+) -> BooleanTrace {
+    let params = BooleanParams {
+        classify_segments: segments,
+        ..BooleanParams::default()
+    };
+
+    let mut trace = BooleanTrace::default();
+    let aabb_a = bbox::solid_aabb(solid_a);
+    let aabb_b = bbox::solid_aabb(solid_b);
+    if !aabb_a.overlaps(&aabb_b) {
+        return trace;
+    }
+
+    let _ = brep_boolean(
+        solid_a,
+        solid_b,
+        op,
+        &params,
+        None,
+        DEFAULT_MAX_SPLIT_ITERATIONS,
+        None,
+        Some(&mut trace),
+    );
+    trace
+}
+
+/// Split a solid into the two halves on either side of a plane.
+///
+/// Returns `(positive, negative)`, where `positive` is the material on the
+/// side the plane normal points towards. Every face crossing the plane is
+/// split, and each half is capped with a new planar face on the cut, so
+/// both come back watertight — the usual reasons to section a part
+/// (printing halves of something too large for the bed, inspecting
+/// internal structure) need closed solids.
+///
+/// A plane that never reaches the solid sends the whole solid to the half
+/// it lies in and leaves the other half as an empty solid. The cut itself
+/// runs the same split/sew pipeline as [`boolean_op`] against a half-space
+/// slab on each side of the plane; if the pipeline degrades to a mesh for
+/// one side, that half also comes back empty, since there is no B-rep
+/// topology to return.
+pub fn split_by_plane(solid: &BRepSolid, plane: &Plane) -> (BRepSolid, BRepSolid) {
+    let n = *plane.normal_dir.as_ref();
+    let tol = geometry_tolerance();
+
+    // Signed extent of the solid along the plane normal — if the plane
+    // misses the solid entirely there is nothing to cut
+    let mut min_d = f64::INFINITY;
+    let mut max_d = f64::NEG_INFINITY;
+    for (_, vertex) in &solid.topology.vertices {
+        let d = (vertex.point - plane.origin).dot(&n);
+        min_d = min_d.min(d);
+        max_d = max_d.max(d);
+    }
+    if !min_d.is_finite() {
+        return (empty_half(), empty_half());
+    }
+    if min_d >= -tol {
+        return (solid.clone(), empty_half());
+    }
+    if max_d <= tol {
+        return (empty_half(), solid.clone());
+    }
+
+    let aabb = bbox::solid_aabb(solid);
+    let center = Point3::from((aabb.min.coords + aabb.max.coords) * 0.5);
+    let diag = (aabb.max - aabb.min).norm();
+    let offset = (center - plane.origin).dot(&n);
+    // Big enough that the slab's far faces clear the solid on every side
+    let size = 2.0 * (diag + offset.abs()) + 1.0;
+    let anchor = center - n * offset;
+
+    let positive = match boolean_op(
+        solid,
+        &half_space_slab(plane, anchor, size, true),
+        BooleanOp::Intersection,
+        32,
+    ) {
+        BooleanResult::BRep(brep) => *brep,
+        BooleanResult::Mesh(_) => empty_half(),
+    };
+    let negative = match boolean_op(
+        solid,
+        &half_space_slab(plane, anchor, size, false),
+        BooleanOp::Intersection,
+        32,
+    ) {
+        BooleanResult::BRep(brep) => *brep,
+        BooleanResult::Mesh(_) => empty_half(),
+    };
+    (positive, negative)
+}
+
+/// Build a cube slab whose bottom face lies on `plane` centered at
+/// `anchor`, extending distance `size` to the positive side of the plane
+/// normal (or the negative side when `positive` is false).
+fn half_space_slab(plane: &Plane, anchor: Point3, size: f64, positive: bool) -> BRepSolid {
+    // Pick a right-handed frame (u × v = slab +Z) so the cube's outward
+    // face orientations survive the mapping
+    let (u, v, w) = if positive {
+        (
+            *plane.x_dir.as_ref(),
+            *plane.y_dir.as_ref(),
+            *plane.normal_dir.as_ref(),
+        )
+    } else {
+        (
+            *plane.y_dir.as_ref(),
+            *plane.x_dir.as_ref(),
+            -*plane.normal_dir.as_ref(),
+        )
+    };
+    // Map the cube's bottom-face center (size/2, size/2, 0) to the anchor
+    let t = anchor - u * (size * 0.5) - v * (size * 0.5);
+    let transform = Transform::from_row_major(&[
+        u.x, v.x, w.x, t.x, u.y, v.y, w.y, t.y, u.z, v.z, w.z, t.z, 0.0, 0.0, 0.0, 1.0,
+    ]);
+
+    let mut slab = make_cube(size, size, size);
+    for (_, vertex) in &mut slab.topology.vertices {
+        vertex.point = transform.apply_point(&vertex.point);
+    }
+    slab.geometry.surfaces = slab
+        .geometry
+        .surfaces
+        .drain(..)
+        .map(|s| s.transform(&transform))
+        .collect();
+    slab
+}
+
+/// An empty solid: one empty outer shell and no geometry.
+fn empty_half() -> BRepSolid {
+    let mut topology = Topology::new();
+    let shell = topology.add_shell(Vec::new(), ShellType::Outer);
+    let solid_id = topology.add_solid(shell);
+    BRepSolid {
+        topology,
+        geometry: GeometryStore::new(),
+        solid_id,
+    }
 }
@@ -102,10 +102,7 @@ pub fn face_aabb(brep: &BRepSolid, face_id: FaceId) -> Aabb3 {
 
             // If AABB is nearly degenerate (diagonal < 1), this is likely a circular boundary
             if diag < 1.0 {
-                if let Some(plane) = surface
-                    .as_any()
-                    .downcast_ref::<vcad_kernel_geom::Plane>()
-                {
+                if let Some(plane) = surface.as_any().downcast_ref::<vcad_kernel_geom::Plane>() {
                     // The vertex is on the circular boundary. The radius is its distance
                     // from the plane's origin (circle center) projected onto the plane.
                     // Use the first vertex position to estimate the radius.
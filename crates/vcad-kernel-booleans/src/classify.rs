@@ -298,15 +298,20 @@ pub fn face_sample_point(brep: &BRepSolid, face_id: FaceId) -> Point3 {
                 let ref_dir = cyl.ref_dir.as_ref();
                 let y_dir = cyl.axis.as_ref().cross(ref_dir);
 
-                // Compute U angles for each boundary vertex
-                let mut u_angles: Vec<f64> = vertices
+                // Compute U angles for each boundary vertex (in loop order)
+                let loop_angles: Vec<f64> = vertices
                     .iter()
                     .map(|v| {
                         let d = *v - cyl.center;
                         let u = d.dot(&y_dir).atan2(d.dot(ref_dir));
-                        if u < 0.0 { u + 2.0 * PI } else { u }
+                        if u < 0.0 {
+                            u + 2.0 * PI
+                        } else {
+                            u
+                        }
                     })
                     .collect();
+                let mut u_angles = loop_angles.clone();
                 u_angles.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
                 u_angles.dedup_by(|a, b| (*a - *b).abs() < 0.01);
 
@@ -314,21 +319,85 @@ pub fn face_sample_point(brep: &BRepSolid, face_id: FaceId) -> Point3 {
                     let u_min = u_angles[0];
                     let u_max = u_angles[u_angles.len() - 1];
 
+                    // The arc traversed by the loop's bottom rim chain is the
+                    // face's exact angular extent (the bottom rim runs +U
+                    // regardless of orientation — reversal flips only the
+                    // orientation flag, never the winding). The min/max span
+                    // heuristic below can't tell a sub-face spanning more
+                    // than half the circumference from its complement, so
+                    // prefer the chain when one exists
+                    let bottom_arc = {
+                        let n = vertices.len();
+                        let v_params: Vec<f64> = vertices
+                            .iter()
+                            .map(|v| (*v - cyl.center).dot(cyl.axis.as_ref()))
+                            .collect();
+                        let vmin = v_params.iter().cloned().fold(f64::MAX, f64::min);
+                        let vmax = v_params.iter().cloned().fold(f64::MIN, f64::max);
+                        let v_tol = 1e-6 * (vmax - vmin).abs().max(1.0);
+                        let is_bottom = |i: usize| (v_params[i % n] - vmin).abs() < v_tol;
+                        let bottom_edge = |i: usize| is_bottom(i) && is_bottom(i + 1);
+                        let mut run = None;
+                        for i in 0..n {
+                            if bottom_edge(i) && !bottom_edge(i + n - 1) {
+                                run = Some(i);
+                                break;
+                            }
+                        }
+                        run.map(|start| {
+                            let mut end = start;
+                            while is_bottom(end + 2) && (end + 1 - start) < n {
+                                end += 1;
+                            }
+                            let s_ang = loop_angles[start];
+                            let e_ang = loop_angles[(end + 1) % n];
+                            let width = (e_ang - s_ang).rem_euclid(2.0 * PI);
+                            (s_ang, width)
+                        })
+                    };
+
                     // Check if face wraps around 2π (gap between max and min is small)
                     let direct_span = u_max - u_min;
                     let wrap_span = 2.0 * PI - direct_span;
 
-                    let u_mid = if wrap_span < direct_span {
+                    let u_mid = if let Some((lo, width)) = bottom_arc.filter(|&(_, w)| w > 0.01) {
+                        (lo + width / 2.0) % (2.0 * PI)
+                    } else if wrap_span < direct_span {
                         // Face wraps around: use midpoint of the wrap region
                         let mid = (u_max + u_min + 2.0 * PI) / 2.0;
-                        if mid >= 2.0 * PI { mid - 2.0 * PI } else { mid }
+                        if mid >= 2.0 * PI {
+                            mid - 2.0 * PI
+                        } else {
+                            mid
+                        }
                     } else {
                         // Normal face: use midpoint of direct span
                         (u_min + u_max) / 2.0
                     };
 
-                    // Compute V (height) at centroid
-                    let v_mid = (centroid - cyl.center).dot(cyl.axis.as_ref());
+                    // Compute V (height) from boundary vertices near u_mid.
+                    // On a band bounded by curved chains (a crossing-cylinder
+                    // split, say) the all-vertex centroid can sit outside the
+                    // band at u_mid; vertices at that angle bracket the local
+                    // V range, so their mean stays inside the face
+                    let near_v: Vec<f64> = vertices
+                        .iter()
+                        .filter(|v| {
+                            let d = **v - cyl.center;
+                            let mut u = d.dot(&y_dir).atan2(d.dot(ref_dir));
+                            if u < 0.0 {
+                                u += 2.0 * PI;
+                            }
+                            let du = (u - u_mid).abs();
+                            du.min(2.0 * PI - du) < 0.3
+                        })
+                        .map(|v| (*v - cyl.center).dot(cyl.axis.as_ref()))
+                        .collect();
+                    let v_mid = if near_v.is_empty() {
+                        (centroid - cyl.center).dot(cyl.axis.as_ref())
+                    } else {
+                        near_v.iter().sum::<f64>() / near_v.len() as f64
+                    };
 
                     // Evaluate point on cylinder surface
                     let sin_u = u_mid.sin();
@@ -353,10 +422,29 @@ pub fn face_sample_point(brep: &BRepSolid, face_id: FaceId) -> Point3 {
 ///
 /// The `other_mesh` is the tessellated mesh of the other solid, used
 /// for point-in-solid testing.
+///
+/// Reads the thread-local [`vcad_kernel_math::geometry_tolerance`]; call
+/// [`classify_face_with_tol`] with an explicit tolerance from code that may
+/// run on a worker thread.
 pub fn classify_face(
     brep: &BRepSolid,
     face_id: FaceId,
     other_mesh: &TriangleMesh,
+) -> FaceClassification {
+    classify_face_with_tol(
+        brep,
+        face_id,
+        other_mesh,
+        vcad_kernel_math::geometry_tolerance(),
+    )
+}
+
+/// Like [`classify_face`], with an explicit probe-offset tolerance.
+pub fn classify_face_with_tol(
+    brep: &BRepSolid,
+    face_id: FaceId,
+    other_mesh: &TriangleMesh,
+    tol: f64,
 ) -> FaceClassification {
     let sample = face_sample_point(brep, face_id);
 
@@ -400,31 +488,59 @@ pub fn classify_face(
         }
     };
 
-    // Test the sample point offset slightly inward (negative normal)
-    let eps = 1e-4;
+    // Probe on both sides of the face. A coarse module tolerance pushes the
+    // probes far enough out that near-coincident faces still classify
+    // robustly. When the other solid's boundary separates the two probes,
+    // this face lies ON that boundary: material on the inward side only
+    // means the outward normals agree (each solid is on the far side of the
+    // shared wall from the other's normal), material on the outward side
+    // only means they oppose.
+    let eps = tol.max(1e-4);
     let inward_point = sample - eps * oriented_normal;
+    let outward_point = sample + eps * oriented_normal;
 
-    let is_inside = point_in_mesh(&inward_point, other_mesh);
+    let inward_inside = point_in_mesh(&inward_point, other_mesh);
+    let outward_inside = point_in_mesh(&outward_point, other_mesh);
 
-    if is_inside {
-        FaceClassification::Inside
-    } else {
-        FaceClassification::Outside
+    match (inward_inside, outward_inside) {
+        (true, true) => FaceClassification::Inside,
+        (false, false) => FaceClassification::Outside,
+        (true, false) => FaceClassification::OnSame,
+        (false, true) => FaceClassification::OnOpposite,
     }
 }
 
 /// Classify all faces of a solid relative to another solid.
+///
+/// Reads the thread-local [`vcad_kernel_math::geometry_tolerance`]; the
+/// pipeline calls [`classify_all_faces_with_tol`] with the tolerance captured
+/// in [`BooleanParams::geom_tol`](crate::BooleanParams) instead.
 pub fn classify_all_faces(
     brep: &BRepSolid,
     other: &BRepSolid,
     segments: u32,
+) -> Vec<(FaceId, FaceClassification)> {
+    classify_all_faces_with_tol(
+        brep,
+        other,
+        segments,
+        vcad_kernel_math::geometry_tolerance(),
+    )
+}
+
+/// Like [`classify_all_faces`], with an explicit probe-offset tolerance.
+pub fn classify_all_faces_with_tol(
+    brep: &BRepSolid,
+    other: &BRepSolid,
+    segments: u32,
+    tol: f64,
 ) -> Vec<(FaceId, FaceClassification)> {
     let other_mesh = tessellate_brep(other, segments);
     brep.topology
         .faces
         .iter()
         .map(|(face_id, _)| {
-            let class = classify_face(brep, face_id, &other_mesh);
+            let class = classify_face_with_tol(brep, face_id, &other_mesh, tol);
             (face_id, class)
         })
         .collect()
@@ -434,6 +550,12 @@ pub fn classify_all_faces(
 ///
 /// Returns `(faces_from_a, faces_from_b, reverse_b)`.
 /// `reverse_b` indicates that B's kept faces should have their orientation flipped.
+///
+/// For [`BooleanOp::SymmetricDifference`] this lists only the faces kept with
+/// their original orientation (each solid's outside faces, plus A's boundary
+/// faces). The inside faces of each solid also appear in the XOR boundary but
+/// re-oriented, which a single `reverse_b` flag cannot express — the pipeline
+/// therefore sews XOR from two difference selections.
 pub fn select_faces(
     op: BooleanOp,
     classes_a: &[(FaceId, FaceClassification)],
@@ -454,6 +576,12 @@ pub fn select_faces(
             BooleanOp::Intersection => {
                 matches!(c, FaceClassification::Inside | FaceClassification::OnSame)
             }
+            BooleanOp::SymmetricDifference => {
+                matches!(
+                    c,
+                    FaceClassification::Outside | FaceClassification::OnOpposite
+                )
+            }
         })
         .map(|(f, _)| *f)
         .collect();
@@ -464,6 +592,7 @@ pub fn select_faces(
             BooleanOp::Union => matches!(c, FaceClassification::Outside),
             BooleanOp::Difference => matches!(c, FaceClassification::Inside),
             BooleanOp::Intersection => matches!(c, FaceClassification::Inside),
+            BooleanOp::SymmetricDifference => matches!(c, FaceClassification::Outside),
         })
         .map(|(f, _)| *f)
         .collect();
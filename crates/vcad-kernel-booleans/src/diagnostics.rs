@@ -0,0 +1,411 @@
+//! Post-boolean result validation.
+//!
+//! Reusable versions of the checks the test-suite applies to boolean
+//! results: mesh closure, triangle facing, coplanar face overlap, and
+//! stray geometry outside the topology bounds. Lets callers validate a
+//! result programmatically instead of eyeballing a render.
+
+use vcad_kernel_geom::{Plane, SurfaceKind};
+use vcad_kernel_math::{Point3, Vec3};
+use vcad_kernel_primitives::BRepSolid;
+use vcad_kernel_tessellate::{tessellate_brep, TriangleMesh};
+use vcad_kernel_topo::Orientation;
+
+use crate::mesh::point_in_mesh;
+
+/// Segment count used when tessellating a solid for analysis.
+const ANALYSIS_SEGMENTS: u32 = 32;
+
+/// Findings from analysing a boolean result solid.
+///
+/// Every field counts problems, so a fully healthy result is all zeros —
+/// see [`ResultDiagnostics::is_clean`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResultDiagnostics {
+    /// Half-edges in a loop with no twin — the shell has an open
+    /// boundary there.
+    pub open_boundary_half_edges: usize,
+    /// Half-edges that belong to a loop but lost their twin pairing
+    /// during sewing.
+    pub orphan_half_edges: usize,
+    /// Triangles whose normal points into the solid instead of out.
+    pub wrong_facing_triangles: usize,
+    /// Pairs of distinct same-plane faces whose footprints overlap —
+    /// duplicated or zero-thickness geometry left by a coplanar boolean.
+    pub overlapping_coplanar_faces: usize,
+    /// Triangles with a vertex outside the solid's bounding box —
+    /// tessellation produced geometry the topology does not account for.
+    pub triangles_outside_bounds: usize,
+}
+
+impl ResultDiagnostics {
+    /// `true` when every check came back clean.
+    pub fn is_clean(&self) -> bool {
+        self.open_boundary_half_edges == 0
+            && self.orphan_half_edges == 0
+            && self.wrong_facing_triangles == 0
+            && self.overlapping_coplanar_faces == 0
+            && self.triangles_outside_bounds == 0
+    }
+}
+
+/// Analyze a boolean result solid for the defects the pipeline can
+/// produce when sewing or classification goes wrong.
+///
+/// Runs four families of checks:
+///
+/// 1. **Open boundaries** — every half-edge in a loop must have a twin
+///    and keep its edge record; anything less is an unsewn seam.
+/// 2. **Facing** — each triangle's outward side must leave the solid and
+///    its inward side must enter it (probed with [`point_in_mesh`]).
+/// 3. **Coplanar overlap** — no two distinct planar faces may occupy the
+///    same region of the same plane.
+/// 4. **Bounds** — no triangle may extend past the solid's bounding box
+///    (from [`crate::bbox::solid_aabb`], which accounts for curved faces
+///    bulging beyond their boundary vertices).
+///
+/// The checks tessellate the solid and ray-cast per triangle, so this is
+/// a debugging aid, not something to run per frame.
+pub fn analyze_result(brep: &BRepSolid) -> ResultDiagnostics {
+    let mesh = tessellate_brep(brep, ANALYSIS_SEGMENTS);
+    ResultDiagnostics {
+        open_boundary_half_edges: count_open_boundary_half_edges(brep),
+        orphan_half_edges: count_orphan_half_edges(brep),
+        wrong_facing_triangles: count_wrong_facing_triangles(&mesh),
+        overlapping_coplanar_faces: count_overlapping_coplanar_faces(brep),
+        triangles_outside_bounds: count_triangles_outside_bounds(brep, &mesh),
+    }
+}
+
+/// Count half-edges that sit in a loop but have no twin.
+fn count_open_boundary_half_edges(brep: &BRepSolid) -> usize {
+    brep.topology
+        .half_edges
+        .iter()
+        .filter(|(_, he)| he.loop_id.is_some() && he.twin.is_none())
+        .count()
+}
+
+/// Count half-edges that sit in a loop but have no edge record.
+fn count_orphan_half_edges(brep: &BRepSolid) -> usize {
+    brep.topology
+        .half_edges
+        .iter()
+        .filter(|(_, he)| he.loop_id.is_some() && he.edge.is_none())
+        .count()
+}
+
+/// Count triangles whose outward side is inside the solid (or whose
+/// inward side is outside) — i.e. the winding faces the wrong way.
+fn count_wrong_facing_triangles(mesh: &TriangleMesh) -> usize {
+    // Probe offset scaled to the mesh so thin features don't false-positive
+    let (bb_min, bb_max) = mesh_bounds(mesh);
+    let eps = (bb_max - bb_min).norm() * 1e-4;
+    if eps == 0.0 {
+        return 0;
+    }
+
+    let mut wrong = 0;
+    for tri in mesh.indices.chunks(3) {
+        let p = |idx: u32| {
+            let i = idx as usize * 3;
+            Point3::new(
+                mesh.vertices[i] as f64,
+                mesh.vertices[i + 1] as f64,
+                mesh.vertices[i + 2] as f64,
+            )
+        };
+        let (v0, v1, v2) = (p(tri[0]), p(tri[1]), p(tri[2]));
+        let normal = (v1 - v0).cross(&(v2 - v0));
+        let len = normal.norm();
+        if len < 1e-12 {
+            continue; // degenerate
+        }
+        let normal = normal / len;
+        let centroid = Point3::new(
+            (v0.x + v1.x + v2.x) / 3.0,
+            (v0.y + v1.y + v2.y) / 3.0,
+            (v0.z + v1.z + v2.z) / 3.0,
+        );
+        // An outward-facing triangle exits the solid along its normal and
+        // enters it against the normal; require both probes to disagree
+        // before flagging so edge-grazing rays don't false-positive
+        let outside_front = !point_in_mesh(&(centroid + normal * eps), mesh);
+        let inside_back = point_in_mesh(&(centroid - normal * eps), mesh);
+        if !outside_front && !inside_back {
+            wrong += 1;
+        }
+    }
+    wrong
+}
+
+/// Count pairs of distinct planar faces lying in the same plane whose
+/// interiors genuinely overlap (edge-to-edge contact is fine).
+fn count_overlapping_coplanar_faces(brep: &BRepSolid) -> usize {
+    const TOL: f64 = 1e-6;
+
+    // Effective plane (point, outward normal), loop AABB, and loop polygon
+    // per planar face
+    let mut planes: Vec<(Point3, Vec3, Point3, Point3, Vec<Point3>)> = Vec::new();
+    for (_, face) in &brep.topology.faces {
+        let surface = &brep.geometry.surfaces[face.surface_index];
+        if surface.surface_type() != SurfaceKind::Plane {
+            continue;
+        }
+        let Some(plane) = surface.as_any().downcast_ref::<Plane>() else {
+            continue;
+        };
+        let mut normal = *plane.normal_dir.as_ref();
+        if face.orientation == Orientation::Reversed {
+            normal = -normal;
+        }
+
+        let mut min = Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        let mut max = Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+        let mut poly = Vec::new();
+        for v in brep.topology.loop_vertices(face.outer_loop) {
+            let p = brep.topology.vertices[v].point;
+            min = Point3::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z));
+            max = Point3::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z));
+            poly.push(p);
+        }
+        if min.x.is_finite() {
+            planes.push((plane.origin, normal, min, max, poly));
+        }
+    }
+
+    let mut overlapping = 0;
+    for i in 0..planes.len() {
+        for j in (i + 1)..planes.len() {
+            let (origin_a, normal_a, min_a, max_a, poly_a) = &planes[i];
+            let (origin_b, normal_b, min_b, max_b, poly_b) = &planes[j];
+
+            // Same plane: parallel normals and no offset along them
+            if normal_a.cross(normal_b).norm() > TOL {
+                continue;
+            }
+            if (origin_b - origin_a).dot(normal_a).abs() > TOL {
+                continue;
+            }
+
+            // Overlap must have real extent in at least two axes — faces
+            // that only share an edge or corner touch degenerately
+            let mut wide_axes = 0;
+            let mut separated = false;
+            for axis in 0..3 {
+                let lo = min_a[axis].max(min_b[axis]);
+                let hi = max_a[axis].min(max_b[axis]);
+                if hi - lo > TOL {
+                    wide_axes += 1;
+                } else if hi - lo < -TOL {
+                    separated = true;
+                }
+            }
+            if separated || wide_axes < 2 {
+                continue;
+            }
+
+            // AABBs overlap, but that is a coarse proxy: a lens split off a
+            // cap sits inside the bounding box of the concave remainder
+            // without sharing any area. Confirm with interior probes
+            if polygons_share_area(poly_a, poly_b, normal_a)
+                || polygons_share_area(poly_b, poly_a, normal_a)
+            {
+                overlapping += 1;
+            }
+        }
+    }
+    overlapping
+}
+
+/// True if a point just inside `poly_a`'s boundary lies strictly inside
+/// `poly_b`. Probes are edge midpoints nudged inward (by winding), so
+/// polygons that only share boundary edges are not reported.
+fn polygons_share_area(poly_a: &[Point3], poly_b: &[Point3], normal: &Vec3) -> bool {
+    use crate::trim::point_in_polygon;
+    use vcad_kernel_math::Point2;
+
+    let n_a = poly_a.len();
+    if n_a < 3 || poly_b.len() < 3 {
+        return false;
+    }
+
+    // 2D frame in the shared plane
+    let seed = if normal.x.abs() < 0.9 {
+        Vec3::new(1.0, 0.0, 0.0)
+    } else {
+        Vec3::new(0.0, 1.0, 0.0)
+    };
+    let u_axis = normal.cross(&seed).normalize();
+    let v_axis = normal.cross(&u_axis);
+    let origin = poly_a[0];
+    let to_2d = |p: &Point3| {
+        let d = *p - origin;
+        Point2::new(d.dot(&u_axis), d.dot(&v_axis))
+    };
+
+    let a_2d: Vec<Point2> = poly_a.iter().map(&to_2d).collect();
+    let b_2d: Vec<Point2> = poly_b.iter().map(&to_2d).collect();
+
+    // Signed area fixes the inward direction for the probe offset
+    let mut area2 = 0.0;
+    for k in 0..n_a {
+        let p = a_2d[k];
+        let q = a_2d[(k + 1) % n_a];
+        area2 += p.x * q.y - q.x * p.y;
+    }
+    let winding = if area2 >= 0.0 { 1.0 } else { -1.0 };
+
+    let diag = {
+        let d = a_2d
+            .iter()
+            .fold((f64::MAX, f64::MIN, f64::MAX, f64::MIN), |acc, p| {
+                (
+                    acc.0.min(p.x),
+                    acc.1.max(p.x),
+                    acc.2.min(p.y),
+                    acc.3.max(p.y),
+                )
+            });
+        ((d.1 - d.0).powi(2) + (d.3 - d.2).powi(2)).sqrt()
+    };
+    let eps = diag * 1e-5;
+
+    for k in 0..n_a {
+        let p = a_2d[k];
+        let q = a_2d[(k + 1) % n_a];
+        let edge = Point2::new(q.x - p.x, q.y - p.y);
+        let len = (edge.x * edge.x + edge.y * edge.y).sqrt();
+        if len < eps {
+            continue;
+        }
+        let inward = Point2::new(-edge.y / len * winding, edge.x / len * winding);
+        let probe = Point2::new(
+            (p.x + q.x) / 2.0 + inward.x * eps,
+            (p.y + q.y) / 2.0 + inward.y * eps,
+        );
+        if point_in_polygon(&probe, &a_2d) && point_in_polygon(&probe, &b_2d) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Count triangles with a vertex outside the solid's AABB (plus a
+/// small tolerance).
+fn count_triangles_outside_bounds(brep: &BRepSolid, mesh: &TriangleMesh) -> usize {
+    let aabb = crate::bbox::solid_aabb(brep);
+    let (min, max) = (aabb.min, aabb.max);
+    if !min.x.is_finite() {
+        return 0;
+    }
+    let tol = (max - min).norm() * 1e-6 + 1e-9;
+
+    mesh.indices
+        .chunks(3)
+        .filter(|tri| {
+            tri.iter().any(|&idx| {
+                let i = idx as usize * 3;
+                let x = mesh.vertices[i] as f64;
+                let y = mesh.vertices[i + 1] as f64;
+                let z = mesh.vertices[i + 2] as f64;
+                x < min.x - tol
+                    || x > max.x + tol
+                    || y < min.y - tol
+                    || y > max.y + tol
+                    || z < min.z - tol
+                    || z > max.z + tol
+            })
+        })
+        .count()
+}
+
+/// Bounding box of all mesh vertices.
+fn mesh_bounds(mesh: &TriangleMesh) -> (Point3, Point3) {
+    let mut min = Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+    let mut max = Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for i in 0..mesh.num_vertices() {
+        let x = mesh.vertices[i * 3] as f64;
+        let y = mesh.vertices[i * 3 + 1] as f64;
+        let z = mesh.vertices[i * 3 + 2] as f64;
+        min = Point3::new(min.x.min(x), min.y.min(y), min.z.min(z));
+        max = Point3::new(max.x.max(x), max.y.max(y), max.z.max(z));
+    }
+    if !min.x.is_finite() {
+        return (Point3::origin(), Point3::origin());
+    }
+    (min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{boolean_op, BooleanOp, BooleanResult};
+    use vcad_kernel_math::Transform;
+    use vcad_kernel_primitives::{make_cube, make_cylinder};
+
+    #[test]
+    fn test_analyze_result_passes_clean_boolean() {
+        // Offset cylinder union: a result the pipeline is known to sew well
+        let a = make_cylinder(5.0, 10.0, 32);
+        let mut b = make_cylinder(5.0, 10.0, 32);
+        let t = Transform::translation(4.0, 0.0, 0.0);
+        for (_, v) in &mut b.topology.vertices {
+            v.point = t.apply_point(&v.point);
+        }
+        b.geometry.surfaces = b
+            .geometry
+            .surfaces
+            .drain(..)
+            .map(|s| s.transform(&t))
+            .collect();
+
+        let result = match boolean_op(&a, &b, BooleanOp::Union, 32) {
+            BooleanResult::BRep(b) => *b,
+            BooleanResult::Mesh(_) => panic!("expected BRep result"),
+        };
+
+        let diag = analyze_result(&result);
+        assert!(diag.is_clean(), "clean boolean flagged: {diag:?}");
+    }
+
+    #[test]
+    fn test_analyze_result_flags_known_bad() {
+        // Flip one face of a cube: its triangles face into the solid and
+        // their windings no longer match the neighbours
+        let mut flipped = make_cube(10.0, 10.0, 10.0);
+        let face_id = flipped.topology.faces.keys().next().unwrap();
+        let face = &mut flipped.topology.faces[face_id];
+        face.orientation = match face.orientation {
+            Orientation::Forward => Orientation::Reversed,
+            Orientation::Reversed => Orientation::Forward,
+        };
+
+        let diag = analyze_result(&flipped);
+        assert!(!diag.is_clean());
+        assert!(diag.wrong_facing_triangles > 0, "{diag:?}");
+
+        // Unsew one edge: both half-edges lose their twin
+        let mut unsewn = make_cube(10.0, 10.0, 10.0);
+        let edge_id = unsewn.topology.edges.keys().next().unwrap();
+        let he = unsewn.topology.edges[edge_id].half_edge;
+        let twin = unsewn.topology.half_edges[he].twin.unwrap();
+        unsewn.topology.half_edges[he].twin = None;
+        unsewn.topology.half_edges[twin].twin = None;
+        let diag = analyze_result(&unsewn);
+        assert_eq!(diag.open_boundary_half_edges, 2);
+        assert!(!diag.is_clean());
+
+        // Strip an edge record: the half-edges become orphans
+        let mut torn = make_cube(10.0, 10.0, 10.0);
+        let edge_id = torn.topology.edges.keys().next().unwrap();
+        let he = torn.topology.edges[edge_id].half_edge;
+        let twin = torn.topology.half_edges[he].twin.unwrap();
+        torn.topology.half_edges[he].edge = None;
+        torn.topology.half_edges[twin].edge = None;
+        torn.topology.edges.remove(edge_id);
+        let diag = analyze_result(&torn);
+        assert_eq!(diag.orphan_half_edges, 2);
+        assert!(!diag.is_clean());
+    }
+}
@@ -2,7 +2,8 @@
 
 //! CSG boolean operations on B-rep solids for the vcad kernel.
 //!
-//! Implements union, difference, and intersection of B-rep solids.
+//! Implements union, difference, intersection, and symmetric difference of
+//! B-rep solids.
 //!
 //! The boolean pipeline has 4 stages:
 //! 1. **AABB filter** — broadphase to find candidate face pairs
@@ -17,22 +18,31 @@
 mod api;
 pub mod bbox;
 pub mod classify;
+pub mod diagnostics;
 pub mod mesh;
 mod pipeline;
-mod repair;
+pub mod repair;
 pub mod sew;
 pub mod split;
 pub mod ssi;
 pub mod trim;
 
 // Re-export public API
-pub use api::{boolean_op, BooleanOp, BooleanResult};
+pub use api::{
+    boolean_op, boolean_op_adaptive, boolean_op_debug, boolean_op_tagged, boolean_op_with_limit,
+    boolean_op_with_params, imprint, split_by_plane, try_boolean_op, BooleanError, BooleanOp,
+    BooleanParams, BooleanResult, BooleanTrace, FaceProvenance, FaceSplit, SolidSource, SsiCurve,
+    DEFAULT_MAX_SPLIT_ITERATIONS,
+};
+pub use diagnostics::{analyze_result, ResultDiagnostics};
 pub use mesh::point_in_mesh;
+pub use repair::{heal_solid, RepairParams, RepairStats};
+pub use sew::{check_manifold, ManifoldReport};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use vcad_kernel_math::{Point3, Transform};
+    use vcad_kernel_math::{Point3, Transform, Vec3};
     use vcad_kernel_primitives::{make_cube, BRepSolid};
     use vcad_kernel_tessellate::{tessellate_brep, TriangleMesh};
 
@@ -127,6 +137,254 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_difference_countersink_cone_in_plate() {
+        use vcad_kernel_geom::SurfaceKind;
+        use vcad_kernel_primitives::{make_cone, make_cylinder};
+
+        // Plate with a through hole at its center
+        let plate = make_cube(20.0, 20.0, 5.0);
+        let mut hole = make_cylinder(3.0, 20.0, 32);
+        translate_brep(&mut hole, 10.0, 10.0, -5.0);
+        let plate_with_hole = boolean_op(&plate, &hole, BooleanOp::Difference, 32)
+            .into_brep()
+            .expect("plate with hole should stay a B-rep");
+
+        // Countersink: widening cone coaxial with the hole, opening to r=3
+        // at z=4 inside the plate (plate top is z=5, so no coplanar faces)
+        let mut countersink = make_cone(1.0, 7.0, 6.0, 32);
+        translate_brep(&mut countersink, 10.0, 10.0, 2.0);
+        let result = boolean_op(&plate_with_hole, &countersink, BooleanOp::Difference, 32);
+
+        // The conical transition between hole and countersink must survive
+        // as a cone face in the result
+        let brep = result
+            .as_brep()
+            .expect("countersink result should be a B-rep");
+        let has_cone_face = brep.topology.faces.values().any(|f| {
+            brep.geometry
+                .surfaces
+                .get(f.surface_index)
+                .is_some_and(|s| s.surface_type() == SurfaceKind::Cone)
+        });
+        assert!(has_cone_face, "Expected a conical transition face");
+
+        // Volume: 20*20*5 plate minus hole (~141) minus countersink bite
+        let volume = compute_mesh_volume(&result.to_mesh(32));
+        assert!(
+            volume > 1500.0 && volume < 1900.0,
+            "Expected plausible countersunk-plate volume, got {}",
+            volume
+        );
+    }
+
+    #[test]
+    fn test_union_perpendicular_cylinders() {
+        use vcad_kernel_primitives::make_cylinder;
+
+        // Two r=5 cylinders with perpendicular axes crossing at (0,0,10):
+        // A along Z, B along X (rotated about Y, then recentered)
+        let a = make_cylinder(5.0, 20.0, 32);
+        let mut b = make_cylinder(5.0, 20.0, 32);
+        let rot = Transform::from_row_major(&[
+            0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ]);
+        for (_, vertex) in &mut b.topology.vertices {
+            vertex.point = rot.apply_point(&vertex.point);
+        }
+        b.geometry.surfaces = b
+            .geometry
+            .surfaces
+            .drain(..)
+            .map(|s| s.transform(&rot))
+            .collect();
+        translate_brep(&mut b, -10.0, 0.0, 10.0);
+
+        let result = boolean_op(&a, &b, BooleanOp::Union, 32);
+        let volume = compute_mesh_volume(&result.to_mesh(32));
+
+        // Each cylinder is π·5²·20 ≈ 1570.8; the shared Steinmetz lobe
+        // (16r³/3 ≈ 666.7) must only be counted once
+        let single = std::f64::consts::PI * 25.0 * 20.0;
+        let lobe = 16.0 * 125.0 / 3.0;
+        assert!(
+            volume < 2.0 * single - 0.5 * lobe,
+            "union volume {} should be well below the summed volumes {}",
+            volume,
+            2.0 * single
+        );
+        assert!(
+            volume > 2.0 * single - 1.5 * lobe,
+            "union volume {} should not lose more than the shared lobe",
+            volume
+        );
+    }
+
+    #[test]
+    fn test_boolean_op_debug_traces_cube_minus_cylinder() {
+        use vcad_kernel_geom::SurfaceKind;
+        use vcad_kernel_primitives::make_cylinder;
+
+        // Through hole: the cylinder wall crosses the plate's top and
+        // bottom planes
+        let plate = make_cube(20.0, 20.0, 5.0);
+        let mut hole = make_cylinder(3.0, 20.0, 32);
+        translate_brep(&mut hole, 10.0, 10.0, -5.0);
+
+        let trace = boolean_op_debug(&plate, &hole, BooleanOp::Difference, 32);
+
+        assert!(!trace.candidate_pairs.is_empty());
+
+        // Wall × top plane and wall × bottom plane are coaxial circles of
+        // the hole radius
+        let circles: Vec<_> = trace
+            .ssi_curves
+            .iter()
+            .filter_map(|s| match &s.curve {
+                ssi::IntersectionCurve::Circle(c) => Some(c),
+                _ => None,
+            })
+            .collect();
+        assert!(
+            circles.len() >= 2,
+            "expected at least 2 circle SSI curves, got {}",
+            circles.len()
+        );
+        for circle in &circles {
+            assert!((circle.radius - 3.0).abs() < 1e-9);
+        }
+
+        // Those circles split the cylinder's lateral face into sub-faces
+        // (the working copies share face ids with the inputs)
+        let lateral = hole
+            .topology
+            .faces
+            .iter()
+            .find(|(_, f)| {
+                hole.geometry
+                    .surfaces
+                    .get(f.surface_index)
+                    .is_some_and(|s| s.surface_type() == SurfaceKind::Cylinder)
+            })
+            .map(|(id, _)| id)
+            .expect("cylinder should have a lateral face");
+        let split = trace
+            .splits_b
+            .iter()
+            .find(|s| s.input_face == lateral)
+            .expect("expected the cylinder lateral face to be split");
+        assert!(
+            split.sub_faces.len() >= 2,
+            "expected >= 2 sub-faces, got {:?}",
+            split.sub_faces
+        );
+
+        // Classification and selection stages were recorded too
+        assert!(!trace.classifications_a.is_empty());
+        assert!(!trace.classifications_b.is_empty());
+        assert!(!trace.selected_a.is_empty());
+        assert!(!trace.selected_b.is_empty());
+        assert!(trace.reverse_b, "difference sews kept B faces reversed");
+    }
+
+    #[test]
+    fn test_intersection_interlocking_solids_is_empty() {
+        // Two L-shaped solids whose AABBs overlap but whose material never
+        // touches: A is a bottom slab with a column on the left, B a top
+        // slab with a column on the right
+        let slab_a = make_cube(10.0, 10.0, 2.0);
+        let mut col_a = make_cube(2.0, 8.0, 6.0);
+        translate_brep(&mut col_a, 0.5, 1.0, 1.0);
+        let l_a = boolean_op(&slab_a, &col_a, BooleanOp::Union, 32)
+            .into_brep()
+            .expect("L-shape A should be a B-rep");
+
+        let mut slab_b = make_cube(10.0, 10.0, 2.0);
+        translate_brep(&mut slab_b, 0.0, 0.0, 8.0);
+        let mut col_b = make_cube(2.0, 8.0, 6.0);
+        translate_brep(&mut col_b, 7.5, 1.0, 3.0);
+        let l_b = boolean_op(&slab_b, &col_b, BooleanOp::Union, 32)
+            .into_brep()
+            .expect("L-shape B should be a B-rep");
+
+        let result = boolean_op(&l_a, &l_b, BooleanOp::Intersection, 32);
+        let volume = compute_mesh_volume(&result.to_mesh(32));
+        assert!(
+            volume.abs() < 1e-9,
+            "Expected empty intersection, got volume {}",
+            volume
+        );
+    }
+
+    #[test]
+    fn test_difference_enclosed_sphere_creates_void_shell() {
+        use vcad_kernel_primitives::make_sphere;
+        use vcad_kernel_topo::ShellType;
+
+        // Sphere fully inside the cube — no surface intersections at all
+        let cube = make_cube(10.0, 10.0, 10.0);
+        let mut sphere = make_sphere(2.0, 32);
+        translate_brep(&mut sphere, 5.0, 5.0, 5.0);
+
+        let result = boolean_op(&cube, &sphere, BooleanOp::Difference, 32);
+        let brep = result
+            .into_brep()
+            .expect("enclosed difference should stay a B-rep");
+
+        // Two shells: the outer cube boundary and the spherical cavity
+        let solid = &brep.topology.solids[brep.solid_id];
+        assert_eq!(
+            solid.void_shells.len(),
+            1,
+            "Expected exactly one void shell for the cavity"
+        );
+        let outer = &brep.topology.shells[solid.outer_shell];
+        assert_eq!(
+            outer.faces.len(),
+            6,
+            "Outer shell should be the 6 cube faces"
+        );
+        let cavity = &brep.topology.shells[solid.void_shells[0]];
+        assert_eq!(cavity.shell_type, ShellType::Void);
+        assert_eq!(
+            cavity.faces.len(),
+            1,
+            "Cavity should be the single sphere face"
+        );
+
+        // The tessellation includes the cavity walls, so the signed volume is
+        // cube minus the (tessellated, slightly under-full) sphere
+        let mesh = tessellate_brep(&brep, 32);
+        let volume = compute_mesh_volume(&mesh);
+        let sphere_vol = 4.0 / 3.0 * std::f64::consts::PI * 8.0;
+        assert!(
+            volume > 1000.0 - sphere_vol - 2.0 && volume < 1000.0 - sphere_vol + 4.0,
+            "Expected volume near {}, got {}",
+            1000.0 - sphere_vol,
+            volume
+        );
+    }
+
+    #[test]
+    fn test_boolean_timeout_with_tiny_iteration_budget() {
+        // Two overlapping cubes need far more than one face split, so a
+        // one-iteration budget must trip the guard instead of completing
+        let a = make_cube(10.0, 10.0, 10.0);
+        let mut b = make_cube(10.0, 10.0, 10.0);
+        translate_brep(&mut b, 5.0, 5.0, 5.0);
+
+        let result = boolean_op_with_limit(&a, &b, BooleanOp::Union, 32, 1);
+        assert_eq!(
+            result.err(),
+            Some(BooleanError::Timeout { max_iterations: 1 })
+        );
+
+        // A generous budget completes normally on the same input
+        let ok = boolean_op_with_limit(&a, &b, BooleanOp::Union, 32, DEFAULT_MAX_SPLIT_ITERATIONS)
+            .expect("union should converge");
+        assert!(compute_mesh_volume(&ok.to_mesh(32)) > 1000.0);
+    }
+
     #[test]
     fn test_point_in_cube_mesh() {
         let brep = make_cube(10.0, 10.0, 10.0);
@@ -154,6 +412,202 @@ mod tests {
         assert!(mesh.num_triangles() > 0);
     }
 
+    #[test]
+    fn test_try_boolean_op_returns_closed_brep() {
+        use vcad_kernel_primitives::make_cylinder;
+
+        // Offset cylinder union: a result the pipeline is known to sew
+        // closed
+        let a = make_cylinder(5.0, 10.0, 32);
+        let mut b = make_cylinder(5.0, 10.0, 32);
+        translate_brep(&mut b, 4.0, 0.0, 0.0);
+
+        let solid = try_boolean_op(&a, &b, BooleanOp::Union, 32).expect("union should succeed");
+        let diag = analyze_result(&solid);
+        assert!(diag.is_clean(), "strict union flagged: {diag:?}");
+    }
+
+    #[test]
+    fn test_try_boolean_op_empty_intersection() {
+        let a = make_cube(10.0, 10.0, 10.0);
+        let mut b = make_cube(10.0, 10.0, 10.0);
+        translate_brep(&mut b, 20.0, 0.0, 0.0);
+
+        // Disjoint solids: intersection is genuinely empty, with no B-rep
+        // to return — the permissive API degrades to an empty mesh instead
+        let err = try_boolean_op(&a, &b, BooleanOp::Intersection, 16).unwrap_err();
+        assert_eq!(err, BooleanError::EmptyResult);
+
+        let result = boolean_op(&a, &b, BooleanOp::Intersection, 16);
+        assert!(result.as_brep().is_none());
+    }
+
+    #[test]
+    fn test_boolean_params_weld_tolerance_range() {
+        use vcad_kernel_primitives::make_cylinder;
+
+        // Plate with a through-hole at both a very fine and a coarse weld
+        // tolerance: both should close into a watertight shell with the
+        // expected volume
+        let plate = make_cube(20.0, 20.0, 5.0);
+        let mut hole = make_cylinder(3.0, 20.0, 32);
+        translate_brep(&mut hole, 10.0, 10.0, -5.0);
+
+        // 32-gon hole area is slightly under π·r²
+        let polygon_area = 0.5 * 32.0 * 9.0 * (2.0 * std::f64::consts::PI / 32.0).sin();
+        let expected = 20.0 * 20.0 * 5.0 - polygon_area * 5.0;
+
+        for weld_tol in [1e-9, 1e-3] {
+            let params = BooleanParams {
+                weld_tol,
+                ..BooleanParams::default()
+            };
+            let result = boolean_op_with_params(&plate, &hole, BooleanOp::Difference, &params);
+            let brep = result
+                .as_brep()
+                .expect("difference should be a B-rep")
+                .clone();
+
+            let diag = analyze_result(&brep);
+            assert_eq!(
+                diag.open_boundary_half_edges, 0,
+                "weld_tol={weld_tol}: result should be watertight"
+            );
+
+            let mesh = result.to_mesh(32);
+            let volume = compute_mesh_volume(&mesh);
+            assert!(
+                (volume - expected).abs() < expected * 0.02,
+                "weld_tol={weld_tol}: expected volume ~{expected:.1}, got {volume:.1}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_geometry_tolerance_welds_near_coincident_vertices() {
+        // Two cubes 1e-3 apart: under the default 1e-6 tolerance the facing
+        // walls keep their own vertices; a coarse module tolerance welds
+        // each near-coincident pair during sewing
+        let a = make_cube(10.0, 10.0, 10.0);
+        let mut b = make_cube(10.0, 10.0, 10.0);
+        translate_brep(&mut b, 10.001, 0.0, 0.0);
+
+        let fine = boolean_op(&a, &b, BooleanOp::Union, 4);
+        let fine_vertices = fine
+            .as_brep()
+            .expect("union should be a B-rep")
+            .topology
+            .vertices
+            .len();
+
+        vcad_kernel_math::set_geometry_tolerance(1e-2);
+        let coarse = boolean_op(&a, &b, BooleanOp::Union, 4);
+        let coarse_vertices = coarse
+            .as_brep()
+            .expect("union should be a B-rep")
+            .topology
+            .vertices
+            .len();
+        vcad_kernel_math::set_geometry_tolerance(1e-6);
+
+        assert_eq!(fine_vertices, 16, "Fine tolerance keeps both walls");
+        assert_eq!(
+            coarse_vertices, 12,
+            "Coarse tolerance should weld the 4 near-coincident vertex pairs"
+        );
+    }
+
+    #[test]
+    fn test_default_params_capture_geometry_tolerance() {
+        // The trim and classify stages run on rayon worker threads, which see
+        // their own thread-local tolerance default. Default params must
+        // therefore capture the caller's tolerance for explicit pass-through.
+        vcad_kernel_math::set_geometry_tolerance(1e-3);
+        let params = BooleanParams::default();
+        vcad_kernel_math::set_geometry_tolerance(1e-6);
+
+        assert_eq!(params.geom_tol, 1e-3);
+        assert_eq!(params.weld_tol, 1e-3);
+        assert_eq!(BooleanParams::default().geom_tol, 1e-6);
+    }
+
+    #[test]
+    fn test_adaptive_segments_resolve_small_hole() {
+        use vcad_kernel_primitives::make_cylinder;
+
+        // Small through-hole (r=2) in a large cube: a fixed 32-segment split
+        // leaves the hole a coarse 32-gon, while a 1 µm chord tolerance
+        // resolves it much finer without touching the flat faces
+        let cube = make_cube(100.0, 100.0, 100.0);
+        let mut hole = make_cylinder(2.0, 120.0, 32);
+        translate_brep(&mut hole, 50.0, 50.0, -10.0);
+
+        let rim_vertices = |brep: &BRepSolid| {
+            brep.topology
+                .vertices
+                .values()
+                .filter(|v| {
+                    let r = ((v.point.x - 50.0).powi(2) + (v.point.y - 50.0).powi(2)).sqrt();
+                    (v.point.z - 100.0).abs() < 1e-6 && (r - 2.0).abs() < 1e-2
+                })
+                .count()
+        };
+
+        let fixed = boolean_op(&cube, &hole, BooleanOp::Difference, 32);
+        let fixed_rim = rim_vertices(fixed.as_brep().expect("fixed result should be a B-rep"));
+
+        let adaptive = boolean_op_adaptive(&cube, &hole, BooleanOp::Difference, 0.001);
+        let brep = adaptive
+            .as_brep()
+            .expect("adaptive result should be a B-rep");
+        let adaptive_rim = rim_vertices(brep);
+
+        // The hole's circular edge is resolved finely...
+        assert!(
+            adaptive_rim >= 64 && adaptive_rim > fixed_rim,
+            "Expected a finer hole rim than the {}-vertex fixed split, got {}",
+            fixed_rim,
+            adaptive_rim
+        );
+
+        // ...while untouched flat faces stay coarse (4-corner side walls)
+        let side_vertices = brep
+            .topology
+            .vertices
+            .values()
+            .filter(|v| v.point.x.abs() < 1e-6)
+            .count();
+        assert_eq!(
+            side_vertices, 4,
+            "Side face at x=0 should keep its 4 corners"
+        );
+    }
+
+    #[test]
+    fn test_symmetric_difference_overlapping() {
+        // Partially overlapping cubes, as in test_union_overlapping (shifted
+        // diagonally so no side faces are coplanar)
+        let a = make_cube(10.0, 10.0, 10.0);
+        let mut b = make_cube(10.0, 10.0, 10.0);
+        translate_brep(&mut b, 5.0, 5.0, 5.0);
+
+        let result = boolean_op(&a, &b, BooleanOp::SymmetricDifference, 32);
+        assert!(matches!(result, BooleanResult::BRep(_)));
+        let mesh = result.to_mesh(32);
+        assert!(mesh.num_triangles() > 0);
+
+        // XOR volume = vol(A) + vol(B) - 2*vol(A∩B) = 1000 + 1000 - 2*125
+        let overlap = 5.0 * 5.0 * 5.0;
+        let expected = 1000.0 + 1000.0 - 2.0 * overlap;
+        let volume = compute_mesh_volume(&mesh);
+        assert!(
+            (volume - expected).abs() < 50.0,
+            "Expected XOR volume ~{}, got {}",
+            expected,
+            volume
+        );
+    }
+
     #[test]
     fn test_difference_overlapping() {
         let a = make_cube(10.0, 10.0, 10.0);
@@ -354,18 +808,30 @@ mod tests {
         let h12345678 = unwrap_brep(boolean_op(&h1234567, &edge3, BooleanOp::Union, 32));
         let holes_all = unwrap_brep(boolean_op(&h12345678, &edge4, BooleanOp::Union, 32));
 
-        eprintln!("After 9-hole union: {} orphan half-edges", count_orphan_half_edges(&holes_all));
+        eprintln!(
+            "After 9-hole union: {} orphan half-edges",
+            count_orphan_half_edges(&holes_all)
+        );
 
         // Check that the unioned holes have no orphan half-edges
         let orphan_count = count_orphan_half_edges(&holes_all);
-        assert_eq!(orphan_count, 0, "Unioned holes should have no orphan half-edges");
+        assert_eq!(
+            orphan_count, 0,
+            "Unioned holes should have no orphan half-edges"
+        );
 
         // Now subtract from plate
         let result = unwrap_brep(boolean_op(&plate, &holes_all, BooleanOp::Difference, 32));
-        eprintln!("After difference: {} orphan half-edges", count_orphan_half_edges(&result));
+        eprintln!(
+            "After difference: {} orphan half-edges",
+            count_orphan_half_edges(&result)
+        );
 
         let final_orphan_count = count_orphan_half_edges(&result);
-        assert_eq!(final_orphan_count, 0, "Final result should have no orphan half-edges");
+        assert_eq!(
+            final_orphan_count, 0,
+            "Final result should have no orphan half-edges"
+        );
     }
 
     /// Test boolean difference with cylinder extending outside cube bounds.
@@ -393,7 +859,10 @@ mod tests {
         // Check bounding box - x should not extend below 0
         let (min, max) = compute_mesh_bbox(&mesh);
 
-        eprintln!("Cube-Cylinder Difference bbox: min={:?}, max={:?}", min, max);
+        eprintln!(
+            "Cube-Cylinder Difference bbox: min={:?}, max={:?}",
+            min, max
+        );
 
         // The key assertion: no geometry should extend to negative x
         assert!(
@@ -447,7 +916,11 @@ mod tests {
             "Centered Cylinder Difference bbox: min={:?}, max={:?}",
             min, max
         );
-        eprintln!("Mesh has {} triangles, {} vertices", mesh.num_triangles(), mesh.num_vertices());
+        eprintln!(
+            "Mesh has {} triangles, {} vertices",
+            mesh.num_triangles(),
+            mesh.num_vertices()
+        );
 
         // The key assertion: no geometry should extend to negative x
         assert!(
@@ -478,10 +951,7 @@ mod tests {
         for (i, &idx) in mesh.indices.iter().enumerate() {
             if idx as usize >= num_verts {
                 invalid_count += 1;
-                eprintln!(
-                    "Invalid index at {}: {} >= {} vertices",
-                    i, idx, num_verts
-                );
+                eprintln!("Invalid index at {}: {} >= {} vertices", i, idx, num_verts);
             }
             if idx > max_idx {
                 max_idx = idx;
@@ -500,7 +970,12 @@ mod tests {
     // =========================================================================
 
     /// Count triangles that have all vertices at a specific coordinate value.
-    fn count_triangles_at_coord(mesh: &TriangleMesh, coord: usize, value: f64, tolerance: f64) -> usize {
+    fn count_triangles_at_coord(
+        mesh: &TriangleMesh,
+        coord: usize,
+        value: f64,
+        tolerance: f64,
+    ) -> usize {
         let verts = &mesh.vertices;
         let indices = &mesh.indices;
         let mut count = 0;
@@ -517,7 +992,12 @@ mod tests {
     }
 
     /// Count triangles with at least one vertex at a coordinate value.
-    fn count_triangles_touching_coord(mesh: &TriangleMesh, coord: usize, value: f64, tolerance: f64) -> usize {
+    fn count_triangles_touching_coord(
+        mesh: &TriangleMesh,
+        coord: usize,
+        value: f64,
+        tolerance: f64,
+    ) -> usize {
         let verts = &mesh.vertices;
         let indices = &mesh.indices;
         let mut count = 0;
@@ -574,8 +1054,14 @@ mod tests {
         let y0_tris = count_triangles_at_coord(&mesh, 1, 0.0, 0.01);
         let y20_tris = count_triangles_at_coord(&mesh, 1, 20.0, 0.01);
 
-        assert!(z0_tris > 0, "Bottom face (z=0) should exist with circular hole");
-        assert!(z20_tris > 0, "Top face (z=20) should exist with circular hole");
+        assert!(
+            z0_tris > 0,
+            "Bottom face (z=0) should exist with circular hole"
+        );
+        assert!(
+            z20_tris > 0,
+            "Top face (z=20) should exist with circular hole"
+        );
         assert!(x0_tris > 0, "Left face (x=0) should exist");
         assert!(x20_tris > 0, "Right face (x=20) should exist");
         assert!(y0_tris > 0, "Front face (y=0) should exist");
@@ -613,7 +1099,11 @@ mod tests {
         eprintln!("Edge intersection bbox: min={:?}, max={:?}", min, max);
 
         // Verify bounding box
-        assert!(min[0] >= -0.01, "Should not extend to negative x: min_x = {}", min[0]);
+        assert!(
+            min[0] >= -0.01,
+            "Should not extend to negative x: min_x = {}",
+            min[0]
+        );
         assert!(max[0] <= 20.01, "Should not exceed x=20");
         assert!(min[1] >= -0.01, "Should not extend to negative y");
         assert!(max[1] <= 20.01, "Should not exceed y=20");
@@ -667,7 +1157,9 @@ mod tests {
                 let surface = &brep.geometry.surfaces[face.surface_index];
                 let surf_type = surface.surface_type();
                 let orientation = face.orientation;
-                let loop_verts: Vec<_> = brep.topology.loop_half_edges(face.outer_loop)
+                let loop_verts: Vec<_> = brep
+                    .topology
+                    .loop_half_edges(face.outer_loop)
                     .map(|he| brep.topology.vertices[brep.topology.half_edges[he].origin].point)
                     .collect();
                 if loop_verts.len() >= 3 {
@@ -678,7 +1170,11 @@ mod tests {
                     let e1 = loop_verts[1] - loop_verts[0];
                     let e2 = loop_verts[2] - loop_verts[0];
                     let winding_normal = e1.cross(&e2);
-                    let wn = if winding_normal.norm() > 1e-12 { winding_normal.normalize() } else { winding_normal };
+                    let wn = if winding_normal.norm() > 1e-12 {
+                        winding_normal.normalize()
+                    } else {
+                        winding_normal
+                    };
                     eprintln!("  {:?}: {:?}, {} verts, {} inner_loops, z=[{:.1},{:.1}], orient={:?}, winding_n=({:.2},{:.2},{:.2})",
                         face_id, surf_type, loop_verts.len(), face.inner_loops.len(), z_min, z_max, orientation, wn.x, wn.y, wn.z);
                 }
@@ -695,7 +1191,10 @@ mod tests {
         let expected_vol = box_vol - half_cylinder_vol;
         let actual_vol = compute_mesh_volume(&mesh);
 
-        eprintln!("Expected volume: {:.2}, Actual: {:.2}", expected_vol, actual_vol);
+        eprintln!(
+            "Expected volume: {:.2}, Actual: {:.2}",
+            expected_vol, actual_vol
+        );
         let vol_error = ((actual_vol - expected_vol) / expected_vol).abs();
         // TODO: Tighten this to 5% once arc-split geometry is fixed
         assert!(
@@ -826,30 +1325,48 @@ mod tests {
         if let Some(brep) = result.as_brep() {
             let solid = &brep.topology.solids[brep.solid_id];
             let shell = &brep.topology.shells[solid.outer_shell];
-            eprintln!("\nShell has {} faces, topology has {} faces", shell.faces.len(), brep.topology.faces.len());
+            eprintln!(
+                "\nShell has {} faces, topology has {} faces",
+                shell.faces.len(),
+                brep.topology.faces.len()
+            );
             eprintln!("Shell face IDs: {:?}", shell.faces);
             eprintln!("\nALL BRep faces ({} total):", brep.topology.faces.len());
             for (face_id, face) in &brep.topology.faces {
-                let loop_verts: Vec<_> = brep.topology.loop_half_edges(face.outer_loop)
+                let loop_verts: Vec<_> = brep
+                    .topology
+                    .loop_half_edges(face.outer_loop)
                     .map(|he| brep.topology.vertices[brep.topology.half_edges[he].origin].point)
                     .collect();
                 if loop_verts.len() < 3 {
                     continue;
                 }
                 let z_min = loop_verts.iter().map(|v| v.z).fold(f64::INFINITY, f64::min);
-                let z_max = loop_verts.iter().map(|v| v.z).fold(f64::NEG_INFINITY, f64::max);
+                let z_max = loop_verts
+                    .iter()
+                    .map(|v| v.z)
+                    .fold(f64::NEG_INFINITY, f64::max);
                 let e1 = loop_verts[1] - loop_verts[0];
                 let e2 = loop_verts[2] - loop_verts[0];
                 let winding_n = e1.cross(&e2);
-                let wn = if winding_n.norm() > 1e-12 { winding_n.normalize() } else { winding_n };
+                let wn = if winding_n.norm() > 1e-12 {
+                    winding_n.normalize()
+                } else {
+                    winding_n
+                };
                 let surface = &brep.geometry.surfaces[face.surface_index];
                 // Compute signed area to get true winding (not just first 3 verts)
                 let mut signed_area_xy = 0.0;
                 for i in 0..loop_verts.len() {
                     let j = (i + 1) % loop_verts.len();
-                    signed_area_xy += loop_verts[i].x * loop_verts[j].y - loop_verts[j].x * loop_verts[i].y;
+                    signed_area_xy +=
+                        loop_verts[i].x * loop_verts[j].y - loop_verts[j].x * loop_verts[i].y;
                 }
-                let _true_winding_z = if signed_area_xy > 0.0 { "CCW (+z)" } else { "CW (-z)" };
+                let _true_winding_z = if signed_area_xy > 0.0 {
+                    "CCW (+z)"
+                } else {
+                    "CW (-z)"
+                };
                 eprintln!(
                     "  {:?}: {:?}, {} verts, z=[{:.1},{:.1}], orient={:?}, winding=({:.2},{:.2},{:.2}), area={:.1}",
                     face_id, surface.surface_type(), loop_verts.len(), z_min, z_max, face.orientation, wn.x, wn.y, wn.z, signed_area_xy
@@ -864,7 +1381,10 @@ mod tests {
                     let e1 = loop_verts[1] - loop_verts[0];
                     let e2 = loop_verts[2] - loop_verts[0];
                     let n1 = e1.cross(&e2);
-                    eprintln!("    First triangle (v0,v1,v2) cross: ({:.4}, {:.4}, {:.4})", n1.x, n1.y, n1.z);
+                    eprintln!(
+                        "    First triangle (v0,v1,v2) cross: ({:.4}, {:.4}, {:.4})",
+                        n1.x, n1.y, n1.z
+                    );
                 }
             }
         }
@@ -923,7 +1443,10 @@ mod tests {
         let expected_vol = box_vol - quarter_cylinder_vol;
         let actual_vol = compute_mesh_volume(&mesh);
 
-        eprintln!("Expected volume: {:.2}, Actual: {:.2}", expected_vol, actual_vol);
+        eprintln!(
+            "Expected volume: {:.2}, Actual: {:.2}",
+            expected_vol, actual_vol
+        );
         let vol_error = ((actual_vol - expected_vol) / expected_vol).abs();
         // TODO: Tighten this to 5% once arc-split geometry is fixed
         assert!(
@@ -956,7 +1479,10 @@ mod tests {
         let box_vol = 20.0 * 20.0 * 20.0;
         let actual_vol = compute_mesh_volume(&mesh);
 
-        eprintln!("Tangent case - Box vol: {:.2}, Result: {:.2}", box_vol, actual_vol);
+        eprintln!(
+            "Tangent case - Box vol: {:.2}, Result: {:.2}",
+            box_vol, actual_vol
+        );
 
         // The volumes should be very close (tangent contact removes negligible material)
         let vol_error = ((actual_vol - box_vol) / box_vol).abs();
@@ -1036,7 +1562,10 @@ mod tests {
         let z0_tris = count_triangles_at_coord(&mesh, 2, 0.0, 0.01);
         let z20_tris = count_triangles_at_coord(&mesh, 2, 20.0, 0.01);
 
-        eprintln!("Multiple cylinders - z=0: {} tris, z=20: {} tris", z0_tris, z20_tris);
+        eprintln!(
+            "Multiple cylinders - z=0: {} tris, z=20: {} tris",
+            z0_tris, z20_tris
+        );
 
         assert!(z0_tris > 0, "Bottom face should have 3 holes");
         assert!(z20_tris > 0, "Top face should have 3 holes");
@@ -1048,8 +1577,12 @@ mod tests {
         let actual_vol = compute_mesh_volume(&mesh);
 
         let vol_error = ((actual_vol - expected_vol) / expected_vol).abs();
-        eprintln!("Expected vol: {:.2}, Actual: {:.2}, Error: {:.2}%",
-                  expected_vol, actual_vol, vol_error * 100.0);
+        eprintln!(
+            "Expected vol: {:.2}, Actual: {:.2}, Error: {:.2}%",
+            expected_vol,
+            actual_vol,
+            vol_error * 100.0
+        );
         assert!(
             vol_error < 0.05,
             "Volume error {:.1}% exceeds tolerance",
@@ -1065,9 +1598,9 @@ mod tests {
     #[derive(Debug)]
     struct BadTriangle {
         tri_index: usize,
-        face_axis: usize,       // 0=X, 1=Y, 2=Z
-        face_coord: f64,        // coordinate value (e.g., 20.0 for z=20)
-        expected_sign: f32,     // +1.0 or -1.0
+        face_axis: usize,   // 0=X, 1=Y, 2=Z
+        face_coord: f64,    // coordinate value (e.g., 20.0 for z=20)
+        expected_sign: f32, // +1.0 or -1.0
         actual_normal: [f32; 3],
         vertices: [[f32; 3]; 3],
     }
@@ -1112,10 +1645,9 @@ mod tests {
             // Check each face definition
             for &(axis, coord, expected_sign) in faces {
                 // Check if all vertices are on this face
-                let on_face =
-                    (v0[axis] as f64 - coord).abs() < tolerance &&
-                    (v1[axis] as f64 - coord).abs() < tolerance &&
-                    (v2[axis] as f64 - coord).abs() < tolerance;
+                let on_face = (v0[axis] as f64 - coord).abs() < tolerance
+                    && (v1[axis] as f64 - coord).abs() < tolerance
+                    && (v2[axis] as f64 - coord).abs() < tolerance;
 
                 if on_face {
                     let normal = compute_triangle_normal(mesh, tri * 3);
@@ -1150,15 +1682,12 @@ mod tests {
         for bt in bad {
             eprintln!(
                 "BAD TRI #{} on {}={:.1} face:",
-                bt.tri_index,
-                axis_names[bt.face_axis],
-                bt.face_coord
+                bt.tri_index, axis_names[bt.face_axis], bt.face_coord
             );
             let sign_str = if bt.expected_sign > 0.0 { "+" } else { "-" };
             eprintln!(
                 "  Expected normal: {}{}",
-                sign_str,
-                axis_names[bt.face_axis]
+                sign_str, axis_names[bt.face_axis]
             );
             eprintln!(
                 "  Actual normal: ({:.3}, {:.3}, {:.3})",
@@ -1176,34 +1705,59 @@ mod tests {
     fn debug_z0_faces(brep: &BRepSolid, label: &str) {
         eprintln!("\n=== {} z=0 faces ===", label);
         for (face_id, face) in &brep.topology.faces {
-            let loop_verts: Vec<_> = brep.topology.loop_half_edges(face.outer_loop)
+            let loop_verts: Vec<_> = brep
+                .topology
+                .loop_half_edges(face.outer_loop)
                 .map(|he| brep.topology.vertices[brep.topology.half_edges[he].origin].point)
                 .collect();
-            if loop_verts.is_empty() { continue; }
+            if loop_verts.is_empty() {
+                continue;
+            }
             let z_min = loop_verts.iter().map(|v| v.z).fold(f64::INFINITY, f64::min);
-            let z_max = loop_verts.iter().map(|v| v.z).fold(f64::NEG_INFINITY, f64::max);
-            if z_min.abs() > 0.1 || z_max.abs() > 0.1 { continue; } // Skip non-z=0 faces
+            let z_max = loop_verts
+                .iter()
+                .map(|v| v.z)
+                .fold(f64::NEG_INFINITY, f64::max);
+            if z_min.abs() > 0.1 || z_max.abs() > 0.1 {
+                continue;
+            } // Skip non-z=0 faces
 
             let surface = &brep.geometry.surfaces[face.surface_index];
             let surf_type = surface.surface_type();
 
             // Get surface normal at first point
-            let surf_normal = if let Some(plane) = surface.as_any().downcast_ref::<vcad_kernel_geom::Plane>() {
-                format!("({:.2},{:.2},{:.2})", plane.normal_dir.x, plane.normal_dir.y, plane.normal_dir.z)
-            } else {
-                "N/A".to_string()
-            };
+            let surf_normal =
+                if let Some(plane) = surface.as_any().downcast_ref::<vcad_kernel_geom::Plane>() {
+                    format!(
+                        "({:.2},{:.2},{:.2})",
+                        plane.normal_dir.x, plane.normal_dir.y, plane.normal_dir.z
+                    )
+                } else {
+                    "N/A".to_string()
+                };
 
             // Compute loop winding from vertices
             let mut signed_area_xy = 0.0;
             for i in 0..loop_verts.len() {
                 let j = (i + 1) % loop_verts.len();
-                signed_area_xy += loop_verts[i].x * loop_verts[j].y - loop_verts[j].x * loop_verts[i].y;
+                signed_area_xy +=
+                    loop_verts[i].x * loop_verts[j].y - loop_verts[j].x * loop_verts[i].y;
             }
-            let winding = if signed_area_xy > 0.0 { "CCW(+z)" } else { "CW(-z)" };
+            let winding = if signed_area_xy > 0.0 {
+                "CCW(+z)"
+            } else {
+                "CW(-z)"
+            };
 
-            eprintln!("  {:?}: {:?}, {} verts, orient={:?}, surf_n={}, winding={}",
-                face_id, surf_type, loop_verts.len(), face.orientation, surf_normal, winding);
+            eprintln!(
+                "  {:?}: {:?}, {} verts, orient={:?}, surf_n={}, winding={}",
+                face_id,
+                surf_type,
+                loop_verts.len(),
+                face.orientation,
+                surf_normal,
+                winding
+            );
 
             // Print vertices for small faces
             if loop_verts.len() <= 6 {
@@ -1257,25 +1811,43 @@ mod tests {
         // The cylinder caps inside the box are reversed, so they point INTO the hole.
         let face_specs: Vec<(usize, f64, f32)> = vec![
             // Only check faces that are definitely outer box faces
-            (0, 20.0, 1.0),   // x=20 -> +X (box right face)
-            // Note: y=0 and y=20 are partially cut by the cylinder,
-            // but the remaining portions should still face outward
+            (0, 20.0, 1.0), // x=20 -> +X (box right face)
+                            // Note: y=0 and y=20 are partially cut by the cylinder,
+                            // but the remaining portions should still face outward
         ];
 
         let bad = validate_outward_normals(&mesh, &face_specs, 0.1);
 
         if !bad.is_empty() {
-            eprintln!("\n=== DIFFERENCE: {} triangles have wrong normals ===", bad.len());
+            eprintln!(
+                "\n=== DIFFERENCE: {} triangles have wrong normals ===",
+                bad.len()
+            );
             print_bad_triangles(&bad);
         }
 
         // Count triangles per face for context
         eprintln!("\nDifference face triangle counts:");
-        eprintln!("  z=0:  {} tris", count_triangles_at_coord(&mesh, 2, 0.0, 0.1));
-        eprintln!("  z=20: {} tris", count_triangles_at_coord(&mesh, 2, 20.0, 0.1));
-        eprintln!("  x=20: {} tris", count_triangles_at_coord(&mesh, 0, 20.0, 0.1));
-        eprintln!("  y=0:  {} tris", count_triangles_at_coord(&mesh, 1, 0.0, 0.1));
-        eprintln!("  y=20: {} tris", count_triangles_at_coord(&mesh, 1, 20.0, 0.1));
+        eprintln!(
+            "  z=0:  {} tris",
+            count_triangles_at_coord(&mesh, 2, 0.0, 0.1)
+        );
+        eprintln!(
+            "  z=20: {} tris",
+            count_triangles_at_coord(&mesh, 2, 20.0, 0.1)
+        );
+        eprintln!(
+            "  x=20: {} tris",
+            count_triangles_at_coord(&mesh, 0, 20.0, 0.1)
+        );
+        eprintln!(
+            "  y=0:  {} tris",
+            count_triangles_at_coord(&mesh, 1, 0.0, 0.1)
+        );
+        eprintln!(
+            "  y=20: {} tris",
+            count_triangles_at_coord(&mesh, 1, 20.0, 0.1)
+        );
 
         assert!(
             bad.is_empty(),
@@ -1317,27 +1889,45 @@ mod tests {
         // y=20 (back, partial) -> normal +Y
         // The cylinder extends to x=-10, so there's curved surface there
         let face_specs: Vec<(usize, f64, f32)> = vec![
-            (2, 0.0, -1.0),   // z=0 -> -Z
-            (2, 20.0, 1.0),   // z=20 -> +Z
-            (0, 20.0, 1.0),   // x=20 -> +X
-            (1, 0.0, -1.0),   // y=0 -> -Y
-            (1, 20.0, 1.0),   // y=20 -> +Y
+            (2, 0.0, -1.0), // z=0 -> -Z
+            (2, 20.0, 1.0), // z=20 -> +Z
+            (0, 20.0, 1.0), // x=20 -> +X
+            (1, 0.0, -1.0), // y=0 -> -Y
+            (1, 20.0, 1.0), // y=20 -> +Y
         ];
 
         let bad = validate_outward_normals(&mesh, &face_specs, 0.1);
 
         if !bad.is_empty() {
-            eprintln!("\n=== UNION: {} triangles have wrong normals ===", bad.len());
+            eprintln!(
+                "\n=== UNION: {} triangles have wrong normals ===",
+                bad.len()
+            );
             print_bad_triangles(&bad);
         }
 
         // Count triangles per face for context
         eprintln!("\nUnion face triangle counts:");
-        eprintln!("  z=0:  {} tris", count_triangles_at_coord(&mesh, 2, 0.0, 0.1));
-        eprintln!("  z=20: {} tris", count_triangles_at_coord(&mesh, 2, 20.0, 0.1));
-        eprintln!("  x=20: {} tris", count_triangles_at_coord(&mesh, 0, 20.0, 0.1));
-        eprintln!("  y=0:  {} tris", count_triangles_at_coord(&mesh, 1, 0.0, 0.1));
-        eprintln!("  y=20: {} tris", count_triangles_at_coord(&mesh, 1, 20.0, 0.1));
+        eprintln!(
+            "  z=0:  {} tris",
+            count_triangles_at_coord(&mesh, 2, 0.0, 0.1)
+        );
+        eprintln!(
+            "  z=20: {} tris",
+            count_triangles_at_coord(&mesh, 2, 20.0, 0.1)
+        );
+        eprintln!(
+            "  x=20: {} tris",
+            count_triangles_at_coord(&mesh, 0, 20.0, 0.1)
+        );
+        eprintln!(
+            "  y=0:  {} tris",
+            count_triangles_at_coord(&mesh, 1, 0.0, 0.1)
+        );
+        eprintln!(
+            "  y=20: {} tris",
+            count_triangles_at_coord(&mesh, 1, 20.0, 0.1)
+        );
 
         // Check bounding box extends to x=-10 (cylinder protrusion)
         let (min, max) = compute_mesh_bbox(&mesh);
@@ -1354,4 +1944,265 @@ mod tests {
             bad.len()
         );
     }
+
+    #[test]
+    fn test_oblique_box_cut_follows_ellipse() {
+        use vcad_kernel_primitives::make_cylinder;
+
+        // Cylinder r=10 h=40, cut by a big box rotated 45° about X so its
+        // bottom face is the oblique plane z = y + 25. The cut curve is an
+        // ellipse with semi-minor axis 10 (along x) and semi-major 10√2.
+        let cyl = make_cylinder(10.0, 40.0, 32);
+
+        let mut cutter = make_cube(60.0, 60.0, 60.0);
+        let t = Transform::translation(0.0, 0.0, 25.0)
+            .then(&Transform::rotation_x(std::f64::consts::FRAC_PI_4))
+            .then(&Transform::translation(-30.0, -30.0, 0.0));
+        for (_, v) in &mut cutter.topology.vertices {
+            v.point = t.apply_point(&v.point);
+        }
+        cutter.geometry.surfaces = cutter
+            .geometry
+            .surfaces
+            .drain(..)
+            .map(|s| s.transform(&t))
+            .collect();
+
+        let result = boolean_op(&cyl, &cutter, BooleanOp::Difference, 32);
+        let mesh = result.to_mesh(32);
+        let volume = compute_mesh_volume(&mesh);
+
+        // The oblique plane passes through the axis at z=25, so the cut is
+        // symmetric and the remaining volume is exactly π·r²·25
+        let expected = std::f64::consts::PI * 100.0 * 25.0;
+        assert!(
+            (volume - expected).abs() < 0.01 * expected,
+            "Expected volume ~{:.0}, got {:.1}",
+            expected,
+            volume
+        );
+
+        // The cut plane z = y + 25 caps the solid between z=15 and z=35
+        let (min, max) = compute_mesh_bbox(&mesh);
+        assert!(
+            (max[2] - 35.0).abs() < 0.1,
+            "Top of oblique cut should be z~=35, got {:.2}",
+            max[2]
+        );
+        assert!(min[2].abs() < 1e-6, "Base should stay at z=0");
+
+        // Every vertex on the cut plane must lie on (or inside) the ellipse
+        // x²+y²=100, z=y+25, and the extreme samples must reach the ellipse
+        // axes: |x| up to 10 and distance up to 10√2 from the center (0,0,25)
+        let mut max_abs_x: f64 = 0.0;
+        let mut max_dist: f64 = 0.0;
+        let mut on_plane = 0;
+        for v in mesh.vertices.chunks(3) {
+            let (x, y, z) = (v[0] as f64, v[1] as f64, v[2] as f64);
+            if (z - (y + 25.0)).abs() > 1e-3 {
+                continue;
+            }
+            on_plane += 1;
+            let r2 = x * x + y * y;
+            assert!(
+                r2 < 100.0 + 1e-3,
+                "Cut-plane vertex ({:.2},{:.2},{:.2}) lies outside the ellipse",
+                x,
+                y,
+                z
+            );
+            max_abs_x = max_abs_x.max(x.abs());
+            max_dist = max_dist.max((r2 + (z - 25.0) * (z - 25.0)).sqrt());
+        }
+        assert!(on_plane > 32, "Expected a tessellated elliptical cut face");
+        assert!(
+            (max_abs_x - 10.0).abs() < 0.05,
+            "Ellipse semi-minor axis should be 10, got {:.3}",
+            max_abs_x
+        );
+        assert!(
+            (max_dist - 10.0 * 2.0_f64.sqrt()).abs() < 0.05,
+            "Ellipse semi-major axis should be 10√2, got {:.3}",
+            max_dist
+        );
+    }
+
+    #[test]
+    fn test_imprint_cube_contact_rectangle_on_plate() {
+        // A 10-cube embedded through the top face of a 40x40x5 plate: the
+        // cube's four side planes imprint the contact rectangle at z = 5
+        let plate = make_cube(40.0, 40.0, 5.0);
+        let mut tool = make_cube(10.0, 10.0, 10.0);
+        translate_brep(&mut tool, 15.0, 15.0, 0.0);
+
+        let imprinted = imprint(&plate, &tool, 32);
+
+        // No material removed: same volume as the plate
+        let vol = compute_mesh_volume(&tessellate_brep(&imprinted, 16));
+        assert!((vol - 8000.0).abs() < 80.0, "expected ~8000, got {vol}");
+
+        // The top face was split into sub-faces
+        assert!(
+            imprinted.topology.faces.len() > 6,
+            "expected new sub-faces, got {}",
+            imprinted.topology.faces.len()
+        );
+
+        // All four corners of the contact rectangle became vertices, and
+        // each rectangle side is an edge between adjacent corners
+        let corners = [
+            Point3::new(15.0, 15.0, 5.0),
+            Point3::new(25.0, 15.0, 5.0),
+            Point3::new(25.0, 25.0, 5.0),
+            Point3::new(15.0, 25.0, 5.0),
+        ];
+        for c in &corners {
+            assert!(
+                imprinted
+                    .topology
+                    .vertices
+                    .values()
+                    .any(|v| (v.point - c).norm() < 1e-6),
+                "missing contact corner {c:?}"
+            );
+        }
+
+        // Each rectangle side shows up as a half-edge between adjacent
+        // corners (the split helpers build loops directly; no sewing pass
+        // runs to pair them into shared edges)
+        let has_edge = |a: &Point3, b: &Point3| {
+            imprinted.topology.half_edges.iter().any(|(_, he)| {
+                let Some(next) = he.next else { return false };
+                let p0 = imprinted.topology.vertices[he.origin].point;
+                let p1 =
+                    imprinted.topology.vertices[imprinted.topology.half_edges[next].origin].point;
+                ((p0 - a).norm() < 1e-6 && (p1 - b).norm() < 1e-6)
+                    || ((p0 - b).norm() < 1e-6 && (p1 - a).norm() < 1e-6)
+            })
+        };
+        for i in 0..4 {
+            let a = &corners[i];
+            let b = &corners[(i + 1) % 4];
+            assert!(has_edge(a, b), "missing contact edge {a:?} -> {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_split_by_plane_cube_volumes_watertight() {
+        use vcad_kernel_geom::Plane;
+
+        let cube = make_cube(10.0, 10.0, 10.0);
+        let plane = Plane::new(
+            Point3::new(0.0, 0.0, 4.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+
+        let (above, below) = split_by_plane(&cube, &plane);
+
+        let vol_above = compute_mesh_volume(&tessellate_brep(&above, 16));
+        let vol_below = compute_mesh_volume(&tessellate_brep(&below, 16));
+        assert!(
+            (vol_above - 600.0).abs() < 1.0,
+            "positive half: expected 600, got {vol_above}"
+        );
+        assert!(
+            (vol_below - 400.0).abs() < 1.0,
+            "negative half: expected 400, got {vol_below}"
+        );
+
+        // Both halves are watertight: every half-edge is paired
+        for (name, half) in [("above", &above), ("below", &below)] {
+            let open = half
+                .topology
+                .half_edges
+                .values()
+                .filter(|he| he.twin.is_none())
+                .count();
+            assert_eq!(open, 0, "{name} half has {open} unpaired half-edges");
+        }
+
+        // A plane clear of the solid sends everything to one side
+        let far_plane = Plane::new(
+            Point3::new(0.0, 0.0, -5.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        let (all, none) = split_by_plane(&cube, &far_plane);
+        let vol_all = compute_mesh_volume(&tessellate_brep(&all, 16));
+        assert!((vol_all - 1000.0).abs() < 1e-6);
+        assert!(none.topology.faces.is_empty());
+    }
+
+    #[test]
+    fn test_boolean_op_tagged_hole_walls_come_from_b() {
+        use vcad_kernel_geom::SurfaceKind;
+        use vcad_kernel_primitives::make_cylinder;
+
+        let cube = make_cube(10.0, 10.0, 10.0);
+        let mut drill = make_cylinder(2.0, 20.0, 32);
+        translate_brep(&mut drill, 5.0, 5.0, -5.0);
+        let drill_lateral = drill
+            .topology
+            .faces
+            .iter()
+            .find(|(_, f)| {
+                drill.geometry.surfaces[f.surface_index].surface_type() == SurfaceKind::Cylinder
+            })
+            .map(|(id, _)| id)
+            .unwrap();
+
+        let (result, provenance) =
+            boolean_op_tagged(&cube, &drill, BooleanOp::Difference, 32).unwrap();
+
+        // Every result face is accounted for
+        assert_eq!(provenance.len(), result.topology.faces.len());
+
+        // The hole walls are cylindrical faces contributed by B, descending
+        // from the drill's lateral face
+        let mut wall_count = 0;
+        for (face_id, face) in &result.topology.faces {
+            if result.geometry.surfaces[face.surface_index].surface_type() == SurfaceKind::Cylinder
+            {
+                wall_count += 1;
+                let tag = &provenance[&face_id];
+                assert_eq!(tag.source, SolidSource::B);
+                assert_eq!(tag.original_face, Some(drill_lateral));
+            }
+        }
+        assert!(wall_count > 0, "expected cylindrical hole-wall faces");
+
+        // The cube's sides stay tagged as A faces with their input ids
+        let a_faces = provenance
+            .values()
+            .filter(|tag| tag.source == SolidSource::A)
+            .count();
+        assert!(a_faces >= 6, "expected all six cube faces from A");
+        for tag in provenance.values() {
+            if tag.source == SolidSource::A {
+                assert!(tag
+                    .original_face
+                    .is_some_and(|f| cube.topology.faces.contains_key(f)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_boolean_op_tagged_rejects_symmetric_difference() {
+        let a = make_cube(10.0, 10.0, 10.0);
+        let mut b = make_cube(10.0, 10.0, 10.0);
+        translate_brep(&mut b, 5.0, 0.0, 0.0);
+
+        // Symmetric difference is built from nested sews, so provenance
+        // tracking cannot follow it — the error names the operation rather
+        // than claiming an empty result
+        let err = boolean_op_tagged(&a, &b, BooleanOp::SymmetricDifference, 32).unwrap_err();
+        assert_eq!(
+            err,
+            BooleanError::UnsupportedOperation {
+                op: BooleanOp::SymmetricDifference,
+                context: "boolean_op_tagged",
+            }
+        );
+    }
 }
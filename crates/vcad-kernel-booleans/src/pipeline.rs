@@ -8,7 +8,10 @@ use vcad_kernel_primitives::BRepSolid;
 use vcad_kernel_tessellate::TriangleMesh;
 use vcad_kernel_topo::FaceId;
 
-use crate::api::{BooleanOp, BooleanResult};
+use crate::api::{
+    BooleanError, BooleanOp, BooleanParams, BooleanResult, BooleanTrace, FaceProvenance, FaceSplit,
+    SolidSource, SsiCurve,
+};
 use crate::{bbox, classify, sew, split, ssi, trim};
 
 /// Debug logging macro - only prints when debug-boolean feature is enabled
@@ -32,20 +35,22 @@ pub(crate) fn non_overlapping_boolean(
     solid_a: &BRepSolid,
     solid_b: &BRepSolid,
     op: BooleanOp,
-    _segments: u32,
+    params: &BooleanParams,
 ) -> BooleanResult {
+    let tolerance = params.weld_tol;
     match op {
-        BooleanOp::Union => {
-            // Union of non-overlapping = both solids combined
+        BooleanOp::Union | BooleanOp::SymmetricDifference => {
+            // Union (and XOR, which matches it when nothing overlaps) of
+            // non-overlapping = both solids combined
             let faces_a: Vec<_> = solid_a.topology.faces.keys().collect();
             let faces_b: Vec<_> = solid_b.topology.faces.keys().collect();
-            let result = sew::sew_faces(solid_a, &faces_a, solid_b, &faces_b, false, 1e-6);
+            let result = sew::sew_faces(solid_a, &faces_a, solid_b, &faces_b, false, tolerance);
             BooleanResult::BRep(Box::new(result))
         }
         BooleanOp::Difference => {
             // Difference with non-overlapping = just A (nothing to subtract)
             let faces_a: Vec<_> = solid_a.topology.faces.keys().collect();
-            let result = sew::sew_faces(solid_a, &faces_a, solid_b, &[], false, 1e-6);
+            let result = sew::sew_faces(solid_a, &faces_a, solid_b, &[], false, tolerance);
             BooleanResult::BRep(Box::new(result))
         }
         BooleanOp::Intersection => {
@@ -59,6 +64,18 @@ pub(crate) fn non_overlapping_boolean(
     }
 }
 
+/// Segment count that keeps the chord sagitta below `tol` on a circle of
+/// the given radius (mirroring the viewer's chord-tolerance tessellation).
+pub(crate) fn segments_for_chord(radius: f64, tol: f64) -> u32 {
+    if radius <= 0.0 || tol <= 0.0 || tol >= radius {
+        return 8;
+    }
+    // Sagitta of a chord spanning angle θ on radius r: s = r·(1 - cos(θ/2)).
+    // Solve s <= tol for the number of segments n = 2π/θ.
+    let theta = 2.0 * (1.0 - tol / radius).acos();
+    ((2.0 * std::f64::consts::PI / theta).ceil() as u32).clamp(8, 512)
+}
+
 /// Snap a value to 0 if it's within epsilon of 0.
 /// This prevents floating point errors like -0.0000001 from affecting classification.
 fn snap_to_zero(v: f64, eps: f64) -> f64 {
@@ -115,18 +132,36 @@ fn evaluate_curve(curve: &ssi::IntersectionCurve, t: f64) -> Point3 {
 }
 
 /// Apply splits from intersection curves to solid A.
+#[allow(clippy::too_many_arguments)]
 fn apply_splits_to_solid(
     solid: &mut BRepSolid,
     splits: HashMap<FaceId, Vec<(ssi::IntersectionCurve, Point3, Point3)>>,
-    segments: u32,
+    params: &BooleanParams,
+    chord_tolerance: Option<f64>,
     #[allow(unused_variables)] solid_name: &str,
-) {
+    iterations: &mut u64,
+    max_iterations: u64,
+    mut lineage: Option<&mut HashMap<FaceId, FaceId>>,
+) -> Result<(), BooleanError> {
     for (face_id, split_list) in splits {
         let mut current_faces = vec![face_id];
         for (curve, _entry, _exit) in split_list {
+            // With a chord tolerance, split circles are polygonized at their
+            // own radius so a small hole keeps the same absolute accuracy as
+            // a large bore, independent of the global segment count
+            let segments = match (&curve, chord_tolerance) {
+                (ssi::IntersectionCurve::Circle(c), Some(tol)) => segments_for_chord(c.radius, tol),
+                _ => params.classify_segments,
+            };
             let mut new_faces = Vec::new();
             for &fid in &current_faces {
                 if solid.topology.faces.contains_key(fid) {
+                    // Guard against a split loop that keeps producing new
+                    // sub-faces without converging on pathological input
+                    *iterations += 1;
+                    if *iterations > max_iterations {
+                        return Err(BooleanError::Timeout { max_iterations });
+                    }
                     // Check if this is a cylindrical face - use specialized split
                     if split::is_cylindrical_face(solid, fid) {
                         debug_bool!(
@@ -134,8 +169,14 @@ fn apply_splits_to_solid(
                             solid_name,
                             fid,
                             match &curve {
-                                ssi::IntersectionCurve::Line(l) => format!("Line at ({:.2},{:.2},{:.2})", l.origin.x, l.origin.y, l.origin.z),
-                                ssi::IntersectionCurve::Circle(c) => format!("Circle at ({:.2},{:.2},{:.2}) r={:.2}", c.center.x, c.center.y, c.center.z, c.radius),
+                                ssi::IntersectionCurve::Line(l) => format!(
+                                    "Line at ({:.2},{:.2},{:.2})",
+                                    l.origin.x, l.origin.y, l.origin.z
+                                ),
+                                ssi::IntersectionCurve::Circle(c) => format!(
+                                    "Circle at ({:.2},{:.2},{:.2}) r={:.2}",
+                                    c.center.x, c.center.y, c.center.z, c.radius
+                                ),
                                 _ => format!("{:?}", curve),
                             }
                         );
@@ -153,14 +194,53 @@ fn apply_splits_to_solid(
                         continue;
                     }
 
-                    // Check if this is a circular disk face (cylinder cap) with a line curve
+                    // Conical faces split along coaxial circles just like
+                    // cylindrical ones
+                    if split::is_conical_face(solid, fid) {
+                        let result = split::split_conical_face(solid, fid, &curve);
+                        debug_bool!(
+                            "    -> Conical split result: {} sub-faces {:?}",
+                            result.sub_faces.len(),
+                            result.sub_faces
+                        );
+                        if result.sub_faces.len() >= 2 {
+                            new_faces.extend(result.sub_faces);
+                        } else {
+                            new_faces.push(fid);
+                        }
+                        continue;
+                    }
+
+                    // Check if this is a circular disk face (cylinder cap) with
+                    // a line or partially overlapping circle curve
                     if split::is_circular_disk_face(solid, fid) {
+                        if let ssi::IntersectionCurve::Circle(_circle) = &curve {
+                            debug_bool!(
+                                "  Split {} circular disk face {:?} by Circle at ({:.2},{:.2},{:.2}) r={:.2}",
+                                solid_name,
+                                fid,
+                                _circle.center.x,
+                                _circle.center.y,
+                                _circle.center.z,
+                                _circle.radius
+                            );
+                            let result =
+                                split::split_circular_disk_face(solid, fid, &curve, segments);
+                            if result.sub_faces.len() >= 2 {
+                                new_faces.extend(result.sub_faces);
+                            } else {
+                                new_faces.push(fid);
+                            }
+                            continue;
+                        }
                         if let ssi::IntersectionCurve::Line(_line) = &curve {
                             debug_bool!(
                                 "  Split {} circular disk face {:?} by Line at ({:.2},{:.2},{:.2})",
                                 solid_name,
                                 fid,
-                                _line.origin.x, _line.origin.y, _line.origin.z
+                                _line.origin.x,
+                                _line.origin.y,
+                                _line.origin.z
                             );
                             let result =
                                 split::split_circular_disk_face(solid, fid, &curve, segments);
@@ -212,6 +292,27 @@ fn apply_splits_to_solid(
                             continue;
                         }
 
+                        // Closed sampled rings (oblique ellipses) split like
+                        // circles: inner face plus outer face with a hole
+                        if let ssi::IntersectionCurve::Sampled(points) = &curve {
+                            if split::is_closed_sampled_ring(points) {
+                                let result = split::split_planar_face(
+                                    solid,
+                                    fid,
+                                    &curve,
+                                    &Point3::origin(),
+                                    &Point3::origin(),
+                                    segments,
+                                );
+                                if result.sub_faces.len() >= 2 {
+                                    new_faces.extend(result.sub_faces);
+                                } else {
+                                    new_faces.push(fid);
+                                }
+                                continue;
+                            }
+                        }
+
                         // Handle line curves on planar faces
                         if let ssi::IntersectionCurve::Line(_) = &curve {
                             let result = split::split_planar_face(
@@ -232,7 +333,13 @@ fn apply_splits_to_solid(
                     }
 
                     // Re-trim the curve to THIS sub-face's boundary
-                    let segs = trim::trim_curve_to_face(&curve, fid, solid, 64);
+                    let segs = trim::trim_curve_to_face_with_tol(
+                        &curve,
+                        fid,
+                        solid,
+                        params.trim_samples as usize,
+                        params.geom_tol,
+                    );
                     debug_bool!(
                         "  Split {} face {:?}: re-trim got {} segs",
                         solid_name,
@@ -282,7 +389,15 @@ fn apply_splits_to_solid(
                 current_faces = new_faces;
             }
         }
+        // Split sub-faces all descend from the face the split group was
+        // keyed on, which is a face of the pre-split input solid
+        if let Some(map) = lineage.as_deref_mut() {
+            for &fid in &current_faces {
+                map.insert(fid, face_id);
+            }
+        }
     }
+    Ok(())
 }
 
 /// B-rep boolean pipeline for overlapping solids.
@@ -293,12 +408,21 @@ fn apply_splits_to_solid(
 /// 3. Splitting both A and B faces along intersection curves
 /// 4. Classifying split sub-faces
 /// 5. Selecting and sewing result faces
+///
+/// When `trace` is provided, each stage also records its intermediate data
+/// there for [`crate::boolean_op_debug`].
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn brep_boolean(
     solid_a: &BRepSolid,
     solid_b: &BRepSolid,
     op: BooleanOp,
-    segments: u32,
-) -> BooleanResult {
+    params: &BooleanParams,
+    chord_tolerance: Option<f64>,
+    max_iterations: u64,
+    provenance: Option<&mut HashMap<FaceId, FaceProvenance>>,
+    mut trace: Option<&mut BooleanTrace>,
+) -> Result<BooleanResult, BooleanError> {
+    let segments = params.classify_segments;
     debug_bool!("\n========== BREP BOOLEAN START ==========");
     debug_bool!("Operation: {:?}", op);
     debug_bool!("Solid A: {} faces", solid_a.topology.faces.len());
@@ -313,6 +437,35 @@ pub(crate) fn brep_boolean(
     debug_bool!("\n--- Stage 1: AABB filtering ---");
     debug_bool!("Candidate face pairs: {}", pairs.len());
 
+    // Tracing recomputes SSI per pair outside the parallel split loop, like
+    // the pre-flight scan in try_boolean_op — the loop below discards the
+    // curves once it has derived splits from them
+    if let Some(t) = trace.as_deref_mut() {
+        t.candidate_pairs = pairs.clone();
+        for (face_a, face_b) in &pairs {
+            let Some(face_data_a) = a.topology.faces.get(*face_a) else {
+                continue;
+            };
+            let Some(face_data_b) = b.topology.faces.get(*face_b) else {
+                continue;
+            };
+            let Some(surf_a) = a.geometry.surfaces.get(face_data_a.surface_index) else {
+                continue;
+            };
+            let Some(surf_b) = b.geometry.surfaces.get(face_data_b.surface_index) else {
+                continue;
+            };
+            let curve = ssi::intersect_surfaces(surf_a.as_ref(), surf_b.as_ref());
+            if !matches!(curve, ssi::IntersectionCurve::Empty) {
+                t.ssi_curves.push(SsiCurve {
+                    face_a: *face_a,
+                    face_b: *face_b,
+                    curve,
+                });
+            }
+        }
+    }
+
     // 2. For each face pair, compute SSI and collect splits for both A and B
     // This is the hot path - parallelize with rayon
     let split_results: Vec<_> = pairs
@@ -333,17 +486,70 @@ pub(crate) fn brep_boolean(
             let mut results_a = Vec::new();
             let mut results_b = Vec::new();
 
-            // For circle curves on planar faces, we don't need to trim
+            // For circle curves on planar, cylindrical, and conical faces,
+            // we don't need to trim — the circle splits the face directly
             if let ssi::IntersectionCurve::Circle(circle) = &curve {
-                if split::is_planar_face(&a, *face_a) {
+                if split::is_planar_face(&a, *face_a)
+                    || split::is_cylindrical_face(&a, *face_a)
+                    || split::is_conical_face(&a, *face_a)
+                {
                     results_a.push((curve.clone(), circle.center, circle.center));
                 }
-                if split::is_cylindrical_face(&b, *face_b) {
+                if split::is_planar_face(&b, *face_b)
+                    || split::is_cylindrical_face(&b, *face_b)
+                    || split::is_conical_face(&b, *face_b)
+                {
                     results_b.push((curve.clone(), circle.center, circle.center));
                 }
                 return Some((*face_a, results_a, *face_b, results_b));
             }
 
+            // Closed sampled rings (oblique plane/cylinder ellipses) also
+            // split planar and cylindrical faces whole — trimming would
+            // collapse them because entry and exit coincide on a closed
+            // curve. Crossing-cylinder quartics arrive as several closed
+            // loops in one polyline, each of which splits independently
+            if let ssi::IntersectionCurve::Sampled(points) = &curve {
+                let components = ssi::sampled_components(points);
+                if components.iter().all(|c| split::is_closed_sampled_ring(c)) {
+                    // Marching follows surface A's parameterization, so the
+                    // loops arrive organized as graphs over A's angle. Redo
+                    // the intersection with the operands swapped so B's side
+                    // gets loops organized over its own angle instead
+                    let components_b =
+                        match ssi::intersect_surfaces(surf_b.as_ref(), surf_a.as_ref()) {
+                            ssi::IntersectionCurve::Sampled(points_b) => {
+                                let swapped = ssi::sampled_components(&points_b);
+                                if swapped.iter().all(|c| split::is_closed_sampled_ring(c)) {
+                                    swapped
+                                } else {
+                                    components.clone()
+                                }
+                            }
+                            _ => components.clone(),
+                        };
+                    for component in components {
+                        let p0 = component[0];
+                        let loop_curve = ssi::IntersectionCurve::Sampled(component);
+                        if split::is_planar_face(&a, *face_a)
+                            || split::is_cylindrical_face(&a, *face_a)
+                        {
+                            results_a.push((loop_curve, p0, p0));
+                        }
+                    }
+                    for component in components_b {
+                        let p0 = component[0];
+                        let loop_curve = ssi::IntersectionCurve::Sampled(component);
+                        if split::is_planar_face(&b, *face_b)
+                            || split::is_cylindrical_face(&b, *face_b)
+                        {
+                            results_b.push((loop_curve, p0, p0));
+                        }
+                    }
+                    return Some((*face_a, results_a, *face_b, results_b));
+                }
+            }
+
             // Expand TwoLines into individual Line curves for processing
             let curves_to_process: Vec<ssi::IntersectionCurve> = match &curve {
                 ssi::IntersectionCurve::TwoLines(line1, line2) => {
@@ -382,7 +588,13 @@ pub(crate) fn brep_boolean(
 
             for single_curve in &curves_to_process {
                 // Trim curve to A's face boundary (for non-circle curves)
-                let segs_a = trim::trim_curve_to_face(single_curve, *face_a, &a, 64);
+                let segs_a = trim::trim_curve_to_face_with_tol(
+                    single_curve,
+                    *face_a,
+                    &a,
+                    params.trim_samples as usize,
+                    params.geom_tol,
+                );
                 debug_bool!(
                     "    Trim to face A ({:?}): {} segments",
                     face_a,
@@ -408,7 +620,13 @@ pub(crate) fn brep_boolean(
                 }
 
                 // Trim curve to B's face boundary (for non-circle curves)
-                let segs_b = trim::trim_curve_to_face(single_curve, *face_b, &b, 64);
+                let segs_b = trim::trim_curve_to_face_with_tol(
+                    single_curve,
+                    *face_b,
+                    &b,
+                    params.trim_samples as usize,
+                    params.geom_tol,
+                );
                 debug_bool!(
                     "    Trim to face B ({:?}): {} segments",
                     face_b,
@@ -457,20 +675,52 @@ pub(crate) fn brep_boolean(
     debug_bool!("Faces of A to split: {}", splits_a.len());
     debug_bool!("Faces of B to split: {}", splits_b.len());
 
-    // Apply splits to both solids
-    apply_splits_to_solid(&mut a, splits_a, segments, "A");
+    // Apply splits to both solids, tracking which input face each split
+    // sub-face descends from for provenance reporting
+    let mut lineage_a: HashMap<FaceId, FaceId> = HashMap::new();
+    let mut lineage_b: HashMap<FaceId, FaceId> = HashMap::new();
+    let mut iterations = 0u64;
+    apply_splits_to_solid(
+        &mut a,
+        splits_a,
+        params,
+        chord_tolerance,
+        "A",
+        &mut iterations,
+        max_iterations,
+        Some(&mut lineage_a),
+    )?;
     debug_bool!("\n--- Stage 2.5: After splits applied to A ---");
     debug_bool!("A now has {} faces", a.topology.faces.len());
 
-    apply_splits_to_solid(&mut b, splits_b, segments, "B");
+    apply_splits_to_solid(
+        &mut b,
+        splits_b,
+        params,
+        chord_tolerance,
+        "B",
+        &mut iterations,
+        max_iterations,
+        Some(&mut lineage_b),
+    )?;
+
+    if let Some(t) = trace.as_deref_mut() {
+        t.splits_a = lineage_to_splits(&lineage_a);
+        t.splits_b = lineage_to_splits(&lineage_b);
+    }
 
     // 3. Classify all faces (including split sub-faces)
     debug_bool!("\n--- Stage 3: Classification ---");
     debug_bool!("Solid A has {} faces after splits", a.topology.faces.len());
     debug_bool!("Solid B has {} faces after splits", b.topology.faces.len());
 
-    let classes_a = classify::classify_all_faces(&a, &b, segments);
-    let classes_b = classify::classify_all_faces(&b, &a, segments);
+    let classes_a = classify::classify_all_faces_with_tol(&a, &b, segments, params.geom_tol);
+    let classes_b = classify::classify_all_faces_with_tol(&b, &a, segments, params.geom_tol);
+
+    if let Some(t) = trace.as_deref_mut() {
+        t.classifications_a = classes_a.clone();
+        t.classifications_b = classes_b.clone();
+    }
 
     debug_bool!("\nClassification of A faces:");
     for (fid, _class) in &classes_a {
@@ -500,9 +750,49 @@ pub(crate) fn brep_boolean(
         );
     }
 
+    // Symmetric difference: (A \ B) ⊔ (B \ A). The two differences have
+    // disjoint interiors, so each is sewn from the shared split and
+    // classification, and the results are combined like a non-overlapping
+    // union. A single select/sew pass can't express XOR because `reverse_b`
+    // flips all of one solid's kept faces, while XOR needs each solid's
+    // inside faces flipped and its outside faces kept as-is.
+    if op == BooleanOp::SymmetricDifference {
+        let tolerance = params.weld_tol;
+        let (keep_a, keep_b, reverse_b) =
+            classify::select_faces(BooleanOp::Difference, &classes_a, &classes_b);
+        if let Some(t) = trace.as_deref_mut() {
+            t.selected_a = keep_a.clone();
+            t.selected_b = keep_b.clone();
+            t.reverse_b = reverse_b;
+        }
+        let a_minus_b = sew::sew_faces(&a, &keep_a, &b, &keep_b, reverse_b, tolerance);
+
+        let (keep_b, keep_a, reverse_a) =
+            classify::select_faces(BooleanOp::Difference, &classes_b, &classes_a);
+        let b_minus_a = sew::sew_faces(&b, &keep_b, &a, &keep_a, reverse_a, tolerance);
+
+        let faces_ab: Vec<FaceId> = a_minus_b.topology.faces.keys().collect();
+        let faces_ba: Vec<FaceId> = b_minus_a.topology.faces.keys().collect();
+        let result = sew::sew_faces(
+            &a_minus_b, &faces_ab, &b_minus_a, &faces_ba, false, tolerance,
+        );
+
+        debug_bool!("\n--- Stage 5: Result ---");
+        debug_bool!("XOR result solid has {} faces", result.topology.faces.len());
+        #[cfg(feature = "debug-boolean")]
+        warn_non_manifold(&result);
+        return Ok(BooleanResult::BRep(Box::new(result)));
+    }
+
     // 4. Select and sew
     let (keep_a, keep_b, reverse_b) = classify::select_faces(op, &classes_a, &classes_b);
 
+    if let Some(t) = trace {
+        t.selected_a = keep_a.clone();
+        t.selected_b = keep_b.clone();
+        t.reverse_b = reverse_b;
+    }
+
     debug_bool!("\n--- Stage 4: Selection (op={:?}) ---", op);
     debug_bool!("Keep {} A faces:", keep_a.len());
     for fid in &keep_a {
@@ -533,11 +823,202 @@ pub(crate) fn brep_boolean(
         );
     }
 
-    let result = sew::sew_faces(&a, &keep_a, &b, &keep_b, reverse_b, 1e-6);
+    // An intersection that selects no faces is genuinely empty (AABBs can
+    // overlap while the geometry doesn't touch) — return an explicit empty
+    // result rather than sewing a spurious sliver
+    if op == BooleanOp::Intersection && keep_a.is_empty() && keep_b.is_empty() {
+        debug_bool!("\n--- Stage 5: Result ---");
+        debug_bool!("No faces selected for intersection — empty result");
+        return Ok(BooleanResult::Mesh(TriangleMesh {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            normals: Vec::new(),
+        }));
+    }
+
+    let (result, sources) =
+        sew::sew_faces_mapped(&a, &keep_a, &b, &keep_b, reverse_b, params.weld_tol);
+    if let Some(out) = provenance {
+        for (&new_face, &(from_b, src_face)) in &sources {
+            let (input, lineage, source) = if from_b {
+                (solid_b, &lineage_b, SolidSource::B)
+            } else {
+                (solid_a, &lineage_a, SolidSource::A)
+            };
+            // A face that survived splitting keeps its input id (the working
+            // copies are clones); anything else descends from a split group
+            let original_face = if input.topology.faces.contains_key(src_face) {
+                Some(src_face)
+            } else {
+                lineage.get(&src_face).copied()
+            };
+            out.insert(
+                new_face,
+                FaceProvenance {
+                    source,
+                    original_face,
+                },
+            );
+        }
+    }
 
     debug_bool!("\n--- Stage 5: Result ---");
     debug_bool!("Result solid has {} faces", result.topology.faces.len());
     debug_bool!("========== BREP BOOLEAN END ==========\n");
 
-    BooleanResult::BRep(Box::new(result))
+    #[cfg(feature = "debug-boolean")]
+    warn_non_manifold(&result);
+
+    Ok(BooleanResult::BRep(Box::new(result)))
+}
+
+/// Group a sub-face → input-face lineage map into per-input-face split
+/// records, keeping only faces the split stage actually subdivided.
+fn lineage_to_splits(lineage: &HashMap<FaceId, FaceId>) -> Vec<FaceSplit> {
+    let mut groups: HashMap<FaceId, Vec<FaceId>> = HashMap::new();
+    for (&sub_face, &input_face) in lineage {
+        groups.entry(input_face).or_default().push(sub_face);
+    }
+    groups
+        .into_iter()
+        .filter(|(input_face, sub_faces)| sub_faces.as_slice() != [*input_face])
+        .map(|(input_face, mut sub_faces)| {
+            sub_faces.sort();
+            FaceSplit {
+                input_face,
+                sub_faces,
+            }
+        })
+        .collect()
+}
+
+/// Log a warning when a sewn result fails the manifold check. Compiled
+/// only with the `debug-boolean` feature, alongside the stage logging.
+#[cfg(feature = "debug-boolean")]
+fn warn_non_manifold(result: &BRepSolid) {
+    let report = sew::check_manifold(result);
+    if !report.is_clean() {
+        debug_bool!(
+            "WARNING: non-manifold result: {} open edges, {} over-shared edges, {} isolated vertices ({} edge records affected)",
+            report.open_edges,
+            report.over_shared_edges,
+            report.isolated_vertices.len(),
+            report.defective_edges.len()
+        );
+    }
+}
+
+/// Split solid A's faces along its intersection curves with solid B,
+/// without removing any material.
+///
+/// Runs stages 1–3 of the boolean pipeline (AABB filter, SSI, face
+/// splitting) on A only and skips classification and sewing, so A comes
+/// back whole but with extra edges where B touches it. Split failures
+/// leave the affected face unchanged rather than failing the whole
+/// operation — imprinting never loses geometry.
+pub(crate) fn imprint_splits(
+    solid_a: &BRepSolid,
+    solid_b: &BRepSolid,
+    params: &BooleanParams,
+) -> BRepSolid {
+    let mut a = solid_a.clone();
+
+    // 1. Candidate face pairs via AABB
+    let pairs = bbox::find_candidate_face_pairs(&a, solid_b);
+
+    // 2. SSI per pair, collecting splits for A's side only
+    let split_results: Vec<_> = pairs
+        .par_iter()
+        .filter_map(|(face_a, face_b)| {
+            let face_data_a = a.topology.faces.get(*face_a)?;
+            let face_data_b = solid_b.topology.faces.get(*face_b)?;
+            let surf_a = a.geometry.surfaces.get(face_data_a.surface_index)?;
+            let surf_b = solid_b.geometry.surfaces.get(face_data_b.surface_index)?;
+
+            let curve = ssi::intersect_surfaces(surf_a.as_ref(), surf_b.as_ref());
+            if matches!(curve, ssi::IntersectionCurve::Empty) {
+                return None;
+            }
+
+            let mut results_a = Vec::new();
+
+            // Circles and closed sampled rings split supported faces whole,
+            // as in brep_boolean
+            if let ssi::IntersectionCurve::Circle(circle) = &curve {
+                if split::is_planar_face(&a, *face_a)
+                    || split::is_cylindrical_face(&a, *face_a)
+                    || split::is_conical_face(&a, *face_a)
+                {
+                    results_a.push((curve.clone(), circle.center, circle.center));
+                }
+                return Some((*face_a, results_a));
+            }
+            if let ssi::IntersectionCurve::Sampled(points) = &curve {
+                let components = ssi::sampled_components(points);
+                if components.iter().all(|c| split::is_closed_sampled_ring(c)) {
+                    for component in components {
+                        let p0 = component[0];
+                        let loop_curve = ssi::IntersectionCurve::Sampled(component);
+                        if split::is_planar_face(&a, *face_a)
+                            || split::is_cylindrical_face(&a, *face_a)
+                        {
+                            results_a.push((loop_curve, p0, p0));
+                        }
+                    }
+                    return Some((*face_a, results_a));
+                }
+            }
+
+            let curves_to_process: Vec<ssi::IntersectionCurve> = match &curve {
+                ssi::IntersectionCurve::TwoLines(line1, line2) => vec![
+                    ssi::IntersectionCurve::Line(line1.clone()),
+                    ssi::IntersectionCurve::Line(line2.clone()),
+                ],
+                _ => vec![curve.clone()],
+            };
+
+            for single_curve in &curves_to_process {
+                let segs_a = trim::trim_curve_to_face_with_tol(
+                    single_curve,
+                    *face_a,
+                    &a,
+                    params.trim_samples as usize,
+                    params.geom_tol,
+                );
+                for seg in &segs_a {
+                    let entry = evaluate_curve(single_curve, seg.t_start);
+                    let exit = evaluate_curve(single_curve, seg.t_end);
+                    if (exit - entry).norm() > 1e-6 {
+                        results_a.push((single_curve.clone(), entry, exit));
+                    }
+                }
+            }
+
+            Some((*face_a, results_a))
+        })
+        .collect();
+
+    let mut splits_a: HashMap<FaceId, Vec<(ssi::IntersectionCurve, Point3, Point3)>> =
+        HashMap::new();
+    for (face_a, results_a) in split_results {
+        if !results_a.is_empty() {
+            splits_a.entry(face_a).or_default().extend(results_a);
+        }
+    }
+
+    // 3. Apply splits to A. A timeout here just stops splitting early —
+    // the solid is still valid, merely less finely imprinted
+    let mut iterations = 0u64;
+    let _ = apply_splits_to_solid(
+        &mut a,
+        splits_a,
+        params,
+        None,
+        "A",
+        &mut iterations,
+        crate::api::DEFAULT_MAX_SPLIT_ITERATIONS,
+        None,
+    );
+
+    a
 }
@@ -5,11 +5,15 @@
 //! - collapse zero-length half-edges
 //! - remove local A-B-A spikes in loops
 //! - pair orphan half-edges into edges when endpoints match
+//!
+//! [`heal_solid`] builds on these for imported geometry, adding vertex
+//! welding, sliver-face removal, and boundary-gap snapping.
 
 use std::collections::HashMap;
 
-use vcad_kernel_math::Point3;
-use vcad_kernel_topo::{HalfEdgeId, Topology};
+use vcad_kernel_math::{geometry_tolerance, Point3, Vec3};
+use vcad_kernel_primitives::BRepSolid;
+use vcad_kernel_topo::{FaceId, HalfEdgeId, Topology, VertexId};
 
 /// Repair common topology issues in-place.
 pub fn repair_topology(topo: &mut Topology, tolerance: f64) {
@@ -19,6 +23,239 @@ pub fn repair_topology(topo: &mut Topology, tolerance: f64) {
     pair_half_edges(topo, tolerance);
 }
 
+/// Tolerances for [`heal_solid`].
+#[derive(Debug, Clone, Copy)]
+pub struct RepairParams {
+    /// Vertices closer than this are welded into one (mm).
+    pub weld_tol: f64,
+    /// Faces whose outer loop encloses less area than this are removed (mm²).
+    pub min_area: f64,
+    /// Unpaired boundary endpoints within this distance are snapped together (mm).
+    pub max_gap: f64,
+}
+
+impl Default for RepairParams {
+    fn default() -> Self {
+        let tol = geometry_tolerance();
+        Self {
+            weld_tol: tol,
+            min_area: tol * tol,
+            max_gap: 10.0 * tol,
+        }
+    }
+}
+
+/// Counts of what [`heal_solid`] changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RepairStats {
+    /// Vertices welded into a surviving neighbor, including boundary-gap snaps.
+    pub merged_vertices: usize,
+    /// Sliver faces removed for falling below `min_area`.
+    pub removed_faces: usize,
+}
+
+/// Heal an open or degenerate solid in-place.
+///
+/// Runs three conservative passes sized by `params` — weld vertices within
+/// `weld_tol`, remove faces whose outer loop encloses less than `min_area`,
+/// and snap unpaired boundary endpoints within `max_gap` onto each other —
+/// then finishes with [`repair_topology`] so the snapped boundaries pair
+/// back into edges. Intended for imported geometry (STEP in particular)
+/// whose tiny gaps and sliver faces break tessellation and booleans.
+pub fn heal_solid(brep: &mut BRepSolid, params: &RepairParams) -> RepairStats {
+    let topo = &mut brep.topology;
+
+    let mut merged_vertices = weld_vertices(topo, params.weld_tol);
+
+    let mut removed_faces = 0;
+    let face_ids: Vec<FaceId> = topo.faces.keys().collect();
+    for face_id in face_ids {
+        if let Some(area) = face_outer_area(topo, face_id) {
+            if area < params.min_area {
+                remove_face(topo, face_id);
+                removed_faces += 1;
+            }
+        }
+    }
+
+    merged_vertices += snap_boundary_gaps(topo, params.max_gap);
+    repair_topology(topo, params.weld_tol);
+    drop_dangling_vertex_anchors(topo);
+
+    RepairStats {
+        merged_vertices,
+        removed_faces,
+    }
+}
+
+/// Merge vertices within `tolerance` of each other, returning how many
+/// were removed. Mirrors the sewing stage's vertex merge, but counts.
+fn weld_vertices(topo: &mut Topology, tolerance: f64) -> usize {
+    let tol2 = tolerance * tolerance;
+    let verts: Vec<(VertexId, Point3)> =
+        topo.vertices.iter().map(|(id, v)| (id, v.point)).collect();
+
+    // Merge map: vertex_to_remove → vertex_to_keep
+    let mut merge_map: HashMap<VertexId, VertexId> = HashMap::new();
+    for i in 0..verts.len() {
+        if merge_map.contains_key(&verts[i].0) {
+            continue;
+        }
+        for j in (i + 1)..verts.len() {
+            if merge_map.contains_key(&verts[j].0) {
+                continue;
+            }
+            if (verts[i].1 - verts[j].1).norm_squared() <= tol2 {
+                merge_map.insert(verts[j].0, verts[i].0);
+            }
+        }
+    }
+    if merge_map.is_empty() {
+        return 0;
+    }
+
+    let he_ids: Vec<_> = topo.half_edges.keys().collect();
+    for he_id in he_ids {
+        let origin = topo.half_edges[he_id].origin;
+        if let Some(&target) = merge_map.get(&origin) {
+            topo.half_edges[he_id].origin = target;
+        }
+    }
+    for v_id in merge_map.keys() {
+        topo.vertices.remove(*v_id);
+    }
+    merge_map.len()
+}
+
+/// Area enclosed by a face's outer loop (Newell's method on its vertices).
+///
+/// Returns `None` for loops with fewer than 3 half-edges — those are
+/// closed-curve boundaries (cylinder seams, circular caps) whose area the
+/// vertices alone can't measure, so they are never treated as slivers.
+fn face_outer_area(topo: &Topology, face_id: FaceId) -> Option<f64> {
+    let points: Vec<Point3> = topo
+        .loop_half_edges(topo.faces[face_id].outer_loop)
+        .map(|he| topo.vertices[topo.half_edges[he].origin].point)
+        .collect();
+    if points.len() < 3 {
+        return None;
+    }
+    let mut normal = Vec3::zeros();
+    for i in 0..points.len() {
+        let p = points[i].coords;
+        let q = points[(i + 1) % points.len()].coords;
+        normal += p.cross(&q);
+    }
+    Some(normal.norm() / 2.0)
+}
+
+/// Remove a face and its loops and half-edges, unpairing any twins so the
+/// neighbors become boundary half-edges (candidates for re-pairing later).
+fn remove_face(topo: &mut Topology, face_id: FaceId) {
+    let mut loops = vec![topo.faces[face_id].outer_loop];
+    loops.extend(topo.faces[face_id].inner_loops.iter().copied());
+    for loop_id in loops {
+        let hes: Vec<HalfEdgeId> = topo.loop_half_edges(loop_id).collect();
+        for he_id in hes {
+            if let Some(twin) = topo.half_edges[he_id].twin {
+                topo.half_edges[twin].twin = None;
+                topo.half_edges[twin].edge = None;
+            }
+            if let Some(edge_id) = topo.half_edges[he_id].edge {
+                topo.edges.remove(edge_id);
+            }
+            topo.half_edges.remove(he_id);
+        }
+        topo.loops.remove(loop_id);
+    }
+    if let Some(shell_id) = topo.faces[face_id].shell {
+        topo.shells[shell_id].faces.retain(|f| *f != face_id);
+    }
+    topo.faces.remove(face_id);
+}
+
+/// Snap unpaired boundary endpoints within `max_gap` of each other onto a
+/// single vertex, returning how many vertices were merged.
+///
+/// Only opposite-direction endpoint pairs are considered (this boundary's
+/// origin against that boundary's destination), which is the configuration
+/// left by a small gap between two faces that should share an edge.
+fn snap_boundary_gaps(topo: &mut Topology, max_gap: f64) -> usize {
+    if max_gap <= 0.0 {
+        return 0;
+    }
+
+    fn resolve(map: &HashMap<VertexId, VertexId>, mut v: VertexId) -> VertexId {
+        while let Some(&target) = map.get(&v) {
+            v = target;
+        }
+        v
+    }
+
+    let mut boundary: Vec<(VertexId, VertexId)> = Vec::new();
+    for (he_id, he) in topo.half_edges.iter() {
+        if he.twin.is_some() || he.loop_id.is_none() || he.next.is_none() {
+            continue;
+        }
+        boundary.push((he.origin, topo.half_edge_dest(he_id)));
+    }
+
+    let gap2 = max_gap * max_gap;
+    let mut merge_map: HashMap<VertexId, VertexId> = HashMap::new();
+    for i in 0..boundary.len() {
+        for j in (i + 1)..boundary.len() {
+            let (o_i, d_i) = boundary[i];
+            let (o_j, d_j) = boundary[j];
+            for (a, b) in [(o_i, d_j), (d_i, o_j)] {
+                let a = resolve(&merge_map, a);
+                let b = resolve(&merge_map, b);
+                if a == b {
+                    continue;
+                }
+                let pa = topo.vertices[a].point;
+                let pb = topo.vertices[b].point;
+                if (pa - pb).norm_squared() <= gap2 {
+                    merge_map.insert(b, a);
+                }
+            }
+        }
+    }
+    if merge_map.is_empty() {
+        return 0;
+    }
+
+    let targets: Vec<(VertexId, VertexId)> = merge_map
+        .keys()
+        .map(|&v| (v, resolve(&merge_map, v)))
+        .collect();
+    let targets: HashMap<VertexId, VertexId> = targets.into_iter().collect();
+    let he_ids: Vec<_> = topo.half_edges.keys().collect();
+    for he_id in he_ids {
+        let origin = topo.half_edges[he_id].origin;
+        if let Some(&target) = targets.get(&origin) {
+            topo.half_edges[he_id].origin = target;
+        }
+    }
+    for v_id in targets.keys() {
+        topo.vertices.remove(*v_id);
+    }
+    targets.len()
+}
+
+/// Clear vertex → half-edge anchors that point at removed half-edges.
+fn drop_dangling_vertex_anchors(topo: &mut Topology) {
+    let v_ids: Vec<VertexId> = topo.vertices.keys().collect();
+    for v_id in v_ids {
+        let dangling = matches!(
+            topo.vertices[v_id].half_edge,
+            Some(he) if !topo.half_edges.contains_key(he)
+        );
+        if dangling {
+            topo.vertices[v_id].half_edge = None;
+        }
+    }
+}
+
 fn collapse_degenerate_half_edges(topo: &mut Topology, tolerance: f64) {
     let he_ids: Vec<_> = topo.half_edges.keys().collect();
     for he_id in he_ids {
@@ -265,6 +502,67 @@ fn vertex_key_less(a: VertexKey, b: VertexKey) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use vcad_kernel_primitives::make_cube;
+    use vcad_kernel_tessellate::{tessellate_brep, TriangleMesh};
+
+    /// Count undirected mesh edges (keyed on exact vertex coordinates) not
+    /// used by exactly two triangles — zero for a watertight mesh.
+    fn open_edge_count(mesh: &TriangleMesh) -> usize {
+        let key = |i: u32| {
+            let p = &mesh.vertices[i as usize * 3..i as usize * 3 + 3];
+            (p[0].to_bits(), p[1].to_bits(), p[2].to_bits())
+        };
+        let mut counts: HashMap<_, usize> = HashMap::new();
+        for tri in mesh.indices.chunks(3) {
+            for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                let (ka, kb) = (key(a), key(b));
+                let edge = if ka <= kb { (ka, kb) } else { (kb, ka) };
+                *counts.entry(edge).or_default() += 1;
+            }
+        }
+        counts.values().filter(|&&c| c != 2).count()
+    }
+
+    #[test]
+    fn test_heal_solid_welds_split_vertex() {
+        let mut cube = make_cube(10.0, 10.0, 10.0);
+
+        // Split one corner: give one face its own copy of a shared vertex,
+        // nudged by 1e-7, so adjacent faces no longer tessellate to the
+        // same coordinates there
+        let topo = &mut cube.topology;
+        let face_id = topo.faces.keys().next().expect("cube has faces");
+        let he_id = topo.loops[topo.faces[face_id].outer_loop].half_edge;
+        let corner = topo.half_edges[he_id].origin;
+        let nudged = topo.vertices[corner].point + Vec3::new(1e-7, 0.0, 0.0);
+        let split = topo.add_vertex(nudged);
+        topo.half_edges[he_id].origin = split;
+
+        let cracked = tessellate_brep(&cube, 8);
+        assert!(
+            open_edge_count(&cracked) > 0,
+            "expected a cracked tessellation before repair"
+        );
+
+        let stats = heal_solid(&mut cube, &RepairParams::default());
+        assert!(stats.merged_vertices >= 1);
+        assert_eq!(stats.removed_faces, 0);
+
+        let healed = tessellate_brep(&cube, 8);
+        assert_eq!(
+            open_edge_count(&healed),
+            0,
+            "expected a watertight tessellation after repair"
+        );
+    }
+
+    #[test]
+    fn test_heal_solid_noop_on_clean_cube() {
+        let mut cube = make_cube(10.0, 10.0, 10.0);
+        let stats = heal_solid(&mut cube, &RepairParams::default());
+        assert_eq!(stats, RepairStats::default());
+        assert_eq!(open_edge_count(&tessellate_brep(&cube, 8)), 0);
+    }
 
     #[test]
     fn test_collapse_degenerate_half_edge() {
@@ -43,9 +43,9 @@
 //! ```
 
 use vcad_kernel_geom::{GeometryStore, SurfaceKind};
-use vcad_kernel_math::{Point3, Vec3};
+use vcad_kernel_math::{geometry_tolerance, Point3, Vec3};
 use vcad_kernel_primitives::BRepSolid;
-use vcad_kernel_topo::{FaceId, Orientation, ShellType, Topology};
+use vcad_kernel_topo::{EdgeId, FaceId, Orientation, ShellType, Topology, VertexId};
 
 use std::collections::HashMap;
 
@@ -62,10 +62,7 @@ impl PlaneEq {
     /// Create a plane equation from a point and normal.
     fn from_point_normal(point: &Point3, normal: &Vec3) -> Self {
         let d = normal.x * point.x + normal.y * point.y + normal.z * point.z;
-        Self {
-            normal: *normal,
-            d,
-        }
+        Self { normal: *normal, d }
     }
 
     /// Check if another plane is coplanar with this one (same plane, possibly opposite normal).
@@ -109,11 +106,31 @@ pub fn sew_faces(
     reverse_b: bool,
     tolerance: f64,
 ) -> BRepSolid {
+    sew_faces_mapped(a, faces_a, b, faces_b, reverse_b, tolerance).0
+}
+
+/// Like [`sew_faces`], but also reports where each result face came from.
+///
+/// The map is keyed by face ids in the result topology; each value is
+/// `(from_b, source_face)` with `source_face` an id in the originating
+/// input solid. Coplanar B faces dropped during a difference are simply
+/// absent. Feeds face provenance tracking in the boolean pipeline.
+pub fn sew_faces_mapped(
+    a: &BRepSolid,
+    faces_a: &[FaceId],
+    b: &BRepSolid,
+    faces_b: &[FaceId],
+    reverse_b: bool,
+    tolerance: f64,
+) -> (BRepSolid, HashMap<FaceId, (bool, FaceId)>) {
     let mut topo = Topology::new();
     let mut geom = GeometryStore::new();
+    let mut sources: HashMap<FaceId, (bool, FaceId)> = HashMap::new();
 
     // Copy faces from A
-    let _a_face_map = copy_faces(a, faces_a, false, &mut topo, &mut geom);
+    for (old, new) in copy_faces(a, faces_a, false, &mut topo, &mut geom) {
+        sources.insert(new, (false, old));
+    }
 
     // For difference operations, B faces that are coplanar with A faces should NOT
     // be reversed. These are typically end caps (like cylinder caps) that lie on
@@ -132,9 +149,7 @@ pub fn sew_faces(
                 }
 
                 // Get a point on the face and its normal
-                let plane = surface
-                    .as_any()
-                    .downcast_ref::<vcad_kernel_geom::Plane>()?;
+                let plane = surface.as_any().downcast_ref::<vcad_kernel_geom::Plane>()?;
                 let normal = plane.normal_dir.as_ref();
                 // Account for face orientation
                 let effective_normal = match face.orientation {
@@ -155,10 +170,7 @@ pub fn sew_faces(
             let surface = &b.geometry.surfaces[face.surface_index];
 
             if surface.surface_type() == SurfaceKind::Plane {
-                if let Some(plane) = surface
-                    .as_any()
-                    .downcast_ref::<vcad_kernel_geom::Plane>()
-                {
+                if let Some(plane) = surface.as_any().downcast_ref::<vcad_kernel_geom::Plane>() {
                     let b_normal = plane.normal_dir.as_ref();
                     let effective_normal = match face.orientation {
                         Orientation::Forward => *b_normal,
@@ -193,11 +205,17 @@ pub fn sew_faces(
         }
 
         // Copy B faces: coplanar ones without reversal, others with reversal
-        let _b_face_map_no_rev = copy_faces(b, &b_faces_no_reverse, false, &mut topo, &mut geom);
-        let _b_face_map_rev = copy_faces(b, &b_faces_reverse, true, &mut topo, &mut geom);
+        for (old, new) in copy_faces(b, &b_faces_no_reverse, false, &mut topo, &mut geom) {
+            sources.insert(new, (true, old));
+        }
+        for (old, new) in copy_faces(b, &b_faces_reverse, true, &mut topo, &mut geom) {
+            sources.insert(new, (true, old));
+        }
     } else {
         // No reversal needed - copy all B faces normally
-        let _b_face_map = copy_faces(b, faces_b, false, &mut topo, &mut geom);
+        for (old, new) in copy_faces(b, faces_b, false, &mut topo, &mut geom) {
+            sources.insert(new, (true, old));
+        }
     }
 
     // Merge vertices within tolerance
@@ -206,26 +224,162 @@ pub fn sew_faces(
     // Repair topology issues after merges
     repair::repair_topology(&mut topo, tolerance);
 
+    // Pair any boundary half-edges the hash-based matching missed
+    pair_coincident_half_edges(&mut topo, tolerance);
+
+    // Pair lone rim half-edges against the polygonized arcs that cover them
+    pair_rims_against_chains(&mut topo, &geom, tolerance);
+
+    // Rebuild rim chains for unpaired loops that lie on another face's
+    // surface (e.g. hole rims against a seam-only cylindrical wall)
+    pair_boundary_chains(&mut topo, &geom, tolerance);
+
     // Build shell from all faces
     let all_faces: Vec<FaceId> = topo.faces.keys().collect();
     if all_faces.is_empty() {
         let shell = topo.add_shell(Vec::new(), ShellType::Outer);
         let solid = topo.add_solid(shell);
-        return BRepSolid {
+        return (
+            BRepSolid {
+                topology: topo,
+                geometry: geom,
+                solid_id: solid,
+            },
+            sources,
+        );
+    }
+
+    // Split the faces into connected components. A component that encloses
+    // negative volume is a cavity left by a fully-enclosed tool (its faces
+    // were reversed to point into the material) — it becomes a void shell of
+    // the result instead of joining the outer boundary.
+    let components = face_components(&topo, &all_faces);
+    let mut outer_faces: Vec<FaceId> = Vec::new();
+    let mut void_components: Vec<Vec<FaceId>> = Vec::new();
+
+    if components.len() == 1 {
+        outer_faces = all_faces;
+    } else {
+        for component in components {
+            if component_is_void(&topo, &component) {
+                void_components.push(component);
+            } else {
+                outer_faces.extend(component);
+            }
+        }
+        if outer_faces.is_empty() {
+            // Degenerate: nothing outward-facing. Keep everything in the
+            // outer shell rather than producing a solid with no boundary.
+            outer_faces = void_components.drain(..).flatten().collect();
+        }
+    }
+
+    let shell = topo.add_shell(outer_faces, ShellType::Outer);
+    let solid = topo.add_solid(shell);
+    for component in void_components {
+        let void_shell = topo.add_shell(component, ShellType::Void);
+        topo.add_void_shell(solid, void_shell);
+    }
+
+    (
+        BRepSolid {
             topology: topo,
             geometry: geom,
             solid_id: solid,
-        };
+        },
+        sources,
+    )
+}
+
+/// Group faces into connected components by shared (merged) vertices.
+fn face_components(topo: &Topology, faces: &[FaceId]) -> Vec<Vec<FaceId>> {
+    use vcad_kernel_topo::VertexId;
+
+    // Map each vertex to the faces that use it
+    let mut vertex_faces: HashMap<VertexId, Vec<usize>> = HashMap::new();
+    for (i, &face_id) in faces.iter().enumerate() {
+        let face = &topo.faces[face_id];
+        let mut loops = vec![face.outer_loop];
+        loops.extend(&face.inner_loops);
+        for loop_id in loops {
+            for v in topo.loop_vertices(loop_id) {
+                vertex_faces.entry(v).or_default().push(i);
+            }
+        }
     }
 
-    let shell = topo.add_shell(all_faces, ShellType::Outer);
-    let solid = topo.add_solid(shell);
+    // Flood fill over faces that share a vertex
+    let mut component_of = vec![usize::MAX; faces.len()];
+    let mut components: Vec<Vec<FaceId>> = Vec::new();
+    for start in 0..faces.len() {
+        if component_of[start] != usize::MAX {
+            continue;
+        }
+        let comp_index = components.len();
+        let mut members = Vec::new();
+        let mut stack = vec![start];
+        component_of[start] = comp_index;
+        while let Some(i) = stack.pop() {
+            members.push(faces[i]);
+            let face = &topo.faces[faces[i]];
+            let mut loops = vec![face.outer_loop];
+            loops.extend(&face.inner_loops);
+            for loop_id in loops {
+                for v in topo.loop_vertices(loop_id) {
+                    for &j in &vertex_faces[&v] {
+                        if component_of[j] == usize::MAX {
+                            component_of[j] = comp_index;
+                            stack.push(j);
+                        }
+                    }
+                }
+            }
+        }
+        components.push(members);
+    }
+    components
+}
 
-    BRepSolid {
-        topology: topo,
-        geometry: geom,
-        solid_id: solid,
+/// Decide whether a face component is a cavity (faces pointing into the
+/// enclosed region) rather than an outward-facing body.
+///
+/// The primary test is the sign of the enclosed volume approximated from the
+/// outer loop polygons. Single-face curved components (e.g. a whole sphere)
+/// have degenerate loops whose polygon volume is ~0; for those, an
+/// all-reversed component can only be a fully-enclosed tool turned inside
+/// out, so we fall back to checking orientations.
+fn component_is_void(topo: &Topology, faces: &[FaceId]) -> bool {
+    let volume = component_signed_volume(topo, faces);
+    if volume.abs() > 1e-9 {
+        return volume < 0.0;
     }
+    faces
+        .iter()
+        .all(|&f| topo.faces[f].orientation == Orientation::Reversed)
+}
+
+/// Signed volume enclosed by a face component, approximated from the outer
+/// loop polygons.
+fn component_signed_volume(topo: &Topology, faces: &[FaceId]) -> f64 {
+    let mut volume = 0.0;
+    for &face_id in faces {
+        let face = &topo.faces[face_id];
+        let verts = topo.loop_vertices(face.outer_loop);
+        if verts.len() < 3 {
+            continue;
+        }
+        let points: Vec<Point3> = verts.iter().map(|&v| topo.vertices[v].point).collect();
+        let mut face_volume = 0.0;
+        for i in 1..points.len() - 1 {
+            let (v0, v1, v2) = (points[0].coords, points[i].coords, points[i + 1].coords);
+            face_volume += v0.dot(&v1.cross(&v2)) / 6.0;
+        }
+        if face.orientation == Orientation::Reversed {
+            face_volume = -face_volume;
+        }
+        volume += face_volume;
+    }
+    volume
 }
 
 /// Copy selected faces from a source BRep into the target topology/geometry.
@@ -419,6 +573,333 @@ impl VertexPosKey {
     }
 }
 
+/// Pair boundary half-edges that are geometrically coincident but were
+/// left untwinned by the ID- and hash-based matching in repair.
+///
+/// When A and B contribute the same edge with separately-created vertices,
+/// position hashing can miss the match: two endpoints within tolerance may
+/// quantize to different grid cells. This pass brute-forces the (small) set
+/// of remaining unpaired half-edges, pairing each with a candidate whose
+/// endpoints coincide within `tolerance` in the opposite direction,
+/// regardless of which operand contributed it.
+fn pair_coincident_half_edges(topo: &mut Topology, tolerance: f64) {
+    use vcad_kernel_topo::HalfEdgeId;
+
+    let unpaired: Vec<HalfEdgeId> = topo
+        .half_edges
+        .iter()
+        .filter(|(_, he)| he.twin.is_none() && he.loop_id.is_some() && he.next.is_some())
+        .map(|(id, _)| id)
+        .collect();
+
+    for i in 0..unpaired.len() {
+        let he_i = unpaired[i];
+        if topo.half_edges[he_i].twin.is_some() {
+            continue;
+        }
+        let origin_i = topo.vertices[topo.half_edges[he_i].origin].point;
+        let dest_i = topo.vertices[topo.half_edge_dest(he_i)].point;
+
+        for &he_j in &unpaired[i + 1..] {
+            if topo.half_edges[he_j].twin.is_some() {
+                continue;
+            }
+            let origin_j = topo.vertices[topo.half_edges[he_j].origin].point;
+            let dest_j = topo.vertices[topo.half_edge_dest(he_j)].point;
+
+            if (origin_i - dest_j).norm() <= tolerance && (dest_i - origin_j).norm() <= tolerance {
+                topo.add_edge(he_i, he_j);
+                break;
+            }
+        }
+    }
+}
+
+/// Pair lone boundary half-edges against coincident polyline chains.
+///
+/// A cylindrical wall keeps its rim arcs as single half-edges between
+/// split corners, while the planar cap it must sew to polygonizes the
+/// same arc into a chain of short edges: the endpoints coincide but
+/// edge-for-edge pairing can't. For each unpaired half-edge whose
+/// endpoints are bridged by a chain of unpaired half-edges running the
+/// opposite way, with every chain vertex on the lone edge's surface,
+/// this splices a reversed copy of the chain in place of the lone
+/// half-edge and twins the two chains segment by segment.
+fn pair_rims_against_chains(topo: &mut Topology, geom: &GeometryStore, tolerance: f64) {
+    use std::collections::HashMap;
+    use vcad_kernel_geom::distance_to_surface;
+    use vcad_kernel_topo::{HalfEdgeId, VertexId};
+
+    let unpaired: Vec<HalfEdgeId> = topo
+        .half_edges
+        .iter()
+        .filter(|(_, he)| he.twin.is_none() && he.loop_id.is_some() && he.next.is_some())
+        .map(|(id, _)| id)
+        .collect();
+
+    let mut by_origin: HashMap<VertexId, Vec<HalfEdgeId>> = HashMap::new();
+    for &he in &unpaired {
+        by_origin
+            .entry(topo.half_edges[he].origin)
+            .or_default()
+            .push(he);
+    }
+
+    for &lone in &unpaired {
+        if topo.half_edges[lone].twin.is_some() {
+            continue;
+        }
+        let Some(own_loop) = topo.half_edges[lone].loop_id else {
+            continue;
+        };
+        let start = topo.half_edges[lone].origin;
+        let end = topo.half_edge_dest(lone);
+        // Closed rims (seam circles) are handled by pair_boundary_chains
+        if start == end {
+            continue;
+        }
+        let Some(face_id) = topo.loops[own_loop].face else {
+            continue;
+        };
+        let surface = geom.surfaces[topo.faces[face_id].surface_index].as_ref();
+        // Only cylinder rims polygonize asymmetrically here; on a plane the
+        // surface-distance walk below would accept any coplanar detour
+        let Some(cyl) = surface
+            .as_any()
+            .downcast_ref::<vcad_kernel_geom::CylinderSurface>()
+        else {
+            continue;
+        };
+
+        // The chain must cover the same arc as the lone half-edge, not the
+        // complementary one (both lie on the surface when the neighbouring
+        // hole is a full circle). Rim arcs follow the loop convention:
+        // the bottom rim is traversed in +U and the top rim in -U,
+        // regardless of orientation — reversal flips only the orientation
+        // flag, never the winding.
+        let y_dir = cyl.axis.as_ref().cross(cyl.ref_dir.as_ref());
+        let u_of = |p: &vcad_kernel_math::Point3| {
+            let d = *p - cyl.center;
+            d.dot(&y_dir)
+                .atan2(d.dot(cyl.ref_dir.as_ref()))
+                .rem_euclid(2.0 * std::f64::consts::PI)
+        };
+        let v_of = |p: &vcad_kernel_math::Point3| (*p - cyl.center).dot(cyl.axis.as_ref());
+        let loop_vs: Vec<f64> = topo
+            .loop_half_edges(own_loop)
+            .map(|he| v_of(&topo.vertices[topo.half_edges[he].origin].point))
+            .collect();
+        let vmin = loop_vs.iter().cloned().fold(f64::MAX, f64::min);
+        let vmax = loop_vs.iter().cloned().fold(f64::MIN, f64::max);
+        let v_start = v_of(&topo.vertices[start].point);
+        let v_end = v_of(&topo.vertices[end].point);
+        let on_bottom = (v_start - vmin).abs() <= tolerance && (v_end - vmin).abs() <= tolerance;
+        let on_top = (v_start - vmax).abs() <= tolerance && (v_end - vmax).abs() <= tolerance;
+        if on_bottom == on_top {
+            continue;
+        }
+        let dir = if on_bottom { 1.0 } else { -1.0 };
+        let u_start = u_of(&topo.vertices[start].point);
+        let u_end = u_of(&topo.vertices[end].point);
+        let width = ((u_end - u_start) * dir).rem_euclid(2.0 * std::f64::consts::PI);
+        let on_arc = |p: &vcad_kernel_math::Point3| {
+            ((u_of(p) - u_start) * dir).rem_euclid(2.0 * std::f64::consts::PI) <= width + 0.01
+        };
+
+        // Walk unpaired half-edges from the lone edge's destination back
+        // to its origin, staying on the lone edge's arc
+        let mut chain: Vec<HalfEdgeId> = Vec::new();
+        let mut current = end;
+        let mut closed = false;
+        for _ in 0..4096 {
+            let Some(candidates) = by_origin.get(&current) else {
+                break;
+            };
+            let next = candidates.iter().copied().find(|&c| {
+                c != lone
+                    && topo.half_edges[c].twin.is_none()
+                    && topo.half_edges[c].loop_id.is_some_and(|l| l != own_loop)
+                    && !chain.contains(&c)
+                    && {
+                        let d = topo.half_edge_dest(c);
+                        let p = topo.vertices[d].point;
+                        d == start || (distance_to_surface(surface, &p) <= tolerance && on_arc(&p))
+                    }
+            });
+            let Some(next) = next else {
+                break;
+            };
+            chain.push(next);
+            current = topo.half_edge_dest(next);
+            if current == start {
+                closed = true;
+                break;
+            }
+        }
+        if !closed || chain.len() < 2 {
+            continue;
+        }
+
+        let Some(prev) = topo.half_edges[lone].prev else {
+            continue;
+        };
+        let Some(after) = topo.half_edges[lone].next else {
+            continue;
+        };
+
+        // Reversed copies of the chain, in place of the lone half-edge
+        let mut new_hes: Vec<(HalfEdgeId, HalfEdgeId)> = Vec::with_capacity(chain.len());
+        for &c in chain.iter().rev() {
+            let nh = topo.add_half_edge(topo.half_edge_dest(c));
+            topo.half_edges[nh].loop_id = Some(own_loop);
+            new_hes.push((nh, c));
+        }
+        for w in 0..new_hes.len() - 1 {
+            let (a, _) = new_hes[w];
+            let (b, _) = new_hes[w + 1];
+            topo.half_edges[a].next = Some(b);
+            topo.half_edges[b].prev = Some(a);
+        }
+        let first = new_hes[0].0;
+        let last = new_hes[new_hes.len() - 1].0;
+        topo.half_edges[prev].next = Some(first);
+        topo.half_edges[first].prev = Some(prev);
+        topo.half_edges[last].next = Some(after);
+        topo.half_edges[after].prev = Some(last);
+        if topo.loops[own_loop].half_edge == lone {
+            topo.loops[own_loop].half_edge = first;
+        }
+        topo.half_edges[lone].loop_id = None;
+        topo.half_edges[lone].next = None;
+        topo.half_edges[lone].prev = None;
+
+        for (nh, c) in new_hes {
+            topo.add_edge(c, nh);
+        }
+    }
+}
+
+/// Pair closed chains of unpaired half-edges against the face whose
+/// surface they lie on.
+///
+/// Seam-represented faces (cylinder walls, cones) lose their closed rim
+/// half-edges during repair when the adjacent cap is discarded, leaving the
+/// rim polygons on the neighbouring faces with nothing to twin against.
+/// For each closed ring of unpaired half-edges, this finds a face whose
+/// surface contains every ring vertex and whose boundary passes through one
+/// of them, splices a reversed copy of the ring into that boundary at the
+/// shared vertex, and twins the two chains edge by edge.
+fn pair_boundary_chains(topo: &mut Topology, geom: &GeometryStore, tolerance: f64) {
+    use std::collections::HashSet;
+    use vcad_kernel_geom::distance_to_surface;
+    use vcad_kernel_topo::{HalfEdgeId, VertexId};
+
+    let unpaired: Vec<HalfEdgeId> = topo
+        .half_edges
+        .iter()
+        .filter(|(_, he)| he.twin.is_none() && he.loop_id.is_some() && he.next.is_some())
+        .map(|(id, _)| id)
+        .collect();
+
+    let mut visited: HashSet<HalfEdgeId> = HashSet::new();
+    for &start in &unpaired {
+        if visited.contains(&start) || topo.half_edges[start].twin.is_some() {
+            continue;
+        }
+
+        // Walk forward collecting a closed ring of consecutive unpaired
+        // half-edges; partial chains are left for other repair passes
+        let mut chain = vec![start];
+        visited.insert(start);
+        let mut closed = false;
+        let mut current = start;
+        while let Some(next) = topo.half_edges[current].next {
+            if next == start {
+                closed = true;
+                break;
+            }
+            if topo.half_edges[next].twin.is_some() || visited.contains(&next) {
+                break;
+            }
+            chain.push(next);
+            visited.insert(next);
+            current = next;
+        }
+        if !closed || chain.len() < 3 {
+            continue;
+        }
+
+        let chain_verts: Vec<VertexId> =
+            chain.iter().map(|&he| topo.half_edges[he].origin).collect();
+        let own_loop = topo.half_edges[start].loop_id;
+
+        // Find a host: a face whose surface contains every ring vertex and
+        // whose boundary passes through one of them (shared after merging)
+        let mut host: Option<(HalfEdgeId, usize)> = None;
+        'faces: for face in topo.faces.values() {
+            let surface = geom.surfaces[face.surface_index].as_ref();
+            let mut face_loops = vec![face.outer_loop];
+            face_loops.extend(&face.inner_loops);
+            for loop_id in face_loops {
+                if Some(loop_id) == own_loop {
+                    continue 'faces;
+                }
+                for h in topo.loop_half_edges(loop_id) {
+                    let Some(k) = chain_verts
+                        .iter()
+                        .position(|&v| v == topo.half_edges[h].origin)
+                    else {
+                        continue;
+                    };
+                    let on_surface = chain_verts.iter().all(|&v| {
+                        distance_to_surface(surface, &topo.vertices[v].point) <= tolerance
+                    });
+                    if on_surface {
+                        host = Some((h, k));
+                        break 'faces;
+                    }
+                }
+            }
+        }
+        let Some((splice_at, k)) = host else {
+            continue;
+        };
+        let Some(splice_prev) = topo.half_edges[splice_at].prev else {
+            continue;
+        };
+        let host_loop = topo.half_edges[splice_at].loop_id;
+
+        // Build the reversed ring starting and ending at the shared vertex
+        let n = chain.len();
+        let mut new_hes: Vec<(HalfEdgeId, HalfEdgeId)> = Vec::with_capacity(n);
+        for step in 1..=n {
+            let c = chain[(k + n - step) % n];
+            let dest = topo.half_edge_dest(c);
+            let nh = topo.add_half_edge(dest);
+            topo.half_edges[nh].loop_id = host_loop;
+            new_hes.push((nh, c));
+        }
+        for w in 0..n - 1 {
+            let (a, _) = new_hes[w];
+            let (b, _) = new_hes[w + 1];
+            topo.half_edges[a].next = Some(b);
+            topo.half_edges[b].prev = Some(a);
+        }
+
+        // Splice into the host boundary just before the shared vertex
+        let first = new_hes[0].0;
+        let last = new_hes[n - 1].0;
+        topo.half_edges[splice_prev].next = Some(first);
+        topo.half_edges[first].prev = Some(splice_prev);
+        topo.half_edges[last].next = Some(splice_at);
+        topo.half_edges[splice_at].prev = Some(last);
+
+        for (nh, c) in new_hes {
+            topo.add_edge(c, nh);
+        }
+    }
+}
+
 /// Merge vertices that are within tolerance of each other.
 ///
 /// After merging, half-edges pointing to the merged-away vertex
@@ -468,6 +949,147 @@ fn merge_nearby_vertices(topo: &mut Topology, tolerance: f64) {
     }
 }
 
+/// Findings from [`check_manifold`] on a sewn solid.
+///
+/// A watertight two-manifold has every geometric edge shared by exactly
+/// two half-edges. Classification that keeps overlapping faces can leave
+/// an edge open (one half-edge) or over-shared (three or more), and such
+/// defects pass silently into tessellation as visual artifacts. A healthy
+/// result is all zeros/empty — see [`ManifoldReport::is_clean`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifoldReport {
+    /// Geometric edges with fewer than two incident half-edges.
+    pub open_edges: usize,
+    /// Geometric edges with three or more incident half-edges.
+    pub over_shared_edges: usize,
+    /// Edge records involved in a defective geometric edge.
+    pub defective_edges: Vec<EdgeId>,
+    /// Vertices that no half-edge uses as an origin.
+    pub isolated_vertices: Vec<VertexId>,
+}
+
+impl ManifoldReport {
+    /// True when every edge is shared by exactly two half-edges and no
+    /// vertex is stranded.
+    pub fn is_clean(&self) -> bool {
+        self.open_edges == 0 && self.over_shared_edges == 0 && self.isolated_vertices.is_empty()
+    }
+}
+
+/// Check that a sewn solid is a two-manifold.
+///
+/// Over-shared edges are found by grouping half-edges on their unordered
+/// endpoint positions, quantized to the geometry tolerance — twins are
+/// paired geometrically during sewing, so coincident endpoints need not
+/// share vertex ids — and flagging any group with three or more members.
+/// Twinless half-edges are only open when some span of them has no other
+/// collinear twinless half-edge covering it: polyline splits routinely
+/// leave one long half-edge facing several short ones (T-junctions), and
+/// reversed B faces keep their winding (only the orientation field flips),
+/// so the facing half-edge may run in either direction. Both are untwinned
+/// but still watertight.
+pub fn check_manifold(brep: &BRepSolid) -> ManifoldReport {
+    let topo = &brep.topology;
+    let tol = geometry_tolerance();
+    let quantize = |p: Point3| -> (i64, i64, i64) {
+        (
+            (p.x / tol).round() as i64,
+            (p.y / tol).round() as i64,
+            (p.z / tol).round() as i64,
+        )
+    };
+
+    type QuantPoint = (i64, i64, i64);
+    let mut incidence: HashMap<(QuantPoint, QuantPoint), Vec<Option<EdgeId>>> = HashMap::new();
+    let mut used_vertices: std::collections::HashSet<VertexId> = std::collections::HashSet::new();
+    let mut twinless: Vec<(Point3, Point3, Option<EdgeId>)> = Vec::new();
+    let mut report = ManifoldReport::default();
+
+    for (_, he) in &topo.half_edges {
+        if he.loop_id.is_none() {
+            continue;
+        }
+        let Some(next) = he.next else { continue };
+        let origin = he.origin;
+        let dest = topo.half_edges[next].origin;
+        used_vertices.insert(origin);
+        used_vertices.insert(dest);
+
+        let p = topo.vertices[origin].point;
+        let q = topo.vertices[dest].point;
+        if he.twin.is_none() {
+            twinless.push((p, q, he.edge));
+        }
+
+        let a = quantize(p);
+        let b = quantize(q);
+        let key = if a <= b { (a, b) } else { (b, a) };
+        incidence.entry(key).or_default().push(he.edge);
+    }
+
+    for edges in incidence.values() {
+        if edges.len() > 2 {
+            report.over_shared_edges += 1;
+            report.defective_edges.extend(edges.iter().flatten());
+        }
+    }
+
+    // A twinless half-edge is open where no other collinear twinless
+    // half-edge covers it
+    for (i, &(p, q, edge)) in twinless.iter().enumerate() {
+        let dir = q - p;
+        let len = dir.norm();
+        if len < tol {
+            continue;
+        }
+        let dir = dir / len;
+
+        let mut intervals: Vec<(f64, f64)> = Vec::new();
+        for (j, &(a, b, _)) in twinless.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let ta = (a - p).dot(&dir);
+            let tb = (b - p).dot(&dir);
+            // Collinear within tolerance?
+            if ((a - p) - ta * dir).norm() > tol || ((b - p) - tb * dir).norm() > tol {
+                continue;
+            }
+            let (lo, hi) = if ta < tb { (ta, tb) } else { (tb, ta) };
+            let (lo, hi) = (lo.max(0.0), hi.min(len));
+            if hi > lo {
+                intervals.push((lo, hi));
+            }
+        }
+
+        intervals.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(std::cmp::Ordering::Equal));
+        let mut frontier = 0.0_f64;
+        let mut open = false;
+        for (lo, hi) in intervals {
+            if lo > frontier + tol {
+                open = true;
+                break;
+            }
+            frontier = frontier.max(hi);
+        }
+        if open || frontier < len - tol {
+            report.open_edges += 1;
+            report.defective_edges.extend(edge);
+        }
+    }
+
+    report.defective_edges.sort();
+    report.defective_edges.dedup();
+
+    report.isolated_vertices = topo
+        .vertices
+        .keys()
+        .filter(|v| !used_vertices.contains(v))
+        .collect();
+
+    report
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -489,6 +1111,49 @@ mod tests {
         assert_eq!(result.topology.faces.len(), 12); // 6 + 6
     }
 
+    #[test]
+    fn test_check_manifold_flags_over_shared_edge() {
+        // Sew a cube against an extra copy of its own bottom face: the
+        // duplicate's four edges each end up with more than two incident
+        // half-edges, which classification bugs can produce when
+        // overlapping faces are kept
+        let a = make_cube(10.0, 10.0, 10.0);
+        let faces_a: Vec<FaceId> = a.topology.faces.keys().collect();
+        let bottom = faces_a[0];
+
+        let bad = sew_faces(&a, &faces_a, &a, &[bottom], false, 1e-6);
+        let report = check_manifold(&bad);
+        assert!(!report.is_clean());
+        assert!(
+            report.over_shared_edges >= 4,
+            "expected the duplicated face's edges flagged, got {report:?}"
+        );
+        assert!(!report.defective_edges.is_empty());
+    }
+
+    #[test]
+    fn test_check_manifold_clean_difference() {
+        use crate::{boolean_op, BooleanOp};
+
+        let a = make_cube(20.0, 20.0, 20.0);
+        let mut b = make_cube(10.0, 10.0, 10.0);
+        for (_, v) in &mut b.topology.vertices {
+            v.point += Vec3::new(15.0, 15.0, 15.0);
+        }
+        for surf in std::mem::take(&mut b.geometry.surfaces) {
+            b.geometry
+                .surfaces
+                .push(surf.transform(&vcad_kernel_math::Transform::translation(15.0, 15.0, 15.0)));
+        }
+
+        let result = boolean_op(&a, &b, BooleanOp::Difference, 16);
+        let brep = result
+            .as_brep()
+            .expect("difference should stay in B-rep form");
+        let report = check_manifold(brep);
+        assert!(report.is_clean(), "difference not manifold: {report:?}");
+    }
+
     #[test]
     fn test_sew_with_reverse() {
         let a = make_cube(10.0, 10.0, 10.0);
@@ -579,15 +1244,25 @@ mod tests {
         let faces_a: Vec<FaceId> = a.topology.faces.keys().collect();
         let faces_b: Vec<FaceId> = b.topology.faces.keys().collect();
 
-        eprintln!("Cylinder A: {} faces, {} half-edges", a.topology.faces.len(), a.topology.half_edges.len());
-        eprintln!("Cylinder B: {} faces, {} half-edges", b.topology.faces.len(), b.topology.half_edges.len());
+        eprintln!(
+            "Cylinder A: {} faces, {} half-edges",
+            a.topology.faces.len(),
+            a.topology.half_edges.len()
+        );
+        eprintln!(
+            "Cylinder B: {} faces, {} half-edges",
+            b.topology.faces.len(),
+            b.topology.half_edges.len()
+        );
 
         let result = sew_faces(&a, &faces_a, &b, &faces_b, false, 1e-6);
 
-        eprintln!("Result: {} faces, {} half-edges, {} edges",
+        eprintln!(
+            "Result: {} faces, {} half-edges, {} edges",
             result.topology.faces.len(),
             result.topology.half_edges.len(),
-            result.topology.edges.len());
+            result.topology.edges.len()
+        );
 
         // Count half-edges without parent edges
         let mut orphan_count = 0;
@@ -608,4 +1283,44 @@ mod tests {
             orphan_count
         );
     }
+
+    #[test]
+    fn test_sew_difference_pairs_plate_hole_walls() {
+        use crate::{boolean_op, BooleanOp};
+        use vcad_kernel_math::Transform;
+        use vcad_kernel_primitives::make_cylinder;
+
+        // Plate with a through-hole: the hole walls come from B while the
+        // top/bottom rims come from split A faces, so every interior wall
+        // edge must be paired across operands
+        let plate = make_cube(20.0, 20.0, 5.0);
+        let mut drill = make_cylinder(3.0, 15.0, 32);
+        let t = Transform::translation(10.0, 10.0, -5.0);
+        for (_, v) in &mut drill.topology.vertices {
+            v.point = t.apply_point(&v.point);
+        }
+        drill.geometry.surfaces = drill
+            .geometry
+            .surfaces
+            .drain(..)
+            .map(|s| s.transform(&t))
+            .collect();
+
+        let result = boolean_op(&plate, &drill, BooleanOp::Difference, 32);
+        let brep = result
+            .as_brep()
+            .expect("difference should stay in B-rep form");
+
+        let unpaired = brep
+            .topology
+            .half_edges
+            .values()
+            .filter(|he| he.loop_id.is_some() && he.twin.is_none())
+            .count();
+        assert_eq!(
+            unpaired, 0,
+            "found {} unpaired half-edges after sewing",
+            unpaired
+        );
+    }
 }
@@ -9,7 +9,7 @@
 
 use vcad_kernel_math::{Point2, Point3};
 use vcad_kernel_primitives::BRepSolid;
-use vcad_kernel_topo::{FaceId, Orientation};
+use vcad_kernel_topo::{FaceId, Orientation, VertexId};
 
 use crate::ssi::IntersectionCurve;
 
@@ -289,10 +289,7 @@ fn point_to_segment_dist(p: &Point3, a: &Point3, b: &Point3) -> f64 {
 /// in order along the line direction.
 ///
 /// Uses exact orient2d predicates for robust line-segment intersection detection.
-fn find_line_polygon_crossings(
-    polygon: &[Point3],
-    line: &vcad_kernel_geom::Line3d,
-) -> Vec<Point3> {
+fn find_line_polygon_crossings(polygon: &[Point3], line: &vcad_kernel_geom::Line3d) -> Vec<Point3> {
     use vcad_kernel_math::predicates::{orient2d, Sign};
 
     let n = polygon.len();
@@ -392,7 +389,9 @@ fn find_line_polygon_crossings(
         let intersection = snap_point(line.origin + t * line.direction);
 
         // Avoid duplicate crossings at vertices
-        let is_duplicate = crossings.iter().any(|c: &Point3| (*c - intersection).norm() < 0.01);
+        let is_duplicate = crossings
+            .iter()
+            .any(|c: &Point3| (*c - intersection).norm() < 0.01);
         if !is_duplicate {
             crossings.push(intersection);
         }
@@ -622,7 +621,10 @@ pub fn split_planar_face_by_circle(
     let hole_loop = brep.topology.add_loop(&hole_hes);
     brep.topology.faces[outer_face].inner_loops.push(hole_loop);
 
-    // Copy existing inner loops from the original face to preserve previous holes
+    // Copy existing inner loops from the original face to preserve previous
+    // holes. A hole lying inside the new circle belongs to the disk sub-face
+    // (e.g. a bore opening inside a countersink circle), the rest stay with
+    // the outer face.
     let existing_inner_loops = brep.topology.faces[face_id].inner_loops.clone();
     for existing_loop in existing_inner_loops {
         // Re-create the inner loop with new half-edges for the new face
@@ -632,6 +634,19 @@ pub fn split_planar_face_by_circle(
             .map(|he| brep.topology.vertices[brep.topology.half_edges[he].origin].point)
             .collect();
 
+        let target_face = match loop_verts_existing.first() {
+            Some(p) => {
+                let d = *p - circle.center;
+                let in_plane = d - d.dot(circle.normal.as_ref()) * circle.normal.into_inner();
+                if in_plane.norm() < circle.radius {
+                    inner_face
+                } else {
+                    outer_face
+                }
+            }
+            None => outer_face,
+        };
+
         let new_verts: Vec<_> = loop_verts_existing
             .iter()
             .map(|p| find_or_create_vertex(brep, p, tolerance))
@@ -643,7 +658,7 @@ pub fn split_planar_face_by_circle(
             .collect();
 
         let new_loop = brep.topology.add_loop(&new_hes);
-        brep.topology.faces[outer_face].inner_loops.push(new_loop);
+        brep.topology.faces[target_face].inner_loops.push(new_loop);
     }
 
     // Add twin edges between inner face circle and outer face hole
@@ -681,6 +696,227 @@ pub fn split_planar_face_by_circle(
     }
 }
 
+/// Check if a sampled curve forms a closed ring.
+///
+/// SSI returns oblique plane–cylinder intersections (ellipses) as `Sampled`
+/// polylines without a duplicated closing point, so "closed" means the gap
+/// between the last and first samples is comparable to the sample spacing.
+pub fn is_closed_sampled_ring(points: &[Point3]) -> bool {
+    if points.len() < 8 {
+        return false;
+    }
+    let total: f64 = points.windows(2).map(|w| (w[1] - w[0]).norm()).sum();
+    let avg_spacing = total / (points.len() - 1) as f64;
+    if avg_spacing < 1e-12 {
+        return false;
+    }
+    (points[points.len() - 1] - points[0]).norm() < 2.0 * avg_spacing
+}
+
+/// Split a planar face along a closed sampled intersection curve.
+///
+/// Oblique plane–cylinder intersections produce an ellipse, which SSI returns
+/// as a closed `Sampled` polyline. When the full ring lies inside the face,
+/// the face splits like the circle case into:
+/// - An inner face bounded by the sampled ring
+/// - An outer face (the original polygon with the ring as a hole)
+///
+/// Rings that are only partially inside the face are left unsplit; the
+/// trim-based path handles those.
+pub fn split_planar_face_by_sampled(
+    brep: &mut BRepSolid,
+    face_id: FaceId,
+    points: &[Point3],
+) -> SplitResult {
+    let face = &brep.topology.faces[face_id];
+    let surface_index = face.surface_index;
+    let orientation = face.orientation;
+    let outer_loop = face.outer_loop;
+
+    let loop_hes: Vec<_> = brep.topology.loop_half_edges(outer_loop).collect();
+    let loop_verts: Vec<Point3> = loop_hes
+        .iter()
+        .map(|&he| brep.topology.vertices[brep.topology.half_edges[he].origin].point)
+        .collect();
+
+    if loop_verts.len() < 3 {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+
+    // Drop degenerate samples and a duplicated closing point, if present
+    let mut ring = remove_consecutive_duplicates(points, 1e-9);
+    if ring.len() > 1 && (ring[ring.len() - 1] - ring[0]).norm() < 1e-9 {
+        ring.pop();
+    }
+    if ring.len() < 3 {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+
+    // Project to the face plane and require every ring sample strictly inside
+    // the polygon — mirrors circle_fully_inside_polygon for the sampled case
+    let e1 = loop_verts[1] - loop_verts[0];
+    let e2 = loop_verts[2] - loop_verts[0];
+    let face_normal = e1.cross(&e2);
+    if face_normal.norm() < 1e-12 {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+    let u_axis = e1.normalize();
+    let v_axis = face_normal.cross(&e1).normalize();
+    let origin = loop_verts[0];
+
+    let project = |p: &Point3| -> (f64, f64) {
+        let d = *p - origin;
+        (d.dot(&u_axis), d.dot(&v_axis))
+    };
+
+    let poly_2d: Vec<(f64, f64)> = loop_verts.iter().map(&project).collect();
+    let ring_2d: Vec<(f64, f64)> = ring.iter().map(&project).collect();
+    if ring_2d
+        .iter()
+        .any(|&(x, y)| !point_in_polygon_2d(x, y, &poly_2d))
+    {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+
+    // The sampled ring arrives in an arbitrary winding (SSI samples around
+    // the cylinder axis). The inner face's loop must wind like the parent
+    // outer loop, and the hole loop must wind opposite to it.
+    let signed_area = |pts: &[(f64, f64)]| -> f64 {
+        let mut area = 0.0;
+        for i in 0..pts.len() {
+            let j = (i + 1) % pts.len();
+            area += pts[i].0 * pts[j].1 - pts[j].0 * pts[i].1;
+        }
+        area / 2.0
+    };
+
+    let outer_area = signed_area(&poly_2d);
+    let ring_area = signed_area(&ring_2d);
+    if (outer_area > 0.0) != (ring_area > 0.0) {
+        ring.reverse();
+    }
+
+    // Create inner face bounded by the ring, oriented like the parent face
+    let tolerance = 1e-6;
+    let inner_verts: Vec<_> = ring
+        .iter()
+        .map(|p| find_or_create_vertex(brep, p, tolerance))
+        .collect();
+
+    let inner_hes: Vec<_> = inner_verts
+        .iter()
+        .map(|&v| brep.topology.add_half_edge(v))
+        .collect();
+
+    let inner_loop = brep.topology.add_loop(&inner_hes);
+    let inner_face = brep
+        .topology
+        .add_face(inner_loop, surface_index, orientation);
+
+    let hole_verts: Vec<Point3> = ring.iter().rev().cloned().collect();
+
+    let outer_inner_verts: Vec<_> = hole_verts
+        .iter()
+        .map(|p| find_or_create_vertex(brep, p, tolerance))
+        .collect();
+
+    // Create new outer loop (copy of original)
+    let outer_verts: Vec<_> = loop_verts
+        .iter()
+        .map(|p| find_or_create_vertex(brep, p, tolerance))
+        .collect();
+
+    let outer_hes: Vec<_> = outer_verts
+        .iter()
+        .map(|&v| brep.topology.add_half_edge(v))
+        .collect();
+
+    let new_outer_loop = brep.topology.add_loop(&outer_hes);
+    let outer_face = brep
+        .topology
+        .add_face(new_outer_loop, surface_index, orientation);
+
+    let hole_hes: Vec<_> = outer_inner_verts
+        .iter()
+        .map(|&v| brep.topology.add_half_edge(v))
+        .collect();
+
+    let hole_loop = brep.topology.add_loop(&hole_hes);
+    brep.topology.faces[outer_face].inner_loops.push(hole_loop);
+
+    // Preserve previous holes: loops inside the ring belong to the inner
+    // face, the rest stay with the outer face
+    let existing_inner_loops = brep.topology.faces[face_id].inner_loops.clone();
+    for existing_loop in existing_inner_loops {
+        let loop_verts_existing: Vec<Point3> = brep
+            .topology
+            .loop_half_edges(existing_loop)
+            .map(|he| brep.topology.vertices[brep.topology.half_edges[he].origin].point)
+            .collect();
+
+        let target_face = match loop_verts_existing.first() {
+            Some(p) => {
+                let (x, y) = project(p);
+                if point_in_polygon_2d(x, y, &ring_2d) {
+                    inner_face
+                } else {
+                    outer_face
+                }
+            }
+            None => outer_face,
+        };
+
+        let new_verts: Vec<_> = loop_verts_existing
+            .iter()
+            .map(|p| find_or_create_vertex(brep, p, tolerance))
+            .collect();
+
+        let new_hes: Vec<_> = new_verts
+            .iter()
+            .map(|&v| brep.topology.add_half_edge(v))
+            .collect();
+
+        let new_loop = brep.topology.add_loop(&new_hes);
+        brep.topology.faces[target_face].inner_loops.push(new_loop);
+    }
+
+    // Twin the ring edges between the inner face and the outer face's hole
+    let n = inner_hes.len();
+    for i in 0..n {
+        let outer_he = hole_hes[(n - 1 - i) % n];
+        brep.topology.add_edge(inner_hes[i], outer_he);
+    }
+
+    // Add the new faces to the shell
+    if let Some(shell_id) = brep.topology.faces[face_id].shell {
+        brep.topology.shells[shell_id].faces.push(inner_face);
+        brep.topology.shells[shell_id].faces.push(outer_face);
+
+        brep.topology.faces[inner_face].shell = Some(shell_id);
+        brep.topology.faces[outer_face].shell = Some(shell_id);
+
+        brep.topology.shells[shell_id]
+            .faces
+            .retain(|&f| f != face_id);
+    }
+
+    // Remove the original face. Unlike the circle case there is no analytic
+    // 3D curve to record for a sampled ring.
+    brep.topology.faces.remove(face_id);
+
+    SplitResult {
+        sub_faces: vec![inner_face, outer_face],
+    }
+}
+
 /// Check if a circle is FULLY inside a polygon (in 3D, assumes coplanar).
 ///
 /// Returns true only if the entire circle is contained within the polygon.
@@ -837,7 +1073,14 @@ fn point_in_polygon_2d(px: f64, py: f64, polygon: &[(f64, f64)]) -> bool {
 }
 
 /// Distance from point to line segment (2D).
-pub(crate) fn point_to_segment_dist_2d(px: f64, py: f64, x1: f64, y1: f64, x2: f64, y2: f64) -> f64 {
+pub(crate) fn point_to_segment_dist_2d(
+    px: f64,
+    py: f64,
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+) -> f64 {
     let dx = x2 - x1;
     let dy = y2 - y1;
     let len2 = dx * dx + dy * dy;
@@ -970,11 +1213,13 @@ fn find_circle_polygon_intersections(
             let point_3d = origin_3d + px * u_axis + py * v_axis;
 
             // Avoid duplicate intersections (at corners)
-            let is_duplicate = intersections.iter().any(|other: &CirclePolygonIntersection| {
-                let dist_2d =
-                    ((px - other.point_2d.0).powi(2) + (py - other.point_2d.1).powi(2)).sqrt();
-                dist_2d < 0.01
-            });
+            let is_duplicate = intersections
+                .iter()
+                .any(|other: &CirclePolygonIntersection| {
+                    let dist_2d =
+                        ((px - other.point_2d.0).powi(2) + (py - other.point_2d.1).powi(2)).sqrt();
+                    dist_2d < 0.01
+                });
 
             if !is_duplicate {
                 intersections.push(CirclePolygonIntersection {
@@ -1180,7 +1425,11 @@ pub fn split_planar_face_by_arc(
     // Add arc points (from inside_start to inside_end, forward direction)
     // arc_points_3d goes from inside_start to inside_end, so iterate forward
     // This completes the loop: ... → inside_start → arc → (closes to inside_end)
-    for pt in arc_points_3d.iter().skip(1).take(arc_points_3d.len().saturating_sub(2)) {
+    for pt in arc_points_3d
+        .iter()
+        .skip(1)
+        .take(arc_points_3d.len().saturating_sub(2))
+    {
         face1_points.push(*pt);
     }
 
@@ -1283,7 +1532,10 @@ pub fn split_planar_face_by_arc(
 ///
 /// Returns true if the circle crosses the polygon boundary at exactly 2 points,
 /// meaning it's only partially inside and needs arc-based splitting.
-fn circle_partially_inside_polygon(polygon: &[Point3], circle: &vcad_kernel_geom::Circle3d) -> bool {
+fn circle_partially_inside_polygon(
+    polygon: &[Point3],
+    circle: &vcad_kernel_geom::Circle3d,
+) -> bool {
     if polygon.len() < 3 {
         return false;
     }
@@ -1331,6 +1583,7 @@ fn circle_partially_inside_polygon(polygon: &[Point3], circle: &vcad_kernel_geom
 /// This dispatches to the appropriate split method based on the curve type:
 /// - Circle: creates inner disk + outer face with hole
 /// - Line: entry/exit split (existing implementation)
+/// - Closed sampled ring (oblique ellipse): inner face + outer face with hole
 pub fn split_planar_face(
     brep: &mut BRepSolid,
     face_id: FaceId,
@@ -1367,6 +1620,9 @@ pub fn split_planar_face(
                 }
             }
         }
+        IntersectionCurve::Sampled(points) if is_closed_sampled_ring(points) => {
+            split_planar_face_by_sampled(brep, face_id, points)
+        }
         IntersectionCurve::TwoLines(line1, _line2) => {
             // TwoLines should be expanded before calling this function.
             // If we get here, just process the first line.
@@ -1591,60 +1847,35 @@ pub fn split_cylindrical_face_by_circle(
     }
 }
 
-/// Compute the U parameter for a point on a cylinder surface.
-fn compute_cylinder_u(point: &Point3, cyl: &vcad_kernel_geom::CylinderSurface) -> f64 {
-    let d = *point - cyl.center;
-    let ref_dir = cyl.ref_dir.as_ref();
-    let y_dir = cyl.axis.as_ref().cross(ref_dir);
-    let u = d.dot(&y_dir).atan2(d.dot(ref_dir));
-    if u < 0.0 { u + 2.0 * std::f64::consts::PI } else { u }
-}
-
-/// Check if angle `u` is within the range from `u_start` to `u_end` (CCW direction).
-/// Handles wrap-around at 2π. For wrap-around cases, u_end may be > 2π.
-fn angle_in_range(u: f64, u_start: f64, u_end: f64) -> bool {
-    let tol = 0.01;
-    let two_pi = 2.0 * std::f64::consts::PI;
-
-    // If u_end > 2π, the face wraps around. Check if u is in [u_start, 2π) or [0, u_end - 2π)
-    if u_end > two_pi {
-        let end_wrapped = u_end - two_pi;
-        // u is in range if it's in [u_start, 2π) or [0, end_wrapped)
-        (u > u_start + tol && u < two_pi - tol) || (u > tol && u < end_wrapped - tol)
-    } else if u_end >= u_start {
-        // Simple case: range doesn't wrap around
-        u > u_start + tol && u < u_end - tol
-    } else {
-        // Range wraps around 2π (e.g., from 5.5 to 0.5)
-        u > u_start + tol || u < u_end - tol
-    }
-}
-
-/// Split a cylindrical face along a line intersection curve.
-///
-/// When a plane parallel to the cylinder axis intersects the cylinder,
-/// the result is a vertical line on the cylinder surface. In the cylinder's
-/// UV space `[0, 2π] × [v_min, v_max]`, this line becomes a vertical line
-/// at constant u = u_split.
+/// Split a cylindrical face along a closed sampled intersection curve.
 ///
-/// This function splits the cylindrical face into two parts:
-/// - One part: `[u_min, u_split] × [v_min, v_max]`
-/// - Other part: `[u_split, u_max] × [v_min, v_max]`
+/// Oblique planes and crossing cylinders cut the lateral surface in closed
+/// curves returned by SSI as `Sampled` polylines. In the cylinder's UV space
+/// such a curve is a graph `v = f(u)` spanning the full `[0, 2π]` angular
+/// range, so the face splits into:
+/// - A lower band from the bottom boundary up to the curve
+/// - An upper band from the curve up to the top boundary
 ///
-/// Works for both:
-/// - Full lateral faces (single seam vertex, u spans 0 to 2π)
-/// - Partial lateral faces (4 corner vertices from previous splits)
-pub fn split_cylindrical_face_by_line(
+/// Both bands share the sampled curve edge and a seam edge at `u = 0`, the
+/// same structure `split_cylindrical_face_by_circle` produces for horizontal
+/// cuts. Handled faces are bands — a bottom chain, a seam edge up, a top
+/// chain, a seam edge down — which covers the canonical full lateral face as
+/// well as the bands this split itself produces, so stacked curves (the two
+/// branches of a crossing-cylinder intersection, say) each split in turn.
+/// The curve may touch a band boundary at isolated tangent points, as the
+/// branches of an equal-radius crossing do, but must not leave the band.
+pub fn split_cylindrical_face_by_sampled(
     brep: &mut BRepSolid,
     face_id: FaceId,
-    line: &vcad_kernel_geom::Line3d,
+    points: &[Point3],
 ) -> SplitResult {
+    let two_pi = 2.0 * std::f64::consts::PI;
+
     let face = &brep.topology.faces[face_id];
     let surface_index = face.surface_index;
     let orientation = face.orientation;
     let surface = &brep.geometry.surfaces[surface_index];
 
-    // Verify surface is a cylinder
     let cyl = match surface
         .as_any()
         .downcast_ref::<vcad_kernel_geom::CylinderSurface>()
@@ -1657,66 +1888,392 @@ pub fn split_cylindrical_face_by_line(
         }
     };
 
-    let ref_dir = cyl.ref_dir.as_ref();
-    let y_dir = cyl.axis.as_ref().cross(ref_dir);
-
-    // Find the U parameter of the split line
-    let d = line.origin - cyl.center;
-    let u_split = d.dot(&y_dir).atan2(d.dot(ref_dir));
-    let u_split = if u_split < 0.0 {
-        u_split + 2.0 * std::f64::consts::PI
-    } else {
-        u_split
-    };
-
-    // Get the current face's vertex bounds
+    // The seam half-edges are the unique pair in the loop twinned with each
+    // other; the two arcs between them are the bottom and top boundary
+    // chains
     let loop_hes: Vec<_> = brep.topology.loop_half_edges(face.outer_loop).collect();
-    if loop_hes.is_empty() {
+    let he_count = loop_hes.len();
+    if he_count < 4 {
         return SplitResult {
             sub_faces: vec![face_id],
         };
     }
-
-    // Collect all unique vertices with their (v, u) coordinates
-    let mut v_min = f64::INFINITY;
-    let mut v_max = f64::NEG_INFINITY;
-    let mut all_verts: Vec<(vcad_kernel_topo::VertexId, f64, f64)> = Vec::new(); // (vid, v, u)
-
-    for &he_id in &loop_hes {
-        let vid = brep.topology.half_edges[he_id].origin;
-        let point = brep.topology.vertices[vid].point;
-        let v = (point - cyl.center).dot(cyl.axis.as_ref());
-        v_min = v_min.min(v);
-        v_max = v_max.max(v);
-
-        // Only add if not duplicate
-        if !all_verts.iter().any(|(id, _, _)| *id == vid) {
-            let u = compute_cylinder_u(&point, &cyl);
-            all_verts.push((vid, v, u));
+    let mut seam_positions = Vec::new();
+    for (i, &he_id) in loop_hes.iter().enumerate() {
+        if let Some(twin) = brep.topology.half_edges[he_id].twin {
+            if loop_hes.contains(&twin) {
+                seam_positions.push(i);
+            }
         }
     }
-
-    // Separate into top and bottom vertices
-    let bottom_verts: Vec<_> = all_verts.iter()
+    if seam_positions.len() != 2 {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+    let (s0, s1) = (seam_positions[0], seam_positions[1]);
+    if s1 - s0 < 2 || s0 + he_count - s1 < 2 {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+
+    // Chain vertices in loop order between two seam half-edges, including
+    // the seam endpoints on each side
+    let chain_vertices = |from: usize, to: usize| -> Vec<VertexId> {
+        let mut vids = Vec::new();
+        let mut i = (from + 1) % he_count;
+        while i != to {
+            vids.push(brep.topology.half_edges[loop_hes[i]].origin);
+            i = (i + 1) % he_count;
+        }
+        vids.push(brep.topology.half_edges[loop_hes[to]].origin);
+        vids
+    };
+    let uv_of = |vid: VertexId| -> (f64, f64) {
+        let p = brep.topology.vertices[vid].point;
+        (
+            compute_cylinder_u(&p, &cyl),
+            (p - cyl.center).dot(cyl.axis.as_ref()),
+        )
+    };
+    let mean_v = |chain: &[VertexId]| -> f64 {
+        chain.iter().map(|&vid| uv_of(vid).1).sum::<f64>() / chain.len() as f64
+    };
+
+    let chain_a = chain_vertices(s0, s1);
+    let chain_b = chain_vertices(s1, s0);
+    let (bottom_chain, top_chain) = if mean_v(&chain_a) <= mean_v(&chain_b) {
+        (chain_a, chain_b)
+    } else {
+        (chain_b, chain_a)
+    };
+
+    // Piecewise-linear v(u) graph of a chain, duplicating seam vertices at
+    // both u = 0 and u = 2π so interpolation covers the full turn
+    let chain_graph = |chain: &[VertexId]| -> Vec<(f64, f64)> {
+        let mut graph = Vec::new();
+        for &vid in chain {
+            let (u, v) = uv_of(vid);
+            if u < 1e-7 || u > two_pi - 1e-7 {
+                graph.push((0.0, v));
+                graph.push((two_pi, v));
+            } else {
+                graph.push((u, v));
+            }
+        }
+        graph.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        graph
+    };
+    let interp = |graph: &[(f64, f64)], u: f64| -> f64 {
+        match graph.iter().position(|&(gu, _)| gu >= u) {
+            None => graph.last().map(|&(_, v)| v).unwrap_or(0.0),
+            Some(0) => graph[0].1,
+            Some(i) => {
+                let (u0, v0) = graph[i - 1];
+                let (u1, v1) = graph[i];
+                if u1 - u0 < 1e-12 {
+                    v1
+                } else {
+                    v0 + (v1 - v0) * (u - u0) / (u1 - u0)
+                }
+            }
+        }
+    };
+    let bottom_graph = chain_graph(&bottom_chain);
+    let top_graph = chain_graph(&top_chain);
+
+    // Map samples to (u, v); the curve must stay within the band, touching
+    // a boundary chain at most at isolated tangent points
+    let band_tol = 1e-6;
+    let mut samples: Vec<(f64, Point3)> = Vec::with_capacity(points.len());
+    let mut clears_bottom = false;
+    let mut clears_top = false;
+    for p in points {
+        let u = compute_cylinder_u(p, &cyl);
+        let v = (*p - cyl.center).dot(cyl.axis.as_ref());
+        let v_bot = interp(&bottom_graph, u);
+        let v_top = interp(&top_graph, u);
+        if v < v_bot - band_tol || v > v_top + band_tol {
+            return SplitResult {
+                sub_faces: vec![face_id],
+            };
+        }
+        clears_bottom |= v > v_bot + band_tol;
+        clears_top |= v < v_top - band_tol;
+        samples.push((u, *p));
+    }
+    // A curve glued to one of the chains would leave a zero-area band
+    if !clears_bottom || !clears_top {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+
+    samples.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    samples.dedup_by(|a, b| (a.0 - b.0).abs() < 1e-9);
+    if samples.len() < 8 {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+
+    // The curve must wrap the full circumference: no large angular gaps,
+    // including across the seam
+    for i in 0..samples.len() {
+        let gap = if i + 1 < samples.len() {
+            samples[i + 1].0 - samples[i].0
+        } else {
+            samples[0].0 + two_pi - samples[i].0
+        };
+        if gap > 1.0 {
+            return SplitResult {
+                sub_faces: vec![face_id],
+            };
+        }
+    }
+
+    // Seam crossing at u = 0: reuse the first sample if it sits on the seam,
+    // otherwise interpolate across the wrap and project back onto the
+    // cylinder (u = 0 means the point lies along ref_dir)
+    let (seam_v_coord, ring): (f64, Vec<Point3>) = if samples[0].0 < 1e-6 {
+        let seam = samples[0].1;
+        (
+            (seam - cyl.center).dot(cyl.axis.as_ref()),
+            samples[1..].iter().map(|&(_, p)| p).collect(),
+        )
+    } else {
+        let (u_last, p_last) = samples[samples.len() - 1];
+        let (u_first, p_first) = samples[0];
+        let t = (two_pi - u_last) / (two_pi - u_last + u_first);
+        let lerp = p_last + t * (p_first - p_last);
+        (
+            (lerp - cyl.center).dot(cyl.axis.as_ref()),
+            samples.iter().map(|&(_, p)| p).collect(),
+        )
+    };
+
+    let seam_point =
+        cyl.center + cyl.radius * cyl.ref_dir.as_ref() + seam_v_coord * cyl.axis.as_ref();
+
+    let bot_end = *bottom_chain.last().expect("non-empty chain");
+    let top_end = *top_chain.last().expect("non-empty chain");
+
+    let tolerance = 1e-6;
+    let seam_vid = find_or_create_vertex(brep, &seam_point, tolerance);
+    let ring_vids: Vec<_> = ring
+        .iter()
+        .map(|p| find_or_create_vertex(brep, p, tolerance))
+        .collect();
+    let n = ring_vids.len();
+
+    // Lower band: bottom chain, seam up, curve traversed in descending u
+    // (from the seam at u = 2π back to u = 0), seam down
+    let mut lower_hes = Vec::with_capacity(bottom_chain.len() + n + 2);
+    for &vid in &bottom_chain[..bottom_chain.len() - 1] {
+        lower_hes.push(brep.topology.add_half_edge(vid));
+    }
+    let he_lower_seam_up = brep.topology.add_half_edge(bot_end);
+    lower_hes.push(he_lower_seam_up);
+    let mut lower_curve_hes = Vec::with_capacity(n + 1);
+    lower_curve_hes.push(brep.topology.add_half_edge(seam_vid));
+    for i in (0..n).rev() {
+        lower_curve_hes.push(brep.topology.add_half_edge(ring_vids[i]));
+    }
+    lower_hes.extend(&lower_curve_hes);
+    let he_lower_seam_down = brep.topology.add_half_edge(seam_vid);
+    lower_hes.push(he_lower_seam_down);
+    let lower_loop = brep.topology.add_loop(&lower_hes);
+    let lower_face = brep
+        .topology
+        .add_face(lower_loop, surface_index, orientation);
+
+    // Upper band: curve traversed in ascending u, seam up, top chain,
+    // seam down
+    let mut upper_curve_hes = Vec::with_capacity(n + 1);
+    upper_curve_hes.push(brep.topology.add_half_edge(seam_vid));
+    for &vid in &ring_vids {
+        upper_curve_hes.push(brep.topology.add_half_edge(vid));
+    }
+    let mut upper_hes = upper_curve_hes.clone();
+    let he_upper_seam_up = brep.topology.add_half_edge(seam_vid);
+    upper_hes.push(he_upper_seam_up);
+    for &vid in &top_chain[..top_chain.len() - 1] {
+        upper_hes.push(brep.topology.add_half_edge(vid));
+    }
+    let he_upper_seam_down = brep.topology.add_half_edge(top_end);
+    upper_hes.push(he_upper_seam_down);
+    let upper_loop = brep.topology.add_loop(&upper_hes);
+    let upper_face = brep
+        .topology
+        .add_face(upper_loop, surface_index, orientation);
+
+    // Twin edges: the seam edges within each band, and each curve segment
+    // between the two bands (lower walks the curve backwards, so segment k
+    // of the lower band twins segment n - k of the upper band)
+    brep.topology.add_edge(he_lower_seam_up, he_lower_seam_down);
+    brep.topology.add_edge(he_upper_seam_up, he_upper_seam_down);
+    for (k, &lower_he) in lower_curve_hes.iter().enumerate() {
+        brep.topology.add_edge(lower_he, upper_curve_hes[n - k]);
+    }
+
+    // Add the new faces to the shell
+    if let Some(shell_id) = brep.topology.faces[face_id].shell {
+        brep.topology.shells[shell_id].faces.push(lower_face);
+        brep.topology.shells[shell_id].faces.push(upper_face);
+
+        brep.topology.faces[lower_face].shell = Some(shell_id);
+        brep.topology.faces[upper_face].shell = Some(shell_id);
+
+        brep.topology.shells[shell_id]
+            .faces
+            .retain(|&f| f != face_id);
+    }
+
+    // Remove the original face
+    brep.topology.faces.remove(face_id);
+
+    SplitResult {
+        sub_faces: vec![lower_face, upper_face],
+    }
+}
+
+/// Compute the U parameter for a point on a cylinder surface.
+fn compute_cylinder_u(point: &Point3, cyl: &vcad_kernel_geom::CylinderSurface) -> f64 {
+    let d = *point - cyl.center;
+    let ref_dir = cyl.ref_dir.as_ref();
+    let y_dir = cyl.axis.as_ref().cross(ref_dir);
+    let u = d.dot(&y_dir).atan2(d.dot(ref_dir));
+    if u < 0.0 {
+        u + 2.0 * std::f64::consts::PI
+    } else {
+        u
+    }
+}
+
+/// Check if angle `u` is within the range from `u_start` to `u_end` (CCW direction).
+/// Handles wrap-around at 2π. For wrap-around cases, u_end may be > 2π.
+fn angle_in_range(u: f64, u_start: f64, u_end: f64) -> bool {
+    let tol = 0.01;
+    let two_pi = 2.0 * std::f64::consts::PI;
+
+    // If u_end > 2π, the face wraps around. Check if u is in [u_start, 2π) or [0, u_end - 2π)
+    if u_end > two_pi {
+        let end_wrapped = u_end - two_pi;
+        // u is in range if it's in [u_start, 2π) or [0, end_wrapped)
+        (u > u_start + tol && u < two_pi - tol) || (u > tol && u < end_wrapped - tol)
+    } else if u_end >= u_start {
+        // Simple case: range doesn't wrap around
+        u > u_start + tol && u < u_end - tol
+    } else {
+        // Range wraps around 2π (e.g., from 5.5 to 0.5)
+        u > u_start + tol || u < u_end - tol
+    }
+}
+
+/// Split a cylindrical face along a line intersection curve.
+///
+/// When a plane parallel to the cylinder axis intersects the cylinder,
+/// the result is a vertical line on the cylinder surface. In the cylinder's
+/// UV space `[0, 2π] × [v_min, v_max]`, this line becomes a vertical line
+/// at constant u = u_split.
+///
+/// This function splits the cylindrical face into two parts:
+/// - One part: `[u_min, u_split] × [v_min, v_max]`
+/// - Other part: `[u_split, u_max] × [v_min, v_max]`
+///
+/// Works for both:
+/// - Full lateral faces (single seam vertex, u spans 0 to 2π)
+/// - Partial lateral faces (4 corner vertices from previous splits)
+pub fn split_cylindrical_face_by_line(
+    brep: &mut BRepSolid,
+    face_id: FaceId,
+    line: &vcad_kernel_geom::Line3d,
+) -> SplitResult {
+    let face = &brep.topology.faces[face_id];
+    let surface_index = face.surface_index;
+    let orientation = face.orientation;
+    let surface = &brep.geometry.surfaces[surface_index];
+
+    // Verify surface is a cylinder
+    let cyl = match surface
+        .as_any()
+        .downcast_ref::<vcad_kernel_geom::CylinderSurface>()
+    {
+        Some(c) => c.clone(),
+        None => {
+            return SplitResult {
+                sub_faces: vec![face_id],
+            };
+        }
+    };
+
+    let ref_dir = cyl.ref_dir.as_ref();
+    let y_dir = cyl.axis.as_ref().cross(ref_dir);
+
+    // Find the U parameter of the split line
+    let d = line.origin - cyl.center;
+    let u_split = d.dot(&y_dir).atan2(d.dot(ref_dir));
+    let u_split = if u_split < 0.0 {
+        u_split + 2.0 * std::f64::consts::PI
+    } else {
+        u_split
+    };
+
+    // Get the current face's vertex bounds
+    let loop_hes: Vec<_> = brep.topology.loop_half_edges(face.outer_loop).collect();
+    if loop_hes.is_empty() {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+
+    // Collect all unique vertices with their (v, u) coordinates
+    let mut v_min = f64::INFINITY;
+    let mut v_max = f64::NEG_INFINITY;
+    let mut all_verts: Vec<(vcad_kernel_topo::VertexId, f64, f64)> = Vec::new(); // (vid, v, u)
+
+    for &he_id in &loop_hes {
+        let vid = brep.topology.half_edges[he_id].origin;
+        let point = brep.topology.vertices[vid].point;
+        let v = (point - cyl.center).dot(cyl.axis.as_ref());
+        v_min = v_min.min(v);
+        v_max = v_max.max(v);
+
+        // Only add if not duplicate
+        if !all_verts.iter().any(|(id, _, _)| *id == vid) {
+            let u = compute_cylinder_u(&point, &cyl);
+            all_verts.push((vid, v, u));
+        }
+    }
+
+    // Separate into top and bottom vertices
+    let bottom_verts: Vec<_> = all_verts
+        .iter()
         .filter(|(_, v, _)| (*v - v_min).abs() < 1e-6)
         .cloned()
         .collect();
-    let top_verts: Vec<_> = all_verts.iter()
+    let top_verts: Vec<_> = all_verts
+        .iter()
         .filter(|(_, v, _)| (*v - v_max).abs() < 1e-6)
         .cloned()
         .collect();
 
-
     // Determine face type and get corner vertices
     let (u_start, u_end, v_start_bot, v_end_bot, v_start_top, v_end_top, is_full_face) =
         if bottom_verts.len() == 1 && top_verts.len() == 1 {
             // Full cylindrical face with single seam vertex at each end
             // U spans from 0 (seam) around to 2π (back to seam)
             let seam_u = bottom_verts[0].2;
-            (seam_u, seam_u + 2.0 * std::f64::consts::PI,
-             bottom_verts[0].0, bottom_verts[0].0,
-             top_verts[0].0, top_verts[0].0,
-             true)
+            (
+                seam_u,
+                seam_u + 2.0 * std::f64::consts::PI,
+                bottom_verts[0].0,
+                bottom_verts[0].0,
+                top_verts[0].0,
+                top_verts[0].0,
+                true,
+            )
         } else if bottom_verts.len() == 2 && top_verts.len() == 2 {
             // Partial cylindrical face with 4 corner vertices
             // Use the loop order to determine the U direction (CCW in UV space)
@@ -1779,10 +2336,15 @@ pub fn split_cylindrical_face_by_line(
                 end_bot.2
             };
 
-            (start_bot.2, end_u,
-             start_bot.0, end_bot.0,
-             start_top.0, end_top.0,
-             false)
+            (
+                start_bot.2,
+                end_u,
+                start_bot.0,
+                end_bot.0,
+                start_top.0,
+                end_top.0,
+                false,
+            )
         } else {
             // Unexpected face structure
             return SplitResult {
@@ -1794,8 +2356,8 @@ pub fn split_cylindrical_face_by_line(
     let in_range = if is_full_face {
         // For full face, any u_split is valid (except exactly at the seam)
         let seam_u = u_start;
-        (u_split - seam_u).abs() > 0.01 &&
-        (u_split - seam_u - 2.0 * std::f64::consts::PI).abs() > 0.01
+        (u_split - seam_u).abs() > 0.01
+            && (u_split - seam_u - 2.0 * std::f64::consts::PI).abs() > 0.01
     } else {
         angle_in_range(u_split, u_start, u_end)
     };
@@ -1831,9 +2393,7 @@ pub fn split_cylindrical_face_by_line(
     let loop1 = brep
         .topology
         .add_loop(&[he1_bot, he1_left, he1_top, he1_right]);
-    let face1 = brep
-        .topology
-        .add_face(loop1, surface_index, orientation);
+    let face1 = brep.topology.add_face(loop1, surface_index, orientation);
 
     // Face 2: arc from split to end
     let he2_bot = brep.topology.add_half_edge(v_split_bottom);
@@ -1844,9 +2404,7 @@ pub fn split_cylindrical_face_by_line(
     let loop2 = brep
         .topology
         .add_loop(&[he2_bot, he2_left, he2_top, he2_right]);
-    let face2 = brep
-        .topology
-        .add_face(loop2, surface_index, orientation);
+    let face2 = brep.topology.add_face(loop2, surface_index, orientation);
 
     // Add twin edges for the shared split line
     brep.topology.add_edge(he1_left, he2_right);
@@ -1882,7 +2440,7 @@ pub fn split_cylindrical_face_by_line(
 /// This dispatches to the appropriate split method based on the curve type:
 /// - Circle: horizontal split (perpendicular plane intersection)
 /// - Line: vertical split (parallel plane intersection)
-/// - Sampled: general oblique split - TODO
+/// - Closed sampled ring: oblique split along an ellipse
 pub fn split_cylindrical_face(
     brep: &mut BRepSolid,
     face_id: FaceId,
@@ -1892,11 +2450,12 @@ pub fn split_cylindrical_face(
         IntersectionCurve::Circle(circle) => {
             split_cylindrical_face_by_circle(brep, face_id, circle)
         }
-        IntersectionCurve::Line(line) => {
-            split_cylindrical_face_by_line(brep, face_id, line)
+        IntersectionCurve::Line(line) => split_cylindrical_face_by_line(brep, face_id, line),
+        IntersectionCurve::Sampled(points) if is_closed_sampled_ring(points) => {
+            split_cylindrical_face_by_sampled(brep, face_id, points)
         }
         IntersectionCurve::Sampled(_points) => {
-            // TODO: Implement general oblique split
+            // Open sampled curves (partial oblique cuts) are not yet handled
             SplitResult {
                 sub_faces: vec![face_id],
             }
@@ -1912,6 +2471,176 @@ pub fn split_cylindrical_face(
     }
 }
 
+// =============================================================================
+// Conical Face Splitting
+// =============================================================================
+
+/// Check if a face's underlying surface is a cone.
+pub fn is_conical_face(brep: &BRepSolid, face_id: FaceId) -> bool {
+    let face = &brep.topology.faces[face_id];
+    let surface = &brep.geometry.surfaces[face.surface_index];
+    surface.surface_type() == vcad_kernel_geom::SurfaceKind::Cone
+}
+
+/// Split a conical face along a circle intersection curve.
+///
+/// A circle coaxial with the cone sits at constant axial height, so in the
+/// cone's UV space it is a horizontal line — exactly the situation
+/// [`split_cylindrical_face_by_circle`] handles for cylinders. The face is
+/// split into two bands that share the circle edge, using the axial
+/// coordinate from the apex in place of the cylinder's height.
+pub fn split_conical_face_by_circle(
+    brep: &mut BRepSolid,
+    face_id: FaceId,
+    circle: &vcad_kernel_geom::Circle3d,
+) -> SplitResult {
+    let face = &brep.topology.faces[face_id];
+    let surface_index = face.surface_index;
+    let orientation = face.orientation;
+    let surface = &brep.geometry.surfaces[surface_index];
+
+    let cone = match surface
+        .as_any()
+        .downcast_ref::<vcad_kernel_geom::ConeSurface>()
+    {
+        Some(c) => c.clone(),
+        None => {
+            return SplitResult {
+                sub_faces: vec![face_id],
+            };
+        }
+    };
+
+    // Axial height of the split circle, measured from the apex
+    let v_split = (circle.center - cone.apex).dot(cone.axis.as_ref());
+
+    let loop_hes: Vec<_> = brep.topology.loop_half_edges(face.outer_loop).collect();
+    if loop_hes.is_empty() {
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+
+    // Axial bounds of the face from its boundary vertices
+    let mut v_min = f64::INFINITY;
+    let mut v_max = f64::NEG_INFINITY;
+    for &he_id in &loop_hes {
+        let v_id = brep.topology.half_edges[he_id].origin;
+        let point = brep.topology.vertices[v_id].point;
+        let v = (point - cone.apex).dot(cone.axis.as_ref());
+        v_min = v_min.min(v);
+        v_max = v_max.max(v);
+    }
+
+    if v_split <= v_min + 1e-9 || v_split >= v_max - 1e-9 {
+        // Circle doesn't cross the face interior
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
+    }
+
+    // Seam point (u=0) at the split height: the cone radius there is
+    // tan(half_angle) · axial height
+    let radius_at_split = v_split * cone.half_angle.tan();
+    let seam_point_at_split =
+        cone.apex + v_split * cone.axis.as_ref() + radius_at_split * cone.ref_dir.as_ref();
+    let v_split_seam = brep.topology.add_vertex(seam_point_at_split);
+
+    // Identify the narrow-end and wide-end seam vertices by axial height.
+    // A pointed cone has its apex vertex at v_min, which works the same way.
+    let mut v_bottom = None;
+    let mut v_top = None;
+    for &he_id in &loop_hes {
+        let vid = brep.topology.half_edges[he_id].origin;
+        let point = brep.topology.vertices[vid].point;
+        let v = (point - cone.apex).dot(cone.axis.as_ref());
+        if (v - v_min).abs() < 1e-9 {
+            v_bottom = Some(vid);
+        }
+        if (v - v_max).abs() < 1e-9 {
+            v_top = Some(vid);
+        }
+    }
+    let (v_bottom, v_top) = match (v_bottom, v_top) {
+        (Some(b), Some(t)) => (b, t),
+        _ => {
+            return SplitResult {
+                sub_faces: vec![face_id],
+            };
+        }
+    };
+
+    // Band nearer the apex: v_min to v_split
+    let he_lower_bot = brep.topology.add_half_edge(v_bottom);
+    let he_lower_seam_up = brep.topology.add_half_edge(v_bottom);
+    let he_lower_split = brep.topology.add_half_edge(v_split_seam);
+    let he_lower_seam_down = brep.topology.add_half_edge(v_split_seam);
+    let lower_loop = brep.topology.add_loop(&[
+        he_lower_bot,
+        he_lower_seam_up,
+        he_lower_split,
+        he_lower_seam_down,
+    ]);
+    let lower_face = brep
+        .topology
+        .add_face(lower_loop, surface_index, orientation);
+
+    // Band away from the apex: v_split to v_max
+    let he_upper_split = brep.topology.add_half_edge(v_split_seam);
+    let he_upper_seam_up = brep.topology.add_half_edge(v_split_seam);
+    let he_upper_top = brep.topology.add_half_edge(v_top);
+    let he_upper_seam_down = brep.topology.add_half_edge(v_top);
+    let upper_loop = brep.topology.add_loop(&[
+        he_upper_split,
+        he_upper_seam_up,
+        he_upper_top,
+        he_upper_seam_down,
+    ]);
+    let upper_face = brep
+        .topology
+        .add_face(upper_loop, surface_index, orientation);
+
+    // Twin edges: the two seams, and the shared split circle
+    brep.topology.add_edge(he_lower_seam_up, he_lower_seam_down);
+    brep.topology.add_edge(he_upper_seam_up, he_upper_seam_down);
+    brep.topology.add_edge(he_lower_split, he_upper_split);
+
+    // Move the new faces into the original face's shell
+    if let Some(shell_id) = brep.topology.faces[face_id].shell {
+        brep.topology.shells[shell_id].faces.push(lower_face);
+        brep.topology.shells[shell_id].faces.push(upper_face);
+        brep.topology.faces[lower_face].shell = Some(shell_id);
+        brep.topology.faces[upper_face].shell = Some(shell_id);
+        brep.topology.shells[shell_id]
+            .faces
+            .retain(|&f| f != face_id);
+    }
+
+    brep.topology.faces.remove(face_id);
+    brep.geometry.add_curve_3d(Box::new(circle.clone()));
+
+    SplitResult {
+        sub_faces: vec![lower_face, upper_face],
+    }
+}
+
+/// Split a conical face by an intersection curve, dispatching on curve type.
+///
+/// Only coaxial circles are handled specially; other curve types keep the
+/// face unchanged (the generic re-trim path handles them).
+pub fn split_conical_face(
+    brep: &mut BRepSolid,
+    face_id: FaceId,
+    curve: &IntersectionCurve,
+) -> SplitResult {
+    match curve {
+        IntersectionCurve::Circle(circle) => split_conical_face_by_circle(brep, face_id, circle),
+        _ => SplitResult {
+            sub_faces: vec![face_id],
+        },
+    }
+}
+
 // =============================================================================
 // Circular Face (Disk) Splitting by Line
 // =============================================================================
@@ -1944,7 +2673,10 @@ pub fn is_circular_disk_face(brep: &BRepSolid, face_id: FaceId) -> bool {
 /// Get the circle parameters of a circular disk face.
 ///
 /// Returns (center, radius, normal) if the face is a valid circular disk.
-pub fn get_disk_circle_params(brep: &BRepSolid, face_id: FaceId) -> Option<(Point3, f64, vcad_kernel_math::Vec3)> {
+pub fn get_disk_circle_params(
+    brep: &BRepSolid,
+    face_id: FaceId,
+) -> Option<(Point3, f64, vcad_kernel_math::Vec3)> {
     let face = &brep.topology.faces[face_id];
     let surface = &brep.geometry.surfaces[face.surface_index];
 
@@ -1991,7 +2723,11 @@ pub fn split_circular_face_by_line(
     // Get disk parameters
     let (center, radius, normal) = match get_disk_circle_params(brep, face_id) {
         Some(params) => params,
-        None => return SplitResult { sub_faces: vec![face_id] },
+        None => {
+            return SplitResult {
+                sub_faces: vec![face_id],
+            }
+        }
     };
 
     let face = &brep.topology.faces[face_id];
@@ -2008,7 +2744,9 @@ pub fn split_circular_face_by_line(
 
     // Check if line is parallel to the plane normal (no intersection)
     if line_dir.dot(&normal).abs() > 0.999 {
-        return SplitResult { sub_faces: vec![face_id] };
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
     }
 
     // Project line onto the plane
@@ -2022,7 +2760,9 @@ pub fn split_circular_face_by_line(
 
     // If line doesn't intersect the circle, no split needed
     if dist_to_center > radius - 1e-9 {
-        return SplitResult { sub_faces: vec![face_id] };
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
     }
 
     // Compute intersection points with circle
@@ -2037,11 +2777,17 @@ pub fn split_circular_face_by_line(
     let surface = &brep.geometry.surfaces[surface_index];
     let plane = match surface.as_any().downcast_ref::<vcad_kernel_geom::Plane>() {
         Some(p) => p,
-        None => return SplitResult { sub_faces: vec![face_id] },
+        None => {
+            return SplitResult {
+                sub_faces: vec![face_id],
+            }
+        }
     };
 
     if plane.signed_distance(&p1).abs() > 0.1 || plane.signed_distance(&p2).abs() > 0.1 {
-        return SplitResult { sub_faces: vec![face_id] };
+        return SplitResult {
+            sub_faces: vec![face_id],
+        };
     }
 
     // Compute angles of intersection points relative to center
@@ -2056,8 +2802,16 @@ pub fn split_circular_face_by_line(
     let angle2 = to_p2.dot(&y_axis).atan2(to_p2.dot(&x_axis));
 
     // Normalize angles to [0, 2π)
-    let angle1 = if angle1 < 0.0 { angle1 + 2.0 * std::f64::consts::PI } else { angle1 };
-    let angle2 = if angle2 < 0.0 { angle2 + 2.0 * std::f64::consts::PI } else { angle2 };
+    let angle1 = if angle1 < 0.0 {
+        angle1 + 2.0 * std::f64::consts::PI
+    } else {
+        angle1
+    };
+    let angle2 = if angle2 < 0.0 {
+        angle2 + 2.0 * std::f64::consts::PI
+    } else {
+        angle2
+    };
 
     // Order angles so we know which arc is which
     let (start_angle, end_angle, start_pt, end_pt) = if angle1 < angle2 {
@@ -2116,7 +2870,10 @@ pub fn split_circular_face_by_line(
     face1_verts.push(v_end);
 
     // Create half-edges and loop for face 1
-    let hes1: Vec<_> = face1_verts.iter().map(|&v| brep.topology.add_half_edge(v)).collect();
+    let hes1: Vec<_> = face1_verts
+        .iter()
+        .map(|&v| brep.topology.add_half_edge(v))
+        .collect();
     let loop1 = brep.topology.add_loop(&hes1);
     let face1 = brep.topology.add_face(loop1, surface_index, orientation);
 
@@ -2129,7 +2886,10 @@ pub fn split_circular_face_by_line(
     face2_verts.push(v_start);
 
     // Create half-edges and loop for face 2
-    let hes2: Vec<_> = face2_verts.iter().map(|&v| brep.topology.add_half_edge(v)).collect();
+    let hes2: Vec<_> = face2_verts
+        .iter()
+        .map(|&v| brep.topology.add_half_edge(v))
+        .collect();
     let loop2 = brep.topology.add_loop(&hes2);
     let face2 = brep.topology.add_face(loop2, surface_index, orientation);
 
@@ -2150,25 +2910,233 @@ pub fn split_circular_face_by_line(
         brep.topology.faces[face2].shell = Some(shell_id);
 
         // Remove original face from shell
-        brep.topology.shells[shell_id].faces.retain(|&f| f != face_id);
+        brep.topology.shells[shell_id]
+            .faces
+            .retain(|&f| f != face_id);
     }
 
     // Remove the original face
     brep.topology.faces.remove(face_id);
 
     // Add 3D curve for the split line (chord)
-    brep.geometry.add_curve_3d(Box::new(vcad_kernel_geom::Line3d::from_points(start_pt, end_pt)));
+    brep.geometry
+        .add_curve_3d(Box::new(vcad_kernel_geom::Line3d::from_points(
+            start_pt, end_pt,
+        )));
 
     SplitResult {
         sub_faces: vec![face1, face2],
     }
 }
 
+/// Split a circular disk face along a coplanar circle that partially
+/// overlaps it.
+///
+/// This is the cap imprint from a crossing cylinder with parallel axes:
+/// the other cylinder's wall meets the cap plane in a circle whose lens
+/// with the disk must become its own face so it can classify separately
+/// (it is ON the other solid's cap). The disk splits into:
+///
+/// - The lens: the boundary arc nearest the circle's center plus the arc
+///   of the circle inside the disk
+/// - The remainder: the complementary boundary arc closed by the same
+///   circle arc traversed the other way
+///
+/// Concentric or fully contained circles are left alone (those need a
+/// hole, not a split), as are circles that miss the disk entirely.
+pub fn split_circular_face_by_circle(
+    brep: &mut BRepSolid,
+    face_id: FaceId,
+    circle: &vcad_kernel_geom::Circle3d,
+    segments: u32,
+) -> SplitResult {
+    use std::f64::consts::PI;
+
+    let unsplit = SplitResult {
+        sub_faces: vec![face_id],
+    };
+
+    let (center, radius, normal) = match get_disk_circle_params(brep, face_id) {
+        Some(params) => params,
+        None => return unsplit,
+    };
+
+    // The circle must lie in the disk's plane
+    if circle.normal.as_ref().dot(&normal).abs() < 0.999
+        || (circle.center - center).dot(&normal).abs() > 1e-6
+    {
+        return unsplit;
+    }
+
+    let face = &brep.topology.faces[face_id];
+    let surface_index = face.surface_index;
+    let orientation = face.orientation;
+
+    let surface = &brep.geometry.surfaces[surface_index];
+    let plane = match surface.as_any().downcast_ref::<vcad_kernel_geom::Plane>() {
+        Some(p) => p,
+        None => return unsplit,
+    };
+    let x_axis = plane.x_dir.normalize();
+    let y_axis = plane.y_dir.normalize();
+
+    // Circle-circle intersection in the plane
+    let offset = circle.center - center;
+    let d = offset.norm();
+    let r1 = circle.radius;
+    if d >= radius + r1 - 1e-9 || d <= (radius - r1).abs() + 1e-9 {
+        return unsplit;
+    }
+
+    // Intersection points at disk angles alpha ± phi, where alpha points
+    // at the circle's center; on the circle they sit at delta ∓ psi with
+    // delta pointing back at the disk's center
+    let alpha = offset.dot(&y_axis).atan2(offset.dot(&x_axis));
+    let cos_phi = (d * d + radius * radius - r1 * r1) / (2.0 * d * radius);
+    let phi = cos_phi.clamp(-1.0, 1.0).acos();
+    let cos_psi = (d * d + r1 * r1 - radius * radius) / (2.0 * d * r1);
+    let psi = cos_psi.clamp(-1.0, 1.0).acos();
+    let delta = alpha + PI;
+
+    let disk_point = |ang: f64| -> Point3 {
+        let (sin_a, cos_a) = ang.sin_cos();
+        snap_point(center + radius * (cos_a * x_axis + sin_a * y_axis))
+    };
+    let circle_point = |ang: f64| -> Point3 {
+        let (sin_a, cos_a) = ang.sin_cos();
+        snap_point(circle.center + r1 * (cos_a * x_axis + sin_a * y_axis))
+    };
+
+    let p_start = disk_point(alpha - phi);
+    let p_end = disk_point(alpha + phi);
+
+    // Angles of the disk's existing boundary vertices (the wall seam for a
+    // cylinder cap). These must reappear in the sub-face polylines so the
+    // wall rims, which end at the seam, can sew against them
+    let seam_angles: Vec<f64> = brep
+        .topology
+        .loop_half_edges(brep.topology.faces[face_id].outer_loop)
+        .map(|he_id| {
+            let p = brep.topology.vertices[brep.topology.half_edges[he_id].origin].point;
+            let d = p - center;
+            d.dot(&y_axis).atan2(d.dot(&x_axis))
+        })
+        .collect();
+
+    let tolerance = 1e-6;
+    let v_start = find_or_create_vertex(brep, &p_start, tolerance);
+    let v_end = find_or_create_vertex(brep, &p_end, tolerance);
+
+    // Segment counts proportional to angular span only: the matching arc
+    // on the other solid's cap subtends the same angle about the same
+    // center, so equal counts make the two polylines vertex-identical and
+    // sewing can pair them edge for edge
+    let seg_count = |span: f64| -> usize {
+        let full = segments.max(8) as f64;
+        ((full * span / (2.0 * PI)).ceil() as usize).max(2)
+    };
+    let n_near = seg_count(2.0 * phi);
+    let n_far = seg_count(2.0 * PI - 2.0 * phi);
+    let n_arc = seg_count(2.0 * psi);
+
+    // Interior arc of the circle, from p_end (delta - psi) CCW to p_start
+    // (delta + psi); both sub-faces share these vertices
+    let mut arc_verts = vec![v_end];
+    for i in 1..n_arc {
+        let t = i as f64 / n_arc as f64;
+        let pt = circle_point(delta - psi + t * 2.0 * psi);
+        arc_verts.push(find_or_create_vertex(brep, &pt, tolerance));
+    }
+    arc_verts.push(v_start);
+
+    // Uniform samples along a disk boundary arc, with any seam vertex that
+    // falls inside the arc spliced in at its exact angle (deduplicated, so
+    // a sample landing on the seam does not produce a zero-length edge)
+    let boundary_arc = |brep: &mut BRepSolid, a0: f64, span: f64, n: usize| -> Vec<VertexId> {
+        let mut rels: Vec<f64> = (1..n).map(|i| i as f64 / n as f64 * span).collect();
+        for &sa in &seam_angles {
+            let rel = (sa - a0).rem_euclid(2.0 * PI);
+            if rel > 1e-9 && rel < span - 1e-9 {
+                rels.push(rel);
+            }
+        }
+        rels.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let mut out: Vec<VertexId> = Vec::with_capacity(rels.len());
+        for rel in rels {
+            let v = find_or_create_vertex(brep, &disk_point(a0 + rel), tolerance);
+            if out.last() != Some(&v) && v != v_start && v != v_end {
+                out.push(v);
+            }
+        }
+        out
+    };
+
+    // Lens face: disk boundary arc from p_start CCW through alpha to
+    // p_end, then the circle arc back to p_start
+    let mut lens_verts = vec![v_start];
+    lens_verts.extend(boundary_arc(brep, alpha - phi, 2.0 * phi, n_near));
+    let lens_arc_base = lens_verts.len();
+    lens_verts.extend(&arc_verts[..arc_verts.len() - 1]);
+
+    let lens_hes: Vec<_> = lens_verts
+        .iter()
+        .map(|&v| brep.topology.add_half_edge(v))
+        .collect();
+    let lens_loop = brep.topology.add_loop(&lens_hes);
+    let lens_face = brep
+        .topology
+        .add_face(lens_loop, surface_index, orientation);
+
+    // Remainder face: disk boundary arc from p_end CCW the long way back
+    // to p_start, then the circle arc reversed
+    let mut rest_verts = vec![v_end];
+    rest_verts.extend(boundary_arc(brep, alpha + phi, 2.0 * PI - 2.0 * phi, n_far));
+    let rest_arc_base = rest_verts.len();
+    rest_verts.extend(arc_verts[1..].iter().rev());
+
+    let rest_hes: Vec<_> = rest_verts
+        .iter()
+        .map(|&v| brep.topology.add_half_edge(v))
+        .collect();
+    let rest_loop = brep.topology.add_loop(&rest_hes);
+    let rest_face = brep
+        .topology
+        .add_face(rest_loop, surface_index, orientation);
+
+    // Twin the shared circle-arc half-edges. In the lens, arc segment k
+    // runs arc_verts[k] → arc_verts[k+1]; the remainder traverses them in
+    // reverse order
+    let n_seg = arc_verts.len() - 1;
+    for k in 0..n_seg {
+        let lens_he = lens_hes[lens_arc_base + k];
+        let rest_he = rest_hes[rest_arc_base + (n_seg - 1 - k)];
+        brep.topology.add_edge(lens_he, rest_he);
+    }
+
+    // Replace the original face in its shell
+    if let Some(shell_id) = brep.topology.faces[face_id].shell {
+        brep.topology.shells[shell_id].faces.push(lens_face);
+        brep.topology.shells[shell_id].faces.push(rest_face);
+        brep.topology.faces[lens_face].shell = Some(shell_id);
+        brep.topology.faces[rest_face].shell = Some(shell_id);
+        brep.topology.shells[shell_id]
+            .faces
+            .retain(|&f| f != face_id);
+    }
+    brep.topology.faces.remove(face_id);
+
+    brep.geometry.add_curve_3d(Box::new(circle.clone()));
+
+    SplitResult {
+        sub_faces: vec![lens_face, rest_face],
+    }
+}
+
 /// Split a circular disk face along an intersection curve.
 ///
 /// Dispatches to the appropriate method based on curve type:
 /// - Line: splits disk into two arc-bounded segments
-/// - Circle: not applicable (circle on circle is degenerate)
+/// - Circle: splits off the lens with a partially overlapping circle
 /// - Other: no split
 pub fn split_circular_disk_face(
     brep: &mut BRepSolid,
@@ -2177,9 +3145,7 @@ pub fn split_circular_disk_face(
     segments: u32,
 ) -> SplitResult {
     match curve {
-        IntersectionCurve::Line(line) => {
-            split_circular_face_by_line(brep, face_id, line, segments)
-        }
+        IntersectionCurve::Line(line) => split_circular_face_by_line(brep, face_id, line, segments),
         IntersectionCurve::TwoLines(line1, line2) => {
             // Split by the first line, then by the second
             let result1 = split_circular_face_by_line(brep, face_id, line1, segments);
@@ -2209,11 +3175,18 @@ pub fn split_circular_disk_face(
                     all_faces.extend(result2.sub_faces);
                 }
             }
-            SplitResult { sub_faces: all_faces }
+            SplitResult {
+                sub_faces: all_faces,
+            }
+        }
+        IntersectionCurve::Circle(circle) => {
+            split_circular_face_by_circle(brep, face_id, circle, segments)
         }
         _ => {
             // No split for other curve types on circular faces
-            SplitResult { sub_faces: vec![face_id] }
+            SplitResult {
+                sub_faces: vec![face_id],
+            }
         }
     }
 }
@@ -2353,8 +3326,10 @@ mod tests {
             10.0,
             vcad_kernel_math::Vec3::new(0.0, 0.0, 1.0),
         );
-        println!("Circle: center=({:.1},{:.1},{:.1}), r={:.1}",
-            circle.center.x, circle.center.y, circle.center.z, circle.radius);
+        println!(
+            "Circle: center=({:.1},{:.1},{:.1}), r={:.1}",
+            circle.center.x, circle.center.y, circle.center.z, circle.radius
+        );
 
         // Check if circle is partially inside
         let is_partial = circle_partially_inside_polygon(&verts, &circle);
@@ -2364,7 +3339,11 @@ mod tests {
         let initial_faces = brep.topology.faces.len();
         let result = split_planar_face_by_circle(&mut brep, z0_face_id, &circle, 32);
         println!("Split result: {} sub-faces", result.sub_faces.len());
-        println!("Total faces after: {} (was {})", brep.topology.faces.len(), initial_faces);
+        println!(
+            "Total faces after: {} (was {})",
+            brep.topology.faces.len(),
+            initial_faces
+        );
 
         // Print result face info
         for &fid in &result.sub_faces {
@@ -2381,8 +3360,15 @@ mod tests {
                 let min_y = vs.iter().map(|v| v.y).fold(f64::INFINITY, f64::min);
                 let max_y = vs.iter().map(|v| v.y).fold(f64::NEG_INFINITY, f64::max);
 
-                println!("  {:?}: {} verts, x=[{:.1},{:.1}], y=[{:.1},{:.1}]",
-                    fid, vs.len(), min_x, max_x, min_y, max_y);
+                println!(
+                    "  {:?}: {} verts, x=[{:.1},{:.1}], y=[{:.1},{:.1}]",
+                    fid,
+                    vs.len(),
+                    min_x,
+                    max_x,
+                    min_y,
+                    max_y
+                );
             }
         }
 
@@ -5,7 +5,8 @@
 //! intersections have known closed-form solutions.
 
 use vcad_kernel_geom::{
-    Circle3d, CylinderSurface, Line3d, Plane, SphereSurface, Surface, SurfaceKind, TorusSurface,
+    Circle3d, ConeSurface, CylinderSurface, Line3d, Plane, SphereSurface, Surface, SurfaceKind,
+    TorusSurface,
 };
 use vcad_kernel_math::{Dir3, Point2, Point3};
 
@@ -96,6 +97,61 @@ pub fn intersect_surfaces(a: &dyn Surface, b: &dyn Surface) -> IntersectionCurve
                 _ => IntersectionCurve::Empty,
             }
         }
+        // Cone intersections
+        (SurfaceKind::Plane, SurfaceKind::Cone) => {
+            let p = downcast_plane(a);
+            let cone = downcast_cone(b);
+            match (p, cone) {
+                (Some(p), Some(cone)) => plane_cone(p, cone),
+                _ => IntersectionCurve::Empty,
+            }
+        }
+        (SurfaceKind::Cone, SurfaceKind::Plane) => {
+            let cone = downcast_cone(a);
+            let p = downcast_plane(b);
+            match (cone, p) {
+                (Some(cone), Some(p)) => plane_cone(p, cone),
+                _ => IntersectionCurve::Empty,
+            }
+        }
+        (SurfaceKind::Cone, SurfaceKind::Cylinder) => {
+            let cone = downcast_cone(a);
+            let c = downcast_cylinder(b);
+            match (cone, c) {
+                (Some(cone), Some(c)) => cone_cylinder(cone, c),
+                _ => IntersectionCurve::Empty,
+            }
+        }
+        (SurfaceKind::Cylinder, SurfaceKind::Cone) => {
+            let c = downcast_cylinder(a);
+            let cone = downcast_cone(b);
+            match (cone, c) {
+                (Some(cone), Some(c)) => cone_cylinder(cone, c),
+                _ => IntersectionCurve::Empty,
+            }
+        }
+        (SurfaceKind::Cone, SurfaceKind::Cone) => {
+            let ca = downcast_cone(a);
+            let cb = downcast_cone(b);
+            match (ca, cb) {
+                (Some(ca), Some(cb)) => cone_cone(ca, cb),
+                _ => IntersectionCurve::Empty,
+            }
+        }
+        (SurfaceKind::Cylinder, SurfaceKind::Cylinder) => {
+            let ca = downcast_cylinder(a);
+            let cb = downcast_cylinder(b);
+            match (ca, cb) {
+                (Some(ca), Some(cb)) => cylinder_cylinder(ca, cb),
+                _ => IntersectionCurve::Empty,
+            }
+        }
+        (SurfaceKind::Sphere, SurfaceKind::Cylinder)
+        | (SurfaceKind::Cylinder, SurfaceKind::Sphere) => {
+            // Sphere-cylinder intersections are general quartics with no
+            // closed form — sample the curve
+            marching_ssi(a, b, 64)
+        }
         (SurfaceKind::Cylinder, SurfaceKind::Torus)
         | (SurfaceKind::Torus, SurfaceKind::Cylinder)
         | (SurfaceKind::Sphere, SurfaceKind::Torus)
@@ -113,6 +169,40 @@ pub fn intersect_surfaces(a: &dyn Surface, b: &dyn Surface) -> IntersectionCurve
     }
 }
 
+/// Whether [`intersect_surfaces`] has a dedicated routine for this pair of
+/// surface kinds, as opposed to the low-resolution marching fallback used
+/// for unsupported combinations.
+pub(crate) fn has_dedicated_intersection(a: SurfaceKind, b: SurfaceKind) -> bool {
+    use SurfaceKind::*;
+    if a == BSpline || b == BSpline {
+        return true;
+    }
+    matches!(
+        (a, b),
+        (Plane, Plane)
+            | (Plane, Sphere)
+            | (Sphere, Plane)
+            | (Plane, Cylinder)
+            | (Cylinder, Plane)
+            | (Plane, Torus)
+            | (Torus, Plane)
+            | (Plane, Cone)
+            | (Cone, Plane)
+            | (Sphere, Sphere)
+            | (Cylinder, Cylinder)
+            | (Cone, Cylinder)
+            | (Cylinder, Cone)
+            | (Cone, Cone)
+            | (Sphere, Cylinder)
+            | (Cylinder, Sphere)
+            | (Cylinder, Torus)
+            | (Torus, Cylinder)
+            | (Sphere, Torus)
+            | (Torus, Sphere)
+            | (Torus, Torus)
+    )
+}
+
 // =============================================================================
 // Downcasting helpers (safe via as_any())
 // =============================================================================
@@ -133,6 +223,10 @@ fn downcast_torus(s: &dyn Surface) -> Option<&TorusSurface> {
     s.as_any().downcast_ref::<TorusSurface>()
 }
 
+fn downcast_cone(s: &dyn Surface) -> Option<&ConeSurface> {
+    s.as_any().downcast_ref::<ConeSurface>()
+}
+
 // =============================================================================
 // Plane-Plane intersection
 // =============================================================================
@@ -359,6 +453,305 @@ fn plane_cylinder(plane: &Plane, cyl: &CylinderSurface) -> IntersectionCurve {
     }
 }
 
+// =============================================================================
+// Cylinder-Cylinder intersection
+// =============================================================================
+
+/// Intersection of two cylinders.
+///
+/// - Parallel axes → 0, 1, or 2 generator lines from the circle-circle
+///   solution in the common cross-section plane (Empty when coaxial)
+/// - Crossing or skew axes → `Sampled` polyline: the curve is a quartic
+///   with no convenient closed form (the perpendicular equal-radius case
+///   degenerates into the two Steinmetz ellipses), so each generator line
+///   of A is intersected with B's surface — a quadratic in the height
+///   parameter — and the roots are chained into one closed loop per
+///   connected component, concatenated into a single polyline
+fn cylinder_cylinder(a: &CylinderSurface, b: &CylinderSurface) -> IntersectionCurve {
+    let na = a.axis;
+    let nb = b.axis;
+
+    if na.as_ref().cross(nb.as_ref()).norm() < 1e-9 {
+        // Parallel axes — reduce to circle-circle in the plane ⊥ axis
+        let offset = b.center - a.center;
+        let radial = offset - offset.dot(na.as_ref()) * na.into_inner();
+        let d = radial.norm();
+
+        if d < 1e-9 {
+            // Coaxial: coincident (equal radii) or nested — no curve
+            return IntersectionCurve::Empty;
+        }
+        if d > a.radius + b.radius + 1e-9 || d < (a.radius - b.radius).abs() - 1e-9 {
+            return IntersectionCurve::Empty;
+        }
+
+        // Radical-line solution for the two cross-section circles
+        let x = (d * d + a.radius * a.radius - b.radius * b.radius) / (2.0 * d);
+        let y2 = a.radius * a.radius - x * x;
+        let x_dir = radial / d;
+        let base = a.center + x * x_dir;
+        if y2 < 1e-12 {
+            // Tangent generators
+            return IntersectionCurve::Line(Line3d {
+                origin: base,
+                direction: *na.as_ref(),
+            });
+        }
+        let y_dir = na.as_ref().cross(&x_dir);
+        let y = y2.sqrt();
+        return IntersectionCurve::TwoLines(
+            Line3d {
+                origin: base + y * y_dir,
+                direction: *na.as_ref(),
+            },
+            Line3d {
+                origin: base - y * y_dir,
+                direction: *na.as_ref(),
+            },
+        );
+    }
+
+    // Crossing or skew axes. For each angle t around A, the generator
+    // q(t) + s·axis_a meets B's surface where the component of the offset
+    // perpendicular to B's axis has length r_b — a quadratic in s
+    let n_samples = 128;
+    let u = a.ref_dir;
+    let v = na.as_ref().cross(u.as_ref());
+    let dir = na.into_inner() - na.as_ref().dot(nb.as_ref()) * nb.into_inner();
+    let qa = dir.dot(&dir);
+    if qa < 1e-15 {
+        return IntersectionCurve::Empty;
+    }
+
+    // Quadratic coefficients for the generator at angle t: the generator
+    // point, the linear coefficient, and the discriminant
+    let generator = |t: f64| -> (Point3, f64, f64) {
+        let (sin_t, cos_t) = t.sin_cos();
+        let q = a.center + a.radius * (cos_t * u.into_inner() + sin_t * v);
+        let w = q - b.center;
+        let w_perp = w - w.dot(nb.as_ref()) * nb.into_inner();
+        let qb = 2.0 * dir.dot(&w_perp);
+        let qc = w_perp.dot(&w_perp) - b.radius * b.radius;
+        (q, qb, qb * qb - 4.0 * qa * qc)
+    };
+    // The grazing point between a missing and a crossing generator, found
+    // by bisecting the discriminant's zero
+    let graze = |t_miss: f64, t_cross: f64| -> Point3 {
+        let (mut lo, mut hi) = (t_miss, t_cross);
+        for _ in 0..30 {
+            let mid = 0.5 * (lo + hi);
+            if generator(mid).2 < 0.0 {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let (q, qb, _) = generator(hi);
+        q + (-qb / (2.0 * qa)) * na.into_inner()
+    };
+
+    let step = 2.0 * std::f64::consts::PI / n_samples as f64;
+    // Both roots per valid generator; `None` where the generator misses B.
+    // A discriminant within rounding error of zero counts as a (double-root)
+    // graze, so tangent configurations like equal-radius crossings don't
+    // flip between the branch organizations on floating-point noise
+    let disc_eps = 4.0 * qa * b.radius * b.radius * 1e-9;
+    let mut roots: Vec<Option<(Point3, Point3)>> = Vec::with_capacity(n_samples);
+    for i in 0..n_samples {
+        let (q, qb, disc) = generator(i as f64 * step);
+        if disc < -disc_eps {
+            roots.push(None);
+            continue;
+        }
+        let sq = disc.max(0.0).sqrt();
+        let s1 = (-qb - sq) / (2.0 * qa);
+        let s2 = (-qb + sq) / (2.0 * qa);
+        roots.push(Some((q + s1 * na.into_inner(), q + s2 * na.into_inner())));
+    }
+
+    let mut points = Vec::new();
+    if roots.iter().all(Option::is_some) {
+        // Every generator of A crosses B: the two root branches are each a
+        // closed loop around A (they meet wherever the discriminant
+        // touches zero, so each branch is continuous)
+        for p in roots.iter().flatten() {
+            points.push(p.0);
+        }
+        for p in roots.iter().flatten() {
+            points.push(p.1);
+        }
+    } else {
+        // Runs of crossing generators each contribute one closed loop: the
+        // grazing point at the run's start, the near-root branch forward,
+        // the grazing point at the run's end, then the far-root branch
+        // backward
+        let n = roots.len();
+        for start in 0..n {
+            if roots[start].is_none() || roots[(start + n - 1) % n].is_some() {
+                continue;
+            }
+            let mut run = Vec::new();
+            let mut i = start;
+            while let Some(pair) = roots[i] {
+                run.push((i, pair));
+                i = (i + 1) % n;
+                if i == start {
+                    break;
+                }
+            }
+            let end = run.last().expect("non-empty run").0;
+            points.push(graze((start as f64 - 1.0) * step, start as f64 * step));
+            points.extend(run.iter().map(|(_, pair)| pair.0));
+            points.push(graze((end as f64 + 1.0) * step, end as f64 * step));
+            points.extend(run.iter().rev().map(|(_, pair)| pair.1));
+        }
+    }
+
+    if points.is_empty() {
+        IntersectionCurve::Empty
+    } else {
+        IntersectionCurve::Sampled(points)
+    }
+}
+
+/// Split a sampled polyline into its connected components.
+///
+/// Multi-loop intersections (crossing cylinders) concatenate several
+/// closed loops into one `Sampled` polyline; the loops are separated by
+/// jumps much larger than the sample spacing. Single-component curves come
+/// back unchanged.
+pub(crate) fn sampled_components(points: &[Point3]) -> Vec<Vec<Point3>> {
+    if points.len() < 2 {
+        return vec![points.to_vec()];
+    }
+    let total: f64 = points.windows(2).map(|w| (w[1] - w[0]).norm()).sum();
+    let avg_spacing = total / (points.len() - 1) as f64;
+    if avg_spacing < 1e-12 {
+        return vec![points.to_vec()];
+    }
+
+    let mut components = vec![vec![points[0]]];
+    for w in points.windows(2) {
+        if (w[1] - w[0]).norm() > 4.0 * avg_spacing {
+            components.push(Vec::new());
+        }
+        components.last_mut().expect("non-empty").push(w[1]);
+    }
+    components
+}
+
+// =============================================================================
+// Cone-Cylinder and Cone-Cone intersection
+// =============================================================================
+
+/// Intersection of a plane and a cone.
+///
+/// - Plane perpendicular to the axis → Circle at the plane's axial height
+///   (Point at the apex, Empty behind it)
+/// - Oblique or axis-parallel planes → marching fallback
+fn plane_cone(plane: &Plane, cone: &ConeSurface) -> IntersectionCurve {
+    let cos_angle = plane.normal_dir.as_ref().dot(cone.axis.as_ref()).abs();
+
+    if (cos_angle - 1.0).abs() < 1e-9 {
+        // Axial height where the plane cuts the axis
+        let s = plane.signed_distance(&cone.apex)
+            * -plane.normal_dir.as_ref().dot(cone.axis.as_ref()).signum();
+        if s < -1e-9 {
+            return IntersectionCurve::Empty;
+        }
+        if s < 1e-9 {
+            return IntersectionCurve::Point(cone.apex);
+        }
+        let center = cone.apex + s * cone.axis.into_inner();
+        return IntersectionCurve::Circle(Circle3d::with_normal(
+            center,
+            s * cone.half_angle.tan(),
+            *cone.axis.as_ref(),
+        ));
+    }
+
+    marching_ssi(plane, cone, 64)
+}
+
+/// Intersection of a cone and a cylinder.
+///
+/// - Coaxial → Circle at the height where the cone opens to the cylinder
+///   radius (the countersink/hole transition case)
+/// - Skew or offset axes → marching fallback
+fn cone_cylinder(cone: &ConeSurface, cyl: &CylinderSurface) -> IntersectionCurve {
+    let cross = cone.axis.as_ref().cross(cyl.axis.as_ref()).norm();
+    let offset = cone.apex - cyl.center;
+    let radial = offset - offset.dot(cyl.axis.as_ref()) * cyl.axis.into_inner();
+
+    if cross < 1e-9 && radial.norm() < 1e-9 {
+        let tan_a = cone.half_angle.tan();
+        if tan_a < 1e-12 {
+            // Degenerate cone (zero half-angle) never reaches the radius
+            return IntersectionCurve::Empty;
+        }
+
+        // Distance from the apex along the axis where the cone radius
+        // matches the cylinder radius
+        let s = cyl.radius / tan_a;
+        let center = cone.apex + s * cone.axis.into_inner();
+        return IntersectionCurve::Circle(Circle3d::with_normal(
+            center,
+            cyl.radius,
+            *cone.axis.as_ref(),
+        ));
+    }
+
+    marching_ssi(cone, cyl, 64)
+}
+
+/// Intersection of two cones.
+///
+/// - Coaxial with different slopes → Circle where the radii agree (Point at
+///   a shared apex, Empty if the circle would fall outside either half-cone)
+/// - Coaxial with equal slopes → Empty (parallel or coincident rulings)
+/// - Skew or offset axes → marching fallback
+fn cone_cone(a: &ConeSurface, b: &ConeSurface) -> IntersectionCurve {
+    let cross = a.axis.as_ref().cross(b.axis.as_ref()).norm();
+    let offset = b.apex - a.apex;
+    let radial = offset - offset.dot(a.axis.as_ref()) * a.axis.into_inner();
+
+    if cross < 1e-9 && radial.norm() < 1e-9 {
+        let ta = a.half_angle.tan();
+        let tb = b.half_angle.tan();
+        // Axial coordinate of B's apex measured from A's apex, and the
+        // orientation of B's axis relative to A's
+        let s_b = offset.dot(a.axis.as_ref());
+        let sigma = a.axis.as_ref().dot(b.axis.as_ref()).signum();
+
+        // Radii agree where ta·s = tb·σ·(s − s_b), with s the axial
+        // coordinate from A's apex
+        let denom = ta - tb * sigma;
+        if denom.abs() < 1e-12 {
+            // Equal slopes: rulings are parallel (or the cones coincide)
+            return IntersectionCurve::Empty;
+        }
+        let s = -tb * sigma * s_b / denom;
+
+        // The circle must lie on both half-cones (v ≥ 0 from each apex)
+        if s < -1e-9 || sigma * (s - s_b) < -1e-9 {
+            return IntersectionCurve::Empty;
+        }
+
+        let r = ta * s;
+        if r < 1e-9 {
+            // Shared apex — tangent point
+            return IntersectionCurve::Point(a.apex);
+        }
+        return IntersectionCurve::Circle(Circle3d::with_normal(
+            a.apex + s * a.axis.into_inner(),
+            r,
+            *a.axis.as_ref(),
+        ));
+    }
+
+    marching_ssi(a, b, 64)
+}
+
 // =============================================================================
 // Sphere-Sphere intersection
 // =============================================================================
@@ -592,7 +985,14 @@ fn marching_ssi(a: &dyn Surface, b: &dyn Surface, n_samples: usize) -> Intersect
             // Simple approach: check if distance is small
             let (closest_pt, dist) = closest_point_on_surface(b, &pt_a);
 
-            if dist < 1e-3 {
+            // Accept candidates within half the local sample spacing — a
+            // fixed tolerance misses curves that pass between grid samples
+            let du = (u_max_a - u_min_a) / n as f64;
+            let dv = (v_max_a - v_min_a) / n as f64;
+            let spacing =
+                (a.d_du(Point2::new(u, v)).norm() * du).max(a.d_dv(Point2::new(u, v)).norm() * dv);
+
+            if dist < (0.5 * spacing).max(1e-3) {
                 // Refine using Newton-Raphson or gradient descent
                 let refined = refine_intersection_point(a, b, &pt_a, &closest_pt);
                 if let Some(pt) = refined {
@@ -627,6 +1027,7 @@ fn closest_point_on_surface(surface: &dyn Surface, target: &Point3) -> (Point3,
     let mut best_dist = f64::INFINITY;
 
     let n = 16;
+    let mut best_uv = Point2::new(u_min, v_min);
     for i in 0..=n {
         let u = u_min + (u_max - u_min) * i as f64 / n as f64;
         for j in 0..=n {
@@ -636,30 +1037,74 @@ fn closest_point_on_surface(surface: &dyn Surface, target: &Point3) -> (Point3,
             if dist < best_dist {
                 best_dist = dist;
                 best_pt = pt;
+                best_uv = Point2::new(u, v);
             }
         }
     }
 
+    // Refine by shrinking the search window around the best sample — the
+    // coarse grid alone is far too inaccurate on large domains
+    let mut half_u = (u_max - u_min) / n as f64;
+    let mut half_v = (v_max - v_min) / n as f64;
+    for _ in 0..6 {
+        let n_fine = 8;
+        let u_lo = (best_uv.x - half_u).max(u_min);
+        let u_hi = (best_uv.x + half_u).min(u_max);
+        let v_lo = (best_uv.y - half_v).max(v_min);
+        let v_hi = (best_uv.y + half_v).min(v_max);
+        for i in 0..=n_fine {
+            let u = u_lo + (u_hi - u_lo) * i as f64 / n_fine as f64;
+            for j in 0..=n_fine {
+                let v = v_lo + (v_hi - v_lo) * j as f64 / n_fine as f64;
+                let pt = surface.evaluate(Point2::new(u, v));
+                let dist = (pt - target).norm();
+                if dist < best_dist {
+                    best_dist = dist;
+                    best_pt = pt;
+                    best_uv = Point2::new(u, v);
+                }
+            }
+        }
+        half_u /= 4.0;
+        half_v /= 4.0;
+    }
+
     (best_pt, best_dist)
 }
 
 /// Refine an intersection point using iterative projection.
 fn refine_intersection_point(
-    _a: &dyn Surface,
-    _b: &dyn Surface,
+    a: &dyn Surface,
+    b: &dyn Surface,
     pt_a: &Point3,
     pt_b: &Point3,
 ) -> Option<Point3> {
-    // Simple approach: return midpoint if close enough
-    let mid = Point3::new(
+    // Alternating projection: bounce the midpoint between the two surfaces
+    // until it lies on both (or fails to converge)
+    let mut current = Point3::new(
         0.5 * (pt_a.x + pt_b.x),
         0.5 * (pt_a.y + pt_b.y),
         0.5 * (pt_a.z + pt_b.z),
     );
-    let dist = (pt_a - pt_b).norm();
 
-    if dist < 1e-2 {
-        Some(mid)
+    let mut gap = f64::INFINITY;
+    for _ in 0..3 {
+        let (on_a, _) = closest_point_on_surface(a, &current);
+        let (on_b, dist) = closest_point_on_surface(b, &on_a);
+        current = Point3::new(
+            0.5 * (on_a.x + on_b.x),
+            0.5 * (on_a.y + on_b.y),
+            0.5 * (on_a.z + on_b.z),
+        );
+        gap = dist;
+        if gap < 1e-6 {
+            break;
+        }
+    }
+
+    // Accept if the final gap is small relative to the sampling accuracy
+    if gap < 1e-2 {
+        Some(current)
     } else {
         None
     }
@@ -726,6 +1171,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_plane_sphere_off_center_slice() {
+        // Plane at z = 6 slicing an r=10 sphere: circle centered at the
+        // projection of the sphere center with radius sqrt(r² − d²) = 8
+        let plane = Plane::new(Point3::new(0.0, 0.0, 6.0), Vec3::x(), Vec3::y());
+        let sphere = SphereSurface::new(10.0);
+
+        let result = plane_sphere(&plane, &sphere);
+        match result {
+            IntersectionCurve::Circle(circle) => {
+                assert!((circle.radius - 8.0).abs() < 1e-10);
+                assert!(circle.center.x.abs() < 1e-10);
+                assert!(circle.center.y.abs() < 1e-10);
+                assert!((circle.center.z - 6.0).abs() < 1e-10);
+            }
+            _ => panic!("Expected Circle intersection, got {:?}", result),
+        }
+    }
+
     #[test]
     fn test_plane_sphere_no_intersection() {
         let plane = Plane::new(Point3::new(0.0, 0.0, 15.0), Vec3::x(), Vec3::y());
@@ -751,6 +1215,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sphere_sphere_unit_offset() {
+        // Two unit spheres with centers 1 apart: circle at the midpoint
+        // with radius sqrt(1 − 0.5²) = √3/2
+        let a = SphereSurface::new(1.0);
+        let b = SphereSurface::with_center(Point3::new(1.0, 0.0, 0.0), 1.0);
+
+        let result = sphere_sphere(&a, &b);
+        match result {
+            IntersectionCurve::Circle(circle) => {
+                assert!((circle.center.x - 0.5).abs() < 1e-12);
+                assert!(circle.center.y.abs() < 1e-12);
+                assert!(circle.center.z.abs() < 1e-12);
+                assert!((circle.radius - 3.0_f64.sqrt() / 2.0).abs() < 1e-12);
+                // Circle plane is perpendicular to the center line
+                assert!(circle.normal.as_ref().cross(&Vec3::x()).norm() < 1e-12);
+            }
+            _ => panic!("Expected Circle intersection, got {:?}", result),
+        }
+    }
+
     #[test]
     fn test_sphere_sphere_too_far() {
         let a = SphereSurface::new(5.0);
@@ -790,6 +1275,102 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_plane_cylinder_oblique_is_sampled_ellipse() {
+        // 45° plane through a cylinder along Z → elliptical section, not a circle
+        let normal = Vec3::new(0.0, 1.0, 1.0).normalize();
+        let x_dir = Vec3::x();
+        let y_dir = normal.cross(&x_dir);
+        let plane = Plane::new(Point3::new(0.0, 0.0, 0.0), x_dir, y_dir);
+        let cyl = CylinderSurface::new(10.0);
+
+        let result = plane_cylinder(&plane, &cyl);
+        let points = match result {
+            IntersectionCurve::Sampled(points) => points,
+            _ => panic!("Expected Sampled ellipse, got {:?}", result),
+        };
+
+        // Every sample lies on both surfaces
+        for p in &points {
+            assert!(plane.signed_distance(p).abs() < 1e-9);
+            let radial = (p.x * p.x + p.y * p.y).sqrt();
+            assert!((radial - 10.0).abs() < 1e-9);
+        }
+
+        // Semi-axes of the section: minor = r, major = r / cos(45°) = r√2
+        let max_dist = points
+            .iter()
+            .map(|p| (p - Point3::origin()).norm())
+            .fold(0.0, f64::max);
+        let min_dist = points
+            .iter()
+            .map(|p| (p - Point3::origin()).norm())
+            .fold(f64::MAX, f64::min);
+        assert!((max_dist - 10.0 * 2.0_f64.sqrt()).abs() < 0.1);
+        assert!((min_dist - 10.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_plane_cylinder_tangent_line() {
+        // Plane parallel to the axis, touching the cylinder at x = 10
+        let plane = Plane::new(Point3::new(10.0, 0.0, 0.0), Vec3::y(), Vec3::z());
+        let cyl = CylinderSurface::new(10.0);
+
+        let result = plane_cylinder(&plane, &cyl);
+        match result {
+            IntersectionCurve::Line(line) => {
+                assert!((line.origin.x - 10.0).abs() < 1e-9);
+                assert!(line.direction.cross(&Vec3::z()).norm() < 1e-9);
+            }
+            _ => panic!("Expected tangent Line, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_plane_cylinder_parallel_two_lines() {
+        // Plane parallel to the axis, cutting through at x = 6 → two chord lines
+        let plane = Plane::new(Point3::new(6.0, 0.0, 0.0), Vec3::y(), Vec3::z());
+        let cyl = CylinderSurface::new(10.0);
+
+        let result = plane_cylinder(&plane, &cyl);
+        match result {
+            IntersectionCurve::TwoLines(l1, l2) => {
+                // Chord half-width: √(r² − d²) = 8
+                assert!((l1.origin.x - 6.0).abs() < 1e-9);
+                assert!((l2.origin.x - 6.0).abs() < 1e-9);
+                assert!((l1.origin.y.abs() - 8.0).abs() < 1e-9);
+                assert!((l2.origin.y.abs() - 8.0).abs() < 1e-9);
+                assert!((l1.origin.y + l2.origin.y).abs() < 1e-9);
+            }
+            _ => panic!("Expected TwoLines, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_plane_cylinder_through_axis_two_lines() {
+        // Plane at x = 0 contains the axis of a cylinder centered at the
+        // origin — the degenerate parallel case where the axis lies in the
+        // plane. Both diametrically opposed generators must come back, or a
+        // quarter-cylinder cut only splits one wall.
+        let plane = Plane::new(Point3::origin(), Vec3::y(), Vec3::z());
+        let cyl = CylinderSurface::new(10.0);
+
+        let result = plane_cylinder(&plane, &cyl);
+        match result {
+            IntersectionCurve::TwoLines(l1, l2) => {
+                assert!(l1.origin.x.abs() < 1e-9);
+                assert!(l2.origin.x.abs() < 1e-9);
+                assert!((l1.origin.y.abs() - 10.0).abs() < 1e-9);
+                assert!((l2.origin.y.abs() - 10.0).abs() < 1e-9);
+                // Distinct origins on opposite sides of the axis
+                assert!((l1.origin - l2.origin).norm() > 19.0);
+                assert!(l1.direction.cross(&Vec3::z()).norm() < 1e-9);
+                assert!(l2.direction.cross(&Vec3::z()).norm() < 1e-9);
+            }
+            _ => panic!("Expected TwoLines, got {:?}", result),
+        }
+    }
+
     #[test]
     fn test_intersect_surfaces_dispatch() {
         let a: Box<dyn Surface> = Box::new(Plane::xy());
@@ -841,4 +1422,163 @@ mod tests {
             _ => panic!("Expected Circle intersection at tangent"),
         }
     }
+
+    #[test]
+    fn test_sphere_sphere_circle_radius_formula() {
+        // Two r=10 spheres with centers 15 apart: the intersection circle
+        // sits at x = d/2 with radius sqrt(r² − (d/2)²)
+        let a = SphereSurface::new(10.0);
+        let b = SphereSurface::with_center(Point3::new(15.0, 0.0, 0.0), 10.0);
+
+        let result = intersect_surfaces(&a, &b);
+        match result {
+            IntersectionCurve::Circle(circle) => {
+                let expected_radius = (100.0_f64 - 7.5 * 7.5).sqrt();
+                assert!((circle.center.x - 7.5).abs() < 1e-10);
+                assert!((circle.radius - expected_radius).abs() < 1e-10);
+            }
+            _ => panic!("Expected Circle intersection, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_sphere_cylinder_sampled() {
+        // Offset sphere and cylinder meet in a quartic curve — expect a
+        // sampled polyline, never a (wrong) analytic circle
+        let sphere = SphereSurface::with_center(Point3::new(3.0, 0.0, 5.0), 4.0);
+        let cyl = CylinderSurface::new(2.0);
+
+        let result = intersect_surfaces(&sphere, &cyl);
+        match result {
+            IntersectionCurve::Sampled(points) => assert!(!points.is_empty()),
+            other => panic!("Expected Sampled intersection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_cone_cylinder_coaxial_circle() {
+        // 45° cone opening from the origin meets an r=5 cylinder at z=5
+        let cone = ConeSurface::new(std::f64::consts::FRAC_PI_4);
+        let cyl = CylinderSurface::new(5.0);
+
+        let result = intersect_surfaces(&cone, &cyl);
+        match result {
+            IntersectionCurve::Circle(circle) => {
+                assert!((circle.radius - 5.0).abs() < 1e-10);
+                assert!((circle.center.z - 5.0).abs() < 1e-10);
+            }
+            _ => panic!("Expected Circle intersection, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_cone_cone_coaxial_circle() {
+        // Two opposing 45° cones with apexes 10 apart meet at z=5, r=5
+        let a = ConeSurface::new(std::f64::consts::FRAC_PI_4);
+        let mut b = ConeSurface::new(std::f64::consts::FRAC_PI_4);
+        b.apex = Point3::new(0.0, 0.0, 10.0);
+        b.axis = Dir3::new_normalize(-Vec3::z());
+
+        let result = intersect_surfaces(&a, &b);
+        match result {
+            IntersectionCurve::Circle(circle) => {
+                assert!((circle.radius - 5.0).abs() < 1e-10);
+                assert!((circle.center.z - 5.0).abs() < 1e-10);
+            }
+            _ => panic!("Expected Circle intersection, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_cone_cone_shared_apex_point() {
+        // Same apex, different half-angles — tangent at the apex only
+        let a = ConeSurface::new(std::f64::consts::FRAC_PI_4);
+        let b = ConeSurface::new(std::f64::consts::FRAC_PI_6);
+
+        let result = intersect_surfaces(&a, &b);
+        assert!(matches!(result, IntersectionCurve::Point(_)));
+    }
+
+    #[test]
+    fn test_cylinder_cylinder_parallel_two_lines() {
+        // Two r=5 cylinders along Z with axes 6 apart: two shared
+        // generators on the radical plane x = 3, chord half-width 4
+        let a = CylinderSurface::new(5.0);
+        let b = CylinderSurface::with_axis(Point3::new(6.0, 0.0, 0.0), Vec3::z(), 5.0);
+
+        let result = intersect_surfaces(&a, &b);
+        match result {
+            IntersectionCurve::TwoLines(l1, l2) => {
+                assert!((l1.origin.x - 3.0).abs() < 1e-9);
+                assert!((l2.origin.x - 3.0).abs() < 1e-9);
+                assert!((l1.origin.y.abs() - 4.0).abs() < 1e-9);
+                assert!((l1.origin.y + l2.origin.y).abs() < 1e-9);
+                assert!(l1.direction.cross(&Vec3::z()).norm() < 1e-9);
+            }
+            _ => panic!("Expected TwoLines, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_cylinder_cylinder_parallel_empty_cases() {
+        let a = CylinderSurface::new(5.0);
+        // Too far apart
+        let far = CylinderSurface::with_axis(Point3::new(20.0, 0.0, 0.0), Vec3::z(), 5.0);
+        assert!(matches!(
+            intersect_surfaces(&a, &far),
+            IntersectionCurve::Empty
+        ));
+        // Nested
+        let nested = CylinderSurface::with_axis(Point3::new(1.0, 0.0, 0.0), Vec3::z(), 1.0);
+        assert!(matches!(
+            intersect_surfaces(&a, &nested),
+            IntersectionCurve::Empty
+        ));
+        // Coaxial
+        let coaxial = CylinderSurface::new(5.0);
+        assert!(matches!(
+            intersect_surfaces(&a, &coaxial),
+            IntersectionCurve::Empty
+        ));
+    }
+
+    #[test]
+    fn test_cylinder_cylinder_perpendicular_sampled_on_both() {
+        // r=3 cylinder along X drilled through an r=5 cylinder along Z
+        let a = CylinderSurface::new(5.0);
+        let b = CylinderSurface::with_axis(Point3::new(0.0, 0.0, 10.0), Vec3::x(), 3.0);
+
+        let result = intersect_surfaces(&a, &b);
+        let points = match result {
+            IntersectionCurve::Sampled(points) => points,
+            other => panic!("Expected Sampled curve, got {:?}", other),
+        };
+
+        // Every sample lies on both surfaces
+        for p in &points {
+            let radial_a = (p.x * p.x + p.y * p.y).sqrt();
+            assert!((radial_a - 5.0).abs() < 1e-9, "off cylinder A: {:?}", p);
+            let radial_b = (p.y * p.y + (p.z - 10.0) * (p.z - 10.0)).sqrt();
+            assert!((radial_b - 3.0).abs() < 1e-9, "off cylinder B: {:?}", p);
+        }
+
+        // The through-hole crosses the wall in two closed loops (entry and
+        // exit), which downstream splitting consumes one at a time
+        let components = sampled_components(&points);
+        assert_eq!(components.len(), 2);
+        for component in &components {
+            assert!(crate::split::is_closed_sampled_ring(component));
+        }
+    }
+
+    #[test]
+    fn test_cone_cylinder_offset_not_circle() {
+        // Offset axes have no single-circle solution — must not report one
+        let cone = ConeSurface::new(std::f64::consts::FRAC_PI_4);
+        let mut cyl = CylinderSurface::new(2.0);
+        cyl.center = Point3::new(3.0, 0.0, 0.0);
+
+        let result = intersect_surfaces(&cone, &cyl);
+        assert!(!matches!(result, IntersectionCurve::Circle(_)));
+    }
 }
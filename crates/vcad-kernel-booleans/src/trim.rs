@@ -39,14 +39,22 @@
 //! resulting in the entire Z face being classified as Outside instead of having
 //! a middle strip (Y=0 to Y=6) classified as Inside.
 
+use std::collections::HashMap;
+
 use vcad_kernel_geom::Surface;
 use vcad_kernel_math::{Point2, Point3};
 use vcad_kernel_primitives::BRepSolid;
-use vcad_kernel_topo::FaceId;
+use vcad_kernel_topo::{FaceId, LoopId};
 
 use crate::bbox;
 use crate::ssi::IntersectionCurve;
 
+#[cfg(test)]
+thread_local! {
+    /// Counts calls to [`project_point_to_uv`] on the current test thread.
+    static PROJECTION_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
 /// A trimmed segment of an intersection curve, expressed as a parameter range.
 #[derive(Debug, Clone)]
 pub struct TrimmedSegment {
@@ -56,6 +64,120 @@ pub struct TrimmedSegment {
     pub t_end: f64,
 }
 
+/// Boundary loops of a face projected once into the surface's (u,v) space.
+#[derive(Debug, Clone)]
+struct CachedUvLoops {
+    /// Outer boundary loop in UV coordinates.
+    outer: Vec<Point2>,
+    /// Inner (hole) loops in UV coordinates.
+    inners: Vec<Vec<Point2>>,
+    /// Seam cut used to unwrap loops on periodic surfaces, if any.
+    /// Test points must be unwrapped with the same cut before the polygon test.
+    seam_cut: Option<f64>,
+}
+
+/// Cached UV boundary loops for the faces of a solid.
+///
+/// [`point_in_face`] projects every loop vertex into UV space on each query,
+/// so repeated trimming against the same face re-does the same projections.
+/// Building a cache projects each face's loops once; [`point_in_face_cached`]
+/// and [`trim_curve_to_face_cached`] then only project the test point.
+///
+/// Faces whose containment test is not a UV polygon test (single-vertex
+/// circular caps, full-cylinder lateral faces) are left out of the cache and
+/// fall back to the uncached path, so results are identical either way.
+#[derive(Debug, Clone, Default)]
+pub struct FaceUvCache {
+    faces: HashMap<FaceId, CachedUvLoops>,
+}
+
+impl FaceUvCache {
+    /// Project the boundary loops of every cacheable face in `brep` to UV.
+    ///
+    /// Reads the thread-local [`vcad_kernel_math::geometry_tolerance`]; call
+    /// [`FaceUvCache::build_with_tol`] with an explicit tolerance from code
+    /// that may run on a worker thread.
+    pub fn build(brep: &BRepSolid) -> Self {
+        Self::build_with_tol(brep, vcad_kernel_math::geometry_tolerance())
+    }
+
+    /// Like [`FaceUvCache::build`], with an explicit coincidence tolerance.
+    pub fn build_with_tol(brep: &BRepSolid, tol: f64) -> Self {
+        let mut faces = HashMap::new();
+        for (face_id, face) in brep.topology.faces.iter() {
+            let surface = &brep.geometry.surfaces[face.surface_index];
+            let outer_verts_3d = loop_verts_3d(brep, face.outer_loop);
+
+            // Single-vertex circular caps are tested with 3D circle geometry.
+            if outer_verts_3d.len() <= 1 {
+                continue;
+            }
+
+            if surface.surface_type() == vcad_kernel_geom::SurfaceKind::Cylinder {
+                // Full-cylinder lateral faces (two unique seam vertices) are
+                // tested with a V-range check, not a polygon.
+                let mut unique_verts: Vec<Point3> = Vec::new();
+                for v in &outer_verts_3d {
+                    let is_dup = unique_verts.iter().any(|u| (*u - *v).norm() < tol);
+                    if !is_dup {
+                        unique_verts.push(*v);
+                    }
+                }
+                if unique_verts.len() == 2 {
+                    continue;
+                }
+
+                let outer_uv = project_points_to_uv(surface.as_ref(), &outer_verts_3d);
+                let (outer, seam_cut) = unwrap_cylindrical_loop(&outer_uv);
+                let inners = face
+                    .inner_loops
+                    .iter()
+                    .map(|&inner_loop_id| {
+                        let inner_verts = loop_verts_3d(brep, inner_loop_id);
+                        let inner_uv = project_points_to_uv(surface.as_ref(), &inner_verts);
+                        unwrap_cylindrical_loop_with_cut(&inner_uv, seam_cut)
+                    })
+                    .collect();
+                faces.insert(
+                    face_id,
+                    CachedUvLoops {
+                        outer,
+                        inners,
+                        seam_cut: Some(seam_cut),
+                    },
+                );
+            } else {
+                let outer = project_points_to_uv(surface.as_ref(), &outer_verts_3d);
+                let inners = face
+                    .inner_loops
+                    .iter()
+                    .map(|&inner_loop_id| {
+                        let inner_verts = loop_verts_3d(brep, inner_loop_id);
+                        project_points_to_uv(surface.as_ref(), &inner_verts)
+                    })
+                    .collect();
+                faces.insert(
+                    face_id,
+                    CachedUvLoops {
+                        outer,
+                        inners,
+                        seam_cut: None,
+                    },
+                );
+            }
+        }
+        Self { faces }
+    }
+}
+
+/// Collect the 3D vertex positions of a loop in order.
+fn loop_verts_3d(brep: &BRepSolid, loop_id: LoopId) -> Vec<Point3> {
+    let topo = &brep.topology;
+    topo.loop_half_edges(loop_id)
+        .map(|he_id| topo.vertices[topo.half_edges[he_id].origin].point)
+        .collect()
+}
+
 /// Test if a 2D point is inside a closed polygon using exact predicates.
 ///
 /// Uses Shewchuk's exact orient2d predicate for robust crossing detection.
@@ -130,7 +252,26 @@ pub fn point_in_polygon(point: &Point2, polygon: &[Point2]) -> bool {
 ///
 /// Projects the point into the face's (u,v) parameter space and tests
 /// against the face's trim loops.
+///
+/// Reads the thread-local [`vcad_kernel_math::geometry_tolerance`]; call
+/// [`point_in_face_with_tol`] with an explicit tolerance from code that may
+/// run on a worker thread.
 pub fn point_in_face(brep: &BRepSolid, face_id: FaceId, point_3d: &Point3) -> bool {
+    point_in_face_with_tol(
+        brep,
+        face_id,
+        point_3d,
+        vcad_kernel_math::geometry_tolerance(),
+    )
+}
+
+/// Like [`point_in_face`], with an explicit coincidence tolerance.
+pub fn point_in_face_with_tol(
+    brep: &BRepSolid,
+    face_id: FaceId,
+    point_3d: &Point3,
+    tol: f64,
+) -> bool {
     let topo = &brep.topology;
     let face = &topo.faces[face_id];
     let surface = &brep.geometry.surfaces[face.surface_index];
@@ -167,7 +308,8 @@ pub fn point_in_face(brep: &BRepSolid, face_id: FaceId, point_3d: &Point3) -> bo
 
                 // Check if point is within the circle
                 let center_to_point = point_3d - plane.origin;
-                let dist_from_center = (center_to_point - dist_along_normal * plane.normal_dir.into_inner()).norm();
+                let dist_from_center =
+                    (center_to_point - dist_along_normal * plane.normal_dir.into_inner()).norm();
 
                 return dist_from_center <= radius + 1e-6;
             }
@@ -186,7 +328,7 @@ pub fn point_in_face(brep: &BRepSolid, face_id: FaceId, point_3d: &Point3) -> bo
         // Get unique vertices by position
         let mut unique_verts: Vec<Point3> = Vec::new();
         for v in &outer_verts_3d {
-            let is_dup = unique_verts.iter().any(|u| (*u - *v).norm() < 1e-6);
+            let is_dup = unique_verts.iter().any(|u| (*u - *v).norm() < tol);
             if !is_dup {
                 unique_verts.push(*v);
             }
@@ -276,149 +418,49 @@ pub fn point_in_face(brep: &BRepSolid, face_id: FaceId, point_3d: &Point3) -> bo
     true
 }
 
-/// Project a 3D point onto a surface's UV parameter space.
+/// Test if a 3D point lies inside a face, using cached UV boundary loops.
 ///
-/// Uses a simple closest-point heuristic for analytic surfaces.
-pub fn project_point_to_uv(surface: &dyn Surface, point: &Point3) -> Point2 {
-    use vcad_kernel_geom::SurfaceKind;
-
-    match surface.surface_type() {
-        SurfaceKind::Plane => {
-            // For planes, we can get the exact UV from the Plane struct
-            if let Some(plane) = surface.as_any().downcast_ref::<vcad_kernel_geom::Plane>() {
-                plane.project(point)
-            } else {
-                approx_project_to_uv(surface, point)
-            }
-        }
-        SurfaceKind::Cylinder => {
-            if let Some(cyl) = surface
-                .as_any()
-                .downcast_ref::<vcad_kernel_geom::CylinderSurface>()
-            {
-                // Project: u = atan2(dot(p-center, y_dir), dot(p-center, ref_dir))
-                //          v = dot(p-center, axis)
-                let d = point - cyl.center;
-                let ref_dir = cyl.ref_dir.as_ref();
-                let y_dir = cyl.axis.as_ref().cross(ref_dir);
-                let u = d.dot(&y_dir).atan2(d.dot(ref_dir));
-                let u = if u < 0.0 {
-                    u + 2.0 * std::f64::consts::PI
-                } else {
-                    u
-                };
-                let v = d.dot(cyl.axis.as_ref());
-                Point2::new(u, v)
-            } else {
-                approx_project_to_uv(surface, point)
-            }
-        }
-        SurfaceKind::Sphere => {
-            if let Some(sph) = surface
-                .as_any()
-                .downcast_ref::<vcad_kernel_geom::SphereSurface>()
-            {
-                let d = (point - sph.center).normalize();
-                let ref_dir = sph.ref_dir.as_ref();
-                let y_dir = sph.axis.as_ref().cross(ref_dir);
-                let v = d.dot(sph.axis.as_ref()).asin(); // latitude
-                let cos_v = v.cos();
-                let u = if cos_v.abs() < 1e-12 {
-                    0.0 // at pole
-                } else {
-                    let dx = d.dot(ref_dir) / cos_v;
-                    let dy = d.dot(&y_dir) / cos_v;
-                    let u = dy.atan2(dx);
-                    if u < 0.0 {
-                        u + 2.0 * std::f64::consts::PI
-                    } else {
-                        u
-                    }
-                };
-                Point2::new(u, v)
-            } else {
-                approx_project_to_uv(surface, point)
-            }
-        }
-        SurfaceKind::Cone => approx_project_to_uv(surface, point),
-        SurfaceKind::Torus => {
-            if let Some(torus) = surface
-                .as_any()
-                .downcast_ref::<vcad_kernel_geom::TorusSurface>()
-            {
-                // Torus parameterization:
-                // P(u,v) = center + (R + r·cos(v))·(cos(u)·ref_dir + sin(u)·y_dir) + r·sin(v)·axis
-                //
-                // To find u: project point onto the center plane and compute angle
-                // To find v: find angle around the tube cross-section
-
-                let d = point - torus.center;
-                let ref_dir = torus.ref_dir.as_ref();
-                let y_dir = torus.axis.as_ref().cross(ref_dir);
-
-                // Project d onto the center plane (remove axis component)
-                let d_axis = d.dot(torus.axis.as_ref());
-                let d_plane = d - d_axis * torus.axis.into_inner();
-                let d_plane_len = d_plane.norm();
-
-                // u = angle in the center plane
-                let u = if d_plane_len < 1e-12 {
-                    0.0 // degenerate case: point on axis
-                } else {
-                    let d_plane_norm = d_plane / d_plane_len;
-                    let u = d_plane_norm.dot(&y_dir).atan2(d_plane_norm.dot(ref_dir));
-                    if u < 0.0 {
-                        u + 2.0 * std::f64::consts::PI
-                    } else {
-                        u
-                    }
-                };
-
-                // v = angle around the tube cross-section
-                // The tube center at this u is at distance R from center
-                let tube_center_dist = torus.major_radius;
-                let radial_dist = d_plane_len - tube_center_dist; // distance from tube center in radial direction
-                let axial_dist = d_axis; // distance from tube center in axis direction
+/// Behaves like [`point_in_face`] but reuses the loops projected by
+/// [`FaceUvCache::build`], so only the test point itself is projected.
+/// Faces absent from the cache fall back to [`point_in_face`].
+pub fn point_in_face_cached(
+    brep: &BRepSolid,
+    face_id: FaceId,
+    point_3d: &Point3,
+    cache: &FaceUvCache,
+) -> bool {
+    let loops = match cache.faces.get(&face_id) {
+        Some(loops) => loops,
+        None => return point_in_face(brep, face_id, point_3d),
+    };
+    let face = &brep.topology.faces[face_id];
+    let surface = &brep.geometry.surfaces[face.surface_index];
 
-                let v = axial_dist.atan2(radial_dist);
+    let mut test_uv = project_point_to_uv(surface.as_ref(), point_3d);
+    if let Some(seam_cut) = loops.seam_cut {
+        test_uv = unwrap_cylindrical_uv(&test_uv, seam_cut);
+    }
 
-                Point2::new(u, v)
-            } else {
-                approx_project_to_uv(surface, point)
-            }
+    if !point_in_polygon(&test_uv, &loops.outer) {
+        return false;
+    }
+    for inner_uv in &loops.inners {
+        if point_in_polygon(&test_uv, inner_uv) {
+            return false; // inside a hole
         }
-        _ => approx_project_to_uv(surface, point),
     }
+    true
 }
 
-/// Approximate UV projection by searching over the parameter domain.
-fn approx_project_to_uv(surface: &dyn Surface, point: &Point3) -> Point2 {
-    let ((u_min, u_max), (v_min, v_max)) = surface.domain();
-    // Clamp domain for search
-    let u_min = u_min.max(-100.0);
-    let u_max = u_max.min(100.0);
-    let v_min = v_min.max(-100.0);
-    let v_max = v_max.min(100.0);
-
-    let n = 20;
-    let mut best_uv = Point2::new(0.5 * (u_min + u_max), 0.5 * (v_min + v_max));
-    let mut best_dist = f64::INFINITY;
-
-    for i in 0..=n {
-        for j in 0..=n {
-            let u = u_min + (u_max - u_min) * i as f64 / n as f64;
-            let v = v_min + (v_max - v_min) * j as f64 / n as f64;
-            let uv = Point2::new(u, v);
-            let p = surface.evaluate(uv);
-            let dist = (p - point).norm_squared();
-            if dist < best_dist {
-                best_dist = dist;
-                best_uv = uv;
-            }
-        }
-    }
+/// Project a 3D point onto a surface's UV parameter space.
+///
+/// Thin wrapper over [`vcad_kernel_geom::project_point_to_surface`], which
+/// holds the analytic per-surface projection logic.
+pub fn project_point_to_uv(surface: &dyn Surface, point: &Point3) -> Point2 {
+    #[cfg(test)]
+    PROJECTION_CALLS.with(|c| c.set(c.get() + 1));
 
-    best_uv
+    vcad_kernel_geom::project_point_to_surface(surface, point)
 }
 
 /// Project multiple 3D points to UV space on a surface.
@@ -434,19 +476,79 @@ fn project_points_to_uv(surface: &dyn Surface, points: &[Point3]) -> Vec<Point2>
 /// Samples the curve at regular intervals and checks which samples
 /// lie inside the face. Returns parameter ranges where the curve
 /// is inside the face.
+///
+/// Reads the thread-local [`vcad_kernel_math::geometry_tolerance`]; call
+/// [`trim_curve_to_face_with_tol`] with an explicit tolerance from code that
+/// may run on a worker thread (the pipeline passes
+/// [`BooleanParams::geom_tol`](crate::BooleanParams)).
 pub fn trim_curve_to_face(
     curve: &IntersectionCurve,
     face_id: FaceId,
     brep: &BRepSolid,
     n_samples: usize,
 ) -> Vec<TrimmedSegment> {
+    trim_curve_to_face_impl(
+        curve,
+        face_id,
+        brep,
+        n_samples,
+        None,
+        vcad_kernel_math::geometry_tolerance(),
+    )
+}
+
+/// Like [`trim_curve_to_face`], with an explicit coincidence tolerance.
+pub fn trim_curve_to_face_with_tol(
+    curve: &IntersectionCurve,
+    face_id: FaceId,
+    brep: &BRepSolid,
+    n_samples: usize,
+    tol: f64,
+) -> Vec<TrimmedSegment> {
+    trim_curve_to_face_impl(curve, face_id, brep, n_samples, None, tol)
+}
+
+/// Trim an intersection curve to the domain of a face, using cached UV loops.
+///
+/// Same as [`trim_curve_to_face`] but containment tests go through
+/// [`point_in_face_cached`], avoiding re-projection of the face's boundary
+/// loops at every sample.
+pub fn trim_curve_to_face_cached(
+    curve: &IntersectionCurve,
+    face_id: FaceId,
+    brep: &BRepSolid,
+    n_samples: usize,
+    cache: &FaceUvCache,
+) -> Vec<TrimmedSegment> {
+    trim_curve_to_face_impl(
+        curve,
+        face_id,
+        brep,
+        n_samples,
+        Some(cache),
+        vcad_kernel_math::geometry_tolerance(),
+    )
+}
+
+fn trim_curve_to_face_impl(
+    curve: &IntersectionCurve,
+    face_id: FaceId,
+    brep: &BRepSolid,
+    n_samples: usize,
+    cache: Option<&FaceUvCache>,
+    tol: f64,
+) -> Vec<TrimmedSegment> {
+    let inside = |p: &Point3| match cache {
+        Some(cache) => point_in_face_cached(brep, face_id, p, cache),
+        None => point_in_face_with_tol(brep, face_id, p, tol),
+    };
     let aabb = bbox::face_aabb(brep, face_id);
     let diag = (aabb.max - aabb.min).norm();
-    let merge_tol = (diag * 1e-6).max(1e-6);
+    let merge_tol = (diag * 1e-6).max(tol);
     match curve {
         IntersectionCurve::Empty => Vec::new(),
         IntersectionCurve::Point(p) => {
-            if point_in_face(brep, face_id, p) {
+            if inside(p) {
                 vec![TrimmedSegment {
                     t_start: 0.0,
                     t_end: 0.0,
@@ -526,8 +628,7 @@ pub fn trim_curve_to_face(
                 t_min,
                 t_max,
                 n_samples,
-                face_id,
-                brep,
+                &inside,
             );
             merge_segments(|t| line.origin + t * line.direction, &segments, merge_tol)
         }
@@ -544,8 +645,7 @@ pub fn trim_curve_to_face(
                 0.0,
                 2.0 * PI,
                 n_samples,
-                face_id,
-                brep,
+                &inside,
             );
             merge_segments(
                 |t| {
@@ -572,12 +672,12 @@ pub fn trim_curve_to_face(
 
             for (i, p) in points.iter().enumerate() {
                 let t = i as f64 / (n - 1).max(1) as f64;
-                let inside = point_in_face(brep, face_id, p);
+                let is_inside = inside(p);
 
-                if inside && !in_segment {
+                if is_inside && !in_segment {
                     seg_start = t;
                     in_segment = true;
-                } else if !inside && in_segment {
+                } else if !is_inside && in_segment {
                     segments.push(TrimmedSegment {
                         t_start: seg_start,
                         t_end: t,
@@ -598,7 +698,14 @@ pub fn trim_curve_to_face(
         IntersectionCurve::TwoLines(line1, _line2) => {
             // TwoLines should be expanded before calling this function.
             // If we get here, just process the first line.
-            trim_curve_to_face(&IntersectionCurve::Line(line1.clone()), face_id, brep, n_samples)
+            trim_curve_to_face_impl(
+                &IntersectionCurve::Line(line1.clone()),
+                face_id,
+                brep,
+                n_samples,
+                cache,
+                tol,
+            )
         }
     }
 }
@@ -608,8 +715,7 @@ fn refine_crossing(
     eval: &impl Fn(f64) -> Point3,
     t_inside: f64,
     t_outside: f64,
-    face_id: FaceId,
-    brep: &BRepSolid,
+    inside: &impl Fn(&Point3) -> bool,
     iterations: usize,
 ) -> f64 {
     let mut t_in = t_inside;
@@ -617,7 +723,7 @@ fn refine_crossing(
     for _ in 0..iterations {
         let t_mid = 0.5 * (t_in + t_out);
         let p = eval(t_mid);
-        if point_in_face(brep, face_id, &p) {
+        if inside(&p) {
             t_in = t_mid;
         } else {
             t_out = t_mid;
@@ -635,8 +741,7 @@ fn sample_and_trim(
     t_min: f64,
     t_max: f64,
     n_samples: usize,
-    face_id: FaceId,
-    brep: &BRepSolid,
+    inside: &impl Fn(&Point3) -> bool,
 ) -> Vec<TrimmedSegment> {
     let mut segments = Vec::new();
     let n = n_samples.max(2);
@@ -646,8 +751,7 @@ fn sample_and_trim(
     for i in 0..=n {
         let t = t_min + (t_max - t_min) * i as f64 / n as f64;
         let p = eval(t);
-        let inside = point_in_face(brep, face_id, &p);
-        samples.push((t, inside));
+        samples.push((t, inside(&p)));
     }
 
     // Find transitions and refine them
@@ -660,11 +764,11 @@ fn sample_and_trim(
 
         if inside_curr && !inside_prev {
             // Transition from outside to inside - refine to find exact entry
-            seg_start = refine_crossing(&eval, t_curr, t_prev, face_id, brep, 20);
+            seg_start = refine_crossing(&eval, t_curr, t_prev, inside, 20);
             in_segment = true;
         } else if !inside_curr && inside_prev {
             // Transition from inside to outside - refine to find exact exit
-            let seg_end = refine_crossing(&eval, t_prev, t_curr, face_id, brep, 20);
+            let seg_end = refine_crossing(&eval, t_prev, t_curr, inside, 20);
             if in_segment {
                 segments.push(TrimmedSegment {
                     t_start: seg_start,
@@ -712,7 +816,11 @@ fn merge_segments(
         return segments.to_vec();
     }
     let mut sorted = segments.to_vec();
-    sorted.sort_by(|a, b| a.t_start.partial_cmp(&b.t_start).unwrap_or(std::cmp::Ordering::Equal));
+    sorted.sort_by(|a, b| {
+        a.t_start
+            .partial_cmp(&b.t_start)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
     let mut merged = Vec::new();
     let mut current = sorted[0].clone();
     for next in sorted.into_iter().skip(1) {
@@ -861,6 +969,55 @@ mod tests {
         assert!(max_u - min_u < 2.0 * std::f64::consts::PI);
     }
 
+    #[test]
+    fn test_face_uv_cache_matches_uncached_trim() {
+        use vcad_kernel_geom::Line3d;
+        use vcad_kernel_math::Vec3;
+        use vcad_kernel_primitives::make_cube;
+
+        let brep = make_cube(10.0, 10.0, 10.0);
+        let cache = FaceUvCache::build(&brep);
+
+        // All six cube faces are planar polygons, so all should be cached.
+        assert_eq!(cache.faces.len(), brep.topology.faces.len());
+
+        // A line crossing the bottom face (z=0) along X at y=5.
+        let curve = IntersectionCurve::Line(Line3d {
+            origin: Point3::new(-5.0, 5.0, 0.0),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+        });
+        let bottom_face = brep
+            .topology
+            .faces
+            .iter()
+            .find(|(fid, _)| {
+                loop_verts_3d(&brep, brep.topology.faces[*fid].outer_loop)
+                    .iter()
+                    .all(|v| v.z.abs() < 1e-10)
+            })
+            .map(|(fid, _)| fid)
+            .expect("cube should have a z=0 face");
+
+        PROJECTION_CALLS.with(|c| c.set(0));
+        let uncached = trim_curve_to_face(&curve, bottom_face, &brep, 100);
+        let uncached_calls = PROJECTION_CALLS.with(|c| c.get());
+
+        PROJECTION_CALLS.with(|c| c.set(0));
+        let cached = trim_curve_to_face_cached(&curve, bottom_face, &brep, 100, &cache);
+        let cached_calls = PROJECTION_CALLS.with(|c| c.get());
+
+        // Same trimmed segments either way.
+        assert_eq!(uncached.len(), cached.len());
+        assert!(!cached.is_empty());
+        for (a, b) in uncached.iter().zip(cached.iter()) {
+            assert!((a.t_start - b.t_start).abs() < 1e-9);
+            assert!((a.t_end - b.t_end).abs() < 1e-9);
+        }
+
+        // The cached path only projects the test points, not the loop vertices.
+        assert!(cached_calls < uncached_calls);
+    }
+
     #[test]
     fn test_trim_empty_curve() {
         use vcad_kernel_primitives::make_cube;
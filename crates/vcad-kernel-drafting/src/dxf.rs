@@ -0,0 +1,441 @@
+//! DXF export for projected views.
+//!
+//! Writes minimal AutoCAD R12 (AC1009) files with a `VISIBLE` layer
+//! (continuous lines) and a `HIDDEN` layer (dashed lines), one LINE entity
+//! per projected edge. Multi-view drawings add a `BORDER` layer with the
+//! sheet frame and title block.
+
+use std::io::Write;
+
+use crate::types::{Point2D, ProjectedEdge, ProjectedView, Visibility};
+
+/// Standard sheet arrangements for multi-view drawings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawingLayout {
+    /// First-angle projection (ISO): top view below front, right view to
+    /// the left.
+    FirstAngle,
+    /// Third-angle projection (ANSI): top view above front, right view to
+    /// the right.
+    ThirdAngle,
+}
+
+/// Export a projected view to DXF format.
+///
+/// Visible edges go on the `VISIBLE` layer with continuous linetype, hidden
+/// edges on the `HIDDEN` layer with dashed linetype. Zero-length edges and
+/// coincident duplicates (projection collapses depth edges and overlays
+/// front/back outlines) are dropped, with visible edges winning over hidden
+/// ones at the same position. Returns the DXF file content as bytes.
+pub fn export_view_to_dxf(view: &ProjectedView) -> Vec<u8> {
+    let mut buffer = Vec::new();
+
+    write_header(&mut buffer);
+    write_tables(&mut buffer, false);
+
+    // Entities
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "SECTION").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "ENTITIES").unwrap();
+
+    write_view_edges(&mut buffer, view, 0.0, 0.0);
+
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "ENDSEC").unwrap();
+
+    // EOF
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "EOF").unwrap();
+
+    buffer
+}
+
+/// Export front/top/right/isometric views as one multi-view DXF drawing.
+///
+/// Views are placed on a 2×2 grid per the layout convention — third-angle
+/// puts the top view above the front and the right view to its right,
+/// first-angle mirrors the arrangement — with the isometric view in the
+/// remaining corner. A sheet border and title block go on the `BORDER`
+/// layer. Returns the DXF file content as bytes.
+pub fn export_drawing_to_dxf(
+    front: &ProjectedView,
+    top: &ProjectedView,
+    right: &ProjectedView,
+    iso: &ProjectedView,
+    layout: DrawingLayout,
+) -> Vec<u8> {
+    let views = [front, top, right, iso];
+
+    // Uniform cell size from the largest view so the grid stays aligned
+    let mut cell_w = 0.0_f64;
+    let mut cell_h = 0.0_f64;
+    for view in views {
+        if view.bounds.is_valid() {
+            cell_w = cell_w.max(view.bounds.max_x - view.bounds.min_x);
+            cell_h = cell_h.max(view.bounds.max_y - view.bounds.min_y);
+        }
+    }
+    cell_w = cell_w.max(1.0);
+    cell_h = cell_h.max(1.0);
+    let gap = 0.2 * cell_w.max(cell_h);
+
+    // Grid cells as (column, row), row 0 at the bottom
+    let cells: [(f64, f64); 4] = match layout {
+        DrawingLayout::ThirdAngle => [(0.0, 0.0), (0.0, 1.0), (1.0, 0.0), (1.0, 1.0)],
+        DrawingLayout::FirstAngle => [(1.0, 1.0), (1.0, 0.0), (0.0, 1.0), (0.0, 0.0)],
+    };
+
+    let mut buffer = Vec::new();
+    write_header(&mut buffer);
+    write_tables(&mut buffer, true);
+
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "SECTION").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "ENTITIES").unwrap();
+
+    for (view, (col, row)) in views.iter().zip(cells) {
+        if !view.bounds.is_valid() {
+            continue;
+        }
+        // Shift the view's minimum corner onto its cell origin
+        let dx = col * (cell_w + gap) - view.bounds.min_x;
+        let dy = row * (cell_h + gap) - view.bounds.min_y;
+        write_view_edges(&mut buffer, view, dx, dy);
+    }
+
+    // Sheet border with a title block in the bottom-right corner
+    let sheet_w = 2.0 * cell_w + gap;
+    let sheet_h = 2.0 * cell_h + gap;
+    let margin = gap;
+    let (bx0, by0) = (-margin, -margin);
+    let (bx1, by1) = (sheet_w + margin, sheet_h + margin);
+
+    write_line(&mut buffer, "BORDER", "CONTINUOUS", bx0, by0, bx1, by0);
+    write_line(&mut buffer, "BORDER", "CONTINUOUS", bx1, by0, bx1, by1);
+    write_line(&mut buffer, "BORDER", "CONTINUOUS", bx1, by1, bx0, by1);
+    write_line(&mut buffer, "BORDER", "CONTINUOUS", bx0, by1, bx0, by0);
+
+    let title_w = 0.35 * (bx1 - bx0);
+    let title_h = margin;
+    write_line(
+        &mut buffer,
+        "BORDER",
+        "CONTINUOUS",
+        bx1 - title_w,
+        by0,
+        bx1 - title_w,
+        by0 + title_h,
+    );
+    write_line(
+        &mut buffer,
+        "BORDER",
+        "CONTINUOUS",
+        bx1 - title_w,
+        by0 + title_h,
+        bx1,
+        by0 + title_h,
+    );
+
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "ENDSEC").unwrap();
+
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "EOF").unwrap();
+
+    buffer
+}
+
+/// Deduplicate a view's edges for export.
+///
+/// Drops zero-length edges and coincident duplicates at DXF precision
+/// (1e-6); visible edges are considered first so a hidden edge coinciding
+/// with a visible one is dropped.
+fn dedup_edges(view: &ProjectedView) -> Vec<&ProjectedEdge> {
+    let mut seen = std::collections::HashSet::new();
+    let mut edges = Vec::new();
+    for edge in view.visible_edges().chain(view.hidden_edges()) {
+        let q = |v: f64| (v * 1e6).round() as i64;
+        let a = (q(edge.start.x), q(edge.start.y));
+        let b = (q(edge.end.x), q(edge.end.y));
+        if a == b {
+            continue;
+        }
+        let key = if a <= b { (a, b) } else { (b, a) };
+        if seen.insert(key) {
+            edges.push(edge);
+        }
+    }
+    edges
+}
+
+/// Write a view's deduplicated edges as LINE entities, offset by `(dx, dy)`.
+fn write_view_edges(buffer: &mut Vec<u8>, view: &ProjectedView, dx: f64, dy: f64) {
+    for edge in dedup_edges(view) {
+        let (layer, linetype) = match edge.visibility {
+            Visibility::Visible => ("VISIBLE", "CONTINUOUS"),
+            Visibility::Hidden => ("HIDDEN", "HIDDEN"),
+        };
+        let start = Point2D::new(edge.start.x + dx, edge.start.y + dy);
+        let end = Point2D::new(edge.end.x + dx, edge.end.y + dy);
+        write_line(buffer, layer, linetype, start.x, start.y, end.x, end.y);
+    }
+}
+
+/// Write a single LINE entity.
+fn write_line(
+    buffer: &mut Vec<u8>,
+    layer: &str,
+    linetype: &str,
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+) {
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "LINE").unwrap();
+    writeln!(buffer, "8").unwrap();
+    writeln!(buffer, "{}", layer).unwrap();
+    writeln!(buffer, "6").unwrap();
+    writeln!(buffer, "{}", linetype).unwrap();
+    writeln!(buffer, "10").unwrap();
+    writeln!(buffer, "{:.6}", x1).unwrap();
+    writeln!(buffer, "20").unwrap();
+    writeln!(buffer, "{:.6}", y1).unwrap();
+    writeln!(buffer, "11").unwrap();
+    writeln!(buffer, "{:.6}", x2).unwrap();
+    writeln!(buffer, "21").unwrap();
+    writeln!(buffer, "{:.6}", y2).unwrap();
+}
+
+/// Write the HEADER section (R12, millimeter units).
+fn write_header(buffer: &mut Vec<u8>) {
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "SECTION").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "HEADER").unwrap();
+    writeln!(buffer, "9").unwrap();
+    writeln!(buffer, "$ACADVER").unwrap();
+    writeln!(buffer, "1").unwrap();
+    writeln!(buffer, "AC1009").unwrap();
+    writeln!(buffer, "9").unwrap();
+    writeln!(buffer, "$INSUNITS").unwrap();
+    writeln!(buffer, "70").unwrap();
+    writeln!(buffer, "4").unwrap();
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "ENDSEC").unwrap();
+}
+
+/// Write the TABLES section with linetypes and layers.
+fn write_tables(buffer: &mut Vec<u8>, with_border_layer: bool) {
+    let layer_count = if with_border_layer { 3 } else { 2 };
+
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "SECTION").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "TABLES").unwrap();
+
+    // Linetypes
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "TABLE").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "LTYPE").unwrap();
+    writeln!(buffer, "70").unwrap();
+    writeln!(buffer, "2").unwrap();
+
+    // Continuous
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "LTYPE").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "CONTINUOUS").unwrap();
+    writeln!(buffer, "70").unwrap();
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "3").unwrap();
+    writeln!(buffer, "Solid line").unwrap();
+    writeln!(buffer, "72").unwrap();
+    writeln!(buffer, "65").unwrap();
+    writeln!(buffer, "73").unwrap();
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "40").unwrap();
+    writeln!(buffer, "0.0").unwrap();
+
+    // Hidden
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "LTYPE").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "HIDDEN").unwrap();
+    writeln!(buffer, "70").unwrap();
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "3").unwrap();
+    writeln!(buffer, "Hidden line").unwrap();
+    writeln!(buffer, "72").unwrap();
+    writeln!(buffer, "65").unwrap();
+    writeln!(buffer, "73").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "40").unwrap();
+    writeln!(buffer, "9.525").unwrap();
+    writeln!(buffer, "49").unwrap();
+    writeln!(buffer, "6.35").unwrap();
+    writeln!(buffer, "49").unwrap();
+    writeln!(buffer, "-3.175").unwrap();
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "ENDTAB").unwrap();
+
+    // Layers
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "TABLE").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "LAYER").unwrap();
+    writeln!(buffer, "70").unwrap();
+    writeln!(buffer, "{}", layer_count).unwrap();
+
+    // VISIBLE layer
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "LAYER").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "VISIBLE").unwrap();
+    writeln!(buffer, "70").unwrap();
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "62").unwrap();
+    writeln!(buffer, "7").unwrap();
+    writeln!(buffer, "6").unwrap();
+    writeln!(buffer, "CONTINUOUS").unwrap();
+
+    // HIDDEN layer
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "LAYER").unwrap();
+    writeln!(buffer, "2").unwrap();
+    writeln!(buffer, "HIDDEN").unwrap();
+    writeln!(buffer, "70").unwrap();
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "62").unwrap();
+    writeln!(buffer, "8").unwrap();
+    writeln!(buffer, "6").unwrap();
+    writeln!(buffer, "HIDDEN").unwrap();
+
+    if with_border_layer {
+        // BORDER layer
+        writeln!(buffer, "0").unwrap();
+        writeln!(buffer, "LAYER").unwrap();
+        writeln!(buffer, "2").unwrap();
+        writeln!(buffer, "BORDER").unwrap();
+        writeln!(buffer, "70").unwrap();
+        writeln!(buffer, "0").unwrap();
+        writeln!(buffer, "62").unwrap();
+        writeln!(buffer, "5").unwrap();
+        writeln!(buffer, "6").unwrap();
+        writeln!(buffer, "CONTINUOUS").unwrap();
+    }
+
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "ENDTAB").unwrap();
+
+    writeln!(buffer, "0").unwrap();
+    writeln!(buffer, "ENDSEC").unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EdgeType, Point2D, ProjectedEdge, ViewDirection};
+
+    fn square_view(direction: ViewDirection, size: f64) -> ProjectedView {
+        let mut view = ProjectedView::new(direction);
+        let corners = [
+            (0.0, 0.0, size, 0.0),
+            (size, 0.0, size, size),
+            (size, size, 0.0, size),
+            (0.0, size, 0.0, 0.0),
+        ];
+        for (x1, y1, x2, y2) in corners {
+            view.add_edge(ProjectedEdge::new(
+                Point2D::new(x1, y1),
+                Point2D::new(x2, y2),
+                Visibility::Visible,
+                EdgeType::Sharp,
+                0.0,
+            ));
+        }
+        view
+    }
+
+    #[test]
+    fn test_export_view_to_dxf_layers_and_entities() {
+        let mut view = ProjectedView::new(ViewDirection::Front);
+        view.add_edge(ProjectedEdge::new(
+            Point2D::new(0.0, 0.0),
+            Point2D::new(10.0, 0.0),
+            Visibility::Visible,
+            EdgeType::Sharp,
+            0.0,
+        ));
+        view.add_edge(ProjectedEdge::new(
+            Point2D::new(0.0, 5.0),
+            Point2D::new(10.0, 5.0),
+            Visibility::Hidden,
+            EdgeType::Sharp,
+            0.0,
+        ));
+
+        let bytes = export_view_to_dxf(&view);
+        let text = String::from_utf8(bytes).unwrap();
+
+        assert!(text.starts_with("0\nSECTION"));
+        assert!(text.ends_with("0\nEOF\n"));
+        assert_eq!(text.matches("\nLINE\n").count(), 2);
+        assert_eq!(text.matches("\nVISIBLE\n").count(), 2); // layer table + entity
+        assert!(text.contains("10.000000"));
+    }
+
+    #[test]
+    fn test_export_view_to_dxf_drops_duplicates_and_zero_length() {
+        let mut view = ProjectedView::new(ViewDirection::Front);
+        let edge = ProjectedEdge::new(
+            Point2D::new(0.0, 0.0),
+            Point2D::new(10.0, 0.0),
+            Visibility::Visible,
+            EdgeType::Sharp,
+            0.0,
+        );
+        view.add_edge(edge.clone());
+        // Hidden duplicate with reversed direction and a zero-length edge
+        view.add_edge(ProjectedEdge::new(
+            Point2D::new(10.0, 0.0),
+            Point2D::new(0.0, 0.0),
+            Visibility::Hidden,
+            EdgeType::Sharp,
+            5.0,
+        ));
+        view.add_edge(ProjectedEdge::new(
+            Point2D::new(3.0, 3.0),
+            Point2D::new(3.0, 3.0),
+            Visibility::Visible,
+            EdgeType::Sharp,
+            0.0,
+        ));
+
+        let bytes = export_view_to_dxf(&view);
+        let text = String::from_utf8(bytes).unwrap();
+        assert_eq!(text.matches("\nLINE\n").count(), 1);
+    }
+
+    #[test]
+    fn test_export_drawing_to_dxf_places_views_and_border() {
+        let front = square_view(ViewDirection::Front, 10.0);
+        let top = square_view(ViewDirection::Top, 10.0);
+        let right = square_view(ViewDirection::Right, 10.0);
+        let iso = square_view(ViewDirection::ISOMETRIC_STANDARD, 10.0);
+
+        let bytes = export_drawing_to_dxf(&front, &top, &right, &iso, DrawingLayout::ThirdAngle);
+        let text = String::from_utf8(bytes).unwrap();
+
+        // 4 views × 4 edges, plus 4 border lines and 2 title-block lines
+        assert_eq!(text.matches("\nLINE\n").count(), 22);
+        assert!(text.contains("BORDER"));
+
+        // Third-angle: the top view sits above the front view, the right
+        // view to its right (cells are 10 wide/high with a 2mm gap)
+        assert!(text.contains("22.000000")); // offset into second row/column
+    }
+}
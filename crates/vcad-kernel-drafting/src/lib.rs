@@ -37,6 +37,7 @@
 
 pub mod detail;
 pub mod dimension;
+pub mod dxf;
 pub mod edge_extract;
 pub mod hidden_line;
 pub mod projection;
@@ -51,6 +52,7 @@ pub use dimension::{
     LinearDimensionType, MaterialCondition, OrdinateDimension, RadialDimension, RenderedArc,
     RenderedArrow, RenderedDimension, RenderedText, TextAlignment, TextPlacement, ToleranceMode,
 };
+pub use dxf::{export_drawing_to_dxf, export_view_to_dxf, DrawingLayout};
 pub use edge_extract::{
     extract_drawing_edges, extract_edges, extract_sharp_edges, extract_silhouette_edges,
     DEFAULT_SHARP_ANGLE,
@@ -62,9 +64,9 @@ pub use section::{
     section_mesh,
 };
 pub use types::{
-    BoundingBox2D, DetailView, DetailViewParams, EdgeType, HatchPattern, HatchRegion, MeshEdge,
-    Point2D, ProjectedEdge, ProjectedView, SectionCurve, SectionPlane, SectionView, Triangle3D,
-    ViewDirection, Visibility,
+    BoundingBox2D, DetailView, DetailViewParams, EdgeType, HatchPattern, HatchRegion, HatchStyle,
+    MeshEdge, Point2D, ProjectedEdge, ProjectedView, SectionCurve, SectionPlane, SectionView,
+    Triangle3D, ViewDirection, Visibility,
 };
 
 #[cfg(test)]
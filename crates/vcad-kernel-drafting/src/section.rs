@@ -10,7 +10,9 @@ use std::collections::HashMap;
 use vcad_kernel_math::{Point3, Vec3};
 use vcad_kernel_tessellate::TriangleMesh;
 
-use crate::types::{BoundingBox2D, HatchPattern, Point2D, SectionCurve, SectionPlane, SectionView};
+use crate::types::{
+    BoundingBox2D, HatchPattern, HatchStyle, Point2D, SectionCurve, SectionPlane, SectionView,
+};
 
 /// Default tolerance for geometric comparisons (in mm).
 const DEFAULT_TOLERANCE: f64 = 1e-6;
@@ -307,14 +309,34 @@ pub fn project_to_section_plane(
 
 /// Generate hatch lines for a region with optional holes.
 ///
-/// Creates parallel lines at the specified angle and spacing, clipped to the
-/// boundary polygon and excluding any holes.
+/// Creates parallel lines at the pattern angle and spacing, clipped to the
+/// boundary polygon and excluding any holes. Cross-style patterns add a
+/// second set of lines rotated 90° from the first.
 pub fn generate_hatch_lines(
     boundary: &[Point2D],
     holes: &[Vec<Point2D>],
     pattern: &HatchPattern,
 ) -> Vec<(Point2D, Point2D)> {
-    if boundary.len() < 3 || pattern.spacing <= 0.0 {
+    let mut lines = generate_parallel_lines(boundary, holes, pattern.spacing, pattern.angle);
+    if pattern.style == HatchStyle::Cross {
+        lines.extend(generate_parallel_lines(
+            boundary,
+            holes,
+            pattern.spacing,
+            pattern.angle + std::f64::consts::FRAC_PI_2,
+        ));
+    }
+    lines
+}
+
+/// Generate a single set of parallel hatch lines at one angle.
+fn generate_parallel_lines(
+    boundary: &[Point2D],
+    holes: &[Vec<Point2D>],
+    spacing: f64,
+    angle: f64,
+) -> Vec<(Point2D, Point2D)> {
+    if boundary.len() < 3 || spacing <= 0.0 {
         return Vec::new();
     }
 
@@ -332,15 +354,15 @@ pub fn generate_hatch_lines(
     }
 
     // Expand bounds slightly for safety
-    let margin = pattern.spacing * 2.0;
+    let margin = spacing * 2.0;
     min_x -= margin;
     max_x += margin;
     min_y -= margin;
     max_y += margin;
 
     // Hatch direction
-    let cos_a = pattern.angle.cos();
-    let sin_a = pattern.angle.sin();
+    let cos_a = angle.cos();
+    let sin_a = angle.sin();
 
     // Direction vector along hatch lines
     let dir = Point2D::new(cos_a, sin_a);
@@ -390,7 +412,7 @@ pub fn generate_hatch_lines(
             hatch_lines.extend(final_segments);
         }
 
-        offset += pattern.spacing;
+        offset += spacing;
     }
 
     hatch_lines
@@ -673,34 +695,42 @@ pub fn section_mesh(
 
     // Step 5: Generate hatch lines if pattern provided
     let hatch_lines = if let Some(pattern) = hatch_pattern {
-        // Find closed curves to use as boundaries
         let mut all_hatch_lines = Vec::new();
 
-        for curve in &curves {
-            if curve.is_closed && curve.points.len() >= 3 {
-                // Use this closed curve as a hatch boundary
-                // For simplicity, treat other closed curves as holes if they're inside this one
-                let holes: Vec<Vec<Point2D>> = curves
-                    .iter()
-                    .filter(|c| c.is_closed && c.points.len() >= 3)
-                    .filter(|c| {
-                        // Check if this curve's center is inside the boundary
-                        if c.points.is_empty() || std::ptr::eq(*c, curve) {
-                            return false;
-                        }
-                        let center = c.points.iter().fold(Point2D::new(0.0, 0.0), |acc, p| {
-                            Point2D::new(acc.x + p.x, acc.y + p.y)
-                        });
-                        let n = c.points.len() as f64;
-                        let center = Point2D::new(center.x / n, center.y / n);
-                        point_in_polygon(&center, &curve.points)
-                    })
-                    .map(|c| c.points.clone())
-                    .collect();
-
-                let lines = generate_hatch_lines(&curve.points, &holes, pattern);
-                all_hatch_lines.extend(lines);
+        let closed_curves: Vec<&SectionCurve> = curves
+            .iter()
+            .filter(|c| c.is_closed && c.points.len() >= 3)
+            .collect();
+
+        // Containment depth of each closed curve: how many other closed
+        // curves enclose it. Even depth = solid boundary to hatch; odd
+        // depth = hole, which only clips its parent's hatching. A boundary
+        // vertex is used as the representative point — unlike the centroid,
+        // it always lies on the region (annular outlines have their centroid
+        // inside the hole).
+        let depth = |curve: &SectionCurve| {
+            closed_curves
+                .iter()
+                .filter(|c| !std::ptr::eq(**c, curve))
+                .filter(|c| point_in_polygon(&curve.points[0], &c.points))
+                .count()
+        };
+
+        for curve in &closed_curves {
+            if depth(curve) % 2 != 0 {
+                continue;
             }
+
+            // Treat closed curves inside this one as holes
+            let holes: Vec<Vec<Point2D>> = closed_curves
+                .iter()
+                .filter(|c| !std::ptr::eq(**c, *curve))
+                .filter(|c| point_in_polygon(&c.points[0], &curve.points))
+                .map(|c| c.points.clone())
+                .collect();
+
+            let lines = generate_hatch_lines(&curve.points, &holes, pattern);
+            all_hatch_lines.extend(lines);
         }
 
         all_hatch_lines
@@ -889,5 +919,103 @@ mod tests {
         let pattern = HatchPattern::default();
         assert!((pattern.angle - std::f64::consts::FRAC_PI_4).abs() < 1e-10);
         assert!((pattern.spacing - 2.0).abs() < 1e-10);
+        assert_eq!(pattern.style, crate::types::HatchStyle::Lines);
+    }
+
+    /// Create a tube (hollow cylinder) mesh centered on the Z axis.
+    fn make_tube(outer_r: f64, inner_r: f64, height: f64, segments: usize) -> TriangleMesh {
+        let mut vertices: Vec<f32> = Vec::new();
+        // Rings: outer bottom, outer top, inner bottom, inner top
+        for (r, z) in [
+            (outer_r, 0.0),
+            (outer_r, height),
+            (inner_r, 0.0),
+            (inner_r, height),
+        ] {
+            for i in 0..segments {
+                let a = 2.0 * std::f64::consts::PI * i as f64 / segments as f64;
+                vertices.push((r * a.cos()) as f32);
+                vertices.push((r * a.sin()) as f32);
+                vertices.push(z as f32);
+            }
+        }
+
+        let n = segments as u32;
+        let (ob, ot, ib, it) = (0, n, 2 * n, 3 * n);
+        let mut indices: Vec<u32> = Vec::new();
+        let mut quad = |a: u32, b: u32, c: u32, d: u32| {
+            indices.extend_from_slice(&[a, b, c, a, c, d]);
+        };
+        for i in 0..n {
+            let j = (i + 1) % n;
+            // Outer wall, inner wall, bottom and top annuli
+            quad(ob + i, ob + j, ot + j, ot + i);
+            quad(ib + i, it + i, it + j, ib + j);
+            quad(ob + i, ib + i, ib + j, ob + j);
+            quad(ot + i, ot + j, it + j, it + i);
+        }
+
+        TriangleMesh {
+            vertices,
+            indices,
+            normals: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_tube_section_hatch_avoids_hole() {
+        let mesh = make_tube(10.0, 5.0, 10.0, 48);
+        let plane = SectionPlane::horizontal(5.0);
+        let pattern = HatchPattern::new(1.0, 0.0);
+
+        let view = section_mesh(&mesh, &plane, Some(&pattern));
+
+        // Outer and inner boundary curves
+        assert_eq!(view.curves.len(), 2, "Should have 2 curves");
+        assert!(!view.hatch_lines.is_empty(), "Should have hatch lines");
+
+        // No hatch segment may pass through the central hole; sample along
+        // each segment and check the distance from the tube axis. The inner
+        // boundary is a 48-gon, so allow a small chord-sagitta tolerance.
+        for (p0, p1) in &view.hatch_lines {
+            for k in 0..=10 {
+                let t = k as f64 / 10.0;
+                let x = p0.x + t * (p1.x - p0.x);
+                let y = p0.y + t * (p1.y - p0.y);
+                let r = (x * x + y * y).sqrt();
+                assert!(r > 4.9, "hatch line enters hole at r={r}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cube_section_cross_hatch_two_angles() {
+        let mesh = make_cube(10.0);
+        let plane = SectionPlane::horizontal(5.0);
+        let pattern = HatchPattern::cross(1.0, 0.0);
+
+        let view = section_mesh(&mesh, &plane, Some(&pattern));
+        assert!(!view.hatch_lines.is_empty(), "Should have hatch lines");
+
+        // Bucket line directions by angle modulo π
+        let mut horizontal = 0;
+        let mut vertical = 0;
+        for (p0, p1) in &view.hatch_lines {
+            let angle = (p1.y - p0.y)
+                .atan2(p1.x - p0.x)
+                .rem_euclid(std::f64::consts::PI);
+            if angle < 1e-6 || (std::f64::consts::PI - angle) < 1e-6 {
+                horizontal += 1;
+            } else if (angle - std::f64::consts::FRAC_PI_2).abs() < 1e-6 {
+                vertical += 1;
+            } else {
+                panic!("unexpected hatch angle {angle}");
+            }
+        }
+        assert!(
+            horizontal >= 5,
+            "expected horizontal lines, got {horizontal}"
+        );
+        assert!(vertical >= 5, "expected vertical lines, got {vertical}");
     }
 }
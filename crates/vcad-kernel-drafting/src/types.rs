@@ -481,6 +481,17 @@ impl SectionCurve {
     }
 }
 
+/// Style of a hatch pattern: how many line sets are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HatchStyle {
+    /// Single set of parallel lines (ANSI31-style).
+    #[default]
+    Lines,
+    /// Two sets of parallel lines 90° apart (cross-hatch / grid).
+    Cross,
+}
+
 /// Cross-hatching pattern for solid regions.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct HatchPattern {
@@ -488,27 +499,55 @@ pub struct HatchPattern {
     pub spacing: f64,
     /// Direction in radians (0 = horizontal, π/4 = 45°).
     pub angle: f64,
+    /// Pattern style (serialized as `"pattern"` in hatch JSON).
+    #[serde(default, rename = "pattern")]
+    pub style: HatchStyle,
 }
 
 impl HatchPattern {
-    /// Create a new hatch pattern.
+    /// Create a new single-direction hatch pattern.
     pub fn new(spacing: f64, angle: f64) -> Self {
-        Self { spacing, angle }
+        Self {
+            spacing,
+            angle,
+            style: HatchStyle::Lines,
+        }
     }
 
     /// Standard 45-degree hatch at 2mm spacing.
     pub const STANDARD_45: Self = Self {
         spacing: 2.0,
         angle: std::f64::consts::FRAC_PI_4,
+        style: HatchStyle::Lines,
     };
 
+    /// ANSI31 (general steel): 45° parallel lines at 1/8" (3.175mm) spacing.
+    pub fn ansi31() -> Self {
+        Self::new(3.175, std::f64::consts::FRAC_PI_4)
+    }
+
     /// Horizontal hatch at specified spacing.
     pub fn horizontal(spacing: f64) -> Self {
         Self {
             spacing,
             angle: 0.0,
+            style: HatchStyle::Lines,
+        }
+    }
+
+    /// Cross-hatch: two line sets at `angle` and `angle + 90°`.
+    pub fn cross(spacing: f64, angle: f64) -> Self {
+        Self {
+            spacing,
+            angle,
+            style: HatchStyle::Cross,
         }
     }
+
+    /// Grid: horizontal and vertical lines at the given spacing.
+    pub fn grid(spacing: f64) -> Self {
+        Self::cross(spacing, 0.0)
+    }
 }
 
 impl Default for HatchPattern {
@@ -605,7 +644,13 @@ pub struct DetailViewParams {
 
 impl DetailViewParams {
     /// Create new detail view parameters.
-    pub fn new(center: Point2D, scale: f64, width: f64, height: f64, label: impl Into<String>) -> Self {
+    pub fn new(
+        center: Point2D,
+        scale: f64,
+        width: f64,
+        height: f64,
+        label: impl Into<String>,
+    ) -> Self {
         Self {
             center,
             scale,
@@ -669,12 +714,18 @@ impl DetailView {
 
     /// Number of visible edges.
     pub fn num_visible(&self) -> usize {
-        self.edges.iter().filter(|e| e.visibility == Visibility::Visible).count()
+        self.edges
+            .iter()
+            .filter(|e| e.visibility == Visibility::Visible)
+            .count()
     }
 
     /// Number of hidden edges.
     pub fn num_hidden(&self) -> usize {
-        self.edges.iter().filter(|e| e.visibility == Visibility::Hidden).count()
+        self.edges
+            .iter()
+            .filter(|e| e.visibility == Visibility::Hidden)
+            .count()
     }
 }
 
@@ -34,7 +34,6 @@ struct FaceInfo {
 /// Information about an edge.
 #[derive(Debug, Clone)]
 struct EdgeInfo {
-    #[allow(dead_code)]
     edge_id: EdgeId,
     /// Start vertex (origin of the primary half-edge).
     v_start: VertexId,
@@ -705,6 +704,446 @@ pub fn fillet_all_edges(brep: &BRepSolid, radius: f64) -> BRepSolid {
     }
 }
 
+// =============================================================================
+// Chain fillet
+// =============================================================================
+
+/// Unordered vertex pairs of the edges being filleted, stored in both
+/// orientations so loop walks can test membership directly.
+type ChainPairs = std::collections::HashSet<(VertexId, VertexId)>;
+
+/// Compute trim vertices when only a subset of edges is filleted.
+///
+/// Same trim-line intersection as [`compute_trim_vertices`], except each of
+/// the two edges at a vertex is offset by `distance` only if it belongs to
+/// the chain (otherwise its offset is zero). Vertices touching no chain edge
+/// keep their original position.
+fn compute_chain_trim_vertices(
+    faces: &[FaceInfo],
+    chain_pairs: &ChainPairs,
+    distance: f64,
+) -> HashMap<TrimKey, Point3> {
+    let mut trims = HashMap::new();
+
+    for face in faces {
+        let n = face.vertex_ids.len();
+        let normal = face.normal;
+
+        for i in 0..n {
+            let v_id = face.vertex_ids[i];
+            let v_pos = face.positions[i];
+            let prev_idx = (i + n - 1) % n;
+            let next_idx = (i + 1) % n;
+
+            // Offset only the edges that are part of the chain
+            let d_enter_off = if chain_pairs.contains(&(face.vertex_ids[prev_idx], v_id)) {
+                distance
+            } else {
+                0.0
+            };
+            let d_leave_off = if chain_pairs.contains(&(v_id, face.vertex_ids[next_idx])) {
+                distance
+            } else {
+                0.0
+            };
+
+            if d_enter_off == 0.0 && d_leave_off == 0.0 {
+                trims.insert((v_id, face.face_id), v_pos);
+                continue;
+            }
+
+            let prev_pos = face.positions[prev_idx];
+            let d_enter = v_pos - prev_pos;
+            let d_enter_len = d_enter.norm();
+            let next_pos = face.positions[next_idx];
+            let d_leave = next_pos - v_pos;
+            let d_leave_len = d_leave.norm();
+
+            if d_enter_len < 1e-15 || d_leave_len < 1e-15 {
+                trims.insert((v_id, face.face_id), v_pos);
+                continue;
+            }
+
+            let d_enter = d_enter / d_enter_len;
+            let d_leave = d_leave / d_leave_len;
+
+            let perp_enter = normal.cross(&d_enter);
+            let pe_len = perp_enter.norm();
+            let perp_leave = normal.cross(&d_leave);
+            let pl_len = perp_leave.norm();
+
+            if pe_len < 1e-15 || pl_len < 1e-15 {
+                trims.insert((v_id, face.face_id), v_pos);
+                continue;
+            }
+
+            let perp_enter = perp_enter / pe_len;
+            let perp_leave = perp_leave / pl_len;
+
+            // Same intersection as compute_trim_vertices, with per-edge offsets
+            let delta = d_enter_off * perp_enter - d_leave_off * perp_leave;
+            let cross_dirs = d_enter.cross(&d_leave);
+            let denom = cross_dirs.dot(&normal);
+
+            if denom.abs() < 1e-15 {
+                // Parallel (tangent-continuous) edges — offsets agree, just shift
+                let p = v_pos + 0.5 * (d_enter_off * perp_enter + d_leave_off * perp_leave);
+                trims.insert((v_id, face.face_id), p);
+                continue;
+            }
+
+            let cross_delta = delta.cross(&d_leave);
+            let t1 = -cross_delta.dot(&normal) / denom;
+
+            let p1 = v_pos + d_enter_off * perp_enter;
+            let trim_point = Point3::from(p1.coords + t1 * d_enter);
+            trims.insert((v_id, face.face_id), trim_point);
+        }
+    }
+
+    trims
+}
+
+/// Fillet a connected chain of edges with a constant radius.
+///
+/// Unlike [`fillet_all_edges`], only the listed edges are blended. The chain
+/// is treated as one feature: where two chain edges meet at a vertex the
+/// adjacent blend faces share their boundary vertices, so the fillets join
+/// without gaps instead of behaving like independent quarter-cylinders. At
+/// junctions where the blends do not meet edge-to-edge a planar transition
+/// patch is inserted, and at open chain ends a small cap closes the solid.
+///
+/// Edges not in the chain (and faces not adjacent to it) are left untouched.
+///
+/// # Requirements
+///
+/// - All faces must be planar
+/// - The solid should be convex around the chain
+/// - Radius must be positive and smaller than the shortest adjacent edge / 2
+pub fn fillet_edge_chain(brep: &BRepSolid, chain: &[EdgeId], radius: f64) -> BRepSolid {
+    let faces = extract_faces(brep);
+    let edges = extract_edges(brep);
+
+    let chain_set: std::collections::HashSet<EdgeId> = chain.iter().copied().collect();
+    let chain_edges: Vec<&EdgeInfo> = edges
+        .iter()
+        .filter(|e| chain_set.contains(&e.edge_id))
+        .collect();
+
+    if chain_edges.is_empty() {
+        return brep.clone();
+    }
+
+    let mut chain_pairs: ChainPairs = ChainPairs::new();
+    let mut chain_vertices: std::collections::HashSet<VertexId> = std::collections::HashSet::new();
+    for edge in &chain_edges {
+        chain_pairs.insert((edge.v_start, edge.v_end));
+        chain_pairs.insert((edge.v_end, edge.v_start));
+        chain_vertices.insert(edge.v_start);
+        chain_vertices.insert(edge.v_end);
+    }
+
+    let trims = compute_chain_trim_vertices(&faces, &chain_pairs, radius);
+    let face_map: HashMap<FaceId, &FaceInfo> = faces.iter().map(|f| (f.face_id, f)).collect();
+
+    let mut new_topo = Topology::new();
+    let mut new_geom = GeometryStore::new();
+    let mut vertex_cache: HashMap<[i64; 3], VertexId> = HashMap::new();
+
+    let get_or_create_vertex =
+        |cache: &mut HashMap<[i64; 3], VertexId>, topo: &mut Topology, pos: Point3| -> VertexId {
+            let key = quantize(pos);
+            *cache.entry(key).or_insert_with(|| topo.add_vertex(pos))
+        };
+
+    let mut all_faces = Vec::new();
+
+    // 1. Build modified original faces. Faces away from the chain keep their
+    //    original positions because every trim there equals the vertex itself.
+    for face in &faces {
+        let new_positions: Vec<Point3> = face
+            .vertex_ids
+            .iter()
+            .filter_map(|&v_id| trims.get(&(v_id, face.face_id)).copied())
+            .collect();
+
+        if new_positions.len() < 3 {
+            continue;
+        }
+
+        let verts: Vec<VertexId> = new_positions
+            .iter()
+            .map(|p| get_or_create_vertex(&mut vertex_cache, &mut new_topo, *p))
+            .collect();
+
+        let p0 = new_positions[0];
+        let x_dir = new_positions[1] - p0;
+        let y_dir = new_positions[new_positions.len() - 1] - p0;
+        let surf_idx = if x_dir.norm() > 1e-12 && y_dir.norm() > 1e-12 {
+            new_geom.add_surface(Box::new(Plane::new(p0, x_dir, y_dir)))
+        } else {
+            new_geom.add_surface(Box::new(Plane::from_normal(p0, face.normal)))
+        };
+
+        let hes: Vec<HalfEdgeId> = verts.iter().map(|&v| new_topo.add_half_edge(v)).collect();
+        let loop_id = new_topo.add_loop(&hes);
+        let face_id = new_topo.add_face(loop_id, surf_idx, Orientation::Forward);
+        all_faces.push(face_id);
+    }
+
+    // 2. Build cylindrical blend faces for the chain edges only
+    for edge_info in &chain_edges {
+        let fa = face_map[&edge_info.face_a];
+        let fb = face_map[&edge_info.face_b];
+
+        let pa_s = trims.get(&(edge_info.v_start, edge_info.face_a));
+        let pa_e = trims.get(&(edge_info.v_end, edge_info.face_a));
+        let pb_s = trims.get(&(edge_info.v_start, edge_info.face_b));
+        let pb_e = trims.get(&(edge_info.v_end, edge_info.face_b));
+
+        if let (Some(&pa_s), Some(&pa_e), Some(&pb_s), Some(&pb_e)) = (pa_s, pa_e, pb_s, pb_e) {
+            let v_start_pos = brep.topology.vertices[edge_info.v_start].point;
+            let v_end_pos = brep.topology.vertices[edge_info.v_end].point;
+            let edge_dir = v_end_pos - v_start_pos;
+            let edge_len = edge_dir.norm();
+            if edge_len < 1e-12 {
+                continue;
+            }
+            let edge_unit = edge_dir / edge_len;
+
+            // Blend axis sits inside the solid, at distance r from both faces
+            let center_start = v_start_pos - radius * (fa.normal + fb.normal);
+
+            let to_tangent_a = pa_s - center_start;
+            let ref_dir = to_tangent_a - to_tangent_a.dot(&edge_unit) * edge_unit;
+            let ref_len = ref_dir.norm();
+            if ref_len < 1e-12 {
+                continue;
+            }
+
+            let cyl_surface = CylinderSurface {
+                center: center_start,
+                axis: Dir3::new_normalize(edge_unit),
+                ref_dir: Dir3::new_normalize(ref_dir),
+                radius,
+            };
+            let surf_idx = new_geom.add_surface(Box::new(cyl_surface));
+
+            let solid_center = compute_centroid(&faces);
+            let blend_center =
+                Point3::from((pa_s.coords + pa_e.coords + pb_e.coords + pb_s.coords) * 0.25);
+            let outward = blend_center - solid_center;
+
+            let e1 = pa_e - pa_s;
+            let e2 = pb_s - pa_s;
+            let n = e1.cross(&e2);
+
+            let positions = if n.dot(&outward) > 0.0 {
+                vec![pa_s, pa_e, pb_e, pb_s]
+            } else {
+                vec![pa_s, pb_s, pb_e, pa_e]
+            };
+
+            let verts: Vec<VertexId> = positions
+                .iter()
+                .map(|p| get_or_create_vertex(&mut vertex_cache, &mut new_topo, *p))
+                .collect();
+
+            let hes: Vec<HalfEdgeId> = verts.iter().map(|&v| new_topo.add_half_edge(v)).collect();
+            let loop_id = new_topo.add_loop(&hes);
+            let face_id = new_topo.add_face(loop_id, surf_idx, Orientation::Forward);
+            all_faces.push(face_id);
+        }
+    }
+
+    // 3. Close junctions and open chain ends. At each chain vertex collect
+    //    the distinct trim positions of every incident face; when neighbouring
+    //    blends already share an edge there are fewer than three distinct
+    //    points and no patch is needed (the twins pair up in step 4).
+    for &v_id in &chain_vertices {
+        let v_pos = brep.topology.vertices[v_id].point;
+
+        let mut patch_points: Vec<Point3> = Vec::new();
+        let mut seen: std::collections::HashSet<[i64; 3]> = std::collections::HashSet::new();
+        for face in &faces {
+            if face.vertex_ids.contains(&v_id) {
+                if let Some(&p) = trims.get(&(v_id, face.face_id)) {
+                    if seen.insert(quantize(p)) {
+                        patch_points.push(p);
+                    }
+                }
+            }
+        }
+
+        if patch_points.len() < 3 {
+            continue;
+        }
+
+        // Sort by angle around the axis from solid center to vertex
+        let solid_center = compute_centroid(&faces);
+        let axis = (v_pos - solid_center).normalize();
+
+        let arbitrary = if axis.x.abs() < 0.9 {
+            Vec3::x()
+        } else {
+            Vec3::y()
+        };
+        let u_dir = axis.cross(&arbitrary).normalize();
+        let v_dir = axis.cross(&u_dir);
+
+        let center = patch_points
+            .iter()
+            .fold(Vec3::zeros(), |acc, p| acc + p.coords)
+            / patch_points.len() as f64;
+        let center = Point3::from(center);
+
+        let mut indexed: Vec<(usize, f64)> = patch_points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let d = *p - center;
+                (i, d.dot(&v_dir).atan2(d.dot(&u_dir)))
+            })
+            .collect();
+        indexed.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let sorted_positions: Vec<Point3> = indexed.iter().map(|(i, _)| patch_points[*i]).collect();
+
+        let e1 = sorted_positions[1] - sorted_positions[0];
+        let e2 = sorted_positions[2] - sorted_positions[0];
+        let n = e1.cross(&e2);
+        let outward = center - solid_center;
+
+        let final_positions = if n.dot(&outward) > 0.0 {
+            sorted_positions
+        } else {
+            let mut rev = sorted_positions;
+            rev.reverse();
+            rev
+        };
+
+        let verts: Vec<VertexId> = final_positions
+            .iter()
+            .map(|p| get_or_create_vertex(&mut vertex_cache, &mut new_topo, *p))
+            .collect();
+
+        let x_dir = final_positions[1] - final_positions[0];
+        let y_dir = final_positions[final_positions.len() - 1] - final_positions[0];
+        let surf_idx = new_geom.add_surface(Box::new(Plane::new(final_positions[0], x_dir, y_dir)));
+
+        let hes: Vec<HalfEdgeId> = verts.iter().map(|&v| new_topo.add_half_edge(v)).collect();
+        let loop_id = new_topo.add_loop(&hes);
+        let face_id = new_topo.add_face(loop_id, surf_idx, Orientation::Forward);
+        all_faces.push(face_id);
+    }
+
+    // 4. Pair twin half-edges
+    pair_twin_half_edges(&mut new_topo);
+
+    // 5. Build shell and solid
+    let shell = new_topo.add_shell(all_faces, ShellType::Outer);
+    let solid_id = new_topo.add_solid(shell);
+
+    BRepSolid {
+        topology: new_topo,
+        geometry: new_geom,
+        solid_id,
+    }
+}
+
+// =============================================================================
+// Fillet preview
+// =============================================================================
+
+/// Per-edge feasibility and extent report for a prospective fillet,
+/// returned by [`fillet_preview`].
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeFilletPreview {
+    /// The edge this entry describes.
+    pub edge_id: EdgeId,
+    /// Whether the edge can be blended at the requested radius.
+    pub filletable: bool,
+    /// Approximate bounding box `(min, max)` of the prospective blend
+    /// face, present for filletable edges.
+    pub bounds: Option<([f64; 3], [f64; 3])>,
+}
+
+/// Report which of the requested edges can be filleted at `radius` and
+/// the approximate extent of each blend, without building the result.
+///
+/// An edge is rejected when the radius is not positive, the edge has no
+/// two adjacent faces, or the radius reaches half the length of any
+/// boundary edge of its adjacent faces (the trim would consume the face).
+/// For filletable edges the bounding box covers the edge and its tangent
+/// trim points on both faces. Results are returned in request order.
+pub fn fillet_preview(brep: &BRepSolid, edges: &[EdgeId], radius: f64) -> Vec<EdgeFilletPreview> {
+    let faces = extract_faces(brep);
+    let all_edges = extract_edges(brep);
+    let edge_map: HashMap<EdgeId, &EdgeInfo> = all_edges.iter().map(|e| (e.edge_id, e)).collect();
+    let face_map: HashMap<FaceId, &FaceInfo> = faces.iter().map(|f| (f.face_id, f)).collect();
+
+    let min_edge_len = |face: &FaceInfo| -> f64 {
+        let n = face.positions.len();
+        (0..n)
+            .map(|i| (face.positions[(i + 1) % n] - face.positions[i]).norm())
+            .fold(f64::MAX, f64::min)
+    };
+
+    let filletable = |edge: &EdgeInfo| -> bool {
+        let limit = min_edge_len(face_map[&edge.face_a]).min(min_edge_len(face_map[&edge.face_b]));
+        radius > 0.0 && radius < 0.5 * limit
+    };
+
+    // Trim positions for the feasible subset, as fillet_edge_chain would use
+    let mut chain_pairs: ChainPairs = ChainPairs::new();
+    for id in edges {
+        if let Some(edge) = edge_map.get(id) {
+            if filletable(edge) {
+                chain_pairs.insert((edge.v_start, edge.v_end));
+                chain_pairs.insert((edge.v_end, edge.v_start));
+            }
+        }
+    }
+    let trims = compute_chain_trim_vertices(&faces, &chain_pairs, radius);
+
+    edges
+        .iter()
+        .map(|&edge_id| {
+            let Some(edge) = edge_map.get(&edge_id).filter(|e| filletable(e)) else {
+                return EdgeFilletPreview {
+                    edge_id,
+                    filletable: false,
+                    bounds: None,
+                };
+            };
+
+            let corners = [
+                Some(brep.topology.vertices[edge.v_start].point),
+                Some(brep.topology.vertices[edge.v_end].point),
+                trims.get(&(edge.v_start, edge.face_a)).copied(),
+                trims.get(&(edge.v_end, edge.face_a)).copied(),
+                trims.get(&(edge.v_start, edge.face_b)).copied(),
+                trims.get(&(edge.v_end, edge.face_b)).copied(),
+            ];
+
+            let mut min = [f64::MAX; 3];
+            let mut max = [f64::MIN; 3];
+            for p in corners.into_iter().flatten() {
+                for (a, &c) in [p.x, p.y, p.z].iter().enumerate() {
+                    min[a] = min[a].min(c);
+                    max[a] = max[a].max(c);
+                }
+            }
+
+            EdgeFilletPreview {
+                edge_id,
+                filletable: true,
+                bounds: Some((min, max)),
+            }
+        })
+        .collect()
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -832,6 +1271,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fillet_chain_top_rim() {
+        let cube = make_cube(10.0, 10.0, 10.0);
+
+        // The four top edges form a closed chain around the top face
+        let top_edges: Vec<EdgeId> = extract_edges(&cube)
+            .iter()
+            .filter(|e| {
+                cube.topology.vertices[e.v_start].point.z > 9.0
+                    && cube.topology.vertices[e.v_end].point.z > 9.0
+            })
+            .map(|e| e.edge_id)
+            .collect();
+        assert_eq!(top_edges.len(), 4, "cube should have 4 top edges");
+
+        let filleted = fillet_edge_chain(&cube, &top_edges, 2.0);
+
+        // One cylindrical blend per chain edge, bottom edges untouched
+        let n_cyl = filleted
+            .geometry
+            .surfaces
+            .iter()
+            .filter(|s| s.surface_type() == vcad_kernel_geom::SurfaceKind::Cylinder)
+            .count();
+        assert_eq!(n_cyl, 4, "chain fillet should have 4 cylindrical surfaces");
+
+        // 6 trimmed originals + 4 blends; the blends meet edge-to-edge at the
+        // corners, so no extra junction patches are needed
+        assert_eq!(filleted.topology.faces.len(), 10);
+
+        // Neighbouring blends share their corner vertices: 4 bottom vertices
+        // plus 2 shared vertices per top corner. Four independent fillets
+        // would have duplicated them (16 top vertices instead of 8).
+        assert_eq!(
+            filleted.topology.vertices.len(),
+            12,
+            "corner fillets should share vertices"
+        );
+
+        // No gaps: every half-edge pairs with a twin
+        let total_hes = filleted.topology.half_edges.len();
+        let paired_hes = filleted
+            .topology
+            .half_edges
+            .values()
+            .filter(|he| he.twin.is_some())
+            .count();
+        assert_eq!(
+            paired_hes, total_hes,
+            "all {} half-edges should be paired, got {} paired",
+            total_hes, paired_hes
+        );
+
+        // The blend must arc inward: every mesh point stays inside the cube,
+        // and the volume stays near the cube's (the tessellated bands overlap
+        // a little at the mitered corners, so only sanity-check it)
+        let mesh = vcad_kernel_tessellate::tessellate_brep(&filleted, 32);
+        for chunk in mesh.vertices.chunks(3) {
+            for &c in chunk {
+                assert!(
+                    (-1e-3..=10.001).contains(&(c as f64)),
+                    "blend should stay inside the cube, got coordinate {}",
+                    c
+                );
+            }
+        }
+        let vol = compute_mesh_volume(&mesh);
+        assert!(
+            (900.0..1050.0).contains(&vol),
+            "chain-filleted cube volume should be near 1000, got {:.1}",
+            vol
+        );
+    }
+
+    #[test]
+    fn test_fillet_preview_rejects_oversized_radius() {
+        let cube = make_cube(10.0, 10.0, 10.0);
+        let edge_ids: Vec<EdgeId> = extract_edges(&cube).iter().map(|e| e.edge_id).collect();
+
+        // Radius larger than half the face size consumes the face
+        let previews = fillet_preview(&cube, &edge_ids, 6.0);
+        assert_eq!(previews.len(), 12);
+        for p in &previews {
+            assert!(!p.filletable, "radius 6 should not fit a 10mm face");
+            assert!(p.bounds.is_none());
+        }
+
+        // A modest radius is accepted, with a bounds box covering the edge
+        let previews = fillet_preview(&cube, &edge_ids[..1], 2.0);
+        assert_eq!(previews.len(), 1);
+        assert!(previews[0].filletable);
+        let (min, max) = previews[0].bounds.unwrap();
+        for a in 0..3 {
+            assert!(min[a] >= -1e-9 && max[a] <= 10.0 + 1e-9);
+        }
+        // The blend region spans the full 10mm edge along one axis
+        assert!((0..3).any(|a| (max[a] - min[a] - 10.0).abs() < 1e-9));
+
+        // Zero and negative radii are rejected
+        assert!(!fillet_preview(&cube, &edge_ids[..1], 0.0)[0].filletable);
+    }
+
     fn compute_mesh_volume(mesh: &vcad_kernel_tessellate::TriangleMesh) -> f64 {
         let verts = &mesh.vertices;
         let indices = &mesh.indices;
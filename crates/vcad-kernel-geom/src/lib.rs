@@ -346,7 +346,8 @@ impl ConeSurface {
         let apex_z = if radius_bottom > radius_top {
             height * radius_bottom / dr
         } else {
-            -height * radius_top / (radius_top - radius_bottom)
+            // Widening cone: radius hits zero below the base
+            -height * radius_bottom / (radius_top - radius_bottom)
         };
         let apex = Point3::new(center.x, center.y, center.z + apex_z);
         let axis = if radius_bottom > radius_top {
@@ -648,7 +649,8 @@ impl Surface for TorusSurface {
         let tube_center_dir = cos_u * self.ref_dir.as_ref() + sin_u * self.y_dir();
 
         // d/dv: -r·sin(v)·tube_center_dir + r·cos(v)·axis
-        -self.minor_radius * sin_v * tube_center_dir + self.minor_radius * cos_v * self.axis.as_ref()
+        -self.minor_radius * sin_v * tube_center_dir
+            + self.minor_radius * cos_v * self.axis.as_ref()
     }
 
     fn domain(&self) -> ((f64, f64), (f64, f64)) {
@@ -1154,6 +1156,229 @@ impl GeometryStore {
     }
 }
 
+/// Unsigned distance from a point to a surface.
+///
+/// Analytic for planes, cylinders, spheres, cones, and tori; other surface
+/// kinds fall back to a sampled closest-point search over the parameter
+/// domain (refined around the minimum, so the result is approximate).
+pub fn distance_to_surface(surface: &dyn Surface, p: &Point3) -> f64 {
+    match surface.surface_type() {
+        SurfaceKind::Plane => {
+            let plane = surface.as_any().downcast_ref::<Plane>().unwrap();
+            plane.signed_distance(p).abs()
+        }
+        SurfaceKind::Cylinder => {
+            let cyl = surface.as_any().downcast_ref::<CylinderSurface>().unwrap();
+            let v = p - cyl.center;
+            let radial = v - v.dot(cyl.axis.as_ref()) * cyl.axis.as_ref();
+            (radial.norm() - cyl.radius).abs()
+        }
+        SurfaceKind::Sphere => {
+            let sph = surface.as_any().downcast_ref::<SphereSurface>().unwrap();
+            ((p - sph.center).norm() - sph.radius).abs()
+        }
+        SurfaceKind::Cone => {
+            let cone = surface.as_any().downcast_ref::<ConeSurface>().unwrap();
+            let v = p - cone.apex;
+            let h = v.dot(cone.axis.as_ref());
+            let r = (v - h * cone.axis.as_ref()).norm();
+            // Distance to the cone's generating line in the (axis, radial) plane
+            (r * cone.half_angle.cos() - h * cone.half_angle.sin()).abs()
+        }
+        SurfaceKind::Torus => {
+            let torus = surface.as_any().downcast_ref::<TorusSurface>().unwrap();
+            let v = p - torus.center;
+            let h = v.dot(torus.axis.as_ref());
+            let rho = (v - h * torus.axis.as_ref()).norm();
+            let d_tube = ((rho - torus.major_radius).powi(2) + h * h).sqrt();
+            (d_tube - torus.minor_radius).abs()
+        }
+        _ => sampled_distance_to_surface(surface, p),
+    }
+}
+
+/// Closest-point distance via grid sampling of the parameter domain.
+fn sampled_distance_to_surface(surface: &dyn Surface, p: &Point3) -> f64 {
+    let ((u_min, u_max), (v_min, v_max)) = surface.domain();
+    let (mut u_lo, mut u_hi, mut v_lo, mut v_hi) = (u_min, u_max, v_min, v_max);
+    let mut best = f64::MAX;
+    let mut best_uv = Point2::new(u_lo, v_lo);
+
+    for _ in 0..4 {
+        let n = 16;
+        for i in 0..=n {
+            for j in 0..=n {
+                let uv = Point2::new(
+                    u_lo + (u_hi - u_lo) * i as f64 / n as f64,
+                    v_lo + (v_hi - v_lo) * j as f64 / n as f64,
+                );
+                let d = (surface.evaluate(uv) - p).norm();
+                if d < best {
+                    best = d;
+                    best_uv = uv;
+                }
+            }
+        }
+        let du = (u_hi - u_lo) / n as f64;
+        let dv = (v_hi - v_lo) / n as f64;
+        u_lo = (best_uv.x - du).max(u_min);
+        u_hi = (best_uv.x + du).min(u_max);
+        v_lo = (best_uv.y - dv).max(v_min);
+        v_hi = (best_uv.y + dv).min(v_max);
+    }
+
+    best
+}
+
+/// Project a 3D point onto a surface's (u, v) parameter space.
+///
+/// Analytic for planes, cylinders, spheres, and tori; other surface kinds
+/// (including cones) fall back to a grid search over the parameter domain,
+/// so the result is approximate there.
+pub fn project_point_to_surface(surface: &dyn Surface, point: &Point3) -> Point2 {
+    match surface.surface_type() {
+        SurfaceKind::Plane => {
+            let plane = surface.as_any().downcast_ref::<Plane>().unwrap();
+            plane.project(point)
+        }
+        SurfaceKind::Cylinder => {
+            let cyl = surface.as_any().downcast_ref::<CylinderSurface>().unwrap();
+            // u = atan2(dot(p-center, y_dir), dot(p-center, ref_dir))
+            // v = dot(p-center, axis)
+            let d = point - cyl.center;
+            let ref_dir = cyl.ref_dir.as_ref();
+            let y_dir = cyl.axis.as_ref().cross(ref_dir);
+            let u = d.dot(&y_dir).atan2(d.dot(ref_dir));
+            let u = if u < 0.0 { u + 2.0 * PI } else { u };
+            let v = d.dot(cyl.axis.as_ref());
+            Point2::new(u, v)
+        }
+        SurfaceKind::Sphere => {
+            let sph = surface.as_any().downcast_ref::<SphereSurface>().unwrap();
+            let d = (point - sph.center).normalize();
+            let ref_dir = sph.ref_dir.as_ref();
+            let y_dir = sph.axis.as_ref().cross(ref_dir);
+            let v = d.dot(sph.axis.as_ref()).asin(); // latitude
+            let cos_v = v.cos();
+            let u = if cos_v.abs() < 1e-12 {
+                0.0 // at pole
+            } else {
+                let dx = d.dot(ref_dir) / cos_v;
+                let dy = d.dot(&y_dir) / cos_v;
+                let u = dy.atan2(dx);
+                if u < 0.0 {
+                    u + 2.0 * PI
+                } else {
+                    u
+                }
+            };
+            Point2::new(u, v)
+        }
+        SurfaceKind::Torus => {
+            let torus = surface.as_any().downcast_ref::<TorusSurface>().unwrap();
+            // u = angle in the center plane, v = angle around the tube
+            let d = point - torus.center;
+            let ref_dir = torus.ref_dir.as_ref();
+            let y_dir = torus.axis.as_ref().cross(ref_dir);
+
+            let d_axis = d.dot(torus.axis.as_ref());
+            let d_plane = d - d_axis * torus.axis.into_inner();
+            let d_plane_len = d_plane.norm();
+
+            let u = if d_plane_len < 1e-12 {
+                0.0 // degenerate case: point on axis
+            } else {
+                let d_plane_norm = d_plane / d_plane_len;
+                let u = d_plane_norm.dot(&y_dir).atan2(d_plane_norm.dot(ref_dir));
+                if u < 0.0 {
+                    u + 2.0 * PI
+                } else {
+                    u
+                }
+            };
+
+            let radial_dist = d_plane_len - torus.major_radius;
+            let v = d_axis.atan2(radial_dist);
+
+            Point2::new(u, v)
+        }
+        _ => approx_project_to_surface(surface, point),
+    }
+}
+
+/// Approximate UV projection by searching over the parameter domain.
+fn approx_project_to_surface(surface: &dyn Surface, point: &Point3) -> Point2 {
+    let ((u_min, u_max), (v_min, v_max)) = surface.domain();
+    // Clamp domain for search
+    let u_min = u_min.max(-100.0);
+    let u_max = u_max.min(100.0);
+    let v_min = v_min.max(-100.0);
+    let v_max = v_max.min(100.0);
+
+    let n = 20;
+    let mut best_uv = Point2::new(0.5 * (u_min + u_max), 0.5 * (v_min + v_max));
+    let mut best_dist = f64::INFINITY;
+
+    for i in 0..=n {
+        for j in 0..=n {
+            let u = u_min + (u_max - u_min) * i as f64 / n as f64;
+            let v = v_min + (v_max - v_min) * j as f64 / n as f64;
+            let uv = Point2::new(u, v);
+            let p = surface.evaluate(uv);
+            let dist = (p - point).norm_squared();
+            if dist < best_dist {
+                best_dist = dist;
+                best_uv = uv;
+            }
+        }
+    }
+
+    best_uv
+}
+
+/// Project a 3D curve onto a surface, returning its path in UV space.
+///
+/// Samples the curve uniformly over its parameter domain at `samples` points
+/// (at least 2) and projects each sample with [`project_point_to_surface`].
+/// On surfaces with a periodic angular coordinate (cylinder, sphere, cone,
+/// torus) each angle is shifted by a multiple of 2π to stay closest to the
+/// previous sample, so the path is continuous across the seam instead of
+/// jumping when the curve crosses u = 0.
+pub fn project_curve_to_surface(
+    curve: &dyn Curve3d,
+    surface: &dyn Surface,
+    samples: u32,
+) -> Vec<Point2> {
+    let n = samples.max(2) as usize;
+    let (t_min, t_max) = curve.domain();
+    let periodic_u = matches!(
+        surface.surface_type(),
+        SurfaceKind::Cylinder | SurfaceKind::Sphere | SurfaceKind::Cone | SurfaceKind::Torus
+    );
+    let periodic_v = surface.surface_type() == SurfaceKind::Torus;
+
+    let mut path: Vec<Point2> = Vec::with_capacity(n);
+    for i in 0..n {
+        let t = t_min + (t_max - t_min) * i as f64 / (n - 1) as f64;
+        let mut uv = project_point_to_surface(surface, &curve.evaluate(t));
+        if let Some(prev) = path.last() {
+            if periodic_u {
+                uv.x = unwrap_periodic(prev.x, uv.x);
+            }
+            if periodic_v {
+                uv.y = unwrap_periodic(prev.y, uv.y);
+            }
+        }
+        path.push(uv);
+    }
+    path
+}
+
+/// Shift `angle` by a multiple of 2π so it lies as close as possible to `prev`.
+fn unwrap_periodic(prev: f64, angle: f64) -> f64 {
+    angle - 2.0 * PI * ((angle - prev) / (2.0 * PI)).round()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1272,7 +1497,7 @@ mod tests {
     #[test]
     fn test_torus_evaluate() {
         let torus = TorusSurface::new(10.0, 3.0); // R=10, r=3
-        // u=0, v=0: outer equator, at (R+r, 0, 0) = (13, 0, 0)
+                                                  // u=0, v=0: outer equator, at (R+r, 0, 0) = (13, 0, 0)
         let pt = torus.evaluate(Point2::new(0.0, 0.0));
         assert!((pt.x - 13.0).abs() < 1e-10);
         assert!(pt.y.abs() < 1e-10);
@@ -1346,4 +1571,36 @@ mod tests {
         assert!((d_dv.y - d_dv_fd.y).abs() < 1e-4);
         assert!((d_dv.z - d_dv_fd.z).abs() < 1e-4);
     }
+
+    #[test]
+    fn test_project_point_to_surface_cylinder() {
+        let cyl = CylinderSurface::new(5.0);
+        let uv = project_point_to_surface(&cyl, &Point3::new(0.0, 5.0, 3.0));
+        assert!((uv.x - PI / 2.0).abs() < 1e-10);
+        assert!((uv.y - 3.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_project_curve_onto_cylinder_unwraps_seam() {
+        let cyl = CylinderSurface::new(5.0);
+        // A line on the cylinder wall that crosses the u = 0 seam:
+        // u goes from atan2(-5, 5) = -π/4 (wrapped to 7π/4) up to
+        // atan2(5, 5) = π/4, while v climbs from 0 to 10.
+        let line = Line3d::from_points(Point3::new(5.0, -5.0, 0.0), Point3::new(5.0, 5.0, 10.0));
+        let path = project_curve_to_surface(&line, &cyl, 33);
+        assert_eq!(path.len(), 33);
+
+        // The angular coordinate must be monotonic — no 2π jump at the seam.
+        for w in path.windows(2) {
+            assert!(w[1].x > w[0].x);
+        }
+
+        // It spans the expected quarter turn, from 7π/4 to 9π/4.
+        assert!((path[0].x - 7.0 * PI / 4.0).abs() < 1e-9);
+        assert!((path[path.len() - 1].x - path[0].x - PI / 2.0).abs() < 1e-9);
+
+        // The height coordinate follows the line.
+        assert!((path[0].y - 0.0).abs() < 1e-9);
+        assert!((path[path.len() - 1].y - 10.0).abs() < 1e-9);
+    }
 }
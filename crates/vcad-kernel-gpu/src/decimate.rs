@@ -41,6 +41,36 @@ pub async fn decimate_mesh(
     positions: &[f32],
     indices: &[u32],
     target_ratio: f32,
+) -> Result<DecimationResult, GpuError> {
+    decimate_mesh_impl(positions, indices, None, target_ratio).await
+}
+
+/// Decimate a mesh while preserving feature edges between B-rep faces.
+///
+/// Like [`decimate_mesh`], but takes one face id per triangle (as produced by
+/// `tessellate_solid_tagged`) and forbids collapsing any edge that touches a
+/// face boundary. Only edges strictly interior to a single face group can
+/// collapse, so silhouettes and sharp corners survive the reduction.
+///
+/// # Arguments
+/// * `positions` - Flat array of vertex positions
+/// * `indices` - Triangle indices
+/// * `face_ids` - One originating-face id per triangle
+/// * `target_ratio` - Target ratio of triangles to keep (0.5 = 50%)
+pub async fn decimate_mesh_tagged(
+    positions: &[f32],
+    indices: &[u32],
+    face_ids: &[u32],
+    target_ratio: f32,
+) -> Result<DecimationResult, GpuError> {
+    decimate_mesh_impl(positions, indices, Some(face_ids), target_ratio).await
+}
+
+async fn decimate_mesh_impl(
+    positions: &[f32],
+    indices: &[u32],
+    face_ids: Option<&[u32]>,
+    target_ratio: f32,
 ) -> Result<DecimationResult, GpuError> {
     let ctx = GpuContext::init().await?;
 
@@ -198,9 +228,16 @@ pub async fn decimate_mesh(
             push_constant_ranges: &[],
         });
 
-    let init_pipeline = create_compute_pipeline(&ctx.device, &pipeline_layout, &shader, "init_quadrics");
-    let accumulate_pipeline = create_compute_pipeline(&ctx.device, &pipeline_layout, &shader, "accumulate_quadrics");
-    let cost_pipeline = create_compute_pipeline(&ctx.device, &pipeline_layout, &shader, "compute_edge_costs");
+    let init_pipeline =
+        create_compute_pipeline(&ctx.device, &pipeline_layout, &shader, "init_quadrics");
+    let accumulate_pipeline = create_compute_pipeline(
+        &ctx.device,
+        &pipeline_layout,
+        &shader,
+        "accumulate_quadrics",
+    );
+    let cost_pipeline =
+        create_compute_pipeline(&ctx.device, &pipeline_layout, &shader, "compute_edge_costs");
 
     // Run GPU compute passes
     let mut encoder = ctx
@@ -210,13 +247,31 @@ pub async fn decimate_mesh(
         });
 
     // Phase 1: Init quadrics
-    dispatch_compute(&mut encoder, &init_pipeline, &bind_group, vertex_count, "Init Quadrics");
+    dispatch_compute(
+        &mut encoder,
+        &init_pipeline,
+        &bind_group,
+        vertex_count,
+        "Init Quadrics",
+    );
 
     // Phase 2: Accumulate quadrics from faces
-    dispatch_compute(&mut encoder, &accumulate_pipeline, &bind_group, triangle_count, "Accumulate Quadrics");
+    dispatch_compute(
+        &mut encoder,
+        &accumulate_pipeline,
+        &bind_group,
+        triangle_count,
+        "Accumulate Quadrics",
+    );
 
     // Phase 3: Compute edge costs
-    dispatch_compute(&mut encoder, &cost_pipeline, &bind_group, edge_count, "Compute Edge Costs");
+    dispatch_compute(
+        &mut encoder,
+        &cost_pipeline,
+        &bind_group,
+        edge_count,
+        "Compute Edge Costs",
+    );
 
     // Read back edge costs
     let cost_staging = ctx.device.create_buffer(&wgpu::BufferDescriptor {
@@ -237,7 +292,18 @@ pub async fn decimate_mesh(
     ctx.queue.submit(std::iter::once(encoder.finish()));
 
     // Read edge costs
-    let edge_costs = read_buffer::<f32>(&ctx.device, &cost_staging, edge_count as usize).await?;
+    let mut edge_costs =
+        read_buffer::<f32>(&ctx.device, &cost_staging, edge_count as usize).await?;
+
+    // Lock edges that touch a face boundary so feature edges survive
+    if let Some(face_ids) = face_ids {
+        let allowed = collapsible_edges(positions, indices, &edges, face_ids);
+        for (cost, ok) in edge_costs.iter_mut().zip(&allowed) {
+            if !ok {
+                *cost = 1e30;
+            }
+        }
+    }
 
     // CPU-side decimation using GPU-computed costs
     let (decimated_positions, decimated_indices) = cpu_decimate(
@@ -274,6 +340,265 @@ pub fn decimate_mesh_blocking(
     pollster::block_on(decimate_mesh(positions, indices, target_ratio))
 }
 
+/// Decimate a tagged mesh synchronously (native only).
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(dead_code)]
+pub fn decimate_mesh_tagged_blocking(
+    positions: &[f32],
+    indices: &[u32],
+    face_ids: &[u32],
+    target_ratio: f32,
+) -> Result<DecimationResult, GpuError> {
+    pollster::block_on(decimate_mesh_tagged(
+        positions,
+        indices,
+        face_ids,
+        target_ratio,
+    ))
+}
+
+/// Simplify a mesh entirely on the CPU using quadric error metrics.
+///
+/// Unlike [`decimate_mesh`] this needs no GPU context: per-vertex quadrics
+/// and edge costs are computed directly, then collapsed with the same
+/// cost-ordered CPU pass. With `preserve_boundaries` set, any edge touching
+/// an open boundary or a sharp feature edge (dihedral angle above 30°) is
+/// locked, so patch borders and silhouette creases survive the reduction —
+/// the GPU path has no equivalent option.
+///
+/// # Arguments
+/// * `positions` - Flat array of vertex positions
+/// * `indices` - Triangle indices
+/// * `target_ratio` - Target ratio of triangles to keep (0.5 = 50%)
+/// * `preserve_boundaries` - Lock boundary and feature edges
+pub fn simplify_mesh(
+    positions: &[f32],
+    indices: &[u32],
+    target_ratio: f32,
+    preserve_boundaries: bool,
+) -> DecimationResult {
+    let vertex_count = positions.len() / 3;
+    let triangle_count = indices.len() / 3;
+    let target_triangles = ((triangle_count as f32) * target_ratio.clamp(0.1, 1.0)) as usize;
+
+    let edges = build_edge_list(indices, vertex_count as u32);
+
+    // Accumulate fundamental plane quadrics per vertex: K = p pᵀ for the
+    // triangle plane p = [a, b, c, d] with unit normal (a, b, c)
+    let vertex = |i: u32| {
+        let base = i as usize * 3;
+        [
+            positions[base] as f64,
+            positions[base + 1] as f64,
+            positions[base + 2] as f64,
+        ]
+    };
+    let mut quadrics = vec![[0.0_f64; 10]; vertex_count];
+    let mut tri_normals = Vec::with_capacity(triangle_count);
+    for tri in indices.chunks_exact(3) {
+        let (p0, p1, p2) = (vertex(tri[0]), vertex(tri[1]), vertex(tri[2]));
+        let e1 = [p1[0] - p0[0], p1[1] - p0[1], p1[2] - p0[2]];
+        let e2 = [p2[0] - p0[0], p2[1] - p0[1], p2[2] - p0[2]];
+        let mut n = [
+            e1[1] * e2[2] - e1[2] * e2[1],
+            e1[2] * e2[0] - e1[0] * e2[2],
+            e1[0] * e2[1] - e1[1] * e2[0],
+        ];
+        let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+        if len < 1e-12 {
+            tri_normals.push([0.0; 3]);
+            continue;
+        }
+        n = [n[0] / len, n[1] / len, n[2] / len];
+        tri_normals.push(n);
+        let d = -(n[0] * p0[0] + n[1] * p0[1] + n[2] * p0[2]);
+        let k = [
+            n[0] * n[0],
+            n[0] * n[1],
+            n[0] * n[2],
+            n[0] * d,
+            n[1] * n[1],
+            n[1] * n[2],
+            n[1] * d,
+            n[2] * n[2],
+            n[2] * d,
+            d * d,
+        ];
+        for &v in tri {
+            let q = &mut quadrics[v as usize];
+            for (a, b) in q.iter_mut().zip(&k) {
+                *a += b;
+            }
+        }
+    }
+
+    // Edge cost: quadric error of the collapsed midpoint under Q0 + Q1,
+    // matching the midpoint placement used by `cpu_decimate`
+    let mut edge_costs: Vec<f32> = edges
+        .iter()
+        .map(|&(v0, v1)| {
+            let (p0, p1) = (vertex(v0), vertex(v1));
+            let m = [
+                (p0[0] + p1[0]) / 2.0,
+                (p0[1] + p1[1]) / 2.0,
+                (p0[2] + p1[2]) / 2.0,
+            ];
+            let (q0, q1) = (&quadrics[v0 as usize], &quadrics[v1 as usize]);
+            let q: Vec<f64> = q0.iter().zip(q1).map(|(a, b)| a + b).collect();
+            (m[0] * (q[0] * m[0] + q[1] * m[1] + q[2] * m[2] + q[3])
+                + m[1] * (q[1] * m[0] + q[4] * m[1] + q[5] * m[2] + q[6])
+                + m[2] * (q[2] * m[0] + q[5] * m[1] + q[7] * m[2] + q[8])
+                + (q[3] * m[0] + q[6] * m[1] + q[8] * m[2] + q[9])) as f32
+        })
+        .collect();
+
+    if preserve_boundaries {
+        let locked = locked_vertices(indices, &edges, &tri_normals, vertex_count);
+        for (cost, &(v0, v1)) in edge_costs.iter_mut().zip(&edges) {
+            if locked[v0 as usize] || locked[v1 as usize] {
+                *cost = 1e30;
+            }
+        }
+    }
+
+    let (new_positions, new_indices) =
+        cpu_decimate(positions, indices, &edges, &edge_costs, target_triangles);
+    let normals = area_weighted_normals(&new_positions, &new_indices);
+
+    DecimationResult {
+        positions: new_positions,
+        indices: new_indices,
+        normals,
+    }
+}
+
+/// Vertices that may not move during boundary-preserving simplification:
+/// endpoints of open boundary edges (one incident triangle) and of feature
+/// edges whose incident triangles meet at more than 30°.
+fn locked_vertices(
+    indices: &[u32],
+    edges: &[(u32, u32)],
+    tri_normals: &[[f64; 3]],
+    vertex_count: usize,
+) -> Vec<bool> {
+    use std::collections::HashMap;
+
+    let mut incident: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for (t, tri) in indices.chunks_exact(3).enumerate() {
+        for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+            let key = if a < b { (a, b) } else { (b, a) };
+            incident.entry(key).or_default().push(t);
+        }
+    }
+
+    let crease_cos = (std::f64::consts::PI / 6.0).cos();
+    let mut locked = vec![false; vertex_count];
+    for &(v0, v1) in edges {
+        let tris = &incident[&(v0, v1)];
+        let is_feature = match tris.as_slice() {
+            [_] => true, // open boundary
+            [t0, t1] => {
+                let (a, b) = (&tri_normals[*t0], &tri_normals[*t1]);
+                a[0] * b[0] + a[1] * b[1] + a[2] * b[2] < crease_cos
+            }
+            _ => true, // non-manifold
+        };
+        if is_feature {
+            locked[v0 as usize] = true;
+            locked[v1 as usize] = true;
+        }
+    }
+    locked
+}
+
+/// Area-weighted vertex normals, for meshes simplified without a GPU.
+fn area_weighted_normals(positions: &[f32], indices: &[u32]) -> Vec<f32> {
+    let mut normals = vec![0.0_f32; positions.len()];
+    for tri in indices.chunks_exact(3) {
+        let p = |i: u32| {
+            let base = i as usize * 3;
+            [positions[base], positions[base + 1], positions[base + 2]]
+        };
+        let (p0, p1, p2) = (p(tri[0]), p(tri[1]), p(tri[2]));
+        let e1 = [p1[0] - p0[0], p1[1] - p0[1], p1[2] - p0[2]];
+        let e2 = [p2[0] - p0[0], p2[1] - p0[1], p2[2] - p0[2]];
+        let n = [
+            e1[1] * e2[2] - e1[2] * e2[1],
+            e1[2] * e2[0] - e1[0] * e2[2],
+            e1[0] * e2[1] - e1[1] * e2[0],
+        ];
+        for &v in tri {
+            let base = v as usize * 3;
+            normals[base] += n[0];
+            normals[base + 1] += n[1];
+            normals[base + 2] += n[2];
+        }
+    }
+    for n in normals.chunks_exact_mut(3) {
+        let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+        if len > 1e-12 {
+            n[0] /= len;
+            n[1] /= len;
+            n[2] /= len;
+        }
+    }
+    normals
+}
+
+/// For each candidate edge, whether collapsing it is allowed under face
+/// tagging: both endpoints must be incident to exactly one face group, and
+/// the same one.
+///
+/// Vertices are welded by quantized position so seam-duplicated vertices
+/// along face boundaries accumulate ids from every adjacent face; any vertex
+/// on a boundary (or corner) then has two or more ids and every edge touching
+/// it is locked.
+fn collapsible_edges(
+    positions: &[f32],
+    indices: &[u32],
+    edges: &[(u32, u32)],
+    face_ids: &[u32],
+) -> Vec<bool> {
+    use std::collections::HashMap;
+
+    let vertex_count = positions.len() / 3;
+    let quantize = |i: usize| -> [i64; 3] {
+        [
+            (positions[i * 3] as f64 * 1e6).round() as i64,
+            (positions[i * 3 + 1] as f64 * 1e6).round() as i64,
+            (positions[i * 3 + 2] as f64 * 1e6).round() as i64,
+        ]
+    };
+    let mut canonical: HashMap<[i64; 3], usize> = HashMap::new();
+    let mut canon_of: Vec<usize> = Vec::with_capacity(vertex_count);
+    for i in 0..vertex_count {
+        let c = *canonical.entry(quantize(i)).or_insert(i);
+        canon_of.push(c);
+    }
+
+    // Incident face groups per welded vertex (small sorted vecs; nearly all
+    // vertices touch one or two faces)
+    let mut incident: Vec<Vec<u32>> = vec![Vec::new(); vertex_count];
+    for (t, tri) in indices.chunks(3).enumerate() {
+        let id = face_ids[t];
+        for &v in tri {
+            let set = &mut incident[canon_of[v as usize]];
+            if let Err(pos) = set.binary_search(&id) {
+                set.insert(pos, id);
+            }
+        }
+    }
+
+    edges
+        .iter()
+        .map(|&(v0, v1)| {
+            let a = &incident[canon_of[v0 as usize]];
+            let b = &incident[canon_of[v1 as usize]];
+            a.len() == 1 && a == b
+        })
+        .collect()
+}
+
 fn buffer_layout_entry(binding: u32, read_only: bool) -> wgpu::BindGroupLayoutEntry {
     wgpu::BindGroupLayoutEntry {
         binding,
@@ -382,7 +707,10 @@ fn cpu_decimate(
     impl Ord for EdgeEntry {
         fn cmp(&self, other: &Self) -> std::cmp::Ordering {
             // Reverse order for min-heap
-            other.cost.partial_cmp(&self.cost).unwrap_or(std::cmp::Ordering::Equal)
+            other
+                .cost
+                .partial_cmp(&self.cost)
+                .unwrap_or(std::cmp::Ordering::Equal)
         }
     }
 
@@ -514,6 +842,130 @@ mod tests {
         assert_eq!(edges.len(), 5); // 5 unique edges for 2 triangles sharing an edge
     }
 
+    #[test]
+    fn test_tagged_decimation_preserves_cube_corners() {
+        use vcad_kernel_tessellate::{tessellate_solid_tagged, TessellationParams};
+
+        // Tagged cube + cylinder side by side as one mesh
+        let cube = vcad_kernel_primitives::make_cube(10.0, 10.0, 10.0);
+        let cyl = vcad_kernel_primitives::make_cylinder(4.0, 10.0, 32);
+        let params = TessellationParams {
+            circle_segments: 32,
+            height_segments: 4,
+            latitude_segments: 16,
+            ..Default::default()
+        };
+        let (cube_mesh, cube_tags) = tessellate_solid_tagged(&cube, &params, None);
+        let (cyl_mesh, cyl_tags) = tessellate_solid_tagged(&cyl, &params, None);
+        let cube_faces = cube_tags.iter().max().unwrap() + 1;
+
+        let mut positions = cube_mesh.vertices.clone();
+        for i in 0..cyl_mesh.num_vertices() {
+            positions.push(cyl_mesh.vertices[i * 3] + 20.0);
+            positions.push(cyl_mesh.vertices[i * 3 + 1]);
+            positions.push(cyl_mesh.vertices[i * 3 + 2]);
+        }
+        let mut indices = cube_mesh.indices.clone();
+        let offset = cube_mesh.num_vertices() as u32;
+        indices.extend(cyl_mesh.indices.iter().map(|&i| i + offset));
+        let mut face_ids = cube_tags;
+        face_ids.extend(cyl_tags.iter().map(|&t| t + cube_faces));
+
+        // Exercise the constrained collapse directly (no GPU): uniform costs
+        // with boundary-touching edges locked, as decimate_mesh_tagged does
+        let edges = build_edge_list(&indices, (positions.len() / 3) as u32);
+        let allowed = collapsible_edges(&positions, &indices, &edges, &face_ids);
+        let costs: Vec<f32> = allowed
+            .iter()
+            .map(|&ok| if ok { 1.0 } else { 1e30 })
+            .collect();
+        let target = indices.len() / 3 / 2;
+        let (new_positions, new_indices) =
+            cpu_decimate(&positions, &indices, &edges, &costs, target);
+        assert!(new_indices.len() <= indices.len());
+
+        // Every cube corner must still sit exactly on its original position
+        for corner in [
+            [0.0, 0.0, 0.0],
+            [10.0, 0.0, 0.0],
+            [0.0, 10.0, 0.0],
+            [10.0, 10.0, 0.0],
+            [0.0, 0.0, 10.0],
+            [10.0, 0.0, 10.0],
+            [0.0, 10.0, 10.0],
+            [10.0, 10.0, 10.0],
+        ] {
+            let found = new_positions.chunks(3).any(|v| {
+                (v[0] - corner[0]).abs() < 1e-6
+                    && (v[1] - corner[1]).abs() < 1e-6
+                    && (v[2] - corner[2]).abs() < 1e-6
+            });
+            assert!(found, "cube corner {corner:?} was moved by decimation");
+        }
+    }
+
+    #[test]
+    fn test_simplify_preserves_open_boundary() {
+        // Open 7x7-cell grid patch in the XY plane (8x8 vertices, 98 tris)
+        let n = 8_u32;
+        let mut positions = Vec::new();
+        for j in 0..n {
+            for i in 0..n {
+                positions.extend_from_slice(&[i as f32, j as f32, 0.0]);
+            }
+        }
+        let mut indices = Vec::new();
+        for j in 0..n - 1 {
+            for i in 0..n - 1 {
+                let v = j * n + i;
+                indices.extend_from_slice(&[v, v + 1, v + n + 1, v, v + n + 1, v + n]);
+            }
+        }
+
+        let boundary_vertices = |positions: &[f32], indices: &[u32]| -> Vec<[f32; 3]> {
+            use std::collections::{BTreeSet, HashMap};
+            let mut counts: HashMap<(u32, u32), u32> = HashMap::new();
+            for tri in indices.chunks_exact(3) {
+                for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                    *counts
+                        .entry(if a < b { (a, b) } else { (b, a) })
+                        .or_insert(0) += 1;
+                }
+            }
+            let mut verts = BTreeSet::new();
+            for (&(a, b), &c) in &counts {
+                if c == 1 {
+                    verts.insert(a);
+                    verts.insert(b);
+                }
+            }
+            verts
+                .into_iter()
+                .map(|v| {
+                    let base = v as usize * 3;
+                    [positions[base], positions[base + 1], positions[base + 2]]
+                })
+                .collect()
+        };
+
+        let before = boundary_vertices(&positions, &indices);
+        assert_eq!(before.len(), 28); // perimeter of the 8x8 grid
+
+        let result = simplify_mesh(&positions, &indices, 0.5, true);
+        assert!(result.indices.len() < indices.len());
+        assert_eq!(result.normals.len(), result.positions.len());
+
+        // Every boundary vertex survives at its exact original position
+        let after = boundary_vertices(&result.positions, &result.indices);
+        assert_eq!(after.len(), before.len());
+        for v in &before {
+            assert!(
+                after.iter().any(|w| w == v),
+                "boundary vertex {v:?} was moved or removed"
+            );
+        }
+    }
+
     #[test]
     #[ignore = "requires GPU"]
     fn test_decimate_mesh() {
@@ -11,5 +11,5 @@ mod decimate;
 mod normals;
 
 pub use context::{GpuContext, GpuError};
-pub use decimate::{decimate_mesh, DecimationResult};
+pub use decimate::{decimate_mesh, decimate_mesh_tagged, simplify_mesh, DecimationResult};
 pub use normals::compute_creased_normals;
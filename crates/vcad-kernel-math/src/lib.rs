@@ -115,6 +115,28 @@ impl Transform {
         Self { matrix: m }
     }
 
+    /// Build a transform from 16 row-major matrix values.
+    pub fn from_row_major(values: &[f64; 16]) -> Self {
+        let mut m = Matrix4::identity();
+        for row in 0..4 {
+            for col in 0..4 {
+                m[(row, col)] = values[row * 4 + col];
+            }
+        }
+        Self { matrix: m }
+    }
+
+    /// Export the matrix as 16 row-major values (inverse of [`Transform::from_row_major`]).
+    pub fn to_row_major(&self) -> [f64; 16] {
+        let mut values = [0.0; 16];
+        for row in 0..4 {
+            for col in 0..4 {
+                values[row * 4 + col] = self.matrix[(row, col)];
+            }
+        }
+        values
+    }
+
     /// Compose: `self` then `other` (self * other).
     pub fn then(&self, other: &Transform) -> Self {
         Self {
@@ -195,6 +217,32 @@ impl Default for Tolerance {
     }
 }
 
+thread_local! {
+    static GEOMETRY_TOLERANCE: std::cell::Cell<f64> =
+        const { std::cell::Cell::new(Tolerance::DEFAULT.linear) };
+}
+
+/// Set the thread-local geometry tolerance in mm.
+///
+/// This is the default "how close is coincident" distance consumed by
+/// downstream modules (boolean sewing and welding, trimming, import) so a
+/// caller working at an unusual scale can set it once instead of threading
+/// a tolerance through every call. Non-finite or non-positive values are
+/// ignored.
+pub fn set_geometry_tolerance(tol: f64) {
+    if tol.is_finite() && tol > 0.0 {
+        GEOMETRY_TOLERANCE.with(|c| c.set(tol));
+    }
+}
+
+/// The current thread-local geometry tolerance in mm.
+///
+/// Defaults to [`Tolerance::DEFAULT`]'s linear tolerance (1e-6 mm) until
+/// [`set_geometry_tolerance`] is called on this thread.
+pub fn geometry_tolerance() -> f64 {
+    GEOMETRY_TOLERANCE.with(|c| c.get())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,6 +338,15 @@ mod tests {
         assert!(r2.z.abs() < 1e-12);
     }
 
+    #[test]
+    fn test_row_major_roundtrip() {
+        let axis = Dir3::new_normalize(Vec3::new(1.0, 2.0, 3.0));
+        let t = Transform::translation(4.0, -5.0, 6.0)
+            .then(&Transform::rotation_about_axis(&axis, PI / 3.0));
+        let roundtripped = Transform::from_row_major(&t.to_row_major());
+        assert!((roundtripped.matrix - t.matrix).abs().max() < 1e-15);
+    }
+
     #[test]
     fn test_tolerance_points_equal() {
         let tol = Tolerance::DEFAULT;
@@ -299,4 +356,16 @@ mod tests {
         let c = Point3::new(1.001, 2.0, 3.0);
         assert!(!tol.points_equal(&a, &c));
     }
+
+    #[test]
+    fn test_geometry_tolerance_default_and_set() {
+        assert_eq!(geometry_tolerance(), Tolerance::DEFAULT.linear);
+        set_geometry_tolerance(1e-3);
+        assert_eq!(geometry_tolerance(), 1e-3);
+        // Invalid values are ignored
+        set_geometry_tolerance(0.0);
+        set_geometry_tolerance(f64::NAN);
+        assert_eq!(geometry_tolerance(), 1e-3);
+        set_geometry_tolerance(Tolerance::DEFAULT.linear);
+    }
 }
@@ -89,11 +89,7 @@ fn create_trimesh(mesh: &TriangleMesh, name: &str) -> Result<SharedShape, Physic
         .collect();
 
     // Extract triangle indices
-    let indices: Vec<[u32; 3]> = mesh
-        .indices
-        .chunks(3)
-        .map(|i| [i[0], i[1], i[2]])
-        .collect();
+    let indices: Vec<[u32; 3]> = mesh.indices.chunks(3).map(|i| [i[0], i[1], i[2]]).collect();
 
     if indices.is_empty() {
         return Err(PhysicsError::CollisionShape {
@@ -132,7 +128,11 @@ fn create_aabb(mesh: &TriangleMesh, _name: &str) -> Result<SharedShape, PhysicsE
 
     let half_extents = (max - min) / 2.0;
 
-    Ok(SharedShape::cuboid(half_extents.x, half_extents.y, half_extents.z))
+    Ok(SharedShape::cuboid(
+        half_extents.x,
+        half_extents.y,
+        half_extents.z,
+    ))
 }
 
 /// Compute the center of mass from a triangle mesh.
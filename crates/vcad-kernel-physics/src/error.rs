@@ -41,4 +41,17 @@ pub enum PhysicsError {
     /// Evaluation error.
     #[error("Failed to evaluate geometry: {0}")]
     Evaluation(String),
+
+    /// No end effector at the given index.
+    #[error("No end effector at index {0}")]
+    InvalidEndEffector(usize),
+
+    /// Inverse kinematics failed to reach the target.
+    #[error("Inverse kinematics did not converge: residual {residual:.4} m, {angular:.4} rad")]
+    IkNotConverged {
+        /// Remaining position error in meters.
+        residual: f64,
+        /// Remaining orientation error in radians.
+        angular: f64,
+    },
 }
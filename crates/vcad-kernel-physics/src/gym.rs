@@ -1,7 +1,10 @@
 //! Gym-style interface for reinforcement learning.
 
+use std::collections::{HashMap, HashSet};
+
+use nalgebra::{Isometry3, Translation3, UnitQuaternion, UnitVector3, Vector3};
 use serde::{Deserialize, Serialize};
-use vcad_ir::Document;
+use vcad_ir::{Document, JointKind};
 
 use crate::error::PhysicsError;
 use crate::world::PhysicsWorld;
@@ -59,6 +62,10 @@ pub struct RobotEnv {
     initial_doc: Document,
     /// Random seed.
     seed: u64,
+    /// Gravity override, reapplied on reset.
+    gravity: Option<[f32; 3]>,
+    /// Global (linear, angular) damping override, reapplied on reset.
+    damping: Option<(f32, f32)>,
 }
 
 impl RobotEnv {
@@ -89,19 +96,45 @@ impl RobotEnv {
             current_step: 0,
             initial_doc: doc,
             seed: 0,
+            gravity: None,
+            damping: None,
         })
     }
 
+    /// Set the gravity vector in m/s² (default: `(0, -9.81, 0)`).
+    ///
+    /// The override persists across [`Self::reset`].
+    pub fn set_gravity(&mut self, x: f32, y: f32, z: f32) {
+        self.gravity = Some([x, y, z]);
+        self.world.set_gravity(x, y, z);
+    }
+
+    /// Set linear and angular damping on every rigid body.
+    ///
+    /// The override persists across [`Self::reset`].
+    pub fn set_global_damping(&mut self, linear: f32, angular: f32) {
+        self.damping = Some((linear, angular));
+        self.world.set_global_damping(linear, angular);
+    }
+
     /// Reset the environment to initial state.
     ///
     /// Returns the initial observation.
     pub fn reset(&mut self) -> Observation {
         // Recreate physics world from initial document
-        self.world = PhysicsWorld::from_document(&self.initial_doc)
-            .expect("Failed to reset physics world");
+        self.world =
+            PhysicsWorld::from_document(&self.initial_doc).expect("Failed to reset physics world");
         self.joint_ids = self.world.joint_ids();
         self.current_step = 0;
 
+        // Reapply world-parameter overrides lost with the old world
+        if let Some([x, y, z]) = self.gravity {
+            self.world.set_gravity(x, y, z);
+        }
+        if let Some((linear, angular)) = self.damping {
+            self.world.set_global_damping(linear, angular);
+        }
+
         self.observe()
     }
 
@@ -153,7 +186,8 @@ impl RobotEnv {
         let mut end_effector_poses = Vec::with_capacity(self.end_effector_ids.len());
         for ee_id in &self.end_effector_ids {
             if let Some((pos, quat)) = self.world.get_instance_pose(ee_id) {
-                end_effector_poses.push([pos[0], pos[1], pos[2], quat[0], quat[1], quat[2], quat[3]]);
+                end_effector_poses
+                    .push([pos[0], pos[1], pos[2], quat[0], quat[1], quat[2], quat[3]]);
             } else {
                 end_effector_poses.push([0.0; 7]);
             }
@@ -166,6 +200,240 @@ impl RobotEnv {
         }
     }
 
+    /// Compute end-effector poses for a joint configuration without
+    /// touching the simulation.
+    ///
+    /// Walks the kinematic chain from the document's joint definitions:
+    /// each child pose is its parent's pose composed with the joint
+    /// transform at the given position (degrees for revolute, mm for
+    /// prismatic, in `joint_ids` order — the same units and order as
+    /// observations). Returns one `[x, y, z, qw, qx, qy, qz]` pose in
+    /// meters per tracked end effector, matching
+    /// [`Observation::end_effector_poses`]. Instances that are unreachable
+    /// through the chain keep their document transform.
+    pub fn forward_kinematics(&self, joint_positions: &[f64]) -> Vec<[f64; 7]> {
+        let doc = &self.initial_doc;
+        let instances = doc.instances.as_deref().unwrap_or(&[]);
+        let joints = doc.joints.as_deref().unwrap_or(&[]);
+
+        // Base poses from instance transforms (mm → m, Euler degrees)
+        let mut poses: HashMap<String, Isometry3<f64>> = instances
+            .iter()
+            .map(|inst| {
+                let pose = inst
+                    .transform
+                    .as_ref()
+                    .map(|t| {
+                        Isometry3::from_parts(
+                            Translation3::new(
+                                t.translation.x / 1000.0,
+                                t.translation.y / 1000.0,
+                                t.translation.z / 1000.0,
+                            ),
+                            UnitQuaternion::from_euler_angles(
+                                t.rotation.x.to_radians(),
+                                t.rotation.y.to_radians(),
+                                t.rotation.z.to_radians(),
+                            ),
+                        )
+                    })
+                    .unwrap_or_else(Isometry3::identity);
+                (inst.id.clone(), pose)
+            })
+            .collect();
+
+        let position_of: HashMap<&str, f64> = self
+            .joint_ids
+            .iter()
+            .map(String::as_str)
+            .zip(joint_positions.iter().copied())
+            .collect();
+
+        // An instance's pose is final once it is not waiting on a joint:
+        // either it is no joint's child, or its joint has been applied
+        let joint_children: HashSet<&str> = joints
+            .iter()
+            .map(|j| j.child_instance_id.as_str())
+            .collect();
+        let mut pending: HashSet<&str> = joint_children.clone();
+        loop {
+            let mut progressed = false;
+            for joint in joints {
+                if !pending.contains(joint.child_instance_id.as_str()) {
+                    continue;
+                }
+                let parent_pose = match joint.parent_instance_id.as_deref() {
+                    None => Isometry3::identity(),
+                    Some(id) if !pending.contains(id) => match poses.get(id) {
+                        Some(pose) => *pose,
+                        None => continue,
+                    },
+                    // Parent still waiting on its own joint
+                    Some(_) => continue,
+                };
+
+                let state = position_of.get(joint.id.as_str()).copied().unwrap_or(0.0);
+                let to_parent_anchor = Translation3::new(
+                    joint.parent_anchor.x / 1000.0,
+                    joint.parent_anchor.y / 1000.0,
+                    joint.parent_anchor.z / 1000.0,
+                );
+                let from_child_anchor = Translation3::new(
+                    -joint.child_anchor.x / 1000.0,
+                    -joint.child_anchor.y / 1000.0,
+                    -joint.child_anchor.z / 1000.0,
+                );
+                let motion: Isometry3<f64> = match &joint.kind {
+                    JointKind::Fixed | JointKind::Ball => Isometry3::identity(),
+                    JointKind::Revolute { axis, .. } | JointKind::Cylindrical { axis } => {
+                        let axis = UnitVector3::new_normalize(Vector3::new(axis.x, axis.y, axis.z));
+                        Isometry3::from_parts(
+                            Translation3::identity(),
+                            UnitQuaternion::from_axis_angle(&axis, state.to_radians()),
+                        )
+                    }
+                    JointKind::Slider { axis, .. } => {
+                        let axis = UnitVector3::new_normalize(Vector3::new(axis.x, axis.y, axis.z));
+                        Translation3::from(axis.into_inner() * (state / 1000.0)).into()
+                    }
+                };
+
+                let child_pose = parent_pose * to_parent_anchor * motion * from_child_anchor;
+                poses.insert(joint.child_instance_id.clone(), child_pose);
+                pending.remove(joint.child_instance_id.as_str());
+                progressed = true;
+            }
+            if !progressed {
+                break;
+            }
+        }
+
+        self.end_effector_ids
+            .iter()
+            .map(|id| {
+                poses
+                    .get(id)
+                    .map(|pose| {
+                        let t = pose.translation;
+                        let q = pose.rotation;
+                        [t.x, t.y, t.z, q.w, q.i, q.j, q.k]
+                    })
+                    .unwrap_or([0.0; 7])
+            })
+            .collect()
+    }
+
+    /// Solve for joint positions that place an end effector at a target
+    /// pose.
+    ///
+    /// Damped-least-squares Jacobian IK over the kinematic chain: iterates
+    /// [`Self::forward_kinematics`] with a numerically differentiated
+    /// Jacobian until the tracked end effector reaches `target_pos` (meters)
+    /// and `target_quat` (`[w, x, y, z]`). Returns joint positions in
+    /// observation units and order. Joint limits are not enforced. Errors
+    /// if the target is unreachable (no convergence).
+    pub fn inverse_kinematics(
+        &self,
+        ee_index: usize,
+        target_pos: [f64; 3],
+        target_quat: [f64; 4],
+    ) -> Result<Vec<f64>, PhysicsError> {
+        use nalgebra::{DMatrix, DVector, Matrix6, Point3, Quaternion, Vector6};
+
+        if ee_index >= self.end_effector_ids.len() {
+            return Err(PhysicsError::InvalidEndEffector(ee_index));
+        }
+
+        let target_pos = Point3::new(target_pos[0], target_pos[1], target_pos[2]);
+        let target_rot = UnitQuaternion::from_quaternion(Quaternion::new(
+            target_quat[0],
+            target_quat[1],
+            target_quat[2],
+            target_quat[3],
+        ));
+
+        // Per-joint scale from vcad units (degrees/mm) to natural units
+        // (radians/meters), so damping is unit-consistent; zero for joints
+        // with no solvable degree of freedom
+        let joints = self.initial_doc.joints.as_deref().unwrap_or(&[]);
+        let scales: Vec<f64> = self
+            .joint_ids
+            .iter()
+            .map(
+                |id| match joints.iter().find(|j| &j.id == id).map(|j| &j.kind) {
+                    Some(JointKind::Revolute { .. }) | Some(JointKind::Cylindrical { .. }) => {
+                        std::f64::consts::PI / 180.0
+                    }
+                    Some(JointKind::Slider { .. }) => 1.0 / 1000.0,
+                    _ => 0.0,
+                },
+            )
+            .collect();
+
+        let pose_error = |q: &[f64]| -> Vector6<f64> {
+            let [x, y, z, qw, qx, qy, qz] = self.forward_kinematics(q)[ee_index];
+            let rot = UnitQuaternion::from_quaternion(Quaternion::new(qw, qx, qy, qz));
+            let angular = (target_rot * rot.inverse()).scaled_axis();
+            Vector6::new(
+                target_pos.x - x,
+                target_pos.y - y,
+                target_pos.z - z,
+                angular.x,
+                angular.y,
+                angular.z,
+            )
+        };
+
+        const MAX_ITERATIONS: usize = 200;
+        const POS_TOLERANCE: f64 = 1e-5;
+        const ROT_TOLERANCE: f64 = 1e-4;
+        const DAMPING: f64 = 0.05;
+        const STEP: f64 = 1e-5;
+
+        let n = self.joint_ids.len();
+        let mut q = self.observe().joint_positions;
+        let mut error = pose_error(&q);
+
+        for _ in 0..MAX_ITERATIONS {
+            if error.fixed_rows::<3>(0).norm() < POS_TOLERANCE
+                && error.fixed_rows::<3>(3).norm() < ROT_TOLERANCE
+            {
+                return Ok(q);
+            }
+
+            // Numeric Jacobian in natural units: d(pose) / d(q_j)
+            let mut jacobian = DMatrix::zeros(6, n);
+            for (j, &scale) in scales.iter().enumerate() {
+                if scale == 0.0 {
+                    continue;
+                }
+                let mut perturbed = q.clone();
+                perturbed[j] += STEP / scale;
+                let column = (pose_error(&perturbed) - error) / -STEP;
+                jacobian.set_column(j, &DVector::from_column_slice(column.as_slice()));
+            }
+
+            // dq = J^T (J J^T + λ² I)^-1 e
+            let jjt: Matrix6<f64> = (&jacobian * jacobian.transpose()).fixed_resize(0.0);
+            let damped = jjt + Matrix6::identity() * DAMPING * DAMPING;
+            let Some(inv) = damped.try_inverse() else {
+                break;
+            };
+            let dq = jacobian.transpose() * (inv * error);
+            for (j, &scale) in scales.iter().enumerate() {
+                if scale != 0.0 {
+                    q[j] += dq[j] / scale;
+                }
+            }
+            error = pose_error(&q);
+        }
+
+        Err(PhysicsError::IkNotConverged {
+            residual: error.fixed_rows::<3>(0).norm(),
+            angular: error.fixed_rows::<3>(3).norm(),
+        })
+    }
+
     /// Set the random seed.
     pub fn seed(&mut self, seed: u64) {
         self.seed = seed;
@@ -255,6 +523,7 @@ mod tests {
             vcad_ir::Node {
                 id: 1,
                 name: Some("base".to_string()),
+                material: None,
                 op: vcad_ir::CsgOp::Cube {
                     size: Vec3::new(100.0, 100.0, 50.0),
                 },
@@ -265,6 +534,7 @@ mod tests {
             vcad_ir::Node {
                 id: 2,
                 name: Some("link1".to_string()),
+                material: None,
                 op: vcad_ir::CsgOp::Cube {
                     size: Vec3::new(20.0, 20.0, 100.0),
                 },
@@ -275,6 +545,7 @@ mod tests {
             vcad_ir::Node {
                 id: 3,
                 name: Some("link2".to_string()),
+                material: None,
                 op: vcad_ir::CsgOp::Cube {
                     size: Vec3::new(20.0, 20.0, 100.0),
                 },
@@ -283,24 +554,33 @@ mod tests {
 
         // Part definitions
         let mut part_defs = HashMap::new();
-        part_defs.insert("base".to_string(), PartDef {
-            id: "base".to_string(),
-            name: Some("Base".to_string()),
-            root: 1,
-            default_material: None,
-        });
-        part_defs.insert("link1".to_string(), PartDef {
-            id: "link1".to_string(),
-            name: Some("Link 1".to_string()),
-            root: 2,
-            default_material: None,
-        });
-        part_defs.insert("link2".to_string(), PartDef {
-            id: "link2".to_string(),
-            name: Some("Link 2".to_string()),
-            root: 3,
-            default_material: None,
-        });
+        part_defs.insert(
+            "base".to_string(),
+            PartDef {
+                id: "base".to_string(),
+                name: Some("Base".to_string()),
+                root: 1,
+                default_material: None,
+            },
+        );
+        part_defs.insert(
+            "link1".to_string(),
+            PartDef {
+                id: "link1".to_string(),
+                name: Some("Link 1".to_string()),
+                root: 2,
+                default_material: None,
+            },
+        );
+        part_defs.insert(
+            "link2".to_string(),
+            PartDef {
+                id: "link2".to_string(),
+                name: Some("Link 2".to_string()),
+                root: 3,
+                default_material: None,
+            },
+        );
         doc.part_defs = Some(part_defs);
 
         // Instances
@@ -392,9 +672,278 @@ mod tests {
 
         // Step with position target
         let action = Action::PositionTarget(vec![45.0, 30.0]);
-        let (obs, reward, done) = env.step(action);
+        let (obs, _reward, done) = env.step(action);
 
         assert_eq!(obs.joint_positions.len(), 2);
         assert!(!done); // Should not be done after 1 step
     }
+
+    /// Two-link document: a grounded base and one link on a revolute joint,
+    /// defined entirely through the IR `Joint` type.
+    fn create_two_link_robot() -> Document {
+        let mut doc = Document::new();
+
+        doc.nodes.insert(
+            1,
+            vcad_ir::Node {
+                id: 1,
+                name: Some("base".to_string()),
+                material: None,
+                op: vcad_ir::CsgOp::Cube {
+                    size: Vec3::new(100.0, 100.0, 50.0),
+                },
+            },
+        );
+        doc.nodes.insert(
+            2,
+            vcad_ir::Node {
+                id: 2,
+                name: Some("link".to_string()),
+                material: None,
+                op: vcad_ir::CsgOp::Cube {
+                    size: Vec3::new(20.0, 20.0, 100.0),
+                },
+            },
+        );
+
+        let mut part_defs = HashMap::new();
+        part_defs.insert(
+            "base".to_string(),
+            PartDef {
+                id: "base".to_string(),
+                name: Some("Base".to_string()),
+                root: 1,
+                default_material: None,
+            },
+        );
+        part_defs.insert(
+            "link".to_string(),
+            PartDef {
+                id: "link".to_string(),
+                name: Some("Link".to_string()),
+                root: 2,
+                default_material: None,
+            },
+        );
+        doc.part_defs = Some(part_defs);
+
+        doc.instances = Some(vec![
+            Instance {
+                id: "base_inst".to_string(),
+                part_def_id: "base".to_string(),
+                name: Some("Base".to_string()),
+                transform: None,
+                material: None,
+            },
+            Instance {
+                id: "link_inst".to_string(),
+                part_def_id: "link".to_string(),
+                name: Some("Link".to_string()),
+                transform: None,
+                material: None,
+            },
+        ]);
+
+        doc.joints = Some(vec![Joint {
+            id: "hinge".to_string(),
+            name: Some("Hinge".to_string()),
+            parent_instance_id: Some("base_inst".to_string()),
+            child_instance_id: "link_inst".to_string(),
+            parent_anchor: Vec3::new(0.0, 0.0, 25.0),
+            child_anchor: Vec3::new(0.0, 0.0, -50.0),
+            kind: JointKind::Revolute {
+                axis: Vec3::new(0.0, 1.0, 0.0),
+                limits: None,
+            },
+            state: 0.0,
+        }]);
+
+        doc.ground_instance_id = Some("base_inst".to_string());
+
+        doc
+    }
+
+    #[test]
+    fn test_revolute_joint_from_ir_articulates_under_torque() {
+        let doc = create_two_link_robot();
+        let mut env = RobotEnv::new(doc, vec!["link_inst".to_string()], None, None).unwrap();
+
+        // The single IR joint becomes the whole action space
+        assert_eq!(env.num_joints(), 1);
+
+        let obs = env.reset();
+        assert!(obs.joint_positions[0].abs() < 1e-6);
+
+        // A constant torque about the (gravity-neutral) Y hinge axis must
+        // swing the link away from its initial pose
+        let mut obs = env.observe();
+        for _ in 0..50 {
+            obs = env.step(Action::Torque(vec![2.0])).0;
+        }
+        assert!(
+            obs.joint_positions[0].abs() > 1.0,
+            "joint did not articulate: position = {}",
+            obs.joint_positions[0]
+        );
+        assert!(obs.joint_velocities[0].abs() > 1e-3);
+    }
+
+    #[test]
+    fn test_forward_kinematics_matches_analytic() {
+        let doc = create_two_link_robot();
+        let env = RobotEnv::new(doc, vec!["link_inst".to_string()], None, None).unwrap();
+
+        // At zero the link hangs straight along +Z: anchor offset 25mm on
+        // the base plus 50mm from the joint to the link origin
+        let poses = env.forward_kinematics(&[0.0]);
+        assert_eq!(poses.len(), 1);
+        let [x, y, z, qw, ..] = poses[0];
+        assert!(x.abs() < 1e-12 && y.abs() < 1e-12);
+        assert!((z - 0.075).abs() < 1e-12);
+        assert!((qw - 1.0).abs() < 1e-12);
+
+        // Rotating 90 degrees about the Y hinge swings the 50mm offset
+        // from +Z onto +X
+        let poses = env.forward_kinematics(&[90.0]);
+        let [x, y, z, ..] = poses[0];
+        assert!((x - 0.05).abs() < 1e-12, "x = {x}");
+        assert!(y.abs() < 1e-12);
+        assert!((z - 0.025).abs() < 1e-12, "z = {z}");
+    }
+
+    #[test]
+    fn test_inverse_kinematics_reaches_target() {
+        let doc = create_two_link_robot();
+        let env = RobotEnv::new(doc, vec!["link_inst".to_string()], None, None).unwrap();
+
+        // A pose known to be reachable: the FK result at 35 degrees
+        let [x, y, z, qw, qx, qy, qz] = env.forward_kinematics(&[35.0])[0];
+        let solution = env
+            .inverse_kinematics(0, [x, y, z], [qw, qx, qy, qz])
+            .unwrap();
+        assert_eq!(solution.len(), 1);
+
+        // Feeding the solution back through FK lands on the target
+        let [sx, sy, sz, ..] = env.forward_kinematics(&solution)[0];
+        assert!((sx - x).abs() < 1e-4, "x: {sx} vs {x}");
+        assert!((sy - y).abs() < 1e-4);
+        assert!((sz - z).abs() < 1e-4, "z: {sz} vs {z}");
+    }
+
+    #[test]
+    fn test_inverse_kinematics_unreachable_target() {
+        let doc = create_two_link_robot();
+        let env = RobotEnv::new(doc, vec!["link_inst".to_string()], None, None).unwrap();
+
+        // A meter away — far outside the 50mm link's reach
+        let result = env.inverse_kinematics(0, [1.0, 0.0, 0.0], [1.0, 0.0, 0.0, 0.0]);
+        assert!(matches!(result, Err(PhysicsError::IkNotConverged { .. })));
+
+        // And an end effector that does not exist
+        let result = env.inverse_kinematics(1, [0.0, 0.0, 0.05], [1.0, 0.0, 0.0, 0.0]);
+        assert!(matches!(result, Err(PhysicsError::InvalidEndEffector(1))));
+    }
+
+    /// A grounded base plus one unjointed free body floating 300mm up.
+    fn create_free_body_doc() -> Document {
+        let mut doc = Document::new();
+
+        doc.nodes.insert(
+            1,
+            vcad_ir::Node {
+                id: 1,
+                name: Some("base".to_string()),
+                material: None,
+                op: vcad_ir::CsgOp::Cube {
+                    size: Vec3::new(100.0, 20.0, 100.0),
+                },
+            },
+        );
+        doc.nodes.insert(
+            2,
+            vcad_ir::Node {
+                id: 2,
+                name: Some("ball".to_string()),
+                material: None,
+                op: vcad_ir::CsgOp::Cube {
+                    size: Vec3::new(20.0, 20.0, 20.0),
+                },
+            },
+        );
+
+        let mut part_defs = HashMap::new();
+        part_defs.insert(
+            "base".to_string(),
+            PartDef {
+                id: "base".to_string(),
+                name: None,
+                root: 1,
+                default_material: None,
+            },
+        );
+        part_defs.insert(
+            "ball".to_string(),
+            PartDef {
+                id: "ball".to_string(),
+                name: None,
+                root: 2,
+                default_material: None,
+            },
+        );
+        doc.part_defs = Some(part_defs);
+
+        doc.instances = Some(vec![
+            Instance {
+                id: "base_inst".to_string(),
+                part_def_id: "base".to_string(),
+                name: None,
+                transform: None,
+                material: None,
+            },
+            Instance {
+                id: "ball_inst".to_string(),
+                part_def_id: "ball".to_string(),
+                name: None,
+                transform: Some(vcad_ir::Transform3D {
+                    translation: Vec3::new(0.0, 300.0, 0.0),
+                    ..Default::default()
+                }),
+                material: None,
+            },
+        ]);
+        doc.joints = Some(Vec::new());
+        doc.ground_instance_id = Some("base_inst".to_string());
+
+        doc
+    }
+
+    #[test]
+    fn test_zero_gravity_free_body_does_not_fall() {
+        // Under default gravity the free body falls
+        let doc = create_free_body_doc();
+        let mut env = RobotEnv::new(doc, vec!["ball_inst".to_string()], None, None).unwrap();
+        let y0 = env.observe().end_effector_poses[0][1];
+        for _ in 0..10 {
+            env.step(Action::Torque(Vec::new()));
+        }
+        let y_fallen = env.observe().end_effector_poses[0][1];
+        assert!(
+            y0 - y_fallen > 0.01,
+            "body did not fall: {y0} -> {y_fallen}"
+        );
+
+        // With gravity zeroed it stays put, including after a reset
+        let doc = create_free_body_doc();
+        let mut env = RobotEnv::new(doc, vec!["ball_inst".to_string()], None, None).unwrap();
+        env.set_gravity(0.0, 0.0, 0.0);
+        env.reset();
+        for _ in 0..10 {
+            env.step(Action::Torque(Vec::new()));
+        }
+        let y_float = env.observe().end_effector_poses[0][1];
+        assert!(
+            (y_float - y0).abs() < 1e-6,
+            "body moved in zero gravity: {y0} -> {y_float}"
+        );
+    }
 }
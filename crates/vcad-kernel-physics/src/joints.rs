@@ -2,8 +2,7 @@
 
 use nalgebra::{Point3, UnitVector3, Vector3};
 use rapier3d::dynamics::{
-    GenericJoint, GenericJointBuilder, JointAxesMask, JointAxis, MotorModel,
-    RigidBodyHandle,
+    GenericJoint, GenericJointBuilder, JointAxesMask, JointAxis, MotorModel, RigidBodyHandle,
 };
 use vcad_ir::{Joint as VcadJoint, JointKind};
 
@@ -12,9 +11,7 @@ use crate::error::PhysicsError;
 /// Default motor parameters.
 #[allow(dead_code)]
 pub const DEFAULT_MOTOR_STIFFNESS: f32 = 1000.0;
-#[allow(dead_code)]
 pub const DEFAULT_MOTOR_DAMPING: f32 = 100.0;
-#[allow(dead_code)]
 pub const DEFAULT_MAX_FORCE: f32 = 1000.0;
 
 /// Create a Rapier joint from a vcad joint definition.
@@ -73,7 +70,11 @@ pub fn vcad_joint_to_rapier(
         JointKind::Cylindrical { axis } => {
             let axis_vec = Vector3::new(axis.x as f32, axis.y as f32, axis.z as f32);
             let axis_unit = UnitVector3::new_normalize(axis_vec);
-            Ok(create_cylindrical_joint(parent_anchor, child_anchor, axis_unit))
+            Ok(create_cylindrical_joint(
+                parent_anchor,
+                child_anchor,
+                axis_unit,
+            ))
         }
         JointKind::Ball => Ok(create_ball_joint(parent_anchor, child_anchor)),
     }
@@ -145,10 +146,8 @@ fn create_cylindrical_joint(
 ) -> GenericJoint {
     // Cylindrical = rotation + translation along axis
     // Lock all axes except AngX and LinX
-    let locked = JointAxesMask::LIN_Y
-        | JointAxesMask::LIN_Z
-        | JointAxesMask::ANG_Y
-        | JointAxesMask::ANG_Z;
+    let locked =
+        JointAxesMask::LIN_Y | JointAxesMask::LIN_Z | JointAxesMask::ANG_Y | JointAxesMask::ANG_Z;
 
     GenericJointBuilder::new(locked)
         .local_anchor1(parent_anchor)
@@ -228,7 +227,10 @@ mod tests {
         let rapier_joint = vcad_joint_to_rapier(&joint, None, RigidBodyHandle::invalid()).unwrap();
 
         // Check that joint was created (just verify it doesn't panic)
-        assert!(rapier_joint.local_anchor1().coords.norm() > 0.0 || rapier_joint.local_anchor2().coords.norm() >= 0.0);
+        assert!(
+            rapier_joint.local_anchor1().coords.norm() > 0.0
+                || rapier_joint.local_anchor2().coords.norm() >= 0.0
+        );
     }
 
     #[test]
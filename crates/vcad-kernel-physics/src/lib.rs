@@ -35,8 +35,10 @@ mod colliders;
 mod error;
 mod gym;
 mod joints;
+mod vec_env;
 mod world;
 
 pub use error::PhysicsError;
 pub use gym::{Action, Observation, RobotEnv};
+pub use vec_env::VecRobotEnv;
 pub use world::{JointState, PhysicsWorld};
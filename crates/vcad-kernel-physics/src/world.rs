@@ -4,9 +4,8 @@ use std::collections::HashMap;
 
 use nalgebra::{Isometry3, UnitQuaternion, Vector3};
 use rapier3d::dynamics::{
-    CCDSolver, ImpulseJointHandle, ImpulseJointSet, IntegrationParameters,
-    IslandManager, MultibodyJointSet, RigidBodyBuilder, RigidBodyHandle, RigidBodySet,
-    RigidBodyType,
+    CCDSolver, ImpulseJointHandle, ImpulseJointSet, IntegrationParameters, IslandManager,
+    MultibodyJointSet, RigidBodyBuilder, RigidBodyHandle, RigidBodySet, RigidBodyType,
 };
 use rapier3d::geometry::{BroadPhaseMultiSap, ColliderBuilder, ColliderSet, NarrowPhase};
 use rapier3d::pipeline::{PhysicsPipeline, QueryPipeline};
@@ -14,7 +13,10 @@ use vcad_ir::{Document, JointKind};
 
 use crate::colliders::{estimate_mass, mesh_to_collider, ColliderStrategy};
 use crate::error::PhysicsError;
-use crate::joints::{convert_state_from_physics, convert_state_to_physics, get_joint_axis, vcad_joint_to_rapier};
+use crate::joints::{
+    convert_state_from_physics, convert_state_to_physics, get_joint_axis, vcad_joint_to_rapier,
+    DEFAULT_MAX_FORCE, DEFAULT_MOTOR_DAMPING,
+};
 
 /// State of a single joint.
 #[derive(Debug, Clone, Default)]
@@ -172,7 +174,9 @@ impl PhysicsWorld {
             world
                 .joint_to_impulse
                 .insert(joint.id.clone(), joint_handle);
-            world.joint_kinds.insert(joint.id.clone(), joint.kind.clone());
+            world
+                .joint_kinds
+                .insert(joint.id.clone(), joint.kind.clone());
 
             // Set initial joint state
             if joint.state.abs() > 1e-6 {
@@ -204,6 +208,17 @@ impl PhysicsWorld {
         }
     }
 
+    /// Set linear and angular damping on every rigid body.
+    ///
+    /// Damping models drag from the surrounding medium: zero for vacuum
+    /// (Rapier's default), higher values for underwater-like scenarios.
+    pub fn set_global_damping(&mut self, linear: f32, angular: f32) {
+        for (_, body) in self.bodies.iter_mut() {
+            body.set_linear_damping(linear);
+            body.set_angular_damping(angular);
+        }
+    }
+
     /// Step the physics simulation by dt seconds.
     pub fn step(&mut self, dt: f32) {
         self.integration_params.dt = dt;
@@ -286,7 +301,9 @@ impl PhysicsWorld {
 
                         (joint_pos, joint_vel)
                     }
-                    JointKind::Fixed | JointKind::Cylindrical { .. } | JointKind::Ball => (0.0, 0.0),
+                    JointKind::Fixed | JointKind::Cylindrical { .. } | JointKind::Ball => {
+                        (0.0, 0.0)
+                    }
                 };
 
                 states.insert(
@@ -319,6 +336,9 @@ impl PhysicsWorld {
                     joint
                         .data
                         .set_motor_position(axis, physics_target, 1000.0, 100.0);
+                    // Joints are built with the motor force capped at zero;
+                    // enable it when a target is first set
+                    joint.data.set_motor_max_force(axis, DEFAULT_MAX_FORCE);
                 }
             }
         }
@@ -338,6 +358,7 @@ impl PhysicsWorld {
 
                 if let Some(joint) = self.impulse_joints.get_mut(handle, true) {
                     joint.data.set_motor_velocity(axis, physics_target, 100.0);
+                    joint.data.set_motor_max_force(axis, DEFAULT_MAX_FORCE);
                 }
             }
         }
@@ -355,8 +376,14 @@ impl PhysicsWorld {
                 let axis = get_joint_axis(kind);
 
                 if let Some(joint) = self.impulse_joints.get_mut(handle, true) {
-                    // Apply as motor with target velocity 0 but limited force
-                    joint.data.set_motor_velocity(axis, 0.0, torque.abs() as f32);
+                    // Approximate a constant torque: drive toward a saturated
+                    // velocity with the motor force capped at the requested
+                    // magnitude
+                    let target = if torque >= 0.0 { 1.0e4 } else { -1.0e4 };
+                    joint
+                        .data
+                        .set_motor_velocity(axis, target, DEFAULT_MOTOR_DAMPING);
+                    joint.data.set_motor_max_force(axis, torque.abs() as f32);
                 }
             }
         }
@@ -414,23 +441,36 @@ impl PhysicsWorld {
 
         // Create a simple mesh based on the primitive type
         let solid = match &node.op {
-            vcad_ir::CsgOp::Cube { size } => {
-                vcad_kernel::Solid::cube(size.x, size.y, size.z)
-            }
-            vcad_ir::CsgOp::Cylinder { radius, height, segments } => {
-                vcad_kernel::Solid::cylinder(*radius, *height, if *segments == 0 { 32 } else { *segments })
-            }
+            vcad_ir::CsgOp::Cube { size } => vcad_kernel::Solid::cube(size.x, size.y, size.z),
+            vcad_ir::CsgOp::Cylinder {
+                radius,
+                height,
+                segments,
+            } => vcad_kernel::Solid::cylinder(
+                *radius,
+                *height,
+                if *segments == 0 { 32 } else { *segments },
+            ),
             vcad_ir::CsgOp::Sphere { radius, segments } => {
                 vcad_kernel::Solid::sphere(*radius, if *segments == 0 { 32 } else { *segments })
             }
-            vcad_ir::CsgOp::Cone { radius_bottom, radius_top, height, segments } => {
-                vcad_kernel::Solid::cone(*radius_bottom, *radius_top, *height, if *segments == 0 { 32 } else { *segments })
-            }
+            vcad_ir::CsgOp::Cone {
+                radius_bottom,
+                radius_top,
+                height,
+                segments,
+            } => vcad_kernel::Solid::cone(
+                *radius_bottom,
+                *radius_top,
+                *height,
+                if *segments == 0 { 32 } else { *segments },
+            ),
             _ => {
                 // For other operations, create a small placeholder
                 vcad_kernel::Solid::cube(10.0, 10.0, 10.0)
             }
-        };
+        }
+        .map_err(|e| PhysicsError::Evaluation(format!("Node {}: {}", node_id, e)))?;
 
         Ok(solid.to_mesh(32))
     }
@@ -450,6 +490,7 @@ mod tests {
             vcad_ir::Node {
                 id: 1,
                 name: Some("base_geom".to_string()),
+                material: None,
                 op: vcad_ir::CsgOp::Cube {
                     size: Vec3::new(100.0, 100.0, 50.0),
                 },
@@ -460,6 +501,7 @@ mod tests {
             vcad_ir::Node {
                 id: 2,
                 name: Some("arm_geom".to_string()),
+                material: None,
                 op: vcad_ir::CsgOp::Cube {
                     size: Vec3::new(20.0, 20.0, 100.0),
                 },
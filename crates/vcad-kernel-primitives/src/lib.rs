@@ -452,6 +452,173 @@ pub fn make_cone(radius_bottom: f64, radius_top: f64, height: f64, _segments: u3
     }
 }
 
+/// Build a B-rep regular polygonal prism with `sides` planar lateral faces.
+///
+/// The polygon's vertices lie on a circle of the given circumradius in the
+/// XY plane (first vertex on the +X axis), extruded along Z from `z=0` to
+/// `z=height`. Useful as hex-nut or standoff stock where a faceted cylinder
+/// approximation won't do.
+pub fn make_polygon_prism(sides: u32, circumradius: f64, height: f64) -> BRepSolid {
+    let n = sides.max(3) as usize;
+    let mut topo = Topology::new();
+    let geom = GeometryStore::new();
+
+    let ring = |z: f64, topo: &mut Topology| -> Vec<vcad_kernel_topo::VertexId> {
+        (0..n)
+            .map(|i| {
+                let a = 2.0 * std::f64::consts::PI * (i as f64 / n as f64);
+                topo.add_vertex(Point3::new(
+                    circumradius * a.cos(),
+                    circumradius * a.sin(),
+                    z,
+                ))
+            })
+            .collect()
+    };
+    let bottom = ring(0.0, &mut topo);
+    let top = ring(height, &mut topo);
+
+    // Bottom cap (normal -Z, CCW viewed from below), top cap (+Z), laterals
+    let mut bottom_rev = bottom.clone();
+    bottom_rev[1..].reverse();
+    let mut face_defs: Vec<(Vec<vcad_kernel_topo::VertexId>, Plane)> = vec![
+        (
+            bottom_rev,
+            Plane::new(
+                Point3::origin(),
+                Vec3::new(0.0, 1.0, 0.0),
+                Vec3::new(1.0, 0.0, 0.0),
+            ),
+        ),
+        (
+            top.clone(),
+            Plane::new(
+                Point3::new(0.0, 0.0, height),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(0.0, 1.0, 0.0),
+            ),
+        ),
+    ];
+    for i in 0..n {
+        let j = (i + 1) % n;
+        let p_i = topo.vertices[bottom[i]].point;
+        let p_j = topo.vertices[bottom[j]].point;
+        face_defs.push((
+            vec![bottom[i], bottom[j], top[j], top[i]],
+            Plane::new(p_i, p_j - p_i, Vec3::new(0.0, 0.0, 1.0)),
+        ));
+    }
+
+    build_planar_brep(topo, geom, face_defs)
+}
+
+/// Build a B-rep pyramid over a regular polygon base.
+///
+/// The base polygon lies in the XY plane (circumradius as for
+/// [`make_polygon_prism`]) with the apex at `(0, 0, height)`: `base_sides`
+/// triangular lateral faces plus the base cap.
+pub fn make_pyramid(base_sides: u32, circumradius: f64, height: f64) -> BRepSolid {
+    let n = base_sides.max(3) as usize;
+    let mut topo = Topology::new();
+    let geom = GeometryStore::new();
+
+    let base: Vec<vcad_kernel_topo::VertexId> = (0..n)
+        .map(|i| {
+            let a = 2.0 * std::f64::consts::PI * (i as f64 / n as f64);
+            topo.add_vertex(Point3::new(
+                circumradius * a.cos(),
+                circumradius * a.sin(),
+                0.0,
+            ))
+        })
+        .collect();
+    let apex = topo.add_vertex(Point3::new(0.0, 0.0, height));
+    let apex_point = topo.vertices[apex].point;
+
+    let mut base_rev = base.clone();
+    base_rev[1..].reverse();
+    let mut face_defs: Vec<(Vec<vcad_kernel_topo::VertexId>, Plane)> = vec![(
+        base_rev,
+        Plane::new(
+            Point3::origin(),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+        ),
+    )];
+    for i in 0..n {
+        let j = (i + 1) % n;
+        let p_i = topo.vertices[base[i]].point;
+        let p_j = topo.vertices[base[j]].point;
+        face_defs.push((
+            vec![base[i], base[j], apex],
+            Plane::new(p_i, p_j - p_i, apex_point - p_i),
+        ));
+    }
+
+    build_planar_brep(topo, geom, face_defs)
+}
+
+/// Assemble planar faces (vertex loops + planes) into a closed B-rep solid:
+/// builds loops, pairs twin half-edges, adds line curves for every edge, and
+/// wraps all faces in a single outer shell.
+fn build_planar_brep(
+    mut topo: Topology,
+    mut geom: GeometryStore,
+    face_defs: Vec<(Vec<vcad_kernel_topo::VertexId>, Plane)>,
+) -> BRepSolid {
+    let mut he_map: std::collections::HashMap<
+        (vcad_kernel_topo::VertexId, vcad_kernel_topo::VertexId),
+        HalfEdgeId,
+    > = std::collections::HashMap::new();
+
+    let mut all_faces = Vec::new();
+    for (verts, plane) in face_defs {
+        let surface_idx = geom.add_surface(Box::new(plane));
+        let count = verts.len();
+        let mut hes = Vec::new();
+        for j in 0..count {
+            let he = topo.add_half_edge(verts[j]);
+            hes.push(he);
+            he_map.insert((verts[j], verts[(j + 1) % count]), he);
+        }
+        let loop_id = topo.add_loop(&hes);
+        all_faces.push(topo.add_face(loop_id, surface_idx, Orientation::Forward));
+    }
+
+    // Pair twin half-edges (each edge only once)
+    let mut paired = std::collections::HashSet::new();
+    for &(v_from, v_to) in he_map.keys() {
+        if paired.contains(&(v_to, v_from)) {
+            continue;
+        }
+        if let Some(&he2) = he_map.get(&(v_to, v_from)) {
+            let he1 = he_map[&(v_from, v_to)];
+            topo.add_edge(he1, he2);
+            paired.insert((v_from, v_to));
+        }
+    }
+
+    // Add 3D curves for all edges (lines)
+    for &face_id in &all_faces {
+        let face = &topo.faces[face_id];
+        for he_id in topo.loop_half_edges(face.outer_loop).collect::<Vec<_>>() {
+            let origin = topo.vertices[topo.half_edges[he_id].origin].point;
+            let dest_id = topo.half_edge_dest(he_id);
+            let dest = topo.vertices[dest_id].point;
+            geom.add_curve_3d(Box::new(Line3d::from_points(origin, dest)));
+        }
+    }
+
+    let shell = topo.add_shell(all_faces, ShellType::Outer);
+    let solid_id = topo.add_solid(shell);
+
+    BRepSolid {
+        topology: topo,
+        geometry: geom,
+        solid_id,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -545,6 +712,67 @@ mod tests {
         assert_eq!(topo.edges.len(), 3); // 2 circles + 1 seam
     }
 
+    #[test]
+    fn test_polygon_prism_topology() {
+        let brep = make_polygon_prism(6, 10.0, 5.0);
+        // 2 caps + 6 lateral faces, 12 vertices, 18 edges
+        assert_eq!(brep.topology.faces.len(), 8);
+        assert_eq!(brep.topology.vertices.len(), 12);
+        assert_eq!(brep.topology.edges.len(), 18);
+        // Every half-edge has a twin (closed solid)
+        for (_, he) in &brep.topology.half_edges {
+            assert!(he.twin.is_some());
+        }
+    }
+
+    #[test]
+    fn test_pyramid_topology() {
+        let brep = make_pyramid(4, 10.0, 8.0);
+        // base + 4 triangles, 5 vertices, 8 edges
+        assert_eq!(brep.topology.faces.len(), 5);
+        assert_eq!(brep.topology.vertices.len(), 5);
+        assert_eq!(brep.topology.edges.len(), 8);
+        for (_, he) in &brep.topology.half_edges {
+            assert!(he.twin.is_some());
+        }
+    }
+
+    #[test]
+    fn test_hexagonal_prism_faces_and_cap_area() {
+        let r = 10.0;
+        let brep = make_polygon_prism(6, r, 5.0);
+        let topo = &brep.topology;
+
+        // Compute each face's area and normal from its outer loop polygon
+        let mut lateral_count = 0;
+        let mut cap_areas = Vec::new();
+        for (_, face) in &topo.faces {
+            let points: Vec<_> = topo
+                .loop_half_edges(face.outer_loop)
+                .map(|he| topo.vertices[topo.half_edges[he].origin].point)
+                .collect();
+            let mut area_vec = vcad_kernel_math::Vec3::new(0.0, 0.0, 0.0);
+            for i in 1..points.len() - 1 {
+                let a = points[i] - points[0];
+                let b = points[i + 1] - points[0];
+                area_vec += a.cross(&b) * 0.5;
+            }
+            if area_vec.z.abs() < 1e-9 {
+                lateral_count += 1;
+            } else {
+                cap_areas.push(area_vec.norm());
+            }
+        }
+
+        assert_eq!(lateral_count, 6);
+        assert_eq!(cap_areas.len(), 2);
+        // Regular hexagon area: 3√3/2 · r²
+        let expected = 1.5 * 3.0_f64.sqrt() * r * r;
+        for area in cap_areas {
+            assert!((area - expected).abs() < 1e-9);
+        }
+    }
+
     #[test]
     fn test_cone_equal_radii_is_cylinder() {
         let brep = make_cone(5.0, 5.0, 10.0, 32);
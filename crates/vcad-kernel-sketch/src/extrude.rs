@@ -962,7 +962,6 @@ mod tests {
             twist_angle: PI, // 180 degrees
             scale_end: 0.8,
             arc_segments: 4,
-            ..Default::default()
         };
 
         let solid = extrude_with_options(&profile, Vec3::new(0.0, 0.0, 20.0), options).unwrap();
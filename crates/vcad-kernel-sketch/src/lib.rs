@@ -62,8 +62,9 @@ pub enum SketchError {
     #[error("arc segments not supported for revolve operation")]
     ArcNotSupported,
 
-    /// Profile intersects the revolution axis.
-    #[error("profile intersects the revolution axis")]
+    /// Profile has points on both sides of the revolution axis, which would
+    /// sweep a self-intersecting solid.
+    #[error("profile crosses the revolution axis")]
     AxisIntersection,
 
     /// Profile has no segments.
@@ -28,7 +28,8 @@ use crate::{SketchError, SketchProfile, SketchSegment};
 /// - `ZeroAxis` if the axis direction is zero
 /// - `InvalidAngle` if angle is not in (0, 2π]
 /// - `ArcNotSupported` if the profile contains arc segments
-/// - `AxisIntersection` if any profile vertex lies on the axis
+/// - `AxisIntersection` if the profile has points on both sides of the axis
+///   (points exactly on the axis are allowed and revolve to a pole)
 ///
 /// # Current Limitations
 ///
@@ -78,14 +79,27 @@ pub fn revolve(
     let tol = Tolerance::DEFAULT;
     let is_full = (angle - 2.0 * PI).abs() < 1e-9;
 
-    // Validate profile doesn't intersect axis
+    // Validate the profile against the axis: points may lie on the axis
+    // (they revolve to a pole), but points on both sides would sweep a
+    // self-intersecting solid. Sidedness is measured along the in-plane
+    // direction perpendicular to the axis.
+    let side_dir = profile.normal.as_ref().cross(axis.as_ref());
+    let mut has_positive = false;
+    let mut has_negative = false;
     for seg in &profile.segments {
         let p = profile.to_3d(seg.start());
-        let dist = point_to_line_distance(&p, &axis_origin, axis.as_ref());
-        if dist < tol.linear {
-            return Err(SketchError::AxisIntersection);
+        let rel = p - axis_origin;
+        let radial = rel - rel.dot(axis.as_ref()) * axis.as_ref();
+        let signed = radial.dot(&side_dir);
+        if signed > tol.linear {
+            has_positive = true;
+        } else if signed < -tol.linear {
+            has_negative = true;
         }
     }
+    if has_positive && has_negative {
+        return Err(SketchError::AxisIntersection);
+    }
 
     let mut topo = Topology::new();
     let mut geom = GeometryStore::new();
@@ -483,12 +497,6 @@ fn pair_twin_half_edges(topo: &mut Topology, he_map: &HashMap<([i64; 3], [i64; 3
     }
 }
 
-fn point_to_line_distance(point: &Point3, line_origin: &Point3, line_dir: &Vec3) -> f64 {
-    let v = point - line_origin;
-    let proj = v.dot(line_dir) * line_dir;
-    (v - proj).norm()
-}
-
 fn rotate_point(point: &Point3, axis_origin: &Point3, axis: &Vec3, angle: f64) -> Point3 {
     let v = point - axis_origin;
 
@@ -516,6 +524,7 @@ fn rotate_point(point: &Point3, axis_origin: &Point3, axis: &Vec3, angle: f64) -
 #[cfg(test)]
 mod tests {
     use super::*;
+    use vcad_kernel_math::Point2;
 
     #[test]
     fn test_revolve_rectangle_full() {
@@ -597,21 +606,39 @@ mod tests {
     }
 
     #[test]
-    fn test_revolve_axis_intersection_error() {
-        // Profile with a vertex on the Z-axis (x=0, y=0)
-        // Rectangle at origin in XZ plane, one corner at (0,0,0) which is on Z-axis
-        let profile = SketchProfile::rectangle(
-            Point3::origin(), // Origin at (0, 0, 0) which is on Z-axis
-            Vec3::x(),
-            Vec3::z(),
-            5.0,
-            5.0,
-        );
+    fn test_revolve_axis_crossing_error() {
+        // Rectangle straddling the Z-axis: x spans [-2, 3]
+        let profile =
+            SketchProfile::rectangle(Point3::new(-2.0, 0.0, 0.0), Vec3::x(), Vec3::z(), 5.0, 5.0);
 
         let result = revolve(&profile, Point3::origin(), Vec3::z(), PI);
         assert!(matches!(result, Err(SketchError::AxisIntersection)));
     }
 
+    #[test]
+    fn test_revolve_touching_axis_ok() {
+        // Triangle with one vertex on the Z-axis: (0,0,0), (5,0,0), (5,0,5).
+        // Revolving it fully yields a cylinder with a conical bore — valid.
+        let p0 = Point2::new(0.0, 0.0);
+        let p1 = Point2::new(5.0, 0.0);
+        let p2 = Point2::new(5.0, 5.0);
+        let profile = SketchProfile::new(
+            Point3::origin(),
+            Vec3::x(),
+            Vec3::z(),
+            vec![
+                SketchSegment::Line { start: p0, end: p1 },
+                SketchSegment::Line { start: p1, end: p2 },
+                SketchSegment::Line { start: p2, end: p0 },
+            ],
+        )
+        .unwrap();
+
+        let solid = revolve(&profile, Point3::origin(), Vec3::z(), 2.0 * PI).unwrap();
+        let mesh = vcad_kernel_tessellate::tessellate_brep(&solid, 32);
+        assert!(mesh.num_triangles() > 0);
+    }
+
     #[test]
     fn test_revolve_90_degrees_volume() {
         // Rectangle profile: inner radius 5, outer radius 8, height 10
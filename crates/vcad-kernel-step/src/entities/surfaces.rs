@@ -201,12 +201,19 @@ pub fn parse_bspline_surface(file: &StepFile, id: u64) -> Result<BSplineSurface,
             let v_mults = entity.list(9)?.to_vec();
             let u_knots = entity.list(10)?.to_vec();
             let v_knots = entity.list(11)?.to_vec();
-            (u_degree, v_degree, cp_list, u_mults, v_mults, u_knots, v_knots)
+            (
+                u_degree, v_degree, cp_list, u_mults, v_mults, u_knots, v_knots,
+            )
         } else {
             // Complex entity - look for B_SPLINE_SURFACE and B_SPLINE_SURFACE_WITH_KNOTS in args
             let mut bspline_data: Option<(&Vec<StepValue>, usize, usize)> = None;
             #[allow(clippy::type_complexity)]
-            let mut knots_data: Option<(&Vec<StepValue>, &Vec<StepValue>, &Vec<StepValue>, &Vec<StepValue>)> = None;
+            let mut knots_data: Option<(
+                &Vec<StepValue>,
+                &Vec<StepValue>,
+                &Vec<StepValue>,
+                &Vec<StepValue>,
+            )> = None;
 
             for arg in &entity.args {
                 if let StepValue::Typed { type_name, args } = arg {
@@ -233,9 +240,15 @@ pub fn parse_bspline_surface(file: &StepFile, id: u64) -> Result<BSplineSurface,
             }
 
             match (bspline_data, knots_data) {
-                (Some((cp_list, u_deg, v_deg)), Some((u_mults, v_mults, u_knots, v_knots))) => {
-                    (u_deg, v_deg, cp_list.clone(), u_mults.clone(), v_mults.clone(), u_knots.clone(), v_knots.clone())
-                }
+                (Some((cp_list, u_deg, v_deg)), Some((u_mults, v_mults, u_knots, v_knots))) => (
+                    u_deg,
+                    v_deg,
+                    cp_list.clone(),
+                    u_mults.clone(),
+                    v_mults.clone(),
+                    u_knots.clone(),
+                    v_knots.clone(),
+                ),
                 _ => {
                     // Can't extract B-spline data - treat as unsupported
                     return Err(StepError::UnsupportedEntity(format!(
@@ -312,8 +325,13 @@ pub fn parse_bspline_surface(file: &StepFile, id: u64) -> Result<BSplineSurface,
 fn expand_knots(knots: &[StepValue], mults: &[StepValue]) -> Result<Vec<f64>, StepError> {
     let mut result = Vec::new();
     for (knot, mult) in knots.iter().zip(mults.iter()) {
-        let k = knot.as_real().ok_or_else(|| StepError::parser(None, "invalid knot value"))?;
-        let m = mult.as_integer().ok_or_else(|| StepError::parser(None, "invalid multiplicity"))? as usize;
+        let k = knot
+            .as_real()
+            .ok_or_else(|| StepError::parser(None, "invalid knot value"))?;
+        let m = mult
+            .as_integer()
+            .ok_or_else(|| StepError::parser(None, "invalid multiplicity"))?
+            as usize;
         for _ in 0..m {
             result.push(k);
         }
@@ -11,6 +11,7 @@ use crate::error::StepError;
 use stepperoni::{Parser, StepFile};
 
 use vcad_kernel_geom::GeometryStore;
+use vcad_kernel_math::{geometry_tolerance, Point3};
 use vcad_kernel_primitives::BRepSolid;
 use vcad_kernel_topo::{EdgeId, HalfEdgeId, LoopId, Orientation, ShellType, Topology, VertexId};
 
@@ -54,6 +55,9 @@ struct StepReader<'a> {
     half_edge_map: HashMap<(u64, bool), HalfEdgeId>,
     /// Maps STEP surface ID to vcad geometry store index.
     surface_map: HashMap<u64, usize>,
+    /// Vertices already added to the current solid, for welding
+    /// near-coincident points that use distinct STEP entities.
+    welded_points: Vec<(Point3, VertexId)>,
 }
 
 impl<'a> StepReader<'a> {
@@ -64,9 +68,26 @@ impl<'a> StepReader<'a> {
             edge_map: HashMap::new(),
             half_edge_map: HashMap::new(),
             surface_map: HashMap::new(),
+            welded_points: Vec::new(),
         }
     }
 
+    /// Add a vertex to the topology, welding it onto an existing vertex if
+    /// one lies within the module geometry tolerance.
+    fn weld_vertex(&mut self, topo: &mut Topology, point: Point3) -> VertexId {
+        let tol = geometry_tolerance();
+        if let Some((_, vid)) = self
+            .welded_points
+            .iter()
+            .find(|(p, _)| (p - point).norm() < tol)
+        {
+            return *vid;
+        }
+        let vid = topo.add_vertex(point);
+        self.welded_points.push((point, vid));
+        vid
+    }
+
     fn read_all_solids(&mut self) -> Result<Vec<BRepSolid>, StepError> {
         let solid_entities = self.file.entities_of_type("MANIFOLD_SOLID_BREP");
         if solid_entities.is_empty() {
@@ -80,6 +101,7 @@ impl<'a> StepReader<'a> {
             self.edge_map.clear();
             self.half_edge_map.clear();
             self.surface_map.clear();
+            self.welded_points.clear();
 
             let solid = self.read_solid(entity.id)?;
             solids.push(solid);
@@ -130,12 +152,12 @@ impl<'a> StepReader<'a> {
                     // Add vertices
                     if !self.vertex_map.contains_key(&edge.start_vertex_id) {
                         let v = parse_vertex_point(self.file, edge.start_vertex_id)?;
-                        let vid = topo.add_vertex(v.point);
+                        let vid = self.weld_vertex(&mut topo, v.point);
                         self.vertex_map.insert(edge.start_vertex_id, vid);
                     }
                     if !self.vertex_map.contains_key(&edge.end_vertex_id) {
                         let v = parse_vertex_point(self.file, edge.end_vertex_id)?;
-                        let vid = topo.add_vertex(v.point);
+                        let vid = self.weld_vertex(&mut topo, v.point);
                         self.vertex_map.insert(edge.end_vertex_id, vid);
                     }
                 }
@@ -4,18 +4,21 @@ use std::collections::HashMap;
 use std::io::Write;
 use std::path::Path;
 
+use crate::entities::curves::write_circle;
 use crate::entities::{
     cylinder_to_placement, plane_to_placement, sphere_to_placement, torus_to_placement,
     write_advanced_face, write_axis2_placement_3d, write_cartesian_point, write_closed_shell,
     write_conical_surface, write_cylindrical_surface, write_direction, write_edge_curve,
-    write_edge_loop, write_face_bound, write_manifold_solid_brep, write_oriented_edge,
-    write_plane, write_spherical_surface, write_toroidal_surface, write_vertex_point,
-    AxisPlacement,
+    write_edge_loop, write_face_bound, write_manifold_solid_brep, write_oriented_edge, write_plane,
+    write_spherical_surface, write_toroidal_surface, write_vertex_point, AxisPlacement,
 };
 use crate::error::StepError;
 
-use vcad_kernel_geom::{ConeSurface, CylinderSurface, Plane, SphereSurface, SurfaceKind, TorusSurface};
-use vcad_kernel_math::{Dir3, Vec3};
+use vcad_kernel_geom::{
+    Circle3d, ConeSurface, CylinderSurface, Plane, SphereSurface, Surface, SurfaceKind,
+    TorusSurface,
+};
+use vcad_kernel_math::{Dir3, Point3, Vec3};
 use vcad_kernel_primitives::BRepSolid;
 use vcad_kernel_topo::{EdgeId, FaceId, HalfEdgeId, LoopId, Orientation, VertexId};
 
@@ -201,9 +204,12 @@ impl<'a> StepWriter<'a> {
                     )
                 }
                 SurfaceKind::Cone => {
-                    let cone = surface.as_any().downcast_ref::<ConeSurface>().ok_or_else(|| {
-                        StepError::InvalidGeometry("failed to downcast Cone surface".into())
-                    })?;
+                    let cone = surface
+                        .as_any()
+                        .downcast_ref::<ConeSurface>()
+                        .ok_or_else(|| {
+                            StepError::InvalidGeometry("failed to downcast Cone surface".into())
+                        })?;
                     let placement = AxisPlacement {
                         location: cone.apex,
                         axis: Some(cone.axis),
@@ -218,16 +224,24 @@ impl<'a> StepWriter<'a> {
                     )
                 }
                 SurfaceKind::Torus => {
-                    let torus = surface
-                        .as_any()
-                        .downcast_ref::<TorusSurface>()
-                        .ok_or_else(|| {
-                            StepError::InvalidGeometry("failed to downcast Torus surface".into())
-                        })?;
+                    let torus =
+                        surface
+                            .as_any()
+                            .downcast_ref::<TorusSurface>()
+                            .ok_or_else(|| {
+                                StepError::InvalidGeometry(
+                                    "failed to downcast Torus surface".into(),
+                                )
+                            })?;
                     let placement_id = self.write_axis_placement(&torus_to_placement(torus))?;
                     (
                         placement_id,
-                        write_toroidal_surface(torus.major_radius, torus.minor_radius, "", placement_id),
+                        write_toroidal_surface(
+                            torus.major_radius,
+                            torus.minor_radius,
+                            "",
+                            placement_id,
+                        ),
                     )
                 }
                 _ => {
@@ -293,43 +307,20 @@ impl<'a> StepWriter<'a> {
             let start_vertex = self.vertex_map[&start_vid];
             let end_vertex = self.vertex_map[&end_vid];
 
-            // Write line geometry for the edge
             let start_point = topo.vertices[start_vid].point;
             let end_point = topo.vertices[end_vid].point;
 
-            let dir_vec = end_point - start_point;
-            let magnitude = dir_vec.norm();
-            let dir = if magnitude > 1e-15 {
-                Dir3::new_normalize(dir_vec)
-            } else {
-                Dir3::new_normalize(Vec3::x())
+            // Edges on a curved surface whose endpoints share a radius and
+            // height are circles (rim edges of cylinders, cones, tori);
+            // everything else is written as a line
+            let curve_id = match self.edge_circle(edge.half_edge, &start_point, &end_point) {
+                Some(circle) => self.write_circle_curve(&circle)?,
+                None => self.write_line_curve(&start_point, &end_point)?,
             };
 
-            // Write point for line origin
-            let line_point_id = self.alloc_id();
-            self.emit(line_point_id, &write_cartesian_point(&start_point, ""));
-
-            // Write direction
-            let dir_id = self.alloc_id();
-            self.emit(dir_id, &write_direction(&dir, ""));
-
-            // Write vector
-            let vec_id = self.alloc_id();
-            self.emit(
-                vec_id,
-                &format!("VECTOR('', #{}, {:.15E})", dir_id, magnitude),
-            );
-
-            // Write line
-            let line_id = self.alloc_id();
-            self.emit(
-                line_id,
-                &format!("LINE('', #{}, #{})", line_point_id, vec_id),
-            );
-
             // Write edge curve
             let step_edge_id = self.alloc_id();
-            let entity = write_edge_curve("", start_vertex, end_vertex, line_id, true);
+            let entity = write_edge_curve("", start_vertex, end_vertex, curve_id, true);
             self.emit(step_edge_id, &entity);
             self.edge_map.insert(edge_id, step_edge_id);
         }
@@ -337,6 +328,120 @@ impl<'a> StepWriter<'a> {
         Ok(())
     }
 
+    /// The circle an edge lies on, if an adjacent surface implies one.
+    ///
+    /// Looks for a cylindrical or conical surface on either side of the
+    /// edge; the edge is circular when it is closed or when both endpoints
+    /// sit at the same radius and height along the surface axis.
+    fn edge_circle(
+        &self,
+        he_id: HalfEdgeId,
+        start_point: &Point3,
+        end_point: &Point3,
+    ) -> Option<Circle3d> {
+        let topo = &self.solid.topology;
+        let geom = &self.solid.geometry;
+
+        let face_surface = |he: HalfEdgeId| -> Option<&dyn Surface> {
+            let l = topo.half_edges[he].loop_id?;
+            let f = topo.loops[l].face?;
+            Some(geom.surfaces[topo.faces[f].surface_index].as_ref())
+        };
+        let twin = topo.half_edges[he_id].twin;
+        let surfaces = [Some(he_id), twin].into_iter().flatten().map(face_surface);
+
+        // Axis origin and direction of an adjacent rotational surface
+        let (origin, axis) = surfaces.flatten().find_map(|s| match s.surface_type() {
+            SurfaceKind::Cylinder => {
+                let cyl = s.as_any().downcast_ref::<CylinderSurface>()?;
+                Some((cyl.center, *cyl.axis.as_ref()))
+            }
+            SurfaceKind::Cone => {
+                let cone = s.as_any().downcast_ref::<ConeSurface>()?;
+                Some((cone.apex, *cone.axis.as_ref()))
+            }
+            _ => None,
+        })?;
+
+        let radial = |p: &Point3| {
+            let d = p - origin;
+            d - d.dot(&axis) * axis
+        };
+        let r0 = radial(start_point);
+        let r1 = radial(end_point);
+        let h0 = (start_point - origin).dot(&axis);
+        let h1 = (end_point - origin).dot(&axis);
+
+        let closed = (end_point - start_point).norm() < 1e-9;
+        let same_ring = (r0.norm() - r1.norm()).abs() < 1e-9 && (h0 - h1).abs() < 1e-9;
+        if r0.norm() < 1e-9 || (!closed && !same_ring) {
+            return None;
+        }
+
+        // Circle through the start point, centered on the axis
+        let x_dir = Dir3::new_normalize(r0);
+        let normal = Dir3::new_normalize(axis);
+        let y_dir = Dir3::new_normalize(normal.cross(&x_dir));
+        Some(Circle3d {
+            center: origin + h0 * axis,
+            radius: r0.norm(),
+            x_dir,
+            y_dir,
+            normal,
+        })
+    }
+
+    /// Write a CIRCLE with its axis placement, returning the curve id.
+    fn write_circle_curve(&mut self, circle: &Circle3d) -> Result<u64, StepError> {
+        let placement = AxisPlacement {
+            location: circle.center,
+            axis: Some(circle.normal),
+            ref_direction: Some(circle.x_dir),
+        };
+        let placement_id = self.write_axis_placement(&placement)?;
+        let circle_id = self.alloc_id();
+        self.emit(circle_id, &write_circle(circle, "", placement_id));
+        Ok(circle_id)
+    }
+
+    /// Write a LINE between two points, returning the curve id.
+    fn write_line_curve(
+        &mut self,
+        start_point: &Point3,
+        end_point: &Point3,
+    ) -> Result<u64, StepError> {
+        let dir_vec = end_point - start_point;
+        let magnitude = dir_vec.norm();
+        let dir = if magnitude > 1e-15 {
+            Dir3::new_normalize(dir_vec)
+        } else {
+            Dir3::new_normalize(Vec3::x())
+        };
+
+        // Write point for line origin
+        let line_point_id = self.alloc_id();
+        self.emit(line_point_id, &write_cartesian_point(start_point, ""));
+
+        // Write direction
+        let dir_id = self.alloc_id();
+        self.emit(dir_id, &write_direction(&dir, ""));
+
+        // Write vector
+        let vec_id = self.alloc_id();
+        self.emit(
+            vec_id,
+            &format!("VECTOR('', #{}, {:.15E})", dir_id, magnitude),
+        );
+
+        // Write line
+        let line_id = self.alloc_id();
+        self.emit(
+            line_id,
+            &format!("LINE('', #{}, #{})", line_point_id, vec_id),
+        );
+        Ok(line_id)
+    }
+
     fn write_loops(&mut self) -> Result<(), StepError> {
         let topo = &self.solid.topology;
 
@@ -443,7 +548,7 @@ fn chrono_lite_date() -> String {
 mod tests {
     use super::*;
     use crate::reader::read_step_from_buffer;
-    use vcad_kernel_primitives::make_cube;
+    use vcad_kernel_primitives::{make_cube, make_cylinder};
 
     #[test]
     fn test_write_cube() {
@@ -488,4 +593,29 @@ mod tests {
             imported.geometry.surfaces.len()
         );
     }
+
+    #[test]
+    fn test_roundtrip_cylinder_exact_geometry() {
+        let original = make_cylinder(5.0, 10.0, 32);
+
+        let buffer = write_step_to_buffer(&original).unwrap();
+        let content = String::from_utf8_lossy(&buffer);
+
+        // Lateral surface and rim edges are exact, not faceted
+        assert!(content.contains("CYLINDRICAL_SURFACE"));
+        assert!(content.contains("CIRCLE"));
+
+        let solids = read_step_from_buffer(&buffer).unwrap();
+        assert_eq!(solids.len(), 1);
+        let imported = &solids[0];
+
+        // The re-imported lateral face is still a true cylinder
+        let cylinder = imported
+            .geometry
+            .surfaces
+            .iter()
+            .find_map(|s| s.as_any().downcast_ref::<CylinderSurface>())
+            .expect("re-imported solid should have a cylindrical surface");
+        assert!((cylinder.radius - 5.0).abs() < 1e-9);
+    }
 }
@@ -180,6 +180,89 @@ impl FrenetFrame {
     }
 }
 
+/// How profile frames are oriented along a sweep path.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FrameMode {
+    /// Classic Frenet frames: the normal follows the curvature center.
+    ///
+    /// Flips at inflection points, which can introduce sudden twists.
+    Frenet,
+    /// Rotation-minimizing (parallel transport) frames.
+    ///
+    /// Propagates the normal along the path to avoid the twist that Frenet
+    /// frames introduce. This is the default.
+    #[default]
+    ParallelTransport,
+    /// Lock the profile's up direction (binormal) to a fixed world vector.
+    ///
+    /// The up vector is projected perpendicular to the tangent at each
+    /// station. Degenerates where the tangent is parallel to the up vector.
+    FixedUp(Vec3),
+}
+
+/// Compute a sequence of frames along a curve using the given frame mode.
+///
+/// Returns `n_samples` frames evenly spaced in the curve's parameter domain.
+pub fn frames_for_mode(curve: &dyn Curve3d, n_samples: usize, mode: FrameMode) -> Vec<FrenetFrame> {
+    match mode {
+        FrameMode::ParallelTransport => rotation_minimizing_frames(curve, n_samples),
+        FrameMode::Frenet => {
+            if n_samples < 2 {
+                return vec![];
+            }
+            let (t_min, t_max) = curve.domain();
+            let dt = (t_max - t_min) / (n_samples - 1) as f64;
+            (0..n_samples)
+                .map(|i| FrenetFrame::from_curve(curve, t_min + i as f64 * dt))
+                .collect()
+        }
+        FrameMode::FixedUp(up) => fixed_up_frames(curve, n_samples, up),
+    }
+}
+
+/// Compute frames whose binormal (profile up) is locked to a world vector.
+fn fixed_up_frames(curve: &dyn Curve3d, n_samples: usize, up: Vec3) -> Vec<FrenetFrame> {
+    if n_samples < 2 {
+        return vec![];
+    }
+
+    let (t_min, t_max) = curve.domain();
+    let dt = (t_max - t_min) / (n_samples - 1) as f64;
+
+    let mut frames = Vec::with_capacity(n_samples);
+    for i in 0..n_samples {
+        let t = t_min + i as f64 * dt;
+        let position = curve.evaluate(t);
+
+        let tangent_vec = curve.tangent(t);
+        if tangent_vec.norm() < 1e-12 {
+            frames.push(FrenetFrame::default_at(position));
+            continue;
+        }
+        let tangent = Dir3::new_normalize(tangent_vec);
+
+        // Project the up vector perpendicular to the tangent
+        let up_perp = up - up.dot(tangent.as_ref()) * tangent.as_ref();
+        if up_perp.norm() < 1e-12 {
+            // Tangent parallel to up - fall back to an arbitrary normal
+            frames.push(FrenetFrame::with_arbitrary_normal(position, tangent));
+            continue;
+        }
+
+        let binormal = Dir3::new_normalize(up_perp);
+        let normal = Dir3::new_normalize(binormal.as_ref().cross(tangent.as_ref()));
+
+        frames.push(FrenetFrame {
+            position,
+            tangent,
+            normal,
+            binormal,
+        });
+    }
+
+    frames
+}
+
 /// Compute a sequence of rotation-minimizing frames along a curve.
 ///
 /// This produces smoother results than independent Frenet frames by
@@ -28,7 +28,7 @@ mod frenet;
 mod loft;
 mod sweep;
 
-pub use frenet::FrenetFrame;
+pub use frenet::{frames_for_mode, FrameMode, FrenetFrame};
 pub use loft::{loft, LoftMode, LoftOptions};
 pub use sweep::{sweep, Helix, SweepOptions};
 
@@ -9,7 +9,7 @@ use vcad_kernel_primitives::BRepSolid;
 use vcad_kernel_sketch::SketchProfile;
 use vcad_kernel_topo::{HalfEdgeId, Orientation, ShellType, Topology, VertexId};
 
-use crate::frenet::rotation_minimizing_frames;
+use crate::frenet::{frames_for_mode, FrameMode};
 use crate::SweepError;
 
 /// Options for the sweep operation.
@@ -27,6 +27,8 @@ pub struct SweepOptions {
     pub arc_segments: u32,
     /// Initial profile rotation around the path tangent (radians). Default: 0.0
     pub orientation_angle: f64,
+    /// How the profile is oriented along the path. Default: parallel transport.
+    pub frame_mode: FrameMode,
 }
 
 impl Default for SweepOptions {
@@ -38,6 +40,7 @@ impl Default for SweepOptions {
             scale_end: 1.0,
             arc_segments: 8,
             orientation_angle: 0.0,
+            frame_mode: FrameMode::default(),
         }
     }
 }
@@ -90,8 +93,8 @@ pub fn sweep(
     let n_profile_verts = tessellated_profile.segments.len();
     let n_path_samples = n_path_segments + 1; // number of profile copies
 
-    // Compute rotation-minimizing frames along the path
-    let mut frames = rotation_minimizing_frames(path, n_path_samples);
+    // Compute frames along the path using the requested orientation mode
+    let mut frames = frames_for_mode(path, n_path_samples, options.frame_mode);
     if frames.len() < 2 {
         return Err(SweepError::ZeroLengthPath);
     }
@@ -365,20 +368,26 @@ fn estimate_path_length(path: &dyn Curve3d) -> f64 {
 ///
 /// The helix is parameterized as:
 /// ```text
-/// x(t) = radius * cos(2π * turns * t)
-/// y(t) = radius * sin(2π * turns * t)
-/// z(t) = pitch * turns * t
+/// x(t) = r(t) * cos(2π * turns * t)
+/// y(t) = r(t) * sin(2π * turns * t)
+/// z(t) = height * h(t)
 /// ```
 ///
-/// Where `t ∈ [0, 1]`.
+/// Where `t ∈ [0, 1]`, `r(t)` interpolates linearly from `radius` to
+/// `radius_end`, and `h(t)` climbs proportionally to the interpolated pitch
+/// (identically `t` for constant pitch). See [`Helix::tapered`].
 #[derive(Debug, Clone)]
 pub struct Helix {
     /// Center of the helix at the base.
     pub center: Point3,
-    /// Radius of the helix.
+    /// Radius of the helix at the base.
     pub radius: f64,
-    /// Pitch (height per turn).
+    /// Radius of the helix at the top.
+    pub radius_end: f64,
+    /// Pitch (height per turn) at the base.
     pub pitch: f64,
+    /// Pitch (height per turn) at the top.
+    pub pitch_end: f64,
     /// Total height of the helix.
     pub height: f64,
     /// Number of turns.
@@ -398,7 +407,35 @@ impl Helix {
         Self {
             center: Point3::origin(),
             radius,
+            radius_end: radius,
             pitch,
+            pitch_end: pitch,
+            height,
+            turns,
+        }
+    }
+
+    /// Create a tapered helix with radius and pitch interpolated along the height.
+    ///
+    /// Used for tapered pipe threads and conical springs. Radius varies
+    /// linearly from `radius_start` at the base to `radius_end` at the top;
+    /// pitch varies linearly as well, so the axial advance per turn changes
+    /// along the helix (the total height is fixed by `height`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn tapered(
+        radius_start: f64,
+        radius_end: f64,
+        pitch_start: f64,
+        pitch_end: f64,
+        height: f64,
+        turns: f64,
+    ) -> Self {
+        Self {
+            center: Point3::origin(),
+            radius: radius_start,
+            radius_end,
+            pitch: pitch_start,
+            pitch_end,
             height,
             turns,
         }
@@ -409,15 +446,44 @@ impl Helix {
         self.center = center;
         self
     }
+
+    /// Radius at parameter `t`, linearly interpolated base → top.
+    fn radius_at(&self, t: f64) -> f64 {
+        self.radius + t * (self.radius_end - self.radius)
+    }
+
+    /// Fraction of the total height climbed at parameter `t`.
+    ///
+    /// For constant pitch this is simply `t`. For variable pitch it is the
+    /// normalized integral of the interpolated pitch, so the local climb rate
+    /// is proportional to the pitch at each station while the total height
+    /// remains `height`.
+    fn height_fraction(&self, t: f64) -> f64 {
+        let sum = self.pitch + self.pitch_end;
+        if sum.abs() < 1e-12 {
+            return t;
+        }
+        (self.pitch * t + 0.5 * (self.pitch_end - self.pitch) * t * t) / (0.5 * sum)
+    }
+
+    /// Derivative of [`Self::height_fraction`] with respect to `t`.
+    fn height_fraction_deriv(&self, t: f64) -> f64 {
+        let sum = self.pitch + self.pitch_end;
+        if sum.abs() < 1e-12 {
+            return 1.0;
+        }
+        (self.pitch + t * (self.pitch_end - self.pitch)) / (0.5 * sum)
+    }
 }
 
 impl Curve3d for Helix {
     fn evaluate(&self, t: f64) -> Point3 {
         let angle = 2.0 * PI * self.turns * t;
-        let z = self.height * t;
+        let r = self.radius_at(t);
+        let z = self.height * self.height_fraction(t);
         Point3::new(
-            self.center.x + self.radius * angle.cos(),
-            self.center.y + self.radius * angle.sin(),
+            self.center.x + r * angle.cos(),
+            self.center.y + r * angle.sin(),
             self.center.z + z,
         )
     }
@@ -425,11 +491,13 @@ impl Curve3d for Helix {
     fn tangent(&self, t: f64) -> Vec3 {
         let angle = 2.0 * PI * self.turns * t;
         let d_angle = 2.0 * PI * self.turns;
+        let r = self.radius_at(t);
+        let dr = self.radius_end - self.radius;
 
         Vec3::new(
-            -self.radius * d_angle * angle.sin(),
-            self.radius * d_angle * angle.cos(),
-            self.height,
+            dr * angle.cos() - r * d_angle * angle.sin(),
+            dr * angle.sin() + r * d_angle * angle.cos(),
+            self.height * self.height_fraction_deriv(t),
         )
     }
 
@@ -561,6 +629,71 @@ mod tests {
         assert_eq!(unpaired, 0, "expected no unpaired half-edges");
     }
 
+    /// An S-shaped curve with an inflection point at t=0.5.
+    #[derive(Debug, Clone)]
+    struct SCurve;
+
+    impl Curve3d for SCurve {
+        fn evaluate(&self, t: f64) -> Point3 {
+            Point3::new(20.0 * t, 5.0 * (2.0 * PI * t).sin(), 0.0)
+        }
+
+        fn tangent(&self, t: f64) -> Vec3 {
+            Vec3::new(20.0, 10.0 * PI * (2.0 * PI * t).cos(), 0.0)
+        }
+
+        fn domain(&self) -> (f64, f64) {
+            (0.0, 1.0)
+        }
+
+        fn curve_type(&self) -> CurveKind {
+            CurveKind::Line
+        }
+
+        fn clone_box(&self) -> Box<dyn Curve3d> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn test_sweep_s_curve_frame_modes() {
+        let profile = create_circle_profile(1.0, 8);
+
+        for mode in [
+            FrameMode::Frenet,
+            FrameMode::ParallelTransport,
+            FrameMode::FixedUp(Vec3::z()),
+        ] {
+            let options = SweepOptions {
+                frame_mode: mode,
+                path_segments: 32,
+                ..Default::default()
+            };
+            let solid = sweep(&profile, &SCurve, options).unwrap();
+            assert!(
+                !solid.topology.faces.is_empty(),
+                "sweep failed for mode {mode:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fixed_up_keeps_up_constant() {
+        // FixedUp must hold the profile's up vector (binormal) constant at
+        // every station, even across the S-curve's inflection point.
+        let up = Vec3::z();
+        let frames = crate::frames_for_mode(&SCurve, 33, FrameMode::FixedUp(up));
+        assert_eq!(frames.len(), 33);
+
+        for (i, frame) in frames.iter().enumerate() {
+            let dot = frame.binormal.as_ref().dot(&up);
+            assert!(
+                (dot - 1.0).abs() < 1e-9,
+                "binormal deviated from up at station {i}: dot = {dot}"
+            );
+        }
+    }
+
     #[test]
     fn test_sweep_zero_length_path_error() {
         let profile = create_rectangle_profile();
@@ -587,6 +720,57 @@ mod tests {
         assert!((p1.z - 10.0).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_tapered_helix_evaluate() {
+        let helix = Helix::tapered(10.0, 5.0, 4.0, 2.0, 20.0, 3.0);
+
+        // Base: radius 10 at z=0
+        let p0 = helix.evaluate(0.0);
+        assert!(((p0.x * p0.x + p0.y * p0.y).sqrt() - 10.0).abs() < 1e-9);
+        assert!(p0.z.abs() < 1e-9);
+
+        // Top: radius 5 at z=height
+        let p1 = helix.evaluate(1.0);
+        assert!(((p1.x * p1.x + p1.y * p1.y).sqrt() - 5.0).abs() < 1e-9);
+        assert!((p1.z - 20.0).abs() < 1e-9);
+
+        // Pitch tapers from 4 to 2, so the first half-turn climbs faster
+        // (proportionally) than the last
+        let early = helix.evaluate(0.25).z;
+        let late = helix.evaluate(1.0).z - helix.evaluate(0.75).z;
+        assert!(
+            early > late,
+            "expected faster climb early: {early} vs {late}"
+        );
+    }
+
+    #[test]
+    fn test_sweep_tapered_helix() {
+        let profile = create_circle_profile(0.5, 8);
+        let helix = Helix::tapered(10.0, 4.0, 5.0, 5.0, 20.0, 3.0);
+
+        let solid = sweep(&profile, &helix, SweepOptions::default()).unwrap();
+
+        // Measure the max radial extent of vertices near the bottom and top
+        let mut bottom_r: f64 = 0.0;
+        let mut top_r: f64 = 0.0;
+        for v in solid.topology.vertices.values() {
+            let p = v.point;
+            let r = (p.x * p.x + p.y * p.y).sqrt();
+            if p.z < 2.0 {
+                bottom_r = bottom_r.max(r);
+            } else if p.z > 18.0 {
+                top_r = top_r.max(r);
+            }
+        }
+
+        // Bottom ring sits near radius 10, top ring near radius 4
+        assert!(
+            bottom_r > top_r + 3.0,
+            "expected tapered radius: bottom {bottom_r}, top {top_r}"
+        );
+    }
+
     #[test]
     fn test_sweep_volume_straight() {
         // Sweep a 4x2 rectangle along 10 units should give volume ~80
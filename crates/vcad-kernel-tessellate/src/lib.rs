@@ -8,6 +8,7 @@
 //! 3. Triangulating via ear-clipping
 //! 4. Mapping back to 3D via surface evaluation
 
+use std::collections::HashMap;
 use std::f64::consts::PI;
 use vcad_kernel_geom::{BilinearSurface, GeometryStore, Surface, SurfaceKind};
 use vcad_kernel_math::{Point2, Point3, Vec3};
@@ -67,6 +68,445 @@ impl TriangleMesh {
         self.indices
             .extend(other.indices.iter().map(|&i| i + offset));
     }
+
+    /// Split the mesh into connected components (separate bodies).
+    ///
+    /// Triangles are considered connected when they share a vertex position
+    /// (welded by quantized coordinates, so duplicated vertices along face
+    /// boundaries still connect). Returns one mesh per component; an empty
+    /// mesh yields an empty vec.
+    pub fn connected_components(&self) -> Vec<TriangleMesh> {
+        use std::collections::HashMap;
+
+        let num_verts = self.num_vertices();
+        if num_verts == 0 || self.indices.is_empty() {
+            return Vec::new();
+        }
+
+        // Union-find over vertices, welding duplicates by quantized position
+        let mut parent: Vec<usize> = (0..num_verts).collect();
+        fn find(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        fn union(parent: &mut [usize], a: usize, b: usize) {
+            let ra = find(parent, a);
+            let rb = find(parent, b);
+            if ra != rb {
+                parent[ra] = rb;
+            }
+        }
+
+        let quantize = |i: usize| -> [i64; 3] {
+            [
+                (self.vertices[i * 3] as f64 * 1e6).round() as i64,
+                (self.vertices[i * 3 + 1] as f64 * 1e6).round() as i64,
+                (self.vertices[i * 3 + 2] as f64 * 1e6).round() as i64,
+            ]
+        };
+
+        let mut by_position: HashMap<[i64; 3], usize> = HashMap::new();
+        for i in 0..num_verts {
+            match by_position.entry(quantize(i)) {
+                std::collections::hash_map::Entry::Occupied(e) => {
+                    union(&mut parent, i, *e.get());
+                }
+                std::collections::hash_map::Entry::Vacant(e) => {
+                    e.insert(i);
+                }
+            }
+        }
+
+        for tri in self.indices.chunks(3) {
+            union(&mut parent, tri[0] as usize, tri[1] as usize);
+            union(&mut parent, tri[1] as usize, tri[2] as usize);
+        }
+
+        // Group triangles by component root
+        let mut component_of_root: HashMap<usize, usize> = HashMap::new();
+        let mut components: Vec<TriangleMesh> = Vec::new();
+        let mut vertex_maps: Vec<HashMap<u32, u32>> = Vec::new();
+
+        for tri in self.indices.chunks(3) {
+            let root = find(&mut parent, tri[0] as usize);
+            let comp_idx = *component_of_root.entry(root).or_insert_with(|| {
+                components.push(TriangleMesh::new());
+                vertex_maps.push(HashMap::new());
+                components.len() - 1
+            });
+
+            let mesh = &mut components[comp_idx];
+            let map = &mut vertex_maps[comp_idx];
+            for &idx in tri {
+                let new_idx = *map.entry(idx).or_insert_with(|| {
+                    let i = idx as usize * 3;
+                    mesh.vertices.extend_from_slice(&self.vertices[i..i + 3]);
+                    if self.normals.len() >= i + 3 {
+                        mesh.normals.extend_from_slice(&self.normals[i..i + 3]);
+                    }
+                    (mesh.vertices.len() / 3 - 1) as u32
+                });
+                mesh.indices.push(new_idx);
+            }
+        }
+
+        components
+    }
+
+    /// Collapse vertices whose positions are within `tol` of each other,
+    /// remapping triangle indices and dropping the duplicated entries.
+    ///
+    /// Per-face tessellation leaves every shared edge with duplicated
+    /// positions; welding restores shared vertices so vertex counts, smooth
+    /// normals, and edge-based integrity checks behave. Normals of welded
+    /// vertices are averaged (and renormalized) when the mesh carries them.
+    ///
+    /// Uses a spatial hash on `tol`-quantized coordinates, probing the
+    /// neighbouring cells so near-misses across a cell boundary still weld.
+    pub fn weld(&mut self, tol: f64) {
+        let num_verts = self.num_vertices();
+        if num_verts == 0 {
+            return;
+        }
+        let has_normals = self.normals.len() == self.vertices.len();
+        let cell = tol.max(1e-12);
+        let position = |i: usize| -> [f64; 3] {
+            [
+                self.vertices[i * 3] as f64,
+                self.vertices[i * 3 + 1] as f64,
+                self.vertices[i * 3 + 2] as f64,
+            ]
+        };
+        let key_of = |p: &[f64; 3]| -> [i64; 3] {
+            [
+                (p[0] / cell).round() as i64,
+                (p[1] / cell).round() as i64,
+                (p[2] / cell).round() as i64,
+            ]
+        };
+
+        // Spatial hash: representative vertex per occupied cell. Probe the
+        // 27 surrounding cells so two points within tol never miss each
+        // other just because they straddle a cell boundary
+        let mut cells: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+        let mut remap: Vec<u32> = Vec::with_capacity(num_verts);
+        let mut new_vertices: Vec<f32> = Vec::new();
+        let mut new_normals: Vec<f32> = Vec::new();
+
+        for i in 0..num_verts {
+            let p = position(i);
+            let key = key_of(&p);
+            let mut found = None;
+            'probe: for dx in -1..=1 {
+                for dy in -1..=1 {
+                    for dz in -1..=1 {
+                        let probe = [key[0] + dx, key[1] + dy, key[2] + dz];
+                        if let Some(reps) = cells.get(&probe) {
+                            for &rep in reps {
+                                let q = [
+                                    new_vertices[rep * 3] as f64,
+                                    new_vertices[rep * 3 + 1] as f64,
+                                    new_vertices[rep * 3 + 2] as f64,
+                                ];
+                                let d2 = (p[0] - q[0]).powi(2)
+                                    + (p[1] - q[1]).powi(2)
+                                    + (p[2] - q[2]).powi(2);
+                                if d2 <= tol * tol {
+                                    found = Some(rep);
+                                    break 'probe;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            match found {
+                Some(rep) => {
+                    remap.push(rep as u32);
+                    if has_normals {
+                        for k in 0..3 {
+                            new_normals[rep * 3 + k] += self.normals[i * 3 + k];
+                        }
+                    }
+                }
+                None => {
+                    let rep = new_vertices.len() / 3;
+                    new_vertices.extend_from_slice(&self.vertices[i * 3..i * 3 + 3]);
+                    if has_normals {
+                        new_normals.extend_from_slice(&self.normals[i * 3..i * 3 + 3]);
+                    }
+                    cells.entry(key).or_default().push(rep);
+                    remap.push(rep as u32);
+                }
+            }
+        }
+
+        // Renormalize the accumulated normals
+        if has_normals {
+            for rep in 0..new_vertices.len() / 3 {
+                let n = &mut new_normals[rep * 3..rep * 3 + 3];
+                let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+                if len > 1e-12 {
+                    n[0] /= len;
+                    n[1] /= len;
+                    n[2] /= len;
+                }
+            }
+        }
+
+        for idx in &mut self.indices {
+            *idx = remap[*idx as usize];
+        }
+        self.vertices = new_vertices;
+        if has_normals {
+            self.normals = new_normals;
+        }
+    }
+
+    /// Count how many triangles use each undirected edge, welding vertices
+    /// by quantized position so duplicated boundary vertices share edges.
+    fn edge_uses(&self) -> HashMap<(usize, usize), (u32, u32)> {
+        let quantize = |i: usize| -> [i64; 3] {
+            [
+                (self.vertices[i * 3] as f64 * 1e6).round() as i64,
+                (self.vertices[i * 3 + 1] as f64 * 1e6).round() as i64,
+                (self.vertices[i * 3 + 2] as f64 * 1e6).round() as i64,
+            ]
+        };
+
+        let mut canonical: HashMap<[i64; 3], usize> = HashMap::new();
+        let mut canon_of: Vec<usize> = Vec::with_capacity(self.num_vertices());
+        for i in 0..self.num_vertices() {
+            let c = *canonical.entry(quantize(i)).or_insert(i);
+            canon_of.push(c);
+        }
+
+        // For each undirected edge, count uses in (forward, backward) order
+        let mut uses: HashMap<(usize, usize), (u32, u32)> = HashMap::new();
+        for tri in self.indices.chunks(3) {
+            for k in 0..3 {
+                let a = canon_of[tri[k] as usize];
+                let b = canon_of[tri[(k + 1) % 3] as usize];
+                if a == b {
+                    continue; // degenerate edge
+                }
+                let entry = uses.entry((a.min(b), a.max(b))).or_insert((0, 0));
+                if a < b {
+                    entry.0 += 1;
+                } else {
+                    entry.1 += 1;
+                }
+            }
+        }
+        uses
+    }
+
+    /// Whether the mesh is two-manifold: every edge is used by at most two
+    /// triangles, with opposite orientations when shared.
+    ///
+    /// Boundary edges (used once) are allowed, so an open surface can still
+    /// be manifold — use [`TriangleMesh::is_closed`] to also require
+    /// watertightness.
+    pub fn is_manifold(&self) -> bool {
+        self.edge_uses()
+            .values()
+            .all(|&(fwd, bwd)| fwd <= 1 && bwd <= 1)
+    }
+
+    /// Whether the mesh is closed (watertight): every edge is shared by
+    /// exactly two triangles with opposite orientations.
+    pub fn is_closed(&self) -> bool {
+        self.edge_uses()
+            .values()
+            .all(|&(fwd, bwd)| fwd == 1 && bwd == 1)
+    }
+
+    /// Whether the mesh is watertight: no edge is used an odd number of
+    /// times.
+    ///
+    /// This is a parity check — a crack shows up as edges with a single
+    /// use. Unlike [`TriangleMesh::is_closed`] it does not require
+    /// consistent winding, so it stays useful on meshes that have not been
+    /// through [`TriangleMesh::fix_winding`]. A one-call integrity check
+    /// before STEP/STL export.
+    pub fn is_watertight(&self) -> bool {
+        self.boundary_edges().is_empty()
+    }
+
+    /// Edges used an odd number of times, as sorted pairs of vertex
+    /// indices.
+    ///
+    /// Vertices are welded by quantized position first (the same scheme as
+    /// the other edge queries), so seam-duplicated vertices along face
+    /// boundaries don't report false cracks. Indices refer to the welded
+    /// representative of each position. An empty result means the mesh is
+    /// watertight.
+    pub fn boundary_edges(&self) -> Vec<(u32, u32)> {
+        let mut edges: Vec<(u32, u32)> = self
+            .edge_uses()
+            .iter()
+            .filter(|(_, &(fwd, bwd))| (fwd + bwd) % 2 == 1)
+            .map(|(&(a, b), _)| (a as u32, b as u32))
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    /// Reorient triangles so each connected component is consistently wound
+    /// and faces outward, then recompute vertex normals from the corrected
+    /// winding.
+    ///
+    /// Orientation is propagated across shared edges (welded by quantized
+    /// position), so neighbouring triangles always end up agreeing; a whole
+    /// component is then flipped if its signed volume is negative. Normals
+    /// are rebuilt as area-weighted averages of incident triangle normals so
+    /// they match the new winding.
+    pub fn fix_winding(&mut self) {
+        let num_tris = self.num_triangles();
+        if num_tris == 0 {
+            return;
+        }
+
+        // Weld vertices by quantized position so seam-duplicated vertices
+        // share edges (same scheme as `edge_uses`)
+        let quantize = |i: usize| -> [i64; 3] {
+            [
+                (self.vertices[i * 3] as f64 * 1e6).round() as i64,
+                (self.vertices[i * 3 + 1] as f64 * 1e6).round() as i64,
+                (self.vertices[i * 3 + 2] as f64 * 1e6).round() as i64,
+            ]
+        };
+        let mut canonical: HashMap<[i64; 3], usize> = HashMap::new();
+        let mut canon_of: Vec<usize> = Vec::with_capacity(self.num_vertices());
+        for i in 0..self.num_vertices() {
+            let c = *canonical.entry(quantize(i)).or_insert(i);
+            canon_of.push(c);
+        }
+
+        // For each undirected edge, which triangles use it and in which
+        // direction (true = min→max order)
+        let mut edge_tris: HashMap<(usize, usize), Vec<(usize, bool)>> = HashMap::new();
+        for (t, tri) in self.indices.chunks(3).enumerate() {
+            for k in 0..3 {
+                let a = canon_of[tri[k] as usize];
+                let b = canon_of[tri[(k + 1) % 3] as usize];
+                if a == b {
+                    continue;
+                }
+                edge_tris
+                    .entry((a.min(b), a.max(b)))
+                    .or_default()
+                    .push((t, a < b));
+            }
+        }
+
+        // Propagate orientation per connected component: a neighbour using a
+        // shared edge in the SAME direction disagrees and must flip relative
+        // to the current triangle
+        let mut flip = vec![false; num_tris];
+        let mut visited = vec![false; num_tris];
+        for start in 0..num_tris {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut component = vec![start];
+            let mut queue = vec![start];
+            while let Some(t) = queue.pop() {
+                let tri = &self.indices[t * 3..t * 3 + 3];
+                for k in 0..3 {
+                    let a = canon_of[tri[k] as usize];
+                    let b = canon_of[tri[(k + 1) % 3] as usize];
+                    if a == b {
+                        continue;
+                    }
+                    let users = &edge_tris[&(a.min(b), a.max(b))];
+                    // Only propagate across manifold edges; non-manifold
+                    // junctions have no well-defined shared orientation
+                    if users.len() != 2 {
+                        continue;
+                    }
+                    let dir_here = (a < b) != flip[t];
+                    for &(n, dir_n) in users {
+                        if n == t || visited[n] {
+                            continue;
+                        }
+                        visited[n] = true;
+                        flip[n] = dir_n == dir_here;
+                        component.push(n);
+                        queue.push(n);
+                    }
+                }
+            }
+
+            // Flip the whole component outward if its signed volume is
+            // negative with the propagated orientation
+            let mut volume = 0.0f64;
+            for &t in &component {
+                let corner = |idx: u32| -> Vec3 {
+                    let i = idx as usize * 3;
+                    Vec3::new(
+                        self.vertices[i] as f64,
+                        self.vertices[i + 1] as f64,
+                        self.vertices[i + 2] as f64,
+                    )
+                };
+                let tri = &self.indices[t * 3..t * 3 + 3];
+                let (v0, mut v1, mut v2) = (corner(tri[0]), corner(tri[1]), corner(tri[2]));
+                if flip[t] {
+                    std::mem::swap(&mut v1, &mut v2);
+                }
+                volume += v0.dot(&v1.cross(&v2)) / 6.0;
+            }
+            if volume < 0.0 {
+                for &t in &component {
+                    flip[t] = !flip[t];
+                }
+            }
+        }
+
+        for (t, &f) in flip.iter().enumerate() {
+            if f {
+                self.indices.swap(t * 3 + 1, t * 3 + 2);
+            }
+        }
+
+        // Rebuild vertex normals from the corrected winding (area-weighted,
+        // accumulated over welded duplicates so seams stay smooth)
+        let mut accum = vec![0.0f64; self.vertices.len()];
+        for tri in self.indices.chunks(3) {
+            let corner = |idx: u32| -> Vec3 {
+                let i = idx as usize * 3;
+                Vec3::new(
+                    self.vertices[i] as f64,
+                    self.vertices[i + 1] as f64,
+                    self.vertices[i + 2] as f64,
+                )
+            };
+            let (v0, v1, v2) = (corner(tri[0]), corner(tri[1]), corner(tri[2]));
+            let n = (v1 - v0).cross(&(v2 - v0));
+            for &idx in tri {
+                let c = canon_of[idx as usize] * 3;
+                accum[c] += n.x;
+                accum[c + 1] += n.y;
+                accum[c + 2] += n.z;
+            }
+        }
+        self.normals.resize(self.vertices.len(), 0.0);
+        for (i, &canon) in canon_of.iter().enumerate() {
+            let c = canon * 3;
+            let n = Vec3::new(accum[c], accum[c + 1], accum[c + 2]);
+            let len = n.norm();
+            let n = if len > 1e-12 { n / len } else { Vec3::z() };
+            self.normals[i * 3] = n.x as f32;
+            self.normals[i * 3 + 1] = n.y as f32;
+            self.normals[i * 3 + 2] = n.z as f32;
+        }
+    }
 }
 
 impl Default for TriangleMesh {
@@ -84,6 +524,16 @@ pub struct TessellationParams {
     pub height_segments: u32,
     /// Number of latitude bands for spherical features.
     pub latitude_segments: u32,
+    /// When set, the polar caps of lat/long sphere tessellation are fanned
+    /// with this many longitude segments instead of `circle_segments`,
+    /// trading pole detail for wider triangles (no slivers). `None` keeps
+    /// full resolution at the poles.
+    pub pole_fan_segments: Option<u32>,
+    /// When set, curved surfaces pick their segment count per face from
+    /// the surface radius so the chord deviation (sag) stays under this
+    /// bound, instead of using the fixed counts above. A tiny fillet then
+    /// gets few segments while a huge cylinder gets many.
+    pub max_sag: Option<f64>,
 }
 
 impl Default for TessellationParams {
@@ -92,6 +542,8 @@ impl Default for TessellationParams {
             circle_segments: 32,
             height_segments: 1,
             latitude_segments: 16,
+            pole_fan_segments: None,
+            max_sag: None,
         }
     }
 }
@@ -103,18 +555,66 @@ impl TessellationParams {
             circle_segments: segments.max(3),
             height_segments: 1,
             latitude_segments: (segments / 2).max(4),
+            pole_fan_segments: None,
+            max_sag: None,
+        }
+    }
+
+    /// Create params that tessellate adaptively to a chord (sag) tolerance.
+    ///
+    /// The fixed counts remain as fallbacks for surfaces without a
+    /// meaningful radius (planes, B-splines).
+    pub fn from_sag(max_sag: f64) -> Self {
+        Self {
+            max_sag: Some(max_sag.max(1e-9)),
+            ..Self::default()
         }
     }
+
+    /// Segment count for a circular feature of the given radius.
+    ///
+    /// With `max_sag` set this is the smallest count keeping the chord
+    /// deviation under the bound (`segments ≈ π / acos(1 - sag/r)`),
+    /// clamped to a sane range; otherwise the fixed `circle_segments`.
+    pub fn segments_for_radius(&self, radius: f64) -> u32 {
+        match self.max_sag {
+            Some(sag) if radius > sag => {
+                let per_segment = (1.0 - sag / radius).acos();
+                ((PI / per_segment).ceil() as u32).clamp(6, 4096)
+            }
+            Some(_) => 6,
+            None => self.circle_segments,
+        }
+    }
+}
+
+/// Collect the faces of a solid's outer shell followed by any void shells,
+/// so cavities left by fully-enclosed boolean tools are rendered too.
+fn solid_shell_faces(brep: &BRepSolid) -> Vec<FaceId> {
+    let solid = &brep.topology.solids[brep.solid_id];
+    let mut faces = brep.topology.shells[solid.outer_shell].faces.clone();
+    for &shell_id in &solid.void_shells {
+        faces.extend(&brep.topology.shells[shell_id].faces);
+    }
+    faces
 }
 
 /// Tessellate an entire B-rep solid into a triangle mesh.
-pub fn tessellate_solid(brep: &BRepSolid, params: &TessellationParams) -> TriangleMesh {
+///
+/// When `overrides` is provided, faces listed in the map are tessellated with
+/// their own parameters instead of `params`, so callers can refine specific
+/// faces (e.g. a small fillet) without paying for detail everywhere.
+pub fn tessellate_solid(
+    brep: &BRepSolid,
+    params: &TessellationParams,
+    overrides: Option<&HashMap<FaceId, TessellationParams>>,
+) -> TriangleMesh {
     let mut mesh = TriangleMesh::new();
-    let solid = &brep.topology.solids[brep.solid_id];
-    let shell = &brep.topology.shells[solid.outer_shell];
+    let faces = solid_shell_faces(brep);
 
-    for &face_id in &shell.faces {
-        let face_mesh = tessellate_face(&brep.topology, &brep.geometry, face_id, params);
+    for &face_id in &faces {
+        let face_params = overrides.and_then(|m| m.get(&face_id)).unwrap_or(params);
+        let face_mesh = tessellate_face(&brep.topology, &brep.geometry, face_id, face_params);
 
         // Validate face mesh before merge
         #[cfg(debug_assertions)]
@@ -153,8 +653,148 @@ pub fn tessellate_solid(brep: &BRepSolid, params: &TessellationParams) -> Triang
     mesh
 }
 
+/// Tessellate a B-rep solid with per-face parameter overrides.
+///
+/// Convenience wrapper over [`tessellate_solid`] for callers that always have
+/// an override map in hand — e.g. CAM or preview tools that refine one
+/// critical curved face while keeping the rest of the model coarse.
+pub fn tessellate_solid_with_overrides(
+    brep: &BRepSolid,
+    default: &TessellationParams,
+    overrides: &HashMap<FaceId, TessellationParams>,
+) -> TriangleMesh {
+    tessellate_solid(brep, default, Some(overrides))
+}
+
+/// Tessellate a B-rep solid, also reporting which face produced each triangle.
+///
+/// Returns the mesh plus one tag per triangle: the index of the originating
+/// face in shell order (the same order [`tessellate_solid`] merges faces).
+/// Consumers that must respect feature edges — e.g. a decimator that may not
+/// collapse across face boundaries — use the tags to tell face groups apart.
+pub fn tessellate_solid_tagged(
+    brep: &BRepSolid,
+    params: &TessellationParams,
+    overrides: Option<&HashMap<FaceId, TessellationParams>>,
+) -> (TriangleMesh, Vec<u32>) {
+    let mut mesh = TriangleMesh::new();
+    let mut tags = Vec::new();
+    let faces = solid_shell_faces(brep);
+
+    for (face_index, &face_id) in faces.iter().enumerate() {
+        let face_params = overrides.and_then(|m| m.get(&face_id)).unwrap_or(params);
+        let face_mesh = tessellate_face(&brep.topology, &brep.geometry, face_id, face_params);
+        tags.resize(tags.len() + face_mesh.num_triangles(), face_index as u32);
+        mesh.merge(&face_mesh);
+    }
+
+    (mesh, tags)
+}
+
+/// Tessellate a B-rep solid with faces processed in parallel.
+///
+/// Faces are independent, so each one is tessellated on the rayon thread pool
+/// and the per-face meshes are merged in shell order afterwards. The merge
+/// order matches [`tessellate_solid`], so the output is byte-identical to the
+/// serial path. Only available with the `parallel` feature; WASM builds are
+/// single-threaded and should stick with [`tessellate_solid`].
+#[cfg(feature = "parallel")]
+pub fn tessellate_solid_parallel(
+    brep: &BRepSolid,
+    params: &TessellationParams,
+    overrides: Option<&HashMap<FaceId, TessellationParams>>,
+) -> TriangleMesh {
+    use rayon::prelude::*;
+
+    let faces = solid_shell_faces(brep);
+
+    let face_meshes: Vec<TriangleMesh> = faces
+        .par_iter()
+        .map(|&face_id| {
+            let face_params = overrides.and_then(|m| m.get(&face_id)).unwrap_or(params);
+            tessellate_face(&brep.topology, &brep.geometry, face_id, face_params)
+        })
+        .collect();
+
+    let mut mesh = TriangleMesh::new();
+    for face_mesh in &face_meshes {
+        mesh.merge(face_mesh);
+    }
+    mesh
+}
+
+/// Worst chord-height deviation between a tessellation and the exact B-rep.
+#[derive(Debug, Clone, Copy)]
+pub struct DeviationReport {
+    /// Largest distance from a triangle centroid to the exact surface.
+    pub max_deviation: f64,
+    /// Triangle centroid where the worst deviation occurs.
+    pub location: Point3,
+}
+
+/// Measure how far the tessellation deviates from the exact surfaces.
+///
+/// For each triangle on a curved face, samples the distance to the true
+/// surface at the triangle centroid and edge midpoints (the midpoint of a
+/// chord edge is where the sagitta peaks) and reports the worst deviation
+/// and where it occurs. Planar faces are exact and skipped. Useful for
+/// deciding when to increase the segment count.
+pub fn tessellation_deviation(brep: &BRepSolid, segments: u32) -> DeviationReport {
+    let params = TessellationParams::from_segments(segments);
+    let solid = &brep.topology.solids[brep.solid_id];
+    let shell = &brep.topology.shells[solid.outer_shell];
+
+    let mut report = DeviationReport {
+        max_deviation: 0.0,
+        location: Point3::origin(),
+    };
+
+    for &face_id in &shell.faces {
+        let face = &brep.topology.faces[face_id];
+        let surface = &brep.geometry.surfaces[face.surface_index];
+        if surface.surface_type() == SurfaceKind::Plane {
+            continue;
+        }
+
+        let mesh = tessellate_face(&brep.topology, &brep.geometry, face_id, &params);
+        for tri in mesh.indices.chunks(3) {
+            let corner = |idx: u32| -> Point3 {
+                let i = idx as usize * 3;
+                Point3::new(
+                    mesh.vertices[i] as f64,
+                    mesh.vertices[i + 1] as f64,
+                    mesh.vertices[i + 2] as f64,
+                )
+            };
+            let (a, b, c) = (corner(tri[0]), corner(tri[1]), corner(tri[2]));
+
+            let centroid = Point3::from((a.coords + b.coords + c.coords) / 3.0);
+            let samples = [
+                centroid,
+                Point3::from((a.coords + b.coords) / 2.0),
+                Point3::from((b.coords + c.coords) / 2.0),
+                Point3::from((c.coords + a.coords) / 2.0),
+            ];
+
+            for sample in samples {
+                let d = vcad_kernel_geom::distance_to_surface(surface.as_ref(), &sample);
+                if d > report.max_deviation {
+                    report.max_deviation = d;
+                    report.location = sample;
+                }
+            }
+        }
+    }
+
+    report
+}
+
 /// Tessellate a single B-rep face.
-fn tessellate_face(
+///
+/// Public so consumers can group or post-process per-face output (for
+/// example building one mesh per display color); [`tessellate_solid`]
+/// merges these same meshes in shell order.
+pub fn tessellate_face(
     topo: &Topology,
     geom: &GeometryStore,
     face_id: FaceId,
@@ -224,23 +864,33 @@ fn tessellate_planar_face_with_geom(
     let dot = geom_normal.dot(&expected_normal);
     let winding_matches = dot > 0.0;
 
-    // Debug: print when winding mismatch is detected
-    if !winding_matches {
-        eprintln!(
-            "[TESSELLATE] Face {:?}: winding mismatch detected (dot={:.4}), flipping reversed={} to {}",
-            face_id, dot, reversed, !reversed
-        );
-    }
-
-    // If winding doesn't match, flip the reversed flag
-    let effective_reversed = if winding_matches { reversed } else { !reversed };
+    // Emit triangles so their normal equals the expected face normal: follow
+    // the loop order when it already winds the right way, otherwise reverse.
+    // Note the orientation flag is the source of truth for the normal (sewing
+    // flips only the flag, never the loop order), so `reversed` contributes
+    // via `expected_normal` and must not flip the triangulation again.
+    let effective_reversed = !winding_matches;
 
     // Check if face has inner loops (holes)
-    if !face.inner_loops.is_empty() {
-        return tessellate_planar_face_with_holes(topo, face_id, effective_reversed);
+    let mut mesh = if !face.inner_loops.is_empty() {
+        tessellate_planar_face_with_holes(topo, face_id, effective_reversed)
+    } else {
+        tessellate_planar_face_core(&outer_verts, effective_reversed)
+    };
+
+    // A flat face shares one normal across all its vertices; the planar
+    // helpers only emit positions, so fill the normal array here to keep
+    // `normals.len() == vertices.len()` across the merged solid mesh
+    if mesh.normals.len() != mesh.vertices.len() {
+        mesh.normals.clear();
+        for _ in 0..mesh.num_vertices() {
+            mesh.normals.push(expected_normal.x as f32);
+            mesh.normals.push(expected_normal.y as f32);
+            mesh.normals.push(expected_normal.z as f32);
+        }
     }
 
-    tessellate_planar_face_core(&outer_verts, effective_reversed)
+    mesh
 }
 
 /// Core tessellation logic for a planar polygon without holes.
@@ -374,8 +1024,8 @@ fn find_best_fan_center(verts: &[Point3]) -> Option<usize> {
             let v1 = &verts[v1_idx];
             let v2 = &verts[v2_idx];
             // Compute signed area of triangle (center, v1, v2)
-            let tri_area = (v1.x - center.x) * (v2.y - center.y)
-                - (v2.x - center.x) * (v1.y - center.y);
+            let tri_area =
+                (v1.x - center.x) * (v2.y - center.y) - (v2.x - center.x) * (v1.y - center.y);
             // Triangle should have same sign as polygon (both positive or both negative)
             // Use a small tolerance to avoid issues with degenerate triangles
             if tri_area.abs() > 1e-10 && (tri_area > 0.0) != (polygon_signed_area > 0.0) {
@@ -1157,10 +1807,9 @@ fn tessellate_cylindrical_face(
 ) -> TriangleMesh {
     let face = &topo.faces[face_id];
     let surface = &geom.surfaces[face.surface_index];
-    let n_circ = params.circle_segments.max(3) as usize;
+    let mut n_circ = params.circle_segments.max(3) as usize;
     let mut n_height = params.height_segments.max(1) as usize;
 
-
     // Determine the v (height) parameter range by projecting seam vertices
     // onto the cylinder axis. This works correctly after any transform.
     let verts: Vec<_> = topo
@@ -1168,6 +1817,29 @@ fn tessellate_cylindrical_face(
         .map(|he| topo.vertices[topo.half_edges[he].origin].point)
         .collect();
 
+    // A loop that contains both halves of a seam edge wraps the full
+    // circumference, however many rim vertices it carries (sewing can
+    // rebuild the rims as polylines twinned against neighbouring faces)
+    let loop_hes: Vec<_> = topo.loop_half_edges(face.outer_loop).collect();
+    let has_seam = loop_hes.iter().any(|&he| {
+        topo.half_edges[he]
+            .twin
+            .is_some_and(|t| loop_hes.contains(&t))
+    });
+
+    // Bands from oblique boolean splits have one boundary that follows a
+    // sampled curve (an ellipse) rather than a constant-height circle; the
+    // regular UV grid below assumes a rectangular band, so those faces take
+    // a boundary-following path instead.
+    if let Some(cyl) = surface
+        .as_any()
+        .downcast_ref::<vcad_kernel_geom::CylinderSurface>()
+    {
+        if let Some(mesh) = tessellate_curved_cylindrical_band(cyl, topo, face_id, params, reversed)
+        {
+            return mesh;
+        }
+    }
 
     let mut radius = None;
     let mut u_min = 0.0;
@@ -1177,6 +1849,7 @@ fn tessellate_cylindrical_face(
         .downcast_ref::<vcad_kernel_geom::CylinderSurface>()
     {
         radius = Some(cyl.radius.abs().max(1e-6));
+        n_circ = params.segments_for_radius(cyl.radius.abs()).max(3) as usize;
         // Project vertices onto axis to get v parameter and compute U angles
         let mut vmin = f64::MAX;
         let mut vmax = f64::MIN;
@@ -1185,12 +1858,14 @@ fn tessellate_cylindrical_face(
         let ref_dir = cyl.ref_dir.as_ref();
         let y_dir = cyl.axis.as_ref().cross(ref_dir);
         let mut angles: Vec<f64> = Vec::new();
+        let mut v_params: Vec<f64> = Vec::new();
 
         for pt in &verts {
             let d = *pt - cyl.center;
             let v = d.dot(cyl.axis.as_ref());
             vmin = vmin.min(v);
             vmax = vmax.max(v);
+            v_params.push(v);
 
             // Compute angle for this vertex
             let dot_y = d.dot(&y_dir);
@@ -1219,11 +1894,48 @@ fn tessellate_cylindrical_face(
             }
         }
 
+        // Chain of loop edges running along the bottom rim (both endpoints
+        // at v_min). By loop convention the bottom rim is traversed in +U —
+        // reversed faces flip only the orientation flag, never the winding —
+        // so the traversed arc IS the face's angular extent. This is exact
+        // where the min/max heuristic below is ambiguous: a 4-vertex
+        // sub-face spanning more than half the circumference has the same
+        // vertex angles as its complement
+        let bottom_arc = {
+            let n = angles.len();
+            let v_tol = 1e-6 * (vmax - vmin).abs().max(1.0);
+            let is_bottom = |i: usize| -> bool { (v_params[i] - vmin).abs() < v_tol };
+            // Start of a contiguous bottom run: a bottom edge whose
+            // predecessor is not a bottom edge
+            let mut run = None;
+            for i in 0..n {
+                let prev = (i + n - 1) % n;
+                let bottom_edge = |j: usize| -> bool { is_bottom(j) && is_bottom((j + 1) % n) };
+                if bottom_edge(i) && !bottom_edge(prev) {
+                    run = Some(i);
+                    break;
+                }
+            }
+            run.map(|start| {
+                let mut end = start;
+                while is_bottom((end + 2) % n) && (end + 1 - start) < n {
+                    end += 1;
+                }
+                let s_ang = angles[start];
+                let e_ang = angles[(end + 1) % n];
+                let width = (e_ang - s_ang).rem_euclid(2.0 * PI);
+                (s_ang, width)
+            })
+        };
 
-        if unique_angles.len() == 1 {
-            // Full cylinder (all vertices at same seam angle)
+        if unique_angles.len() == 1 || has_seam {
+            // Full cylinder: all vertices at the same seam angle, or the
+            // loop carries its own seam edge
             u_min = 0.0;
             u_max = 2.0 * PI;
+        } else if let Some((lo, width)) = bottom_arc.filter(|&(_, w)| w > 0.01) {
+            u_min = lo;
+            u_max = lo + width;
         } else {
             // Determine angular direction from loop vertex order
             // Find the first significant angle change to detect winding direction
@@ -1300,9 +2012,11 @@ fn tessellate_cylindrical_face(
         n_circ
     };
 
+    // Collapsed geometry (e.g. a zero-height scale) leaves the v sentinels
+    // untouched, so a non-finite height must not drive the band count
     if let Some(radius) = radius {
         let arc_length = radius * u_range;
-        if arc_length > 1e-9 {
+        if arc_length > 1e-9 && height.is_finite() {
             let target = (height.abs() / arc_length) * effective_n_circ as f64;
             n_height = n_height.max(target.ceil() as usize).max(1);
         }
@@ -1348,56 +2062,280 @@ fn tessellate_cylindrical_face(
     mesh
 }
 
-/// Tessellate a spherical face.
-/// Uses a single vertex at each pole to avoid normal computation artifacts.
-/// For split caps (from boolean operations), uses boundary-aware tessellation.
-fn tessellate_spherical_face(
+/// Tessellate a cylindrical band whose boundaries follow sampled curves
+/// (oblique ellipses or crossing-cylinder branches from a boolean split)
+/// instead of constant-height circles.
+///
+/// The band is recognised by its loop shape: a seam edge pair (two loop
+/// half-edges twinned with each other) separating a bottom boundary chain
+/// from a top one. Either chain may be a flat circle or a run of curve
+/// samples at varying heights. Columns are placed at the union of both
+/// chains' sample angles so each curved boundary reproduces its split
+/// vertices exactly, keeping the mesh aligned with the neighbouring faces.
+///
+/// Returns `None` for regular rectangular bands, which the UV-grid path in
+/// `tessellate_cylindrical_face` handles.
+fn tessellate_curved_cylindrical_band(
+    cyl: &vcad_kernel_geom::CylinderSurface,
     topo: &Topology,
-    geom: &GeometryStore,
     face_id: FaceId,
     params: &TessellationParams,
     reversed: bool,
-) -> TriangleMesh {
-    let face = &topo.faces[face_id];
-    let surface = &geom.surfaces[face.surface_index];
+) -> Option<TriangleMesh> {
+    let two_pi = 2.0 * PI;
 
-    // Count edges in the face loop to detect split caps
-    let loop_verts: Vec<Point3> = topo
-        .loop_half_edges(face.outer_loop)
-        .map(|he| topo.vertices[topo.half_edges[he].origin].point)
-        .collect();
+    let face = &topo.faces[face_id];
+    let loop_hes: Vec<_> = topo.loop_half_edges(face.outer_loop).collect();
+    let he_count = loop_hes.len();
+    if he_count <= 4 {
+        return None;
+    }
 
-    // A normal sphere has exactly 4 edges from B-rep. Split caps have more.
-    if loop_verts.len() > 4 {
-        return tessellate_spherical_cap(surface.as_ref(), &loop_verts, reversed);
+    // Seam edge pair: the unique loop half-edges twinned with each other
+    let mut seam_positions = Vec::new();
+    for (i, &he_id) in loop_hes.iter().enumerate() {
+        if let Some(twin) = topo.half_edges[he_id].twin {
+            if loop_hes.contains(&twin) {
+                seam_positions.push(i);
+            }
+        }
+    }
+    if seam_positions.len() != 2 {
+        return None;
+    }
+    let (s0, s1) = (seam_positions[0], seam_positions[1]);
+
+    // Chain vertex points in loop order, including the seam endpoints.
+    // Sewing can absorb a flat circle edge entirely, leaving the two seam
+    // half-edges adjacent; the empty arc then yields a single-vertex chain
+    // whose height is the flat boundary's v
+    let chain_points = |from: usize, to: usize| -> Vec<Point3> {
+        let mut pts = Vec::new();
+        let mut i = (from + 1) % he_count;
+        while i != to {
+            pts.push(topo.vertices[topo.half_edges[loop_hes[i]].origin].point);
+            i = (i + 1) % he_count;
+        }
+        pts.push(topo.vertices[topo.half_edges[loop_hes[to]].origin].point);
+        pts
+    };
+    let chain_a = chain_points(s0, s1);
+    let chain_b = chain_points(s1, s0);
+
+    // (u, v, point) graph of a chain, seam vertices duplicated at u = 0 and
+    // u = 2π so interpolation covers the full turn
+    let ref_dir = cyl.ref_dir.as_ref();
+    let y_dir = cyl.axis.as_ref().cross(ref_dir);
+    let graph_of = |chain: &[Point3]| -> Vec<(f64, f64, Point3)> {
+        let mut graph = Vec::new();
+        for p in chain {
+            let d = *p - cyl.center;
+            let v = d.dot(cyl.axis.as_ref());
+            let u = d.dot(&y_dir).atan2(d.dot(ref_dir));
+            let u = if u < 0.0 { u + two_pi } else { u };
+            if u < 1e-7 || u > two_pi - 1e-7 {
+                graph.push((0.0, v, *p));
+                graph.push((two_pi, v, *p));
+            } else {
+                graph.push((u, v, *p));
+            }
+        }
+        graph.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        graph.dedup_by(|a, b| (a.0 - b.0).abs() < 1e-9);
+        graph
+    };
+    let graph_a = graph_of(&chain_a);
+    let graph_b = graph_of(&chain_b);
+    if graph_a.is_empty() || graph_b.is_empty() {
+        return None;
     }
 
-    let n_lon = params.circle_segments as usize;
-    let n_lat = params.latitude_segments as usize;
+    let mean_v = |graph: &[(f64, f64, Point3)]| -> f64 {
+        graph.iter().map(|g| g.1).sum::<f64>() / graph.len() as f64
+    };
+    let (bottom, top) = if mean_v(&graph_a) <= mean_v(&graph_b) {
+        (graph_a, graph_b)
+    } else {
+        (graph_b, graph_a)
+    };
 
-    let mut mesh = TriangleMesh::new();
+    // A rectangular band (two flat circles) keeps the regular UV-grid path
+    let is_flat = |graph: &[(f64, f64, Point3)]| -> bool {
+        let v0 = graph[0].1;
+        graph.iter().all(|g| (g.1 - v0).abs() < 1e-9)
+    };
+    if is_flat(&bottom) && is_flat(&top) {
+        return None;
+    }
 
-    // South pole - single vertex (index 0)
-    let south = surface.evaluate(Point2::new(0.0, -PI / 2.0));
-    mesh.vertices.push(south.x as f32);
-    mesh.vertices.push(south.y as f32);
-    mesh.vertices.push(south.z as f32);
+    let interp_v = |graph: &[(f64, f64, Point3)], u: f64| -> f64 {
+        match graph.iter().position(|g| g.0 >= u) {
+            None => graph.last().map(|g| g.1).unwrap_or(0.0),
+            Some(0) => graph[0].1,
+            Some(i) => {
+                let (u0, v0, _) = graph[i - 1];
+                let (u1, v1, _) = graph[i];
+                if u1 - u0 < 1e-12 {
+                    v1
+                } else {
+                    v0 + (v1 - v0) * (u - u0) / (u1 - u0)
+                }
+            }
+        }
+    };
+    let exact_point = |graph: &[(f64, f64, Point3)], u: f64| -> Option<Point3> {
+        graph.iter().find(|g| (g.0 - u).abs() < 1e-9).map(|g| g.2)
+    };
 
-    // Middle latitude bands (j = 1 to n_lat - 1)
-    for j in 1..n_lat {
-        let v = -PI / 2.0 + PI * (j as f64 / n_lat as f64);
-        for i in 0..=n_lon {
-            let u = 2.0 * PI * (i as f64 / n_lon as f64);
-            let pt = surface.evaluate(Point2::new(u, v));
-            mesh.vertices.push(pt.x as f32);
-            mesh.vertices.push(pt.y as f32);
-            mesh.vertices.push(pt.z as f32);
+    // Columns at the union of both chains' sample angles; the seam
+    // duplicates at u = 0 and u = 2π close the ring
+    let mut us: Vec<f64> = bottom.iter().chain(top.iter()).map(|g| g.0).collect();
+    us.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    us.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+    if us.len() < 4 {
+        return None;
+    }
+    // The column set must wrap the full circumference: flat circle chains
+    // contribute only the seam columns, so the curved chain has to supply
+    // the rest (partial faces from other split kinds keep the rectangular
+    // path)
+    for w in us.windows(2) {
+        if w[1] - w[0] > 1.0 {
+            return None;
         }
     }
 
-    // North pole - single vertex (last index)
-    let north = surface.evaluate(Point2::new(0.0, PI / 2.0));
-    mesh.vertices.push(north.x as f32);
+    let n_circ = params.segments_for_radius(cyl.radius.abs()).max(3) as usize;
+    let mut n_height = params.height_segments.max(1) as usize;
+    let max_extent = us
+        .iter()
+        .map(|&u| (interp_v(&top, u) - interp_v(&bottom, u)).abs())
+        .fold(0.0_f64, f64::max);
+    let arc_length = cyl.radius.abs().max(1e-6) * two_pi;
+    let target = (max_extent / arc_length) * n_circ as f64;
+    n_height = n_height.max(target.ceil() as usize).max(1);
+
+    let mut mesh = TriangleMesh::new();
+
+    // Grid rows run from low v to high v so the winding matches the regular
+    // UV-grid path. Boundary rows reuse the exact chain sample points where
+    // a column angle comes from that chain; interior rows interpolate per
+    // column and evaluate the surface.
+    let stride = us.len() as u32;
+    for j in 0..=n_height {
+        let t = j as f64 / n_height as f64;
+        for &u in &us {
+            let exact = if j == 0 {
+                exact_point(&bottom, u)
+            } else if j == n_height {
+                exact_point(&top, u)
+            } else {
+                None
+            };
+            let pt = exact.unwrap_or_else(|| {
+                let v_bot = interp_v(&bottom, u);
+                let v_top = interp_v(&top, u);
+                cyl.evaluate(Point2::new(u % two_pi, v_bot + (v_top - v_bot) * t))
+            });
+            mesh.vertices.push(pt.x as f32);
+            mesh.vertices.push(pt.y as f32);
+            mesh.vertices.push(pt.z as f32);
+        }
+    }
+
+    for j in 0..n_height as u32 {
+        for i in 0..stride - 1 {
+            let bl = j * stride + i;
+            let br = bl + 1;
+            let tl = bl + stride;
+            let tr = tl + 1;
+
+            if reversed {
+                mesh.indices.extend_from_slice(&[bl, tl, br]);
+                mesh.indices.extend_from_slice(&[br, tl, tr]);
+            } else {
+                mesh.indices.extend_from_slice(&[bl, br, tl]);
+                mesh.indices.extend_from_slice(&[br, tr, tl]);
+            }
+        }
+    }
+
+    Some(mesh)
+}
+
+/// Tessellate a spherical face.
+/// Uses a single vertex at each pole to avoid normal computation artifacts.
+/// For split caps (from boolean operations), uses boundary-aware tessellation.
+fn tessellate_spherical_face(
+    topo: &Topology,
+    geom: &GeometryStore,
+    face_id: FaceId,
+    params: &TessellationParams,
+    reversed: bool,
+) -> TriangleMesh {
+    let face = &topo.faces[face_id];
+    let surface = &geom.surfaces[face.surface_index];
+
+    // Count edges in the face loop to detect split caps
+    let loop_verts: Vec<Point3> = topo
+        .loop_half_edges(face.outer_loop)
+        .map(|he| topo.vertices[topo.half_edges[he].origin].point)
+        .collect();
+
+    // A normal sphere has exactly 4 edges from B-rep. Split caps have more.
+    if loop_verts.len() > 4 {
+        return tessellate_spherical_cap(surface.as_ref(), &loop_verts, reversed);
+    }
+
+    let (n_lon, n_lat) = if let Some(sphere) = surface
+        .as_any()
+        .downcast_ref::<vcad_kernel_geom::SphereSurface>()
+    {
+        let n = params.segments_for_radius(sphere.radius.abs()) as usize;
+        let lat = if params.max_sag.is_some() {
+            (n / 2).max(4)
+        } else {
+            params.latitude_segments as usize
+        };
+        (n, lat)
+    } else {
+        (
+            params.circle_segments as usize,
+            params.latitude_segments as usize,
+        )
+    };
+
+    // Reduced-resolution polar caps, if requested
+    if let Some(fan) = params.pole_fan_segments {
+        let fan = fan.max(3) as usize;
+        if n_lat >= 4 && fan < n_lon {
+            return tessellate_sphere_pole_fan(surface.as_ref(), n_lon, n_lat, fan, reversed);
+        }
+    }
+
+    let mut mesh = TriangleMesh::new();
+
+    // South pole - single vertex (index 0)
+    let south = surface.evaluate(Point2::new(0.0, -PI / 2.0));
+    mesh.vertices.push(south.x as f32);
+    mesh.vertices.push(south.y as f32);
+    mesh.vertices.push(south.z as f32);
+
+    // Middle latitude bands (j = 1 to n_lat - 1)
+    for j in 1..n_lat {
+        let v = -PI / 2.0 + PI * (j as f64 / n_lat as f64);
+        for i in 0..=n_lon {
+            let u = 2.0 * PI * (i as f64 / n_lon as f64);
+            let pt = surface.evaluate(Point2::new(u, v));
+            mesh.vertices.push(pt.x as f32);
+            mesh.vertices.push(pt.y as f32);
+            mesh.vertices.push(pt.z as f32);
+        }
+    }
+
+    // North pole - single vertex (last index)
+    let north = surface.evaluate(Point2::new(0.0, PI / 2.0));
+    mesh.vertices.push(north.x as f32);
     mesh.vertices.push(north.y as f32);
     mesh.vertices.push(north.z as f32);
 
@@ -1452,6 +2390,147 @@ fn tessellate_spherical_face(
     mesh
 }
 
+/// Lat/long sphere tessellation with reduced-resolution polar caps.
+///
+/// The rings adjacent to the poles carry only `fan` longitude segments, so
+/// the pole fans are a few wide triangles instead of `n_lon` slivers. The
+/// segment count doubles per latitude band away from each pole until it
+/// reaches `n_lon`, keeping every stitch between mismatched rings a gentle
+/// 2:1 transition rather than an abrupt jump.
+fn tessellate_sphere_pole_fan(
+    surface: &dyn vcad_kernel_geom::Surface,
+    n_lon: usize,
+    n_lat: usize,
+    fan: usize,
+    reversed: bool,
+) -> TriangleMesh {
+    let mut mesh = TriangleMesh::new();
+
+    let latitude = |j: usize| -PI / 2.0 + PI * (j as f64 / n_lat as f64);
+    let ring_count = |j: usize| -> usize {
+        let bands_from_pole = j.min(n_lat - j);
+        fan.saturating_mul(1usize << (bands_from_pole - 1).min(31))
+            .min(n_lon)
+    };
+
+    // South pole, rings j = 1..n_lat-1 (coarse near the poles), north pole
+    let south = surface.evaluate(Point2::new(0.0, -PI / 2.0));
+    mesh.vertices
+        .extend_from_slice(&[south.x as f32, south.y as f32, south.z as f32]);
+    let south_idx = 0u32;
+
+    let mut ring_starts = Vec::with_capacity(n_lat - 1);
+    for j in 1..n_lat {
+        ring_starts.push(mesh.num_vertices() as u32);
+        let count = ring_count(j);
+        let v = latitude(j);
+        for i in 0..=count {
+            let u = 2.0 * PI * (i as f64 / count as f64);
+            let pt = surface.evaluate(Point2::new(u, v));
+            mesh.vertices
+                .extend_from_slice(&[pt.x as f32, pt.y as f32, pt.z as f32]);
+        }
+    }
+
+    let north_idx = mesh.num_vertices() as u32;
+    let north = surface.evaluate(Point2::new(0.0, PI / 2.0));
+    mesh.vertices
+        .extend_from_slice(&[north.x as f32, north.y as f32, north.z as f32]);
+
+    // South pole fan
+    let south_cap_start = ring_starts[0];
+    for i in 0..ring_count(1) as u32 {
+        let (v1, v2) = (south_cap_start + i, south_cap_start + i + 1);
+        if reversed {
+            mesh.indices.extend_from_slice(&[south_idx, v1, v2]);
+        } else {
+            mesh.indices.extend_from_slice(&[south_idx, v2, v1]);
+        }
+    }
+
+    // Bands between rings: quads where the counts match, stitches where the
+    // resolution steps up or down
+    for j in 1..n_lat - 1 {
+        let (lower, upper) = (ring_starts[j - 1], ring_starts[j]);
+        let (lower_count, upper_count) = (ring_count(j), ring_count(j + 1));
+        if lower_count == upper_count {
+            for i in 0..lower_count as u32 {
+                let (bl, br, tl, tr) = (lower + i, lower + i + 1, upper + i, upper + i + 1);
+                if reversed {
+                    mesh.indices.extend_from_slice(&[bl, tl, br]);
+                    mesh.indices.extend_from_slice(&[br, tl, tr]);
+                } else {
+                    mesh.indices.extend_from_slice(&[bl, br, tl]);
+                    mesh.indices.extend_from_slice(&[br, tr, tl]);
+                }
+            }
+        } else {
+            stitch_rings(
+                &mut mesh.indices,
+                lower,
+                lower_count,
+                upper,
+                upper_count,
+                reversed,
+            );
+        }
+    }
+
+    // North pole fan
+    let north_cap_start = ring_starts[n_lat - 2];
+    for i in 0..ring_count(n_lat - 1) as u32 {
+        let (v1, v2) = (north_cap_start + i, north_cap_start + i + 1);
+        if reversed {
+            mesh.indices.extend_from_slice(&[north_idx, v2, v1]);
+        } else {
+            mesh.indices.extend_from_slice(&[north_idx, v1, v2]);
+        }
+    }
+
+    mesh
+}
+
+/// Triangulate the band between two closed rings with different segment
+/// counts, walking both by parametric angle. Both rings include a duplicated
+/// seam vertex at 2π, so the band is watertight against the fans/quads on
+/// either side.
+fn stitch_rings(
+    indices: &mut Vec<u32>,
+    lower_start: u32,
+    lower_count: usize,
+    upper_start: u32,
+    upper_count: usize,
+    reversed: bool,
+) {
+    let mut i = 0usize;
+    let mut j = 0usize;
+    while i < lower_count || j < upper_count {
+        let advance_lower = if i >= lower_count {
+            false
+        } else if j >= upper_count {
+            true
+        } else {
+            (i + 1) as f64 / lower_count as f64 <= (j + 1) as f64 / upper_count as f64
+        };
+        let (b, c) = if advance_lower {
+            (lower_start + i as u32 + 1, upper_start + j as u32)
+        } else {
+            (upper_start + j as u32 + 1, upper_start + j as u32)
+        };
+        let a = lower_start + i as u32;
+        if reversed {
+            indices.extend_from_slice(&[a, c, b]);
+        } else {
+            indices.extend_from_slice(&[a, b, c]);
+        }
+        if advance_lower {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+}
+
 /// Tessellate a spherical cap defined by a boundary loop.
 /// Used for split faces from boolean operations.
 fn tessellate_spherical_cap(
@@ -1765,7 +2844,6 @@ fn tessellate_conical_face(
 ) -> TriangleMesh {
     let face = &topo.faces[face_id];
     let surface = &geom.surfaces[face.surface_index];
-    let n_circ = params.circle_segments as usize;
     let n_height = params.height_segments as usize;
 
     // Get seam vertices to determine the cone extent
@@ -1774,6 +2852,31 @@ fn tessellate_conical_face(
         .map(|he| topo.vertices[topo.half_edges[he].origin].point)
         .collect();
 
+    // Adaptive counts follow the widest rim of the truncated cone
+    let n_circ = if params.max_sag.is_some() {
+        let r_max = if let Some(cone) = surface
+            .as_any()
+            .downcast_ref::<vcad_kernel_geom::ConeSurface>()
+        {
+            let axis = cone.axis.as_ref();
+            verts
+                .iter()
+                .map(|p| {
+                    let d = p - cone.apex;
+                    (d - d.dot(axis) * axis).norm()
+                })
+                .fold(0.0_f64, f64::max)
+        } else {
+            verts
+                .iter()
+                .map(|v| (v.x * v.x + v.y * v.y).sqrt())
+                .fold(0.0_f64, f64::max)
+        };
+        params.segments_for_radius(r_max) as usize
+    } else {
+        params.circle_segments as usize
+    };
+
     // Extract cone geometry for axis-aware parameterization
     let (axis, apex, ref_dir, half_angle) = if let Some(cone) = surface
         .as_any()
@@ -1813,6 +2916,32 @@ fn tessellate_conical_face(
     let mut mesh = TriangleMesh::new();
     let mut rows: Vec<Vec<u32>> = Vec::new();
 
+    // Average the lateral normals around a well-defined ring for the apex,
+    // where the surface normal itself is undefined. The radial components
+    // cancel, leaving the axial component of the cone normal.
+    let apex_normal = |reversed: bool| -> Vec3 {
+        let v_ring = if v_min.abs() < v_max.abs() {
+            v_max
+        } else {
+            v_min
+        };
+        let mut sum = Vec3::zeros();
+        for i in 0..n_circ {
+            let u = 2.0 * PI * (i as f64 / n_circ as f64);
+            sum += surface.normal(Point2::new(u, v_ring)).into_inner();
+        }
+        let n = if sum.norm() > 1e-12 {
+            sum.normalize()
+        } else {
+            axis
+        };
+        if reversed {
+            -n
+        } else {
+            n
+        }
+    };
+
     for j in 0..=n_height {
         let t = j as f64 / n_height as f64;
         let v = v_min + (v_max - v_min) * t;
@@ -1821,22 +2950,35 @@ fn tessellate_conical_face(
         let mut row = Vec::new();
 
         if r.abs() < 1e-12 {
-            // Apex point
+            // Apex point: a single vertex with the averaged normal
             let pt = apex + v * half_angle.cos() * axis;
+            let n = apex_normal(reversed);
             let idx = mesh.num_vertices() as u32;
             mesh.vertices.push(pt.x as f32);
             mesh.vertices.push(pt.y as f32);
             mesh.vertices.push(pt.z as f32);
+            mesh.normals.push(n.x as f32);
+            mesh.normals.push(n.y as f32);
+            mesh.normals.push(n.z as f32);
             row.push(idx);
         } else {
             let center = apex + v * half_angle.cos() * axis;
             for i in 0..=n_circ {
                 let u = 2.0 * PI * (i as f64 / n_circ as f64);
                 let pt = center + r * (u.cos() * ref_dir + u.sin() * y_dir);
+                let normal = surface.normal(Point2::new(u, v));
                 let idx = mesh.num_vertices() as u32;
                 mesh.vertices.push(pt.x as f32);
                 mesh.vertices.push(pt.y as f32);
                 mesh.vertices.push(pt.z as f32);
+                let (nx, ny, nz) = if reversed {
+                    (-normal.x as f32, -normal.y as f32, -normal.z as f32)
+                } else {
+                    (normal.x as f32, normal.y as f32, normal.z as f32)
+                };
+                mesh.normals.push(nx);
+                mesh.normals.push(ny);
+                mesh.normals.push(nz);
                 row.push(idx);
             }
         }
@@ -1888,9 +3030,37 @@ fn tessellate_conical_face(
         }
     }
 
+    // Drop zero-area triangles (seam duplicates or rows that collapse
+    // towards the apex)
+    drop_degenerate_triangles(&mut mesh);
+
     mesh
 }
 
+/// Remove triangles whose area is (numerically) zero.
+fn drop_degenerate_triangles(mesh: &mut TriangleMesh) {
+    let corner = |mesh: &TriangleMesh, idx: u32| -> Point3 {
+        let i = idx as usize * 3;
+        Point3::new(
+            mesh.vertices[i] as f64,
+            mesh.vertices[i + 1] as f64,
+            mesh.vertices[i + 2] as f64,
+        )
+    };
+
+    let mut kept = Vec::with_capacity(mesh.indices.len());
+    for tri in mesh.indices.chunks(3) {
+        let a = corner(mesh, tri[0]);
+        let b = corner(mesh, tri[1]);
+        let c = corner(mesh, tri[2]);
+        let area = (b - a).cross(&(c - a)).norm() * 0.5;
+        if area > 1e-12 {
+            kept.extend_from_slice(tri);
+        }
+    }
+    mesh.indices = kept;
+}
+
 /// Fallback cone tessellation using direct z-axis coordinates.
 fn tessellate_cone_direct(
     verts: &[Point3],
@@ -1989,8 +3159,23 @@ fn tessellate_toroidal_face(
 ) -> TriangleMesh {
     let face = &topo.faces[face_id];
     let surface = &geom.surfaces[face.surface_index];
-    let n_u = params.circle_segments as usize;
-    let n_v = params.circle_segments as usize;
+    let (n_u, n_v) = if let Some(torus) = surface
+        .as_any()
+        .downcast_ref::<vcad_kernel_geom::TorusSurface>()
+        .filter(|_| params.max_sag.is_some())
+    {
+        // Around the main ring the sag radius is the outer equator
+        (
+            params.segments_for_radius(torus.major_radius.abs() + torus.minor_radius.abs())
+                as usize,
+            params.segments_for_radius(torus.minor_radius.abs()) as usize,
+        )
+    } else {
+        (
+            params.circle_segments as usize,
+            params.circle_segments as usize,
+        )
+    };
 
     let mut mesh = TriangleMesh::new();
 
@@ -2187,12 +3372,11 @@ pub fn tessellate_disk(
 /// - `indices`: flat `Vec<u32>` of triangle vertex indices
 pub fn tessellate(brep: &BRepSolid, segments: u32) -> TriangleMesh {
     let params = TessellationParams::from_segments(segments);
-    let solid = &brep.topology.solids[brep.solid_id];
-    let shell = &brep.topology.shells[solid.outer_shell];
+    let faces = solid_shell_faces(brep);
 
     let mut mesh = TriangleMesh::new();
 
-    for &face_id in &shell.faces {
+    for &face_id in &faces {
         let face = &brep.topology.faces[face_id];
         let surface = &brep.geometry.surfaces[face.surface_index];
         let reversed = face.orientation == Orientation::Reversed;
@@ -2200,7 +3384,12 @@ pub fn tessellate(brep: &BRepSolid, segments: u32) -> TriangleMesh {
         match surface.surface_type() {
             SurfaceKind::Plane => {
                 // Use winding-aware tessellation to handle faces with mismatched loop winding
-                let face_mesh = tessellate_planar_face_with_geom(&brep.topology, &brep.geometry, face_id, reversed);
+                let face_mesh = tessellate_planar_face_with_geom(
+                    &brep.topology,
+                    &brep.geometry,
+                    face_id,
+                    reversed,
+                );
                 mesh.merge(&face_mesh);
             }
             SurfaceKind::Cylinder => {
@@ -2240,7 +3429,12 @@ pub fn tessellate(brep: &BRepSolid, segments: u32) -> TriangleMesh {
             }
             _ => {
                 // Fallback for tessellate(): use winding-aware tessellation
-                let face_mesh = tessellate_planar_face_with_geom(&brep.topology, &brep.geometry, face_id, reversed);
+                let face_mesh = tessellate_planar_face_with_geom(
+                    &brep.topology,
+                    &brep.geometry,
+                    face_id,
+                    reversed,
+                );
                 mesh.merge(&face_mesh);
             }
         }
@@ -2255,97 +3449,108 @@ pub fn tessellate(brep: &BRepSolid, segments: u32) -> TriangleMesh {
 /// This is the primary tessellation function used by the facade crate.
 pub fn tessellate_brep(brep: &BRepSolid, segments: u32) -> TriangleMesh {
     let params = TessellationParams::from_segments(segments);
-    let solid = &brep.topology.solids[brep.solid_id];
-    let shell = &brep.topology.shells[solid.outer_shell];
+    let faces = solid_shell_faces(brep);
 
     // DEBUG: print which shell we're tessellating
-    eprintln!("TESSELLATE_BREP: shell has {} faces: {:?}", shell.faces.len(), shell.faces);
+    eprintln!(
+        "TESSELLATE_BREP: shell has {} faces: {:?}",
+        faces.len(),
+        faces
+    );
 
     let mut mesh = TriangleMesh::new();
 
-    for &face_id in &shell.faces {
-        let face = &brep.topology.faces[face_id];
-        let surface = &brep.geometry.surfaces[face.surface_index];
-        let reversed = face.orientation == Orientation::Reversed;
-        let loop_len = brep.topology.loop_len(face.outer_loop);
+    for &face_id in &faces {
+        mesh.merge(&tessellate_brep_face(brep, face_id, &params));
+    }
 
-        match surface.surface_type() {
-            SurfaceKind::Plane => {
-                if loop_len <= 1 {
-                    // Cap face with a single vertex — this is a circular disk.
-                    // Use the plane surface's origin as center and compute
-                    // the radius from the vertex's distance to the center.
-                    let verts: Vec<_> = brep
-                        .topology
-                        .loop_half_edges(face.outer_loop)
-                        .map(|he| brep.topology.vertices[brep.topology.half_edges[he].origin].point)
-                        .collect();
-                    if let Some(&v) = verts.first() {
-                        let plane = &brep.geometry.surfaces[face.surface_index];
-                        let center = plane.evaluate(Point2::origin());
-                        let r = (v - center).norm();
-                        let x_dir = if r > 1e-12 {
-                            (v - center).normalize()
-                        } else {
-                            plane.d_du(Point2::origin()).normalize()
-                        };
-                        let normal = plane.normal(Point2::origin());
-                        let y_dir = normal.as_ref().cross(&x_dir);
-                        let disk = tessellate_disk_general(
-                            center,
-                            r,
-                            x_dir,
-                            y_dir,
-                            params.circle_segments,
-                            reversed,
-                        );
-                        mesh.merge(&disk);
-                    }
+    mesh
+}
+
+/// Like [`tessellate_brep`], but faces listed in `overrides` are tessellated
+/// with their own parameters instead of the `segments` default.
+pub fn tessellate_brep_refined(
+    brep: &BRepSolid,
+    segments: u32,
+    overrides: &HashMap<FaceId, TessellationParams>,
+) -> TriangleMesh {
+    let params = TessellationParams::from_segments(segments);
+    let faces = solid_shell_faces(brep);
+
+    let mut mesh = TriangleMesh::new();
+
+    for &face_id in &faces {
+        let face_params = overrides.get(&face_id).unwrap_or(&params);
+        mesh.merge(&tessellate_brep_face(brep, face_id, face_params));
+    }
+
+    mesh
+}
+
+/// Tessellate one face for [`tessellate_brep`], handling degenerate
+/// single-vertex cap loops.
+fn tessellate_brep_face(
+    brep: &BRepSolid,
+    face_id: FaceId,
+    params: &TessellationParams,
+) -> TriangleMesh {
+    let face = &brep.topology.faces[face_id];
+    let surface = &brep.geometry.surfaces[face.surface_index];
+    let reversed = face.orientation == Orientation::Reversed;
+    let loop_len = brep.topology.loop_len(face.outer_loop);
+
+    match surface.surface_type() {
+        SurfaceKind::Plane => {
+            if loop_len <= 1 {
+                // Cap face with a single vertex — this is a circular disk.
+                // Use the plane surface's origin as center and compute
+                // the radius from the vertex's distance to the center.
+                let verts: Vec<_> = brep
+                    .topology
+                    .loop_half_edges(face.outer_loop)
+                    .map(|he| brep.topology.vertices[brep.topology.half_edges[he].origin].point)
+                    .collect();
+                if let Some(&v) = verts.first() {
+                    let plane = &brep.geometry.surfaces[face.surface_index];
+                    let center = plane.evaluate(Point2::origin());
+                    let r = (v - center).norm();
+                    let x_dir = if r > 1e-12 {
+                        (v - center).normalize()
+                    } else {
+                        plane.d_du(Point2::origin()).normalize()
+                    };
+                    let normal = plane.normal(Point2::origin());
+                    let y_dir = normal.as_ref().cross(&x_dir);
+                    tessellate_disk_general(
+                        center,
+                        r,
+                        x_dir,
+                        y_dir,
+                        params.circle_segments,
+                        reversed,
+                    )
                 } else {
-                    // Use winding-aware tessellation to handle faces with mismatched loop winding
-                    let face_mesh = tessellate_planar_face_with_geom(&brep.topology, &brep.geometry, face_id, reversed);
-                    mesh.merge(&face_mesh);
+                    TriangleMesh::new()
                 }
-            }
-            SurfaceKind::Cylinder => {
-                let face_mesh = tessellate_cylindrical_face(
-                    &brep.topology,
-                    &brep.geometry,
-                    face_id,
-                    &params,
-                    reversed,
-                );
-                mesh.merge(&face_mesh);
-            }
-            SurfaceKind::Sphere => {
-                let face_mesh = tessellate_spherical_face(
-                    &brep.topology,
-                    &brep.geometry,
-                    face_id,
-                    &params,
-                    reversed,
-                );
-                mesh.merge(&face_mesh);
-            }
-            SurfaceKind::Cone => {
-                let face_mesh = tessellate_conical_face(
-                    &brep.topology,
-                    &brep.geometry,
-                    face_id,
-                    &params,
-                    reversed,
-                );
-                mesh.merge(&face_mesh);
-            }
-            _ => {
-                // Fallback for tessellate_brep(): use winding-aware tessellation
-                let face_mesh = tessellate_planar_face_with_geom(&brep.topology, &brep.geometry, face_id, reversed);
-                mesh.merge(&face_mesh);
+            } else {
+                // Use winding-aware tessellation to handle faces with mismatched loop winding
+                tessellate_planar_face_with_geom(&brep.topology, &brep.geometry, face_id, reversed)
             }
         }
+        SurfaceKind::Cylinder => {
+            tessellate_cylindrical_face(&brep.topology, &brep.geometry, face_id, params, reversed)
+        }
+        SurfaceKind::Sphere => {
+            tessellate_spherical_face(&brep.topology, &brep.geometry, face_id, params, reversed)
+        }
+        SurfaceKind::Cone => {
+            tessellate_conical_face(&brep.topology, &brep.geometry, face_id, params, reversed)
+        }
+        _ => {
+            // Fallback for tessellate_brep(): use winding-aware tessellation
+            tessellate_planar_face_with_geom(&brep.topology, &brep.geometry, face_id, reversed)
+        }
     }
-
-    mesh
 }
 
 #[cfg(test)]
@@ -2366,6 +3571,378 @@ mod tests {
         assert!(mesh.num_vertices() > 0);
     }
 
+    #[test]
+    fn test_pointed_cone_apex_tessellation() {
+        let brep = make_cone(5.0, 0.0, 10.0, 32);
+        let mesh = tessellate_brep(&brep, 32);
+        assert!(mesh.num_triangles() > 0);
+
+        // No degenerate triangles
+        for tri in mesh.indices.chunks(3) {
+            let corner = |idx: u32| -> Point3 {
+                let i = idx as usize * 3;
+                Point3::new(
+                    mesh.vertices[i] as f64,
+                    mesh.vertices[i + 1] as f64,
+                    mesh.vertices[i + 2] as f64,
+                )
+            };
+            let (a, b, c) = (corner(tri[0]), corner(tri[1]), corner(tri[2]));
+            let area = (b - a).cross(&(c - a)).norm() * 0.5;
+            assert!(area > 1e-12, "degenerate triangle with area {area}");
+        }
+
+        // The apex vertex appears exactly once
+        let apex_count = (0..mesh.num_vertices())
+            .filter(|&i| {
+                let p = Point3::new(
+                    mesh.vertices[i * 3] as f64,
+                    mesh.vertices[i * 3 + 1] as f64,
+                    mesh.vertices[i * 3 + 2] as f64,
+                );
+                (p - Point3::new(0.0, 0.0, 10.0)).norm() < 1e-6
+            })
+            .count();
+        assert_eq!(apex_count, 1);
+
+        // The apex normal is the averaged lateral normal: straight up
+        let apex_idx = (0..mesh.num_vertices())
+            .find(|&i| (mesh.vertices[i * 3 + 2] - 10.0).abs() < 1e-6)
+            .unwrap();
+        let nz = mesh.normals[apex_idx * 3 + 2];
+        assert!(nz > 0.9, "expected apex normal along +Z, got nz={nz}");
+    }
+
+    #[test]
+    fn test_manifold_and_closed_cube() {
+        let brep = make_cube(10.0, 10.0, 10.0);
+        let mesh = tessellate_brep(&brep, 32);
+        assert!(mesh.is_manifold());
+        assert!(mesh.is_closed());
+    }
+
+    #[test]
+    fn test_fix_winding_repairs_scrambled_cube() {
+        let brep = make_cube(10.0, 10.0, 10.0);
+        let mut mesh = tessellate_brep(&brep, 32);
+
+        // Flip every other triangle so windings disagree and the signed
+        // volume is wrong
+        for t in (0..mesh.num_triangles()).step_by(2) {
+            mesh.indices.swap(t * 3 + 1, t * 3 + 2);
+        }
+        assert!(!mesh.is_closed());
+
+        mesh.fix_winding();
+        assert!(mesh.is_closed());
+
+        let signed_volume: f64 = mesh
+            .indices
+            .chunks(3)
+            .map(|tri| {
+                let corner = |idx: u32| -> Vec3 {
+                    let i = idx as usize * 3;
+                    Vec3::new(
+                        mesh.vertices[i] as f64,
+                        mesh.vertices[i + 1] as f64,
+                        mesh.vertices[i + 2] as f64,
+                    )
+                };
+                let (v0, v1, v2) = (corner(tri[0]), corner(tri[1]), corner(tri[2]));
+                v0.dot(&v1.cross(&v2)) / 6.0
+            })
+            .sum();
+        assert!(
+            (signed_volume - 1000.0).abs() < 1.0,
+            "expected outward volume ~1000, got {signed_volume}"
+        );
+
+        // Normals follow the corrected winding: a vertex on the top face
+        // region points away from the centroid
+        let center = Vec3::new(5.0, 5.0, 5.0);
+        for i in 0..mesh.num_vertices() {
+            let p = Vec3::new(
+                mesh.vertices[i * 3] as f64,
+                mesh.vertices[i * 3 + 1] as f64,
+                mesh.vertices[i * 3 + 2] as f64,
+            );
+            let n = Vec3::new(
+                mesh.normals[i * 3] as f64,
+                mesh.normals[i * 3 + 1] as f64,
+                mesh.normals[i * 3 + 2] as f64,
+            );
+            assert!(
+                n.dot(&(p - center)) > 0.0,
+                "inward normal at vertex {i}: {n:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_open_box_manifold_not_closed() {
+        // A unit cube missing its top face: still manifold, but the rim
+        // edges are only used once so it is not closed
+        let brep = make_cube(10.0, 10.0, 10.0);
+        let mut mesh = TriangleMesh::new();
+        let full = tessellate_brep(&brep, 32);
+        for tri in full.indices.chunks(3) {
+            let zs: Vec<f32> = tri
+                .iter()
+                .map(|&i| full.vertices[i as usize * 3 + 2])
+                .collect();
+            if zs.iter().all(|&z| (z - 10.0).abs() < 1e-6) {
+                continue; // drop the top face
+            }
+            let base = mesh.num_vertices() as u32;
+            for &i in tri {
+                let v = i as usize * 3;
+                mesh.vertices.extend_from_slice(&full.vertices[v..v + 3]);
+            }
+            mesh.indices.extend_from_slice(&[base, base + 1, base + 2]);
+        }
+        assert!(mesh.is_manifold());
+        assert!(!mesh.is_closed());
+    }
+
+    #[test]
+    fn test_sag_tolerance_scales_segments_with_radius() {
+        let sag = 0.01;
+        let params = TessellationParams::from_sag(sag);
+
+        // Max chord deviation of the wall triangles: edges whose endpoints
+        // both sit on the wall dip below the surface by the sag at their
+        // midpoint
+        let wall_sag = |mesh: &TriangleMesh, radius: f64| -> f64 {
+            let mut worst = 0.0_f64;
+            for tri in mesh.indices.chunks(3) {
+                for k in 0..3 {
+                    let radial = |idx: u32| {
+                        let i = idx as usize * 3;
+                        let (x, y) = (mesh.vertices[i] as f64, mesh.vertices[i + 1] as f64);
+                        (x * x + y * y).sqrt()
+                    };
+                    let (a, b) = (tri[k], tri[(k + 1) % 3]);
+                    if (radial(a) - radius).abs() > 1e-4 || (radial(b) - radius).abs() > 1e-4 {
+                        continue;
+                    }
+                    let (ia, ib) = (a as usize * 3, b as usize * 3);
+                    let mx = (mesh.vertices[ia] + mesh.vertices[ib]) as f64 / 2.0;
+                    let my = (mesh.vertices[ia + 1] + mesh.vertices[ib + 1]) as f64 / 2.0;
+                    worst = worst.max(radius - (mx * mx + my * my).sqrt());
+                }
+            }
+            worst
+        };
+
+        // Distinct angular positions along the bottom rim = wall segments
+        let rim_segments = |mesh: &TriangleMesh, radius: f64| -> usize {
+            let mut angles: Vec<i64> = (0..mesh.num_vertices())
+                .filter(|&i| {
+                    let (x, y, z) = (
+                        mesh.vertices[i * 3] as f64,
+                        mesh.vertices[i * 3 + 1] as f64,
+                        mesh.vertices[i * 3 + 2] as f64,
+                    );
+                    z.abs() < 1e-6 && ((x * x + y * y).sqrt() - radius).abs() < 1e-4
+                })
+                .map(|i| {
+                    let a = (mesh.vertices[i * 3 + 1] as f64).atan2(mesh.vertices[i * 3] as f64);
+                    (a * 1e6).round() as i64
+                })
+                .collect();
+            angles.sort_unstable();
+            angles.dedup();
+            angles.len()
+        };
+
+        let small = tessellate_solid(&make_cylinder(1.0, 4.0, 32), &params, None);
+        let large = tessellate_solid(&make_cylinder(100.0, 4.0, 32), &params, None);
+
+        // ~23 segments at r=1 vs ~223 at r=100
+        let (seg_small, seg_large) = (rim_segments(&small, 1.0), rim_segments(&large, 100.0));
+        assert!(
+            seg_large > 5 * seg_small,
+            "expected many more segments at r=100: {seg_large} vs {seg_small}"
+        );
+        assert!(wall_sag(&small, 1.0) <= sag + 1e-6);
+        assert!(wall_sag(&large, 100.0) <= sag + 1e-6);
+    }
+
+    #[test]
+    fn test_weld_collapses_cube_duplicates() {
+        let brep = make_cube(10.0, 10.0, 10.0);
+        let mut mesh = tessellate_brep(&brep, 32);
+        assert_eq!(mesh.num_vertices(), 24); // 6 faces × 4 corners, unshared
+        let before: std::collections::BTreeSet<[i64; 3]> = (0..mesh.num_vertices())
+            .map(|i| {
+                [
+                    (mesh.vertices[i * 3] * 1e4).round() as i64,
+                    (mesh.vertices[i * 3 + 1] * 1e4).round() as i64,
+                    (mesh.vertices[i * 3 + 2] * 1e4).round() as i64,
+                ]
+            })
+            .collect();
+
+        mesh.weld(1e-6);
+
+        assert_eq!(mesh.num_vertices(), 8);
+        assert_eq!(mesh.num_triangles(), 12);
+        let after: std::collections::BTreeSet<[i64; 3]> = (0..mesh.num_vertices())
+            .map(|i| {
+                [
+                    (mesh.vertices[i * 3] * 1e4).round() as i64,
+                    (mesh.vertices[i * 3 + 1] * 1e4).round() as i64,
+                    (mesh.vertices[i * 3 + 2] * 1e4).round() as i64,
+                ]
+            })
+            .collect();
+        assert_eq!(before, after, "welding must not move any position");
+        assert!(mesh.is_watertight());
+    }
+
+    #[test]
+    fn test_cube_mesh_is_watertight() {
+        let brep = make_cube(10.0, 10.0, 10.0);
+        let mesh = tessellate_brep(&brep, 32);
+        assert!(mesh.is_watertight());
+        assert!(mesh.boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn test_cube_mesh_has_axis_aligned_vertex_normals() {
+        let brep = make_cube(10.0, 10.0, 10.0);
+        let mesh = tessellate_brep(&brep, 32);
+        assert_eq!(mesh.normals.len(), mesh.vertices.len());
+        for n in mesh.normals.chunks(3) {
+            let abs = [n[0].abs(), n[1].abs(), n[2].abs()];
+            let ones = abs.iter().filter(|&&c| (c - 1.0).abs() < 1e-5).count();
+            let zeros = abs.iter().filter(|&&c| c < 1e-5).count();
+            assert_eq!(
+                (ones, zeros),
+                (1, 2),
+                "cube face normal should be axis-aligned, got {n:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_triangle_reports_three_boundary_edges() {
+        let brep = make_cube(10.0, 10.0, 10.0);
+        let mut mesh = tessellate_brep(&brep, 32);
+        mesh.indices.truncate(mesh.indices.len() - 3);
+        assert!(!mesh.is_watertight());
+        assert_eq!(mesh.boundary_edges().len(), 3);
+    }
+
+    #[test]
+    fn test_per_face_override_refines_single_face() {
+        let brep = make_cylinder(5.0, 10.0, 8);
+        let uniform = tessellate_brep(&brep, 8);
+
+        // Refine only the lateral face to 64 segments
+        let lateral = brep
+            .topology
+            .faces
+            .iter()
+            .find(|(_, f)| {
+                brep.geometry.surfaces[f.surface_index].surface_type() == SurfaceKind::Cylinder
+            })
+            .map(|(id, _)| id)
+            .unwrap();
+        let mut overrides = HashMap::new();
+        overrides.insert(lateral, TessellationParams::from_segments(64));
+
+        let refined = tessellate_brep_refined(&brep, 8, &overrides);
+        assert!(
+            refined.num_triangles() > uniform.num_triangles(),
+            "expected refined mesh ({} tris) to exceed uniform mesh ({} tris)",
+            refined.num_triangles(),
+            uniform.num_triangles()
+        );
+    }
+
+    #[test]
+    fn test_override_triangle_count_dwarfs_default_faces() {
+        let brep = make_cylinder(5.0, 10.0, 8);
+        let lateral = brep
+            .topology
+            .faces
+            .iter()
+            .find(|(_, f)| {
+                brep.geometry.surfaces[f.surface_index].surface_type() == SurfaceKind::Cylinder
+            })
+            .map(|(id, _)| id)
+            .unwrap();
+        let mut overrides = HashMap::new();
+        overrides.insert(lateral, TessellationParams::from_segments(128));
+
+        let default = TessellationParams::from_segments(8);
+        let mesh = tessellate_solid_with_overrides(&brep, &default, &overrides);
+        let (tagged, tags) = tessellate_solid_tagged(&brep, &default, Some(&overrides));
+        assert_eq!(mesh.num_triangles(), tagged.num_triangles());
+
+        // Count triangles on the refined face versus everything else
+        let faces = solid_shell_faces(&brep);
+        let lateral_index = faces.iter().position(|&id| id == lateral).unwrap() as u32;
+        let refined = tags.iter().filter(|&&t| t == lateral_index).count();
+        let rest = tags.len() - refined;
+        assert!(
+            refined > 10 * rest,
+            "expected refined face ({refined} tris) to dwarf the caps ({rest} tris)"
+        );
+    }
+
+    #[test]
+    fn test_tessellation_deviation_cylinder_sagitta() {
+        // At 8 segments the worst deviation on a radius-50 cylinder is the
+        // chord sagitta r * (1 - cos(pi / segments))
+        let brep = make_cylinder(50.0, 10.0, 8);
+        let report = tessellation_deviation(&brep, 8);
+        let expected = 50.0 * (1.0 - (PI / 8.0).cos());
+        assert!(
+            (report.max_deviation - expected).abs() < 0.05,
+            "expected deviation ~{expected}, got {}",
+            report.max_deviation
+        );
+        // The worst point is on the lateral surface, strictly inside the radius
+        let r = (report.location.x.powi(2) + report.location.y.powi(2)).sqrt();
+        assert!(r < 50.0);
+    }
+
+    #[test]
+    fn test_tessellation_deviation_cube_is_zero() {
+        let brep = make_cube(10.0, 10.0, 10.0);
+        let report = tessellation_deviation(&brep, 8);
+        assert_eq!(report.max_deviation, 0.0);
+    }
+
+    #[test]
+    fn test_connected_components_two_cubes() {
+        let a = tessellate_brep(&make_cube(10.0, 10.0, 10.0), 32);
+        let mut merged = a.clone();
+        // Second cube well away from the first
+        let mut b = tessellate_brep(&make_cube(10.0, 10.0, 10.0), 32);
+        for chunk in b.vertices.chunks_mut(3) {
+            chunk[0] += 100.0;
+        }
+        merged.merge(&b);
+
+        let components = merged.connected_components();
+        assert_eq!(components.len(), 2);
+        for c in &components {
+            assert_eq!(c.num_triangles(), a.num_triangles());
+        }
+    }
+
+    #[test]
+    fn test_connected_components_single_body() {
+        let mesh = tessellate_brep(&make_cube(10.0, 10.0, 10.0), 32);
+        let components = mesh.connected_components();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].num_triangles(), mesh.num_triangles());
+        assert!(TriangleMesh::new().connected_components().is_empty());
+    }
+
     #[test]
     fn test_tessellate_cylinder() {
         let brep = make_cylinder(5.0, 10.0, 32);
@@ -2441,6 +4018,83 @@ mod tests {
         );
     }
 
+    /// Worst aspect ratio (longest edge over its altitude) among triangles
+    /// touching the poles of a sphere of the given radius.
+    fn max_polar_aspect(mesh: &TriangleMesh, radius: f64) -> f64 {
+        let corner = |idx: u32| {
+            let i = idx as usize * 3;
+            [
+                mesh.vertices[i] as f64,
+                mesh.vertices[i + 1] as f64,
+                mesh.vertices[i + 2] as f64,
+            ]
+        };
+        let mut worst: f64 = 0.0;
+        for tri in mesh.indices.chunks(3) {
+            let pts = [corner(tri[0]), corner(tri[1]), corner(tri[2])];
+            if !pts.iter().any(|p| p[2].abs() > 0.99 * radius) {
+                continue;
+            }
+            let edge = |a: [f64; 3], b: [f64; 3]| [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+            let e0 = edge(pts[0], pts[1]);
+            let e1 = edge(pts[0], pts[2]);
+            let e2 = edge(pts[1], pts[2]);
+            let len = |e: &[f64; 3]| (e[0] * e[0] + e[1] * e[1] + e[2] * e[2]).sqrt();
+            let cross = [
+                e0[1] * e1[2] - e0[2] * e1[1],
+                e0[2] * e1[0] - e0[0] * e1[2],
+                e0[0] * e1[1] - e0[1] * e1[0],
+            ];
+            let area = 0.5 * len(&cross);
+            if area < 1e-12 {
+                continue;
+            }
+            let longest = len(&e0).max(len(&e1)).max(len(&e2));
+            // Altitude relative to the longest edge is 2·area / longest
+            worst = worst.max(longest * longest / (2.0 * area));
+        }
+        worst
+    }
+
+    #[test]
+    fn test_pole_fan_avoids_polar_slivers() {
+        let brep = make_sphere(10.0, 64);
+        let full = TessellationParams {
+            circle_segments: 64,
+            height_segments: 1,
+            latitude_segments: 32,
+            ..Default::default()
+        };
+        let fanned = TessellationParams {
+            pole_fan_segments: Some(8),
+            ..full
+        };
+
+        let full_mesh = tessellate_solid(&brep, &full, None);
+        let fan_mesh = tessellate_solid(&brep, &fanned, None);
+
+        // The fanned mesh must still be watertight with the right volume
+        assert!(fan_mesh.is_closed(), "pole fan mesh should stay closed");
+        let expected = (4.0 / 3.0) * PI * 1000.0;
+        let vol = compute_mesh_volume(&fan_mesh).abs();
+        assert!(
+            (vol - expected).abs() < expected * 0.05,
+            "expected ~{expected}, got {vol}"
+        );
+
+        // Full-resolution lat/long slivers at the poles are gone
+        let full_aspect = max_polar_aspect(&full_mesh, 10.0);
+        let fan_aspect = max_polar_aspect(&fan_mesh, 10.0);
+        assert!(
+            full_aspect > 7.0,
+            "baseline should have polar slivers, worst aspect {full_aspect}"
+        );
+        assert!(
+            fan_aspect < 3.5,
+            "pole fan should avoid slivers, worst aspect {fan_aspect}"
+        );
+    }
+
     /// Compute signed volume of a triangle mesh using the divergence theorem.
     fn compute_mesh_volume(mesh: &TriangleMesh) -> f64 {
         let verts = &mesh.vertices;
@@ -2546,4 +4200,19 @@ mod tests {
             area
         );
     }
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_parallel_tessellation_matches_serial() {
+        use vcad_kernel_primitives::make_cylinder;
+
+        let brep = make_cylinder(5.0, 10.0, 16);
+        let params = TessellationParams::from_segments(16);
+
+        let serial = tessellate_solid(&brep, &params, None);
+        let parallel = tessellate_solid_parallel(&brep, &params, None);
+
+        assert_eq!(serial.vertices, parallel.vertices);
+        assert_eq!(serial.indices, parallel.indices);
+        assert_eq!(serial.normals, parallel.normals);
+    }
 }
@@ -261,6 +261,12 @@ impl Topology {
         solid_id
     }
 
+    /// Attach a shell to a solid as an inner void (cavity).
+    pub fn add_void_shell(&mut self, solid_id: SolidId, shell_id: ShellId) {
+        self.solids[solid_id].void_shells.push(shell_id);
+        self.shells[shell_id].solid = Some(solid_id);
+    }
+
     // =========================================================================
     // Euler operators
     // =========================================================================
@@ -295,9 +301,10 @@ impl Topology {
     ///
     /// Returns `(new_vertex, new_edge)`.
     pub fn make_edge_vertex(&mut self, he: HalfEdgeId, point: Point3) -> (VertexId, EdgeId) {
+        let v1 = self.half_edges[he].origin;
         let v_new = self.add_vertex(point);
-        let he_new = self.add_half_edge(v_new);
-        let he_twin_new = self.add_half_edge(self.half_edges[he].origin);
+        let he_new = self.add_half_edge(v1); // v1 -> v_new
+        let he_twin_new = self.add_half_edge(v_new); // v_new -> v1
 
         // Link new half-edge into the loop before he
         let prev = self.half_edges[he].prev;
@@ -319,19 +326,317 @@ impl Topology {
                 self.half_edges[tn].prev = Some(he_twin_new);
             }
             self.half_edges[he_twin_new].loop_id = self.half_edges[twin].loop_id;
-            // Update twin's origin to new vertex
-            self.half_edges[twin].origin = v_new;
+            // The twin keeps its origin (v2) and now ends at v_new
         }
 
         // Create edge between new half-edges
         let edge = self.add_edge(he_new, he_twin_new);
         // Update he's origin to new vertex
         self.half_edges[he].origin = v_new;
+        if self.vertices[v1].half_edge == Some(he) {
+            self.vertices[v1].half_edge = Some(he_new);
+        }
         self.vertices[v_new].half_edge = Some(he);
 
         (v_new, edge)
     }
 
+    /// Merge consecutive collinear edges across the whole topology.
+    ///
+    /// A vertex is removable when exactly two edges meet there, the vertex
+    /// deviates from the chord through its two neighbours by less than
+    /// `tol`, and every loop passing through it keeps at least three
+    /// half-edges after the merge. Each removal deletes the vertex, one
+    /// edge, and its two half-edges, extending the surviving edge over the
+    /// combined span — the inverse of [`Topology::make_edge_vertex`].
+    ///
+    /// Returns the number of vertices removed.
+    pub fn merge_collinear_edges(&mut self, tol: f64) -> usize {
+        let mut removed = 0;
+        loop {
+            let candidates: Vec<VertexId> = self.vertices.keys().collect();
+            let before = removed;
+            for v in candidates {
+                if self.vertices.contains_key(v) && self.try_merge_at_vertex(v, tol) {
+                    removed += 1;
+                }
+            }
+            if removed == before {
+                return removed;
+            }
+        }
+    }
+
+    /// Attempt to remove a single degree-two vertex between collinear edges.
+    fn try_merge_at_vertex(&mut self, v: VertexId, tol: f64) -> bool {
+        let outgoing: Vec<HalfEdgeId> = self
+            .half_edges
+            .iter()
+            .filter(|&(_, he)| he.origin == v)
+            .map(|(id, _)| id)
+            .collect();
+        if outgoing.is_empty() || outgoing.len() > 2 {
+            return false;
+        }
+
+        // he1 (u -> v) and he2 (v -> w) are the consecutive half-edges of
+        // the collinear chain within one loop.
+        let he2 = outgoing[0];
+        let Some(he1) = self.half_edges[he2].prev else {
+            return false;
+        };
+        let Some(he2_next) = self.half_edges[he2].next else {
+            return false;
+        };
+        if he1 == he2 {
+            return false;
+        }
+        let u = self.half_edges[he1].origin;
+        let w = self.half_edges[he2_next].origin;
+        if u == v || w == v || u == w {
+            return false;
+        }
+
+        // Deviation of v from the chord u-w must stay within tolerance
+        let pu = self.vertices[u].point;
+        let pv = self.vertices[v].point;
+        let pw = self.vertices[w].point;
+        let chord = pw - pu;
+        let chord_len = chord.norm();
+        if chord_len < tol {
+            return false;
+        }
+        if (pv - pu).cross(&chord).norm() / chord_len > tol {
+            return false;
+        }
+
+        let Some(loop1) = self.half_edges[he2].loop_id else {
+            return false;
+        };
+        if self.loop_len(loop1) < 4 {
+            return false;
+        }
+
+        match (self.half_edges[he1].twin, self.half_edges[he2].twin) {
+            (Some(t1), Some(t2)) if outgoing.len() == 2 => {
+                // The twins must run w -> v -> u consecutively in a single
+                // loop, i.e. v joins exactly the two faces of the chain.
+                if outgoing[1] != t1 || self.half_edges[t1].prev != Some(t2) {
+                    return false;
+                }
+                let Some(loop2) = self.half_edges[t2].loop_id else {
+                    return false;
+                };
+                if self.loop_len(loop2) < 4 {
+                    return false;
+                }
+
+                // Splice he2 and t2 out of their loops; he1/t1 now span u-w
+                self.half_edges[he1].next = Some(he2_next);
+                self.half_edges[he2_next].prev = Some(he1);
+                let t2_prev = self.half_edges[t2].prev;
+                self.half_edges[t1].prev = t2_prev;
+                if let Some(p) = t2_prev {
+                    self.half_edges[p].next = Some(t1);
+                }
+                self.half_edges[t1].origin = w;
+                if self.loops[loop1].half_edge == he2 {
+                    self.loops[loop1].half_edge = he1;
+                }
+                if self.loops[loop2].half_edge == t2 {
+                    self.loops[loop2].half_edge = t1;
+                }
+                if self.vertices[w].half_edge == Some(t2) {
+                    self.vertices[w].half_edge = Some(t1);
+                }
+                if let Some(edge) = self.half_edges[he2].edge {
+                    self.edges.remove(edge);
+                }
+                self.half_edges.remove(he2);
+                self.half_edges.remove(t2);
+            }
+            (None, None) if outgoing.len() == 1 => {
+                // Open boundary chain: splice out of the single loop
+                self.half_edges[he1].next = Some(he2_next);
+                self.half_edges[he2_next].prev = Some(he1);
+                if self.loops[loop1].half_edge == he2 {
+                    self.loops[loop1].half_edge = he1;
+                }
+                if let Some(edge) = self.half_edges[he2].edge {
+                    self.edges.remove(edge);
+                }
+                self.half_edges.remove(he2);
+            }
+            _ => return false,
+        }
+        self.vertices.remove(v);
+        true
+    }
+
+    /// Collapse manifold edges shorter than `min_length` across the whole
+    /// topology.
+    ///
+    /// Each collapse merges the edge's endpoints at their midpoint, removes
+    /// the edge and its two half-edges, and splices the surrounding loops.
+    /// A loop reduced to two half-edges is a degenerate sliver: its face is
+    /// dissolved and the neighbouring faces are stitched directly together,
+    /// so collapsing the short edges of a sliver face removes the face.
+    /// Edges without a twin are left alone.
+    ///
+    /// Returns the number of edges collapsed.
+    pub fn collapse_short_edges(&mut self, min_length: f64) -> usize {
+        let mut removed = 0;
+        loop {
+            let candidates: Vec<EdgeId> = self.edges.keys().collect();
+            let before = removed;
+            for e in candidates {
+                if self.edges.contains_key(e) && self.try_collapse_edge(e, min_length) {
+                    removed += 1;
+                }
+            }
+            if removed == before {
+                return removed;
+            }
+        }
+    }
+
+    /// Attempt to collapse a single edge shorter than `min_length`.
+    fn try_collapse_edge(&mut self, edge: EdgeId, min_length: f64) -> bool {
+        let he1 = self.edges[edge].half_edge;
+        if !self.half_edges.contains_key(he1) {
+            return false;
+        }
+        let Some(he2) = self.half_edges[he1].twin else {
+            return false;
+        };
+
+        let v1 = self.half_edges[he1].origin;
+        let v2 = self.half_edges[he2].origin;
+        if v1 == v2 {
+            return false;
+        }
+        let p1 = self.vertices[v1].point;
+        let p2 = self.vertices[v2].point;
+        if (p2 - p1).norm() >= min_length {
+            return false;
+        }
+
+        let (Some(loop1), Some(loop2)) =
+            (self.half_edges[he1].loop_id, self.half_edges[he2].loop_id)
+        else {
+            return false;
+        };
+        if self.loop_len(loop1) < 3 || self.loop_len(loop2) < 3 {
+            return false;
+        }
+
+        // Merge the endpoints at the midpoint, keeping v1
+        self.vertices[v1].point = Point3::from((p1.coords + p2.coords) * 0.5);
+        let redirect: Vec<HalfEdgeId> = self
+            .half_edges
+            .iter()
+            .filter(|&(_, he)| he.origin == v2)
+            .map(|(id, _)| id)
+            .collect();
+        for id in redirect {
+            self.half_edges[id].origin = v1;
+        }
+
+        // Splice both half-edges out of their loops
+        for (he, loop_id) in [(he1, loop1), (he2, loop2)] {
+            let (Some(prev), Some(next)) = (self.half_edges[he].prev, self.half_edges[he].next)
+            else {
+                continue;
+            };
+            self.half_edges[prev].next = Some(next);
+            self.half_edges[next].prev = Some(prev);
+            if self.loops[loop_id].half_edge == he {
+                self.loops[loop_id].half_edge = next;
+            }
+            if self.vertices[v1].half_edge == Some(he) {
+                self.vertices[v1].half_edge = Some(next);
+            }
+        }
+
+        self.edges.remove(edge);
+        self.half_edges.remove(he1);
+        self.half_edges.remove(he2);
+        self.vertices.remove(v2);
+
+        // A loop left with two half-edges is a degenerate sliver
+        for loop_id in [loop1, loop2] {
+            if self.loops.contains_key(loop_id) && self.loop_len(loop_id) == 2 {
+                self.dissolve_two_gon(loop_id);
+            }
+        }
+        true
+    }
+
+    /// Remove a degenerate two-half-edge loop, dissolving its face and
+    /// stitching the two neighbouring faces directly together.
+    fn dissolve_two_gon(&mut self, loop_id: LoopId) {
+        let ha = self.loops[loop_id].half_edge;
+        let Some(hb) = self.half_edges[ha].next.filter(|&n| n != ha) else {
+            return;
+        };
+
+        // Detach the loop from its face; a face whose outer boundary
+        // degenerated is removed entirely
+        if let Some(f) = self.loops[loop_id].face {
+            if self.faces.contains_key(f) {
+                if self.faces[f].outer_loop == loop_id {
+                    let shell = self.faces[f].shell;
+                    for inner in self.faces[f].inner_loops.clone() {
+                        self.loops[inner].face = None;
+                    }
+                    self.faces.remove(f);
+                    if let Some(s) = shell {
+                        self.shells[s].faces.retain(|&fc| fc != f);
+                    }
+                } else {
+                    self.faces[f].inner_loops.retain(|&il| il != loop_id);
+                }
+            }
+        }
+        self.loops.remove(loop_id);
+
+        let ta = self.half_edges[ha]
+            .twin
+            .filter(|&t| t != hb && self.half_edges.contains_key(t));
+        let tb = self.half_edges[hb]
+            .twin
+            .filter(|&t| t != ha && self.half_edges.contains_key(t));
+        if let Some(e) = self.half_edges[ha].edge {
+            self.edges.remove(e);
+        }
+        if let Some(e) = self.half_edges[hb].edge {
+            self.edges.remove(e);
+        }
+
+        let va = self.half_edges[ha].origin;
+        let vb = self.half_edges[hb].origin;
+        self.half_edges.remove(ha);
+        self.half_edges.remove(hb);
+
+        // The two sides of the sliver now bound the same vertex pair
+        match (ta, tb) {
+            (Some(ta), Some(tb)) => {
+                self.add_edge(ta, tb);
+            }
+            (Some(t), None) | (None, Some(t)) => {
+                self.half_edges[t].twin = None;
+                self.half_edges[t].edge = None;
+            }
+            (None, None) => {}
+        }
+
+        for (v, removed_he, survivor) in [(va, ha, tb), (vb, hb, ta)] {
+            if self.vertices[v].half_edge == Some(removed_he) {
+                self.vertices[v].half_edge = survivor;
+            }
+        }
+    }
+
     // =========================================================================
     // Adjacency iterators
     // =========================================================================
@@ -524,6 +829,139 @@ mod tests {
         assert_eq!(f2, Some(face_b));
     }
 
+    #[test]
+    fn test_merge_collinear_edges_open_loop() {
+        // Square outline with an extra vertex midway along the bottom edge
+        let mut topo = Topology::new();
+        let v0 = topo.add_vertex(Point3::origin());
+        let v_mid = topo.add_vertex(Point3::new(0.5, 0.0, 0.0));
+        let v1 = topo.add_vertex(Point3::new(1.0, 0.0, 0.0));
+        let v2 = topo.add_vertex(Point3::new(1.0, 1.0, 0.0));
+        let v3 = topo.add_vertex(Point3::new(0.0, 1.0, 0.0));
+
+        let hes: Vec<_> = [v0, v_mid, v1, v2, v3]
+            .iter()
+            .map(|&v| topo.add_half_edge(v))
+            .collect();
+        let loop_id = topo.add_loop(&hes);
+        assert_eq!(topo.loop_len(loop_id), 5);
+
+        assert_eq!(topo.merge_collinear_edges(1e-9), 1);
+        assert_eq!(topo.loop_len(loop_id), 4);
+        assert!(!topo.vertices.contains_key(v_mid));
+        // Non-collinear corners survive
+        let verts = topo.loop_vertices(loop_id);
+        assert_eq!(verts, vec![v0, v1, v2, v3]);
+    }
+
+    #[test]
+    fn test_merge_collinear_edges_skips_corner() {
+        let mut topo = Topology::new();
+        let v0 = topo.add_vertex(Point3::origin());
+        let v1 = topo.add_vertex(Point3::new(1.0, 0.0, 0.0));
+        let v2 = topo.add_vertex(Point3::new(1.0, 1.0, 0.0));
+        let v3 = topo.add_vertex(Point3::new(0.0, 1.0, 0.0));
+
+        let hes: Vec<_> = [v0, v1, v2, v3]
+            .iter()
+            .map(|&v| topo.add_half_edge(v))
+            .collect();
+        let loop_id = topo.add_loop(&hes);
+
+        assert_eq!(topo.merge_collinear_edges(1e-9), 0);
+        assert_eq!(topo.loop_len(loop_id), 4);
+    }
+
+    #[test]
+    fn test_collapse_short_edge_merges_endpoints() {
+        // Two quads sharing a sub-millimeter edge u0-u1
+        let mut topo = Topology::new();
+        let u0 = topo.add_vertex(Point3::origin());
+        let u1 = topo.add_vertex(Point3::new(5e-4, 0.0, 0.0));
+        let a = topo.add_vertex(Point3::new(1.0, 1.0, 0.0));
+        let b = topo.add_vertex(Point3::new(-1.0, 1.0, 0.0));
+        let c = topo.add_vertex(Point3::new(-1.0, -1.0, 0.0));
+        let d = topo.add_vertex(Point3::new(1.0, -1.0, 0.0));
+
+        let ha: Vec<_> = [u0, u1, a, b]
+            .iter()
+            .map(|&v| topo.add_half_edge(v))
+            .collect();
+        let hb: Vec<_> = [u1, u0, c, d]
+            .iter()
+            .map(|&v| topo.add_half_edge(v))
+            .collect();
+        let loop_a = topo.add_loop(&ha);
+        let loop_b = topo.add_loop(&hb);
+        topo.add_face(loop_a, 0, Orientation::Forward);
+        topo.add_face(loop_b, 1, Orientation::Forward);
+        topo.add_edge(ha[0], hb[0]);
+
+        assert_eq!(topo.collapse_short_edges(1e-3), 1);
+        assert_eq!(topo.loop_len(loop_a), 3);
+        assert_eq!(topo.loop_len(loop_b), 3);
+        assert!(!topo.vertices.contains_key(u1));
+        // Surviving endpoint moved to the midpoint
+        assert!((topo.vertices[u0].point.x - 2.5e-4).abs() < 1e-12);
+        assert_eq!(topo.edges.len(), 0);
+        assert_eq!(topo.faces.len(), 2);
+    }
+
+    #[test]
+    fn test_collapse_short_edge_removes_degenerate_faces() {
+        // Two triangles sharing a tiny edge: collapsing it leaves two-sided
+        // loops, so both faces dissolve
+        let mut topo = Topology::new();
+        let v0 = topo.add_vertex(Point3::origin());
+        let v1 = topo.add_vertex(Point3::new(5e-4, 0.0, 0.0));
+        let v2 = topo.add_vertex(Point3::new(0.5, 1.0, 0.0));
+        let v3 = topo.add_vertex(Point3::new(0.5, -1.0, 0.0));
+
+        let ha: Vec<_> = [v0, v1, v2]
+            .iter()
+            .map(|&v| topo.add_half_edge(v))
+            .collect();
+        let hb: Vec<_> = [v1, v0, v3]
+            .iter()
+            .map(|&v| topo.add_half_edge(v))
+            .collect();
+        let loop_a = topo.add_loop(&ha);
+        let loop_b = topo.add_loop(&hb);
+        topo.add_face(loop_a, 0, Orientation::Forward);
+        topo.add_face(loop_b, 1, Orientation::Forward);
+        topo.add_edge(ha[0], hb[0]);
+
+        assert_eq!(topo.collapse_short_edges(1e-3), 1);
+        assert!(topo.faces.is_empty());
+        assert!(topo.loops.is_empty());
+        assert!(topo.edges.is_empty());
+    }
+
+    #[test]
+    fn test_collapse_short_edges_keeps_long_edges() {
+        let mut topo = Topology::new();
+        let v0 = topo.add_vertex(Point3::origin());
+        let v1 = topo.add_vertex(Point3::new(1.0, 0.0, 0.0));
+        let v2 = topo.add_vertex(Point3::new(0.5, 1.0, 0.0));
+        let v3 = topo.add_vertex(Point3::new(0.5, -1.0, 0.0));
+
+        let ha: Vec<_> = [v0, v1, v2]
+            .iter()
+            .map(|&v| topo.add_half_edge(v))
+            .collect();
+        let hb: Vec<_> = [v1, v0, v3]
+            .iter()
+            .map(|&v| topo.add_half_edge(v))
+            .collect();
+        topo.add_loop(&ha);
+        topo.add_loop(&hb);
+        topo.add_edge(ha[0], hb[0]);
+
+        assert_eq!(topo.collapse_short_edges(1e-3), 0);
+        assert_eq!(topo.vertices.len(), 4);
+        assert_eq!(topo.edges.len(), 1);
+    }
+
     #[test]
     fn test_half_edge_dest() {
         let mut topo = Topology::new();
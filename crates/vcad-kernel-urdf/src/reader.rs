@@ -197,6 +197,7 @@ impl<'a> UrdfReader<'a> {
                 Node {
                     id: node_id,
                     name: Some(link.name.clone()),
+                    material: None,
                     op: CsgOp::Cube {
                         size: Vec3::new(0.01, 0.01, 0.01), // 1cm placeholder
                     },
@@ -221,6 +222,7 @@ impl<'a> UrdfReader<'a> {
             Node {
                 id: geom_node_id,
                 name: Some(format!("{}_geom", link.name)),
+                material: None,
                 op: geom_op,
             },
         ));
@@ -250,6 +252,7 @@ impl<'a> UrdfReader<'a> {
                     Node {
                         id: rotate_id,
                         name: Some(format!("{}_rotate", link.name)),
+                        material: None,
                         op: CsgOp::Rotate {
                             child: geom_node_id,
                             angles: Vec3::new(rpy_deg[0], rpy_deg[1], rpy_deg[2]),
@@ -264,6 +267,7 @@ impl<'a> UrdfReader<'a> {
                         Node {
                             id: translate_id,
                             name: Some(format!("{}_translate", link.name)),
+                            material: None,
                             op: CsgOp::Translate {
                                 child: rotate_id,
                                 offset: Vec3::new(xyz_mm[0], xyz_mm[1], xyz_mm[2]),
@@ -281,6 +285,7 @@ impl<'a> UrdfReader<'a> {
                     Node {
                         id: translate_id,
                         name: Some(format!("{}_translate", link.name)),
+                        material: None,
                         op: CsgOp::Translate {
                             child: geom_node_id,
                             offset: Vec3::new(xyz_mm[0], xyz_mm[1], xyz_mm[2]),
@@ -593,9 +598,11 @@ mod tests {
         let doc = read_urdf_from_str(urdf).unwrap();
 
         // Find the box node
-        let box_node = doc.nodes.values().find(|n| {
-            matches!(n.op, CsgOp::Cube { .. })
-        }).unwrap();
+        let box_node = doc
+            .nodes
+            .values()
+            .find(|n| matches!(n.op, CsgOp::Cube { .. }))
+            .unwrap();
 
         if let CsgOp::Cube { size } = &box_node.op {
             // 0.1m = 100mm
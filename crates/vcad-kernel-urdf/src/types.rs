@@ -156,7 +156,6 @@ impl Origin {
             .map(|s| parse_xyz(s))
             .unwrap_or([0.0, 0.0, 0.0])
     }
-
 }
 
 /// Parse a space-separated XYZ/RPY string into an array.
@@ -239,7 +238,6 @@ pub struct MeshGeom {
     pub scale: Option<String>,
 }
 
-
 /// Material reference or inline definition.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MaterialRef {
@@ -1,6 +1,14 @@
 //! WASM bindings for the vcad B-rep kernel.
 //!
 //! Exposes the [`Solid`] type for use in JavaScript/TypeScript via wasm-bindgen.
+//!
+//! The checked-in `packages/kernel-wasm` artifacts (`.d.ts`, JS glue,
+//! `.wasm`) are generated from this crate with `wasm-pack build` and must
+//! be regenerated as a set whenever the `#[wasm_bindgen]` surface changes.
+//! Bindings added since the last regeneration — `polygonPrism`, `pyramid`,
+//! `toDxfView`, `toDxfDrawing`, and `getMeshBuffers` — exist here but not
+//! in the shipped package, so the app cannot call them until the package
+//! is rebuilt and all three artifacts are checked in together.
 
 use serde::{Deserialize, Serialize};
 use vcad_kernel::vcad_kernel_math::{Point2, Point3, Vec3};
@@ -8,6 +16,37 @@ use vcad_kernel::vcad_kernel_sketch::{SketchProfile, SketchSegment};
 use wasm_bindgen::prelude::*;
 use wasmosis::module;
 
+/// Log to the browser console; a no-op on native targets so the
+/// evaluation paths stay testable with plain `cargo test`.
+macro_rules! console_log {
+    ($($arg:tt)*) => {{
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::log_1(&format!($($arg)*).into());
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = format_args!($($arg)*);
+    }};
+}
+
+/// Warn to the browser console; a no-op on native targets.
+macro_rules! console_warn {
+    ($($arg:tt)*) => {{
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::warn_1(&format!($($arg)*).into());
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = format_args!($($arg)*);
+    }};
+}
+
+/// Report an error to the browser console; a no-op on native targets.
+macro_rules! console_error {
+    ($($arg:tt)*) => {{
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::error_1(&format!($($arg)*).into());
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = format_args!($($arg)*);
+    }};
+}
+
 /// Version string for verifying correct WASM build is loaded in browser.
 const KERNEL_VERSION: &str = "2025-02-03-geom-debug";
 
@@ -25,7 +64,101 @@ pub fn init() {
     #[cfg(feature = "console_error_panic_hook")]
     console_error_panic_hook::set_once();
     // Version marker to verify correct WASM is loaded
-    web_sys::console::log_1(&format!("[WASM] vcad-kernel-wasm {} loaded", KERNEL_VERSION).into());
+    console_log!("[WASM] vcad-kernel-wasm {} loaded", KERNEL_VERSION);
+}
+
+// =========================================================================
+// Tessellation defaults
+// =========================================================================
+
+thread_local! {
+    static DEFAULT_SEGMENTS: std::cell::Cell<u32> = const { std::cell::Cell::new(32) };
+    static DEFAULT_CHORD_TOLERANCE: std::cell::Cell<f64> = const { std::cell::Cell::new(0.0) };
+}
+
+/// Set the default segment count used when a `segments` argument is omitted.
+///
+/// Lets a viewer set quality in one place instead of threading a segments
+/// argument through every call. Clamped to a minimum of 3.
+#[wasm_bindgen(js_name = setDefaultSegments)]
+pub fn set_default_segments(n: u32) {
+    DEFAULT_SEGMENTS.with(|c| c.set(n.max(3)));
+}
+
+/// Get the current default segment count.
+#[wasm_bindgen(js_name = getDefaultSegments)]
+pub fn get_default_segments() -> u32 {
+    DEFAULT_SEGMENTS.with(|c| c.get())
+}
+
+/// Set the default chord tolerance in mm (0 disables it).
+///
+/// When set, calls that omit `segments` derive the segment count from the
+/// relevant radius so the chord sagitta stays below the tolerance, instead
+/// of using the fixed default from [`set_default_segments`].
+#[wasm_bindgen(js_name = setDefaultChordTolerance)]
+pub fn set_default_chord_tolerance(t: f64) {
+    DEFAULT_CHORD_TOLERANCE.with(|c| c.set(t.max(0.0)));
+}
+
+/// Get the current default chord tolerance in mm (0 when disabled).
+#[wasm_bindgen(js_name = getDefaultChordTolerance)]
+pub fn get_default_chord_tolerance() -> f64 {
+    DEFAULT_CHORD_TOLERANCE.with(|c| c.get())
+}
+
+/// Set the geometry tolerance in mm — how close two points must be to count
+/// as coincident.
+///
+/// Consumed throughout the kernel (boolean sewing and vertex welding,
+/// trimming, STEP import) so models at unusual scales can set "coincident"
+/// in one place instead of per call. Non-positive values are ignored.
+#[wasm_bindgen(js_name = setGeometryTolerance)]
+pub fn set_geometry_tolerance(tol: f64) {
+    vcad_kernel::vcad_kernel_math::set_geometry_tolerance(tol);
+}
+
+/// Get the current geometry tolerance in mm.
+#[wasm_bindgen(js_name = getGeometryTolerance)]
+pub fn get_geometry_tolerance() -> f64 {
+    vcad_kernel::vcad_kernel_math::geometry_tolerance()
+}
+
+/// Segment count for a circle of the given radius with the given sagitta.
+fn segments_from_tolerance(radius: f64, tol: f64) -> u32 {
+    // Sagitta of a chord spanning angle θ on radius r: s = r·(1 - cos(θ/2)).
+    // Solve s <= tol for the number of segments n = 2π/θ.
+    if radius <= 0.0 || tol >= radius {
+        return 3;
+    }
+    let theta = 2.0 * (1.0 - tol / radius).acos();
+    ((2.0 * std::f64::consts::PI / theta).ceil() as u32).clamp(3, 512)
+}
+
+/// Resolve an optional `segments` argument against the module defaults.
+///
+/// Explicit values win; otherwise the chord tolerance (if set) is applied to
+/// `radius`, falling back to the default segment count.
+fn effective_segments(explicit: Option<u32>, radius: f64) -> u32 {
+    if let Some(n) = explicit {
+        return n;
+    }
+    let tol = DEFAULT_CHORD_TOLERANCE.with(|c| c.get());
+    if tol > 0.0 && radius > 0.0 {
+        return segments_from_tolerance(radius, tol);
+    }
+    DEFAULT_SEGMENTS.with(|c| c.get())
+}
+
+/// Resolve segments for meshing an existing solid, using half its bounding
+/// box diagonal as the characteristic radius for the chord tolerance.
+fn mesh_segments(inner: &vcad_kernel::Solid, explicit: Option<u32>) -> u32 {
+    let (min, max) = inner.bounding_box();
+    let dx = max[0] - min[0];
+    let dy = max[1] - min[1];
+    let dz = max[2] - min[2];
+    let radius = 0.5 * (dx * dx + dy * dy + dz * dz).sqrt();
+    effective_segments(explicit, radius)
 }
 
 /// Triangle mesh output for rendering.
@@ -37,6 +170,59 @@ pub struct WasmMesh {
     pub indices: Vec<u32>,
 }
 
+/// Tessellated mesh held in Rust memory, exposing its buffers as typed
+/// arrays without the per-element serialization `getMesh` pays.
+#[wasm_bindgen]
+pub struct MeshBuffers {
+    mesh: vcad_kernel_tessellate::TriangleMesh,
+}
+
+#[wasm_bindgen]
+impl MeshBuffers {
+    /// Number of vertices in the mesh.
+    #[wasm_bindgen(js_name = vertexCount)]
+    pub fn vertex_count(&self) -> u32 {
+        (self.mesh.vertices.len() / 3) as u32
+    }
+
+    /// Number of triangles in the mesh.
+    #[wasm_bindgen(js_name = triangleCount)]
+    pub fn triangle_count(&self) -> u32 {
+        (self.mesh.indices.len() / 3) as u32
+    }
+
+    /// Vertex positions as a `Float32Array` view over the Rust buffer:
+    /// `[x0, y0, z0, x1, y1, z1, ...]`.
+    ///
+    /// The view aliases wasm memory and is invalidated if the module's
+    /// memory grows; call `.slice()` on it if the data must outlive
+    /// further kernel calls.
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen(getter)]
+    pub fn positions(&self) -> js_sys::Float32Array {
+        unsafe { js_sys::Float32Array::view(&self.mesh.vertices) }
+    }
+
+    /// Triangle indices as a `Uint32Array` view over the Rust buffer.
+    ///
+    /// Same lifetime caveat as `positions`.
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> js_sys::Uint32Array {
+        unsafe { js_sys::Uint32Array::view(&self.mesh.indices) }
+    }
+
+    /// Vertex normals as a `Float32Array` view over the Rust buffer.
+    /// Empty when the tessellation did not compute normals.
+    ///
+    /// Same lifetime caveat as `positions`.
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen(getter)]
+    pub fn normals(&self) -> js_sys::Float32Array {
+        unsafe { js_sys::Float32Array::view(&self.mesh.normals) }
+    }
+}
+
 /// A 2D sketch segment (line or arc) for WASM input.
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -175,6 +361,115 @@ impl WasmSketchProfile {
     }
 }
 
+// =========================================================================
+// Panic boundary
+// =========================================================================
+
+/// Run a kernel operation inside a panic boundary.
+///
+/// A panic in the kernel (e.g. an unexpected topology state in a boolean)
+/// is caught and converted into a thrown `JsError` so the caller can report
+/// the failure and keep running — but only on targets that unwind. The
+/// standard `wasm32-unknown-unknown` build lowers panics to traps, which
+/// `catch_unwind` cannot intercept: in the browser the instance still
+/// aborts and the page must reload. The boundary is effective in native
+/// builds (and the tests below), and becomes effective on wasm only when
+/// the module is compiled with an unwinding panic runtime (e.g.
+/// `-Zbuild-std` with `panic=unwind` and wasm exception handling). Until
+/// the app ships such a build, treat this as defense in depth plus a
+/// consistent error message, not as browser crash recovery.
+fn catch_kernel_panic<T>(context: &str, f: impl FnOnce() -> T) -> Result<T, JsError> {
+    catch_kernel_panic_msg(context, f).map_err(|msg| JsError::new(&msg))
+}
+
+/// String-error variant of [`catch_kernel_panic`].
+///
+/// `JsError` can only be constructed on wasm targets, so the
+/// catch-and-describe logic lives here where native tests can exercise it.
+/// The same caveat applies: on a trap-lowering wasm build the closure's
+/// panic never reaches the `map_err` below.
+fn catch_kernel_panic_msg<T>(context: &str, f: impl FnOnce() -> T) -> Result<T, String> {
+    // AssertUnwindSafe is sound here: kernel operations take their inputs by
+    // shared reference and build fresh outputs, so a caught panic cannot
+    // leave the inputs in a torn state.
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).map_err(|payload| {
+        let msg = payload
+            .downcast_ref::<String>()
+            .map(String::as_str)
+            .or_else(|| payload.downcast_ref::<&str>().copied())
+            .unwrap_or("unknown panic");
+        console_error!("[WASM] Kernel panic in {context}: {msg}");
+        format!("Kernel panic in {context}: {msg}")
+    })
+}
+
+/// Check the radial fit of a shaft inside a hole in another solid.
+///
+/// Pairs the shaft's cylindrical faces with coaxial bores in `hole` and
+/// returns `{ shaftRadius, holeRadius, clearance, fit }` for the tightest
+/// pair, where `clearance` is the radial gap in mm (negative for a press
+/// fit) and `fit` is `"clearance"` or `"interference"`. Errors when no
+/// cylindrical face pair lines up.
+#[wasm_bindgen(js_name = assemblyFit)]
+pub fn assembly_fit(shaft: &Solid, hole: &Solid) -> Result<JsValue, JsError> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct WasmAssemblyFit {
+        shaft_radius: f64,
+        hole_radius: f64,
+        clearance: f64,
+        fit: &'static str,
+    }
+
+    let result = shaft
+        .inner
+        .assembly_fit(&hole.inner)
+        .ok_or_else(|| JsError::new("No coaxial cylindrical shaft/hole face pair found"))?;
+
+    serde_wasm_bindgen::to_value(&WasmAssemblyFit {
+        shaft_radius: result.shaft_radius,
+        hole_radius: result.hole_radius,
+        clearance: result.clearance,
+        fit: if result.clearance >= 0.0 {
+            "clearance"
+        } else {
+            "interference"
+        },
+    })
+    .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+}
+
+/// Result of [`Solid::auto_orient_for_print`]: the reoriented solid plus
+/// the chosen down direction and its score.
+#[wasm_bindgen]
+pub struct WasmPrintOrientation {
+    inner: vcad_kernel::PrintOrientation,
+}
+
+#[wasm_bindgen]
+impl WasmPrintOrientation {
+    /// The reoriented solid, translated to rest on the z = 0 bed.
+    #[wasm_bindgen(js_name = getSolid)]
+    pub fn get_solid(&self) -> Solid {
+        Solid {
+            inner: self.inner.solid.clone(),
+        }
+    }
+
+    /// The chosen "down" direction in the original orientation, as [x, y, z].
+    #[wasm_bindgen(getter)]
+    pub fn down(&self) -> Vec<f64> {
+        self.inner.down.to_vec()
+    }
+
+    /// Score of the winning orientation: overhang area in mm² for
+    /// `min_overhang`, build height in mm for `min_height`.
+    #[wasm_bindgen(getter)]
+    pub fn score(&self) -> f64 {
+        self.inner.score
+    }
+}
+
 /// A 3D solid geometry object.
 ///
 /// Create solids from primitives, combine with boolean operations,
@@ -199,53 +494,101 @@ impl Solid {
     }
 
     /// Create a box with corner at origin and dimensions (sx, sy, sz).
+    ///
+    /// Errors if any dimension is zero, negative, or NaN.
     #[wasm_bindgen(js_name = cube)]
-    pub fn cube(sx: f64, sy: f64, sz: f64) -> Solid {
+    pub fn cube(sx: f64, sy: f64, sz: f64) -> Result<Solid, JsError> {
         let solid = Solid {
-            inner: vcad_kernel::Solid::cube(sx, sy, sz),
+            inner: vcad_kernel::Solid::cube(sx, sy, sz)
+                .map_err(|e| JsError::new(&e.to_string()))?,
         };
         let (min, max) = solid.inner.bounding_box();
-        web_sys::console::log_1(&format!(
+        console_log!(
             "[WASM] Created cube({},{},{}): bbox=[{:.2},{:.2},{:.2}]->[{:.2},{:.2},{:.2}]",
-            sx, sy, sz, min[0], min[1], min[2], max[0], max[1], max[2]
-        ).into());
-        solid
+            sx,
+            sy,
+            sz,
+            min[0],
+            min[1],
+            min[2],
+            max[0],
+            max[1],
+            max[2]
+        );
+        Ok(solid)
     }
 
     /// Create a cylinder along Z axis with given radius and height.
+    ///
+    /// Errors if radius or height is not positive or segments < 3.
     #[wasm_bindgen(js_name = cylinder)]
-    pub fn cylinder(radius: f64, height: f64, segments: Option<u32>) -> Solid {
-        let segs = segments.unwrap_or(32);
+    pub fn cylinder(radius: f64, height: f64, segments: Option<u32>) -> Result<Solid, JsError> {
+        let segs = effective_segments(segments, radius);
         let solid = Solid {
-            inner: vcad_kernel::Solid::cylinder(radius, height, segs),
+            inner: vcad_kernel::Solid::cylinder(radius, height, segs)
+                .map_err(|e| JsError::new(&e.to_string()))?,
         };
         let (min, max) = solid.inner.bounding_box();
-        web_sys::console::log_1(&format!(
-            "[WASM] Created cylinder(r={}, h={}, segs={}): bbox=[{:.2},{:.2},{:.2}]->[{:.2},{:.2},{:.2}]",
-            radius, height, segs, min[0], min[1], min[2], max[0], max[1], max[2]
-        ).into());
-        solid
+        console_log!("[WASM] Created cylinder(r={}, h={}, segs={}): bbox=[{:.2},{:.2},{:.2}]->[{:.2},{:.2},{:.2}]",
+            radius, height, segs, min[0], min[1], min[2], max[0], max[1], max[2]);
+        Ok(solid)
     }
 
     /// Create a sphere centered at origin with given radius.
+    ///
+    /// Errors if the radius is not positive or segments < 3.
     #[wasm_bindgen(js_name = sphere)]
-    pub fn sphere(radius: f64, segments: Option<u32>) -> Solid {
-        Solid {
-            inner: vcad_kernel::Solid::sphere(radius, segments.unwrap_or(32)),
-        }
+    pub fn sphere(radius: f64, segments: Option<u32>) -> Result<Solid, JsError> {
+        Ok(Solid {
+            inner: vcad_kernel::Solid::sphere(radius, effective_segments(segments, radius))
+                .map_err(|e| JsError::new(&e.to_string()))?,
+        })
     }
 
     /// Create a cone/frustum along Z axis.
+    ///
+    /// Errors if the radii are negative, both zero, the height is not
+    /// positive, or segments < 3.
     #[wasm_bindgen(js_name = cone)]
-    pub fn cone(radius_bottom: f64, radius_top: f64, height: f64, segments: Option<u32>) -> Solid {
-        Solid {
+    pub fn cone(
+        radius_bottom: f64,
+        radius_top: f64,
+        height: f64,
+        segments: Option<u32>,
+    ) -> Result<Solid, JsError> {
+        Ok(Solid {
             inner: vcad_kernel::Solid::cone(
                 radius_bottom,
                 radius_top,
                 height,
-                segments.unwrap_or(32),
-            ),
-        }
+                effective_segments(segments, radius_bottom.max(radius_top)),
+            )
+            .map_err(|e| JsError::new(&e.to_string()))?,
+        })
+    }
+
+    /// Create a regular polygonal prism with `sides` planar lateral faces,
+    /// vertices on a circle of `circumradius`, extruded along Z.
+    ///
+    /// Errors if `sides < 3` or a dimension is zero, negative, or NaN.
+    #[wasm_bindgen(js_name = polygonPrism)]
+    pub fn polygon_prism(sides: u32, circumradius: f64, height: f64) -> Result<Solid, JsError> {
+        Ok(Solid {
+            inner: vcad_kernel::Solid::polygon_prism(sides, circumradius, height)
+                .map_err(|e| JsError::new(&e.to_string()))?,
+        })
+    }
+
+    /// Create a pyramid over a regular polygon base with the apex at
+    /// `(0, 0, height)`.
+    ///
+    /// Errors if `base_sides < 3` or a dimension is zero, negative, or NaN.
+    #[wasm_bindgen(js_name = pyramid)]
+    pub fn pyramid(base_sides: u32, circumradius: f64, height: f64) -> Result<Solid, JsError> {
+        Ok(Solid {
+            inner: vcad_kernel::Solid::pyramid(base_sides, circumradius, height)
+                .map_err(|e| JsError::new(&e.to_string()))?,
+        })
     }
 
     /// Create a solid by extruding a 2D sketch profile.
@@ -388,8 +731,11 @@ impl Solid {
         path_segments: Option<u32>,
         arc_segments: Option<u32>,
         orientation: Option<f64>,
+        frame_mode: Option<String>,
+        radius_end: Option<f64>,
+        pitch_end: Option<f64>,
     ) -> Result<Solid, JsError> {
-        use vcad_kernel::vcad_kernel_sweep::{Helix, SweepOptions};
+        use vcad_kernel::vcad_kernel_sweep::{FrameMode, Helix, SweepOptions};
 
         let profile: WasmSketchProfile = serde_wasm_bindgen::from_value(profile_js)
             .map_err(|e| JsError::new(&format!("Invalid profile: {}", e)))?;
@@ -399,7 +745,26 @@ impl Solid {
             .to_kernel_profile_centered()
             .map_err(|e| JsError::new(&e))?;
 
-        let path = Helix::new(radius, pitch, height, turns);
+        let path = Helix::tapered(
+            radius,
+            radius_end.unwrap_or(radius),
+            pitch,
+            pitch_end.unwrap_or(pitch),
+            height,
+            turns,
+        );
+
+        let frame_mode = match frame_mode.as_deref() {
+            None | Some("parallel_transport") => FrameMode::ParallelTransport,
+            Some("frenet") => FrameMode::Frenet,
+            Some("fixed_up") => FrameMode::FixedUp(Vec3::z()),
+            Some(other) => {
+                return Err(JsError::new(&format!(
+                "Unknown frame mode '{}' (expected 'frenet', 'parallel_transport', or 'fixed_up')",
+                other
+            )))
+            }
+        };
 
         let options = SweepOptions {
             twist_angle: twist_angle.unwrap_or(0.0),
@@ -408,6 +773,7 @@ impl Solid {
             path_segments: path_segments.unwrap_or(0),
             arc_segments: arc_segments.unwrap_or(8),
             orientation_angle: orientation.unwrap_or(0.0),
+            frame_mode,
         };
 
         vcad_kernel::Solid::sweep(kernel_profile, &path, options)
@@ -449,134 +815,63 @@ impl Solid {
 
     /// Boolean union (self ∪ other).
     #[wasm_bindgen(js_name = union)]
-    pub fn union(&self, other: &Solid) -> Solid {
-        Solid {
+    pub fn union(&self, other: &Solid) -> Result<Solid, JsError> {
+        catch_kernel_panic("union", || Solid {
             inner: self.inner.union(&other.inner),
-        }
+        })
     }
 
     /// Boolean difference (self − other).
     #[wasm_bindgen(js_name = difference)]
-    pub fn difference(&self, other: &Solid) -> Solid {
-        // Log input solid info with more detail
-        let self_tris = self.inner.num_triangles();
-        let other_tris = other.inner.num_triangles();
-
-        // Get detailed info about inputs
-        let (self_min, self_max) = self.inner.bounding_box();
-        let (other_min, other_max) = other.inner.bounding_box();
-
-        web_sys::console::log_1(&format!(
-            "[WASM] Boolean difference inputs:\n  self: {} tris, bbox=[{:.2},{:.2},{:.2}]->[{:.2},{:.2},{:.2}]\n  other: {} tris, bbox=[{:.2},{:.2},{:.2}]->[{:.2},{:.2},{:.2}]",
-            self_tris, self_min[0], self_min[1], self_min[2], self_max[0], self_max[1], self_max[2],
-            other_tris, other_min[0], other_min[1], other_min[2], other_max[0], other_max[1], other_max[2]
-        ).into());
-
-        let result = Solid {
+    pub fn difference(&self, other: &Solid) -> Result<Solid, JsError> {
+        catch_kernel_panic("difference", || Solid {
             inner: self.inner.difference(&other.inner),
-        };
-
-        let result_tris_before_mesh = result.inner.num_triangles();
-        let (result_min, result_max) = result.inner.bounding_box();
-        web_sys::console::log_1(&format!(
-            "[WASM] Difference result: {} tris, bbox=[{:.2},{:.2},{:.2}]->[{:.2},{:.2},{:.2}]",
-            result_tris_before_mesh,
-            result_min[0], result_min[1], result_min[2],
-            result_max[0], result_max[1], result_max[2]
-        ).into());
-
-        let mesh = result.inner.to_mesh(32);
-        let tris = mesh.indices.len() / 3;
-        let verts = mesh.vertices.len() / 3;
-        web_sys::console::log_1(&format!("[WASM] Difference mesh (32 segs): {} triangles, {} vertices", tris, verts).into());
-
-        // Analyze the mesh to find any problematic triangles
-        // Check for triangles with NEGATIVE x or y coordinates (the "ears")
-        let mut negative_x_tris = Vec::new();
-        let mut negative_y_tris = Vec::new();
-        // Also check triangles on z=0 plane (bottom cap)
-        let mut z0_cap_tris = Vec::new();
-
-        for i in (0..mesh.indices.len()).step_by(3) {
-            let i0 = mesh.indices[i] as usize * 3;
-            let i1 = mesh.indices[i + 1] as usize * 3;
-            let i2 = mesh.indices[i + 2] as usize * 3;
-            let v0 = [mesh.vertices[i0], mesh.vertices[i0 + 1], mesh.vertices[i0 + 2]];
-            let v1 = [mesh.vertices[i1], mesh.vertices[i1 + 1], mesh.vertices[i1 + 2]];
-            let v2 = [mesh.vertices[i2], mesh.vertices[i2 + 1], mesh.vertices[i2 + 2]];
-
-            // Check for any vertex with negative x
-            if v0[0] < -0.01 || v1[0] < -0.01 || v2[0] < -0.01 {
-                negative_x_tris.push(format!(
-                    "({:.2},{:.2},{:.2})-({:.2},{:.2},{:.2})-({:.2},{:.2},{:.2})",
-                    v0[0], v0[1], v0[2], v1[0], v1[1], v1[2], v2[0], v2[1], v2[2]
-                ));
-            }
-
-            // Check for any vertex with negative y
-            if v0[1] < -0.01 || v1[1] < -0.01 || v2[1] < -0.01 {
-                negative_y_tris.push(format!(
-                    "({:.2},{:.2},{:.2})-({:.2},{:.2},{:.2})-({:.2},{:.2},{:.2})",
-                    v0[0], v0[1], v0[2], v1[0], v1[1], v1[2], v2[0], v2[1], v2[2]
-                ));
-            }
-
-            // Check triangles on z=0 plane (the bottom cap where ears appear)
-            if v0[2].abs() < 0.1 && v1[2].abs() < 0.1 && v2[2].abs() < 0.1 {
-                z0_cap_tris.push(format!(
-                    "({:.2},{:.2},{:.2})-({:.2},{:.2},{:.2})-({:.2},{:.2},{:.2})",
-                    v0[0], v0[1], v0[2], v1[0], v1[1], v1[2], v2[0], v2[1], v2[2]
-                ));
-            }
-        }
-
-        web_sys::console::log_1(&format!("[WASM] Triangles with NEGATIVE x: {}", negative_x_tris.len()).into());
-        for (i, tri) in negative_x_tris.iter().take(10).enumerate() {
-            web_sys::console::log_1(&format!("[WASM]   neg_x tri {}: {}", i, tri).into());
-        }
+        })
+    }
 
-        web_sys::console::log_1(&format!("[WASM] Triangles with NEGATIVE y: {}", negative_y_tris.len()).into());
-        for (i, tri) in negative_y_tris.iter().take(10).enumerate() {
-            web_sys::console::log_1(&format!("[WASM]   neg_y tri {}: {}", i, tri).into());
+    /// Return a solid whose tessellated mesh is guaranteed to have outward,
+    /// consistently wound triangles.
+    ///
+    /// Boolean results can leave faces with inverted orientation that render
+    /// as holes or dark patches; call this before handing a solid to the
+    /// viewer. The result is mesh-only.
+    #[wasm_bindgen(js_name = fixNormalsForRendering)]
+    pub fn fix_normals_for_rendering(&self) -> Solid {
+        Solid {
+            inner: self.inner.fix_normals_for_rendering(),
         }
+    }
 
-        web_sys::console::log_1(&format!("[WASM] Triangles on z=0 cap: {}", z0_cap_tris.len()).into());
-        for (i, tri) in z0_cap_tris.iter().enumerate() {
-            web_sys::console::log_1(&format!("[WASM]   z0_cap tri {}: {}", i, tri).into());
+    /// Reconstruct a B-rep approximation of a mesh-only solid (e.g. an
+    /// imported STL) by segmenting its triangles into smooth regions and
+    /// fitting planes, cylinders, and spheres within `tolerance` mm.
+    ///
+    /// The result supports STEP export and exact queries again. B-rep and
+    /// empty solids are returned unchanged.
+    #[wasm_bindgen(js_name = fitSurfaces)]
+    pub fn fit_surfaces(&self, tolerance: f64) -> Solid {
+        Solid {
+            inner: self.inner.fit_surfaces(tolerance),
         }
+    }
 
-        // Compute actual bounding box from mesh
-        let mut min_x = f32::INFINITY;
-        let mut max_x = f32::NEG_INFINITY;
-        let mut min_y = f32::INFINITY;
-        let mut max_y = f32::NEG_INFINITY;
-        let mut min_z = f32::INFINITY;
-        let mut max_z = f32::NEG_INFINITY;
-        for i in (0..mesh.vertices.len()).step_by(3) {
-            let x = mesh.vertices[i];
-            let y = mesh.vertices[i + 1];
-            let z = mesh.vertices[i + 2];
-            min_x = min_x.min(x);
-            max_x = max_x.max(x);
-            min_y = min_y.min(y);
-            max_y = max_y.max(y);
-            min_z = min_z.min(z);
-            max_z = max_z.max(z);
+    /// Remove faces lying entirely inside the solid's volume and re-sew.
+    ///
+    /// Cleans up internal walls left behind by messy union chains. `tol` is
+    /// the probe offset in mm used to sample both sides of each face.
+    #[wasm_bindgen(js_name = removeInternalFaces)]
+    pub fn remove_internal_faces(&self, tol: f64) -> Solid {
+        Solid {
+            inner: self.inner.remove_internal_faces(tol),
         }
-        web_sys::console::log_1(&format!(
-            "[WASM] Mesh BBox: [{:.2},{:.2},{:.2}] -> [{:.2},{:.2},{:.2}]",
-            min_x, min_y, min_z, max_x, max_y, max_z
-        ).into());
-
-        result
     }
 
     /// Boolean intersection (self ∩ other).
     #[wasm_bindgen(js_name = intersection)]
-    pub fn intersection(&self, other: &Solid) -> Solid {
-        Solid {
+    pub fn intersection(&self, other: &Solid) -> Result<Solid, JsError> {
+        catch_kernel_panic("intersection", || Solid {
             inner: self.inner.intersection(&other.inner),
-        }
+        })
     }
 
     // =========================================================================
@@ -607,32 +902,154 @@ impl Solid {
         }
     }
 
+    /// Scale the solid by (sx, sy, sz) about the point (cx, cy, cz), which
+    /// stays fixed — e.g. the part's center for an in-place resize.
+    #[wasm_bindgen(js_name = scaleAbout)]
+    pub fn scale_about(&self, cx: f64, cy: f64, cz: f64, sx: f64, sy: f64, sz: f64) -> Solid {
+        Solid {
+            inner: self.inner.scale_about(cx, cy, cz, sx, sy, sz),
+        }
+    }
+
+    /// Rotate the solid by angle_deg degrees about the axis through
+    /// (px, py, pz) with direction (ax, ay, az). Points on the axis stay
+    /// fixed.
+    #[wasm_bindgen(js_name = rotateAboutAxis)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn rotate_about_axis(
+        &self,
+        px: f64,
+        py: f64,
+        pz: f64,
+        ax: f64,
+        ay: f64,
+        az: f64,
+        angle_deg: f64,
+    ) -> Solid {
+        Solid {
+            inner: self
+                .inner
+                .rotate_about_axis(px, py, pz, ax, ay, az, angle_deg),
+        }
+    }
+
+    /// Apply an arbitrary 4×4 affine transform given as 16 row-major values
+    /// (e.g. a parent coordinate frame for assembly placement).
+    ///
+    /// Errors if the array does not have exactly 16 entries.
+    #[wasm_bindgen(js_name = applyMatrix)]
+    pub fn apply_matrix(&self, m: Vec<f64>) -> Result<Solid, JsError> {
+        let values: &[f64; 16] = m.as_slice().try_into().map_err(|_| {
+            JsError::new(&format!("applyMatrix expects 16 values, got {}", m.len()))
+        })?;
+        Ok(Solid {
+            inner: self.inner.apply_matrix(values),
+        })
+    }
+
     // =========================================================================
     // Fillet & Chamfer
     // =========================================================================
 
     /// Chamfer all edges of the solid by the given distance.
     #[wasm_bindgen(js_name = chamfer)]
-    pub fn chamfer(&self, distance: f64) -> Solid {
-        Solid {
+    pub fn chamfer(&self, distance: f64) -> Result<Solid, JsError> {
+        catch_kernel_panic("chamfer", || Solid {
             inner: self.inner.chamfer(distance),
-        }
+        })
     }
 
     /// Fillet all edges of the solid with the given radius.
     #[wasm_bindgen(js_name = fillet)]
-    pub fn fillet(&self, radius: f64) -> Solid {
-        Solid {
+    pub fn fillet(&self, radius: f64) -> Result<Solid, JsError> {
+        catch_kernel_panic("fillet", || Solid {
             inner: self.inner.fillet(radius),
+        })
+    }
+
+    /// Fillet a connected chain of edges with the given radius.
+    ///
+    /// Only the listed edges are blended, and where chain edges meet the
+    /// blends share their boundary vertices so the fillet runs continuously
+    /// around the chain. Edge indices follow topology iteration order, as
+    /// used by `edgeLength`.
+    #[wasm_bindgen(js_name = filletChain)]
+    pub fn fillet_chain(&self, edges: Vec<u32>, radius: f64) -> Result<Solid, JsError> {
+        catch_kernel_panic("filletChain", || {
+            let indices: Vec<usize> = edges.iter().map(|&e| e as usize).collect();
+            Solid {
+                inner: self.inner.fillet_chain(&indices, radius),
+            }
+        })
+    }
+
+    /// Preview which edges can be filleted at a radius, without building
+    /// the result.
+    ///
+    /// Returns an array with one `{edgeIndex, filletable, bounds?}` entry
+    /// per requested edge, where `bounds` is `{min, max}` of the
+    /// approximate blend region for filletable edges. Edges where the
+    /// radius would consume an adjacent face are reported as not
+    /// filletable. Edge indices follow topology iteration order, as used
+    /// by `edgeLength`.
+    #[wasm_bindgen(js_name = filletPreview)]
+    pub fn fillet_preview(&self, edges: Vec<u32>, radius: f64) -> Result<JsValue, JsError> {
+        #[derive(Serialize)]
+        struct WasmBounds {
+            min: [f64; 3],
+            max: [f64; 3],
         }
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct WasmFilletEdgePreview {
+            edge_index: u32,
+            filletable: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            bounds: Option<WasmBounds>,
+        }
+
+        let indices: Vec<usize> = edges.iter().map(|&e| e as usize).collect();
+        let previews: Vec<WasmFilletEdgePreview> = self
+            .inner
+            .fillet_preview(&indices, radius)
+            .iter()
+            .map(|p| WasmFilletEdgePreview {
+                edge_index: p.edge_index as u32,
+                filletable: p.filletable,
+                bounds: p.bounds.map(|(min, max)| WasmBounds { min, max }),
+            })
+            .collect();
+
+        previews
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| JsError::new(&e.to_string()))
     }
 
     /// Shell (hollow) the solid by offsetting all faces inward.
     #[wasm_bindgen(js_name = shell)]
-    pub fn shell(&self, thickness: f64) -> Solid {
-        Solid {
+    pub fn shell(&self, thickness: f64) -> Result<Solid, JsError> {
+        catch_kernel_panic("shell", || Solid {
             inner: self.inner.shell(thickness),
-        }
+        })
+    }
+
+    /// Merge consecutive collinear boundary edges into single edges,
+    /// removing the intermediate vertices.
+    #[wasm_bindgen(js_name = mergeCollinearEdges)]
+    pub fn merge_collinear_edges(&self, tol: f64) -> Result<Solid, JsError> {
+        catch_kernel_panic("mergeCollinearEdges", || Solid {
+            inner: self.inner.merge_collinear_edges(tol),
+        })
+    }
+
+    /// Collapse boundary edges shorter than `min_length`, merging their
+    /// endpoints and dissolving degenerate sliver faces.
+    #[wasm_bindgen(js_name = removeShortEdges)]
+    pub fn remove_short_edges(&self, min_length: f64) -> Result<Solid, JsError> {
+        catch_kernel_panic("removeShortEdges", || Solid {
+            inner: self.inner.remove_short_edges(min_length),
+        })
     }
 
     // =========================================================================
@@ -695,6 +1112,78 @@ impl Solid {
         }
     }
 
+    /// Compute pattern placement transforms without unioning the copies.
+    ///
+    /// Returns `{count, transforms}` where `transforms` is one 16-value
+    /// row-major matrix per instance (identity first, compatible with
+    /// `applyMatrix`). The base solid is left unchanged, so the app can
+    /// render the pattern as instances of a single mesh or emit separate
+    /// bodies for a BOM.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - `"linear"` or `"circular"`
+    /// * `direction` - Pattern direction (linear) or axis direction (circular), [x, y, z]
+    /// * `axis_origin` - A point on the rotation axis [x, y, z] (circular only)
+    /// * `count` - Number of copies (including original)
+    /// * `spacing_or_angle` - Spacing in mm (linear) or total angle span in degrees (circular)
+    #[wasm_bindgen(js_name = patternInstances)]
+    pub fn pattern_instances(
+        &self,
+        kind: &str,
+        direction: Vec<f64>,
+        axis_origin: Option<Vec<f64>>,
+        count: u32,
+        spacing_or_angle: f64,
+    ) -> Result<JsValue, JsError> {
+        use vcad_kernel::vcad_kernel_math::{Point3, Vec3};
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct WasmPatternInstances {
+            count: usize,
+            transforms: Vec<Vec<f64>>,
+        }
+
+        if direction.len() != 3 {
+            return Err(JsError::new("direction must have 3 components"));
+        }
+        let dir = Vec3::new(direction[0], direction[1], direction[2]);
+
+        let transforms = match kind {
+            "linear" => vcad_kernel::Solid::linear_pattern_transforms(dir, count, spacing_or_angle),
+            "circular" => {
+                let origin = axis_origin.ok_or_else(|| {
+                    JsError::new("circular pattern requires an axis_origin point")
+                })?;
+                if origin.len() != 3 {
+                    return Err(JsError::new("axis_origin must have 3 components"));
+                }
+                vcad_kernel::Solid::circular_pattern_transforms(
+                    Point3::new(origin[0], origin[1], origin[2]),
+                    dir,
+                    count,
+                    spacing_or_angle,
+                )
+            }
+            other => {
+                return Err(JsError::new(&format!(
+                    "Unknown pattern kind: {other}. Use 'linear' or 'circular'."
+                )));
+            }
+        };
+
+        WasmPatternInstances {
+            count: transforms.len(),
+            transforms: transforms
+                .iter()
+                .map(|t| t.to_row_major().to_vec())
+                .collect(),
+        }
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+    }
+
     // =========================================================================
     // Queries
     // =========================================================================
@@ -710,7 +1199,7 @@ impl Solid {
     /// Returns a JS object with `positions` (Float32Array) and `indices` (Uint32Array).
     #[wasm_bindgen(js_name = getMesh)]
     pub fn get_mesh(&self, segments: Option<u32>) -> JsValue {
-        let mesh = self.inner.to_mesh(segments.unwrap_or(32));
+        let mesh = self.inner.to_mesh(mesh_segments(&self.inner, segments));
         let num_verts = mesh.vertices.len() / 3;
 
         // Validate indices - check for out-of-bounds references
@@ -726,14 +1215,11 @@ impl Solid {
         }
 
         if invalid_count > 0 {
-            web_sys::console::error_1(
-                &format!(
-                    "[WASM] getMesh: {} invalid indices (max index {} but only {} vertices)",
-                    invalid_count,
-                    max_index,
-                    num_verts
-                )
-                .into(),
+            console_error!(
+                "[WASM] getMesh: {} invalid indices (max index {} but only {} vertices)",
+                invalid_count,
+                max_index,
+                num_verts
             );
         }
 
@@ -744,10 +1230,371 @@ impl Solid {
         serde_wasm_bindgen::to_value(&wasm_mesh).unwrap_or(JsValue::NULL)
     }
 
-    /// Compute the volume of the solid.
-    #[wasm_bindgen(js_name = volume)]
-    pub fn volume(&self) -> f64 {
-        self.inner.volume()
+    /// Tessellate and hand back the mesh buffers by reference.
+    ///
+    /// Unlike `getMesh`, which serializes positions and indices into a
+    /// fresh JS object, the returned [`MeshBuffers`] keeps the data in
+    /// Rust memory and exposes it as typed-array views — the cheap path
+    /// for streaming large meshes to the renderer.
+    #[wasm_bindgen(js_name = getMeshBuffers)]
+    pub fn get_mesh_buffers(&self, segments: Option<u32>) -> MeshBuffers {
+        MeshBuffers {
+            mesh: self.inner.to_mesh(mesh_segments(&self.inner, segments)),
+        }
+    }
+
+    /// Get the mesh with per-face segment overrides.
+    ///
+    /// `refinements` is an object mapping face indices to segment counts
+    /// (e.g. `{ "3": 64 }`); listed faces are tessellated at their own
+    /// detail while the rest use `default_segments`. Lets the UI bump detail
+    /// only where the user is zoomed in.
+    #[wasm_bindgen(js_name = getMeshRefined)]
+    pub fn get_mesh_refined(
+        &self,
+        default_segments: u32,
+        refinements: JsValue,
+    ) -> Result<JsValue, JsError> {
+        let refinements: std::collections::HashMap<usize, u32> =
+            serde_wasm_bindgen::from_value(refinements)
+                .map_err(|e| JsError::new(&format!("Invalid refinements map: {}", e)))?;
+        let mesh = self.inner.to_mesh_refined(default_segments, &refinements);
+        let wasm_mesh = WasmMesh {
+            positions: mesh.vertices,
+            indices: mesh.indices,
+        };
+        serde_wasm_bindgen::to_value(&wasm_mesh)
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
+    /// Get one mesh per connected body, as an array of `{positions, indices}`.
+    ///
+    /// Boolean results can contain several disconnected pieces; this returns
+    /// them separately so a viewer can color or select them individually.
+    #[wasm_bindgen(js_name = getMeshesPerBody)]
+    pub fn get_meshes_per_body(&self, segments: Option<u32>) -> JsValue {
+        let meshes: Vec<WasmMesh> = self
+            .inner
+            .meshes_per_body(mesh_segments(&self.inner, segments))
+            .into_iter()
+            .map(|mesh| WasmMesh {
+                positions: mesh.vertices,
+                indices: mesh.indices,
+            })
+            .collect();
+        serde_wasm_bindgen::to_value(&meshes).unwrap_or(JsValue::NULL)
+    }
+
+    /// Get one mesh per face color, as a `{ color: {positions, indices} }`
+    /// object.
+    ///
+    /// Faces colored with `withFaceColor` land in their color's group;
+    /// everything else is grouped under `"default"`. The viewer can assign
+    /// one material per group for multi-color rendering.
+    #[wasm_bindgen(js_name = getMeshByColor)]
+    pub fn get_mesh_by_color(&self, segments: Option<u32>) -> Result<JsValue, JsError> {
+        let groups: std::collections::BTreeMap<String, WasmMesh> = self
+            .inner
+            .mesh_by_color(mesh_segments(&self.inner, segments))
+            .into_iter()
+            .map(|(color, mesh)| {
+                (
+                    color,
+                    WasmMesh {
+                        positions: mesh.vertices,
+                        indices: mesh.indices,
+                    },
+                )
+            })
+            .collect();
+        serde_wasm_bindgen::to_value(&groups)
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
+    /// Whether the solid's boundary is two-manifold (every edge shared by at
+    /// most two triangles). A quick health check before export or slicing.
+    #[wasm_bindgen(js_name = isManifold)]
+    pub fn is_manifold(&self) -> bool {
+        self.inner.is_manifold()
+    }
+
+    /// Whether the solid's boundary is closed (watertight): every edge is
+    /// shared by exactly two triangles.
+    #[wasm_bindgen(js_name = isClosed)]
+    pub fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+
+    /// Thorough degeneracy check: true for empty solids, zero faces,
+    /// (near-)zero volume, or NaN/infinite coordinates. Guards against
+    /// chaining further operations onto a collapsed result.
+    #[wasm_bindgen(js_name = isDegenerate)]
+    pub fn is_degenerate(&self) -> bool {
+        self.inner.is_degenerate()
+    }
+
+    /// Create an empty solid carrying over this solid's settings (such as
+    /// the default segment count).
+    #[wasm_bindgen(js_name = emptyLike)]
+    pub fn empty_like(&self) -> Solid {
+        Solid {
+            inner: self.inner.empty_like(),
+        }
+    }
+
+    /// Exact area of a single face, computed from its surface geometry
+    /// rather than mesh triangles.
+    ///
+    /// Errors for faces without an analytic area (spheres, tori, freeform)
+    /// or an invalid index. Face indices follow topology iteration order,
+    /// matching `sketchPlaneFromFace` and `unfoldFace`.
+    #[wasm_bindgen(js_name = faceArea)]
+    pub fn face_area(&self, face: u32) -> Result<f64, JsError> {
+        self.inner
+            .face_area(face as usize)
+            .ok_or_else(|| JsError::new("No analytic area for this face (or invalid index)"))
+    }
+
+    /// Rounding radius of a single face's surface: cylinder/sphere radius,
+    /// or the minor radius for toroidal fillet blends.
+    ///
+    /// Returns `undefined` for planar and freeform faces, or an invalid
+    /// index. Face indices follow topology iteration order, matching
+    /// `faceArea`.
+    #[wasm_bindgen(js_name = faceRadius)]
+    pub fn face_radius(&self, face: u32) -> Option<f64> {
+        self.inner.face_radius(face as usize)
+    }
+
+    /// Exact length of a single edge, computed from its geometry: full
+    /// circles for closed rim edges, straight distance for line edges.
+    ///
+    /// Errors for mesh-only solids or an invalid index.
+    #[wasm_bindgen(js_name = edgeLength)]
+    pub fn edge_length(&self, edge: u32) -> Result<f64, JsError> {
+        self.inner
+            .edge_length(edge as usize)
+            .ok_or_else(|| JsError::new("No such edge (or solid has no B-rep)"))
+    }
+
+    /// Sample every B-rep edge as a 3D polyline for wireframe rendering.
+    ///
+    /// Returns an array of polylines, each an array of `[x, y, z]` points:
+    /// straight edges as their two endpoints, circular rim edges sampled
+    /// with `segments` points (closed), arcs with a proportional share.
+    /// These are the actual topology edges, so overlays stay crisp at any
+    /// tessellation quality. Errors for mesh-only solids.
+    #[wasm_bindgen(js_name = wireframeEdges)]
+    pub fn wireframe_edges(&self, segments: u32) -> Result<JsValue, JsError> {
+        let edges = self
+            .inner
+            .wireframe_edges(segments)
+            .ok_or_else(|| JsError::new("wireframeEdges requires a B-rep solid"))?;
+
+        let polylines: Vec<Vec<[f64; 3]>> = edges
+            .iter()
+            .map(|e| e.iter().map(|p| [p.x, p.y, p.z]).collect())
+            .collect();
+        serde_wasm_bindgen::to_value(&polylines)
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
+    /// Unfold a developable face (plane, cylinder, cone) into its flat 2D
+    /// pattern.
+    ///
+    /// Returns the flattened outline as an array of `[x, y]` points. For a
+    /// cylindrical lateral face this is a rectangle of width `2πr`. Errors
+    /// for non-developable faces.
+    #[wasm_bindgen(js_name = unfoldFace)]
+    pub fn unfold_face(&self, face: u32) -> Result<JsValue, JsError> {
+        let outline = self
+            .inner
+            .unfold_face(face as usize)
+            .ok_or_else(|| JsError::new("Face is not developable or does not exist"))?;
+
+        let points: Vec<[f64; 2]> = outline.iter().map(|p| [p.x, p.y]).collect();
+        serde_wasm_bindgen::to_value(&points)
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
+    /// Unfold all connected developable faces into one sheet-metal flat
+    /// pattern.
+    ///
+    /// Returns `{ faces: [{ faceIndex, outline }], bendLines, nonDevelopable }`
+    /// where each outline and bend line is given as `[x, y]` points in the
+    /// pattern plane. Non-developable faces are listed by index and left out
+    /// of the pattern.
+    #[wasm_bindgen(js_name = unfoldShell)]
+    pub fn unfold_shell(&self) -> Result<JsValue, JsError> {
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Face {
+            face_index: usize,
+            outline: Vec<[f64; 2]>,
+        }
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Pattern {
+            faces: Vec<Face>,
+            bend_lines: Vec<[[f64; 2]; 2]>,
+            non_developable: Vec<usize>,
+        }
+
+        let unfold = self.inner.unfold_shell();
+        let pattern = Pattern {
+            faces: unfold
+                .faces
+                .iter()
+                .map(|f| Face {
+                    face_index: f.face_index,
+                    outline: f.outline.iter().map(|p| [p.x, p.y]).collect(),
+                })
+                .collect(),
+            bend_lines: unfold
+                .bend_lines
+                .iter()
+                .map(|[a, b]| [[a.x, a.y], [b.x, b.y]])
+                .collect(),
+            non_developable: unfold.non_developable,
+        };
+        pattern
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
+    /// Sample a height field (depth map) of the solid along an axis.
+    ///
+    /// Covers the solid's bounding box perpendicular to `axis` (`"x"`, `"y"`
+    /// or `"z"`) with a `resolution × resolution` grid and records the
+    /// maximum surface height at each cell center — the surface a roughing
+    /// tool sees looking down the axis, for 2.5D machining previews. Returns
+    /// `{ resolution, origin, cellSize, floor, heights }` with row-major
+    /// heights; uncovered cells hold `floor`.
+    #[wasm_bindgen(js_name = heightField)]
+    pub fn height_field(&self, resolution: u32, axis: &str) -> Result<JsValue, JsError> {
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct WasmHeightField {
+            resolution: u32,
+            origin: [f64; 2],
+            cell_size: [f64; 2],
+            floor: f64,
+            heights: Vec<f64>,
+        }
+
+        let axis = match axis {
+            "x" => vcad_kernel::HeightAxis::X,
+            "y" => vcad_kernel::HeightAxis::Y,
+            "z" => vcad_kernel::HeightAxis::Z,
+            _ => return Err(JsError::new(&format!("Unknown axis: {}", axis))),
+        };
+        let field = self
+            .inner
+            .height_field(resolution, axis)
+            .ok_or_else(|| JsError::new("Solid is empty or resolution is zero"))?;
+        WasmHeightField {
+            resolution: field.resolution,
+            origin: field.origin,
+            cell_size: field.cell_size,
+            floor: field.floor,
+            heights: field.heights,
+        }
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
+    /// Measure the worst tessellation deviation from the exact surfaces.
+    ///
+    /// Returns `{ maxDeviation, location: [x, y, z] }` for the given segment
+    /// count, or an error for mesh-only/empty solids.
+    #[wasm_bindgen(js_name = tessellationError)]
+    pub fn tessellation_error(&self, segments: Option<u32>) -> Result<JsValue, JsError> {
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct WasmDeviation {
+            max_deviation: f64,
+            location: [f64; 3],
+        }
+
+        let report = self
+            .inner
+            .tessellation_error(mesh_segments(&self.inner, segments))
+            .ok_or_else(|| JsError::new("Solid has no B-rep to compare against"))?;
+
+        serde_wasm_bindgen::to_value(&WasmDeviation {
+            max_deviation: report.max_deviation,
+            location: [report.location.x, report.location.y, report.location.z],
+        })
+        .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
+    /// Reorient the solid for 3D printing and rest it on the z = 0 bed.
+    ///
+    /// `strategy` is `"min_overhang"` (default) to minimize the projected
+    /// area of downward-facing overhangs, or `"min_height"` to minimize
+    /// build height. Errors for empty solids or an unknown strategy.
+    #[wasm_bindgen(js_name = autoOrientForPrint)]
+    pub fn auto_orient_for_print(
+        &self,
+        strategy: Option<String>,
+    ) -> Result<WasmPrintOrientation, JsError> {
+        use vcad_kernel::PrintOrientStrategy;
+        let strategy = match strategy.as_deref() {
+            None | Some("min_overhang") => PrintOrientStrategy::MinOverhang,
+            Some("min_height") => PrintOrientStrategy::MinHeight,
+            Some(other) => {
+                return Err(JsError::new(&format!(
+                    "Unknown strategy '{}' (expected 'min_overhang' or 'min_height')",
+                    other
+                )))
+            }
+        };
+        self.inner
+            .auto_orient_for_print(strategy)
+            .map(|inner| WasmPrintOrientation { inner })
+            .ok_or_else(|| JsError::new("Solid is empty"))
+    }
+
+    /// Compute the volume of the solid.
+    #[wasm_bindgen(js_name = volume)]
+    pub fn volume(&self) -> f64 {
+        self.inner.volume()
+    }
+
+    /// Tag the whole solid with a material id, replacing any existing tags.
+    ///
+    /// Tags survive booleans and transforms, feeding `volumeByMaterial`.
+    #[wasm_bindgen(js_name = withMaterialTag)]
+    pub fn with_material_tag(&self, tag: &str) -> Solid {
+        Solid {
+            inner: self.inner.with_material_tag(tag),
+        }
+    }
+
+    /// Assign a display color to one face, replacing any previous color.
+    ///
+    /// `face` is the face index in topology iteration order, as used by
+    /// `faceArea`. Colors survive transforms but are dropped by booleans.
+    /// Consumed by `getMeshByColor`.
+    #[wasm_bindgen(js_name = withFaceColor)]
+    pub fn with_face_color(&self, face: u32, color: &str) -> Solid {
+        Solid {
+            inner: self.inner.with_face_color(face as usize, color),
+        }
+    }
+
+    /// Per-material volume breakdown as a `{ materialId: volume }` object.
+    ///
+    /// Material removed by booleans after tagging is not counted. Empty
+    /// object when nothing was tagged.
+    #[wasm_bindgen(js_name = volumeByMaterial)]
+    pub fn volume_by_material(&self) -> Result<JsValue, JsError> {
+        use serde::Serialize;
+
+        let map: std::collections::BTreeMap<String, f64> =
+            self.inner.volume_by_material().into_iter().collect();
+        map.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
     }
 
     /// Compute the surface area of the solid.
@@ -776,11 +1623,84 @@ impl Solid {
         self.inner.num_triangles()
     }
 
+    /// Derive a sketch plane from a face of this solid.
+    ///
+    /// Returns `{ origin, xDir, yDir, normal }` (each `[x, y, z]`) for the
+    /// face's surface at its centroid, so a sketch can be placed on that
+    /// face. For non-planar faces this is the tangent plane at the centroid.
+    #[wasm_bindgen(js_name = sketchPlaneFromFace)]
+    pub fn sketch_plane_from_face(&self, face: u32) -> Result<JsValue, JsError> {
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct WasmSketchPlane {
+            origin: [f64; 3],
+            x_dir: [f64; 3],
+            y_dir: [f64; 3],
+            normal: [f64; 3],
+        }
+
+        let plane = self
+            .inner
+            .sketch_plane_from_face(face as usize)
+            .ok_or_else(|| JsError::new("No such face (or solid has no B-rep)"))?;
+
+        let result = WasmSketchPlane {
+            origin: [plane.origin.x, plane.origin.y, plane.origin.z],
+            x_dir: [plane.x_dir.x, plane.x_dir.y, plane.x_dir.z],
+            y_dir: [plane.y_dir.x, plane.y_dir.y, plane.y_dir.z],
+            normal: [plane.normal.x, plane.normal.y, plane.normal.z],
+        };
+
+        serde_wasm_bindgen::to_value(&result)
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
+    /// Measure the angle between two planar faces, in degrees.
+    ///
+    /// Adjacent cube faces give 90, opposite ones 180; a concave (inside)
+    /// corner returns the angle negated. Face indices follow topology
+    /// iteration order, matching `sketchPlaneFromFace`.
+    #[wasm_bindgen(js_name = angleBetweenFaces)]
+    pub fn angle_between_faces(&self, face_a: u32, face_b: u32) -> Result<f64, JsError> {
+        self.inner
+            .angle_between_faces(face_a as usize, face_b as usize)
+            .ok_or_else(|| JsError::new("No such faces (or faces are not planar)"))
+    }
+
+    /// Report enclosed internal cavities (void shells), as an array of
+    /// `{ volume, min: [x,y,z], max: [x,y,z] }`.
+    ///
+    /// Differencing a fully-enclosed tool leaves a void shell; this surfaces
+    /// those pockets so the UI can flag trapped material in castings. Solids
+    /// without cavities return an empty array.
+    pub fn cavities(&self) -> Result<JsValue, JsError> {
+        #[derive(serde::Serialize)]
+        struct WasmCavity {
+            volume: f64,
+            min: [f64; 3],
+            max: [f64; 3],
+        }
+
+        let cavities: Vec<WasmCavity> = self
+            .inner
+            .cavities()
+            .into_iter()
+            .map(|c| WasmCavity {
+                volume: c.volume,
+                min: c.min,
+                max: c.max,
+            })
+            .collect();
+
+        serde_wasm_bindgen::to_value(&cavities)
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
     /// Generate a section view by cutting the solid with a plane.
     ///
     /// # Arguments
     /// * `plane_json` - JSON string with plane definition: `{"origin": [x,y,z], "normal": [x,y,z], "up": [x,y,z]}`
-    /// * `hatch_json` - Optional JSON string with hatch pattern: `{"spacing": f64, "angle": f64}`
+    /// * `hatch_json` - Optional JSON string with hatch pattern: `{"spacing": f64, "angle": f64, "pattern": "lines"|"cross"}`
     /// * `segments` - Number of segments for tessellation (optional, default 32)
     ///
     /// # Returns
@@ -804,7 +1724,7 @@ impl Solid {
         let hatch: Option<HatchPattern> = hatch_json.and_then(|h| serde_json::from_str(&h).ok());
 
         // Get mesh
-        let mesh = self.inner.to_mesh(segments.unwrap_or(32));
+        let mesh = self.inner.to_mesh(mesh_segments(&self.inner, segments));
 
         // Generate section view
         let view = section_mesh(&mesh, &plane, hatch.as_ref());
@@ -831,7 +1751,7 @@ impl Solid {
             HatchPattern::new(spacing, hatch_angle.unwrap_or(std::f64::consts::FRAC_PI_4))
         });
 
-        let mesh = self.inner.to_mesh(segments.unwrap_or(32));
+        let mesh = self.inner.to_mesh(mesh_segments(&self.inner, segments));
         let view = section_mesh(&mesh, &plane, hatch.as_ref());
 
         serde_wasm_bindgen::to_value(&view).unwrap_or(JsValue::NULL)
@@ -849,7 +1769,7 @@ impl Solid {
     pub fn project_view(&self, view_direction: &str, segments: Option<u32>) -> JsValue {
         use vcad_kernel_drafting::{project_mesh, ViewDirection};
 
-        let mesh = self.inner.to_mesh(segments.unwrap_or(32));
+        let mesh = self.inner.to_mesh(mesh_segments(&self.inner, segments));
 
         let view_dir = match view_direction.to_lowercase().as_str() {
             "front" => ViewDirection::Front,
@@ -866,6 +1786,71 @@ impl Solid {
         serde_wasm_bindgen::to_value(&view).unwrap_or(JsValue::NULL)
     }
 
+    /// Project the solid and export the view to DXF in one call.
+    ///
+    /// Convenience over `projectView` followed by `exportProjectedViewToDxf`
+    /// for exporting shop drawings directly.
+    ///
+    /// # Arguments
+    /// * `view_direction` - View direction: "front", "back", "top", "bottom", "left", "right", or "isometric"
+    /// * `segments` - Number of segments for tessellation (optional, default 32)
+    ///
+    /// # Returns
+    /// A byte buffer containing the DXF file content.
+    #[wasm_bindgen(js_name = toDxfView)]
+    pub fn to_dxf_view(&self, view_direction: &str, segments: Option<u32>) -> Vec<u8> {
+        use vcad_kernel_drafting::{export_view_to_dxf, project_mesh, ViewDirection};
+
+        let mesh = self.inner.to_mesh(mesh_segments(&self.inner, segments));
+
+        let view_dir = match view_direction.to_lowercase().as_str() {
+            "front" => ViewDirection::Front,
+            "back" => ViewDirection::Back,
+            "top" => ViewDirection::Top,
+            "bottom" => ViewDirection::Bottom,
+            "left" => ViewDirection::Left,
+            "right" => ViewDirection::Right,
+            "isometric" => ViewDirection::ISOMETRIC_STANDARD,
+            _ => ViewDirection::Front,
+        };
+
+        export_view_to_dxf(&project_mesh(&mesh, view_dir))
+    }
+
+    /// Export a multi-view DXF drawing of the solid.
+    ///
+    /// Projects front, top, right, and isometric views, arranges them per
+    /// the layout convention, and writes them into one DXF with a sheet
+    /// border and title block on the `BORDER` layer.
+    ///
+    /// # Arguments
+    /// * `segments` - Number of segments for tessellation (optional, default 32)
+    /// * `layout` - Sheet layout: "first-angle" or "third-angle" (default)
+    ///
+    /// # Returns
+    /// A byte buffer containing the DXF file content.
+    #[wasm_bindgen(js_name = toDxfDrawing)]
+    pub fn to_dxf_drawing(&self, segments: Option<u32>, layout: &str) -> Vec<u8> {
+        use vcad_kernel_drafting::{
+            export_drawing_to_dxf, project_mesh, DrawingLayout, ViewDirection,
+        };
+
+        let mesh = self.inner.to_mesh(mesh_segments(&self.inner, segments));
+
+        let layout = match layout.to_lowercase().as_str() {
+            "first-angle" | "first" => DrawingLayout::FirstAngle,
+            _ => DrawingLayout::ThirdAngle,
+        };
+
+        export_drawing_to_dxf(
+            &project_mesh(&mesh, ViewDirection::Front),
+            &project_mesh(&mesh, ViewDirection::Top),
+            &project_mesh(&mesh, ViewDirection::Right),
+            &project_mesh(&mesh, ViewDirection::ISOMETRIC_STANDARD),
+            layout,
+        )
+    }
+
     /// Export the solid to STEP format.
     ///
     /// # Returns
@@ -880,6 +1865,19 @@ impl Solid {
             .map_err(|e| JsError::new(&e.to_string()))
     }
 
+    /// Export the solid to STEP format, validating the boundary first.
+    ///
+    /// Errors when the boundary has open or non-manifold edges instead of
+    /// writing a STEP file other CAD tools will reject. With `heal: true`,
+    /// open shells are closed by capping planar boundary loops before
+    /// exporting.
+    #[wasm_bindgen(js_name = toStepBufferValidated)]
+    pub fn to_step_buffer_validated(&self, heal: bool) -> Result<Vec<u8>, JsError> {
+        self.inner
+            .to_step_buffer_validated(heal)
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
     /// Check if the solid can be exported to STEP format.
     ///
     /// Returns `true` if the solid has B-rep data available for STEP export.
@@ -927,7 +1925,9 @@ impl Solid {
         use vcad_kernel::vcad_kernel_text::{FontRegistry, TextAlignment};
 
         if origin.len() != 3 || x_dir.len() != 3 || y_dir.len() != 3 || direction.len() != 3 {
-            return Err(JsError::new("origin, x_dir, y_dir, and direction must have 3 components"));
+            return Err(JsError::new(
+                "origin, x_dir, y_dir, and direction must have 3 components",
+            ));
         }
 
         // Parse alignment
@@ -941,7 +1941,10 @@ impl Solid {
         let font_ref = match font.as_deref() {
             Some("sans-serif") | None => FontRegistry::builtin_sans(),
             Some(name) => {
-                return Err(JsError::new(&format!("Unknown font: {}. Use 'sans-serif' or omit for default.", name)));
+                return Err(JsError::new(&format!(
+                    "Unknown font: {}. Use 'sans-serif' or omit for default.",
+                    name
+                )));
             }
         };
 
@@ -950,16 +1953,13 @@ impl Solid {
 
         // Convert text to profiles
         let profiles = vcad_kernel::vcad_kernel_text::text_to_profiles(
-            text,
-            font_ref,
-            height,
-            letter_sp,
-            line_sp,
-            align,
+            text, font_ref, height, letter_sp, line_sp, align,
         );
 
         if profiles.is_empty() {
-            return Ok(Solid { inner: vcad_kernel::Solid::empty() });
+            return Ok(Solid {
+                inner: vcad_kernel::Solid::empty(),
+            });
         }
 
         // Separate profiles into outer contours and holes based on winding order
@@ -1045,6 +2045,173 @@ impl Solid {
             inner: result.unwrap_or_else(vcad_kernel::Solid::empty),
         })
     }
+
+    /// Engrave text around a cylindrical face.
+    ///
+    /// Lays the glyphs of `text` along the face's circumference and cuts
+    /// them `depth` deep into the surface. Glyph counters (the holes in
+    /// 'A', 'B', 'O', …) stay raised.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text string to engrave
+    /// * `font_data` - Raw TTF/OTF font bytes, or omit for the builtin sans-serif
+    /// * `face` - Index of the cylindrical face in topology iteration order
+    /// * `start_angle` - Start angle in radians from the surface's reference direction
+    /// * `height_pos` - Text baseline position along the axis from the surface origin
+    /// * `size` - Text height in mm
+    /// * `depth` - Pocket depth in mm
+    #[wasm_bindgen(js_name = engraveTextOnCylinder)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn engrave_text_on_cylinder(
+        &self,
+        text: &str,
+        font_data: Option<Vec<u8>>,
+        face: u32,
+        start_angle: f64,
+        height_pos: f64,
+        size: f64,
+        depth: f64,
+    ) -> Result<Solid, JsError> {
+        self.inner
+            .engrave_text_on_cylinder(
+                text,
+                font_data.as_deref(),
+                face as usize,
+                start_angle,
+                height_pos,
+                size,
+                depth,
+            )
+            .map(|inner| Solid { inner })
+            .ok_or_else(|| {
+                JsError::new(
+                    "engraveTextOnCylinder requires a B-rep solid, a cylindrical face index, \
+                     valid font data, and a positive depth smaller than the radius",
+                )
+            })
+    }
+
+    /// Wrap this solid so that subsequent operations record their history.
+    #[wasm_bindgen(js_name = withHistory)]
+    pub fn with_history(&self) -> TrackedSolid {
+        TrackedSolid {
+            inner: self.inner.clone().with_history(),
+        }
+    }
+}
+
+/// A solid wrapper that records each operation for editor feature trees.
+///
+/// Created via `Solid.withHistory()`. Every modeling call returns a new
+/// `TrackedSolid`, so earlier states stay valid for undo.
+#[wasm_bindgen]
+pub struct TrackedSolid {
+    inner: vcad_kernel::TrackedSolid,
+}
+
+#[wasm_bindgen]
+impl TrackedSolid {
+    /// Boolean union (records a "union" op).
+    #[wasm_bindgen(js_name = union)]
+    pub fn union(&self, other: &Solid) -> Result<TrackedSolid, JsError> {
+        catch_kernel_panic("union", || TrackedSolid {
+            inner: self.inner.union(&other.inner),
+        })
+    }
+
+    /// Boolean difference (records a "difference" op).
+    #[wasm_bindgen(js_name = difference)]
+    pub fn difference(&self, other: &Solid) -> Result<TrackedSolid, JsError> {
+        catch_kernel_panic("difference", || TrackedSolid {
+            inner: self.inner.difference(&other.inner),
+        })
+    }
+
+    /// Boolean intersection (records an "intersection" op).
+    #[wasm_bindgen(js_name = intersection)]
+    pub fn intersection(&self, other: &Solid) -> Result<TrackedSolid, JsError> {
+        catch_kernel_panic("intersection", || TrackedSolid {
+            inner: self.inner.intersection(&other.inner),
+        })
+    }
+
+    /// Translate (records a "translate" op).
+    #[wasm_bindgen(js_name = translate)]
+    pub fn translate(&self, x: f64, y: f64, z: f64) -> TrackedSolid {
+        TrackedSolid {
+            inner: self.inner.translate(x, y, z),
+        }
+    }
+
+    /// Rotate in degrees (records a "rotate" op).
+    #[wasm_bindgen(js_name = rotate)]
+    pub fn rotate(&self, x_deg: f64, y_deg: f64, z_deg: f64) -> TrackedSolid {
+        TrackedSolid {
+            inner: self.inner.rotate(x_deg, y_deg, z_deg),
+        }
+    }
+
+    /// Scale (records a "scale" op).
+    #[wasm_bindgen(js_name = scale)]
+    pub fn scale(&self, x: f64, y: f64, z: f64) -> TrackedSolid {
+        TrackedSolid {
+            inner: self.inner.scale(x, y, z),
+        }
+    }
+
+    /// Chamfer all edges (records a "chamfer" op).
+    #[wasm_bindgen(js_name = chamfer)]
+    pub fn chamfer(&self, distance: f64) -> Result<TrackedSolid, JsError> {
+        catch_kernel_panic("chamfer", || TrackedSolid {
+            inner: self.inner.chamfer(distance),
+        })
+    }
+
+    /// Fillet all edges (records a "fillet" op).
+    #[wasm_bindgen(js_name = fillet)]
+    pub fn fillet(&self, radius: f64) -> Result<TrackedSolid, JsError> {
+        catch_kernel_panic("fillet", || TrackedSolid {
+            inner: self.inner.fillet(radius),
+        })
+    }
+
+    /// Shell the solid (records a "shell" op).
+    #[wasm_bindgen(js_name = shell)]
+    pub fn shell(&self, thickness: f64) -> Result<TrackedSolid, JsError> {
+        catch_kernel_panic("shell", || TrackedSolid {
+            inner: self.inner.shell(thickness),
+        })
+    }
+
+    /// The recorded history as a JSON array of `{op, params}` records.
+    #[wasm_bindgen(js_name = historyJson)]
+    pub fn history_json(&self) -> String {
+        self.inner.history_json()
+    }
+
+    /// Number of recorded operations.
+    #[wasm_bindgen(js_name = historyLength)]
+    pub fn history_length(&self) -> usize {
+        self.inner.history().len()
+    }
+
+    /// Replay to the state after the first `n` operations.
+    #[wasm_bindgen(js_name = replayTo)]
+    pub fn replay_to(&self, n: usize) -> Result<TrackedSolid, JsError> {
+        self.inner
+            .replay_to(n)
+            .map(|inner| TrackedSolid { inner })
+            .ok_or_else(|| JsError::new("Cannot replay: index out of range"))
+    }
+
+    /// Extract the current solid.
+    #[wasm_bindgen(js_name = toSolid)]
+    pub fn to_solid(&self) -> Solid {
+        Solid {
+            inner: self.inner.solid().clone(),
+        }
+    }
 }
 
 // =========================================================================
@@ -1056,7 +2223,7 @@ impl Solid {
 /// This is a standalone wrapper for lazy loading via wasmosis.
 #[module("advanced")]
 #[wasm_bindgen]
-pub fn op_fillet(solid: &Solid, radius: f64) -> Solid {
+pub fn op_fillet(solid: &Solid, radius: f64) -> Result<Solid, JsError> {
     solid.fillet(radius)
 }
 
@@ -1065,7 +2232,7 @@ pub fn op_fillet(solid: &Solid, radius: f64) -> Solid {
 /// This is a standalone wrapper for lazy loading via wasmosis.
 #[module("advanced")]
 #[wasm_bindgen]
-pub fn op_chamfer(solid: &Solid, distance: f64) -> Solid {
+pub fn op_chamfer(solid: &Solid, distance: f64) -> Result<Solid, JsError> {
     solid.chamfer(distance)
 }
 
@@ -1074,7 +2241,7 @@ pub fn op_chamfer(solid: &Solid, distance: f64) -> Solid {
 /// This is a standalone wrapper for lazy loading via wasmosis.
 #[module("advanced")]
 #[wasm_bindgen]
-pub fn op_shell(solid: &Solid, thickness: f64) -> Solid {
+pub fn op_shell(solid: &Solid, thickness: f64) -> Result<Solid, JsError> {
     solid.shell(thickness)
 }
 
@@ -1170,7 +2337,15 @@ pub fn op_sweep_line(
     scale_end: Option<f64>,
     orientation: Option<f64>,
 ) -> Result<Solid, JsError> {
-    Solid::sweep_line(profile_js, start, end, twist_angle, scale_start, scale_end, orientation)
+    Solid::sweep_line(
+        profile_js,
+        start,
+        end,
+        twist_angle,
+        scale_start,
+        scale_end,
+        orientation,
+    )
 }
 
 /// Create a solid by sweeping a profile along a helix path.
@@ -1191,6 +2366,9 @@ pub fn op_sweep_helix(
     path_segments: Option<u32>,
     arc_segments: Option<u32>,
     orientation: Option<f64>,
+    frame_mode: Option<String>,
+    radius_end: Option<f64>,
+    pitch_end: Option<f64>,
 ) -> Result<Solid, JsError> {
     Solid::sweep_helix(
         profile_js,
@@ -1204,6 +2382,9 @@ pub fn op_sweep_helix(
         path_segments,
         arc_segments,
         orientation,
+        frame_mode,
+        radius_end,
+        pitch_end,
     )
 }
 
@@ -1274,7 +2455,7 @@ pub fn op_circular_pattern(
 /// # Arguments
 /// * `mesh_js` - Mesh data as JS object with `positions` (Float32Array) and `indices` (Uint32Array)
 /// * `plane_json` - JSON string with plane definition: `{"origin": [x,y,z], "normal": [x,y,z], "up": [x,y,z]}`
-/// * `hatch_json` - Optional JSON string with hatch pattern: `{"spacing": f64, "angle": f64}`
+/// * `hatch_json` - Optional JSON string with hatch pattern: `{"spacing": f64, "angle": f64, "pattern": "lines"|"cross"}`
 ///
 /// # Returns
 /// A JS object containing the section view with curves, hatch lines, and bounds.
@@ -1356,6 +2537,91 @@ pub fn project_mesh_wasm(mesh_js: JsValue, view_direction: &str) -> JsValue {
     serde_wasm_bindgen::to_value(&view).unwrap_or(JsValue::NULL)
 }
 
+/// Rigidly align one triangle mesh onto another via iterative closest point.
+///
+/// Useful for registering a scanned part against its nominal model before
+/// measuring deviations.
+///
+/// # Arguments
+/// * `source_js` - Mesh to move, as JS object with `positions` (Float32Array) and `indices` (Uint32Array)
+/// * `target_js` - Mesh to align against, same layout
+/// * `iterations` - Maximum ICP refinement steps (stops early on convergence)
+///
+/// # Returns
+/// `{matrix: number[16], rmsError}` — the row-major rigid transform mapping
+/// source points onto the target, and the residual RMS distance.
+#[module("advanced")]
+#[wasm_bindgen(js_name = alignMeshes)]
+pub fn align_meshes_wasm(
+    source_js: JsValue,
+    target_js: JsValue,
+    iterations: u32,
+) -> Result<JsValue, JsError> {
+    use vcad_kernel_tessellate::TriangleMesh;
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct WasmMeshAlignment {
+        matrix: Vec<f64>,
+        rms_error: f64,
+    }
+
+    let to_mesh = |js: JsValue, name: &str| -> Result<TriangleMesh, JsError> {
+        let data: WasmMesh = serde_wasm_bindgen::from_value(js)
+            .map_err(|e| JsError::new(&format!("invalid {name} mesh: {e}")))?;
+        Ok(TriangleMesh {
+            vertices: data.positions,
+            indices: data.indices,
+            normals: Vec::new(),
+        })
+    };
+    let source = to_mesh(source_js, "source")?;
+    let target = to_mesh(target_js, "target")?;
+
+    let alignment = vcad_kernel::align_meshes(&source, &target, iterations);
+    WasmMeshAlignment {
+        matrix: alignment.matrix.to_vec(),
+        rms_error: alignment.rms_error,
+    }
+    .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+    .map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Per-vertex signed deviation of one mesh from another.
+///
+/// For each source vertex, reports the signed distance to the reference mesh
+/// surface (positive outside, negative inside), suitable for driving an
+/// inspection color map after `alignMeshes`.
+///
+/// # Arguments
+/// * `source_js` - Mesh to measure, as JS object with `positions` (Float32Array) and `indices` (Uint32Array)
+/// * `reference_js` - Nominal mesh to measure against, same layout
+///
+/// # Returns
+/// An array of signed distances, one per source vertex in order.
+#[module("advanced")]
+#[wasm_bindgen(js_name = meshDeviation)]
+pub fn mesh_deviation_wasm(source_js: JsValue, reference_js: JsValue) -> Result<JsValue, JsError> {
+    use vcad_kernel_tessellate::TriangleMesh;
+
+    let to_mesh = |js: JsValue, name: &str| -> Result<TriangleMesh, JsError> {
+        let data: WasmMesh = serde_wasm_bindgen::from_value(js)
+            .map_err(|e| JsError::new(&format!("invalid {name} mesh: {e}")))?;
+        Ok(TriangleMesh {
+            vertices: data.positions,
+            indices: data.indices,
+            normals: Vec::new(),
+        })
+    };
+    let source = to_mesh(source_js, "source")?;
+    let reference = to_mesh(reference_js, "reference")?;
+
+    let deviations = vcad_kernel::mesh_deviation(&source, &reference);
+    deviations
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
 // =========================================================================
 // Dimension annotation bindings
 // =========================================================================
@@ -1388,11 +2654,8 @@ impl WasmAnnotationLayer {
     #[wasm_bindgen(js_name = addHorizontalDimension)]
     pub fn add_horizontal_dimension(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, offset: f64) {
         use vcad_kernel_drafting::Point2D;
-        self.inner.add_horizontal_dimension(
-            Point2D::new(x1, y1),
-            Point2D::new(x2, y2),
-            offset,
-        );
+        self.inner
+            .add_horizontal_dimension(Point2D::new(x1, y1), Point2D::new(x2, y2), offset);
     }
 
     /// Add a vertical dimension between two points.
@@ -1404,11 +2667,8 @@ impl WasmAnnotationLayer {
     #[wasm_bindgen(js_name = addVerticalDimension)]
     pub fn add_vertical_dimension(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, offset: f64) {
         use vcad_kernel_drafting::Point2D;
-        self.inner.add_vertical_dimension(
-            Point2D::new(x1, y1),
-            Point2D::new(x2, y2),
-            offset,
-        );
+        self.inner
+            .add_vertical_dimension(Point2D::new(x1, y1), Point2D::new(x2, y2), offset);
     }
 
     /// Add an aligned dimension between two points.
@@ -1422,11 +2682,8 @@ impl WasmAnnotationLayer {
     #[wasm_bindgen(js_name = addAlignedDimension)]
     pub fn add_aligned_dimension(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, offset: f64) {
         use vcad_kernel_drafting::Point2D;
-        self.inner.add_aligned_dimension(
-            Point2D::new(x1, y1),
-            Point2D::new(x2, y2),
-            offset,
-        );
+        self.inner
+            .add_aligned_dimension(Point2D::new(x1, y1), Point2D::new(x2, y2), offset);
     }
 
     /// Add a diameter dimension for a circle.
@@ -1556,156 +2813,12 @@ impl Default for WasmAnnotationLayer {
 #[module("drafting")]
 #[wasm_bindgen(js_name = exportProjectedViewToDxf)]
 pub fn export_projected_view_to_dxf(view_json: &str) -> Result<Vec<u8>, JsError> {
-    use std::io::Write;
-    use vcad_kernel_drafting::{ProjectedView, Visibility};
+    use vcad_kernel_drafting::{export_view_to_dxf, ProjectedView};
 
     let view: ProjectedView =
         serde_json::from_str(view_json).map_err(|e| JsError::new(&e.to_string()))?;
 
-    // Build DXF content
-    let mut buffer = Vec::new();
-
-    // Header
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "SECTION").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "HEADER").unwrap();
-    writeln!(buffer, "9").unwrap();
-    writeln!(buffer, "$ACADVER").unwrap();
-    writeln!(buffer, "1").unwrap();
-    writeln!(buffer, "AC1009").unwrap();
-    writeln!(buffer, "9").unwrap();
-    writeln!(buffer, "$INSUNITS").unwrap();
-    writeln!(buffer, "70").unwrap();
-    writeln!(buffer, "4").unwrap();
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "ENDSEC").unwrap();
-
-    // Tables
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "SECTION").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "TABLES").unwrap();
-
-    // Linetypes
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "TABLE").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "LTYPE").unwrap();
-    writeln!(buffer, "70").unwrap();
-    writeln!(buffer, "2").unwrap();
-
-    // Continuous
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "LTYPE").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "CONTINUOUS").unwrap();
-    writeln!(buffer, "70").unwrap();
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "3").unwrap();
-    writeln!(buffer, "Solid line").unwrap();
-    writeln!(buffer, "72").unwrap();
-    writeln!(buffer, "65").unwrap();
-    writeln!(buffer, "73").unwrap();
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "40").unwrap();
-    writeln!(buffer, "0.0").unwrap();
-
-    // Hidden
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "LTYPE").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "HIDDEN").unwrap();
-    writeln!(buffer, "70").unwrap();
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "3").unwrap();
-    writeln!(buffer, "Hidden line").unwrap();
-    writeln!(buffer, "72").unwrap();
-    writeln!(buffer, "65").unwrap();
-    writeln!(buffer, "73").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "40").unwrap();
-    writeln!(buffer, "9.525").unwrap();
-    writeln!(buffer, "49").unwrap();
-    writeln!(buffer, "6.35").unwrap();
-    writeln!(buffer, "49").unwrap();
-    writeln!(buffer, "-3.175").unwrap();
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "ENDTAB").unwrap();
-
-    // Layers
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "TABLE").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "LAYER").unwrap();
-    writeln!(buffer, "70").unwrap();
-    writeln!(buffer, "2").unwrap();
-
-    // VISIBLE layer
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "LAYER").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "VISIBLE").unwrap();
-    writeln!(buffer, "70").unwrap();
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "62").unwrap();
-    writeln!(buffer, "7").unwrap();
-    writeln!(buffer, "6").unwrap();
-    writeln!(buffer, "CONTINUOUS").unwrap();
-
-    // HIDDEN layer
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "LAYER").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "HIDDEN").unwrap();
-    writeln!(buffer, "70").unwrap();
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "62").unwrap();
-    writeln!(buffer, "8").unwrap();
-    writeln!(buffer, "6").unwrap();
-    writeln!(buffer, "HIDDEN").unwrap();
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "ENDTAB").unwrap();
-
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "ENDSEC").unwrap();
-
-    // Entities
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "SECTION").unwrap();
-    writeln!(buffer, "2").unwrap();
-    writeln!(buffer, "ENTITIES").unwrap();
-
-    for edge in &view.edges {
-        let (layer, linetype) = match edge.visibility {
-            Visibility::Visible => ("VISIBLE", "CONTINUOUS"),
-            Visibility::Hidden => ("HIDDEN", "HIDDEN"),
-        };
-
-        writeln!(buffer, "0").unwrap();
-        writeln!(buffer, "LINE").unwrap();
-        writeln!(buffer, "8").unwrap();
-        writeln!(buffer, "{}", layer).unwrap();
-        writeln!(buffer, "6").unwrap();
-        writeln!(buffer, "{}", linetype).unwrap();
-        writeln!(buffer, "10").unwrap();
-        writeln!(buffer, "{:.6}", edge.start.x).unwrap();
-        writeln!(buffer, "20").unwrap();
-        writeln!(buffer, "{:.6}", edge.start.y).unwrap();
-        writeln!(buffer, "11").unwrap();
-        writeln!(buffer, "{:.6}", edge.end.x).unwrap();
-        writeln!(buffer, "21").unwrap();
-        writeln!(buffer, "{:.6}", edge.end.y).unwrap();
-    }
-
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "ENDSEC").unwrap();
-
-    // EOF
-    writeln!(buffer, "0").unwrap();
-    writeln!(buffer, "EOF").unwrap();
-
-    Ok(buffer)
+    Ok(export_view_to_dxf(&view))
 }
 
 // =========================================================================
@@ -1740,7 +2853,9 @@ pub fn create_detail_view(
     height: f64,
     label: &str,
 ) -> Result<JsValue, JsError> {
-    use vcad_kernel_drafting::{create_detail_view as create_detail, DetailViewParams, Point2D, ProjectedView};
+    use vcad_kernel_drafting::{
+        create_detail_view as create_detail, DetailViewParams, Point2D, ProjectedView,
+    };
 
     let parent: ProjectedView =
         serde_json::from_str(parent_json).map_err(|e| JsError::new(&e.to_string()))?;
@@ -1775,8 +2890,8 @@ pub fn create_detail_view(
 #[module("step")]
 #[wasm_bindgen(js_name = importStepBuffer)]
 pub fn import_step_buffer(data: &[u8]) -> Result<JsValue, JsError> {
-    let solids = vcad_kernel::Solid::from_step_buffer_all(data)
-        .map_err(|e| JsError::new(&e.to_string()))?;
+    let solids =
+        vcad_kernel::Solid::from_step_buffer_all(data).map_err(|e| JsError::new(&e.to_string()))?;
 
     // Convert each solid to a mesh (use fewer segments for imported files)
     let meshes: Vec<WasmMesh> = solids
@@ -1818,11 +2933,11 @@ pub struct GpuGeometryResult {
 pub async fn init_gpu() -> Result<bool, JsError> {
     match vcad_kernel_gpu::GpuContext::init().await {
         Ok(_) => {
-            web_sys::console::log_1(&"[WASM] GPU context initialized successfully".into());
+            console_log!("[WASM] GPU context initialized successfully");
             Ok(true)
         }
         Err(e) => {
-            web_sys::console::warn_1(&format!("[WASM] GPU init failed: {}", e).into());
+            console_warn!("[WASM] GPU init failed: {}", e);
             Ok(false)
         }
     }
@@ -1833,7 +2948,7 @@ pub async fn init_gpu() -> Result<bool, JsError> {
 #[module("gpu")]
 #[wasm_bindgen(js_name = initGpu)]
 pub async fn init_gpu() -> Result<bool, JsError> {
-    web_sys::console::log_1(&"[WASM] GPU feature not enabled".into());
+    console_log!("[WASM] GPU feature not enabled");
     Ok(false)
 }
 
@@ -2000,6 +3115,55 @@ pub async fn decimate_mesh_gpu(
     Err(JsError::new("GPU feature not enabled"))
 }
 
+/// Simplify a mesh on the CPU with optional boundary preservation.
+///
+/// Quadric-error simplification for viewer LODs. Unlike `decimateMeshGpu`
+/// this runs synchronously without a GPU context, and with
+/// `preserve_boundaries` set it locks open boundary and sharp feature edges
+/// so silhouettes and patch borders stay crisp.
+///
+/// # Arguments
+/// * `positions` - Flat array of vertex positions
+/// * `indices` - Triangle indices
+/// * `target_ratio` - Target ratio of triangles to keep (0.5 = 50%)
+/// * `preserve_boundaries` - Lock boundary and feature edges
+///
+/// # Returns
+/// A JS object with simplified positions, indices, and normals.
+#[cfg(feature = "gpu")]
+#[module("gpu")]
+#[wasm_bindgen(js_name = simplifyMesh)]
+pub fn simplify_mesh(
+    positions: Vec<f32>,
+    indices: Vec<u32>,
+    target_ratio: f32,
+    preserve_boundaries: bool,
+) -> Result<JsValue, JsError> {
+    let result =
+        vcad_kernel_gpu::simplify_mesh(&positions, &indices, target_ratio, preserve_boundaries);
+
+    let gpu_result = GpuGeometryResult {
+        positions: result.positions,
+        indices: result.indices,
+        normals: result.normals,
+    };
+
+    serde_wasm_bindgen::to_value(&gpu_result).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Simplify a mesh (stub when GPU feature is disabled).
+#[cfg(not(feature = "gpu"))]
+#[module("gpu")]
+#[wasm_bindgen(js_name = simplifyMesh)]
+pub fn simplify_mesh(
+    _positions: Vec<f32>,
+    _indices: Vec<u32>,
+    _target_ratio: f32,
+    _preserve_boundaries: bool,
+) -> Result<JsValue, JsError> {
+    Err(JsError::new("GPU feature not enabled"))
+}
+
 // =========================================================================
 // GPU Ray Tracing (Direct BRep Rendering)
 // =========================================================================
@@ -2048,7 +3212,7 @@ impl RayTracer {
         let pipeline = vcad_kernel_raytrace::gpu::RayTracePipeline::new(ctx)
             .map_err(|e| JsError::new(&format!("Failed to create ray trace pipeline: {}", e)))?;
 
-        web_sys::console::log_1(&"[WASM] RayTracer created".into());
+        console_log!("[WASM] RayTracer created");
 
         Ok(RayTracer {
             pipeline,
@@ -2090,7 +3254,7 @@ impl RayTracer {
         // Reset accumulation when debug mode changes
         self.frame_index = 0;
         self.accum_buffer = None;
-        web_sys::console::log_1(&format!("[WASM] Debug mode set to {}", mode).into());
+        console_log!("[WASM] Debug mode set to {}", mode);
     }
 
     /// Get the current debug render mode.
@@ -2106,17 +3270,24 @@ impl RayTracer {
     /// * `depth_threshold` - Depth discontinuity threshold (default: 0.1)
     /// * `normal_threshold` - Normal angle threshold in degrees (default: 30.0)
     #[wasm_bindgen(js_name = setEdgeDetection)]
-    pub fn set_edge_detection(&mut self, enabled: bool, depth_threshold: f32, normal_threshold: f32) {
+    pub fn set_edge_detection(
+        &mut self,
+        enabled: bool,
+        depth_threshold: f32,
+        normal_threshold: f32,
+    ) {
         self.enable_edges = enabled;
         self.edge_depth_threshold = depth_threshold;
         self.edge_normal_threshold = normal_threshold;
         // Reset accumulation when edge settings change
         self.frame_index = 0;
         self.accum_buffer = None;
-        web_sys::console::log_1(&format!(
+        console_log!(
             "[WASM] Edge detection: enabled={}, depth={:.2}, normal={:.1}°",
-            enabled, depth_threshold, normal_threshold
-        ).into());
+            enabled,
+            depth_threshold,
+            normal_threshold
+        );
     }
 
     /// Get whether edge detection is enabled.
@@ -2133,7 +3304,9 @@ impl RayTracer {
         use vcad_kernel_raytrace::gpu::GpuScene;
 
         // Get the BRep from the solid
-        let brep = solid.inner.brep()
+        let brep = solid
+            .inner
+            .brep()
             .ok_or_else(|| JsError::new("Solid has no BRep representation (mesh-only)"))?;
 
         // Build GPU scene from BRep
@@ -2166,30 +3339,30 @@ impl RayTracer {
                 (0.0, 0.0, 0.0, 0.0)
             };
 
-            web_sys::console::log_1(&format!(
-                "[WASM] Face {}: surface={}, trim={}/{}@{}, UV_bounds=[{:.2},{:.2}]->[{:.2},{:.2}], inner={}/{}@{} (desc@{}), AABB=[{:.2},{:.2},{:.2}]->[{:.2},{:.2},{:.2}]",
+            console_log!("[WASM] Face {}: surface={}, trim={}/{}@{}, UV_bounds=[{:.2},{:.2}]->[{:.2},{:.2}], inner={}/{}@{} (desc@{}), AABB=[{:.2},{:.2},{:.2}]->[{:.2},{:.2},{:.2}]",
                 i, face.surface_idx,
                 face.trim_count, face.trim_start, face.trim_start,
                 uv_min_x, uv_min_y, uv_max_x, uv_max_y,
                 face.inner_loop_count, face.inner_count, face.inner_start, face.inner_desc_start,
                 face.aabb_min[0], face.aabb_min[1], face.aabb_min[2],
-                face.aabb_max[0], face.aabb_max[1], face.aabb_max[2]
-            ).into());
+                face.aabb_max[0], face.aabb_max[1], face.aabb_max[2]);
         }
 
         // Log inner_loop_descs buffer size
-        web_sys::console::log_1(&format!(
+        console_log!(
             "[WASM] inner_loop_descs buffer: {} entries, trim_verts: {} entries",
             scene.inner_loop_descs.len(),
             scene.trim_verts.len()
-        ).into());
+        );
 
         self.scene = Some(scene);
 
-        web_sys::console::log_1(&format!(
+        console_log!(
             "[WASM] Uploaded solid: {} faces, {} surfaces, {} BVH nodes",
-            num_faces, num_surfaces, num_bvh_nodes
-        ).into());
+            num_faces,
+            num_surfaces,
+            num_bvh_nodes
+        );
 
         Ok(())
     }
@@ -2201,8 +3374,17 @@ impl RayTracer {
     /// * `metallic` - Metallic factor (0 = dielectric, 1 = metal)
     /// * `roughness` - Roughness factor (0 = smooth/mirror, 1 = rough/diffuse)
     #[wasm_bindgen(js_name = setMaterial)]
-    pub fn set_material(&mut self, r: f32, g: f32, b: f32, metallic: f32, roughness: f32) -> Result<(), JsError> {
-        let scene = self.scene.as_mut()
+    pub fn set_material(
+        &mut self,
+        r: f32,
+        g: f32,
+        b: f32,
+        metallic: f32,
+        roughness: f32,
+    ) -> Result<(), JsError> {
+        let scene = self
+            .scene
+            .as_mut()
             .ok_or_else(|| JsError::new("No solid uploaded. Call uploadSolid() first."))?;
 
         scene.set_material(r, g, b, metallic, roughness);
@@ -2211,10 +3393,14 @@ impl RayTracer {
         self.frame_index = 0;
         self.accum_buffer = None;
 
-        web_sys::console::log_1(&format!(
+        console_log!(
             "[WASM] Set material: rgb=({:.2}, {:.2}, {:.2}), metallic={:.2}, roughness={:.2}",
-            r, g, b, metallic, roughness
-        ).into());
+            r,
+            g,
+            b,
+            metallic,
+            roughness
+        );
 
         Ok(())
     }
@@ -2247,15 +3433,19 @@ impl RayTracer {
         height: u32,
         fov: f32,
     ) -> Result<Vec<u8>, JsError> {
-        use vcad_kernel_raytrace::gpu::GpuCamera;
-        use std::hash::{Hash, Hasher};
         use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        use vcad_kernel_raytrace::gpu::GpuCamera;
 
         if camera.len() != 3 || target.len() != 3 || up.len() != 3 {
-            return Err(JsError::new("camera, target, and up must each have 3 components"));
+            return Err(JsError::new(
+                "camera, target, and up must each have 3 components",
+            ));
         }
 
-        let scene = self.scene.as_ref()
+        let scene = self
+            .scene
+            .as_ref()
             .ok_or_else(|| JsError::new("No solid uploaded. Call uploadSolid() first."))?;
 
         // Compute camera hash to detect changes
@@ -2263,13 +3453,20 @@ impl RayTracer {
         // (e.g., 29.659999999 vs 29.660000001 should hash the same)
         // The React side handles settling detection to avoid spurious renders during damping
         let mut hasher = DefaultHasher::new();
-        for v in &camera { ((v * 100.0).round() as i64).hash(&mut hasher); }
-        for v in &target { ((v * 100.0).round() as i64).hash(&mut hasher); }
+        for v in &camera {
+            ((v * 100.0).round() as i64).hash(&mut hasher);
+        }
+        for v in &target {
+            ((v * 100.0).round() as i64).hash(&mut hasher);
+        }
         ((fov * 100.0).round() as i32).hash(&mut hasher);
         let camera_hash = hasher.finish();
 
         // Reset accumulation if camera changed or dimensions changed
-        if camera_hash != self.last_camera_hash || width != self.last_width || height != self.last_height {
+        if camera_hash != self.last_camera_hash
+            || width != self.last_width
+            || height != self.last_height
+        {
             self.frame_index = 0;
             self.accum_buffer = None;
             self.last_camera_hash = camera_hash;
@@ -2282,12 +3479,16 @@ impl RayTracer {
 
         // Log progress occasionally
         if self.frame_index == 1 || self.frame_index.is_multiple_of(16) {
-            web_sys::console::log_1(&format!(
+            console_log!(
                 "[WASM] render() frame={} camera=[{:.2},{:.2},{:.2}] target=[{:.2},{:.2},{:.2}]",
                 self.frame_index,
-                camera[0], camera[1], camera[2],
-                target[0], target[1], target[2],
-            ).into());
+                camera[0],
+                camera[1],
+                camera[2],
+                target[0],
+                target[1],
+                target[2]
+            );
         }
 
         let gpu_camera = GpuCamera::new(
@@ -2299,22 +3500,24 @@ impl RayTracer {
             height,
         );
 
-        let ctx = vcad_kernel_gpu::GpuContext::get()
-            .ok_or_else(|| JsError::new("GPU context lost"))?;
+        let ctx =
+            vcad_kernel_gpu::GpuContext::get().ok_or_else(|| JsError::new("GPU context lost"))?;
 
-        let (pixels, new_accum) = self.pipeline.render_with_full_settings(
-            ctx,
-            scene,
-            &gpu_camera,
-            width,
-            height,
-            self.frame_index,
-            self.accum_buffer.take(),
-            self.debug_mode,
-            self.enable_edges,
-            self.edge_depth_threshold,
-            self.edge_normal_threshold,
-        )
+        let (pixels, new_accum) = self
+            .pipeline
+            .render_with_full_settings(
+                ctx,
+                scene,
+                &gpu_camera,
+                width,
+                height,
+                self.frame_index,
+                self.accum_buffer.take(),
+                self.debug_mode,
+                self.enable_edges,
+                self.edge_depth_threshold,
+                self.edge_normal_threshold,
+            )
             .await
             .map_err(|e| JsError::new(&format!("Render failed: {}", e)))?;
 
@@ -2345,14 +3548,18 @@ impl RayTracer {
         pixel_x: u32,
         pixel_y: u32,
     ) -> Result<i32, JsError> {
-        use vcad_kernel_raytrace::Ray;
         use vcad_kernel_math::{Point3, Vec3};
+        use vcad_kernel_raytrace::Ray;
 
         if camera.len() != 3 || target.len() != 3 || up.len() != 3 {
-            return Err(JsError::new("camera, target, and up must each have 3 components"));
+            return Err(JsError::new(
+                "camera, target, and up must each have 3 components",
+            ));
         }
 
-        let scene = self.scene.as_ref()
+        let scene = self
+            .scene
+            .as_ref()
             .ok_or_else(|| JsError::new("No solid uploaded. Call uploadSolid() first."))?;
 
         // Compute ray from camera through pixel
@@ -2371,7 +3578,9 @@ impl RayTracer {
         let ndc_x = (pixel_x as f64 + 0.5) / width as f64 * 2.0 - 1.0;
         let ndc_y = 1.0 - (pixel_y as f64 + 0.5) / height as f64 * 2.0;
 
-        let ray_dir = (forward + right * ndc_x * fov_tan * aspect + up_normalized * ndc_y * fov_tan).normalize();
+        let ray_dir =
+            (forward + right * ndc_x * fov_tan * aspect + up_normalized * ndc_y * fov_tan)
+                .normalize();
 
         let ray = Ray::new(cam_pos, ray_dir);
 
@@ -2411,7 +3620,9 @@ impl RayTracer {
     /// Returns an error when raytrace feature is not enabled.
     #[wasm_bindgen(js_name = create)]
     pub fn create() -> Result<RayTracer, JsError> {
-        Err(JsError::new("Ray tracing feature not enabled. Compile with --features raytrace"))
+        Err(JsError::new(
+            "Ray tracing feature not enabled. Compile with --features raytrace",
+        ))
     }
 }
 
@@ -2486,7 +3697,9 @@ pub fn evaluate_compact_ir(compact_ir: &str) -> Result<Solid, JsError> {
         .map_err(|e| JsError::new(&format!("Parse error: {}", e)))?;
 
     // Find the root node
-    let root_id = doc.roots.first()
+    let root_id = doc
+        .roots
+        .first()
         .ok_or_else(|| JsError::new("Document has no root nodes"))?
         .root;
 
@@ -2494,6 +3707,216 @@ pub fn evaluate_compact_ir(compact_ir: &str) -> Result<Solid, JsError> {
     evaluate_node(&doc, root_id)
 }
 
+/// Generate a valid random IR Document (JSON) for fuzzing and dataset seeding.
+///
+/// The same seed always produces the same document; see
+/// `vcad_ir::random_document` for the generation rules.
+///
+/// # Arguments
+/// * `seed` - PRNG seed
+/// * `complexity` - Approximate number of nodes in the generated DAG
+#[module("ml")]
+#[wasm_bindgen(js_name = randomIR)]
+pub fn random_ir(seed: u64, complexity: u32) -> Result<String, JsError> {
+    let doc = vcad_ir::random_document(seed, complexity);
+    doc.to_json()
+        .map_err(|e| JsError::new(&format!("JSON serialization failed: {}", e)))
+}
+
+/// Compute node-level edits between two vcad IR Documents (JSON).
+///
+/// Returns a JSON array of add/remove/modify-node edits that turn the
+/// first document's graph into the second's. See `vcad_ir::diff`.
+///
+/// # Arguments
+/// * `a_json` - JSON string of the source document
+/// * `b_json` - JSON string of the target document
+#[module("ml")]
+#[wasm_bindgen(js_name = diffIR)]
+pub fn diff_ir(a_json: &str, b_json: &str) -> Result<String, JsError> {
+    let a = vcad_ir::Document::from_json(a_json)
+        .map_err(|e| JsError::new(&format!("Invalid JSON for first document: {}", e)))?;
+    let b = vcad_ir::Document::from_json(b_json)
+        .map_err(|e| JsError::new(&format!("Invalid JSON for second document: {}", e)))?;
+
+    let edits = vcad_ir::diff(&a, &b);
+    serde_json::to_string(&edits)
+        .map_err(|e| JsError::new(&format!("JSON serialization failed: {}", e)))
+}
+
+/// Per-item result of [`evaluate_compact_ir_batch`].
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CompactIrBatchResult {
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_vertices: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_triangles: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume: Option<f64>,
+}
+
+/// Evaluate one compact IR string with string errors for batch reporting.
+fn evaluate_compact_ir_item(compact_ir: &str) -> Result<vcad_kernel::Solid, String> {
+    let doc =
+        vcad_ir::compact::from_compact(compact_ir).map_err(|e| format!("Parse error: {}", e))?;
+
+    let root_id = doc
+        .roots
+        .first()
+        .ok_or_else(|| "Document has no root nodes".to_string())?
+        .root;
+
+    let solid = evaluate_node(&doc, root_id)
+        .map_err(|_| format!("Evaluation failed for root node {}", root_id))?;
+    Ok(solid.inner)
+}
+
+fn evaluate_compact_ir_batch_inner(ir_strings: &[String]) -> Vec<CompactIrBatchResult> {
+    ir_strings
+        .iter()
+        .map(|ir| match evaluate_compact_ir_item(ir) {
+            Ok(solid) => {
+                let mesh = solid.to_mesh(mesh_segments(&solid, None));
+                CompactIrBatchResult {
+                    ok: true,
+                    error: None,
+                    num_vertices: Some(mesh.vertices.len() / 3),
+                    num_triangles: Some(mesh.indices.len() / 3),
+                    volume: Some(solid.volume()),
+                }
+            }
+            Err(e) => CompactIrBatchResult {
+                ok: false,
+                error: Some(e),
+                num_vertices: None,
+                num_triangles: None,
+                volume: None,
+            },
+        })
+        .collect()
+}
+
+/// Evaluate many compact IR strings in one call.
+///
+/// Shares the WASM boundary crossing across a whole dataset batch: each
+/// string is parsed and evaluated independently, and a failure is reported
+/// in that item's entry rather than aborting the batch.
+///
+/// # Arguments
+/// * `ir_strings` - The compact IR texts to evaluate
+///
+/// # Returns
+/// An array with one entry per input: `{ok: true, numVertices, numTriangles,
+/// volume}` on success or `{ok: false, error}` on failure.
+#[module("ml")]
+#[wasm_bindgen(js_name = evaluateCompactIRBatch)]
+pub fn evaluate_compact_ir_batch(ir_strings: Vec<String>) -> Result<JsValue, JsError> {
+    let results = evaluate_compact_ir_batch_inner(&ir_strings);
+    results
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
+// =========================================================================
+// Feature graph
+// =========================================================================
+
+/// A node in the feature graph returned by [`solid_feature_graph`].
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeatureGraphNode {
+    id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    op_type: String,
+    params: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bounds: Option<vcad_ir::Bounds>,
+}
+
+/// A parent→child edge in the feature graph.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeatureGraphEdge {
+    parent: u64,
+    child: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeatureGraph {
+    nodes: Vec<FeatureGraphNode>,
+    edges: Vec<FeatureGraphEdge>,
+    roots: Vec<u64>,
+}
+
+fn feature_graph_inner(doc: &vcad_ir::Document) -> Result<FeatureGraph, String> {
+    let mut ids: Vec<u64> = doc.nodes.keys().copied().collect();
+    ids.sort_unstable();
+
+    let mut nodes = Vec::with_capacity(ids.len());
+    let mut edges = Vec::new();
+    for id in ids {
+        let node = &doc.nodes[&id];
+
+        // The op serializes as {"type": ..., ...params}; split the tag off
+        // so the UI gets opType and a clean parameter object
+        let mut op_json = serde_json::to_value(&node.op).map_err(|e| e.to_string())?;
+        let op_type = op_json
+            .get("type")
+            .and_then(|t| t.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if let Some(obj) = op_json.as_object_mut() {
+            obj.remove("type");
+        }
+
+        for child in node.op.children() {
+            edges.push(FeatureGraphEdge { parent: id, child });
+        }
+
+        nodes.push(FeatureGraphNode {
+            id,
+            name: node.name.clone(),
+            op_type,
+            params: op_json,
+            bounds: vcad_ir::estimate_bounds(doc, id),
+        });
+    }
+
+    Ok(FeatureGraph {
+        nodes,
+        edges,
+        roots: doc.roots.iter().map(|r| r.root).collect(),
+    })
+}
+
+/// Compute the feature tree of an IR document as a graph for UI display.
+///
+/// Returns `{nodes, edges, roots}` where each node carries its `id`,
+/// optional `name`, `opType`, a `params` object with the operation's
+/// parameters, and an estimated `bounds` box (`{min, max}`, omitted when
+/// the extent cannot be derived from parameters — see
+/// `vcad_ir::estimate_bounds`). Edges run from parent operation to child
+/// operand, so a front-end can draw the model tree without evaluating
+/// any geometry.
+///
+/// # Arguments
+/// * `doc_json` - JSON string representing a vcad IR Document
+#[wasm_bindgen(js_name = solidFeatureGraph)]
+pub fn solid_feature_graph(doc_json: &str) -> Result<JsValue, JsError> {
+    let doc = vcad_ir::Document::from_json(doc_json)
+        .map_err(|e| JsError::new(&format!("Invalid JSON: {}", e)))?;
+    let graph = feature_graph_inner(&doc).map_err(|e| JsError::new(&e))?;
+    graph
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
 // =========================================================================
 // Physics Simulation (Rapier-based gym environment)
 // =========================================================================
@@ -2531,10 +3954,7 @@ impl PhysicsSim {
         let env = vcad_kernel_physics::RobotEnv::new(doc, end_effector_ids, dt, substeps)
             .map_err(|e| JsError::new(&format!("Failed to create physics env: {}", e)))?;
 
-        web_sys::console::log_1(&format!(
-            "[WASM] PhysicsSim created with {} joints",
-            env.num_joints()
-        ).into());
+        console_log!("[WASM] PhysicsSim created with {} joints", env.num_joints());
 
         Ok(PhysicsSim { env })
     }
@@ -2620,6 +4040,55 @@ impl PhysicsSim {
         serde_wasm_bindgen::to_value(&obs).unwrap_or(JsValue::NULL)
     }
 
+    /// Compute end-effector poses for a joint configuration without
+    /// advancing the simulation.
+    ///
+    /// # Arguments
+    /// * `joint_positions` - Joint positions in observation order (degrees
+    ///   for revolute, mm for prismatic)
+    ///
+    /// # Returns
+    /// Array of `[x, y, z, qw, qx, qy, qz]` world poses in meters, one per
+    /// tracked end effector.
+    #[wasm_bindgen(js_name = forwardKinematics)]
+    pub fn forward_kinematics(&self, joint_positions: Vec<f64>) -> JsValue {
+        let poses = self.env.forward_kinematics(&joint_positions);
+        serde_wasm_bindgen::to_value(&poses).unwrap_or(JsValue::NULL)
+    }
+
+    /// Solve for joint positions that place an end effector at a target
+    /// pose (damped-least-squares Jacobian IK).
+    ///
+    /// # Arguments
+    /// * `ee_index` - Index of the tracked end effector
+    /// * `target_pos` - Target position `[x, y, z]` in meters
+    /// * `target_quat` - Target orientation `[w, x, y, z]`
+    ///
+    /// # Returns
+    /// Array of joint positions (degrees for revolute, mm for prismatic),
+    /// or an error if the target is unreachable.
+    #[wasm_bindgen(js_name = inverseKinematics)]
+    pub fn inverse_kinematics(
+        &self,
+        ee_index: usize,
+        target_pos: Vec<f64>,
+        target_quat: Vec<f64>,
+    ) -> Result<JsValue, JsError> {
+        let pos: [f64; 3] = target_pos
+            .try_into()
+            .map_err(|_| JsError::new("target_pos must have 3 components"))?;
+        let quat: [f64; 4] = target_quat
+            .try_into()
+            .map_err(|_| JsError::new("target_quat must have 4 components"))?;
+
+        let solution = self
+            .env
+            .inverse_kinematics(ee_index, pos, quat)
+            .map_err(|e| JsError::new(&format!("IK failed: {}", e)))?;
+        serde_wasm_bindgen::to_value(&solution)
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
     /// Get the number of joints in the environment.
     #[wasm_bindgen(js_name = numJoints)]
     pub fn num_joints(&self) -> usize {
@@ -2638,6 +4107,23 @@ impl PhysicsSim {
         self.env.action_dim()
     }
 
+    /// Set the gravity vector in m/s² (default: `(0, -9.81, 0)`).
+    ///
+    /// The override persists across resets.
+    #[wasm_bindgen(js_name = setGravity)]
+    pub fn set_gravity(&mut self, x: f32, y: f32, z: f32) {
+        self.env.set_gravity(x, y, z);
+    }
+
+    /// Set linear and angular damping on every rigid body, modelling drag
+    /// from the surrounding medium.
+    ///
+    /// The override persists across resets.
+    #[wasm_bindgen(js_name = setGlobalDamping)]
+    pub fn set_global_damping(&mut self, linear: f32, angular: f32) {
+        self.env.set_global_damping(linear, angular);
+    }
+
     /// Set the maximum episode length.
     #[wasm_bindgen(js_name = setMaxSteps)]
     pub fn set_max_steps(&mut self, max_steps: u32) {
@@ -2651,6 +4137,102 @@ impl PhysicsSim {
     }
 }
 
+/// Batch of independent physics environments for parallel RL rollouts.
+///
+/// All environments are built from the same document but evolve
+/// independently. In WASM the batch is stepped sequentially; the native
+/// crate can fan out over threads.
+#[cfg(feature = "physics")]
+#[wasm_bindgen]
+pub struct VecPhysicsSim {
+    envs: vcad_kernel_physics::VecRobotEnv,
+}
+
+#[cfg(feature = "physics")]
+#[wasm_bindgen]
+impl VecPhysicsSim {
+    /// Create `num_envs` physics environments from a vcad document JSON.
+    ///
+    /// Arguments otherwise match [`PhysicsSim::new`].
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        doc_json: &str,
+        num_envs: usize,
+        end_effector_ids: Vec<String>,
+        dt: Option<f32>,
+        substeps: Option<u32>,
+    ) -> Result<VecPhysicsSim, JsError> {
+        let doc = vcad_ir::Document::from_json(doc_json)
+            .map_err(|e| JsError::new(&format!("Invalid document JSON: {}", e)))?;
+
+        let envs =
+            vcad_kernel_physics::VecRobotEnv::new(doc, num_envs, end_effector_ids, dt, substeps)
+                .map_err(|e| JsError::new(&format!("Failed to create physics envs: {}", e)))?;
+
+        Ok(VecPhysicsSim { envs })
+    }
+
+    /// Step every environment with its own torque action.
+    ///
+    /// # Arguments
+    /// * `actions` - Array of per-environment torque arrays (Nm or N)
+    ///
+    /// # Returns
+    /// Array of `{ observation, reward, done }` objects, one per
+    /// environment, in order.
+    #[wasm_bindgen(js_name = stepBatch)]
+    pub fn step_batch(&mut self, actions: JsValue) -> Result<JsValue, JsError> {
+        let torques: Vec<Vec<f64>> = serde_wasm_bindgen::from_value(actions)
+            .map_err(|e| JsError::new(&format!("Invalid actions: {}", e)))?;
+
+        let results = self.envs.step_batch(
+            torques
+                .into_iter()
+                .map(vcad_kernel_physics::Action::Torque)
+                .collect(),
+        );
+
+        let results: Vec<serde_json::Value> = results
+            .into_iter()
+            .map(|(obs, reward, done)| {
+                serde_json::json!({
+                    "observation": obs,
+                    "reward": reward,
+                    "done": done
+                })
+            })
+            .collect();
+        serde_wasm_bindgen::to_value(&results)
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+    }
+
+    /// Reset every environment and return the initial observations.
+    #[wasm_bindgen(js_name = resetAll)]
+    pub fn reset_all(&mut self) -> JsValue {
+        let obs = self.envs.reset_all();
+        serde_wasm_bindgen::to_value(&obs).unwrap_or(JsValue::NULL)
+    }
+
+    /// Current observations for every environment without stepping.
+    #[wasm_bindgen(js_name = observeAll)]
+    pub fn observe_all(&self) -> JsValue {
+        let obs = self.envs.observe_all();
+        serde_wasm_bindgen::to_value(&obs).unwrap_or(JsValue::NULL)
+    }
+
+    /// Number of environments in the batch.
+    #[wasm_bindgen(js_name = numEnvs)]
+    pub fn num_envs(&self) -> usize {
+        self.envs.num_envs()
+    }
+
+    /// Number of joints per environment.
+    #[wasm_bindgen(js_name = numJoints)]
+    pub fn num_joints(&self) -> usize {
+        self.envs.num_joints()
+    }
+}
+
 /// Stub PhysicsSim when physics feature is not enabled.
 #[cfg(not(feature = "physics"))]
 #[wasm_bindgen]
@@ -2667,7 +4249,9 @@ impl PhysicsSim {
         _dt: Option<f32>,
         _substeps: Option<u32>,
     ) -> Result<PhysicsSim, JsError> {
-        Err(JsError::new("Physics feature not enabled. Compile with --features physics"))
+        Err(JsError::new(
+            "Physics feature not enabled. Compile with --features physics",
+        ))
     }
 }
 
@@ -2684,45 +4268,92 @@ pub fn is_physics_available() -> bool {
 
 /// Recursively evaluate a node in the IR DAG.
 fn evaluate_node(doc: &vcad_ir::Document, node_id: vcad_ir::NodeId) -> Result<Solid, JsError> {
-    let node = doc.nodes.get(&node_id)
+    let node = doc
+        .nodes
+        .get(&node_id)
         .ok_or_else(|| JsError::new(&format!("Node {} not found", node_id)))?;
 
+    let solid = evaluate_node_op(doc, node)?;
+
+    // Tag the node's material so volumeByMaterial can break volumes down
+    Ok(match &node.material {
+        Some(tag) => Solid {
+            inner: solid.inner.with_material_tag(tag),
+        },
+        None => solid,
+    })
+}
+
+fn evaluate_node_op(doc: &vcad_ir::Document, node: &vcad_ir::Node) -> Result<Solid, JsError> {
     match &node.op {
-        vcad_ir::CsgOp::Cube { size } => Ok(Solid::cube(size.x, size.y, size.z)),
+        vcad_ir::CsgOp::Cube { size } => Solid::cube(size.x, size.y, size.z),
 
-        vcad_ir::CsgOp::Cylinder { radius, height, segments } => {
-            let segs = if *segments == 0 { None } else { Some(*segments) };
-            Ok(Solid::cylinder(*radius, *height, segs))
+        vcad_ir::CsgOp::Cylinder {
+            radius,
+            height,
+            segments,
+        } => {
+            let segs = if *segments == 0 {
+                None
+            } else {
+                Some(*segments)
+            };
+            Solid::cylinder(*radius, *height, segs)
         }
 
         vcad_ir::CsgOp::Sphere { radius, segments } => {
-            let segs = if *segments == 0 { None } else { Some(*segments) };
-            Ok(Solid::sphere(*radius, segs))
+            let segs = if *segments == 0 {
+                None
+            } else {
+                Some(*segments)
+            };
+            Solid::sphere(*radius, segs)
         }
 
-        vcad_ir::CsgOp::Cone { radius_bottom, radius_top, height, segments } => {
-            let segs = if *segments == 0 { None } else { Some(*segments) };
-            Ok(Solid::cone(*radius_bottom, *radius_top, *height, segs))
+        vcad_ir::CsgOp::Cone {
+            radius_bottom,
+            radius_top,
+            height,
+            segments,
+        } => {
+            let segs = if *segments == 0 {
+                None
+            } else {
+                Some(*segments)
+            };
+            Solid::cone(*radius_bottom, *radius_top, *height, segs)
         }
 
+        vcad_ir::CsgOp::PolygonPrism {
+            sides,
+            circumradius,
+            height,
+        } => Solid::polygon_prism(*sides, *circumradius, *height),
+
+        vcad_ir::CsgOp::Pyramid {
+            base_sides,
+            circumradius,
+            height,
+        } => Solid::pyramid(*base_sides, *circumradius, *height),
+
         vcad_ir::CsgOp::Empty => Ok(Solid::empty()),
 
         vcad_ir::CsgOp::Union { left, right } => {
             let l = evaluate_node(doc, *left)?;
             let r = evaluate_node(doc, *right)?;
-            Ok(l.union(&r))
+            l.union(&r)
         }
 
         vcad_ir::CsgOp::Difference { left, right } => {
             let l = evaluate_node(doc, *left)?;
             let r = evaluate_node(doc, *right)?;
-            Ok(l.difference(&r))
+            l.difference(&r)
         }
 
         vcad_ir::CsgOp::Intersection { left, right } => {
             let l = evaluate_node(doc, *left)?;
             let r = evaluate_node(doc, *right)?;
-            Ok(l.intersection(&r))
+            l.intersection(&r)
         }
 
         vcad_ir::CsgOp::Translate { child, offset } => {
@@ -2740,65 +4371,99 @@ fn evaluate_node(doc: &vcad_ir::Document, node_id: vcad_ir::NodeId) -> Result<So
             Ok(c.scale(factor.x, factor.y, factor.z))
         }
 
-        vcad_ir::CsgOp::LinearPattern { child, direction, count, spacing } => {
+        vcad_ir::CsgOp::LinearPattern {
+            child,
+            direction,
+            count,
+            spacing,
+        } => {
             let c = evaluate_node(doc, *child)?;
             Ok(c.linear_pattern(direction.x, direction.y, direction.z, *count, *spacing))
         }
 
-        vcad_ir::CsgOp::CircularPattern { child, axis_origin, axis_dir, count, angle_deg } => {
+        vcad_ir::CsgOp::CircularPattern {
+            child,
+            axis_origin,
+            axis_dir,
+            count,
+            angle_deg,
+        } => {
             let c = evaluate_node(doc, *child)?;
             Ok(c.circular_pattern(
-                axis_origin.x, axis_origin.y, axis_origin.z,
-                axis_dir.x, axis_dir.y, axis_dir.z,
-                *count, *angle_deg
+                axis_origin.x,
+                axis_origin.y,
+                axis_origin.z,
+                axis_dir.x,
+                axis_dir.y,
+                axis_dir.z,
+                *count,
+                *angle_deg,
             ))
         }
 
         vcad_ir::CsgOp::Shell { child, thickness } => {
             let c = evaluate_node(doc, *child)?;
-            Ok(c.shell(*thickness))
+            c.shell(*thickness)
         }
 
         vcad_ir::CsgOp::Fillet { child, radius } => {
             let c = evaluate_node(doc, *child)?;
-            Ok(c.fillet(*radius))
+            c.fillet(*radius)
         }
 
         vcad_ir::CsgOp::Chamfer { child, distance } => {
             let c = evaluate_node(doc, *child)?;
-            Ok(c.chamfer(*distance))
+            c.chamfer(*distance)
         }
 
         vcad_ir::CsgOp::Sketch2D { .. } => {
             // Sketch2D nodes cannot be evaluated directly - they must be used with Extrude/Revolve
-            Err(JsError::new("Sketch2D cannot be evaluated directly - use Extrude or Revolve"))
+            Err(JsError::new(
+                "Sketch2D cannot be evaluated directly - use Extrude or Revolve",
+            ))
         }
 
-        vcad_ir::CsgOp::Extrude { sketch, direction, twist_angle, scale_end } => {
+        vcad_ir::CsgOp::Extrude {
+            sketch,
+            direction,
+            twist_angle,
+            scale_end,
+        } => {
             // Get the sketch node
-            let sketch_node = doc.nodes.get(sketch)
+            let sketch_node = doc
+                .nodes
+                .get(sketch)
                 .ok_or_else(|| JsError::new(&format!("Sketch node {} not found", sketch)))?;
 
             match &sketch_node.op {
-                vcad_ir::CsgOp::Sketch2D { origin, x_dir, y_dir, segments } => {
-                    let wasm_segments: Vec<WasmSketchSegment> = segments.iter().map(|seg| {
-                        match seg {
+                vcad_ir::CsgOp::Sketch2D {
+                    origin,
+                    x_dir,
+                    y_dir,
+                    segments,
+                } => {
+                    let wasm_segments: Vec<WasmSketchSegment> = segments
+                        .iter()
+                        .map(|seg| match seg {
                             vcad_ir::SketchSegment2D::Line { start, end } => {
                                 WasmSketchSegment::Line {
                                     start: [start.x, start.y],
                                     end: [end.x, end.y],
                                 }
                             }
-                            vcad_ir::SketchSegment2D::Arc { start, end, center, ccw } => {
-                                WasmSketchSegment::Arc {
-                                    start: [start.x, start.y],
-                                    end: [end.x, end.y],
-                                    center: [center.x, center.y],
-                                    ccw: *ccw,
-                                }
-                            }
-                        }
-                    }).collect();
+                            vcad_ir::SketchSegment2D::Arc {
+                                start,
+                                end,
+                                center,
+                                ccw,
+                            } => WasmSketchSegment::Arc {
+                                start: [start.x, start.y],
+                                end: [end.x, end.y],
+                                center: [center.x, center.y],
+                                ccw: *ccw,
+                            },
+                        })
+                        .collect();
 
                     let profile = WasmSketchProfile {
                         origin: [origin.x, origin.y, origin.z],
@@ -2807,8 +4472,9 @@ fn evaluate_node(doc: &vcad_ir::Document, node_id: vcad_ir::NodeId) -> Result<So
                         segments: wasm_segments,
                     };
 
-                    let profile_js = serde_wasm_bindgen::to_value(&profile)
-                        .map_err(|e| JsError::new(&format!("Profile serialization failed: {}", e)))?;
+                    let profile_js = serde_wasm_bindgen::to_value(&profile).map_err(|e| {
+                        JsError::new(&format!("Profile serialization failed: {}", e))
+                    })?;
 
                     // Use extrudeWithOptions if twist or scale is specified
                     let has_twist = twist_angle.is_some_and(|t| t.abs() > 1e-12);
@@ -2824,34 +4490,50 @@ fn evaluate_node(doc: &vcad_ir::Document, node_id: vcad_ir::NodeId) -> Result<So
                         Solid::extrude(profile_js, vec![direction.x, direction.y, direction.z])
                     }
                 }
-                _ => Err(JsError::new("Extrude requires a Sketch2D node"))
+                _ => Err(JsError::new("Extrude requires a Sketch2D node")),
             }
         }
 
-        vcad_ir::CsgOp::Revolve { sketch, axis_origin, axis_dir, angle_deg } => {
-            let sketch_node = doc.nodes.get(sketch)
+        vcad_ir::CsgOp::Revolve {
+            sketch,
+            axis_origin,
+            axis_dir,
+            angle_deg,
+        } => {
+            let sketch_node = doc
+                .nodes
+                .get(sketch)
                 .ok_or_else(|| JsError::new(&format!("Sketch node {} not found", sketch)))?;
 
             match &sketch_node.op {
-                vcad_ir::CsgOp::Sketch2D { origin, x_dir, y_dir, segments } => {
-                    let wasm_segments: Vec<WasmSketchSegment> = segments.iter().map(|seg| {
-                        match seg {
+                vcad_ir::CsgOp::Sketch2D {
+                    origin,
+                    x_dir,
+                    y_dir,
+                    segments,
+                } => {
+                    let wasm_segments: Vec<WasmSketchSegment> = segments
+                        .iter()
+                        .map(|seg| match seg {
                             vcad_ir::SketchSegment2D::Line { start, end } => {
                                 WasmSketchSegment::Line {
                                     start: [start.x, start.y],
                                     end: [end.x, end.y],
                                 }
                             }
-                            vcad_ir::SketchSegment2D::Arc { start, end, center, ccw } => {
-                                WasmSketchSegment::Arc {
-                                    start: [start.x, start.y],
-                                    end: [end.x, end.y],
-                                    center: [center.x, center.y],
-                                    ccw: *ccw,
-                                }
-                            }
-                        }
-                    }).collect();
+                            vcad_ir::SketchSegment2D::Arc {
+                                start,
+                                end,
+                                center,
+                                ccw,
+                            } => WasmSketchSegment::Arc {
+                                start: [start.x, start.y],
+                                end: [end.x, end.y],
+                                center: [center.x, center.y],
+                                ccw: *ccw,
+                            },
+                        })
+                        .collect();
 
                     let profile = WasmSketchProfile {
                         origin: [origin.x, origin.y, origin.z],
@@ -2860,8 +4542,9 @@ fn evaluate_node(doc: &vcad_ir::Document, node_id: vcad_ir::NodeId) -> Result<So
                         segments: wasm_segments,
                     };
 
-                    let profile_js = serde_wasm_bindgen::to_value(&profile)
-                        .map_err(|e| JsError::new(&format!("Profile serialization failed: {}", e)))?;
+                    let profile_js = serde_wasm_bindgen::to_value(&profile).map_err(|e| {
+                        JsError::new(&format!("Profile serialization failed: {}", e))
+                    })?;
 
                     Solid::revolve(
                         profile_js,
@@ -2870,13 +4553,13 @@ fn evaluate_node(doc: &vcad_ir::Document, node_id: vcad_ir::NodeId) -> Result<So
                         *angle_deg,
                     )
                 }
-                _ => Err(JsError::new("Revolve requires a Sketch2D node"))
+                _ => Err(JsError::new("Revolve requires a Sketch2D node")),
             }
         }
 
-        vcad_ir::CsgOp::StepImport { .. } => {
-            Err(JsError::new("STEP import not supported in compact IR evaluation"))
-        }
+        vcad_ir::CsgOp::StepImport { .. } => Err(JsError::new(
+            "STEP import not supported in compact IR evaluation",
+        )),
 
         vcad_ir::CsgOp::Text2D { .. } => {
             // Text2D doesn't produce geometry by itself - it needs to be extruded.
@@ -2887,7 +4570,9 @@ fn evaluate_node(doc: &vcad_ir::Document, node_id: vcad_ir::NodeId) -> Result<So
             // 1. Create a Text2D node
             // 2. Use it as the sketch input to an Extrude operation
             // The TypeScript evaluate.ts handles converting Text2D inside Extrude
-            Err(JsError::new("Text2D cannot be evaluated directly - use Extrude to convert to solid"))
+            Err(JsError::new(
+                "Text2D cannot be evaluated directly - use Extrude to convert to solid",
+            ))
         }
     }
 }
@@ -2976,6 +4661,7 @@ mod slicer_wasm {
                 },
                 support_enabled: settings.support_enabled,
                 support_angle: settings.support_angle,
+                ..Self::default()
             }
         }
     }
@@ -3081,19 +4767,124 @@ mod slicer_wasm {
     }
 
     /// Slice a solid.
+    ///
+    /// B-rep solids are sliced directly (exact contours for planes and
+    /// vertical cylinders); mesh-only solids fall back to slicing their
+    /// tessellation at `segments` resolution.
     #[wasm_bindgen(js_name = sliceSolid)]
     pub fn slice_solid(
         solid: &Solid,
         settings: &SlicerSettings,
         segments: Option<u32>,
     ) -> Result<SliceResult, JsError> {
-        let mesh = solid.inner.to_mesh(segments.unwrap_or(32));
         let slice_settings: SliceSettings = settings.clone().into();
-        let result =
-            vcad_slicer::slice(&mesh, &slice_settings).map_err(|e| JsError::new(&e.to_string()))?;
+        let result = if let Some(brep) = solid.inner.brep() {
+            let params = vcad_kernel_tessellate::TessellationParams {
+                circle_segments: mesh_segments(&solid.inner, segments),
+                ..Default::default()
+            };
+            vcad_slicer::slice_solid(brep, &slice_settings, &params)
+                .map_err(|e| JsError::new(&e.to_string()))?
+        } else {
+            let mesh = solid.inner.to_mesh(mesh_segments(&solid.inner, segments));
+            vcad_slicer::slice(&mesh, &slice_settings).map_err(|e| JsError::new(&e.to_string()))?
+        };
         Ok(SliceResult { inner: result })
     }
 
+    /// Look up a built-in printer profile by its short id.
+    fn profile_from_id(profile_id: &str) -> PrinterProfile {
+        match profile_id {
+            "bambu_x1c" => PrinterProfile::bambu_x1c(),
+            "bambu_p1s" => PrinterProfile::bambu_p1s(),
+            "bambu_a1" => PrinterProfile::bambu_a1(),
+            "ender3" => PrinterProfile::ender3(),
+            "prusa_mk4" => PrinterProfile::prusa_mk4(),
+            "voron_24" => PrinterProfile::voron_24(),
+            _ => PrinterProfile::generic(),
+        }
+    }
+
+    #[wasm_bindgen]
+    impl Solid {
+        /// Run the aggregate printability check against a printer profile.
+        ///
+        /// Combines bed fit, overhangs, thin walls, trapped cavities, and a
+        /// rough support-volume estimate into one report with a
+        /// `"pass"`/`"warn"`/`"fail"` status. `settings_json` may override
+        /// `{ "overhangAngle", "minWallThickness" }`; pass `"{}"` for the
+        /// defaults.
+        #[wasm_bindgen(js_name = printabilityReport)]
+        pub fn printability_report(
+            &self,
+            profile_id: &str,
+            settings_json: &str,
+        ) -> Result<JsValue, JsError> {
+            #[derive(Deserialize)]
+            #[serde(rename_all = "camelCase", default)]
+            struct WasmPrintabilitySettings {
+                overhang_angle: f64,
+                min_wall_thickness: f64,
+            }
+
+            impl Default for WasmPrintabilitySettings {
+                fn default() -> Self {
+                    let defaults = vcad_kernel::PrintabilitySettings::default();
+                    Self {
+                        overhang_angle: defaults.overhang_angle,
+                        min_wall_thickness: defaults.min_wall_thickness,
+                    }
+                }
+            }
+
+            #[derive(Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct WasmPrintabilityReport {
+                status: &'static str,
+                fits_bed: bool,
+                size: [f64; 3],
+                overhang_area: f64,
+                overhang_triangles: usize,
+                support_volume: f64,
+                thin_wall_area: f64,
+                thin_wall_triangles: usize,
+                trapped_cavities: usize,
+                trapped_cavity_volume: f64,
+            }
+
+            let settings: WasmPrintabilitySettings = serde_json::from_str(settings_json)
+                .map_err(|e| JsError::new(&format!("Invalid settings: {}", e)))?;
+            let profile = profile_from_id(profile_id);
+
+            let report = self.inner.printability_report(
+                [profile.bed_x, profile.bed_y, profile.bed_z],
+                &vcad_kernel::PrintabilitySettings {
+                    overhang_angle: settings.overhang_angle,
+                    min_wall_thickness: settings.min_wall_thickness,
+                },
+            );
+
+            WasmPrintabilityReport {
+                status: match report.status {
+                    vcad_kernel::PrintabilityStatus::Pass => "pass",
+                    vcad_kernel::PrintabilityStatus::Warn => "warn",
+                    vcad_kernel::PrintabilityStatus::Fail => "fail",
+                },
+                fits_bed: report.fits_bed,
+                size: report.size,
+                overhang_area: report.overhang_area,
+                overhang_triangles: report.overhang_triangles,
+                support_volume: report.support_volume,
+                thin_wall_area: report.thin_wall_area,
+                thin_wall_triangles: report.thin_wall_triangles,
+                trapped_cavities: report.trapped_cavities,
+                trapped_cavity_volume: report.trapped_cavity_volume,
+            }
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| JsError::new(&format!("Serialization failed: {}", e)))
+        }
+    }
+
     /// Generate G-code from slice result.
     #[wasm_bindgen(js_name = generateGcode)]
     pub fn generate_gcode(
@@ -3102,15 +4893,7 @@ mod slicer_wasm {
         print_temp: u32,
         bed_temp: u32,
     ) -> Result<String, JsError> {
-        let profile = match printer_profile {
-            "bambu_x1c" => PrinterProfile::bambu_x1c(),
-            "bambu_p1s" => PrinterProfile::bambu_p1s(),
-            "bambu_a1" => PrinterProfile::bambu_a1(),
-            "ender3" => PrinterProfile::ender3(),
-            "prusa_mk4" => PrinterProfile::prusa_mk4(),
-            "voron_24" => PrinterProfile::voron_24(),
-            _ => PrinterProfile::generic(),
-        };
+        let profile = profile_from_id(printer_profile);
 
         let settings = GcodeSettings {
             printer: profile,
@@ -3686,3 +5469,230 @@ mod cam_wasm {
 // Re-export CAM types at module level when feature is enabled
 #[cfg(feature = "cam")]
 pub use cam_wasm::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_segments_used_when_omitted() {
+        set_default_segments(8);
+        let implicit = Solid::sphere(5.0, None).unwrap();
+        let explicit = Solid::sphere(5.0, Some(8)).unwrap();
+        assert_eq!(
+            implicit.inner.num_triangles(),
+            explicit.inner.num_triangles()
+        );
+
+        let coarse = Solid::sphere(5.0, Some(32)).unwrap();
+        assert_ne!(implicit.inner.num_triangles(), coarse.inner.num_triangles());
+        set_default_segments(32);
+    }
+
+    #[test]
+    fn test_chord_tolerance_scales_with_radius() {
+        // A tighter tolerance or a bigger radius needs more segments.
+        assert!(segments_from_tolerance(5.0, 0.01) < segments_from_tolerance(5.0, 0.001));
+        assert!(segments_from_tolerance(5.0, 0.01) < segments_from_tolerance(50.0, 0.01));
+        // Degenerate inputs fall back to the minimum.
+        assert_eq!(segments_from_tolerance(0.0, 0.01), 3);
+        assert_eq!(segments_from_tolerance(1.0, 2.0), 3);
+    }
+
+    #[test]
+    fn test_random_documents_evaluate_without_panicking() {
+        // Coarse tessellation keeps 100 evaluations fast
+        set_default_segments(8);
+        for seed in 0..100 {
+            let doc = vcad_ir::random_document(seed, 8);
+            let root_id = doc.roots[0].root;
+            let solid = evaluate_node(&doc, root_id)
+                .unwrap_or_else(|_| panic!("seed {seed} failed to evaluate"));
+            // Boolean chains may legitimately cancel out to nothing, but the
+            // result must still report a sane volume.
+            assert!(solid.inner.volume() >= 0.0);
+        }
+        set_default_segments(32);
+    }
+
+    #[test]
+    fn test_evaluate_compact_ir_batch_mixed_results() {
+        let batch = vec![
+            "C 10 10 10".to_string(),
+            "Y 5 10".to_string(),
+            "Q bogus line".to_string(),
+        ];
+        let results = evaluate_compact_ir_batch_inner(&batch);
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].ok);
+        assert!((results[0].volume.unwrap() - 1000.0).abs() < 1.0);
+        assert!(results[0].num_triangles.unwrap() > 0);
+        assert!(results[1].ok);
+        assert!(results[1].error.is_none());
+        assert!(!results[2].ok);
+        assert!(results[2].error.as_deref().unwrap().contains("Parse error"));
+    }
+
+    #[test]
+    fn test_panic_boundary_converts_panic_to_error() {
+        // A panicking kernel call must surface as an Err, not an abort
+        let result = catch_kernel_panic_msg("test op", || -> Solid {
+            panic!("unexpected topology state");
+        });
+        let msg = result.err().expect("panic should become an error");
+        assert!(msg.contains("Kernel panic in test op"));
+        assert!(msg.contains("unexpected topology state"));
+
+        // Well-formed operations still succeed through the boundary
+        let a = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let b = Solid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(5.0, 5.0, 5.0);
+        let joined = a.union(&b).expect("union through panic boundary");
+        assert!(joined.inner.volume() > 1000.0);
+    }
+
+    #[test]
+    fn test_to_dxf_view_front_square_outline() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let bytes = cube.to_dxf_view("front", None);
+        let text = String::from_utf8(bytes).unwrap();
+
+        // Collect LINE entities on the VISIBLE layer. Each entity is a fixed
+        // group-code sequence: 0 LINE, 8 layer, 6 linetype, 10/20 start,
+        // 11/21 end.
+        let lines: Vec<&str> = text.lines().collect();
+        let mut visible: Vec<[f64; 4]> = Vec::new();
+        for i in 0..lines.len() {
+            if lines[i] == "LINE" && lines[i + 2] == "VISIBLE" {
+                let coord = |offset: usize| lines[i + offset].parse::<f64>().unwrap();
+                visible.push([coord(6), coord(8), coord(10), coord(12)]);
+            }
+        }
+
+        // Front view of a cube is its square outline
+        assert_eq!(visible.len(), 4, "Expected 4 visible LINE entities");
+        for [x1, y1, x2, y2] in &visible {
+            let axis_aligned = (x1 - x2).abs() < 1e-6 || (y1 - y2).abs() < 1e-6;
+            let len = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt();
+            assert!(axis_aligned, "Outline edge should be axis-aligned");
+            assert!((len - 10.0).abs() < 1e-6, "Outline edge should span 10mm");
+        }
+    }
+
+    #[test]
+    fn test_to_dxf_drawing_four_view_regions() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let bytes = cube.to_dxf_drawing(None, "third-angle");
+        let text = String::from_utf8(bytes).unwrap();
+
+        // Midpoints of view entities (BORDER excluded) and border line count
+        let lines: Vec<&str> = text.lines().collect();
+        let mut midpoints: Vec<(f64, f64)> = Vec::new();
+        let mut border_lines = 0;
+        for i in 0..lines.len() {
+            if lines[i] == "LINE" {
+                if lines[i + 2] == "BORDER" {
+                    border_lines += 1;
+                    continue;
+                }
+                let coord = |offset: usize| lines[i + offset].parse::<f64>().unwrap();
+                midpoints.push(((coord(6) + coord(10)) / 2.0, (coord(8) + coord(12)) / 2.0));
+            }
+        }
+
+        // Sheet frame plus two title-block lines
+        assert_eq!(border_lines, 6, "Expected border and title-block lines");
+
+        // The four views land in four distinct quadrants of the sheet
+        let cx = midpoints.iter().map(|p| p.0).sum::<f64>() / midpoints.len() as f64;
+        let cy = midpoints.iter().map(|p| p.1).sum::<f64>() / midpoints.len() as f64;
+        let mut quadrants = [false; 4];
+        for (x, y) in &midpoints {
+            quadrants[((*x > cx) as usize) * 2 + (*y > cy) as usize] = true;
+        }
+        assert_eq!(
+            quadrants, [true; 4],
+            "Expected entities in all four view regions"
+        );
+    }
+
+    #[test]
+    fn test_get_mesh_buffers_matches_mesh() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let buffers = cube.get_mesh_buffers(Some(32));
+        let mesh = cube.inner.to_mesh(32);
+
+        // Counts agree with the raw tessellation
+        assert_eq!(buffers.vertex_count() as usize * 3, mesh.vertices.len());
+        assert_eq!(buffers.triangle_count() as usize * 3, mesh.indices.len());
+        assert!(buffers.triangle_count() >= 12);
+
+        // The held buffers are the tessellation output, not a re-ordering
+        assert_eq!(buffers.mesh.vertices[..9], mesh.vertices[..9]);
+        assert_eq!(buffers.mesh.indices[..9], mesh.indices[..9]);
+
+        // Every cube vertex lies on the 10 mm cube
+        for chunk in buffers.mesh.vertices.chunks(3) {
+            for &c in chunk {
+                assert!((-1e-4..=10.0001).contains(&(c as f64)), "coord {c}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_feature_graph_multi_op_document() {
+        // cube, translated cube, and their difference
+        let mut doc = vcad_ir::Document::new();
+        for (id, op) in [
+            (
+                0,
+                vcad_ir::CsgOp::Cube {
+                    size: vcad_ir::Vec3::new(20.0, 20.0, 5.0),
+                },
+            ),
+            (
+                1,
+                vcad_ir::CsgOp::Translate {
+                    child: 0,
+                    offset: vcad_ir::Vec3::new(5.0, 5.0, 0.0),
+                },
+            ),
+            (2, vcad_ir::CsgOp::Difference { left: 0, right: 1 }),
+        ] {
+            doc.nodes.insert(
+                id,
+                vcad_ir::Node {
+                    id,
+                    name: None,
+                    op,
+                    material: None,
+                },
+            );
+        }
+
+        let graph = feature_graph_inner(&doc).unwrap();
+        assert_eq!(graph.nodes.len(), 3);
+
+        // Edges reflect parent-child relationships
+        let mut edges: Vec<(u64, u64)> = graph.edges.iter().map(|e| (e.parent, e.child)).collect();
+        edges.sort_unstable();
+        assert_eq!(edges, vec![(1, 0), (2, 0), (2, 1)]);
+
+        // Nodes carry op type, parameters, and estimated bounds
+        let cube = &graph.nodes[0];
+        assert_eq!(cube.op_type, "Cube");
+        assert_eq!(cube.params["size"]["x"], 20.0);
+        let bounds = cube.bounds.unwrap();
+        assert_eq!(bounds.max, vcad_ir::Vec3::new(20.0, 20.0, 5.0));
+
+        // The difference keeps its left operand's bounds
+        let diff = &graph.nodes[2];
+        assert_eq!(diff.op_type, "Difference");
+        assert_eq!(
+            diff.bounds.unwrap().max,
+            vcad_ir::Vec3::new(20.0, 20.0, 5.0)
+        );
+    }
+}
@@ -0,0 +1,440 @@
+//! Mesh-to-mesh rigid registration via iterative closest point (ICP).
+//!
+//! Estimates the rigid transform (rotation + translation) that best maps a
+//! source mesh onto a target mesh, for comparing a manufactured scan against
+//! its nominal CAD model: align first, then measure deviations. Each iteration
+//! pairs every source point with its nearest target point and solves the
+//! point-to-point least-squares rotation in closed form (Horn's quaternion
+//! method), so no initial guess is required as long as the meshes roughly
+//! overlap.
+
+use vcad_kernel_math::{Point3, Transform, Vec3};
+use vcad_kernel_tessellate::TriangleMesh;
+
+/// Cap on the number of points per mesh used for correspondence search;
+/// denser meshes are subsampled by striding over their unique vertices.
+const MAX_SAMPLE_POINTS: usize = 2000;
+
+/// Result of [`align_meshes`]: the recovered rigid transform and the
+/// residual registration error.
+#[derive(Debug, Clone)]
+pub struct MeshAlignment {
+    /// Row-major 4×4 rigid transform mapping source points onto the target,
+    /// in the layout accepted by [`Transform::from_row_major`].
+    pub matrix: [f64; 16],
+    /// Root-mean-square distance from the transformed source points to their
+    /// nearest target points.
+    pub rms_error: f64,
+}
+
+/// Align `source` onto `target` by point-to-point ICP, running at most
+/// `iterations` refinement steps (stopping early once the residual stops
+/// improving). Returns the accumulated rigid transform and the final RMS
+/// error; degenerate inputs (fewer than three points) yield the identity.
+pub fn align_meshes(
+    source: &TriangleMesh,
+    target: &TriangleMesh,
+    iterations: u32,
+) -> MeshAlignment {
+    let src = sample_points(source);
+    let dst = sample_points(target);
+    if src.len() < 3 || dst.len() < 3 {
+        return MeshAlignment {
+            matrix: to_row_major(&Transform::identity()),
+            rms_error: f64::NAN,
+        };
+    }
+
+    let mut total = Transform::identity();
+    let mut rms = f64::MAX;
+    for _ in 0..iterations {
+        let moved: Vec<Point3> = src.iter().map(|p| total.apply_point(p)).collect();
+        let paired: Vec<Point3> = moved.iter().map(|p| nearest_point(p, &dst)).collect();
+
+        let sq_sum: f64 = moved
+            .iter()
+            .zip(&paired)
+            .map(|(p, q)| (p - q).norm_squared())
+            .sum();
+        let new_rms = (sq_sum / moved.len() as f64).sqrt();
+        if new_rms >= rms - 1e-12 {
+            break;
+        }
+        rms = new_rms;
+
+        let step = rigid_fit(&moved, &paired);
+        total = step.then(&total);
+    }
+
+    // Residual after the last accepted step.
+    let sq_sum: f64 = src
+        .iter()
+        .map(|p| {
+            let moved = total.apply_point(p);
+            (moved - nearest_point(&moved, &dst)).norm_squared()
+        })
+        .sum();
+    MeshAlignment {
+        matrix: to_row_major(&total),
+        rms_error: (sq_sum / src.len() as f64).sqrt(),
+    }
+}
+
+/// Unique vertex positions of a mesh, strided down to [`MAX_SAMPLE_POINTS`].
+/// Tessellation duplicates vertices per face, so dedup by exact bit pattern.
+fn sample_points(mesh: &TriangleMesh) -> Vec<Point3> {
+    let mut seen = std::collections::HashSet::new();
+    let mut points = Vec::new();
+    for v in mesh.vertices.chunks_exact(3) {
+        if seen.insert((v[0].to_bits(), v[1].to_bits(), v[2].to_bits())) {
+            points.push(Point3::new(v[0] as f64, v[1] as f64, v[2] as f64));
+        }
+    }
+    if points.len() > MAX_SAMPLE_POINTS {
+        let stride = points.len().div_ceil(MAX_SAMPLE_POINTS);
+        points = points.into_iter().step_by(stride).collect();
+    }
+    points
+}
+
+/// Brute-force nearest neighbour of `p` among `candidates` (non-empty).
+fn nearest_point(p: &Point3, candidates: &[Point3]) -> Point3 {
+    let mut best = candidates[0];
+    let mut best_sq = (p - best).norm_squared();
+    for q in &candidates[1..] {
+        let sq = (p - q).norm_squared();
+        if sq < best_sq {
+            best_sq = sq;
+            best = *q;
+        }
+    }
+    best
+}
+
+/// Closed-form least-squares rigid transform mapping `src` onto `dst`
+/// (Horn's quaternion method): build the 4×4 symmetric matrix from the
+/// cross-covariance of the centred point sets and take the eigenvector of its
+/// largest eigenvalue as the rotation quaternion.
+fn rigid_fit(src: &[Point3], dst: &[Point3]) -> Transform {
+    let n = src.len() as f64;
+    let cs = src.iter().fold(Vec3::zeros(), |a, p| a + p.coords) / n;
+    let cd = dst.iter().fold(Vec3::zeros(), |a, p| a + p.coords) / n;
+
+    let mut s = [[0.0_f64; 3]; 3];
+    for (p, q) in src.iter().zip(dst) {
+        let a = p.coords - cs;
+        let b = q.coords - cd;
+        for (row, s_row) in s.iter_mut().enumerate() {
+            for (col, entry) in s_row.iter_mut().enumerate() {
+                *entry += a[row] * b[col];
+            }
+        }
+    }
+
+    let m = [
+        [
+            s[0][0] + s[1][1] + s[2][2],
+            s[1][2] - s[2][1],
+            s[2][0] - s[0][2],
+            s[0][1] - s[1][0],
+        ],
+        [
+            s[1][2] - s[2][1],
+            s[0][0] - s[1][1] - s[2][2],
+            s[0][1] + s[1][0],
+            s[2][0] + s[0][2],
+        ],
+        [
+            s[2][0] - s[0][2],
+            s[0][1] + s[1][0],
+            -s[0][0] + s[1][1] - s[2][2],
+            s[1][2] + s[2][1],
+        ],
+        [
+            s[0][1] - s[1][0],
+            s[2][0] + s[0][2],
+            s[1][2] + s[2][1],
+            -s[0][0] - s[1][1] + s[2][2],
+        ],
+    ];
+    let q = dominant_eigenvector(&m);
+
+    let (w, x, y, z) = (q[0], q[1], q[2], q[3]);
+    let r = [
+        [
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y - w * z),
+            2.0 * (x * z + w * y),
+        ],
+        [
+            2.0 * (x * y + w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z - w * x),
+        ],
+        [
+            2.0 * (x * z - w * y),
+            2.0 * (y * z + w * x),
+            1.0 - 2.0 * (x * x + y * y),
+        ],
+    ];
+    let rotated_cs = Vec3::new(
+        r[0][0] * cs.x + r[0][1] * cs.y + r[0][2] * cs.z,
+        r[1][0] * cs.x + r[1][1] * cs.y + r[1][2] * cs.z,
+        r[2][0] * cs.x + r[2][1] * cs.y + r[2][2] * cs.z,
+    );
+    let t = cd - rotated_cs;
+
+    Transform::from_row_major(&[
+        r[0][0], r[0][1], r[0][2], t.x, r[1][0], r[1][1], r[1][2], t.y, r[2][0], r[2][1], r[2][2],
+        t.z, 0.0, 0.0, 0.0, 1.0,
+    ])
+}
+
+/// Eigenvector of the most-positive eigenvalue of a symmetric 4×4 matrix by
+/// power iteration, shifted by a Gershgorin bound so that eigenvalue also has
+/// the largest magnitude.
+fn dominant_eigenvector(m: &[[f64; 4]; 4]) -> [f64; 4] {
+    let shift = m
+        .iter()
+        .map(|row| row.iter().map(|v| v.abs()).sum::<f64>())
+        .fold(0.0_f64, f64::max);
+    let mut v = [1.0, 0.0, 0.0, 0.0];
+    for _ in 0..100 {
+        let mut next = [0.0_f64; 4];
+        for (row_idx, row) in m.iter().enumerate() {
+            next[row_idx] = shift * v[row_idx];
+            for (col, entry) in row.iter().enumerate() {
+                next[row_idx] += entry * v[col];
+            }
+        }
+        let norm = next.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm < 1e-30 {
+            return [1.0, 0.0, 0.0, 0.0];
+        }
+        for x in &mut next {
+            *x /= norm;
+        }
+        v = next;
+    }
+    v
+}
+
+/// Flatten a [`Transform`] into the row-major layout of
+/// [`Transform::from_row_major`].
+fn to_row_major(t: &Transform) -> [f64; 16] {
+    let mut out = [0.0; 16];
+    for row in 0..4 {
+        for col in 0..4 {
+            out[row * 4 + col] = t.matrix[(row, col)];
+        }
+    }
+    out
+}
+
+/// Signed distance from each `source` vertex to the `reference` mesh surface,
+/// in vertex order. Positive means outside the reference (along its facet
+/// normals), negative means inside — the input to a deviation color map after
+/// registering a scan with [`align_meshes`]. Closest-point queries run
+/// against an AABB tree over the reference triangles.
+pub fn mesh_deviation(source: &TriangleMesh, reference: &TriangleMesh) -> Vec<f64> {
+    let tree = TriangleTree::build(reference);
+    source
+        .vertices
+        .chunks_exact(3)
+        .map(|v| tree.signed_distance(&Point3::new(v[0] as f64, v[1] as f64, v[2] as f64)))
+        .collect()
+}
+
+/// Leaf size for the deviation query tree.
+const TREE_LEAF_TRIS: usize = 8;
+
+/// AABB tree over a mesh's triangles supporting closest-point queries.
+struct TriangleTree {
+    tris: Vec<[Point3; 3]>,
+    root: Option<TreeNode>,
+}
+
+struct TreeNode {
+    min: Point3,
+    max: Point3,
+    /// Triangle indices for a leaf; empty on internal nodes.
+    tris: Vec<usize>,
+    children: Option<(Box<TreeNode>, Box<TreeNode>)>,
+}
+
+impl TriangleTree {
+    fn build(mesh: &TriangleMesh) -> Self {
+        let point = |i: u32| {
+            let base = i as usize * 3;
+            Point3::new(
+                mesh.vertices[base] as f64,
+                mesh.vertices[base + 1] as f64,
+                mesh.vertices[base + 2] as f64,
+            )
+        };
+        let tris: Vec<[Point3; 3]> = mesh
+            .indices
+            .chunks_exact(3)
+            .map(|t| [point(t[0]), point(t[1]), point(t[2])])
+            .collect();
+        let root = if tris.is_empty() {
+            None
+        } else {
+            Some(build_node(&tris, (0..tris.len()).collect()))
+        };
+        Self { tris, root }
+    }
+
+    /// Distance from `p` to the nearest triangle, signed by that triangle's
+    /// facet normal (positive on the outward side for an outward-wound mesh).
+    fn signed_distance(&self, p: &Point3) -> f64 {
+        let Some(root) = &self.root else {
+            return f64::NAN;
+        };
+        let mut best_sq = f64::MAX;
+        let mut best_tri = 0;
+        let mut best_point = self.tris[0][0];
+        self.query(root, p, &mut best_sq, &mut best_tri, &mut best_point);
+
+        let [a, b, c] = self.tris[best_tri];
+        let normal = (b - a).cross(&(c - a));
+        let dist = best_sq.sqrt();
+        if (p - best_point).dot(&normal) < 0.0 {
+            -dist
+        } else {
+            dist
+        }
+    }
+
+    fn query(
+        &self,
+        node: &TreeNode,
+        p: &Point3,
+        best_sq: &mut f64,
+        best_tri: &mut usize,
+        best_point: &mut Point3,
+    ) {
+        if aabb_distance_squared(&node.min, &node.max, p) >= *best_sq {
+            return;
+        }
+        if let Some((left, right)) = &node.children {
+            // Descend into the nearer child first to tighten the bound early.
+            let dl = aabb_distance_squared(&left.min, &left.max, p);
+            let dr = aabb_distance_squared(&right.min, &right.max, p);
+            let (first, second) = if dl <= dr {
+                (left, right)
+            } else {
+                (right, left)
+            };
+            self.query(first, p, best_sq, best_tri, best_point);
+            self.query(second, p, best_sq, best_tri, best_point);
+        } else {
+            for &ti in &node.tris {
+                let cp = closest_point_on_triangle(p, &self.tris[ti]);
+                let sq = (p - cp).norm_squared();
+                if sq < *best_sq {
+                    *best_sq = sq;
+                    *best_tri = ti;
+                    *best_point = cp;
+                }
+            }
+        }
+    }
+}
+
+fn build_node(tris: &[[Point3; 3]], mut members: Vec<usize>) -> TreeNode {
+    let mut min = Point3::new(f64::MAX, f64::MAX, f64::MAX);
+    let mut max = Point3::new(f64::MIN, f64::MIN, f64::MIN);
+    for &ti in &members {
+        for v in &tris[ti] {
+            min = Point3::new(min.x.min(v.x), min.y.min(v.y), min.z.min(v.z));
+            max = Point3::new(max.x.max(v.x), max.y.max(v.y), max.z.max(v.z));
+        }
+    }
+    if members.len() <= TREE_LEAF_TRIS {
+        return TreeNode {
+            min,
+            max,
+            tris: members,
+            children: None,
+        };
+    }
+
+    // Median split along the longest extent of the bounding box.
+    let extent = max - min;
+    let axis = if extent.x >= extent.y && extent.x >= extent.z {
+        0
+    } else if extent.y >= extent.z {
+        1
+    } else {
+        2
+    };
+    let centroid = |ti: usize| {
+        let [a, b, c] = &tris[ti];
+        (a.coords[axis] + b.coords[axis] + c.coords[axis]) / 3.0
+    };
+    members.sort_by(|&a, &b| centroid(a).total_cmp(&centroid(b)));
+    let right = members.split_off(members.len() / 2);
+    TreeNode {
+        min,
+        max,
+        tris: Vec::new(),
+        children: Some((
+            Box::new(build_node(tris, members)),
+            Box::new(build_node(tris, right)),
+        )),
+    }
+}
+
+/// Squared distance from a point to an axis-aligned box (zero inside).
+fn aabb_distance_squared(min: &Point3, max: &Point3, p: &Point3) -> f64 {
+    let mut sq = 0.0;
+    for axis in 0..3 {
+        let v = p.coords[axis];
+        let d = (min.coords[axis] - v).max(0.0).max(v - max.coords[axis]);
+        sq += d * d;
+    }
+    sq
+}
+
+/// Closest point to `p` on a triangle (Ericson's barycentric region test).
+fn closest_point_on_triangle(p: &Point3, tri: &[Point3; 3]) -> Point3 {
+    let [a, b, c] = tri;
+    let ab = b - a;
+    let ac = c - a;
+    let ap = p - a;
+    let d1 = ab.dot(&ap);
+    let d2 = ac.dot(&ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return *a;
+    }
+
+    let bp = p - b;
+    let d3 = ab.dot(&bp);
+    let d4 = ac.dot(&bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return *b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+
+    let cp = p - c;
+    let d5 = ab.dot(&cp);
+    let d6 = ac.dot(&cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return *c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    let denom = 1.0 / (va + vb + vc);
+    a + ab * (vb * denom) + ac * (vc * denom)
+}
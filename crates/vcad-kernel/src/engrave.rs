@@ -0,0 +1,123 @@
+//! Text engraving on cylindrical faces.
+//!
+//! Lays text around the lateral face of a cylinder and cuts glyph-shaped
+//! pockets by boolean difference. Each glyph outline is placed on the
+//! tangent plane at the glyph's centre angle and extruded radially inward
+//! — a flat approximation that holds while the glyph width stays small
+//! against the cylinder radius, so pocket edges come out marginally
+//! shallower than the nominal depth by the chord sagitta.
+
+use vcad_kernel_geom::{CylinderSurface, SurfaceKind};
+use vcad_kernel_sketch::SketchProfile;
+use vcad_kernel_text::{text_to_profiles, Font, TextAlignment};
+
+use crate::Solid;
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn engrave_text_on_cylinder(
+    solid: &Solid,
+    text: &str,
+    font: &Font,
+    face_index: usize,
+    start_angle: f64,
+    height_pos: f64,
+    size: f64,
+    depth: f64,
+) -> Option<Solid> {
+    if depth <= 0.0 {
+        return None;
+    }
+
+    // Resolve the target face and require a cylindrical surface
+    let brep = solid.brep()?;
+    let (_, face) = brep.topology.faces.iter().nth(face_index)?;
+    let surface = &brep.geometry.surfaces[face.surface_index];
+    if surface.surface_type() != SurfaceKind::Cylinder {
+        return None;
+    }
+    let cyl = surface.as_any().downcast_ref::<CylinderSurface>()?;
+    let center = cyl.center;
+    let axis = *cyl.axis.as_ref();
+    let ref_dir = *cyl.ref_dir.as_ref();
+    let side = axis.cross(&ref_dir);
+    let radius = cyl.radius.abs();
+    if depth >= radius {
+        return None;
+    }
+
+    let profiles = text_to_profiles(text, font, size, 1.0, 1.0, TextAlignment::Left);
+    if profiles.is_empty() {
+        return None;
+    }
+
+    // Partition contours into glyph outlines and their holes by containment,
+    // as textExtrude does — holes keep the glyph counters raised.
+    let n = profiles.len();
+    let mut is_hole = vec![false; n];
+    for i in 0..n {
+        for j in 0..n {
+            if i != j && profiles[i].is_contained_in(&profiles[j]) {
+                is_hole[i] = true;
+                break;
+            }
+        }
+    }
+    let mut groups: Vec<(SketchProfile, Vec<SketchProfile>)> = Vec::new();
+    for (i, profile) in profiles.iter().enumerate() {
+        if !is_hole[i] {
+            let holes = profiles
+                .iter()
+                .enumerate()
+                .filter(|(j, p)| is_hole[*j] && p.is_contained_in(profile))
+                .map(|(_, p)| p.clone())
+                .collect();
+            groups.push((profile.clone(), holes));
+        }
+    }
+
+    // Start each cutter this far outside the surface so its cap face never
+    // grazes the cylinder
+    let lift = depth;
+    let mut result = solid.clone();
+
+    for (outer, holes) in &groups {
+        let xs: Vec<f64> = outer.segments.iter().map(|s| s.start().x).collect();
+        let min_x = xs.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max_x = xs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let cx = 0.5 * (min_x + max_x);
+        if 0.5 * (max_x - min_x) >= radius {
+            // Glyph wider than the cylinder — the flat approximation breaks down
+            return None;
+        }
+
+        // Tangent-plane frame at the glyph's centre angle
+        let theta = start_angle + cx / radius;
+        let radial = theta.cos() * ref_dir + theta.sin() * side;
+        let tangent = axis.cross(&radial);
+        let origin = center + (radius + lift) * radial - cx * tangent + height_pos * axis;
+
+        let world_outer = outer.transform(origin, tangent, axis);
+        let Ok(mut cutter) = Solid::extrude(world_outer, -(lift + depth) * radial) else {
+            continue;
+        };
+        for hole in holes {
+            // Offset the hole prism along the radial so its caps are not
+            // coplanar with the outer prism's
+            let bump = 0.25 * depth;
+            let world_hole = hole.transform(origin + bump * radial, tangent, axis);
+            let Ok(hole_solid) = Solid::extrude(world_hole, -(lift + depth + 2.0 * bump) * radial)
+            else {
+                continue;
+            };
+            let carved = cutter.difference(&hole_solid);
+            // Keep the counter only if the difference stayed B-rep; a mesh
+            // fallback here would poison the final cut
+            if carved.brep().is_some() {
+                cutter = carved;
+            }
+        }
+        result = result.difference(&cutter);
+    }
+
+    Some(result)
+}
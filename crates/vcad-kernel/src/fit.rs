@@ -0,0 +1,521 @@
+//! Mesh-to-B-rep surface fitting.
+//!
+//! Segments a triangle mesh into smooth regions (by normal clustering across
+//! shared edges), fits an analytic surface — plane, cylinder, or sphere — to
+//! each region by least squares, and rebuilds a B-rep whose faces carry the
+//! fitted surfaces with the region boundaries as loops. This turns an
+//! imported STL back into something CAD-like: the result supports STEP export
+//! and exact queries.
+//!
+//! Regions that no analytic surface fits within tolerance (free-form areas)
+//! and closed regions without a boundary loop (e.g. a complete sphere mesh)
+//! fall back to one planar face per triangle, so the reconstruction is always
+//! a complete solid even when only part of it is recognized.
+
+use std::collections::HashMap;
+use vcad_kernel_geom::{CylinderSurface, GeometryStore, Plane, SphereSurface, Surface};
+use vcad_kernel_math::{Point3, Vec3};
+use vcad_kernel_primitives::BRepSolid;
+use vcad_kernel_tessellate::TriangleMesh;
+use vcad_kernel_topo::{HalfEdgeId, Orientation, ShellType, Topology, VertexId};
+
+/// Adjacent triangles stay in one region while their dihedral angle is below
+/// this (cos 30°): flat and smoothly curved areas merge, feature edges split.
+const SMOOTH_COS_THRESHOLD: f64 = 0.866;
+
+/// Fit analytic surfaces to a triangle mesh and build a B-rep approximation.
+///
+/// `tolerance` is the maximum distance (mm) a mesh vertex may lie from the
+/// fitted surface for the fit to be accepted.
+pub(crate) fn fit_mesh_surfaces(mesh: &TriangleMesh, tolerance: f64) -> BRepSolid {
+    let (points, tris) = weld_mesh(mesh);
+
+    // Per-triangle unit normals and areas
+    let mut normals = Vec::with_capacity(tris.len());
+    let mut areas = Vec::with_capacity(tris.len());
+    for tri in &tris {
+        let n = (points[tri[1]] - points[tri[0]]).cross(&(points[tri[2]] - points[tri[0]]));
+        let len = n.norm();
+        normals.push(n / len);
+        areas.push(len * 0.5);
+    }
+
+    // Undirected edge → incident triangles
+    let mut edge_tris: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (t, tri) in tris.iter().enumerate() {
+        for k in 0..3 {
+            let (a, b) = (tri[k], tri[(k + 1) % 3]);
+            edge_tris.entry((a.min(b), a.max(b))).or_default().push(t);
+        }
+    }
+
+    // Region growing: flood across smooth edges
+    let mut region = vec![usize::MAX; tris.len()];
+    let mut regions: Vec<Vec<usize>> = Vec::new();
+    for seed in 0..tris.len() {
+        if region[seed] != usize::MAX {
+            continue;
+        }
+        let id = regions.len();
+        region[seed] = id;
+        let mut members = vec![seed];
+        let mut queue = vec![seed];
+        while let Some(t) = queue.pop() {
+            let tri = &tris[t];
+            for k in 0..3 {
+                let (a, b) = (tri[k], tri[(k + 1) % 3]);
+                for &n in &edge_tris[&(a.min(b), a.max(b))] {
+                    if region[n] == usize::MAX && normals[t].dot(&normals[n]) > SMOOTH_COS_THRESHOLD
+                    {
+                        region[n] = id;
+                        members.push(n);
+                        queue.push(n);
+                    }
+                }
+            }
+        }
+        regions.push(members);
+    }
+
+    // Build the B-rep: one face per fitted region, per-triangle planar faces
+    // otherwise
+    let mut topo = Topology::new();
+    let mut geom = GeometryStore::new();
+    let mut vertex_ids: HashMap<usize, VertexId> = HashMap::new();
+    let mut all_faces = Vec::new();
+
+    for members in &regions {
+        let fitted = fit_region(&points, &tris, &normals, &areas, members, tolerance);
+        let loops = fitted
+            .is_some()
+            .then(|| boundary_loops(&tris, &edge_tris, &region, members))
+            .flatten();
+
+        if let (Some(surface), Some(mut loops)) = (fitted, loops) {
+            // Outer loop is the one with the largest perimeter
+            loops.sort_by(|a, b| {
+                perimeter(&points, b)
+                    .partial_cmp(&perimeter(&points, a))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            let surf_idx = geom.add_surface(surface);
+            let loop_ids: Vec<_> = loops
+                .iter()
+                .map(|verts| {
+                    let hes: Vec<HalfEdgeId> = verts
+                        .iter()
+                        .map(|&w| {
+                            let v = *vertex_ids
+                                .entry(w)
+                                .or_insert_with(|| topo.add_vertex(points[w]));
+                            topo.add_half_edge(v)
+                        })
+                        .collect();
+                    topo.add_loop(&hes)
+                })
+                .collect();
+            let face_id = topo.add_face(loop_ids[0], surf_idx, Orientation::Forward);
+            for &inner in &loop_ids[1..] {
+                topo.add_inner_loop(face_id, inner);
+            }
+            all_faces.push(face_id);
+        } else {
+            // Fallback: one planar face per triangle, as in mesh-only B-reps
+            for &t in members {
+                let tri = &tris[t];
+                let (p0, p1, p2) = (points[tri[0]], points[tri[1]], points[tri[2]]);
+                let surf_idx = geom.add_surface(Box::new(Plane::new(p0, p1 - p0, p2 - p0)));
+                let hes: Vec<HalfEdgeId> = tri
+                    .iter()
+                    .map(|&w| {
+                        let v = *vertex_ids
+                            .entry(w)
+                            .or_insert_with(|| topo.add_vertex(points[w]));
+                        topo.add_half_edge(v)
+                    })
+                    .collect();
+                let loop_id = topo.add_loop(&hes);
+                all_faces.push(topo.add_face(loop_id, surf_idx, Orientation::Forward));
+            }
+        }
+    }
+
+    pair_twin_half_edges(&mut topo);
+    let shell = topo.add_shell(all_faces, ShellType::Outer);
+    let solid_id = topo.add_solid(shell);
+
+    BRepSolid {
+        topology: topo,
+        geometry: geom,
+        solid_id,
+    }
+}
+
+/// Weld mesh vertices by quantized position; returns unique points and
+/// triangles over them, with degenerate triangles dropped.
+fn weld_mesh(mesh: &TriangleMesh) -> (Vec<Point3>, Vec<[usize; 3]>) {
+    let mut ids: HashMap<[i64; 3], usize> = HashMap::new();
+    let mut points = Vec::new();
+    let mut welded = Vec::with_capacity(mesh.num_vertices());
+    for i in 0..mesh.num_vertices() {
+        let p = Point3::new(
+            mesh.vertices[i * 3] as f64,
+            mesh.vertices[i * 3 + 1] as f64,
+            mesh.vertices[i * 3 + 2] as f64,
+        );
+        let key = [
+            (p.x * 1e6).round() as i64,
+            (p.y * 1e6).round() as i64,
+            (p.z * 1e6).round() as i64,
+        ];
+        let id = *ids.entry(key).or_insert_with(|| {
+            points.push(p);
+            points.len() - 1
+        });
+        welded.push(id);
+    }
+
+    let mut tris = Vec::new();
+    for tri in mesh.indices.chunks(3) {
+        let t = [
+            welded[tri[0] as usize],
+            welded[tri[1] as usize],
+            welded[tri[2] as usize],
+        ];
+        if t[0] == t[1] || t[1] == t[2] || t[2] == t[0] {
+            continue;
+        }
+        let area = (points[t[1]] - points[t[0]])
+            .cross(&(points[t[2]] - points[t[0]]))
+            .norm();
+        if area > 1e-12 {
+            tris.push(t);
+        }
+    }
+    (points, tris)
+}
+
+/// Try plane, then cylinder, then sphere on a region; `None` if nothing fits
+/// within tolerance.
+fn fit_region(
+    points: &[Point3],
+    tris: &[[usize; 3]],
+    normals: &[Vec3],
+    areas: &[f64],
+    members: &[usize],
+    tolerance: f64,
+) -> Option<Box<dyn Surface>> {
+    let mut verts: Vec<usize> = members.iter().flat_map(|&t| tris[t]).collect();
+    verts.sort_unstable();
+    verts.dedup();
+
+    if let Some(plane) = fit_plane(points, normals, areas, members, &verts, tolerance) {
+        return Some(Box::new(plane));
+    }
+    if let Some(cyl) = fit_cylinder(points, normals, areas, members, &verts, tolerance) {
+        return Some(Box::new(cyl));
+    }
+    if let Some(sphere) = fit_sphere(points, &verts, tolerance) {
+        return Some(Box::new(sphere));
+    }
+    None
+}
+
+fn fit_plane(
+    points: &[Point3],
+    normals: &[Vec3],
+    areas: &[f64],
+    members: &[usize],
+    verts: &[usize],
+    tolerance: f64,
+) -> Option<Plane> {
+    let mut n = Vec3::zeros();
+    for &t in members {
+        n += normals[t] * areas[t];
+    }
+    let len = n.norm();
+    if len < 1e-12 {
+        return None;
+    }
+    let n = n / len;
+
+    let mut centroid = Vec3::zeros();
+    for &v in verts {
+        centroid += points[v].coords;
+    }
+    let centroid = Point3::from(centroid / verts.len() as f64);
+
+    let max_dev = verts
+        .iter()
+        .map(|&v| (points[v] - centroid).dot(&n).abs())
+        .fold(0.0, f64::max);
+    if max_dev > tolerance {
+        return None;
+    }
+
+    // In-plane axes with x × y = n so the face normal matches the mesh
+    let x_dir = perpendicular(&n);
+    let y_dir = n.cross(&x_dir);
+    Some(Plane::new(centroid, x_dir, y_dir))
+}
+
+fn fit_cylinder(
+    points: &[Point3],
+    normals: &[Vec3],
+    areas: &[f64],
+    members: &[usize],
+    verts: &[usize],
+    tolerance: f64,
+) -> Option<CylinderSurface> {
+    if members.len() < 3 {
+        return None;
+    }
+
+    // Cylinder normals all lie in the plane perpendicular to the axis, so
+    // the axis is the least-significant direction of the normal covariance.
+    // Power-iterate on (trace·I − C), whose dominant eigenvector is the
+    // smallest of C.
+    let mut c = [[0.0f64; 3]; 3];
+    for &t in members {
+        let n = normals[t];
+        let w = areas[t];
+        for (i, ni) in [n.x, n.y, n.z].iter().enumerate() {
+            for (j, nj) in [n.x, n.y, n.z].iter().enumerate() {
+                c[i][j] += w * ni * nj;
+            }
+        }
+    }
+    let trace = c[0][0] + c[1][1] + c[2][2];
+    let m = |v: Vec3| -> Vec3 {
+        Vec3::new(
+            trace * v.x - (c[0][0] * v.x + c[0][1] * v.y + c[0][2] * v.z),
+            trace * v.y - (c[1][0] * v.x + c[1][1] * v.y + c[1][2] * v.z),
+            trace * v.z - (c[2][0] * v.x + c[2][1] * v.y + c[2][2] * v.z),
+        )
+    };
+    let mut axis = Vec3::new(0.577, 0.577, 0.577);
+    for _ in 0..64 {
+        let next = m(axis);
+        let len = next.norm();
+        if len < 1e-12 {
+            return None;
+        }
+        axis = next / len;
+    }
+
+    // Project the region into the plane perpendicular to the axis and fit a
+    // circle (Kåsa): u² + w² = 2·cu·u + 2·cw·w + k is linear in (cu, cw, k)
+    let u_dir = perpendicular(&axis);
+    let w_dir = axis.cross(&u_dir);
+    let mut a = [[0.0f64; 3]; 3];
+    let mut rhs = [0.0f64; 3];
+    for &v in verts {
+        let p = points[v].coords;
+        let (u, w) = (p.dot(&u_dir), p.dot(&w_dir));
+        let row = [2.0 * u, 2.0 * w, 1.0];
+        let b = u * u + w * w;
+        for i in 0..3 {
+            for j in 0..3 {
+                a[i][j] += row[i] * row[j];
+            }
+            rhs[i] += row[i] * b;
+        }
+    }
+    let [cu, cw, k] = solve3(a, rhs)?;
+    let r_sq = k + cu * cu + cw * cw;
+    if r_sq <= 0.0 {
+        return None;
+    }
+    let radius = r_sq.sqrt();
+
+    let max_dev = verts
+        .iter()
+        .map(|&v| {
+            let p = points[v].coords;
+            let (u, w) = (p.dot(&u_dir), p.dot(&w_dir));
+            (((u - cu).powi(2) + (w - cw).powi(2)).sqrt() - radius).abs()
+        })
+        .fold(0.0, f64::max);
+    if max_dev > tolerance {
+        return None;
+    }
+
+    // Base the axis at the lowest projection so v ≥ 0 over the face
+    let t_min = verts
+        .iter()
+        .map(|&v| points[v].coords.dot(&axis))
+        .fold(f64::INFINITY, f64::min);
+    let center = Point3::from(u_dir * cu + w_dir * cw + axis * t_min);
+    Some(CylinderSurface::with_axis(center, axis, radius))
+}
+
+fn fit_sphere(points: &[Point3], verts: &[usize], tolerance: f64) -> Option<SphereSurface> {
+    if verts.len() < 4 {
+        return None;
+    }
+
+    // Kåsa in 3D: |p|² = 2·c·p + k is linear in (cx, cy, cz, k)
+    let mut a = [[0.0f64; 4]; 4];
+    let mut rhs = [0.0f64; 4];
+    for &v in verts {
+        let p = points[v].coords;
+        let row = [2.0 * p.x, 2.0 * p.y, 2.0 * p.z, 1.0];
+        let b = p.norm_squared();
+        for i in 0..4 {
+            for j in 0..4 {
+                a[i][j] += row[i] * row[j];
+            }
+            rhs[i] += row[i] * b;
+        }
+    }
+    let [cx, cy, cz, k] = solve4(a, rhs)?;
+    let center = Point3::new(cx, cy, cz);
+    let r_sq = k + center.coords.norm_squared();
+    if r_sq <= 0.0 {
+        return None;
+    }
+    let radius = r_sq.sqrt();
+
+    let max_dev = verts
+        .iter()
+        .map(|&v| ((points[v] - center).norm() - radius).abs())
+        .fold(0.0, f64::max);
+    if max_dev > tolerance {
+        return None;
+    }
+    Some(SphereSurface::with_center(center, radius))
+}
+
+/// Directed boundary loops of a region: edges used by exactly one of its
+/// triangles, chained tip-to-tail in triangle winding order. `None` when the
+/// region is closed or the chain is broken (non-manifold boundary).
+fn boundary_loops(
+    tris: &[[usize; 3]],
+    edge_tris: &HashMap<(usize, usize), Vec<usize>>,
+    region: &[usize],
+    members: &[usize],
+) -> Option<Vec<Vec<usize>>> {
+    let id = region[members[0]];
+    let mut next: HashMap<usize, usize> = HashMap::new();
+    for &t in members {
+        let tri = &tris[t];
+        for k in 0..3 {
+            let (a, b) = (tri[k], tri[(k + 1) % 3]);
+            let in_region = edge_tris[&(a.min(b), a.max(b))]
+                .iter()
+                .filter(|&&n| region[n] == id)
+                .count();
+            if in_region == 1 && next.insert(a, b).is_some() {
+                return None; // non-manifold boundary vertex
+            }
+        }
+    }
+    if next.is_empty() {
+        return None;
+    }
+
+    let mut loops = Vec::new();
+    while let Some(&start) = next.keys().next() {
+        let mut verts = vec![start];
+        let mut current = next.remove(&start)?;
+        while current != start {
+            verts.push(current);
+            current = next.remove(&current)?;
+        }
+        loops.push(verts);
+    }
+    Some(loops)
+}
+
+fn perimeter(points: &[Point3], verts: &[usize]) -> f64 {
+    verts
+        .iter()
+        .zip(verts.iter().cycle().skip(1))
+        .map(|(&a, &b)| (points[b] - points[a]).norm())
+        .sum()
+}
+
+/// An arbitrary unit vector perpendicular to `n`.
+fn perpendicular(n: &Vec3) -> Vec3 {
+    let pick = if n.x.abs() < 0.9 {
+        Vec3::x()
+    } else {
+        Vec3::y()
+    };
+    (pick - n * pick.dot(n)).normalize()
+}
+
+fn solve3(a: [[f64; 3]; 3], rhs: [f64; 3]) -> Option<[f64; 3]> {
+    solve(&mut [
+        [a[0][0], a[0][1], a[0][2], rhs[0]],
+        [a[1][0], a[1][1], a[1][2], rhs[1]],
+        [a[2][0], a[2][1], a[2][2], rhs[2]],
+    ])
+}
+
+fn solve4(a: [[f64; 4]; 4], rhs: [f64; 4]) -> Option<[f64; 4]> {
+    solve(&mut [
+        [a[0][0], a[0][1], a[0][2], a[0][3], rhs[0]],
+        [a[1][0], a[1][1], a[1][2], a[1][3], rhs[1]],
+        [a[2][0], a[2][1], a[2][2], a[2][3], rhs[2]],
+        [a[3][0], a[3][1], a[3][2], a[3][3], rhs[3]],
+    ])
+}
+
+/// Gaussian elimination with partial pivoting on an augmented N×(N+1) matrix.
+fn solve<const N: usize, const M: usize>(aug: &mut [[f64; M]; N]) -> Option<[f64; N]> {
+    for col in 0..N {
+        let pivot = (col..N).max_by(|&a, &b| {
+            aug[a][col]
+                .abs()
+                .partial_cmp(&aug[b][col].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        if aug[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        aug.swap(col, pivot);
+        for row in 0..N {
+            if row == col {
+                continue;
+            }
+            let factor = aug[row][col] / aug[col][col];
+            let pivot_row = aug[col];
+            for (slot, &p) in aug[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *slot -= factor * p;
+            }
+        }
+    }
+    let mut out = [0.0; N];
+    for (i, slot) in out.iter_mut().enumerate() {
+        *slot = aug[i][N] / aug[i][i];
+    }
+    Some(out)
+}
+
+/// Pair twin half-edges by matching (origin, destination) vertex positions.
+fn pair_twin_half_edges(topo: &mut Topology) {
+    let quantize = |p: Point3| -> [i64; 3] {
+        [
+            (p.x * 1e6).round() as i64,
+            (p.y * 1e6).round() as i64,
+            (p.z * 1e6).round() as i64,
+        ]
+    };
+
+    let mut he_map: HashMap<([i64; 3], [i64; 3]), HalfEdgeId> = HashMap::new();
+    let he_ids: Vec<HalfEdgeId> = topo.half_edges.keys().collect();
+    for he_id in &he_ids {
+        let he = &topo.half_edges[*he_id];
+        let origin = quantize(topo.vertices[he.origin].point);
+        let Some(next) = he.next else { continue };
+        let dest = quantize(topo.vertices[topo.half_edges[next].origin].point);
+
+        if let Some(&twin_id) = he_map.get(&(dest, origin)) {
+            if topo.half_edges[*he_id].twin.is_none() && topo.half_edges[twin_id].twin.is_none() {
+                topo.add_edge(*he_id, twin_id);
+            }
+        }
+        he_map.insert((origin, dest), *he_id);
+    }
+}
@@ -0,0 +1,143 @@
+//! Height-field (depth map) extraction for 2.5D machining previews.
+//!
+//! Samples the maximum surface height of a solid over a regular grid
+//! perpendicular to a chosen axis — the view a roughing pass sees looking
+//! straight down the tool axis. Heights come from the tessellated boundary:
+//! each triangle is projected onto the grid plane and rasterized, keeping
+//! the highest surface at every cell. Cells the solid never covers are set
+//! to the floor (the bounding-box minimum along the axis).
+
+use vcad_kernel_tessellate::TriangleMesh;
+
+/// Tool/view axis for height-field sampling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeightAxis {
+    /// Heights measured along +X, grid over the Y-Z plane.
+    X,
+    /// Heights measured along +Y, grid over the X-Z plane.
+    Y,
+    /// Heights measured along +Z, grid over the X-Y plane.
+    Z,
+}
+
+impl HeightAxis {
+    /// Component indices `(u, v, height)` into an `[x, y, z]` point.
+    fn components(self) -> (usize, usize, usize) {
+        match self {
+            HeightAxis::X => (1, 2, 0),
+            HeightAxis::Y => (0, 2, 1),
+            HeightAxis::Z => (0, 1, 2),
+        }
+    }
+}
+
+/// A regular grid of maximum surface heights along an axis.
+#[derive(Debug, Clone)]
+pub struct HeightField {
+    /// Cells per side of the square grid.
+    pub resolution: u32,
+    /// Grid origin: the bounding-box minimum in the two cross axes.
+    pub origin: [f64; 2],
+    /// Cell size in the two cross axes (mm).
+    pub cell_size: [f64; 2],
+    /// Height assigned to cells the solid does not cover (the bounding-box
+    /// minimum along the sampling axis).
+    pub floor: f64,
+    /// Row-major heights, `resolution * resolution` entries; the cell at
+    /// `(i, j)` in the (u, v) cross axes is `heights[j * resolution + i]`,
+    /// sampled at the cell center.
+    pub heights: Vec<f64>,
+}
+
+pub(crate) fn height_field(
+    mesh: &TriangleMesh,
+    resolution: u32,
+    axis: HeightAxis,
+) -> Option<HeightField> {
+    if resolution == 0 || mesh.indices.is_empty() {
+        return None;
+    }
+    let (ui, vi, hi) = axis.components();
+    let point = |idx: u32| {
+        let p = &mesh.vertices[idx as usize * 3..idx as usize * 3 + 3];
+        [p[0] as f64, p[1] as f64, p[2] as f64]
+    };
+
+    // Bounding box over the grid plane and the height axis
+    let mut min = [f64::MAX; 3];
+    let mut max = [f64::MIN; 3];
+    for i in 0..mesh.vertices.len() / 3 {
+        let p = point(i as u32);
+        for k in 0..3 {
+            min[k] = min[k].min(p[k]);
+            max[k] = max[k].max(p[k]);
+        }
+    }
+
+    let n = resolution as usize;
+    let cell_size = [
+        (max[ui] - min[ui]) / n as f64,
+        (max[vi] - min[vi]) / n as f64,
+    ];
+    if cell_size[0] <= 0.0 || cell_size[1] <= 0.0 {
+        return None;
+    }
+    let floor = min[hi];
+    let mut heights = vec![floor; n * n];
+
+    // Rasterize each triangle over the cells its projection covers
+    let center =
+        |i: usize, k: usize| min[k] + cell_size[if k == ui { 0 } else { 1 }] * (i as f64 + 0.5);
+    for tri in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [point(tri[0]), point(tri[1]), point(tri[2])];
+        let (au, av) = (a[ui], a[vi]);
+        let (bu, bv) = (b[ui], b[vi]);
+        let (cu, cv) = (c[ui], c[vi]);
+
+        let det = (bu - au) * (cv - av) - (cu - au) * (bv - av);
+        if det.abs() < 1e-12 {
+            // Edge-on triangle (e.g. a vertical wall): no top-surface area
+            continue;
+        }
+
+        let u_lo = au.min(bu).min(cu);
+        let u_hi = au.max(bu).max(cu);
+        let v_lo = av.min(bv).min(cv);
+        let v_hi = av.max(bv).max(cv);
+        let i_lo = (((u_lo - min[ui]) / cell_size[0] - 0.5).ceil().max(0.0)) as usize;
+        let j_lo = (((v_lo - min[vi]) / cell_size[1] - 0.5).ceil().max(0.0)) as usize;
+        let i_hi = (((u_hi - min[ui]) / cell_size[0] - 0.5).floor()).min(n as f64 - 1.0);
+        let j_hi = (((v_hi - min[vi]) / cell_size[1] - 0.5).floor()).min(n as f64 - 1.0);
+        if i_hi < 0.0 || j_hi < 0.0 {
+            continue;
+        }
+
+        for j in j_lo..=j_hi as usize {
+            let v = center(j, vi);
+            for i in i_lo..=i_hi as usize {
+                let u = center(i, ui);
+                // Barycentric coordinates of the cell center
+                let l1 = ((bu - u) * (cv - v) - (cu - u) * (bv - v)) / det;
+                let l2 = ((cu - u) * (av - v) - (au - u) * (cv - v)) / det;
+                let l3 = 1.0 - l1 - l2;
+                let eps = -1e-9;
+                if l1 < eps || l2 < eps || l3 < eps {
+                    continue;
+                }
+                let h = l1 * a[hi] + l2 * b[hi] + l3 * c[hi];
+                let cell = &mut heights[j * n + i];
+                if h > *cell {
+                    *cell = h;
+                }
+            }
+        }
+    }
+
+    Some(HeightField {
+        resolution,
+        origin: [min[ui], min[vi]],
+        cell_size,
+        floor,
+        heights,
+    })
+}
@@ -0,0 +1,274 @@
+//! Imperative operation history for editor feature trees.
+//!
+//! [`TrackedSolid`] wraps a [`Solid`] and records every modeling call as an
+//! [`OpRecord`], so a UI can display the feature tree, re-order it, or replay
+//! to a previous state. This mirrors the IR DAG but is captured imperatively
+//! as operations are performed.
+
+use crate::{PrimitiveError, Solid};
+
+/// A single recorded modeling operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpRecord {
+    /// Operation name (e.g. `"cube"`, `"translate"`, `"difference"`).
+    pub op: String,
+    /// Numeric parameters of the operation, in call order.
+    pub params: Vec<f64>,
+}
+
+impl OpRecord {
+    fn new(op: &str, params: &[f64]) -> Self {
+        Self {
+            op: op.to_string(),
+            params: params.to_vec(),
+        }
+    }
+}
+
+/// A solid that records its operation history.
+///
+/// Every modeling call returns a new `TrackedSolid` whose history is the
+/// previous history plus one record, so earlier states remain valid for undo.
+///
+/// # Example
+///
+/// ```
+/// use vcad_kernel::TrackedSolid;
+///
+/// let part = TrackedSolid::cube(10.0, 10.0, 10.0).unwrap().translate(5.0, 0.0, 0.0);
+/// assert_eq!(part.history().len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrackedSolid {
+    solid: Solid,
+    history: Vec<OpRecord>,
+}
+
+impl TrackedSolid {
+    /// Create a tracked box (records a `"cube"` op).
+    pub fn cube(sx: f64, sy: f64, sz: f64) -> Result<Self, PrimitiveError> {
+        Ok(Self {
+            solid: Solid::cube(sx, sy, sz)?,
+            history: vec![OpRecord::new("cube", &[sx, sy, sz])],
+        })
+    }
+
+    /// Create a tracked cylinder (records a `"cylinder"` op).
+    pub fn cylinder(radius: f64, height: f64, segments: u32) -> Result<Self, PrimitiveError> {
+        Ok(Self {
+            solid: Solid::cylinder(radius, height, segments)?,
+            history: vec![OpRecord::new("cylinder", &[radius, height])],
+        })
+    }
+
+    /// Create a tracked sphere (records a `"sphere"` op).
+    pub fn sphere(radius: f64, segments: u32) -> Result<Self, PrimitiveError> {
+        Ok(Self {
+            solid: Solid::sphere(radius, segments)?,
+            history: vec![OpRecord::new("sphere", &[radius])],
+        })
+    }
+
+    /// Create a tracked cone (records a `"cone"` op).
+    pub fn cone(
+        radius_bottom: f64,
+        radius_top: f64,
+        height: f64,
+        segments: u32,
+    ) -> Result<Self, PrimitiveError> {
+        Ok(Self {
+            solid: Solid::cone(radius_bottom, radius_top, height, segments)?,
+            history: vec![OpRecord::new("cone", &[radius_bottom, radius_top, height])],
+        })
+    }
+
+    /// Wrap an existing solid without recording a creation op.
+    pub fn from_solid(solid: Solid) -> Self {
+        Self {
+            solid,
+            history: Vec::new(),
+        }
+    }
+
+    /// The current solid.
+    pub fn solid(&self) -> &Solid {
+        &self.solid
+    }
+
+    /// The recorded operations, in order.
+    pub fn history(&self) -> &[OpRecord] {
+        &self.history
+    }
+
+    /// Serialize the history as a JSON array of `{"op": ..., "params": [...]}`.
+    pub fn history_json(&self) -> String {
+        let records: Vec<String> = self
+            .history
+            .iter()
+            .map(|r| {
+                let params: Vec<String> = r.params.iter().map(|p| format!("{p}")).collect();
+                format!("{{\"op\":\"{}\",\"params\":[{}]}}", r.op, params.join(","))
+            })
+            .collect();
+        format!("[{}]", records.join(","))
+    }
+
+    fn apply(&self, solid: Solid, op: &str, params: &[f64]) -> Self {
+        let mut history = self.history.clone();
+        history.push(OpRecord::new(op, params));
+        Self { solid, history }
+    }
+
+    /// Boolean union (records a `"union"` op).
+    pub fn union(&self, other: &Solid) -> Self {
+        self.apply(self.solid.union(other), "union", &[])
+    }
+
+    /// Boolean difference (records a `"difference"` op).
+    pub fn difference(&self, other: &Solid) -> Self {
+        self.apply(self.solid.difference(other), "difference", &[])
+    }
+
+    /// Boolean intersection (records an `"intersection"` op).
+    pub fn intersection(&self, other: &Solid) -> Self {
+        self.apply(self.solid.intersection(other), "intersection", &[])
+    }
+
+    /// Translate (records a `"translate"` op).
+    pub fn translate(&self, x: f64, y: f64, z: f64) -> Self {
+        self.apply(self.solid.translate(x, y, z), "translate", &[x, y, z])
+    }
+
+    /// Rotate in degrees (records a `"rotate"` op).
+    pub fn rotate(&self, x_deg: f64, y_deg: f64, z_deg: f64) -> Self {
+        self.apply(
+            self.solid.rotate(x_deg, y_deg, z_deg),
+            "rotate",
+            &[x_deg, y_deg, z_deg],
+        )
+    }
+
+    /// Scale (records a `"scale"` op).
+    pub fn scale(&self, x: f64, y: f64, z: f64) -> Self {
+        self.apply(self.solid.scale(x, y, z), "scale", &[x, y, z])
+    }
+
+    /// Chamfer all edges (records a `"chamfer"` op).
+    pub fn chamfer(&self, distance: f64) -> Self {
+        self.apply(self.solid.chamfer(distance), "chamfer", &[distance])
+    }
+
+    /// Fillet all edges (records a `"fillet"` op).
+    pub fn fillet(&self, radius: f64) -> Self {
+        self.apply(self.solid.fillet(radius), "fillet", &[radius])
+    }
+
+    /// Shell (records a `"shell"` op).
+    pub fn shell(&self, thickness: f64) -> Self {
+        self.apply(self.solid.shell(thickness), "shell", &[thickness])
+    }
+
+    /// Rewind to the state after the first `n` operations by replaying them.
+    ///
+    /// Boolean operands are not recorded, so histories containing boolean ops
+    /// can only be truncated, not re-evaluated; in that case the replay stops
+    /// at the last replayable record. Returns `None` if `n` exceeds the
+    /// history length, the history starts with a non-constructor op, or a
+    /// recorded op has degenerate parameters.
+    pub fn replay_to(&self, n: usize) -> Option<Self> {
+        if n > self.history.len() {
+            return None;
+        }
+
+        let mut result: Option<Self> = None;
+        for record in &self.history[..n] {
+            let p = &record.params;
+            result = match (record.op.as_str(), result) {
+                ("cube", None) if p.len() == 3 => Some(Self::cube(p[0], p[1], p[2]).ok()?),
+                ("cylinder", None) if p.len() == 2 => Some(Self::cylinder(p[0], p[1], 32).ok()?),
+                ("sphere", None) if p.len() == 1 => Some(Self::sphere(p[0], 32).ok()?),
+                ("cone", None) if p.len() == 3 => Some(Self::cone(p[0], p[1], p[2], 32).ok()?),
+                ("translate", Some(s)) if p.len() == 3 => Some(s.translate(p[0], p[1], p[2])),
+                ("rotate", Some(s)) if p.len() == 3 => Some(s.rotate(p[0], p[1], p[2])),
+                ("scale", Some(s)) if p.len() == 3 => Some(s.scale(p[0], p[1], p[2])),
+                ("chamfer", Some(s)) if p.len() == 1 => Some(s.chamfer(p[0])),
+                ("fillet", Some(s)) if p.len() == 1 => Some(s.fillet(p[0])),
+                ("shell", Some(s)) if p.len() == 1 => Some(s.shell(p[0])),
+                (_, r) => return r,
+            };
+        }
+        result
+    }
+}
+
+impl Solid {
+    /// Wrap this solid so that subsequent operations record their history.
+    ///
+    /// See [`TrackedSolid`].
+    pub fn with_history(self) -> TrackedSolid {
+        TrackedSolid::from_solid(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_records_in_order() {
+        let tool = Solid::cube(5.0, 5.0, 20.0)
+            .unwrap()
+            .translate(2.0, 2.0, -5.0);
+        let part = TrackedSolid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(1.0, 2.0, 3.0)
+            .difference(&tool);
+
+        let history = part.history();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].op, "cube");
+        assert_eq!(history[0].params, vec![10.0, 10.0, 10.0]);
+        assert_eq!(history[1].op, "translate");
+        assert_eq!(history[1].params, vec![1.0, 2.0, 3.0]);
+        assert_eq!(history[2].op, "difference");
+        assert!(history[2].params.is_empty());
+        assert!(!part.solid().is_empty());
+    }
+
+    #[test]
+    fn test_history_json() {
+        let part = TrackedSolid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(1.0, 0.0, 0.0);
+        let json = part.history_json();
+        assert_eq!(
+            json,
+            "[{\"op\":\"cube\",\"params\":[10,10,10]},{\"op\":\"translate\",\"params\":[1,0,0]}]"
+        );
+    }
+
+    #[test]
+    fn test_with_history_starts_empty() {
+        let part = Solid::cube(10.0, 10.0, 10.0).unwrap().with_history();
+        assert!(part.history().is_empty());
+        let moved = part.translate(5.0, 0.0, 0.0);
+        assert_eq!(moved.history().len(), 1);
+    }
+
+    #[test]
+    fn test_replay_to_previous_state() {
+        let part = TrackedSolid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(100.0, 0.0, 0.0)
+            .scale(2.0, 1.0, 1.0);
+
+        // Replay to just after the translate
+        let earlier = part.replay_to(2).unwrap();
+        assert_eq!(earlier.history().len(), 2);
+        let (min, _) = earlier.solid().bounding_box();
+        assert!((min[0] - 100.0).abs() < 0.1);
+
+        // Out of range
+        assert!(part.replay_to(99).is_none());
+    }
+}
@@ -10,13 +10,28 @@
 //! ```
 //! use vcad_kernel::Solid;
 //!
-//! let cube = Solid::cube(10.0, 20.0, 30.0);
+//! let cube = Solid::cube(10.0, 20.0, 30.0).unwrap();
 //! let mesh = cube.to_mesh(32);
 //! assert!(mesh.num_triangles() >= 12);
 //! ```
 
+use std::cell::RefCell;
 use std::path::Path;
 
+mod align;
+mod engrave;
+mod fit;
+mod height_field;
+mod history;
+mod printability;
+mod unfold;
+
+pub use align::{align_meshes, mesh_deviation, MeshAlignment};
+pub use height_field::{HeightAxis, HeightField};
+pub use history::{OpRecord, TrackedSolid};
+pub use printability::{PrintabilityReport, PrintabilitySettings, PrintabilityStatus};
+pub use unfold::{ShellUnfold, UnfoldedFace};
+
 pub use vcad_kernel_booleans;
 pub use vcad_kernel_constraints;
 pub use vcad_kernel_fillet;
@@ -45,6 +60,8 @@ pub enum StepExportError {
     NotBRep,
     /// The solid is empty (no geometry).
     Empty,
+    /// The boundary failed validation (open or non-manifold edges).
+    Invalid(Vec<String>),
     /// An error occurred during STEP file writing.
     Step(StepError),
 }
@@ -57,6 +74,11 @@ impl std::fmt::Display for StepExportError {
                 "cannot export to STEP: solid has been converted to mesh (B-rep data lost after boolean operations)"
             ),
             StepExportError::Empty => write!(f, "cannot export to STEP: solid is empty"),
+            StepExportError::Invalid(problems) => write!(
+                f,
+                "cannot export to STEP: boundary is invalid ({})",
+                problems.join("; ")
+            ),
             StepExportError::Step(e) => write!(f, "STEP export error: {}", e),
         }
     }
@@ -77,6 +99,129 @@ impl From<StepError> for StepExportError {
     }
 }
 
+/// Error returned when a primitive constructor receives degenerate parameters.
+///
+/// Zero or negative dimensions build solids full of NaNs that only fail much
+/// later (booleans, tessellation), so the constructors reject them up front.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrimitiveError {
+    /// A dimension that must be strictly positive was zero, negative, or NaN.
+    InvalidDimension {
+        /// Parameter name (e.g. `"radius"`).
+        name: &'static str,
+        /// The offending value.
+        value: f64,
+    },
+    /// Fewer than 3 segments were requested for a round feature.
+    TooFewSegments(u32),
+}
+
+impl std::fmt::Display for PrimitiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrimitiveError::InvalidDimension { name, value } => {
+                write!(f, "{} must be positive, got {}", name, value)
+            }
+            PrimitiveError::TooFewSegments(n) => {
+                write!(f, "segments must be at least 3, got {}", n)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PrimitiveError {}
+
+/// Check that a dimension is strictly positive (rejects NaN).
+fn check_positive(name: &'static str, value: f64) -> Result<(), PrimitiveError> {
+    if value > 0.0 {
+        Ok(())
+    } else {
+        Err(PrimitiveError::InvalidDimension { name, value })
+    }
+}
+
+/// Check that a segment count is usable for a round feature.
+fn check_segments(segments: u32) -> Result<(), PrimitiveError> {
+    if segments >= 3 {
+        Ok(())
+    } else {
+        Err(PrimitiveError::TooFewSegments(segments))
+    }
+}
+
+/// A sketch placement frame derived from a face (see [`Solid::sketch_plane_from_face`]).
+#[derive(Debug, Clone, Copy)]
+pub struct SketchPlane {
+    /// Origin of the sketch plane (on the face).
+    pub origin: Point3,
+    /// Unit vector along the sketch X axis.
+    pub x_dir: Vec3,
+    /// Unit vector along the sketch Y axis.
+    pub y_dir: Vec3,
+    /// Unit outward face normal (x_dir × y_dir).
+    pub normal: Vec3,
+}
+
+/// Strategy for choosing a print orientation (see [`Solid::auto_orient_for_print`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintOrientStrategy {
+    /// Minimize the projected area of downward-facing overhangs (> 45°).
+    MinOverhang,
+    /// Minimize the build height (Z extent).
+    MinHeight,
+}
+
+/// Result of [`Solid::auto_orient_for_print`].
+#[derive(Debug, Clone)]
+pub struct PrintOrientation {
+    /// The reoriented solid, translated to rest on the z = 0 bed.
+    pub solid: Solid,
+    /// The chosen "down" direction, expressed in the original orientation.
+    pub down: [f64; 3],
+    /// Score of the winning orientation: overhang area in mm² for
+    /// [`PrintOrientStrategy::MinOverhang`], height in mm for
+    /// [`PrintOrientStrategy::MinHeight`].
+    pub score: f64,
+}
+
+/// Result of [`Solid::assembly_fit`]: radial fit between a cylindrical
+/// shaft face and a coaxial cylindrical hole face.
+#[derive(Debug, Clone, Copy)]
+pub struct AssemblyFit {
+    /// Radius of the shaft's cylindrical face.
+    pub shaft_radius: f64,
+    /// Radius of the hole's cylindrical face.
+    pub hole_radius: f64,
+    /// Minimum radial gap in mm: hole radius minus shaft radius minus any
+    /// axis offset. Negative means interference (press fit).
+    pub clearance: f64,
+}
+
+/// An enclosed internal cavity reported by [`Solid::cavities`]: one void
+/// shell of the B-rep, with its enclosed volume and bounding box.
+#[derive(Debug, Clone, Copy)]
+pub struct Cavity {
+    /// Enclosed volume of the cavity in mm³.
+    pub volume: f64,
+    /// Minimum corner of the cavity's axis-aligned bounding box.
+    pub min: [f64; 3],
+    /// Maximum corner of the cavity's axis-aligned bounding box.
+    pub max: [f64; 3],
+}
+
+/// Preview of one edge in a prospective fillet, reported by
+/// [`Solid::fillet_preview`].
+#[derive(Debug, Clone, Copy)]
+pub struct FilletEdgePreview {
+    /// Edge index in topology iteration order, as passed in.
+    pub edge_index: usize,
+    /// Whether the edge can be blended at the requested radius.
+    pub filletable: bool,
+    /// Approximate bounding box `(min, max)` of the prospective blend
+    /// face, present for filletable edges.
+    pub bounds: Option<([f64; 3], [f64; 3])>,
+}
+
 /// The internal representation of a solid.
 #[derive(Debug, Clone)]
 enum SolidRepr {
@@ -97,6 +242,23 @@ pub struct Solid {
     repr: SolidRepr,
     /// Default tessellation segment count.
     segments: u32,
+    /// Tagged material regions, carried through booleans and transforms.
+    materials: Vec<MaterialRegion>,
+    /// Per-face display colors as `(face_index, color)` pairs, keyed by
+    /// topology iteration order. Carried through transforms; dropped by
+    /// booleans, which rebuild the topology and its face indices.
+    face_colors: Vec<(usize, String)>,
+    /// Last tessellation, keyed by segment count. Mutating operations
+    /// return a new `Solid`, so the cache is never stale.
+    mesh_cache: RefCell<Option<(u32, TriangleMesh)>>,
+}
+
+/// A material region: the solid a material tag was applied to, kept so
+/// later booleans can be accounted for in [`Solid::volume_by_material`].
+#[derive(Debug, Clone)]
+struct MaterialRegion {
+    tag: String,
+    solid: Box<Solid>,
 }
 
 impl Solid {
@@ -109,6 +271,22 @@ impl Solid {
         Self {
             repr: SolidRepr::Empty,
             segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        }
+    }
+
+    /// Create an empty solid carrying over this solid's settings (such as the
+    /// default segment count), so a degenerate result can be replaced without
+    /// losing tessellation configuration.
+    pub fn empty_like(&self) -> Self {
+        Self {
+            repr: SolidRepr::Empty,
+            segments: self.segments,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
         }
     }
 
@@ -117,40 +295,90 @@ impl Solid {
         Self {
             repr: SolidRepr::Mesh(mesh),
             segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
         }
     }
 
     /// Create a box (cuboid) with corner at origin and dimensions `(sx, sy, sz)`.
-    pub fn cube(sx: f64, sy: f64, sz: f64) -> Self {
-        Self {
+    ///
+    /// Returns an error if any dimension is zero, negative, or NaN.
+    pub fn cube(sx: f64, sy: f64, sz: f64) -> Result<Self, PrimitiveError> {
+        check_positive("cube sx", sx)?;
+        check_positive("cube sy", sy)?;
+        check_positive("cube sz", sz)?;
+        Ok(Self {
             repr: SolidRepr::BRep(Box::new(vcad_kernel_primitives::make_cube(sx, sy, sz))),
             segments: 32,
-        }
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        })
     }
 
     /// Create a cylinder along Z axis with the given radius and height.
-    pub fn cylinder(radius: f64, height: f64, segments: u32) -> Self {
-        Self {
+    ///
+    /// Returns an error if radius or height is not positive or `segments < 3`.
+    pub fn cylinder(radius: f64, height: f64, segments: u32) -> Result<Self, PrimitiveError> {
+        check_positive("cylinder radius", radius)?;
+        check_positive("cylinder height", height)?;
+        check_segments(segments)?;
+        Ok(Self {
             repr: SolidRepr::BRep(Box::new(vcad_kernel_primitives::make_cylinder(
                 radius, height, segments,
             ))),
             segments,
-        }
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        })
     }
 
     /// Create a sphere centered at origin with the given radius.
-    pub fn sphere(radius: f64, segments: u32) -> Self {
-        Self {
+    ///
+    /// Returns an error if the radius is not positive or `segments < 3`.
+    pub fn sphere(radius: f64, segments: u32) -> Result<Self, PrimitiveError> {
+        check_positive("sphere radius", radius)?;
+        check_segments(segments)?;
+        Ok(Self {
             repr: SolidRepr::BRep(Box::new(vcad_kernel_primitives::make_sphere(
                 radius, segments,
             ))),
             segments,
-        }
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        })
     }
 
     /// Create a cone/frustum along Z axis.
-    pub fn cone(radius_bottom: f64, radius_top: f64, height: f64, segments: u32) -> Self {
-        Self {
+    ///
+    /// Either radius may be zero (pointed cone), but not both. Returns an
+    /// error if the radii are negative, the height is not positive, or
+    /// `segments < 3`.
+    pub fn cone(
+        radius_bottom: f64,
+        radius_top: f64,
+        height: f64,
+        segments: u32,
+    ) -> Result<Self, PrimitiveError> {
+        if radius_bottom < 0.0 || radius_bottom.is_nan() {
+            return Err(PrimitiveError::InvalidDimension {
+                name: "cone radius_bottom",
+                value: radius_bottom,
+            });
+        }
+        if radius_top < 0.0 || radius_top.is_nan() {
+            return Err(PrimitiveError::InvalidDimension {
+                name: "cone radius_top",
+                value: radius_top,
+            });
+        }
+        check_positive("cone radius (largest)", radius_bottom.max(radius_top))?;
+        check_positive("cone height", height)?;
+        check_segments(segments)?;
+        Ok(Self {
             repr: SolidRepr::BRep(Box::new(vcad_kernel_primitives::make_cone(
                 radius_bottom,
                 radius_top,
@@ -158,7 +386,58 @@ impl Solid {
                 segments,
             ))),
             segments,
-        }
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        })
+    }
+
+    /// Create a regular polygonal prism with `sides` planar lateral faces.
+    ///
+    /// The polygon's vertices lie on a circle of `circumradius` in the XY
+    /// plane (first vertex on the +X axis), extruded along Z.
+    pub fn polygon_prism(
+        sides: u32,
+        circumradius: f64,
+        height: f64,
+    ) -> Result<Self, PrimitiveError> {
+        check_segments(sides)?;
+        check_positive("prism circumradius", circumradius)?;
+        check_positive("prism height", height)?;
+        Ok(Self {
+            repr: SolidRepr::BRep(Box::new(vcad_kernel_primitives::make_polygon_prism(
+                sides,
+                circumradius,
+                height,
+            ))),
+            segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        })
+    }
+
+    /// Create a pyramid over a regular polygon base with the apex at
+    /// `(0, 0, height)`.
+    pub fn pyramid(
+        base_sides: u32,
+        circumradius: f64,
+        height: f64,
+    ) -> Result<Self, PrimitiveError> {
+        check_segments(base_sides)?;
+        check_positive("pyramid circumradius", circumradius)?;
+        check_positive("pyramid height", height)?;
+        Ok(Self {
+            repr: SolidRepr::BRep(Box::new(vcad_kernel_primitives::make_pyramid(
+                base_sides,
+                circumradius,
+                height,
+            ))),
+            segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        })
     }
 
     // =========================================================================
@@ -181,13 +460,15 @@ impl Solid {
     }
 
     fn boolean(&self, other: &Solid, op: BooleanOp) -> Solid {
-        match (&self.repr, &other.repr) {
+        let mut result = match (&self.repr, &other.repr) {
             (SolidRepr::Empty, _) => match op {
-                BooleanOp::Union => other.clone(),
+                BooleanOp::Union | BooleanOp::SymmetricDifference => other.clone(),
                 BooleanOp::Difference | BooleanOp::Intersection => Solid::empty(),
             },
             (_, SolidRepr::Empty) => match op {
-                BooleanOp::Union | BooleanOp::Difference => self.clone(),
+                BooleanOp::Union | BooleanOp::Difference | BooleanOp::SymmetricDifference => {
+                    self.clone()
+                }
                 BooleanOp::Intersection => Solid::empty(),
             },
             (SolidRepr::BRep(a), SolidRepr::BRep(b)) => {
@@ -197,10 +478,16 @@ impl Solid {
                     BooleanResult::Mesh(m) => Solid {
                         repr: SolidRepr::Mesh(m),
                         segments,
+                        materials: Vec::new(),
+                        face_colors: Vec::new(),
+                        mesh_cache: RefCell::new(None),
                     },
                     BooleanResult::BRep(brep) => Solid {
                         repr: SolidRepr::BRep(brep),
                         segments,
+                        materials: Vec::new(),
+                        face_colors: Vec::new(),
+                        mesh_cache: RefCell::new(None),
                     },
                 }
             }
@@ -216,9 +503,50 @@ impl Solid {
                 Solid {
                     repr: SolidRepr::Mesh(combined),
                     segments,
+                    materials: Vec::new(),
+                    face_colors: Vec::new(),
+                    mesh_cache: RefCell::new(None),
                 }
             }
-        }
+        };
+        // Material regions survive booleans: union keeps both sides as-is,
+        // while difference and intersection apply the same cut to each region
+        let cut_regions =
+            |regions: &[MaterialRegion], tool: &Solid, cut: BooleanOp| -> Vec<MaterialRegion> {
+                regions
+                    .iter()
+                    .map(|r| {
+                        let mut solid = r.solid.boolean(tool, cut);
+                        solid.materials.clear();
+                        MaterialRegion {
+                            tag: r.tag.clone(),
+                            solid: Box::new(solid),
+                        }
+                    })
+                    .collect()
+            };
+        result.materials = match op {
+            BooleanOp::Union => self
+                .materials
+                .iter()
+                .chain(&other.materials)
+                .cloned()
+                .collect(),
+            BooleanOp::Difference => cut_regions(&self.materials, other, op),
+            BooleanOp::Intersection => {
+                let mut regions = cut_regions(&self.materials, other, op);
+                regions.extend(cut_regions(&other.materials, self, op));
+                regions
+            }
+            // XOR removes the overlap, so each side's regions lose whatever
+            // lies inside the other solid
+            BooleanOp::SymmetricDifference => {
+                let mut regions = cut_regions(&self.materials, other, BooleanOp::Difference);
+                regions.extend(cut_regions(&other.materials, self, BooleanOp::Difference));
+                regions
+            }
+        };
+        result
     }
 
     // =========================================================================
@@ -239,6 +567,9 @@ impl Solid {
                     brep, distance,
                 ))),
                 segments: self.segments,
+                materials: Vec::new(),
+                face_colors: Vec::new(),
+                mesh_cache: RefCell::new(None),
             },
             _ => self.clone(),
         }
@@ -257,11 +588,89 @@ impl Solid {
             SolidRepr::BRep(brep) => Solid {
                 repr: SolidRepr::BRep(Box::new(vcad_kernel_fillet::fillet_all_edges(brep, radius))),
                 segments: self.segments,
+                materials: Vec::new(),
+                face_colors: Vec::new(),
+                mesh_cache: RefCell::new(None),
             },
             _ => self.clone(),
         }
     }
 
+    /// Fillet a connected chain of edges with the given radius.
+    ///
+    /// Unlike [`Solid::fillet`], which blends every edge, this fillets only
+    /// the listed edges and treats them as one feature: where two chain edges
+    /// meet the blends share their boundary vertices, so the fillet runs
+    /// continuously around the chain instead of leaving gaps between
+    /// independent quarter-cylinders.
+    ///
+    /// Edge indices follow topology iteration order, as used by
+    /// [`Solid::edge_length`]; out-of-range indices are ignored.
+    ///
+    /// Only works on B-rep solids with planar faces. Returns the solid
+    /// unchanged for mesh-only or empty solids.
+    pub fn fillet_chain(&self, edges: &[usize], radius: f64) -> Solid {
+        match &self.repr {
+            SolidRepr::BRep(brep) => {
+                let ids: Vec<_> = edges
+                    .iter()
+                    .filter_map(|&i| brep.topology.edges.keys().nth(i))
+                    .collect();
+                Solid {
+                    repr: SolidRepr::BRep(Box::new(vcad_kernel_fillet::fillet_edge_chain(
+                        brep, &ids, radius,
+                    ))),
+                    segments: self.segments,
+                    materials: Vec::new(),
+                    face_colors: Vec::new(),
+                    mesh_cache: RefCell::new(None),
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Preview which of the given edges can be filleted at `radius`,
+    /// without building the result.
+    ///
+    /// Returns one [`FilletEdgePreview`] per requested edge, in request
+    /// order: whether the blend fits (the radius must stay under half the
+    /// length of every boundary edge of the adjacent faces) and, for
+    /// filletable edges, an approximate bounding box of the blend region
+    /// so a UI can highlight what would change. Edge indices follow
+    /// topology iteration order, as used by [`Solid::edge_length`];
+    /// out-of-range indices are reported as not filletable.
+    ///
+    /// Returns an empty vector for mesh-only or empty solids.
+    pub fn fillet_preview(&self, edges: &[usize], radius: f64) -> Vec<FilletEdgePreview> {
+        let Some(brep) = self.brep() else {
+            return Vec::new();
+        };
+
+        let resolved: Vec<(usize, Option<_>)> = edges
+            .iter()
+            .map(|&i| (i, brep.topology.edges.keys().nth(i)))
+            .collect();
+        let ids: Vec<_> = resolved.iter().filter_map(|(_, id)| *id).collect();
+        let mut previews = vcad_kernel_fillet::fillet_preview(brep, &ids, radius).into_iter();
+
+        resolved
+            .into_iter()
+            .map(|(edge_index, id)| match id.and_then(|_| previews.next()) {
+                Some(p) => FilletEdgePreview {
+                    edge_index,
+                    filletable: p.filletable,
+                    bounds: p.bounds,
+                },
+                None => FilletEdgePreview {
+                    edge_index,
+                    filletable: false,
+                    bounds: None,
+                },
+            })
+            .collect()
+    }
+
     /// Shell (hollow) the solid by offsetting all faces inward.
     ///
     /// Creates a hollow shell with walls of the specified thickness.
@@ -282,14 +691,69 @@ impl Solid {
             SolidRepr::BRep(brep) => Solid {
                 repr: SolidRepr::BRep(Box::new(vcad_kernel_shell::shell_brep(brep, thickness))),
                 segments: self.segments,
+                materials: Vec::new(),
+                face_colors: Vec::new(),
+                mesh_cache: RefCell::new(None),
             },
             SolidRepr::Mesh(mesh) => Solid {
                 repr: SolidRepr::Mesh(vcad_kernel_shell::shell_mesh(mesh, thickness)),
                 segments: self.segments,
+                materials: Vec::new(),
+                face_colors: Vec::new(),
+                mesh_cache: RefCell::new(None),
             },
         }
     }
 
+    /// Merge consecutive collinear boundary edges into single edges.
+    ///
+    /// Splitting operations (booleans, face merging) can leave chains of
+    /// edge fragments along what is geometrically one straight edge. This
+    /// removes each degree-two vertex whose neighbours are collinear within
+    /// `tol`, fusing the fragments into one edge. Mesh-only and empty
+    /// solids are returned unchanged.
+    pub fn merge_collinear_edges(&self, tol: f64) -> Solid {
+        match &self.repr {
+            SolidRepr::BRep(brep) => {
+                let mut brep = (**brep).clone();
+                brep.topology.merge_collinear_edges(tol);
+                Solid {
+                    repr: SolidRepr::BRep(Box::new(brep)),
+                    segments: self.segments,
+                    materials: Vec::new(),
+                    face_colors: Vec::new(),
+                    mesh_cache: RefCell::new(None),
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Collapse boundary edges shorter than `min_length`.
+    ///
+    /// Boolean splits near tangent intersections can leave sub-millimeter
+    /// edges that destabilize later operations and tessellation. Each short
+    /// edge is collapsed by merging its endpoints at their midpoint; sliver
+    /// faces reduced to two edges are dissolved and their neighbours
+    /// stitched together. This is a targeted cleanup, narrower than full
+    /// healing. Mesh-only and empty solids are returned unchanged.
+    pub fn remove_short_edges(&self, min_length: f64) -> Solid {
+        match &self.repr {
+            SolidRepr::BRep(brep) => {
+                let mut brep = (**brep).clone();
+                brep.topology.collapse_short_edges(min_length);
+                Solid {
+                    repr: SolidRepr::BRep(Box::new(brep)),
+                    segments: self.segments,
+                    materials: Vec::new(),
+                    face_colors: Vec::new(),
+                    mesh_cache: RefCell::new(None),
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+
     // =========================================================================
     // Pattern operations
     // =========================================================================
@@ -306,23 +770,34 @@ impl Solid {
     ///
     /// A union of all copies. Returns self if count < 2.
     pub fn linear_pattern(&self, direction: Vec3, count: u32, spacing: f64) -> Solid {
-        if count < 2 {
-            return self.clone();
+        let transforms = Self::linear_pattern_transforms(direction, count, spacing);
+        let mut result = self.clone();
+        for t in &transforms[1..] {
+            result = result.union(&self.apply_transform(t));
         }
+        result
+    }
 
+    /// Compute the placement transforms of a linear pattern without unioning.
+    ///
+    /// Returns one transform per instance, identity first, so callers can
+    /// keep the copies as separate bodies (assembly BOM, instanced
+    /// rendering) instead of the single solid [`Solid::linear_pattern`]
+    /// produces. Returns just the identity if `count < 2` or the direction
+    /// is degenerate.
+    pub fn linear_pattern_transforms(direction: Vec3, count: u32, spacing: f64) -> Vec<Transform> {
         let dir_norm = direction.norm();
-        if dir_norm < 1e-12 {
-            return self.clone();
+        if count < 2 || dir_norm < 1e-12 {
+            return vec![Transform::identity()];
         }
         let dir = direction / dir_norm;
 
-        let mut result = self.clone();
-        for i in 1..count {
-            let offset = dir * (spacing * i as f64);
-            let copy = self.translate(offset.x, offset.y, offset.z);
-            result = result.union(&copy);
-        }
-        result
+        (0..count)
+            .map(|i| {
+                let offset = dir * (spacing * i as f64);
+                Transform::translation(offset.x, offset.y, offset.z)
+            })
+            .collect()
     }
 
     /// Create a circular pattern of the solid around an axis.
@@ -344,33 +819,46 @@ impl Solid {
         count: u32,
         angle_deg: f64,
     ) -> Solid {
-        use vcad_kernel_math::Dir3;
-
-        if count < 2 {
-            return self.clone();
+        let transforms = Self::circular_pattern_transforms(axis_origin, axis_dir, count, angle_deg);
+        let mut result = self.clone();
+        for t in &transforms[1..] {
+            result = result.union(&self.apply_transform(t));
         }
+        result
+    }
+
+    /// Compute the placement transforms of a circular pattern without unioning.
+    ///
+    /// Returns one transform per instance, identity first, so callers can
+    /// keep the copies as separate bodies instead of the single solid
+    /// [`Solid::circular_pattern`] produces. Returns just the identity if
+    /// `count < 2` or the axis direction is degenerate.
+    pub fn circular_pattern_transforms(
+        axis_origin: Point3,
+        axis_dir: Vec3,
+        count: u32,
+        angle_deg: f64,
+    ) -> Vec<Transform> {
+        use vcad_kernel_math::Dir3;
 
         let dir_norm = axis_dir.norm();
-        if dir_norm < 1e-12 {
-            return self.clone();
+        if count < 2 || dir_norm < 1e-12 {
+            return vec![Transform::identity()];
         }
         let axis = Dir3::new_normalize(axis_dir);
         let angle_step = angle_deg.to_radians() / count as f64;
 
-        let mut result = self.clone();
-        for i in 1..count {
-            let angle = angle_step * i as f64;
-            // Build transform: translate to origin, rotate, translate back
-            let t_to_origin =
-                Transform::translation(-axis_origin.x, -axis_origin.y, -axis_origin.z);
-            let rot = Transform::rotation_about_axis(&axis, angle);
-            let t_back = Transform::translation(axis_origin.x, axis_origin.y, axis_origin.z);
-            // Compose: first translate to origin, then rotate, then translate back
-            let composed = t_back.then(&rot).then(&t_to_origin);
-            let copy = self.apply_transform(&composed);
-            result = result.union(&copy);
-        }
-        result
+        (0..count)
+            .map(|i| {
+                let angle = angle_step * i as f64;
+                // Translate to origin, rotate, translate back
+                let t_to_origin =
+                    Transform::translation(-axis_origin.x, -axis_origin.y, -axis_origin.z);
+                let rot = Transform::rotation_about_axis(&axis, angle);
+                let t_back = Transform::translation(axis_origin.x, axis_origin.y, axis_origin.z);
+                t_back.then(&rot).then(&t_to_origin)
+            })
+            .collect()
     }
 
     // =========================================================================
@@ -395,6 +883,9 @@ impl Solid {
         Ok(Solid {
             repr: SolidRepr::BRep(Box::new(brep)),
             segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
         })
     }
 
@@ -425,6 +916,9 @@ impl Solid {
         Ok(Solid {
             repr: SolidRepr::BRep(Box::new(brep)),
             segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
         })
     }
 
@@ -455,6 +949,9 @@ impl Solid {
         Ok(Solid {
             repr: SolidRepr::BRep(Box::new(brep)),
             segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
         })
     }
 
@@ -478,6 +975,9 @@ impl Solid {
         Ok(Solid {
             repr: SolidRepr::BRep(Box::new(brep)),
             segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
         })
     }
 
@@ -499,6 +999,9 @@ impl Solid {
         Ok(Solid {
             repr: SolidRepr::BRep(Box::new(brep)),
             segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
         })
     }
 
@@ -528,8 +1031,62 @@ impl Solid {
         self.apply_transform(&t)
     }
 
+    /// Scale the solid by `(sx, sy, sz)` about the point `(cx, cy, cz)`.
+    ///
+    /// Unlike [`Solid::scale`], which scales about the origin, the given
+    /// point stays fixed — e.g. pass the part's center to resize it in place.
+    pub fn scale_about(&self, cx: f64, cy: f64, cz: f64, sx: f64, sy: f64, sz: f64) -> Solid {
+        let t = Transform::translation(cx, cy, cz)
+            .then(&Transform::scale(sx, sy, sz))
+            .then(&Transform::translation(-cx, -cy, -cz));
+        self.apply_transform(&t)
+    }
+
+    /// Rotate the solid by `angle_deg` degrees about an arbitrary axis.
+    ///
+    /// The axis passes through `(px, py, pz)` with direction
+    /// `(ax, ay, az)`. Unlike [`Solid::rotate`], which rotates about the
+    /// world axes through the origin, points on the axis stay fixed. A
+    /// zero-length axis direction leaves the solid unchanged.
+    #[allow(clippy::too_many_arguments)]
+    pub fn rotate_about_axis(
+        &self,
+        px: f64,
+        py: f64,
+        pz: f64,
+        ax: f64,
+        ay: f64,
+        az: f64,
+        angle_deg: f64,
+    ) -> Solid {
+        let axis = Vec3::new(ax, ay, az);
+        if axis.norm() < 1e-12 {
+            return self.clone();
+        }
+        let axis = vcad_kernel_math::Dir3::new_normalize(axis);
+        let t = Transform::translation(px, py, pz)
+            .then(&Transform::rotation_about_axis(
+                &axis,
+                angle_deg.to_radians(),
+            ))
+            .then(&Transform::translation(-px, -py, -pz));
+        self.apply_transform(&t)
+    }
+
+    /// Apply an arbitrary affine transform given as 16 row-major values.
+    ///
+    /// Lets assembly code place a part with a full 4×4 frame (e.g. a parent
+    /// coordinate system) instead of decomposed translate/rotate/scale
+    /// calls. Vertices and surface definitions are both transformed, and a
+    /// mirroring matrix (negative determinant) flips face orientations like
+    /// the other transforms.
+    pub fn apply_matrix(&self, values: &[f64; 16]) -> Solid {
+        let t = Transform::from_row_major(values);
+        self.apply_transform(&t)
+    }
+
     fn apply_transform(&self, transform: &Transform) -> Solid {
-        match &self.repr {
+        let mut result = match &self.repr {
             SolidRepr::Empty => Solid::empty(),
             SolidRepr::BRep(brep) => {
                 let mut new_brep = brep.as_ref().clone();
@@ -558,6 +1115,9 @@ impl Solid {
                 Solid {
                     repr: SolidRepr::BRep(Box::new(new_brep)),
                     segments: self.segments,
+                    materials: Vec::new(),
+                    face_colors: Vec::new(),
+                    mesh_cache: RefCell::new(None),
                 }
             }
             SolidRepr::Mesh(mesh) => {
@@ -580,9 +1140,24 @@ impl Solid {
                 Solid {
                     repr: SolidRepr::Mesh(new_mesh),
                     segments: self.segments,
+                    materials: Vec::new(),
+                    face_colors: Vec::new(),
+                    mesh_cache: RefCell::new(None),
                 }
             }
-        }
+        };
+        // Material regions move with the solid
+        result.materials = self
+            .materials
+            .iter()
+            .map(|r| MaterialRegion {
+                tag: r.tag.clone(),
+                solid: Box::new(r.solid.apply_transform(transform)),
+            })
+            .collect();
+        // Face order is preserved, so per-face colors stay valid
+        result.face_colors = self.face_colors.clone();
+        result
     }
 
     // =========================================================================
@@ -598,70 +1173,1331 @@ impl Solid {
         }
     }
 
+    /// Thorough degeneracy check for operation results.
+    ///
+    /// Returns `true` for empty solids, solids with no faces or triangles,
+    /// (near-)zero enclosed volume, or coordinates that are NaN/infinite.
+    /// Unlike [`Solid::is_empty`], this catches flattened or collapsed
+    /// geometry — e.g. a scale to zero, or the intersection of disjoint
+    /// solids — so callers can guard before chaining further operations.
+    pub fn is_degenerate(&self) -> bool {
+        match &self.repr {
+            SolidRepr::Empty => true,
+            SolidRepr::BRep(brep) => {
+                if brep.topology.faces.is_empty() {
+                    return true;
+                }
+                let finite = brep
+                    .topology
+                    .vertices
+                    .values()
+                    .all(|v| v.point.coords.iter().all(|c| c.is_finite()));
+                if !finite {
+                    return true;
+                }
+                // Collapsed geometry can tessellate to NaN (e.g. a surface
+                // scaled to zero height), so a non-finite volume counts too
+                let volume = self.volume();
+                !volume.is_finite() || volume < 1e-9
+            }
+            SolidRepr::Mesh(m) => {
+                if m.num_triangles() == 0 || m.vertices.iter().any(|c| !c.is_finite()) {
+                    return true;
+                }
+                let volume = self.volume();
+                !volume.is_finite() || volume < 1e-9
+            }
+        }
+    }
+
     /// Get the triangle mesh representation.
+    ///
+    /// The last tessellation is cached per instance, so repeated calls with
+    /// the same segment count do not re-tessellate.
     pub fn to_mesh(&self, segments: u32) -> TriangleMesh {
         match &self.repr {
             SolidRepr::Empty => TriangleMesh::new(),
-            SolidRepr::BRep(brep) => tessellate_brep(brep.as_ref(), segments),
+            SolidRepr::BRep(brep) => {
+                if let Some((cached_segments, mesh)) = self.mesh_cache.borrow().as_ref() {
+                    if *cached_segments == segments {
+                        return mesh.clone();
+                    }
+                }
+                #[cfg(test)]
+                tests::TESSELLATION_COUNT.with(|c| c.set(c.get() + 1));
+                let mesh = tessellate_brep(brep.as_ref(), segments);
+                *self.mesh_cache.borrow_mut() = Some((segments, mesh.clone()));
+                mesh
+            }
             SolidRepr::Mesh(m) => m.clone(),
         }
     }
 
-    /// Compute the volume of the solid from its triangle mesh.
-    pub fn volume(&self) -> f64 {
-        let mesh = self.to_mesh(self.segments);
-        compute_volume(&mesh)
+    /// Get one triangle mesh per connected body.
+    ///
+    /// Boolean operations can split a solid into several disconnected pieces;
+    /// this tessellates the solid and separates the mesh into connected
+    /// components so each body can be exported or colored individually.
+    pub fn meshes_per_body(&self, segments: u32) -> Vec<TriangleMesh> {
+        self.to_mesh(segments).connected_components()
     }
 
-    /// Compute the surface area of the solid from its triangle mesh.
-    pub fn surface_area(&self) -> f64 {
-        let mesh = self.to_mesh(self.segments);
-        compute_surface_area(&mesh)
+    /// Get the triangle mesh with per-face segment overrides.
+    ///
+    /// `refinements` maps face indices (topology iteration order, as used by
+    /// [`Solid::sketch_plane_from_face`]) to segment counts; listed faces are
+    /// tessellated at their own detail while the rest use `default_segments`.
+    /// Lets a viewer bump detail only where the user is zoomed in. Indices
+    /// not present in the solid are ignored.
+    pub fn to_mesh_refined(
+        &self,
+        default_segments: u32,
+        refinements: &std::collections::HashMap<usize, u32>,
+    ) -> TriangleMesh {
+        use vcad_kernel_tessellate::{tessellate_brep_refined, TessellationParams};
+
+        let Some(brep) = self.brep() else {
+            return self.to_mesh(default_segments);
+        };
+
+        let overrides = brep
+            .topology
+            .faces
+            .keys()
+            .enumerate()
+            .filter_map(|(i, face_id)| {
+                refinements
+                    .get(&i)
+                    .map(|&segments| (face_id, TessellationParams::from_segments(segments)))
+            })
+            .collect();
+        tessellate_brep_refined(brep, default_segments, &overrides)
     }
 
-    /// Compute the axis-aligned bounding box as `(min, max)`.
+    /// Return a solid whose tessellated mesh has consistently wound,
+    /// outward-facing triangles.
     ///
-    /// For B-rep solids with only planar faces, computes directly from vertex
-    /// positions (no tessellation needed). For curved surfaces, falls back to
-    /// the tessellated mesh since vertices alone don't capture the full extent.
-    pub fn bounding_box(&self) -> ([f64; 3], [f64; 3]) {
+    /// Boolean results can leave individual faces with inverted orientation,
+    /// which renders as holes or dark patches in a viewer. This tessellates
+    /// the solid, reorients every triangle via
+    /// [`TriangleMesh::fix_winding`], and returns the result as a mesh-only
+    /// solid the renderer can rely on.
+    pub fn fix_normals_for_rendering(&self) -> Solid {
         match &self.repr {
-            SolidRepr::BRep(brep) => {
-                use vcad_kernel_geom::SurfaceKind;
-                let all_planar = brep
-                    .geometry
-                    .surfaces
-                    .iter()
-                    .all(|s| s.surface_type() == SurfaceKind::Plane);
-                if all_planar {
-                    let mut min = [f64::MAX; 3];
-                    let mut max = [f64::MIN; 3];
-                    for (_id, v) in &brep.topology.vertices {
-                        let p = v.point;
-                        min[0] = min[0].min(p.x);
-                        min[1] = min[1].min(p.y);
-                        min[2] = min[2].min(p.z);
-                        max[0] = max[0].max(p.x);
-                        max[1] = max[1].max(p.y);
-                        max[2] = max[2].max(p.z);
-                    }
-                    (min, max)
-                } else {
-                    let mesh = self.to_mesh(self.segments);
-                    compute_bounding_box(&mesh)
-                }
-            }
+            SolidRepr::Empty => self.clone(),
             _ => {
-                let mesh = self.to_mesh(self.segments);
-                compute_bounding_box(&mesh)
+                let mut mesh = self.to_mesh(self.segments);
+                mesh.fix_winding();
+                Solid {
+                    repr: SolidRepr::Mesh(mesh),
+                    segments: self.segments,
+                    materials: self.materials.clone(),
+                    face_colors: self.face_colors.clone(),
+                    mesh_cache: RefCell::new(None),
+                }
             }
         }
     }
 
-    /// Compute the geometric centroid (volume-weighted center of mass).
-    pub fn center_of_mass(&self) -> [f64; 3] {
-        let mesh = self.to_mesh(self.segments);
-        compute_center_of_mass(&mesh)
+    /// Reconstruct a B-rep approximation of a mesh-only solid by fitting
+    /// analytic surfaces.
+    ///
+    /// The mesh is segmented into smooth regions, and each region gets a
+    /// plane, cylinder, or sphere fitted by least squares; regions within
+    /// `tolerance` (mm) of their fit become single B-rep faces, the rest fall
+    /// back to one planar face per triangle. The result supports STEP export
+    /// and exact queries again — useful for imported STL scans. B-rep and
+    /// empty solids are returned unchanged.
+    pub fn fit_surfaces(&self, tolerance: f64) -> Solid {
+        match &self.repr {
+            SolidRepr::Mesh(mesh) if mesh.num_triangles() > 0 => Solid {
+                repr: SolidRepr::BRep(Box::new(fit::fit_mesh_surfaces(mesh, tolerance))),
+                segments: self.segments,
+                materials: self.materials.clone(),
+                face_colors: self.face_colors.clone(),
+                mesh_cache: RefCell::new(None),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Remove faces lying entirely inside the solid's volume, then re-sew.
+    ///
+    /// Unioning solids that were themselves booleaned can leave internal
+    /// walls the classification missed. A face counts as internal when
+    /// sample points offset by `tol` (mm) to both its sides land inside
+    /// the tessellated boundary; such faces are peeled off and the rest is
+    /// re-sewn. Face colors are dropped since the topology is rebuilt;
+    /// mesh-only and clean solids come back unchanged.
+    pub fn remove_internal_faces(&self, tol: f64) -> Solid {
+        use vcad_kernel_booleans::{classify, point_in_mesh, sew};
+
+        let SolidRepr::BRep(brep) = &self.repr else {
+            return self.clone();
+        };
+        let eps = tol.max(vcad_kernel_math::geometry_tolerance());
+        let params = vcad_kernel_tessellate::TessellationParams::from_segments(self.segments);
+
+        let face_ids: Vec<vcad_kernel_topo::FaceId> = brep.topology.faces.keys().collect();
+        let face_meshes: Vec<TriangleMesh> = face_ids
+            .iter()
+            .map(|&face_id| {
+                vcad_kernel_tessellate::tessellate_face(
+                    &brep.topology,
+                    &brep.geometry,
+                    face_id,
+                    &params,
+                )
+            })
+            .collect();
+        let probes: Vec<[Point3; 2]> = face_ids
+            .iter()
+            .map(|&face_id| {
+                let sample = classify::face_sample_point(brep, face_id);
+                let normal = Self::face_outward_normal(brep, face_id);
+                [sample + eps * normal, sample - eps * normal]
+            })
+            .collect();
+
+        // Peel internal faces until none are left. The containment test is
+        // parity-safe because classification misses leave walls as
+        // coincident face pairs — a probe ray crosses both copies, so the
+        // crossing count stays consistent. Each sweep re-merges only the
+        // surviving faces, since every removal changes what "inside" means
+        let mut live: Vec<usize> = (0..face_ids.len()).collect();
+        loop {
+            let mut full_mesh = TriangleMesh::new();
+            for &i in &live {
+                full_mesh.merge(&face_meshes[i]);
+            }
+            let internal: Vec<usize> = live
+                .iter()
+                .copied()
+                .filter(|&i| probes[i].iter().all(|p| point_in_mesh(p, &full_mesh)))
+                .collect();
+            if internal.is_empty() {
+                break;
+            }
+            live.retain(|i| !internal.contains(i));
+        }
+
+        if live.len() == face_ids.len() {
+            return self.clone();
+        }
+        let keep: Vec<vcad_kernel_topo::FaceId> = live.iter().map(|&i| face_ids[i]).collect();
+        let sewn = sew::sew_faces(
+            brep,
+            &keep,
+            brep,
+            &[],
+            false,
+            vcad_kernel_math::geometry_tolerance(),
+        );
+        Solid {
+            repr: SolidRepr::BRep(Box::new(sewn)),
+            segments: self.segments,
+            materials: self.materials.clone(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        }
+    }
+
+    /// Outward normal of a face from its outer-loop winding, falling back to
+    /// the oriented surface normal for degenerate loops.
+    fn face_outward_normal(brep: &BRepSolid, face_id: vcad_kernel_topo::FaceId) -> Vec3 {
+        let face = &brep.topology.faces[face_id];
+        let verts: Vec<Point3> = brep
+            .topology
+            .loop_half_edges(face.outer_loop)
+            .map(|he_id| brep.topology.vertices[brep.topology.half_edges[he_id].origin].point)
+            .collect();
+        if verts.len() >= 3 {
+            let n = (verts[1] - verts[0]).cross(&(verts[2] - verts[0]));
+            if n.norm() > 1e-15 {
+                return n.normalize();
+            }
+        }
+        let surface = &brep.geometry.surfaces[face.surface_index];
+        let n = *surface.normal(vcad_kernel_math::Point2::origin()).as_ref();
+        match face.orientation {
+            vcad_kernel_topo::Orientation::Forward => n,
+            vcad_kernel_topo::Orientation::Reversed => -n,
+        }
+    }
+
+    /// Whether the solid's boundary is two-manifold: every edge is shared by
+    /// at most two triangles with consistent orientation.
+    ///
+    /// Runs on the tessellated boundary, so it works for both B-rep and
+    /// mesh-only solids. An empty solid is vacuously manifold. A quick health
+    /// check before export or slicing; pair with [`Solid::is_closed`] to also
+    /// require watertightness.
+    pub fn is_manifold(&self) -> bool {
+        match &self.repr {
+            SolidRepr::Empty => true,
+            _ => self.to_mesh(self.segments).is_manifold(),
+        }
+    }
+
+    /// Whether the solid's boundary is closed (watertight): every edge is
+    /// shared by exactly two triangles.
+    ///
+    /// Runs on the tessellated boundary, so it works for both B-rep and
+    /// mesh-only solids. An empty solid is vacuously closed.
+    pub fn is_closed(&self) -> bool {
+        match &self.repr {
+            SolidRepr::Empty => true,
+            _ => self.to_mesh(self.segments).is_closed(),
+        }
+    }
+
+    /// Unfold a developable face into its flat 2D pattern.
+    ///
+    /// Returns the face outline as a polygon in the flattened plane. Planar
+    /// faces project directly; cylindrical lateral faces unroll to a
+    /// rectangle of width `2πr` (arc length) by their axial extent; conical
+    /// faces unroll to an annular sector. This is the inverse of wrapping a
+    /// sketch onto the surface. Returns `None` for non-developable faces
+    /// (spheres, tori, freeform) or an invalid index.
+    pub fn unfold_face(&self, face_index: usize) -> Option<Vec<vcad_kernel_math::Point2>> {
+        use std::f64::consts::PI;
+        use vcad_kernel_geom::{ConeSurface, CylinderSurface, Plane, SurfaceKind};
+        use vcad_kernel_math::Point2;
+
+        let brep = self.brep()?;
+        let (face_id, face) = brep.topology.faces.iter().nth(face_index)?;
+        let surface = &brep.geometry.surfaces[face.surface_index];
+
+        let verts = brep.topology.loop_vertices(face.outer_loop);
+        if verts.is_empty() {
+            return None;
+        }
+        let points: Vec<Point3> = verts
+            .iter()
+            .map(|&v| brep.topology.vertices[v].point)
+            .collect();
+
+        // A face wraps fully around its axis when its loop contains a seam:
+        // a half-edge whose twin lies in the same face.
+        let has_seam = brep.topology.loop_half_edges(face.outer_loop).any(|he| {
+            brep.topology.half_edges[he]
+                .twin
+                .and_then(|t| brep.topology.half_edges[t].loop_id)
+                .and_then(|l| brep.topology.loops[l].face)
+                == Some(face_id)
+        });
+
+        match surface.surface_type() {
+            SurfaceKind::Plane => {
+                let plane = surface.as_any().downcast_ref::<Plane>()?;
+                Some(points.iter().map(|p| plane.project(p)).collect())
+            }
+            SurfaceKind::Cylinder => {
+                let cyl = surface.as_any().downcast_ref::<CylinderSurface>()?;
+                let axis = cyl.axis.as_ref();
+                let x_ref = cyl.ref_dir.as_ref();
+                let y_ref = axis.cross(x_ref);
+
+                let mut v_min = f64::MAX;
+                let mut v_max = f64::MIN;
+                let mut theta_min = f64::MAX;
+                let mut theta_max = f64::MIN;
+                for p in &points {
+                    let d = p - cyl.center;
+                    let v = d.dot(axis);
+                    v_min = v_min.min(v);
+                    v_max = v_max.max(v);
+                    let theta = d.dot(&y_ref).atan2(d.dot(x_ref)).rem_euclid(2.0 * PI);
+                    theta_min = theta_min.min(theta);
+                    theta_max = theta_max.max(theta);
+                }
+
+                // Unrolled width is the arc length of the angular extent
+                let width = if has_seam {
+                    2.0 * PI * cyl.radius
+                } else {
+                    (theta_max - theta_min) * cyl.radius
+                };
+
+                Some(vec![
+                    Point2::new(0.0, v_min),
+                    Point2::new(width, v_min),
+                    Point2::new(width, v_max),
+                    Point2::new(0.0, v_max),
+                ])
+            }
+            SurfaceKind::Cone => {
+                let cone = surface.as_any().downcast_ref::<ConeSurface>()?;
+                let axis = cone.axis.as_ref();
+                let x_ref = cone.ref_dir.as_ref();
+                let y_ref = axis.cross(x_ref);
+                let sin_a = cone.half_angle.sin();
+
+                // Slant range from the apex and angular extent
+                let mut s_min = f64::MAX;
+                let mut s_max: f64 = 0.0;
+                let mut theta_min = f64::MAX;
+                let mut theta_max = f64::MIN;
+                for p in &points {
+                    let d = p - cone.apex;
+                    let s = d.norm();
+                    s_min = s_min.min(s);
+                    s_max = s_max.max(s);
+                    let theta = d.dot(&y_ref).atan2(d.dot(x_ref)).rem_euclid(2.0 * PI);
+                    theta_min = theta_min.min(theta);
+                    theta_max = theta_max.max(theta);
+                }
+
+                // Unrolling maps azimuth θ to flat angle θ·sin(half_angle)
+                let sweep = if has_seam {
+                    2.0 * PI * sin_a
+                } else {
+                    (theta_max - theta_min) * sin_a
+                };
+
+                // Annular sector outline: outer arc then inner arc (reversed)
+                let n = 32;
+                let mut outline = Vec::with_capacity(2 * (n + 1));
+                for i in 0..=n {
+                    let phi = sweep * i as f64 / n as f64;
+                    outline.push(Point2::new(s_max * phi.cos(), s_max * phi.sin()));
+                }
+                if s_min > 1e-9 {
+                    for i in (0..=n).rev() {
+                        let phi = sweep * i as f64 / n as f64;
+                        outline.push(Point2::new(s_min * phi.cos(), s_min * phi.sin()));
+                    }
+                }
+                Some(outline)
+            }
+            _ => None,
+        }
+    }
+
+    /// Unfold every connected developable face of the shell into one flat
+    /// pattern.
+    ///
+    /// Each face is flattened in its own surface coordinates, then rotated
+    /// about the bend edge it shares with an already-placed neighbour so the
+    /// whole pattern lies in a single plane — the sheet-metal flat layout of
+    /// the part. Shared edges between placed faces are reported as bend
+    /// lines. Faces that cannot be developed (spheres, tori, freeform) are
+    /// flagged in [`ShellUnfold::non_developable`] and left out of the
+    /// pattern, as is everything for mesh-only solids.
+    pub fn unfold_shell(&self) -> ShellUnfold {
+        match self.brep() {
+            Some(brep) => unfold::unfold_shell(brep),
+            None => ShellUnfold::default(),
+        }
+    }
+
+    /// Sample a height field (depth map) of the solid along an axis.
+    ///
+    /// Covers the solid's bounding box in the two cross axes with a
+    /// `resolution × resolution` grid and records the maximum surface height
+    /// along `axis` at each cell center — the surface a roughing tool sees
+    /// looking down the axis, for 2.5D machining previews. Cells the solid
+    /// does not cover hold [`HeightField::floor`]. Returns `None` for an
+    /// empty solid, a zero resolution, or a degenerate bounding box.
+    pub fn height_field(&self, resolution: u32, axis: HeightAxis) -> Option<HeightField> {
+        height_field::height_field(&self.to_mesh(self.segments), resolution, axis)
+    }
+
+    /// Compute the exact area of a single face from its surface geometry.
+    ///
+    /// Analytic where the surface allows it: plane polygon area (including
+    /// circular caps and holes), cylinder lateral area (`2πrh` for a full
+    /// wrap, developed polygon area otherwise), and conical faces as the
+    /// flattened annular sector. Returns `None` for surface kinds without an
+    /// analytic formula (spheres, tori, freeform), for mesh-only solids, or
+    /// an invalid index — unlike [`Solid::surface_area`], this never
+    /// approximates from triangles.
+    ///
+    /// `face_index` is the face's position in topology iteration order, as
+    /// used by [`Solid::sketch_plane_from_face`].
+    pub fn face_area(&self, face_index: usize) -> Option<f64> {
+        use std::f64::consts::PI;
+        use vcad_kernel_geom::{ConeSurface, CylinderSurface, Plane, SurfaceKind};
+
+        let brep = self.brep()?;
+        let (face_id, face) = brep.topology.faces.iter().nth(face_index)?;
+        let surface = &brep.geometry.surfaces[face.surface_index];
+
+        let loop_points = |loop_id| -> Vec<Point3> {
+            brep.topology
+                .loop_vertices(loop_id)
+                .iter()
+                .map(|&v| brep.topology.vertices[v].point)
+                .collect()
+        };
+        let points = loop_points(face.outer_loop);
+        if points.is_empty() {
+            return None;
+        }
+
+        let has_seam = brep.topology.loop_half_edges(face.outer_loop).any(|he| {
+            brep.topology.half_edges[he]
+                .twin
+                .and_then(|t| brep.topology.half_edges[t].loop_id)
+                .and_then(|l| brep.topology.loops[l].face)
+                == Some(face_id)
+        });
+
+        match surface.surface_type() {
+            SurfaceKind::Plane => {
+                let plane = surface.as_any().downcast_ref::<Plane>()?;
+                let loop_area = |pts: &[Point3]| -> Option<f64> {
+                    if pts.len() == 1 {
+                        // Circular cap: the single seam vertex sits on the rim
+                        let r = (pts[0] - plane.origin).norm();
+                        Some(PI * r * r)
+                    } else if pts.len() >= 3 {
+                        // Shoelace over the plane's (u, v) projection
+                        let uv: Vec<_> = pts.iter().map(|p| plane.project(p)).collect();
+                        let mut twice_area = 0.0;
+                        for i in 0..uv.len() {
+                            let j = (i + 1) % uv.len();
+                            twice_area += uv[i].x * uv[j].y - uv[j].x * uv[i].y;
+                        }
+                        Some(0.5 * twice_area.abs())
+                    } else {
+                        None
+                    }
+                };
+                let mut area = loop_area(&points)?;
+                for &inner in &face.inner_loops {
+                    area -= loop_area(&loop_points(inner))?;
+                }
+                Some(area)
+            }
+            SurfaceKind::Cylinder => {
+                let cyl = surface.as_any().downcast_ref::<CylinderSurface>()?;
+                let axis = cyl.axis.as_ref();
+                let x_ref = cyl.ref_dir.as_ref();
+                let y_ref = axis.cross(x_ref);
+
+                let mut v_min = f64::MAX;
+                let mut v_max = f64::MIN;
+                let mut thetas = Vec::with_capacity(points.len());
+                for p in &points {
+                    let d = p - cyl.center;
+                    let v = d.dot(axis);
+                    v_min = v_min.min(v);
+                    v_max = v_max.max(v);
+                    thetas.push(d.dot(&y_ref).atan2(d.dot(x_ref)));
+                }
+
+                if has_seam {
+                    // Full wrap: lateral area 2πrh
+                    return Some(2.0 * PI * cyl.radius * (v_max - v_min));
+                }
+
+                // Partial wrap: develop the loop to (r·θ, v) and take the
+                // polygon area, unwrapping θ for continuity between vertices
+                if points.len() < 3 {
+                    return None;
+                }
+                let mut developed = Vec::with_capacity(points.len());
+                let mut prev_theta = thetas[0];
+                for (p, &raw) in points.iter().zip(&thetas) {
+                    let theta = raw - 2.0 * PI * ((raw - prev_theta) / (2.0 * PI)).round();
+                    prev_theta = theta;
+                    let v = (p - cyl.center).dot(axis);
+                    developed.push((cyl.radius * theta, v));
+                }
+                let mut twice_area = 0.0;
+                for i in 0..developed.len() {
+                    let j = (i + 1) % developed.len();
+                    twice_area += developed[i].0 * developed[j].1 - developed[j].0 * developed[i].1;
+                }
+                Some(0.5 * twice_area.abs())
+            }
+            SurfaceKind::Cone => {
+                let cone = surface.as_any().downcast_ref::<ConeSurface>()?;
+                let axis = cone.axis.as_ref();
+                let x_ref = cone.ref_dir.as_ref();
+                let y_ref = axis.cross(x_ref);
+                let sin_a = cone.half_angle.sin();
+
+                let mut s_min = f64::MAX;
+                let mut s_max: f64 = 0.0;
+                let mut theta_min = f64::MAX;
+                let mut theta_max = f64::MIN;
+                for p in &points {
+                    let d = p - cone.apex;
+                    let s = d.norm();
+                    s_min = s_min.min(s);
+                    s_max = s_max.max(s);
+                    let theta = d.dot(&y_ref).atan2(d.dot(x_ref)).rem_euclid(2.0 * PI);
+                    theta_min = theta_min.min(theta);
+                    theta_max = theta_max.max(theta);
+                }
+                if s_max < 1e-12 {
+                    return Some(0.0);
+                }
+                // Pointed cones have the apex in the loop, so s_min is 0 there
+                if s_min == f64::MAX {
+                    s_min = 0.0;
+                }
+
+                // Flattened annular sector: A = ½·sweep·(s_max² − s_min²)
+                let sweep = if has_seam || points.len() <= 2 {
+                    2.0 * PI * sin_a
+                } else {
+                    (theta_max - theta_min) * sin_a
+                };
+                Some(0.5 * sweep * (s_max * s_max - s_min * s_min))
+            }
+            _ => None,
+        }
+    }
+
+    /// Report the rounding radius of a single face's underlying surface.
+    ///
+    /// Cylindrical and spherical faces report their radius, toroidal faces
+    /// (fillet blends over convex edges) the minor radius — the value a
+    /// fillet or rounded feature was built with. Planar and freeform faces
+    /// return `None`, as do mesh-only solids and invalid indices.
+    ///
+    /// `face_index` is the face's position in topology iteration order, as
+    /// used by [`Solid::face_area`].
+    pub fn face_radius(&self, face_index: usize) -> Option<f64> {
+        use vcad_kernel_geom::{CylinderSurface, SphereSurface, SurfaceKind, TorusSurface};
+
+        let brep = self.brep()?;
+        let (_, face) = brep.topology.faces.iter().nth(face_index)?;
+        let surface = &brep.geometry.surfaces[face.surface_index];
+
+        match surface.surface_type() {
+            SurfaceKind::Cylinder => {
+                let cyl = surface.as_any().downcast_ref::<CylinderSurface>()?;
+                Some(cyl.radius.abs())
+            }
+            SurfaceKind::Sphere => {
+                let sphere = surface.as_any().downcast_ref::<SphereSurface>()?;
+                Some(sphere.radius.abs())
+            }
+            SurfaceKind::Torus => {
+                let torus = surface.as_any().downcast_ref::<TorusSurface>()?;
+                Some(torus.minor_radius.abs())
+            }
+            _ => None,
+        }
+    }
+
+    /// Engrave text around a cylindrical face.
+    ///
+    /// Lays the glyphs of `text` along the face's circumference starting at
+    /// `start_angle` (radians from the surface's reference direction) and
+    /// `height_pos` along the axis from the surface origin, then cuts each
+    /// glyph `depth` deep into the surface by boolean difference. Glyph
+    /// counters (the holes in 'A', 'B', 'O', …) stay raised. `size` is the
+    /// text height in mm. `font_data` is raw TTF/OTF bytes; pass `None` for
+    /// the builtin sans-serif.
+    ///
+    /// Each glyph is cut with a prism built on the tangent plane at the
+    /// glyph's centre angle, so pocket edges are shallower than `depth` by
+    /// the chord sagitta — negligible while glyphs are small against the
+    /// radius.
+    ///
+    /// Returns `None` for mesh-only solids, an invalid or non-cylindrical
+    /// `face_index`, unparseable font data, a non-positive `depth` or one
+    /// that reaches the axis, or text that produces no glyphs.
+    ///
+    /// `face_index` is the face's position in topology iteration order, as
+    /// used by [`Solid::face_area`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn engrave_text_on_cylinder(
+        &self,
+        text: &str,
+        font_data: Option<&[u8]>,
+        face_index: usize,
+        start_angle: f64,
+        height_pos: f64,
+        size: f64,
+        depth: f64,
+    ) -> Option<Solid> {
+        let custom = match font_data {
+            Some(data) => Some(vcad_kernel_text::Font::from_data("custom", data).ok()?),
+            None => None,
+        };
+        let font = match &custom {
+            Some(f) => f,
+            None => vcad_kernel_text::FontRegistry::builtin_sans(),
+        };
+        engrave::engrave_text_on_cylinder(
+            self,
+            text,
+            font,
+            face_index,
+            start_angle,
+            height_pos,
+            size,
+            depth,
+        )
+    }
+
+    /// Compute the exact length of a single edge from its geometry.
+    ///
+    /// Closed edges (both half-edges share one vertex) bounding a cylindrical
+    /// or conical face are full circles: length `2πr` with `r` taken as the
+    /// vertex's distance from the surface axis. Open edges on two planar
+    /// faces are straight: the endpoint distance. Open edges on a curved face
+    /// whose endpoints sit at the same radius and height are minor arcs.
+    /// Anything else falls back to the endpoint distance (a chord). Returns
+    /// `None` for mesh-only solids or an invalid index.
+    ///
+    /// `edge_index` is the edge's position in topology iteration order.
+    pub fn edge_length(&self, edge_index: usize) -> Option<f64> {
+        use std::f64::consts::PI;
+        use vcad_kernel_geom::{ConeSurface, CylinderSurface, SurfaceKind};
+
+        let brep = self.brep()?;
+        let edge = brep.topology.edges.values().nth(edge_index)?;
+        let he = &brep.topology.half_edges[edge.half_edge];
+        let p0 = brep.topology.vertices[he.origin].point;
+        let twin = he.twin?;
+        let p1 = brep.topology.vertices[brep.topology.half_edges[twin].origin].point;
+
+        // Surfaces of the two faces adjacent to this edge
+        let face_surface = |he_id| -> Option<&dyn vcad_kernel_geom::Surface> {
+            let l = brep.topology.half_edges[he_id].loop_id?;
+            let f = brep.topology.loops[l].face?;
+            Some(brep.geometry.surfaces[brep.topology.faces[f].surface_index].as_ref())
+        };
+        let surfaces = [face_surface(edge.half_edge), face_surface(twin)];
+
+        // Axis and radius of an adjacent curved surface, if any
+        let curved = surfaces
+            .iter()
+            .flatten()
+            .find_map(|s| match s.surface_type() {
+                SurfaceKind::Cylinder => {
+                    let cyl = s.as_any().downcast_ref::<CylinderSurface>()?;
+                    Some((cyl.center, *cyl.axis.as_ref()))
+                }
+                SurfaceKind::Cone => {
+                    let cone = s.as_any().downcast_ref::<ConeSurface>()?;
+                    Some((cone.apex, *cone.axis.as_ref()))
+                }
+                _ => None,
+            });
+
+        let closed = (p1 - p0).norm() < 1e-12;
+        match curved {
+            Some((origin, axis)) => {
+                let radial = |p: Point3| {
+                    let d = p - origin;
+                    (d - d.dot(&axis) * axis).norm()
+                };
+                let r0 = radial(p0);
+                if closed {
+                    // Full circle (e.g. a cylinder cap rim)
+                    return Some(2.0 * PI * r0);
+                }
+                let r1 = radial(p1);
+                let h0 = (p0 - origin).dot(&axis);
+                let h1 = (p1 - origin).dot(&axis);
+                if (r0 - r1).abs() < 1e-9 && (h0 - h1).abs() < 1e-9 && r0 > 1e-12 {
+                    // Minor arc on a circle of radius r0
+                    let chord = (p1 - p0).norm();
+                    let half_angle = (chord / (2.0 * r0)).clamp(-1.0, 1.0).asin();
+                    return Some(2.0 * r0 * half_angle);
+                }
+                Some((p1 - p0).norm())
+            }
+            None => {
+                if closed {
+                    return Some(0.0);
+                }
+                Some((p1 - p0).norm())
+            }
+        }
+    }
+
+    /// Sample every B-rep edge as a 3D polyline for wireframe rendering.
+    ///
+    /// Straight edges come back as their two endpoints. Closed circular
+    /// edges (cap rims on cylindrical or conical faces) are sampled with
+    /// `segments` points plus a repeat of the first so the polyline closes;
+    /// open arcs get a proportional share of `segments`. Unlike feature
+    /// edges derived from the mesh these are the actual topology edges, so
+    /// a wireframe overlay stays crisp at any tessellation quality. Returns
+    /// `None` for mesh-only solids.
+    pub fn wireframe_edges(&self, segments: u32) -> Option<Vec<Vec<Point3>>> {
+        use std::f64::consts::PI;
+        use vcad_kernel_geom::{ConeSurface, CylinderSurface, SurfaceKind};
+
+        let brep = self.brep()?;
+        let segments = segments.max(3) as usize;
+        let mut polylines = Vec::with_capacity(brep.topology.edges.len());
+
+        for edge in brep.topology.edges.values() {
+            let he = &brep.topology.half_edges[edge.half_edge];
+            let p0 = brep.topology.vertices[he.origin].point;
+            let Some(twin) = he.twin else {
+                continue;
+            };
+            let p1 = brep.topology.vertices[brep.topology.half_edges[twin].origin].point;
+
+            // Axis and origin of an adjacent curved surface, if any — the
+            // same classification edge_length uses
+            let face_surface = |he_id| -> Option<&dyn vcad_kernel_geom::Surface> {
+                let l = brep.topology.half_edges[he_id].loop_id?;
+                let f = brep.topology.loops[l].face?;
+                Some(brep.geometry.surfaces[brep.topology.faces[f].surface_index].as_ref())
+            };
+            let surfaces = [face_surface(edge.half_edge), face_surface(twin)];
+            let curved = surfaces
+                .iter()
+                .flatten()
+                .find_map(|s| match s.surface_type() {
+                    SurfaceKind::Cylinder => {
+                        let cyl = s.as_any().downcast_ref::<CylinderSurface>()?;
+                        Some((cyl.center, *cyl.axis.as_ref()))
+                    }
+                    SurfaceKind::Cone => {
+                        let cone = s.as_any().downcast_ref::<ConeSurface>()?;
+                        Some((cone.apex, *cone.axis.as_ref()))
+                    }
+                    _ => None,
+                });
+
+            let closed = (p1 - p0).norm() < 1e-12;
+            let polyline = match curved {
+                Some((origin, axis)) => {
+                    let d0 = p0 - origin;
+                    let h0 = d0.dot(&axis);
+                    let radial0 = d0 - h0 * axis;
+                    let r0 = radial0.norm();
+                    let d1 = p1 - origin;
+                    let h1 = d1.dot(&axis);
+                    let r1 = (d1 - h1 * axis).norm();
+
+                    if closed && r0 > 1e-12 {
+                        // Full circle (e.g. a cylinder cap rim)
+                        let c = origin + h0 * axis;
+                        let u = radial0 / r0;
+                        let v = axis.cross(&u);
+                        (0..=segments)
+                            .map(|i| {
+                                let t = 2.0 * PI * i as f64 / segments as f64;
+                                c + r0 * (t.cos() * u + t.sin() * v)
+                            })
+                            .collect()
+                    } else if !closed
+                        && (r0 - r1).abs() < 1e-9
+                        && (h0 - h1).abs() < 1e-9
+                        && r0 > 1e-12
+                    {
+                        // Minor arc on a circle of radius r0
+                        let c = origin + h0 * axis;
+                        let u = radial0 / r0;
+                        let v = axis.cross(&u);
+                        let end = (d1 - h1 * axis) / r1;
+                        let angle = end.dot(&v).atan2(end.dot(&u));
+                        let n =
+                            ((segments as f64 * angle.abs() / (2.0 * PI)).ceil() as usize).max(2);
+                        (0..=n)
+                            .map(|i| {
+                                let t = angle * i as f64 / n as f64;
+                                c + r0 * (t.cos() * u + t.sin() * v)
+                            })
+                            .collect()
+                    } else {
+                        vec![p0, p1]
+                    }
+                }
+                None => vec![p0, p1],
+            };
+            polylines.push(polyline);
+        }
+
+        Some(polylines)
+    }
+
+    /// Check the radial fit of this solid (the shaft) inside a hole in
+    /// `hole`.
+    ///
+    /// Pairs the shaft's outward-facing cylindrical faces with the hole
+    /// solid's inward-facing ones (a bore left by a boolean difference),
+    /// keeping only near-parallel axes with overlapping axial extents, and
+    /// reports the tightest pair. Positive clearance means the shaft slips
+    /// in; negative means interference. Returns `None` when either solid
+    /// has no B-rep or no such face pair exists.
+    pub fn assembly_fit(&self, hole: &Solid) -> Option<AssemblyFit> {
+        use vcad_kernel_geom::{CylinderSurface, SurfaceKind};
+        use vcad_kernel_topo::Orientation;
+
+        // Cylindrical faces of the given sense: (center, axis, radius,
+        // extent along the axis)
+        let cylinder_faces = |brep: &BRepSolid, sense: Orientation| {
+            let mut out: Vec<(Point3, Vec3, f64, (f64, f64))> = Vec::new();
+            for face in brep.topology.faces.values() {
+                let surface = &brep.geometry.surfaces[face.surface_index];
+                if surface.surface_type() != SurfaceKind::Cylinder || face.orientation != sense {
+                    continue;
+                }
+                let Some(cyl) = surface.as_any().downcast_ref::<CylinderSurface>() else {
+                    continue;
+                };
+                let axis = *cyl.axis.as_ref();
+                let mut v_min = f64::MAX;
+                let mut v_max = f64::MIN;
+                for loop_id in
+                    std::iter::once(face.outer_loop).chain(face.inner_loops.iter().copied())
+                {
+                    for &v in &brep.topology.loop_vertices(loop_id) {
+                        let h = (brep.topology.vertices[v].point - cyl.center).dot(&axis);
+                        v_min = v_min.min(h);
+                        v_max = v_max.max(h);
+                    }
+                }
+                if v_min <= v_max {
+                    out.push((cyl.center, axis, cyl.radius, (v_min, v_max)));
+                }
+            }
+            out
+        };
+
+        let shafts = cylinder_faces(self.brep()?, Orientation::Forward);
+        let holes = cylinder_faces(hole.brep()?, Orientation::Reversed);
+
+        let mut best: Option<AssemblyFit> = None;
+        for &(s_center, s_axis, s_radius, s_extent) in &shafts {
+            for &(h_center, h_axis, h_radius, h_extent) in &holes {
+                if s_axis.cross(&h_axis).norm() > 1e-6 {
+                    continue;
+                }
+                // Axial extents measured along the hole axis must overlap
+                let shift = (s_center - h_center).dot(&h_axis);
+                let sign = s_axis.dot(&h_axis).signum();
+                let (s_lo, s_hi) = if sign > 0.0 {
+                    (s_extent.0 + shift, s_extent.1 + shift)
+                } else {
+                    (-s_extent.1 + shift, -s_extent.0 + shift)
+                };
+                if s_hi < h_extent.0 || s_lo > h_extent.1 {
+                    continue;
+                }
+                let d = s_center - h_center;
+                let offset = (d - d.dot(&h_axis) * h_axis).norm();
+                let clearance = h_radius - s_radius - offset;
+                if best.is_none_or(|b| clearance < b.clearance) {
+                    best = Some(AssemblyFit {
+                        shaft_radius: s_radius,
+                        hole_radius: h_radius,
+                        clearance,
+                    });
+                }
+            }
+        }
+        best
+    }
+
+    /// Find a good orientation for 3D printing and return the reoriented
+    /// solid resting on the z = 0 bed.
+    ///
+    /// Candidate "flat down" directions are the six axis-aligned directions
+    /// plus the dominant area-weighted normal directions of the tessellated
+    /// mesh (which, for flat-faced parts, are the convex-hull facet normals).
+    /// Each candidate is scored by the strategy — projected overhang area
+    /// (faces steeper than 45° overhang, excluding those on the bed) or
+    /// build height — and the best one wins. Returns `None` for empty solids.
+    pub fn auto_orient_for_print(&self, strategy: PrintOrientStrategy) -> Option<PrintOrientation> {
+        use std::collections::HashMap;
+        use std::f64::consts::PI;
+
+        let mesh = self.to_mesh(self.segments);
+        if mesh.indices.is_empty() {
+            return None;
+        }
+        let points: Vec<Point3> = mesh
+            .vertices
+            .chunks(3)
+            .map(|c| Point3::new(c[0] as f64, c[1] as f64, c[2] as f64))
+            .collect();
+
+        // Candidate down directions: the six axes plus the largest
+        // area-weighted distinct triangle normal directions.
+        let mut candidates: Vec<Vec3> = vec![
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, -1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        let mut normal_areas: HashMap<(i64, i64, i64), (Vec3, f64)> = HashMap::new();
+        for tri in mesh.indices.chunks(3) {
+            let (a, b, c) = (
+                points[tri[0] as usize],
+                points[tri[1] as usize],
+                points[tri[2] as usize],
+            );
+            let n = (b - a).cross(&(c - a));
+            let twice_area = n.norm();
+            if twice_area < 1e-12 {
+                continue;
+            }
+            let dir = n / twice_area;
+            let key = (
+                (dir.x * 1000.0).round() as i64,
+                (dir.y * 1000.0).round() as i64,
+                (dir.z * 1000.0).round() as i64,
+            );
+            let entry = normal_areas.entry(key).or_insert((dir, 0.0));
+            entry.1 += 0.5 * twice_area;
+        }
+        let mut by_area: Vec<(Vec3, f64)> = normal_areas.into_values().collect();
+        by_area.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        // The part lies on the face whose normal points down, so candidate
+        // down directions are the face normals themselves.
+        candidates.extend(by_area.iter().take(12).map(|(n, _)| *n));
+
+        let down_z = Vec3::new(0.0, 0.0, -1.0);
+        let mut best: Option<(f64, Vec3, Transform)> = None;
+        for &down in &candidates {
+            // Rotation taking the candidate down direction to -Z
+            let dot = down.dot(&down_z).clamp(-1.0, 1.0);
+            let transform = if dot > 1.0 - 1e-12 {
+                Transform::identity()
+            } else if dot < -1.0 + 1e-12 {
+                Transform::rotation_x(PI)
+            } else {
+                let axis = vcad_kernel_math::Dir3::new_normalize(down.cross(&down_z));
+                Transform::rotation_about_axis(&axis, dot.acos())
+            };
+
+            let rotated: Vec<Point3> = points.iter().map(|p| transform.apply_point(p)).collect();
+            let z_min = rotated.iter().map(|p| p.z).fold(f64::MAX, f64::min);
+            let z_max = rotated.iter().map(|p| p.z).fold(f64::MIN, f64::max);
+
+            let score = match strategy {
+                PrintOrientStrategy::MinHeight => z_max - z_min,
+                PrintOrientStrategy::MinOverhang => {
+                    let mut overhang = 0.0;
+                    for tri in mesh.indices.chunks(3) {
+                        let (a, b, c) = (
+                            rotated[tri[0] as usize],
+                            rotated[tri[1] as usize],
+                            rotated[tri[2] as usize],
+                        );
+                        let n = (b - a).cross(&(c - a));
+                        let twice_area = n.norm();
+                        if twice_area < 1e-12 {
+                            continue;
+                        }
+                        let nz = n.z / twice_area;
+                        let lowest = a.z.min(b.z).min(c.z);
+                        // Steeper than 45° downward, and not resting on the bed
+                        if nz < -(0.5f64.sqrt()) && lowest - z_min > 1e-6 {
+                            overhang += 0.5 * twice_area * nz.abs();
+                        }
+                    }
+                    overhang
+                }
+            };
+
+            let better = match &best {
+                None => true,
+                Some((best_score, _, _)) => score < best_score - 1e-12,
+            };
+            if better {
+                best = Some((score, down, transform));
+            }
+        }
+
+        let (score, down, transform) = best?;
+        let rotated = self.apply_transform(&transform);
+        let (min, _) = rotated.bounding_box();
+        Some(PrintOrientation {
+            solid: rotated.translate(0.0, 0.0, -min[2]),
+            down: [down.x, down.y, down.z],
+            score,
+        })
+    }
+
+    /// Measure the worst chord-height deviation of the tessellation from the
+    /// exact B-rep surfaces at the given segment count.
+    ///
+    /// Returns `None` for mesh-only or empty solids (there is no exact
+    /// surface to compare against). See
+    /// [`vcad_kernel_tessellate::tessellation_deviation`].
+    pub fn tessellation_error(
+        &self,
+        segments: u32,
+    ) -> Option<vcad_kernel_tessellate::DeviationReport> {
+        match &self.repr {
+            SolidRepr::BRep(brep) => Some(vcad_kernel_tessellate::tessellation_deviation(
+                brep.as_ref(),
+                segments,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Compute the volume of the solid from its triangle mesh.
+    pub fn volume(&self) -> f64 {
+        let mesh = self.to_mesh(self.segments);
+        compute_volume(&mesh)
+    }
+
+    /// Tag the whole solid with a material id, replacing any existing tags.
+    ///
+    /// The tagged region is remembered through booleans and transforms so
+    /// [`Solid::volume_by_material`] can report how much of each material
+    /// remains in the final part.
+    pub fn with_material_tag(&self, tag: &str) -> Solid {
+        let mut result = self.clone();
+        result.materials = vec![MaterialRegion {
+            tag: tag.to_string(),
+            solid: Box::new(Solid {
+                repr: self.repr.clone(),
+                segments: self.segments,
+                materials: Vec::new(),
+                face_colors: Vec::new(),
+                mesh_cache: RefCell::new(None),
+            }),
+        }];
+        result
+    }
+
+    /// Per-material volume breakdown as `(tag, volume)` pairs, in first-tag
+    /// order.
+    ///
+    /// Booleans applied after tagging are carried through to each region, so
+    /// material removed by a later difference is not counted. Regions that
+    /// overlap each other are counted once per tag. Empty when nothing was
+    /// tagged with [`Solid::with_material_tag`].
+    pub fn volume_by_material(&self) -> Vec<(String, f64)> {
+        let mut out: Vec<(String, f64)> = Vec::new();
+        for region in &self.materials {
+            let vol = region.solid.volume();
+            match out.iter_mut().find(|(tag, _)| tag == &region.tag) {
+                Some(entry) => entry.1 += vol,
+                None => out.push((region.tag.clone(), vol)),
+            }
+        }
+        out
+    }
+
+    /// Assign a display color to one face, replacing any previous color on
+    /// that face.
+    ///
+    /// `face_index` is the face's position in topology iteration order, as
+    /// used by [`Solid::face_area`]. Colors survive transforms but are
+    /// dropped by booleans, which rebuild the topology and its face indices.
+    /// Consumed by [`Solid::mesh_by_color`].
+    pub fn with_face_color(&self, face_index: usize, color: &str) -> Solid {
+        let mut result = self.clone();
+        match result
+            .face_colors
+            .iter_mut()
+            .find(|(index, _)| *index == face_index)
+        {
+            Some(entry) => entry.1 = color.to_string(),
+            None => result.face_colors.push((face_index, color.to_string())),
+        }
+        result
+    }
+
+    /// Tessellate into one mesh per face color, as `(color, mesh)` pairs.
+    ///
+    /// Faces without an assigned color are grouped under `"default"`, which
+    /// comes first; colored groups follow in face order. A viewer can assign
+    /// one material per group for multi-color rendering. Mesh-only solids
+    /// have no face topology, so the whole mesh lands in the default group.
+    pub fn mesh_by_color(&self, segments: u32) -> Vec<(String, TriangleMesh)> {
+        let SolidRepr::BRep(brep) = &self.repr else {
+            return vec![("default".to_string(), self.to_mesh(segments))];
+        };
+
+        let params = vcad_kernel_tessellate::TessellationParams::from_segments(segments);
+        let mut groups: Vec<(String, TriangleMesh)> =
+            vec![("default".to_string(), TriangleMesh::new())];
+        for (face_index, face_id) in brep.topology.faces.keys().enumerate() {
+            let color = self
+                .face_colors
+                .iter()
+                .find(|(index, _)| *index == face_index)
+                .map_or("default", |(_, color)| color.as_str());
+            let face_mesh = vcad_kernel_tessellate::tessellate_face(
+                &brep.topology,
+                &brep.geometry,
+                face_id,
+                &params,
+            );
+            match groups.iter_mut().find(|(c, _)| c == color) {
+                Some((_, mesh)) => mesh.merge(&face_mesh),
+                None => groups.push((color.to_string(), face_mesh)),
+            }
+        }
+        groups
+    }
+
+    /// Compute the surface area of the solid from its triangle mesh.
+    pub fn surface_area(&self) -> f64 {
+        let mesh = self.to_mesh(self.segments);
+        compute_surface_area(&mesh)
+    }
+
+    /// Compute the axis-aligned bounding box as `(min, max)`.
+    ///
+    /// For B-rep solids with only planar faces, computes directly from vertex
+    /// positions (no tessellation needed). For curved surfaces, falls back to
+    /// the tessellated mesh since vertices alone don't capture the full extent.
+    pub fn bounding_box(&self) -> ([f64; 3], [f64; 3]) {
+        match &self.repr {
+            SolidRepr::BRep(brep) => {
+                use vcad_kernel_geom::SurfaceKind;
+                let all_planar = brep
+                    .geometry
+                    .surfaces
+                    .iter()
+                    .all(|s| s.surface_type() == SurfaceKind::Plane);
+                if all_planar {
+                    let mut min = [f64::MAX; 3];
+                    let mut max = [f64::MIN; 3];
+                    for (_id, v) in &brep.topology.vertices {
+                        let p = v.point;
+                        min[0] = min[0].min(p.x);
+                        min[1] = min[1].min(p.y);
+                        min[2] = min[2].min(p.z);
+                        max[0] = max[0].max(p.x);
+                        max[1] = max[1].max(p.y);
+                        max[2] = max[2].max(p.z);
+                    }
+                    (min, max)
+                } else {
+                    let mesh = self.to_mesh(self.segments);
+                    compute_bounding_box(&mesh)
+                }
+            }
+            _ => {
+                let mesh = self.to_mesh(self.segments);
+                compute_bounding_box(&mesh)
+            }
+        }
+    }
+
+    /// Report the enclosed internal cavities (void shells) of the solid.
+    ///
+    /// Differencing a fully-enclosed tool leaves a void shell in the B-rep;
+    /// each one is returned with its enclosed volume and bounding box.
+    /// Useful for spotting trapped material or un-drainable pockets in
+    /// castings. Mesh-only and empty solids report no cavities.
+    pub fn cavities(&self) -> Vec<Cavity> {
+        let Some(brep) = self.brep() else {
+            return Vec::new();
+        };
+        let solid = &brep.topology.solids[brep.solid_id];
+        let mut cavities = Vec::new();
+        for &shell_id in &solid.void_shells {
+            // Tessellate just this shell by viewing it as the outer shell of
+            // a temporary solid sharing the same topology and geometry
+            let mut view = brep.clone();
+            view.solid_id = view.topology.add_solid(shell_id);
+            let mesh = vcad_kernel_tessellate::tessellate(&view, self.segments);
+            if mesh.num_triangles() == 0 {
+                continue;
+            }
+            let (min, max) = compute_bounding_box(&mesh);
+            cavities.push(Cavity {
+                volume: compute_volume(&mesh),
+                min,
+                max,
+            });
+        }
+        cavities
+    }
+
+    /// Run the aggregate pre-print check for a build volume of `bed` mm.
+    ///
+    /// Combines bed fit, overhang detection, thin-wall probing, trapped
+    /// cavities, and a rough support-volume estimate into one
+    /// [`PrintabilityReport`] with a pass/warn/fail status. The part is
+    /// checked in its current orientation.
+    pub fn printability_report(
+        &self,
+        bed: [f64; 3],
+        settings: &PrintabilitySettings,
+    ) -> PrintabilityReport {
+        printability::printability_report(
+            &self.to_mesh(self.segments),
+            &self.cavities(),
+            bed,
+            settings,
+        )
+    }
+
+    /// Measure the angle between two planar faces, in degrees.
+    ///
+    /// Returns the angle between the faces' outward normals: adjacent cube
+    /// faces give 90°, opposite ones 180°. When the faces share an edge the
+    /// sign distinguishes convex from concave — an inside corner returns the
+    /// angle negated. `face_a` and `face_b` are positions in topology
+    /// iteration order (as for [`Solid::sketch_plane_from_face`]). Returns
+    /// `None` for mesh-only solids, out-of-range indices, or non-planar
+    /// faces.
+    pub fn angle_between_faces(&self, face_a: usize, face_b: usize) -> Option<f64> {
+        use vcad_kernel_geom::{Plane, SurfaceKind};
+        use vcad_kernel_topo::Orientation;
+
+        let brep = self.brep()?;
+        let topo = &brep.topology;
+        let (id_a, fa) = topo.faces.iter().nth(face_a)?;
+        let (id_b, fb) = topo.faces.iter().nth(face_b)?;
+
+        let outward_normal = |face: &vcad_kernel_topo::Face| -> Option<Vec3> {
+            let surface = &brep.geometry.surfaces[face.surface_index];
+            if surface.surface_type() != SurfaceKind::Plane {
+                return None;
+            }
+            let n: Vec3 = *surface
+                .as_any()
+                .downcast_ref::<Plane>()?
+                .normal_dir
+                .as_ref();
+            Some(match face.orientation {
+                Orientation::Forward => n,
+                Orientation::Reversed => -n,
+            })
+        };
+        let n_a = outward_normal(fa)?;
+        let n_b = outward_normal(fb)?;
+        let angle = n_a.dot(&n_b).clamp(-1.0, 1.0).acos().to_degrees();
+
+        // If the faces share an edge, use its direction in face A's loop to
+        // tell convex (outside) from concave (inside) corners
+        let mut loops = vec![fa.outer_loop];
+        loops.extend(&fa.inner_loops);
+        for loop_id in loops {
+            for he in topo.loop_half_edges(loop_id) {
+                let Some(twin) = topo.half_edges[he].twin else {
+                    continue;
+                };
+                let twin_face = topo.half_edges[twin]
+                    .loop_id
+                    .and_then(|l| topo.loops[l].face);
+                if twin_face != Some(id_b) || id_a == id_b {
+                    continue;
+                }
+                let origin = topo.vertices[topo.half_edges[he].origin].point;
+                let dest = topo.vertices[topo.half_edge_dest(he)].point;
+                if n_a.cross(&n_b).dot(&(dest - origin)) < 0.0 {
+                    return Some(-angle);
+                }
+                return Some(angle);
+            }
+        }
+        Some(angle)
+    }
+
+    /// Compute the geometric centroid (volume-weighted center of mass).
+    pub fn center_of_mass(&self) -> [f64; 3] {
+        let mesh = self.to_mesh(self.segments);
+        compute_center_of_mass(&mesh)
     }
 
     /// Number of triangles in the tessellated mesh.
@@ -670,6 +2506,104 @@ impl Solid {
         mesh.num_triangles()
     }
 
+    /// Derive a sketch plane from a face of this solid.
+    ///
+    /// Returns the frame (origin, x/y directions, normal) of the face's
+    /// surface at the centroid of its outer loop, so a 2D sketch can be
+    /// placed directly "on that face." For non-planar faces this is the
+    /// tangent plane at the centroid.
+    ///
+    /// `face_index` is the face's position in topology iteration order (the
+    /// same order used by tessellation and ray-trace picking). Returns `None`
+    /// if the solid has no B-rep or the index is out of range.
+    pub fn sketch_plane_from_face(&self, face_index: usize) -> Option<SketchPlane> {
+        use vcad_kernel_geom::{Plane, SurfaceKind};
+        use vcad_kernel_math::Point2;
+
+        let brep = self.brep()?;
+        let face = brep.topology.faces.values().nth(face_index)?;
+        let surface = &brep.geometry.surfaces[face.surface_index];
+
+        // Centroid of the face's outer loop vertices
+        let verts = brep.topology.loop_vertices(face.outer_loop);
+        if verts.is_empty() {
+            return None;
+        }
+        let mut centroid = Vec3::zeros();
+        for &v in &verts {
+            centroid += brep.topology.vertices[v].point.coords;
+        }
+        centroid /= verts.len() as f64;
+        let centroid = Point3::from(centroid);
+
+        // Find the surface parameter at the centroid. Planes project exactly;
+        // other surfaces use a coarse grid search refined around the minimum.
+        let uv = if surface.surface_type() == SurfaceKind::Plane {
+            let plane = surface.as_any().downcast_ref::<Plane>()?;
+            plane.project(&centroid)
+        } else {
+            let ((u_min, u_max), (v_min, v_max)) = surface.domain();
+            let mut best = Point2::new(u_min, v_min);
+            let mut best_dist = f64::MAX;
+            let (mut u_lo, mut u_hi, mut v_lo, mut v_hi) = (u_min, u_max, v_min, v_max);
+            for _ in 0..4 {
+                let n = 16;
+                for i in 0..=n {
+                    for j in 0..=n {
+                        let uv = Point2::new(
+                            u_lo + (u_hi - u_lo) * i as f64 / n as f64,
+                            v_lo + (v_hi - v_lo) * j as f64 / n as f64,
+                        );
+                        let d = (surface.evaluate(uv) - centroid).norm_squared();
+                        if d < best_dist {
+                            best_dist = d;
+                            best = uv;
+                        }
+                    }
+                }
+                let du = (u_hi - u_lo) / n as f64;
+                let dv = (v_hi - v_lo) / n as f64;
+                u_lo = (best.x - du).max(u_min);
+                u_hi = (best.x + du).min(u_max);
+                v_lo = (best.y - dv).max(v_min);
+                v_hi = (best.y + dv).min(v_max);
+            }
+            best
+        };
+
+        let origin = surface.evaluate(uv);
+        let mut normal = *surface.normal(uv).as_ref();
+        if face.orientation == vcad_kernel_topo::Orientation::Reversed {
+            normal = -normal;
+        }
+
+        // X along the surface's u direction (projected into the tangent
+        // plane), Y completing a right-handed frame with the face normal
+        let du = surface.d_du(uv);
+        let x_dir = {
+            let t = du - du.dot(&normal) * normal;
+            if t.norm() > 1e-9 {
+                t.normalize()
+            } else {
+                // u direction parallel to normal - pick an arbitrary tangent
+                let arbitrary = if normal.x.abs() < 0.9 {
+                    Vec3::x()
+                } else {
+                    Vec3::y()
+                };
+                arbitrary.cross(&normal).normalize()
+            }
+        };
+        let y_dir = normal.cross(&x_dir);
+
+        Some(SketchPlane {
+            origin,
+            x_dir,
+            y_dir,
+            normal,
+        })
+    }
+
     // =========================================================================
     // STEP import/export
     // =========================================================================
@@ -693,6 +2627,9 @@ impl Solid {
         Ok(Self {
             repr: SolidRepr::BRep(Box::new(brep)),
             segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
         })
     }
 
@@ -716,6 +2653,9 @@ impl Solid {
             .map(|brep| Self {
                 repr: SolidRepr::BRep(Box::new(brep)),
                 segments: 32,
+                materials: Vec::new(),
+                face_colors: Vec::new(),
+                mesh_cache: RefCell::new(None),
             })
             .collect())
     }
@@ -735,6 +2675,9 @@ impl Solid {
         Ok(Self {
             repr: SolidRepr::BRep(Box::new(brep)),
             segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
         })
     }
 
@@ -758,6 +2701,9 @@ impl Solid {
             .map(|brep| Self {
                 repr: SolidRepr::BRep(Box::new(brep)),
                 segments: 32,
+                materials: Vec::new(),
+                face_colors: Vec::new(),
+                mesh_cache: RefCell::new(None),
             })
             .collect())
     }
@@ -804,6 +2750,60 @@ impl Solid {
         }
     }
 
+    /// Export to STEP in memory, validating the boundary first.
+    ///
+    /// Checks the tessellated boundary for open and non-manifold edges
+    /// before writing, so broken solids are caught here instead of failing
+    /// to import in other CAD tools. With `heal: false`, problems are
+    /// returned as [`StepExportError::Invalid`] with one message per issue.
+    /// With `heal: true`, open shells are first closed by capping planar
+    /// boundary loops; if problems remain after healing, the error is
+    /// returned instead.
+    pub fn to_step_buffer_validated(&self, heal: bool) -> Result<Vec<u8>, StepExportError> {
+        let brep = match &self.repr {
+            SolidRepr::BRep(brep) => brep.as_ref(),
+            SolidRepr::Mesh(_) => return Err(StepExportError::NotBRep),
+            SolidRepr::Empty => return Err(StepExportError::Empty),
+        };
+
+        let problems = |solid: &Solid| -> Vec<String> {
+            let mesh = solid.to_mesh(solid.segments);
+            let mut out = Vec::new();
+            if !mesh.is_closed() {
+                out.push("boundary is not closed (open edges)".to_string());
+            }
+            if !mesh.is_manifold() {
+                out.push(
+                    "boundary is non-manifold (edge shared by more than two triangles)".to_string(),
+                );
+            }
+            out
+        };
+
+        if problems(self).is_empty() {
+            return self.to_step_buffer();
+        }
+
+        if !heal {
+            return Err(StepExportError::Invalid(problems(self)));
+        }
+
+        let mut healed = brep.clone();
+        close_open_shells(&mut healed);
+        let healed = Solid {
+            repr: SolidRepr::BRep(Box::new(healed)),
+            segments: self.segments,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        };
+        let remaining = problems(&healed);
+        if !remaining.is_empty() {
+            return Err(StepExportError::Invalid(remaining));
+        }
+        healed.to_step_buffer()
+    }
+
     /// Check if this solid can be exported to STEP format.
     ///
     /// Returns `true` if the solid has B-rep data (not converted to mesh-only).
@@ -833,6 +2833,121 @@ impl Solid {
     }
 }
 
+/// Close open shells by capping planar boundary loops with new faces.
+///
+/// Boundary half-edges (those without a live twin) are chained into loops;
+/// each loop whose vertices are coplanar gets a planar cap face added to the
+/// adjacent shell. Non-planar boundary loops are left open.
+fn close_open_shells(brep: &mut BRepSolid) {
+    use std::collections::HashMap;
+    use vcad_kernel_geom::Plane;
+    use vcad_kernel_topo::{HalfEdgeId, Orientation, VertexId};
+
+    let topo = &brep.topology;
+
+    // Boundary half-edges: on a face loop, but with no twin still in the arena
+    let boundary: Vec<HalfEdgeId> = topo
+        .half_edges
+        .iter()
+        .filter(|(_, he)| {
+            he.loop_id.is_some()
+                && he.next.is_some()
+                && he.twin.is_none_or(|t| !topo.half_edges.contains_key(t))
+        })
+        .map(|(id, _)| id)
+        .collect();
+    if boundary.is_empty() {
+        return;
+    }
+
+    // One reversed cap half-edge per boundary half-edge, indexed by origin
+    let mut cap_by_origin: HashMap<VertexId, (HalfEdgeId, HalfEdgeId)> = HashMap::new();
+    let mut caps: Vec<HalfEdgeId> = Vec::new();
+    for &b in &boundary {
+        let next = brep.topology.half_edges[b].next.unwrap();
+        let v = brep.topology.half_edges[next].origin;
+        let cap = brep.topology.add_half_edge(v);
+        brep.topology.add_edge(b, cap);
+        cap_by_origin.insert(v, (cap, b));
+        caps.push(cap);
+    }
+
+    // Chain caps into loops: the successor of a cap ending at `u` is the
+    // cap whose origin is `u`
+    let mut visited: std::collections::HashSet<HalfEdgeId> = std::collections::HashSet::new();
+    for &start in &caps {
+        if visited.contains(&start) {
+            continue;
+        }
+        let mut ring = Vec::new();
+        let mut current = start;
+        loop {
+            if !visited.insert(current) {
+                break;
+            }
+            ring.push(current);
+            // End vertex of `current` = origin of its boundary twin
+            let twin = brep.topology.half_edges[current].twin.unwrap();
+            let end = brep.topology.half_edges[twin].origin;
+            match cap_by_origin.get(&end) {
+                Some(&(next_cap, _)) if next_cap == start => break,
+                Some(&(next_cap, _)) => current = next_cap,
+                None => {
+                    ring.clear();
+                    break;
+                }
+            }
+        }
+        if ring.len() < 3 {
+            continue;
+        }
+
+        // The ring must be planar to cap with a plane
+        let points: Vec<Point3> = ring
+            .iter()
+            .map(|&he| brep.topology.vertices[brep.topology.half_edges[he].origin].point)
+            .collect();
+        let centroid =
+            Point3::from(points.iter().map(|p| p.coords).sum::<Vec3>() / points.len() as f64);
+        // Newell's method for the loop winding normal
+        let mut normal = Vec3::zeros();
+        for i in 0..points.len() {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            normal += (a - centroid).cross(&(b - centroid));
+        }
+        if normal.norm() < 1e-12 {
+            continue;
+        }
+        let unit = normal.normalize();
+        let planar = points
+            .iter()
+            .all(|p| (p - centroid).dot(&unit).abs() < 1e-6);
+        if !planar {
+            continue;
+        }
+
+        // Cap face: plane normal along the winding normal, orientation forward
+        let plane = Plane::from_normal(centroid, unit);
+        let surface_index = brep.geometry.add_surface(Box::new(plane));
+        let loop_id = brep.topology.add_loop(&ring);
+        let face_id = brep
+            .topology
+            .add_face(loop_id, surface_index, Orientation::Forward);
+
+        // Attach to the shell of an adjacent face
+        let twin = brep.topology.half_edges[ring[0]].twin.unwrap();
+        let shell = brep.topology.half_edges[twin]
+            .loop_id
+            .and_then(|l| brep.topology.loops[l].face)
+            .and_then(|f| brep.topology.faces[f].shell);
+        if let Some(shell_id) = shell {
+            brep.topology.shells[shell_id].faces.push(face_id);
+            brep.topology.faces[face_id].shell = Some(shell_id);
+        }
+    }
+}
+
 // =============================================================================
 // Operator overloads for ergonomic boolean operations
 // =============================================================================
@@ -980,80 +3095,496 @@ fn compute_center_of_mass(mesh: &TriangleMesh) -> [f64; 3] {
     [cx * s, cy * s, cz * s]
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        /// Counts actual tessellations in [`Solid::to_mesh`]; thread-local so
+        /// parallel tests don't see each other's calls.
+        pub(crate) static TESSELLATION_COUNT: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    #[test]
+    fn test_to_mesh_caches_tessellation() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let before = TESSELLATION_COUNT.with(|c| c.get());
+
+        let first = cube.to_mesh(32);
+        let second = cube.to_mesh(32);
+        assert_eq!(TESSELLATION_COUNT.with(|c| c.get()) - before, 1);
+        assert_eq!(first.vertices, second.vertices);
+        assert_eq!(first.indices, second.indices);
+
+        // A different segment count misses the cache
+        let _ = cube.to_mesh(16);
+        assert_eq!(TESSELLATION_COUNT.with(|c| c.get()) - before, 2);
+
+        // Mutating operations return a new solid with a fresh cache
+        let moved = cube.translate(1.0, 0.0, 0.0);
+        let _ = moved.to_mesh(16);
+        assert_eq!(TESSELLATION_COUNT.with(|c| c.get()) - before, 3);
+    }
+
+    #[test]
+    fn test_cube() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        assert!(!cube.is_empty());
+        let mesh = cube.to_mesh(32);
+        assert!(mesh.num_triangles() >= 12);
+    }
+
+    #[test]
+    fn test_cylinder() {
+        let cyl = Solid::cylinder(5.0, 10.0, 32).unwrap();
+        assert!(!cyl.is_empty());
+    }
+
+    #[test]
+    fn test_sphere() {
+        let sphere = Solid::sphere(10.0, 32).unwrap();
+        assert!(!sphere.is_empty());
+    }
+
+    #[test]
+    fn test_cone() {
+        let cone = Solid::cone(5.0, 3.0, 10.0, 32).unwrap();
+        assert!(!cone.is_empty());
+    }
+
+    #[test]
+    fn test_manifold_and_closed_queries() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        assert!(cube.is_manifold());
+        assert!(cube.is_closed());
+
+        // An open box (cube minus its top face) is manifold but not closed
+        let full = cube.to_mesh(32);
+        let mut open = TriangleMesh::new();
+        for tri in full.indices.chunks(3) {
+            let top = tri
+                .iter()
+                .all(|&i| (full.vertices[i as usize * 3 + 2] - 10.0).abs() < 1e-6);
+            if top {
+                continue;
+            }
+            let base = open.num_vertices() as u32;
+            for &i in tri {
+                let v = i as usize * 3;
+                open.vertices.extend_from_slice(&full.vertices[v..v + 3]);
+            }
+            open.indices.extend_from_slice(&[base, base + 1, base + 2]);
+        }
+        let open_solid = Solid::from_mesh(open);
+        assert!(open_solid.is_manifold());
+        assert!(!open_solid.is_closed());
+
+        assert!(Solid::empty().is_manifold());
+        assert!(Solid::empty().is_closed());
+    }
+
+    #[test]
+    fn test_degenerate_primitives_rejected() {
+        assert!(Solid::cylinder(0.0, 10.0, 32).is_err());
+        assert!(Solid::cylinder(5.0, 10.0, 2).is_err());
+        assert!(Solid::cube(-1.0, 5.0, 5.0).is_err());
+        assert!(Solid::cube(f64::NAN, 5.0, 5.0).is_err());
+        assert!(Solid::sphere(0.0, 32).is_err());
+        assert!(Solid::cone(0.0, 0.0, 10.0, 32).is_err());
+        assert!(Solid::cone(5.0, 0.0, -1.0, 32).is_err());
+
+        // A pointed cone (one zero radius) is valid
+        assert!(Solid::cone(5.0, 0.0, 10.0, 32).is_ok());
+
+        let err = Solid::cube(0.0, 5.0, 5.0).unwrap_err();
+        assert_eq!(
+            err,
+            PrimitiveError::InvalidDimension {
+                name: "cube sx",
+                value: 0.0
+            }
+        );
+        assert!(err.to_string().contains("must be positive"));
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty = Solid::empty();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_translate() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let moved = cube.translate(100.0, 0.0, 0.0);
+        let (min, max) = moved.bounding_box();
+        assert!((min[0] - 100.0).abs() < 0.1);
+        assert!((max[0] - 110.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_scale() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let scaled = cube.scale(2.0, 1.0, 1.0);
+        let (min, max) = scaled.bounding_box();
+        assert!((max[0] - min[0] - 20.0).abs() < 0.1);
+        assert!((max[1] - min[1] - 10.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_union() {
+        let a = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let b = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let result = a.union(&b);
+        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let b = Solid::cube(5.0, 5.0, 5.0).unwrap();
+        let result = a.difference(&b);
+        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn test_fix_normals_for_rendering_on_difference() {
+        let plate = Solid::cube(40.0, 40.0, 10.0).unwrap();
+        let hole = Solid::cylinder(5.0, 30.0, 32)
+            .unwrap()
+            .translate(20.0, 20.0, -10.0);
+        let result = plate.difference(&hole);
+
+        let signed_volume = |mesh: &TriangleMesh| -> f64 {
+            mesh.indices
+                .chunks(3)
+                .map(|tri| {
+                    let corner = |idx: u32| -> Vec3 {
+                        let i = idx as usize * 3;
+                        Vec3::new(
+                            mesh.vertices[i] as f64,
+                            mesh.vertices[i + 1] as f64,
+                            mesh.vertices[i + 2] as f64,
+                        )
+                    };
+                    let (v0, v1, v2) = (corner(tri[0]), corner(tri[1]), corner(tri[2]));
+                    v0.dot(&v1.cross(&v2)) / 6.0
+                })
+                .sum()
+        };
+
+        // Sabotage the winding: an inside-out copy of the difference result,
+        // as a face with inverted orientation would produce
+        let mut mesh = result.to_mesh(32);
+        for t in 0..mesh.num_triangles() {
+            mesh.indices.swap(t * 3 + 1, t * 3 + 2);
+        }
+        assert!(signed_volume(&mesh) < 0.0);
+
+        let fixed = Solid::from_mesh(mesh).fix_normals_for_rendering();
+        let fixed_mesh = fixed.to_mesh(32);
+
+        let volume = signed_volume(&fixed_mesh);
+        let expected = 40.0 * 40.0 * 10.0 - std::f64::consts::PI * 25.0 * 10.0;
+        assert!(
+            volume > 0.0 && (volume - expected).abs() / expected < 0.05,
+            "expected positive volume near {expected}, got {volume}"
+        );
+        // Every shared edge pairs with opposite orientations, so the faces'
+        // triangles all agree
+        assert!(fixed_mesh.is_manifold());
+    }
+
+    #[test]
+    fn test_angle_between_faces_on_cube() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+
+        // Every face of a cube meets four others at a convex 90 degrees and
+        // faces the sixth at 180
+        for a in 0..6 {
+            let mut right_angles = 0;
+            let mut opposite = 0;
+            for b in 0..6 {
+                if a == b {
+                    continue;
+                }
+                let angle = cube.angle_between_faces(a, b).unwrap();
+                if (angle - 90.0).abs() < 1e-9 {
+                    right_angles += 1;
+                } else if (angle - 180.0).abs() < 1e-9 {
+                    opposite += 1;
+                }
+            }
+            assert_eq!(right_angles, 4, "face {a}");
+            assert_eq!(opposite, 1, "face {a}");
+        }
+
+        assert!(cube.angle_between_faces(0, 6).is_none());
+        assert!(Solid::from_mesh(cube.to_mesh(32))
+            .angle_between_faces(0, 1)
+            .is_none());
+    }
+
+    #[test]
+    fn test_apply_matrix_matches_rotate_then_translate() {
+        let cube = Solid::cube(10.0, 4.0, 2.0).unwrap();
+
+        // Z-rotation by 90 degrees followed by a translation, as one
+        // row-major matrix
+        #[rustfmt::skip]
+        let m = [
+            0.0, -1.0, 0.0, 5.0,
+            1.0,  0.0, 0.0, -3.0,
+            0.0,  0.0, 1.0, 2.0,
+            0.0,  0.0, 0.0, 1.0,
+        ];
+        let via_matrix = cube.apply_matrix(&m);
+        let via_calls = cube.rotate(0.0, 0.0, 90.0).translate(5.0, -3.0, 2.0);
+
+        let (min_a, max_a) = via_matrix.bounding_box();
+        let (min_b, max_b) = via_calls.bounding_box();
+        for i in 0..3 {
+            assert!((min_a[i] - min_b[i]).abs() < 1e-9, "min axis {i}");
+            assert!((max_a[i] - max_b[i]).abs() < 1e-9, "max axis {i}");
+        }
+
+        let com_a = via_matrix.center_of_mass();
+        let com_b = via_calls.center_of_mass();
+        for i in 0..3 {
+            assert!((com_a[i] - com_b[i]).abs() < 1e-9, "com axis {i}");
+        }
+        assert!((via_matrix.volume() - 80.0).abs() < 1e-6);
+    }
 
     #[test]
-    fn test_cube() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
-        assert!(!cube.is_empty());
-        let mesh = cube.to_mesh(32);
-        assert!(mesh.num_triangles() >= 12);
+    fn test_rotate_about_axis_keeps_center_fixed() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let com = cube.center_of_mass();
+
+        // Rotating about the cube's own center axis leaves the center of
+        // mass in place...
+        let in_place = cube.rotate_about_axis(5.0, 5.0, 5.0, 0.0, 0.0, 1.0, 90.0);
+        let rotated_com = in_place.center_of_mass();
+        for i in 0..3 {
+            assert!(
+                (rotated_com[i] - com[i]).abs() < 1e-6,
+                "center moved: {rotated_com:?} vs {com:?}"
+            );
+        }
+        assert!((in_place.volume() - 1000.0).abs() < 1e-6);
+
+        // ...unlike rotating about the world Z axis through the origin
+        let about_origin = cube.rotate(0.0, 0.0, 90.0);
+        let origin_com = about_origin.center_of_mass();
+        assert!((origin_com[0] - com[0]).abs() > 1.0);
     }
 
     #[test]
-    fn test_cylinder() {
-        let cyl = Solid::cylinder(5.0, 10.0, 32);
-        assert!(!cyl.is_empty());
+    fn test_scale_about_keeps_center_fixed() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let scaled = cube.scale_about(5.0, 5.0, 5.0, 2.0, 2.0, 2.0);
+        let com = scaled.center_of_mass();
+        for (axis, &c) in com.iter().enumerate() {
+            assert!((c - 5.0).abs() < 1e-6, "axis {axis}: center at {c}");
+        }
+        let (min, max) = scaled.bounding_box();
+        assert!((min[0] + 5.0).abs() < 1e-6 && (max[0] - 15.0).abs() < 1e-6);
     }
 
     #[test]
-    fn test_sphere() {
-        let sphere = Solid::sphere(10.0, 32);
-        assert!(!sphere.is_empty());
+    fn test_is_degenerate() {
+        // Intersection of disjoint solids has no volume
+        let a = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let b = Solid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(50.0, 0.0, 0.0);
+        assert!(a.intersection(&b).is_degenerate());
+
+        // A cylinder flattened to zero height still has faces but no volume
+        let flat = Solid::cylinder(5.0, 10.0, 32).unwrap().scale(1.0, 1.0, 0.0);
+        assert!(flat.is_degenerate());
+
+        // A healthy cube is not degenerate, and neither is its mesh form
+        assert!(!a.is_degenerate());
+        assert!(!Solid::from_mesh(a.to_mesh(32)).is_degenerate());
+
+        // Empty solids are trivially degenerate, and empty_like keeps the
+        // segment count of its source
+        assert!(Solid::empty().is_degenerate());
+        let like = Solid::sphere(5.0, 64).unwrap().empty_like();
+        assert!(like.is_degenerate() && like.is_empty());
+        assert_eq!(like.segments, 64);
     }
 
     #[test]
-    fn test_cone() {
-        let cone = Solid::cone(5.0, 3.0, 10.0, 32);
-        assert!(!cone.is_empty());
+    fn test_cavities_reports_enclosed_sphere() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let sphere = Solid::sphere(2.0, 32).unwrap().translate(5.0, 5.0, 5.0);
+        let hollow = cube.difference(&sphere);
+
+        let cavities = hollow.cavities();
+        assert_eq!(cavities.len(), 1, "expected one enclosed cavity");
+        let cavity = &cavities[0];
+        let sphere_vol = 4.0 / 3.0 * std::f64::consts::PI * 8.0;
+        assert!(
+            (cavity.volume - sphere_vol).abs() < sphere_vol * 0.05,
+            "cavity volume {} should be close to {}",
+            cavity.volume,
+            sphere_vol
+        );
+        for axis in 0..3 {
+            assert!((cavity.min[axis] - 3.0).abs() < 0.01);
+            assert!((cavity.max[axis] - 7.0).abs() < 0.01);
+        }
     }
 
     #[test]
-    fn test_empty() {
-        let empty = Solid::empty();
-        assert!(empty.is_empty());
+    fn test_cavities_empty_for_solid_cube() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        assert!(cube.cavities().is_empty());
+
+        // Mesh-only solids have no shell structure to report
+        let mesh_only = Solid::from_mesh(cube.to_mesh(32));
+        assert!(mesh_only.cavities().is_empty());
     }
 
     #[test]
-    fn test_translate() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
-        let moved = cube.translate(100.0, 0.0, 0.0);
-        let (min, max) = moved.bounding_box();
-        assert!((min[0] - 100.0).abs() < 0.1);
-        assert!((max[0] - 110.0).abs() < 0.1);
+    fn test_fit_surfaces_recovers_cube_planes() {
+        use vcad_kernel_geom::SurfaceKind;
+
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let mesh_only = Solid::from_mesh(cube.to_mesh(32));
+        assert!(mesh_only.to_step_buffer().is_err());
+
+        let fitted = mesh_only.fit_surfaces(1e-3);
+        let brep = fitted.brep().expect("fitted solid should be a B-rep");
+        assert_eq!(brep.topology.faces.len(), 6);
+        for face in brep.topology.faces.values() {
+            assert_eq!(
+                brep.geometry.surfaces[face.surface_index].surface_type(),
+                SurfaceKind::Plane
+            );
+        }
+
+        let step = fitted.to_step_buffer().expect("STEP export should work");
+        assert!(!step.is_empty());
+        assert!((fitted.volume() - 1000.0).abs() < 1.0);
     }
 
     #[test]
-    fn test_scale() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
-        let scaled = cube.scale(2.0, 1.0, 1.0);
-        let (min, max) = scaled.bounding_box();
-        assert!((max[0] - min[0] - 20.0).abs() < 0.1);
-        assert!((max[1] - min[1] - 10.0).abs() < 0.1);
+    fn test_fit_surfaces_recovers_cylinder_wall() {
+        use vcad_kernel_geom::{CylinderSurface, SurfaceKind};
+
+        let cyl = Solid::cylinder(5.0, 20.0, 64).unwrap();
+        let fitted = Solid::from_mesh(cyl.to_mesh(64)).fit_surfaces(0.1);
+        let brep = fitted.brep().expect("fitted solid should be a B-rep");
+
+        let wall = brep
+            .topology
+            .faces
+            .values()
+            .find_map(|f| {
+                brep.geometry.surfaces[f.surface_index]
+                    .as_any()
+                    .downcast_ref::<CylinderSurface>()
+            })
+            .expect("expected a fitted cylindrical wall");
+        // The 64-gon chord radius is slightly under the true radius
+        assert!((wall.radius - 5.0).abs() < 0.05, "radius {}", wall.radius);
+        assert!(wall.axis.as_ref().z.abs() > 0.999);
+
+        // Both caps stay planar
+        let planes = brep
+            .topology
+            .faces
+            .values()
+            .filter(|f| {
+                brep.geometry.surfaces[f.surface_index].surface_type() == SurfaceKind::Plane
+            })
+            .count();
+        assert_eq!(planes, 2);
     }
 
     #[test]
-    fn test_union() {
-        let a = Solid::cube(10.0, 10.0, 10.0);
-        let b = Solid::cube(10.0, 10.0, 10.0);
-        let result = a.union(&b);
-        assert!(!result.is_empty());
+    fn test_align_meshes_recovers_rigid_transform() {
+        let target = Solid::cube(10.0, 6.0, 4.0).unwrap().to_mesh(8);
+
+        // Displace a copy by a known rotation + translation and ask ICP to undo it.
+        let t = Transform::translation(3.0, -2.0, 1.5).then(&Transform::rotation_z(0.3));
+        let mut source = target.clone();
+        for v in source.vertices.chunks_exact_mut(3) {
+            let p = t.apply_point(&Point3::new(v[0] as f64, v[1] as f64, v[2] as f64));
+            v[0] = p.x as f32;
+            v[1] = p.y as f32;
+            v[2] = p.z as f32;
+        }
+
+        let alignment = align_meshes(&source, &target, 50);
+        assert!(
+            alignment.rms_error < 1e-3,
+            "rms {} too large",
+            alignment.rms_error
+        );
+
+        // The recovered transform should match the inverse displacement.
+        let recovered = Transform::from_row_major(&alignment.matrix);
+        for p in [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(10.0, 6.0, 4.0),
+            Point3::new(10.0, 0.0, 4.0),
+        ] {
+            let round_trip = recovered.apply_point(&t.apply_point(&p));
+            assert!(
+                (round_trip - p).norm() < 1e-3,
+                "point {p:?} came back as {round_trip:?}"
+            );
+        }
     }
 
     #[test]
-    fn test_difference() {
-        let a = Solid::cube(10.0, 10.0, 10.0);
-        let b = Solid::cube(5.0, 5.0, 5.0);
-        let result = a.difference(&b);
-        assert!(!result.is_empty());
+    fn test_mesh_deviation_signed_distances() {
+        // Reference cube 10³ centered at the origin; source grown by 1mm per
+        // side in x and y but sharing the same height.
+        let reference = Solid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(-5.0, -5.0, -5.0)
+            .to_mesh(8);
+        let enlarged = Solid::cube(12.0, 12.0, 10.0)
+            .unwrap()
+            .translate(-6.0, -6.0, -5.0)
+            .to_mesh(8);
+
+        // Every vertex of the enlarged cube lies outside the reference.
+        let deviations = mesh_deviation(&enlarged, &reference);
+        assert_eq!(deviations.len() * 3, enlarged.vertices.len());
+        for d in &deviations {
+            assert!(*d > 0.9, "expected positive deviation, got {d}");
+        }
+
+        // Points on the shared top plane (z = 5) deviate by ~zero.
+        let on_top = TriangleMesh {
+            vertices: vec![0.0, 0.0, 5.0, 1.0, 0.0, 5.0, 0.0, 1.0, 5.0],
+            indices: vec![0, 1, 2],
+            normals: Vec::new(),
+        };
+        for d in mesh_deviation(&on_top, &reference) {
+            assert!(d.abs() < 1e-6, "expected ~zero deviation, got {d}");
+        }
+
+        // A shrunken cube sits inside: deviations go negative.
+        let shrunken = Solid::cube(8.0, 8.0, 8.0)
+            .unwrap()
+            .translate(-4.0, -4.0, -4.0)
+            .to_mesh(8);
+        for d in mesh_deviation(&shrunken, &reference) {
+            assert!(d < -0.9, "expected negative deviation, got {d}");
+        }
     }
 
     #[test]
     fn test_intersection() {
-        let a = Solid::cube(10.0, 10.0, 10.0);
-        let b = Solid::cube(10.0, 10.0, 10.0);
+        let a = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let b = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let result = a.intersection(&b);
         assert!(!result.is_empty());
     }
@@ -1062,10 +3593,12 @@ mod tests {
     fn test_plate_with_hole_via_solid_api() {
         // This mirrors the exact code path used by the WASM/app
         // Plate: 80x6x60 at origin
-        let plate = Solid::cube(80.0, 6.0, 60.0);
+        let plate = Solid::cube(80.0, 6.0, 60.0).unwrap();
 
         // Hole: 12x20x12, translated to (34, -7, 24)
-        let hole = Solid::cube(12.0, 20.0, 12.0).translate(34.0, -7.0, 24.0);
+        let hole = Solid::cube(12.0, 20.0, 12.0)
+            .unwrap()
+            .translate(34.0, -7.0, 24.0);
 
         // Boolean difference
         let result = plate.difference(&hole);
@@ -1099,21 +3632,21 @@ mod tests {
 
     #[test]
     fn test_cube_volume() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let vol = cube.volume();
         assert!((vol - 1000.0).abs() < 1.0, "expected ~1000, got {vol}");
     }
 
     #[test]
     fn test_cube_surface_area() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let area = cube.surface_area();
         assert!((area - 600.0).abs() < 1.0, "expected ~600, got {area}");
     }
 
     #[test]
     fn test_cube_bounding_box() {
-        let cube = Solid::cube(10.0, 20.0, 30.0);
+        let cube = Solid::cube(10.0, 20.0, 30.0).unwrap();
         let (min, max) = cube.bounding_box();
         assert!((max[0] - min[0] - 10.0).abs() < 0.01);
         assert!((max[1] - min[1] - 20.0).abs() < 0.01);
@@ -1122,7 +3655,7 @@ mod tests {
 
     #[test]
     fn test_cube_center_of_mass() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let com = cube.center_of_mass();
         assert!((com[0] - 5.0).abs() < 0.1, "cx: {}", com[0]);
         assert!((com[1] - 5.0).abs() < 0.1, "cy: {}", com[1]);
@@ -1131,7 +3664,7 @@ mod tests {
 
     #[test]
     fn test_rotate_cube_volume() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let rotated = cube.rotate(45.0, 30.0, 60.0);
         let vol = rotated.volume();
         // Volume should be preserved after rotation
@@ -1140,7 +3673,7 @@ mod tests {
 
     #[test]
     fn test_translate_cylinder_bbox() {
-        let cyl = Solid::cylinder(5.0, 10.0, 32);
+        let cyl = Solid::cylinder(5.0, 10.0, 32).unwrap();
         let moved = cyl.translate(100.0, 200.0, 300.0);
         let (min, max) = moved.bounding_box();
         // Center should be offset by translation
@@ -1152,7 +3685,7 @@ mod tests {
 
     #[test]
     fn test_scale_cylinder_volume() {
-        let cyl = Solid::cylinder(5.0, 10.0, 64);
+        let cyl = Solid::cylinder(5.0, 10.0, 64).unwrap();
         let base_vol = cyl.volume();
         let scaled = cyl.scale(2.0, 2.0, 2.0);
         let scaled_vol = scaled.volume();
@@ -1163,7 +3696,9 @@ mod tests {
 
     #[test]
     fn test_mirror_x() {
-        let cube = Solid::cube(10.0, 10.0, 10.0).translate(5.0, 0.0, 0.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(5.0, 0.0, 0.0);
         let mirrored = cube.scale(-1.0, 1.0, 1.0);
         let (min, _max) = mirrored.bounding_box();
         assert!(
@@ -1176,14 +3711,14 @@ mod tests {
     #[test]
     fn test_empty_union() {
         let empty = Solid::empty();
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let result = empty.union(&cube);
         assert!(!result.is_empty());
     }
 
     #[test]
     fn test_num_triangles() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         assert!(
             cube.num_triangles() >= 12,
             "cube should have at least 12 triangles"
@@ -1192,7 +3727,7 @@ mod tests {
 
     #[test]
     fn test_chamfer_cube() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let chamfered = cube.chamfer(1.0);
         assert!(!chamfered.is_empty());
         let vol = chamfered.volume();
@@ -1205,7 +3740,7 @@ mod tests {
 
     #[test]
     fn test_fillet_cube() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let filleted = cube.fillet(1.0);
         assert!(!filleted.is_empty());
         // Fillet should have more triangles than original cube due to curved surfaces
@@ -1274,14 +3809,14 @@ mod tests {
         // Due to boolean approximations, just check it's positive
         let vol = result.volume();
         assert!(
-            vol >= 1000.0 && vol <= 2000.0,
+            (1000.0..=2000.0).contains(&vol),
             "expected volume between 1000 and 2000, got {vol}"
         );
     }
 
     #[test]
     fn test_linear_pattern() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let pattern = cube.linear_pattern(Vec3::new(1.0, 0.0, 0.0), 3, 20.0);
         assert!(!pattern.is_empty());
         // 3 cubes of 1000mm³ each = 3000mm³
@@ -1299,7 +3834,7 @@ mod tests {
 
     #[test]
     fn test_linear_pattern_single() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let pattern = cube.linear_pattern(Vec3::new(1.0, 0.0, 0.0), 1, 20.0);
         // Should return original cube unchanged
         let vol = pattern.volume();
@@ -1308,7 +3843,9 @@ mod tests {
 
     #[test]
     fn test_circular_pattern() {
-        let cube = Solid::cube(5.0, 5.0, 5.0).translate(10.0, 0.0, 0.0);
+        let cube = Solid::cube(5.0, 5.0, 5.0)
+            .unwrap()
+            .translate(10.0, 0.0, 0.0);
         // Pattern 4 copies around Z axis, 360° total
         let pattern = cube.circular_pattern(Point3::origin(), Vec3::z(), 4, 360.0);
         assert!(!pattern.is_empty());
@@ -1319,7 +3856,9 @@ mod tests {
 
     #[test]
     fn test_circular_pattern_90_deg() {
-        let cube = Solid::cube(5.0, 5.0, 5.0).translate(10.0, 0.0, 0.0);
+        let cube = Solid::cube(5.0, 5.0, 5.0)
+            .unwrap()
+            .translate(10.0, 0.0, 0.0);
         // Pattern 2 copies around Z axis, 90° span (original at 0°, copy at 45°)
         let pattern = cube.circular_pattern(Point3::origin(), Vec3::z(), 2, 90.0);
         assert!(!pattern.is_empty());
@@ -1328,9 +3867,56 @@ mod tests {
         assert!((vol - 250.0).abs() < 10.0, "expected ~250, got {vol}");
     }
 
+    #[test]
+    fn test_linear_pattern_transforms_spacing() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let before = cube.volume();
+
+        let transforms = Solid::linear_pattern_transforms(Vec3::new(0.0, 2.0, 0.0), 5, 12.5);
+        assert_eq!(transforms.len(), 5);
+        for (i, t) in transforms.iter().enumerate() {
+            let p = t.apply_point(&Point3::origin());
+            assert!(p.x.abs() < 1e-12 && p.z.abs() < 1e-12);
+            assert!((p.y - 12.5 * i as f64).abs() < 1e-12);
+        }
+
+        // Placing an instance leaves the base solid unchanged
+        let instance = cube.apply_matrix(&transforms[3].to_row_major());
+        let (min, _) = instance.bounding_box();
+        assert!((min[1] - 37.5).abs() < 1e-9);
+        assert!((cube.volume() - before).abs() < 1e-9);
+
+        // Degenerate cases collapse to a single identity placement
+        assert_eq!(
+            Solid::linear_pattern_transforms(Vec3::x(), 1, 12.5).len(),
+            1
+        );
+        assert_eq!(
+            Solid::linear_pattern_transforms(Vec3::zeros(), 5, 12.5).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_circular_pattern_transforms_quarter_turns() {
+        let transforms =
+            Solid::circular_pattern_transforms(Point3::new(0.0, 0.0, 5.0), Vec3::z(), 4, 360.0);
+        assert_eq!(transforms.len(), 4);
+
+        // Instance 1 is a quarter turn about the axis through (0,0,5)
+        let p = transforms[1].apply_point(&Point3::new(10.0, 0.0, 2.0));
+        assert!(p.x.abs() < 1e-9);
+        assert!((p.y - 10.0).abs() < 1e-9);
+        assert!((p.z - 2.0).abs() < 1e-9);
+
+        // Instance 0 is exactly the identity
+        let q = transforms[0].apply_point(&Point3::new(3.0, -4.0, 5.0));
+        assert!((q - Point3::new(3.0, -4.0, 5.0)).norm() < 1e-15);
+    }
+
     #[test]
     fn test_shell_cube() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         let shell = cube.shell(2.0);
         assert!(!shell.is_empty());
         // Shell should have less volume than the original
@@ -1354,7 +3940,7 @@ mod tests {
     #[test]
     fn test_step_roundtrip() {
         // Create a cube
-        let cube = Solid::cube(15.0, 25.0, 35.0);
+        let cube = Solid::cube(15.0, 25.0, 35.0).unwrap();
 
         // Export to STEP buffer
         let buffer = cube.to_step_buffer().expect("should export to STEP");
@@ -1387,13 +3973,13 @@ mod tests {
 
     #[test]
     fn test_step_can_export() {
-        let cube = Solid::cube(10.0, 10.0, 10.0);
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
         assert!(cube.can_export_step(), "primitive should be exportable");
 
         // After boolean, B-rep is preserved (canExportStep returns true)
         // Note: More complex boolean chains may produce invalid topology
         // that causes toStepBuffer to fail, but canExportStep still returns true
-        let hole = Solid::cylinder(3.0, 15.0, 32);
+        let hole = Solid::cylinder(3.0, 15.0, 32).unwrap();
         let result = cube.difference(&hole);
         assert!(
             result.can_export_step(),
@@ -1413,32 +3999,36 @@ mod tests {
 
     #[test]
     fn test_operator_add() {
-        let a = Solid::cube(10.0, 10.0, 10.0);
-        let b = Solid::cube(10.0, 10.0, 10.0).translate(5.0, 0.0, 0.0);
+        let a = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let b = Solid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(5.0, 0.0, 0.0);
         let result = a + b;
         assert!(!result.is_empty());
     }
 
     #[test]
     fn test_operator_sub() {
-        let a = Solid::cube(10.0, 10.0, 10.0);
-        let b = Solid::cube(5.0, 5.0, 15.0);
+        let a = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let b = Solid::cube(5.0, 5.0, 15.0).unwrap();
         let result = a - b;
         assert!(!result.is_empty());
     }
 
     #[test]
     fn test_operator_bitand() {
-        let a = Solid::cube(10.0, 10.0, 10.0);
-        let b = Solid::cube(10.0, 10.0, 10.0).translate(5.0, 5.0, 5.0);
+        let a = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let b = Solid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(5.0, 5.0, 5.0);
         let result = a & b;
         assert!(!result.is_empty());
     }
 
     #[test]
     fn test_operator_ref() {
-        let a = Solid::cube(10.0, 10.0, 10.0);
-        let b = Solid::cube(10.0, 10.0, 10.0);
+        let a = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let b = Solid::cube(10.0, 10.0, 10.0).unwrap();
         // Test reference operators
         let union = &a + &b;
         let diff = &a - &b;
@@ -1447,4 +4037,1038 @@ mod tests {
         assert!(!diff.is_empty());
         assert!(!inter.is_empty());
     }
+
+    #[test]
+    fn test_meshes_per_body_split_bar() {
+        // Cut a 30x10x10 bar clean through the middle -> two 10x10x10 pieces
+        let bar = Solid::cube(30.0, 10.0, 10.0).unwrap();
+        let cutter = Solid::cube(10.0, 20.0, 20.0)
+            .unwrap()
+            .translate(10.0, -5.0, -5.0);
+        let result = bar.difference(&cutter);
+
+        let bodies = result.meshes_per_body(32);
+        assert_eq!(bodies.len(), 2, "expected two separate bodies");
+
+        let total: f64 = bodies
+            .iter()
+            .map(|m| Solid::from_mesh(m.clone()).volume())
+            .sum();
+        assert!(
+            (total - 2000.0).abs() < 50.0,
+            "expected combined volume ~2000, got {total}"
+        );
+    }
+
+    #[test]
+    fn test_unfold_cylinder_lateral_face() {
+        use std::f64::consts::PI;
+
+        let cyl = Solid::cylinder(5.0, 10.0, 32).unwrap();
+        let num_faces = cyl.brep().unwrap().topology.faces.len();
+
+        // Find the lateral face: its flat pattern is a rectangle whose width
+        // is the full circumference
+        let mut found = false;
+        for i in 0..num_faces {
+            let Some(outline) = cyl.unfold_face(i) else {
+                continue;
+            };
+            let width = outline.iter().map(|p| p.x).fold(f64::MIN, f64::max)
+                - outline.iter().map(|p| p.x).fold(f64::MAX, f64::min);
+            if (width - 2.0 * PI * 5.0).abs() < 1e-9 {
+                found = true;
+                // Height equals the cylinder height
+                let height = outline.iter().map(|p| p.y).fold(f64::MIN, f64::max)
+                    - outline.iter().map(|p| p.y).fold(f64::MAX, f64::min);
+                assert!((height - 10.0).abs() < 1e-9);
+                assert_eq!(outline.len(), 4);
+            }
+        }
+        assert!(found, "expected lateral face to unroll to circumference");
+    }
+
+    #[test]
+    fn test_unfold_face_sphere_not_developable() {
+        let sphere = Solid::sphere(5.0, 16).unwrap();
+        let num_faces = sphere.brep().unwrap().topology.faces.len();
+        for i in 0..num_faces {
+            assert!(sphere.unfold_face(i).is_none());
+        }
+    }
+
+    #[test]
+    fn test_unfold_shell_sheet_with_one_bend() {
+        use std::f64::consts::PI;
+        use vcad_kernel_geom::{CylinderSurface, GeometryStore, Plane};
+        use vcad_kernel_math::Dir3;
+        use vcad_kernel_topo::{Orientation, ShellType, Topology};
+
+        // Sheet-metal L-bracket: a 10x20 base plate at z=0, a quarter-
+        // cylinder bend of radius 5 about the line x=10, z=5, and a 10x20
+        // vertical flange at x=15 rising from z=5 to z=15.
+        let mut topo = Topology::new();
+        let mut geom = GeometryStore::new();
+
+        let v0 = topo.add_vertex(Point3::new(0.0, 0.0, 0.0));
+        let v1 = topo.add_vertex(Point3::new(10.0, 0.0, 0.0));
+        let v2 = topo.add_vertex(Point3::new(10.0, 20.0, 0.0));
+        let v3 = topo.add_vertex(Point3::new(0.0, 20.0, 0.0));
+        let v4 = topo.add_vertex(Point3::new(15.0, 0.0, 5.0));
+        let v5 = topo.add_vertex(Point3::new(15.0, 20.0, 5.0));
+        let v6 = topo.add_vertex(Point3::new(15.0, 0.0, 15.0));
+        let v7 = topo.add_vertex(Point3::new(15.0, 20.0, 15.0));
+
+        let mut faces = Vec::new();
+        let mut he_map = std::collections::HashMap::new();
+        let mut add_face = |topo: &mut Topology, surface_idx: usize, verts: [_; 4]| {
+            let mut hes = Vec::new();
+            for j in 0..4 {
+                let he = topo.add_half_edge(verts[j]);
+                hes.push(he);
+                he_map.insert((verts[j], verts[(j + 1) % 4]), he);
+            }
+            let loop_id = topo.add_loop(&hes);
+            topo.add_face(loop_id, surface_idx, Orientation::Forward)
+        };
+
+        // Base plate (normal +Z)
+        let base = geom.add_surface(Box::new(Plane::new(
+            Point3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        )));
+        faces.push(add_face(&mut topo, base, [v0, v1, v2, v3]));
+
+        // Bend: theta = 0 at the base edge (z=0), pi/2 at the flange edge
+        let bend = geom.add_surface(Box::new(CylinderSurface {
+            center: Point3::new(10.0, 0.0, 5.0),
+            axis: Dir3::new_normalize(Vec3::new(0.0, -1.0, 0.0)),
+            ref_dir: Dir3::new_normalize(Vec3::new(0.0, 0.0, -1.0)),
+            radius: 5.0,
+        }));
+        faces.push(add_face(&mut topo, bend, [v2, v1, v4, v5]));
+
+        // Flange (normal +X)
+        let flange = geom.add_surface(Box::new(Plane::new(
+            Point3::new(15.0, 0.0, 5.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        )));
+        faces.push(add_face(&mut topo, flange, [v5, v4, v6, v7]));
+
+        // Pair the two shared (bend) edges
+        for (a, b) in [(v1, v2), (v4, v5)] {
+            topo.add_edge(he_map[&(a, b)], he_map[&(b, a)]);
+        }
+
+        let shell = topo.add_shell(faces, ShellType::Outer);
+        let solid_id = topo.add_solid(shell);
+        let sheet = Solid {
+            repr: SolidRepr::BRep(Box::new(BRepSolid {
+                topology: topo,
+                geometry: geom,
+                solid_id,
+            })),
+            segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        };
+
+        let pattern = sheet.unfold_shell();
+        assert_eq!(pattern.faces.len(), 3);
+        assert!(pattern.non_developable.is_empty());
+        assert_eq!(pattern.bend_lines.len(), 2);
+
+        // The base plate seeds the pattern at x in [0, 10]; the unrolled
+        // bend spans its arc length pi/2 * 5 and the flange extends 10 more
+        let arc = PI / 2.0 * 5.0;
+        let xs: Vec<f64> = pattern
+            .faces
+            .iter()
+            .flat_map(|f| f.outline.iter().map(|p| p.x))
+            .collect();
+        let ys: Vec<f64> = pattern
+            .faces
+            .iter()
+            .flat_map(|f| f.outline.iter().map(|p| p.y))
+            .collect();
+        let max_x = xs.iter().fold(f64::MIN, |a, &b| a.max(b));
+        let min_x = xs.iter().fold(f64::MAX, |a, &b| a.min(b));
+        let max_y = ys.iter().fold(f64::MIN, |a, &b| a.max(b));
+        let min_y = ys.iter().fold(f64::MAX, |a, &b| a.min(b));
+        assert!(min_x.abs() < 1e-9 && min_y.abs() < 1e-9);
+        assert!((max_x - (20.0 + arc)).abs() < 1e-9, "max_x = {max_x}");
+        assert!((max_y - 20.0).abs() < 1e-9, "max_y = {max_y}");
+
+        // Bend lines sit at the fold locations: the base edge at x=10 and
+        // the flange edge one arc length further, both spanning the 20mm
+        // width of the sheet
+        let mut bend_xs: Vec<f64> = pattern
+            .bend_lines
+            .iter()
+            .map(|[a, b]| {
+                assert!((a.x - b.x).abs() < 1e-9);
+                assert!(((a.y - b.y).abs() - 20.0).abs() < 1e-9);
+                a.x
+            })
+            .collect();
+        bend_xs.sort_by(f64::total_cmp);
+        assert!((bend_xs[0] - 10.0).abs() < 1e-9);
+        assert!((bend_xs[1] - (10.0 + arc)).abs() < 1e-9);
+
+        // Each unfolded face keeps its true area
+        let shoelace = |pts: &[vcad_kernel_math::Point2]| {
+            let n = pts.len();
+            (0..n)
+                .map(|i| {
+                    let (a, b) = (&pts[i], &pts[(i + 1) % n]);
+                    a.x * b.y - b.x * a.y
+                })
+                .sum::<f64>()
+                .abs()
+                / 2.0
+        };
+        let areas: Vec<f64> = pattern.faces.iter().map(|f| shoelace(&f.outline)).collect();
+        assert!((areas[0] - 200.0).abs() < 1e-6);
+        assert!((areas[1] - arc * 20.0).abs() < 1e-6);
+        assert!((areas[2] - 200.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_height_field_stepped_block() {
+        // Stepped block: 20x20 footprint, 10 tall over x < 10, 5 tall over
+        // x > 10
+        let base = Solid::cube(20.0, 20.0, 5.0).unwrap();
+        let tower = Solid::cube(10.0, 20.0, 10.0).unwrap();
+        let block = base.union(&tower);
+
+        let field = block.height_field(10, HeightAxis::Z).unwrap();
+        assert_eq!(field.resolution, 10);
+        assert!((field.origin[0]).abs() < 1e-6);
+        assert!((field.origin[1]).abs() < 1e-6);
+        assert!((field.cell_size[0] - 2.0).abs() < 1e-6);
+        assert!((field.cell_size[1] - 2.0).abs() < 1e-6);
+        assert!(field.floor.abs() < 1e-6);
+        assert_eq!(field.heights.len(), 100);
+
+        // Two plateaus: the tall step over x < 10, the low step beyond it
+        for j in 0..10 {
+            for i in 0..10 {
+                let x = field.origin[0] + field.cell_size[0] * (i as f64 + 0.5);
+                let expected = if x < 10.0 { 10.0 } else { 5.0 };
+                let h = field.heights[j * 10 + i];
+                assert!(
+                    (h - expected).abs() < 1e-3,
+                    "cell ({i}, {j}) at x={x}: expected {expected}, got {h}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_printability_report_overhang_and_thin_wall() {
+        // Thin vertical wall (0.4 mm) with a shelf jutting out near the top:
+        // the shelf's underside is an unsupported horizontal overhang
+        let wall = Solid::cube(0.4, 10.0, 10.0).unwrap();
+        let shelf = Solid::cube(6.0, 8.0, 0.4).unwrap().translate(0.2, 1.0, 9.0);
+        let part = wall.union(&shelf);
+
+        let settings = PrintabilitySettings::default();
+        let report = part.printability_report([256.0, 256.0, 256.0], &settings);
+        assert!(report.fits_bed);
+        assert!(
+            report.overhang_triangles > 0 && report.overhang_area > 1.0,
+            "shelf underside should register as overhang, got {} triangles / {} mm²",
+            report.overhang_triangles,
+            report.overhang_area
+        );
+        assert!(report.support_volume > 0.0);
+        assert!(
+            report.thin_wall_triangles > 0,
+            "0.4 mm wall should be thinner than the {} mm minimum",
+            settings.min_wall_thickness
+        );
+        assert_eq!(report.status, PrintabilityStatus::Warn);
+
+        // A solid cube resting on the bed is clean
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let report = cube.printability_report([256.0, 256.0, 256.0], &settings);
+        assert_eq!(report.status, PrintabilityStatus::Pass);
+        assert_eq!(report.overhang_triangles, 0);
+        assert_eq!(report.thin_wall_triangles, 0);
+
+        // Too large for the build volume fails outright
+        let report = cube.printability_report([5.0, 256.0, 256.0], &settings);
+        assert!(!report.fits_bed);
+        assert_eq!(report.status, PrintabilityStatus::Fail);
+    }
+
+    #[test]
+    fn test_sketch_plane_from_cube_top_face() {
+        let cube = Solid::cube(10.0, 20.0, 30.0).unwrap();
+        let num_faces = cube.brep().unwrap().topology.faces.len();
+
+        // Find the top face (normal +Z) and check its sketch plane
+        let mut found = false;
+        for i in 0..num_faces {
+            let plane = cube.sketch_plane_from_face(i).unwrap();
+            if plane.normal.z > 0.99 {
+                found = true;
+                // Origin lies on the top face
+                assert!((plane.origin.z - 30.0).abs() < 1e-9);
+                assert!((0.0..=10.0).contains(&plane.origin.x));
+                assert!((0.0..=20.0).contains(&plane.origin.y));
+                // Frame is orthonormal and right-handed
+                assert!(plane.x_dir.dot(&plane.y_dir).abs() < 1e-9);
+                let n = plane.x_dir.cross(&plane.y_dir);
+                assert!((n - plane.normal).norm() < 1e-9);
+            }
+        }
+        assert!(found, "cube should have a +Z face");
+    }
+
+    #[test]
+    fn test_sketch_plane_from_face_out_of_range() {
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        assert!(cube.sketch_plane_from_face(999).is_none());
+        assert!(Solid::empty().sketch_plane_from_face(0).is_none());
+    }
+
+    #[test]
+    fn test_to_mesh_refined_cube_plus_cylinder() {
+        use std::collections::HashMap;
+
+        let part = Solid::cube(20.0, 20.0, 20.0).unwrap().union(
+            &Solid::cylinder(5.0, 40.0, 8)
+                .unwrap()
+                .translate(10.0, 10.0, -10.0),
+        );
+        let brep = part.brep().expect("union should stay a B-rep");
+
+        // Refine just the cylindrical lateral face(s) to 64 segments
+        let refinements: HashMap<usize, u32> = brep
+            .topology
+            .faces
+            .values()
+            .enumerate()
+            .filter(|(_, f)| {
+                brep.geometry.surfaces[f.surface_index].surface_type()
+                    == vcad_kernel_geom::SurfaceKind::Cylinder
+            })
+            .map(|(i, _)| (i, 64))
+            .collect();
+        assert!(!refinements.is_empty());
+
+        let uniform = part.to_mesh(8);
+        let refined = part.to_mesh_refined(8, &refinements);
+        assert!(
+            refined.num_triangles() > uniform.num_triangles(),
+            "expected refined mesh ({} tris) to exceed uniform mesh ({} tris)",
+            refined.num_triangles(),
+            uniform.num_triangles()
+        );
+    }
+
+    #[test]
+    fn test_sketch_plane_from_cylinder_lateral_face() {
+        let cyl = Solid::cylinder(5.0, 10.0, 32).unwrap();
+        let num_faces = cyl.brep().unwrap().topology.faces.len();
+
+        // Every face should yield a valid tangent-plane frame
+        for i in 0..num_faces {
+            let plane = cyl.sketch_plane_from_face(i).unwrap();
+            assert!((plane.normal.norm() - 1.0).abs() < 1e-9);
+            assert!(plane.x_dir.dot(&plane.normal).abs() < 1e-6);
+        }
+    }
+    #[test]
+    fn test_face_area_cylinder_exact() {
+        use std::f64::consts::PI;
+        use vcad_kernel_geom::SurfaceKind;
+
+        let cyl = Solid::cylinder(5.0, 10.0, 16).unwrap();
+        let brep = cyl.brep().unwrap();
+
+        // Lateral face area is exactly 2πrh, not the tessellated prism area
+        let lateral = brep
+            .topology
+            .faces
+            .values()
+            .position(|f| {
+                brep.geometry.surfaces[f.surface_index].surface_type() == SurfaceKind::Cylinder
+            })
+            .unwrap();
+        let area = cyl.face_area(lateral).unwrap();
+        assert!((area - 2.0 * PI * 5.0 * 10.0).abs() < 1e-9);
+
+        // Circular caps are exactly πr²
+        let cap = brep
+            .topology
+            .faces
+            .values()
+            .position(|f| {
+                brep.geometry.surfaces[f.surface_index].surface_type() == SurfaceKind::Plane
+            })
+            .unwrap();
+        assert!((cyl.face_area(cap).unwrap() - PI * 25.0).abs() < 1e-9);
+
+        // Cube face areas sum to the exact surface area
+        let cube = Solid::cube(10.0, 20.0, 30.0).unwrap();
+        let cube_brep = cube.brep().unwrap();
+        let total: f64 = (0..cube_brep.topology.faces.len())
+            .map(|i| cube.face_area(i).unwrap())
+            .sum();
+        assert!((total - 2200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_face_radius_fillet() {
+        use vcad_kernel_geom::SurfaceKind;
+
+        // Filleting a cube replaces each edge with a cylindrical blend of
+        // the fillet radius; the trimmed planar faces stay radiusless.
+        let rounded = Solid::cube(20.0, 20.0, 20.0).unwrap().fillet(2.0);
+        let brep = rounded.brep().unwrap();
+
+        let mut cylindrical = 0;
+        for (i, face) in brep.topology.faces.values().enumerate() {
+            match brep.geometry.surfaces[face.surface_index].surface_type() {
+                SurfaceKind::Cylinder => {
+                    let r = rounded.face_radius(i).unwrap();
+                    assert!((r - 2.0).abs() < 1e-9, "fillet face radius {r}");
+                    cylindrical += 1;
+                }
+                SurfaceKind::Plane => {
+                    assert!(rounded.face_radius(i).is_none());
+                }
+                _ => {}
+            }
+        }
+        assert!(cylindrical > 0, "expected cylindrical fillet faces");
+
+        // Out-of-range indices report no radius
+        assert!(rounded.face_radius(usize::MAX).is_none());
+    }
+
+    #[test]
+    fn test_edge_length_exact() {
+        use std::f64::consts::PI;
+
+        // Every cube edge is one of the three side lengths
+        let cube = Solid::cube(10.0, 20.0, 30.0).unwrap();
+        let n_edges = cube.brep().unwrap().topology.edges.len();
+        for i in 0..n_edges {
+            let len = cube.edge_length(i).unwrap();
+            assert!(
+                [10.0, 20.0, 30.0].iter().any(|&l| (len - l).abs() < 1e-9),
+                "unexpected cube edge length {len}"
+            );
+        }
+
+        // Cylinder rim edges are full circles of circumference 2πr
+        let cyl = Solid::cylinder(5.0, 10.0, 16).unwrap();
+        let n_edges = cyl.brep().unwrap().topology.edges.len();
+        let rims = (0..n_edges)
+            .filter(|&i| (cyl.edge_length(i).unwrap() - 2.0 * PI * 5.0).abs() < 1e-9)
+            .count();
+        assert_eq!(rims, 2);
+
+        assert!(cube.edge_length(usize::MAX).is_none());
+        assert!(Solid::empty().edge_length(0).is_none());
+    }
+    #[test]
+    fn test_wireframe_edges_cylinder() {
+        let cyl = Solid::cylinder(5.0, 10.0, 32).unwrap();
+        let edges = cyl.wireframe_edges(24).unwrap();
+
+        // The cylinder B-rep has exactly 3 edges: two rims and the seam —
+        // not a triangulated approximation of the surface
+        assert_eq!(edges.len(), 3);
+
+        let rims: Vec<&Vec<Point3>> = edges.iter().filter(|e| e.len() == 25).collect();
+        let seams: Vec<&Vec<Point3>> = edges.iter().filter(|e| e.len() == 2).collect();
+        assert_eq!(rims.len(), 2, "expected two sampled rim circles");
+        assert_eq!(seams.len(), 1, "expected one straight seam edge");
+
+        // Rim samples all lie on the r=5 circle at z=0 or z=10, closed
+        for rim in &rims {
+            let z = rim[0].z;
+            assert!(z.abs() < 1e-9 || (z - 10.0).abs() < 1e-9);
+            for p in rim.iter() {
+                assert!(((p.x * p.x + p.y * p.y).sqrt() - 5.0).abs() < 1e-9);
+                assert!((p.z - z).abs() < 1e-9);
+            }
+            assert!(
+                (rim[0] - rim[24]).norm() < 1e-9,
+                "rim polyline should close"
+            );
+        }
+
+        // Seam runs the full height at the u=0 generator
+        let seam = seams[0];
+        assert!(((seam[0] - seam[1]).norm() - 10.0).abs() < 1e-9);
+
+        assert!(Solid::empty().wireframe_edges(24).is_none());
+    }
+
+    #[test]
+    fn test_engrave_text_on_cylinder_two_pockets() {
+        use vcad_kernel_text::{text_to_profiles, FontRegistry, TextAlignment};
+
+        let cyl = Solid::cylinder(10.0, 20.0, 64).unwrap();
+        let n_faces = cyl.brep().unwrap().topology.faces.len();
+        let lateral = (0..n_faces)
+            .find(|&i| cyl.face_radius(i) == Some(10.0))
+            .unwrap();
+
+        let start = 0.3;
+        let engraved = cyl
+            .engrave_text_on_cylinder("AB", None, lateral, start, 7.0, 6.0, 1.5)
+            .expect("engrave should succeed on the lateral face");
+
+        // Expected angular span of each glyph, from the same text profiles
+        let profiles = text_to_profiles(
+            "AB",
+            FontRegistry::builtin_sans(),
+            6.0,
+            1.0,
+            1.0,
+            TextAlignment::Left,
+        );
+        let mut spans: Vec<(f64, f64)> = profiles
+            .iter()
+            .enumerate()
+            .filter(|(i, p)| {
+                !profiles
+                    .iter()
+                    .enumerate()
+                    .any(|(j, q)| *i != j && p.is_contained_in(q))
+            })
+            .map(|(_, p)| {
+                let xs: Vec<f64> = p.segments.iter().map(|s| s.start().x).collect();
+                let lo = xs.iter().cloned().fold(f64::INFINITY, f64::min);
+                let hi = xs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                (start + lo / 10.0, start + hi / 10.0)
+            })
+            .collect();
+        spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+        assert_eq!(spans.len(), 2, "expected one outer contour per letter");
+
+        // Pocket vertices sit strictly inside the nominal radius in the text
+        // band — material was removed there. Read them from the topology:
+        // the pocket walls and bottoms are faces of the cut.
+        let mut pocket_angles: Vec<f64> = engraved
+            .brep()
+            .expect("engrave result should stay B-rep")
+            .topology
+            .vertices
+            .values()
+            .filter_map(|v| {
+                let p = v.point;
+                let r = (p.x * p.x + p.y * p.y).sqrt();
+                (r < 9.8 && p.z > 6.5 && p.z < 13.5).then(|| p.y.atan2(p.x))
+            })
+            .collect();
+        pocket_angles.sort_by(f64::total_cmp);
+
+        assert!(!pocket_angles.is_empty(), "no pocket vertices found");
+        let band = (spans[0].0 - 0.1, spans[1].1 + 0.1);
+        assert!(
+            pocket_angles.iter().all(|a| *a > band.0 && *a < band.1),
+            "pocket vertices outside the text band"
+        );
+        for (lo, hi) in &spans {
+            let hit = pocket_angles
+                .iter()
+                .any(|a| *a > lo - 0.05 && *a < hi + 0.05);
+            assert!(hit, "no pocket in glyph span {lo}..{hi}");
+        }
+
+        // The two pockets are separate: each glyph cut leaves a bottom face
+        // whose loop vertices all sit below the surface radius. Collect the
+        // angular extent of every such face and require one per glyph
+        // window, with none bridging both letters. Each cutter prism is
+        // tangent at its glyph's centre angle, so bottom edges bleed past
+        // the nominal span by asin(dx/(r-depth)) - dx/r — hence the slack.
+        let brep = engraved.brep().unwrap();
+        let bottoms: Vec<(f64, f64)> = brep
+            .topology
+            .faces
+            .values()
+            .filter_map(|f| {
+                let angles: Vec<f64> = brep
+                    .topology
+                    .loop_half_edges(f.outer_loop)
+                    .map(|he| {
+                        let p = brep.topology.vertices[brep.topology.half_edges[he].origin].point;
+                        (p.y.atan2(p.x), (p.x * p.x + p.y * p.y).sqrt())
+                    })
+                    .filter(|(_, r)| *r < 9.8)
+                    .map(|(a, _)| a)
+                    .collect();
+                if angles.len() != brep.topology.loop_half_edges(f.outer_loop).count() {
+                    return None;
+                }
+                let lo = angles.iter().cloned().fold(f64::INFINITY, f64::min);
+                let hi = angles.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                Some((lo, hi))
+            })
+            .collect();
+        let within = |range: &(f64, f64), span: &(f64, f64)| {
+            range.0 > span.0 - 0.06 && range.1 < span.1 + 0.06
+        };
+        for span in &spans {
+            assert!(
+                bottoms.iter().any(|b| within(b, span)),
+                "no pocket bottom inside glyph span {span:?}"
+            );
+        }
+        let centers = (
+            0.5 * (spans[0].0 + spans[0].1),
+            0.5 * (spans[1].0 + spans[1].1),
+        );
+        assert!(
+            !bottoms.iter().any(|b| b.0 < centers.0 && b.1 > centers.1),
+            "a single pocket face bridges both letters"
+        );
+
+        // More faces than the plain cylinder's three: the cut added pocket
+        // walls and bottoms
+        assert!(engraved.brep().unwrap().topology.faces.len() > n_faces);
+    }
+
+    #[test]
+    fn test_auto_orient_l_bracket() {
+        // L-bracket: 40×40×10 base with a 10×40×50 wall, tipped onto its side
+        let base = Solid::cube(40.0, 40.0, 10.0).unwrap();
+        let wall = Solid::cube(10.0, 40.0, 50.0).unwrap();
+        let bracket = base.union(&wall);
+        let tilted = bracket.rotate(0.0, 90.0, 0.0);
+
+        let oriented = tilted
+            .auto_orient_for_print(PrintOrientStrategy::MinOverhang)
+            .unwrap();
+        // A flat-down orientation exists, so the overhang score is zero
+        assert!(oriented.score < 1e-6, "overhang score {}", oriented.score);
+
+        // The part rests on the bed with a large flat face in contact (the
+        // union drops the overlapping coplanar patch, so slightly under the
+        // full 1600 mm²)
+        let (min, _) = oriented.solid.bounding_box();
+        assert!(min[2].abs() < 1e-6);
+        let mesh = oriented.solid.to_mesh(8);
+        let mut bed_area = 0.0;
+        for tri in mesh.indices.chunks(3) {
+            let corner = |i: u32| {
+                let v = i as usize * 3;
+                Point3::new(
+                    mesh.vertices[v] as f64,
+                    mesh.vertices[v + 1] as f64,
+                    mesh.vertices[v + 2] as f64,
+                )
+            };
+            let (a, b, c) = (corner(tri[0]), corner(tri[1]), corner(tri[2]));
+            if a.z.abs() < 1e-6 && b.z.abs() < 1e-6 && c.z.abs() < 1e-6 {
+                bed_area += 0.5 * (b - a).cross(&(c - a)).norm();
+            }
+        }
+        assert!(bed_area > 1000.0, "bed contact area {}", bed_area);
+
+        // Min-height rests the bracket on a side so the 50 mm wall lies flat
+        let by_height = tilted
+            .auto_orient_for_print(PrintOrientStrategy::MinHeight)
+            .unwrap();
+        assert!((by_height.score - 40.0).abs() < 1e-6);
+
+        assert!(Solid::empty()
+            .auto_orient_for_print(PrintOrientStrategy::MinOverhang)
+            .is_none());
+    }
+
+    #[test]
+    fn test_assembly_fit_clearance_and_interference() {
+        // Plate with a bore down its center, shaft dropped into it
+        let bored_plate = |bore_radius: f64| {
+            let plate = Solid::cube(20.0, 20.0, 10.0).unwrap();
+            let drill = Solid::cylinder(bore_radius, 20.0, 32)
+                .unwrap()
+                .translate(10.0, 10.0, -5.0);
+            plate.difference(&drill)
+        };
+        let shaft = |radius: f64| {
+            Solid::cylinder(radius, 10.0, 32)
+                .unwrap()
+                .translate(10.0, 10.0, 0.0)
+        };
+
+        // Ø10 shaft in a Ø10.2 hole: 0.1 mm radial clearance
+        let fit = shaft(5.0).assembly_fit(&bored_plate(5.1)).unwrap();
+        assert!((fit.shaft_radius - 5.0).abs() < 1e-9);
+        assert!((fit.hole_radius - 5.1).abs() < 1e-9);
+        assert!((fit.clearance - 0.1).abs() < 1e-9, "{}", fit.clearance);
+
+        // Ø10.2 shaft in a Ø10 hole: 0.1 mm interference
+        let fit = shaft(5.1).assembly_fit(&bored_plate(5.0)).unwrap();
+        assert!((fit.clearance + 0.1).abs() < 1e-9, "{}", fit.clearance);
+
+        // No hole to pair with
+        let cube = Solid::cube(20.0, 20.0, 10.0).unwrap();
+        assert!(shaft(5.0).assembly_fit(&cube).is_none());
+    }
+
+    #[test]
+    fn test_volume_by_material() {
+        // Two touching cubes of different materials, unioned into one part
+        let steel = Solid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .with_material_tag("steel");
+        let brass = Solid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .translate(10.0, 0.0, 0.0)
+            .with_material_tag("brass");
+        let part = steel.union(&brass);
+
+        let volumes = part.volume_by_material();
+        assert_eq!(volumes.len(), 2);
+        let total = part.volume();
+        let sum: f64 = volumes.iter().map(|(_, v)| v).sum();
+        assert!((sum - total).abs() < 1e-6 * total, "{sum} vs {total}");
+        for (tag, vol) in &volumes {
+            assert!((vol - 1000.0).abs() < 1.0, "{tag}: {vol}");
+        }
+
+        // Cutting from the brass end only reduces the brass volume
+        let cutter = Solid::cube(10.0, 20.0, 20.0)
+            .unwrap()
+            .translate(15.0, -5.0, -5.0);
+        let cut = part.difference(&cutter);
+        let volumes = cut.volume_by_material();
+        let get = |t: &str| volumes.iter().find(|(tag, _)| tag == t).unwrap().1;
+        assert!(
+            (get("steel") - 1000.0).abs() < 1.0,
+            "steel {}",
+            get("steel")
+        );
+        assert!((get("brass") - 500.0).abs() < 1.0, "brass {}", get("brass"));
+
+        // Untagged solids report nothing
+        assert!(Solid::cube(1.0, 1.0, 1.0)
+            .unwrap()
+            .volume_by_material()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_face_area_subtracts_inner_loops() {
+        use std::f64::consts::PI;
+
+        // 20×20×5 plate with a Ø6 hole drilled through the middle
+        let plate = Solid::cube(20.0, 20.0, 5.0).unwrap();
+        let drill = Solid::cylinder(3.0, 15.0, 64)
+            .unwrap()
+            .translate(10.0, 10.0, -5.0);
+        let plate_with_hole = plate.difference(&drill);
+
+        let brep = plate_with_hole.brep().unwrap();
+        let n_faces = brep.topology.faces.len();
+
+        // The top and bottom faces have the hole as an inner loop; their
+        // area must be the plate face minus the hole, not the full 400
+        let expected = 400.0 - PI * 9.0;
+        let holed: Vec<f64> = (0..n_faces)
+            .filter(|&i| {
+                let face = brep.topology.faces.values().nth(i).unwrap();
+                !face.inner_loops.is_empty()
+            })
+            .map(|i| plate_with_hole.face_area(i).unwrap())
+            .collect();
+        assert_eq!(holed.len(), 2, "expected two faces with inner loops");
+        for area in holed {
+            // The hole boundary is a 64-gon, so allow its sagitta error
+            assert!((area - expected).abs() < 0.2, "face area {area}");
+            assert!(area < 399.0, "hole was not subtracted: {area}");
+        }
+    }
+
+    #[test]
+    fn test_merge_collinear_edges_restores_plate_boundary() {
+        // Plate with a through-hole, then fragment the top face's outer
+        // boundary the way splitting operations do: three collinear edge
+        // pieces per side instead of one
+        let plate = Solid::cube(20.0, 20.0, 5.0).unwrap();
+        let drill = Solid::cylinder(3.0, 15.0, 32)
+            .unwrap()
+            .translate(10.0, 10.0, -5.0);
+        let holed = plate.difference(&drill);
+
+        let mut brep = holed.brep().unwrap().clone();
+        let (top_face, hole_len) = brep
+            .topology
+            .faces
+            .iter()
+            .find(|(_, f)| {
+                !f.inner_loops.is_empty()
+                    && brep
+                        .topology
+                        .loop_vertices(f.outer_loop)
+                        .iter()
+                        .all(|&v| (brep.topology.vertices[v].point.z - 5.0).abs() < 1e-9)
+            })
+            .map(|(id, f)| (id, brep.topology.loop_len(f.inner_loops[0])))
+            .unwrap();
+        let outer = brep.topology.faces[top_face].outer_loop;
+        assert_eq!(brep.topology.loop_len(outer), 4);
+
+        let hes: Vec<_> = brep.topology.loop_half_edges(outer).collect();
+        for he in hes {
+            let p0 = brep.topology.vertices[brep.topology.half_edges[he].origin].point;
+            let p1 = brep.topology.vertices[brep.topology.half_edge_dest(he)].point;
+            brep.topology.make_edge_vertex(he, p0 + (p1 - p0) / 3.0);
+            brep.topology
+                .make_edge_vertex(he, p0 + (p1 - p0) * (2.0 / 3.0));
+        }
+        assert_eq!(brep.topology.loop_len(outer), 12);
+
+        let fragmented = Solid {
+            repr: SolidRepr::BRep(Box::new(brep)),
+            segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        };
+        let merged = fragmented.merge_collinear_edges(1e-6);
+        let mbrep = merged.brep().unwrap();
+
+        // The outer boundary collapses back to four straight edges; the
+        // polygonal hole rim is left alone (its vertices are not collinear)
+        let top = mbrep
+            .topology
+            .faces
+            .values()
+            .find(|f| {
+                !f.inner_loops.is_empty()
+                    && mbrep
+                        .topology
+                        .loop_vertices(f.outer_loop)
+                        .iter()
+                        .all(|&v| (mbrep.topology.vertices[v].point.z - 5.0).abs() < 1e-9)
+            })
+            .unwrap();
+        assert_eq!(mbrep.topology.loop_len(top.outer_loop), 4);
+        assert_eq!(mbrep.topology.loop_len(top.inner_loops[0]), hole_len);
+        for he in mbrep.topology.loop_half_edges(top.outer_loop) {
+            let p0 = mbrep.topology.vertices[mbrep.topology.half_edges[he].origin].point;
+            let p1 = mbrep.topology.vertices[mbrep.topology.half_edge_dest(he)].point;
+            assert!(((p1 - p0).norm() - 20.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_remove_short_edges_cleans_boolean_sliver() {
+        // A pad protruding 0.0005mm above the cube top leaves four
+        // sub-millimeter vertical edges and four sliver wall faces in the
+        // union result
+        let base = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let pad = Solid::cube(5.0, 5.0, 0.5005)
+            .unwrap()
+            .translate(2.5, 2.5, 9.5);
+        let bumped = base.union(&pad);
+        let volume_before = bumped.volume();
+
+        let edge_lengths = |solid: &Solid| -> Vec<f64> {
+            let brep = solid.brep().unwrap();
+            brep.topology
+                .edges
+                .values()
+                .map(|e| {
+                    let he = e.half_edge;
+                    let p0 = brep.topology.vertices[brep.topology.half_edges[he].origin].point;
+                    let p1 = brep.topology.vertices[brep.topology.half_edge_dest(he)].point;
+                    (p1 - p0).norm()
+                })
+                .collect()
+        };
+        let faces_before = bumped.brep().unwrap().topology.faces.len();
+        assert_eq!(
+            edge_lengths(&bumped).iter().filter(|&&l| l < 0.01).count(),
+            4
+        );
+
+        let cleaned = bumped.remove_short_edges(0.01);
+        assert!(edge_lengths(&cleaned).iter().all(|&l| l >= 0.01));
+        // The four sliver walls dissolve when their short edges collapse
+        let faces_after = cleaned.brep().unwrap().topology.faces.len();
+        assert_eq!(faces_after, faces_before - 4);
+        assert!((cleaned.volume() - volume_before).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_step_export_validated_heals_open_shell() {
+        // Build an open shell: a cube with its top face torn out
+        let cube = Solid::cube(10.0, 10.0, 10.0).unwrap();
+        let mut brep = cube.brep().unwrap().clone();
+        let top = brep
+            .topology
+            .faces
+            .iter()
+            .find(|(_, f)| {
+                brep.topology
+                    .loop_vertices(f.outer_loop)
+                    .iter()
+                    .all(|&v| (brep.topology.vertices[v].point.z - 10.0).abs() < 1e-9)
+            })
+            .map(|(id, _)| id)
+            .unwrap();
+        let loop_id = brep.topology.faces[top].outer_loop;
+        let shell = brep.topology.faces[top].shell;
+        let hes: Vec<_> = brep.topology.loop_half_edges(loop_id).collect();
+        for &he in &hes {
+            if let Some(edge) = brep.topology.half_edges[he].edge {
+                brep.topology.edges.remove(edge);
+            }
+            if let Some(twin) = brep.topology.half_edges[he].twin {
+                brep.topology.half_edges[twin].twin = None;
+                brep.topology.half_edges[twin].edge = None;
+            }
+        }
+        for &he in &hes {
+            let origin = brep.topology.half_edges[he].origin;
+            if brep.topology.vertices[origin].half_edge == Some(he) {
+                brep.topology.vertices[origin].half_edge = None;
+            }
+            brep.topology.half_edges.remove(he);
+        }
+        brep.topology.loops.remove(loop_id);
+        brep.topology.faces.remove(top);
+        if let Some(shell_id) = shell {
+            brep.topology.shells[shell_id].faces.retain(|&f| f != top);
+        }
+        let open = Solid {
+            repr: SolidRepr::BRep(Box::new(brep)),
+            segments: 32,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        };
+        assert!(!open.is_closed());
+
+        // Without healing, validation reports the open boundary
+        match open.to_step_buffer_validated(false) {
+            Err(StepExportError::Invalid(problems)) => {
+                assert!(
+                    problems.iter().any(|p| p.contains("not closed")),
+                    "{problems:?}"
+                );
+            }
+            other => panic!("expected Invalid error, got {:?}", other.map(|b| b.len())),
+        }
+
+        // Healing caps the planar boundary loop and the export succeeds
+        let bytes = open.to_step_buffer_validated(true).unwrap();
+        assert!(!bytes.is_empty());
+
+        // A valid solid passes straight through without healing
+        assert!(cube.to_step_buffer_validated(false).is_ok());
+    }
+
+    #[test]
+    fn test_mesh_by_color_groups_triangles() {
+        // Cube faces in topology order: 0 = bottom (z=0), 1 = top (z=10)
+        let cube = Solid::cube(10.0, 10.0, 10.0)
+            .unwrap()
+            .with_face_color(0, "red")
+            .with_face_color(1, "blue");
+
+        let groups = cube.mesh_by_color(4);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].0, "default");
+        assert_eq!(groups[0].1.num_triangles(), 8);
+
+        let red = &groups.iter().find(|(c, _)| c == "red").unwrap().1;
+        assert_eq!(red.num_triangles(), 2);
+        assert!(red.vertices.chunks(3).all(|v| v[2].abs() < 1e-6));
+
+        let blue = &groups.iter().find(|(c, _)| c == "blue").unwrap().1;
+        assert_eq!(blue.num_triangles(), 2);
+        assert!(blue.vertices.chunks(3).all(|v| (v[2] - 10.0).abs() < 1e-6));
+
+        // Recoloring a face replaces its entry instead of stacking
+        let recolored = cube.with_face_color(0, "green");
+        let groups = recolored.mesh_by_color(4);
+        assert_eq!(groups.len(), 3);
+        assert!(groups.iter().any(|(c, _)| c == "green"));
+        assert!(!groups.iter().any(|(c, _)| c == "red"));
+
+        // An uncolored solid comes back as a single default group
+        let plain = Solid::cube(2.0, 2.0, 2.0).unwrap();
+        let groups = plain.mesh_by_color(4);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].1.num_triangles(), 12);
+    }
+
+    #[test]
+    fn test_remove_internal_faces_after_messy_union() {
+        use vcad_kernel_booleans::sew::sew_faces;
+        use vcad_kernel_primitives::make_cube;
+
+        // Simulate a union that missed its internal wall: sew ALL faces of
+        // two unit cubes sharing the x=1 plane, leaving both coincident
+        // wall faces inside the combined 2x1x1 volume
+        let a = make_cube(1.0, 1.0, 1.0);
+        let mut b = make_cube(1.0, 1.0, 1.0);
+        let shift = Transform::translation(1.0, 0.0, 0.0);
+        for (_, v) in &mut b.topology.vertices {
+            v.point = shift.apply_point(&v.point);
+        }
+        b.geometry.surfaces = b
+            .geometry
+            .surfaces
+            .drain(..)
+            .map(|s| s.transform(&shift))
+            .collect();
+
+        let faces_a: Vec<_> = a.topology.faces.keys().collect();
+        let faces_b: Vec<_> = b.topology.faces.keys().collect();
+        let messy = sew_faces(&a, &faces_a, &b, &faces_b, false, 1e-6);
+        assert_eq!(messy.topology.faces.len(), 12);
+
+        let solid = Solid {
+            repr: SolidRepr::BRep(Box::new(messy)),
+            segments: 16,
+            materials: Vec::new(),
+            face_colors: Vec::new(),
+            mesh_cache: RefCell::new(None),
+        };
+        // The internal wall shows up in the tessellation at x=1
+        let wall_tris = |mesh: &vcad_kernel_tessellate::TriangleMesh| {
+            mesh.indices
+                .chunks(3)
+                .filter(|tri| {
+                    tri.iter()
+                        .all(|&i| (mesh.vertices[i as usize * 3] - 1.0).abs() < 1e-6)
+                })
+                .count()
+        };
+        assert!(wall_tris(&solid.to_mesh(16)) > 0);
+
+        let cleaned = solid.remove_internal_faces(1e-3);
+        let brep = cleaned.brep().unwrap();
+        assert_eq!(brep.topology.faces.len(), 10);
+        assert_eq!(wall_tris(&cleaned.to_mesh(16)), 0);
+        assert!((cleaned.volume() - 2.0).abs() < 1e-6);
+
+        // A clean solid passes through untouched
+        let cube = Solid::cube(1.0, 1.0, 1.0).unwrap();
+        assert_eq!(
+            cube.remove_internal_faces(1e-3)
+                .brep()
+                .unwrap()
+                .topology
+                .faces
+                .len(),
+            6
+        );
+    }
 }
@@ -0,0 +1,229 @@
+//! Aggregate printability checks for FDM printing.
+//!
+//! Combines the individual pre-print analyses — bed fit, overhang
+//! detection, thin-wall probing, and trapped-cavity inspection — into one
+//! report with a pass/warn/fail status, so a viewer can run a single
+//! one-click check instead of calling each analysis separately. All
+//! geometry comes from the tessellated boundary; the part is checked in
+//! its current orientation, resting on the z = 0 bed.
+
+use vcad_kernel_math::{Point3, Vec3};
+use vcad_kernel_tessellate::TriangleMesh;
+
+use crate::Cavity;
+
+/// Thresholds for [`printability_report`](crate::Solid::printability_report).
+#[derive(Debug, Clone, Copy)]
+pub struct PrintabilitySettings {
+    /// Overhang angle threshold in degrees; downward faces steeper than
+    /// this need support (matching the slicer's support convention).
+    pub overhang_angle: f64,
+    /// Minimum printable wall thickness in mm.
+    pub min_wall_thickness: f64,
+}
+
+impl Default for PrintabilitySettings {
+    fn default() -> Self {
+        Self {
+            overhang_angle: 45.0,
+            min_wall_thickness: 0.8,
+        }
+    }
+}
+
+/// Overall verdict of a [`PrintabilityReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintabilityStatus {
+    /// No issues found.
+    Pass,
+    /// Printable, but overhangs, thin walls, or trapped cavities were found.
+    Warn,
+    /// The part does not fit the build volume.
+    Fail,
+}
+
+/// Aggregate pre-print report for one solid.
+#[derive(Debug, Clone)]
+pub struct PrintabilityReport {
+    /// Overall verdict.
+    pub status: PrintabilityStatus,
+    /// Whether the bounding box fits the build volume.
+    pub fits_bed: bool,
+    /// Bounding-box size of the part in mm.
+    pub size: [f64; 3],
+    /// Total area of downward faces steeper than the overhang threshold,
+    /// excluding faces resting on the bed, in mm².
+    pub overhang_area: f64,
+    /// Number of overhanging triangles.
+    pub overhang_triangles: usize,
+    /// Rough support volume: the prism under each overhanging triangle down
+    /// to the bed, in mm³.
+    pub support_volume: f64,
+    /// Total area of surface regions thinner than the minimum wall, in mm².
+    pub thin_wall_area: f64,
+    /// Number of triangles on walls thinner than the minimum.
+    pub thin_wall_triangles: usize,
+    /// Number of enclosed internal cavities that would trap material.
+    pub trapped_cavities: usize,
+    /// Total volume of those cavities in mm³.
+    pub trapped_cavity_volume: f64,
+}
+
+pub(crate) fn printability_report(
+    mesh: &TriangleMesh,
+    cavities: &[Cavity],
+    bed: [f64; 3],
+    settings: &PrintabilitySettings,
+) -> PrintabilityReport {
+    let triangles = collect_triangles(mesh);
+
+    let mut min = [f64::MAX; 3];
+    let mut max = [f64::MIN; 3];
+    for (a, b, c) in &triangles {
+        for p in [a, b, c] {
+            for (axis, value) in [p.x, p.y, p.z].into_iter().enumerate() {
+                min[axis] = min[axis].min(value);
+                max[axis] = max[axis].max(value);
+            }
+        }
+    }
+    let size = if triangles.is_empty() {
+        [0.0; 3]
+    } else {
+        [max[0] - min[0], max[1] - min[1], max[2] - min[2]]
+    };
+    let fits_bed = size[0] <= bed[0] && size[1] <= bed[1] && size[2] <= bed[2];
+
+    // Overhangs: downward faces steeper than the threshold that aren't
+    // resting on the bed, with a prism-to-bed support volume estimate
+    let threshold_cos = settings.overhang_angle.to_radians().cos();
+    let z_min = min[2];
+    let mut overhang_area = 0.0;
+    let mut overhang_triangles = 0;
+    let mut support_volume = 0.0;
+    for (a, b, c) in &triangles {
+        let n = (b - a).cross(&(c - a));
+        let twice_area = n.norm();
+        if twice_area < 1e-12 {
+            continue;
+        }
+        let nz = n.z / twice_area;
+        let lowest = a.z.min(b.z).min(c.z);
+        if nz < -threshold_cos && lowest - z_min > 1e-6 {
+            overhang_area += 0.5 * twice_area;
+            overhang_triangles += 1;
+            let projected = 0.5 * twice_area * nz.abs();
+            let mean_height = (a.z + b.z + c.z) / 3.0 - z_min;
+            support_volume += projected * mean_height;
+        }
+    }
+
+    // Thin walls: probe inward from each triangle's centroid and measure
+    // the distance to the opposite surface
+    let mut thin_wall_area = 0.0;
+    let mut thin_wall_triangles = 0;
+    for (i, (a, b, c)) in triangles.iter().enumerate() {
+        let n = (b - a).cross(&(c - a));
+        let norm = n.norm();
+        if norm < 1e-12 {
+            continue;
+        }
+        let inward = -n / norm;
+        let centroid = Point3::new(
+            (a.x + b.x + c.x) / 3.0,
+            (a.y + b.y + c.y) / 3.0,
+            (a.z + b.z + c.z) / 3.0,
+        );
+        if let Some(thickness) = nearest_hit(&triangles, i, &centroid, &inward) {
+            if thickness < settings.min_wall_thickness {
+                thin_wall_area += 0.5 * norm;
+                thin_wall_triangles += 1;
+            }
+        }
+    }
+
+    let trapped_cavities = cavities.len();
+    let trapped_cavity_volume = cavities.iter().map(|c| c.volume).sum();
+
+    let status = if !fits_bed {
+        PrintabilityStatus::Fail
+    } else if overhang_triangles > 0 || thin_wall_triangles > 0 || trapped_cavities > 0 {
+        PrintabilityStatus::Warn
+    } else {
+        PrintabilityStatus::Pass
+    };
+
+    PrintabilityReport {
+        status,
+        fits_bed,
+        size,
+        overhang_area,
+        overhang_triangles,
+        support_volume,
+        thin_wall_area,
+        thin_wall_triangles,
+        trapped_cavities,
+        trapped_cavity_volume,
+    }
+}
+
+fn collect_triangles(mesh: &TriangleMesh) -> Vec<(Point3, Point3, Point3)> {
+    let point = |i: u32| {
+        let i = i as usize * 3;
+        Point3::new(
+            mesh.vertices[i] as f64,
+            mesh.vertices[i + 1] as f64,
+            mesh.vertices[i + 2] as f64,
+        )
+    };
+    mesh.indices
+        .chunks(3)
+        .map(|tri| (point(tri[0]), point(tri[1]), point(tri[2])))
+        .collect()
+}
+
+/// Distance from `origin` along `dir` to the nearest triangle other than
+/// `skip`, ignoring grazing hits right at the origin.
+fn nearest_hit(
+    triangles: &[(Point3, Point3, Point3)],
+    skip: usize,
+    origin: &Point3,
+    dir: &Vec3,
+) -> Option<f64> {
+    let mut nearest: Option<f64> = None;
+    for (i, (a, b, c)) in triangles.iter().enumerate() {
+        if i == skip {
+            continue;
+        }
+        if let Some(t) = ray_triangle(origin, dir, a, b, c) {
+            if t > 1e-6 && nearest.is_none_or(|n| t < n) {
+                nearest = Some(t);
+            }
+        }
+    }
+    nearest
+}
+
+/// Möller–Trumbore ray/triangle intersection; returns the ray parameter.
+fn ray_triangle(origin: &Point3, dir: &Vec3, a: &Point3, b: &Point3, c: &Point3) -> Option<f64> {
+    let e1 = b - a;
+    let e2 = c - a;
+    let p = dir.cross(&e2);
+    let det = e1.dot(&p);
+    if det.abs() < 1e-12 {
+        return None;
+    }
+    let inv_det = 1.0 / det;
+    let s = origin - a;
+    let u = s.dot(&p) * inv_det;
+    if !(-1e-9..=1.0 + 1e-9).contains(&u) {
+        return None;
+    }
+    let q = s.cross(&e1);
+    let v = dir.dot(&q) * inv_det;
+    if v < -1e-9 || u + v > 1.0 + 1e-9 {
+        return None;
+    }
+    let t = e2.dot(&q) * inv_det;
+    (t > 0.0).then_some(t)
+}
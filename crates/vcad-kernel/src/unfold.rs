@@ -0,0 +1,302 @@
+//! Shell unfolding into a sheet-metal flat pattern.
+//!
+//! Extends single-face unfolding ([`crate::Solid::unfold_face`]) to whole
+//! connected shells: each developable face is flattened in its own surface
+//! coordinates, then chained into one plane by rigidly rotating it about the
+//! bend edge it shares with an already-placed neighbour. Shared edges between
+//! placed faces become bend lines; faces that cannot be developed (spheres,
+//! tori, freeform) are flagged and left out of the pattern.
+
+use std::collections::HashMap;
+
+use vcad_kernel_geom::{ConeSurface, CylinderSurface, Plane, SurfaceKind};
+use vcad_kernel_math::{Point2, Point3};
+use vcad_kernel_primitives::BRepSolid;
+use vcad_kernel_topo::{FaceId, VertexId};
+
+/// One face of a flattened shell pattern.
+#[derive(Debug, Clone)]
+pub struct UnfoldedFace {
+    /// Index of the source face in topology iteration order (as used by
+    /// `unfold_face`).
+    pub face_index: usize,
+    /// Face outline in the flattened plane, in outer-loop order.
+    pub outline: Vec<Point2>,
+}
+
+/// Flat pattern produced by [`crate::Solid::unfold_shell`].
+#[derive(Debug, Clone, Default)]
+pub struct ShellUnfold {
+    /// Unfolded faces, seed face first, then in traversal order.
+    pub faces: Vec<UnfoldedFace>,
+    /// Fold locations: the flattened shared edge of each pair of faces
+    /// joined during traversal.
+    pub bend_lines: Vec<[Point2; 2]>,
+    /// Indices of faces that are not developable and were left out.
+    pub non_developable: Vec<usize>,
+}
+
+pub(crate) fn unfold_shell(brep: &BRepSolid) -> ShellUnfold {
+    let topo = &brep.topology;
+
+    // Flatten every face in its own surface coordinates, keyed by loop order
+    let face_ids: Vec<FaceId> = topo.faces.keys().collect();
+    let index_of: HashMap<FaceId, usize> =
+        face_ids.iter().enumerate().map(|(i, &f)| (f, i)).collect();
+    let mut local: Vec<Option<(Vec<VertexId>, Vec<Point2>)>> = Vec::with_capacity(face_ids.len());
+    let mut non_developable = Vec::new();
+    for (i, &face_id) in face_ids.iter().enumerate() {
+        let flat = flatten_face(brep, face_id);
+        if flat.is_none() {
+            non_developable.push(i);
+        }
+        local.push(flat);
+    }
+
+    let Some(seed) = (0..face_ids.len()).find(|&i| local[i].is_some()) else {
+        return ShellUnfold {
+            non_developable,
+            ..Default::default()
+        };
+    };
+
+    // Breadth-first placement: the seed keeps its local coordinates, each
+    // neighbour is rotated about the shared bend edge into the pattern plane
+    let mut placed: HashMap<usize, HashMap<VertexId, Point2>> = HashMap::new();
+    let (seed_verts, seed_pts) = local[seed].as_ref().unwrap();
+    placed.insert(
+        seed,
+        seed_verts
+            .iter()
+            .copied()
+            .zip(seed_pts.iter().copied())
+            .collect(),
+    );
+
+    let mut faces = vec![UnfoldedFace {
+        face_index: seed,
+        outline: seed_pts.clone(),
+    }];
+    let mut bend_lines = Vec::new();
+    let mut queue = std::collections::VecDeque::from([seed]);
+
+    while let Some(cur) = queue.pop_front() {
+        let cur_loop = topo.faces[face_ids[cur]].outer_loop;
+        let neighbours: Vec<(usize, VertexId, VertexId)> = topo
+            .loop_half_edges(cur_loop)
+            .filter_map(|he| {
+                let twin = topo.half_edges[he].twin?;
+                let neighbour_loop = topo.half_edges[twin].loop_id?;
+                let neighbour = index_of[&topo.loops[neighbour_loop].face?];
+                (neighbour != cur).then(|| {
+                    (
+                        neighbour,
+                        topo.half_edges[he].origin,
+                        topo.half_edge_dest(he),
+                    )
+                })
+            })
+            .collect();
+
+        for (next, p, q) in neighbours {
+            if placed.contains_key(&next) {
+                continue;
+            }
+            let Some((next_verts, next_local)) = &local[next] else {
+                continue;
+            };
+            let cur_placed = &placed[&cur];
+            let (Some(&pp), Some(&pq)) = (cur_placed.get(&p), cur_placed.get(&q)) else {
+                continue;
+            };
+            let find = |v: VertexId| {
+                next_verts
+                    .iter()
+                    .position(|&w| w == v)
+                    .map(|i| next_local[i])
+            };
+            let (Some(lp), Some(lq)) = (find(p), find(q)) else {
+                continue;
+            };
+
+            // Keep the new face on the far side of the bend line from the
+            // face it unfolds away from
+            let cur_centroid = centroid(cur_placed.values());
+            let outline = place_across_edge(next_local, lp, lq, pp, pq, &cur_centroid);
+
+            placed.insert(
+                next,
+                next_verts
+                    .iter()
+                    .copied()
+                    .zip(outline.iter().copied())
+                    .collect(),
+            );
+            faces.push(UnfoldedFace {
+                face_index: next,
+                outline,
+            });
+            bend_lines.push([pp, pq]);
+            queue.push_back(next);
+        }
+    }
+
+    ShellUnfold {
+        faces,
+        bend_lines,
+        non_developable,
+    }
+}
+
+/// Flatten a face into its own surface coordinates, paired with the
+/// outer-loop vertices in order. `None` for non-developable surfaces.
+fn flatten_face(brep: &BRepSolid, face_id: FaceId) -> Option<(Vec<VertexId>, Vec<Point2>)> {
+    let topo = &brep.topology;
+    let face = &topo.faces[face_id];
+    let surface = &brep.geometry.surfaces[face.surface_index];
+    let verts = topo.loop_vertices(face.outer_loop);
+    if verts.is_empty() {
+        return None;
+    }
+    let points: Vec<Point3> = verts.iter().map(|&v| topo.vertices[v].point).collect();
+
+    let flat = match surface.surface_type() {
+        SurfaceKind::Plane => {
+            let plane = surface.as_any().downcast_ref::<Plane>()?;
+            points.iter().map(|p| plane.project(p)).collect()
+        }
+        SurfaceKind::Cylinder => {
+            let cyl = surface.as_any().downcast_ref::<CylinderSurface>()?;
+            let axis = cyl.axis.as_ref();
+            let y_ref = axis.cross(cyl.ref_dir.as_ref());
+            let thetas = continuous_angles(points.iter().map(|p| {
+                let d = p - cyl.center;
+                d.dot(&y_ref).atan2(d.dot(cyl.ref_dir.as_ref()))
+            }));
+            points
+                .iter()
+                .zip(thetas)
+                .map(|(p, theta)| Point2::new(theta * cyl.radius, (p - cyl.center).dot(axis)))
+                .collect()
+        }
+        SurfaceKind::Cone => {
+            let cone = surface.as_any().downcast_ref::<ConeSurface>()?;
+            let axis = cone.axis.as_ref();
+            let y_ref = axis.cross(cone.ref_dir.as_ref());
+            let sin_a = cone.half_angle.sin();
+            let thetas = continuous_angles(points.iter().map(|p| {
+                let d = p - cone.apex;
+                d.dot(&y_ref).atan2(d.dot(cone.ref_dir.as_ref()))
+            }));
+            points
+                .iter()
+                .zip(thetas)
+                .map(|(p, theta)| {
+                    let s = (p - cone.apex).norm();
+                    let phi = theta * sin_a;
+                    Point2::new(s * phi.cos(), s * phi.sin())
+                })
+                .collect()
+        }
+        _ => return None,
+    };
+    Some((verts, flat))
+}
+
+/// Unwrap a sequence of angles so consecutive values never jump across the
+/// ±π seam.
+fn continuous_angles(angles: impl Iterator<Item = f64>) -> Vec<f64> {
+    let mut out: Vec<f64> = Vec::new();
+    for mut a in angles {
+        if let Some(&prev) = out.last() {
+            while a - prev > std::f64::consts::PI {
+                a -= 2.0 * std::f64::consts::PI;
+            }
+            while prev - a > std::f64::consts::PI {
+                a += 2.0 * std::f64::consts::PI;
+            }
+        }
+        out.push(a);
+    }
+    out
+}
+
+/// Rigidly map `outline` so local edge (lp, lq) lands on placed edge
+/// (pp, pq), picking the handedness that puts the outline on the opposite
+/// side of that edge from `away_from`.
+fn place_across_edge(
+    outline: &[Point2],
+    lp: Point2,
+    lq: Point2,
+    pp: Point2,
+    pq: Point2,
+    away_from: &Point2,
+) -> Vec<Point2> {
+    let direct = rigid_map(outline, lp, lq, pp, pq, false);
+    let mirrored = rigid_map(outline, lp, lq, pp, pq, true);
+
+    let edge = pq - pp;
+    let side = |pt: &Point2| edge.x * (pt.y - pp.y) - edge.y * (pt.x - pp.x);
+    let away_side = side(away_from);
+    let direct_side = side(&centroid(direct.iter()));
+    if away_side * direct_side <= 0.0 {
+        direct
+    } else {
+        mirrored
+    }
+}
+
+/// Rotate + translate `outline` so (lp, lq) maps onto (pp, pq), optionally
+/// reflecting across the edge first.
+fn rigid_map(
+    outline: &[Point2],
+    lp: Point2,
+    lq: Point2,
+    pp: Point2,
+    pq: Point2,
+    mirror: bool,
+) -> Vec<Point2> {
+    let a = lq - lp;
+    let b = pq - pp;
+    let len_sq = a.norm_squared();
+    if len_sq < 1e-18 {
+        return outline.to_vec();
+    }
+    // Complex-style rotation aligning a with b (unit scale: both edges have
+    // the same 3D length on a developable surface)
+    let scale = (b.norm_squared() / len_sq).sqrt();
+    let (cos, sin) = {
+        let denom = a.norm() * b.norm();
+        (
+            (a.x * b.x + a.y * b.y) / denom,
+            (a.x * b.y - a.y * b.x) / denom,
+        )
+    };
+    outline
+        .iter()
+        .map(|pt| {
+            let mut d = pt - lp;
+            if mirror {
+                // Reflect across the local edge direction
+                let t = (d.x * a.x + d.y * a.y) / len_sq;
+                let foot = Point2::new(lp.x + t * a.x, lp.y + t * a.y);
+                d = Point2::new(2.0 * foot.x - pt.x, 2.0 * foot.y - pt.y) - lp;
+            }
+            Point2::new(
+                pp.x + scale * (cos * d.x - sin * d.y),
+                pp.y + scale * (sin * d.x + cos * d.y),
+            )
+        })
+        .collect()
+}
+
+fn centroid<'a>(points: impl Iterator<Item = &'a Point2>) -> Point2 {
+    let mut sum = Point2::new(0.0, 0.0);
+    let mut n = 0.0_f64;
+    for p in points {
+        sum.x += p.x;
+        sum.y += p.y;
+        n += 1.0;
+    }
+    Point2::new(sum.x / n.max(1.0), sum.y / n.max(1.0))
+}
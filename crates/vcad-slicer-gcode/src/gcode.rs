@@ -6,6 +6,31 @@ use vcad_slicer::{Polygon, Polyline, PrintLayer, SliceResult};
 
 use crate::printer::PrinterProfile;
 
+/// Angle (radians) at which [`SeamMode::Aligned`] places perimeter seams,
+/// measured about the polygon centroid. Rear-left, like common slicers, so
+/// the seam column faces away from the printer front.
+const ALIGNED_SEAM_ANGLE: f64 = 3.0 * std::f64::consts::FRAC_PI_4;
+
+/// Where each perimeter loop starts printing — the seam position.
+///
+/// Every loop's start/end point leaves a small blob; this controls where
+/// those blobs go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeamMode {
+    /// Start at the vertex nearest the nozzle's current position,
+    /// minimizing travel. Seams land wherever the previous path ended.
+    #[default]
+    Nearest,
+    /// Start at a fixed angular position about the loop centroid, stacking
+    /// seams into a single column that is easy to hide or post-process.
+    Aligned,
+    /// Scatter seams pseudo-randomly around each loop.
+    Random,
+    /// Start at the sharpest corner of the loop, where the blob is least
+    /// visible. Falls back to `Nearest` behavior on smooth loops.
+    SharpestCorner,
+}
+
 /// G-code generation settings.
 #[derive(Debug, Clone)]
 pub struct GcodeSettings {
@@ -31,6 +56,8 @@ pub struct GcodeSettings {
     pub fan_speed: u8,
     /// Layer at which to enable fan.
     pub fan_start_layer: usize,
+    /// Where each perimeter loop starts printing.
+    pub seam_position: SeamMode,
 }
 
 impl Default for GcodeSettings {
@@ -47,6 +74,7 @@ impl Default for GcodeSettings {
             fan_enabled: true,
             fan_speed: 255,
             fan_start_layer: 2,
+            seam_position: SeamMode::default(),
         }
     }
 }
@@ -61,6 +89,7 @@ pub struct GcodeGenerator {
     current_e: f64,
     current_f: f64,
     is_retracted: bool,
+    seam_rng: u64,
 }
 
 impl GcodeGenerator {
@@ -75,6 +104,7 @@ impl GcodeGenerator {
             current_e: 0.0,
             current_f: 0.0,
             is_retracted: false,
+            seam_rng: 0x9E37_79B9_7F4A_7C15,
         }
     }
 
@@ -176,6 +206,14 @@ impl GcodeGenerator {
             self.print_polyline(path, infill_speed, layer.layer_height);
         }
 
+        // Ironing passes: extrusion scales linearly with layer height, so a
+        // flow-scaled height yields the reduced ironing flow
+        if let Some(ironing) = &layer.ironing {
+            for path in &ironing.paths {
+                self.print_polyline(path, infill_speed, layer.layer_height * ironing.flow);
+            }
+        }
+
         // Print support
         if let Some(support) = &layer.support {
             for region in support {
@@ -189,16 +227,19 @@ impl GcodeGenerator {
             return;
         }
 
-        // Travel to start
-        let start = &polygon.points[0];
+        // Travel to the seam position
+        let n = polygon.points.len();
+        let seam = self.seam_index(polygon);
+        let start = &polygon.points[seam];
         self.travel_to(start.x, start.y);
 
         // Unretract
         self.unretract();
 
-        // Print polygon
+        // Print polygon from the seam around
         let feedrate = speed * 60.0; // mm/s to mm/min
-        for point in polygon.points.iter().skip(1) {
+        for i in 1..n {
+            let point = &polygon.points[(seam + i) % n];
             self.extrude_to(point.x, point.y, feedrate, layer_height);
         }
 
@@ -209,6 +250,54 @@ impl GcodeGenerator {
         self.retract();
     }
 
+    /// Pick the vertex where this loop starts printing, per the configured
+    /// seam mode.
+    fn seam_index(&mut self, polygon: &Polygon) -> usize {
+        let points = &polygon.points;
+        let n = points.len();
+        match self.settings.seam_position {
+            SeamMode::Nearest => index_of_min(points.iter().map(|p| {
+                let dx = p.x - self.current_x;
+                let dy = p.y - self.current_y;
+                dx * dx + dy * dy
+            })),
+            SeamMode::Aligned => {
+                let cx = points.iter().map(|p| p.x).sum::<f64>() / n as f64;
+                let cy = points.iter().map(|p| p.y).sum::<f64>() / n as f64;
+                index_of_min(points.iter().map(|p| {
+                    let angle = (p.y - cy).atan2(p.x - cx);
+                    let mut delta = (angle - ALIGNED_SEAM_ANGLE).abs();
+                    if delta > std::f64::consts::PI {
+                        delta = 2.0 * std::f64::consts::PI - delta;
+                    }
+                    delta
+                }))
+            }
+            SeamMode::Random => {
+                // xorshift64 — deterministic scatter with no RNG dependency
+                self.seam_rng ^= self.seam_rng << 13;
+                self.seam_rng ^= self.seam_rng >> 7;
+                self.seam_rng ^= self.seam_rng << 17;
+                (self.seam_rng % n as u64) as usize
+            }
+            SeamMode::SharpestCorner => index_of_min((0..n).map(|i| {
+                let prev = &points[(i + n - 1) % n];
+                let cur = &points[i];
+                let next = &points[(i + 1) % n];
+                let (ax, ay) = (cur.x - prev.x, cur.y - prev.y);
+                let (bx, by) = (next.x - cur.x, next.y - cur.y);
+                let len = (ax * ax + ay * ay).sqrt() * (bx * bx + by * by).sqrt();
+                if len < 1e-12 {
+                    // Degenerate corner: rank last
+                    return f64::MAX;
+                }
+                // Straighter corners have cosine near 1; the sharpest turn
+                // (smallest interior angle) minimizes this
+                (ax * bx + ay * by) / len
+            })),
+        }
+    }
+
     fn print_polyline(&mut self, polyline: &Polyline, speed: f64, layer_height: f64) {
         if polyline.points.is_empty() {
             return;
@@ -298,11 +387,7 @@ impl GcodeGenerator {
             );
             self.current_f = feedrate;
         } else {
-            let _ = writeln!(
-                self.output,
-                "G1 X{:.3} Y{:.3} E{:.5}",
-                x, y, self.current_e
-            );
+            let _ = writeln!(self.output, "G1 X{:.3} Y{:.3} E{:.5}", x, y, self.current_e);
         }
 
         self.current_x = x;
@@ -346,6 +431,15 @@ impl GcodeGenerator {
     }
 }
 
+/// Index of the smallest value in a non-empty sequence.
+fn index_of_min(values: impl Iterator<Item = f64>) -> usize {
+    values
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
 /// Generate G-code from slice result.
 pub fn generate_gcode(result: &SliceResult, settings: GcodeSettings) -> String {
     let mut generator = GcodeGenerator::new(settings);
@@ -355,6 +449,7 @@ pub fn generate_gcode(result: &SliceResult, settings: GcodeSettings) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use vcad_slicer::{slice, SliceSettings};
 
     #[test]
     fn test_gcode_header() {
@@ -363,4 +458,104 @@ mod tests {
         gen.write_header();
         assert!(gen.output.contains("vcad-slicer"));
     }
+
+    fn make_cylinder_mesh(
+        cx: f32,
+        cy: f32,
+        radius: f32,
+        height: f32,
+        segments: u32,
+    ) -> vcad_kernel_tessellate::TriangleMesh {
+        let mut vertices = Vec::new();
+        for z in [0.0, height] {
+            for i in 0..segments {
+                let a = 2.0 * std::f32::consts::PI * i as f32 / segments as f32;
+                vertices.extend([cx + radius * a.cos(), cy + radius * a.sin(), z]);
+            }
+        }
+        let bottom_center = vertices.len() as u32 / 3;
+        vertices.extend([cx, cy, 0.0]);
+        let top_center = bottom_center + 1;
+        vertices.extend([cx, cy, height]);
+
+        let mut indices = Vec::new();
+        for i in 0..segments {
+            let j = (i + 1) % segments;
+            // Wall quad
+            indices.extend([i, j, segments + j]);
+            indices.extend([i, segments + j, segments + i]);
+            // Cap fans
+            indices.extend([bottom_center, j, i]);
+            indices.extend([top_center, segments + i, segments + j]);
+        }
+        vcad_kernel_tessellate::TriangleMesh {
+            vertices,
+            indices,
+            normals: Vec::new(),
+        }
+    }
+
+    /// The XY position each layer's first perimeter starts extruding from.
+    fn perimeter_starts(gcode: &str) -> Vec<(f64, f64)> {
+        let axis = |line: &str, name: char| {
+            line.split_whitespace()
+                .find(|w| w.starts_with(name))
+                .and_then(|w| w[1..].parse::<f64>().ok())
+        };
+        let mut starts = Vec::new();
+        let mut cur = (0.0, 0.0);
+        let mut awaiting_first_extrude = false;
+        for line in gcode.lines() {
+            if line.contains("; Layer") {
+                awaiting_first_extrude = true;
+            }
+            if !line.starts_with("G1") {
+                continue;
+            }
+            if let (Some(x), Some(y)) = (axis(line, 'X'), axis(line, 'Y')) {
+                if awaiting_first_extrude && axis(line, 'E').is_some() {
+                    // First extrusion of the layer: the seam is wherever the
+                    // nozzle was before this move.
+                    starts.push(cur);
+                    awaiting_first_extrude = false;
+                }
+                cur = (x, y);
+            }
+        }
+        starts
+    }
+
+    #[test]
+    fn test_aligned_seam_stacks_layer_starts() {
+        let mesh = make_cylinder_mesh(10.0, 10.0, 5.0, 3.0, 36);
+        let slice_settings = SliceSettings {
+            layer_height: 0.5,
+            first_layer_height: 0.5,
+            wall_count: 1,
+            infill_density: 0.05,
+            ..Default::default()
+        };
+        let result = slice(&mesh, &slice_settings).unwrap();
+
+        let gcode_settings = GcodeSettings {
+            seam_position: SeamMode::Aligned,
+            ..Default::default()
+        };
+        let gcode = generate_gcode(&result, gcode_settings);
+
+        let starts = perimeter_starts(&gcode);
+        assert!(starts.len() > 3, "expected several layers");
+        let angles: Vec<f64> = starts
+            .iter()
+            .map(|(x, y)| (y - 10.0).atan2(x - 10.0))
+            .collect();
+        // Every layer's seam sits within one 10° contour-vertex spacing of
+        // the fixed aligned angle, stacking into a single column
+        for a in &angles {
+            assert!(
+                (a - ALIGNED_SEAM_ANGLE).abs() < 0.18,
+                "seam at {a}, expected ~{ALIGNED_SEAM_ANGLE}"
+            );
+        }
+    }
 }
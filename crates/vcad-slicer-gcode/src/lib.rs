@@ -29,5 +29,5 @@ pub mod gcode;
 pub mod printer;
 
 pub use flavor::GcodeFlavor;
-pub use gcode::{generate_gcode, GcodeGenerator, GcodeSettings};
+pub use gcode::{generate_gcode, GcodeGenerator, GcodeSettings, SeamMode};
 pub use printer::PrinterProfile;
@@ -92,6 +92,7 @@ impl From<WasmSliceSettings> for SliceSettings {
             },
             support_enabled: settings.support_enabled,
             support_angle: settings.support_angle,
+            ..Self::default()
         }
     }
 }
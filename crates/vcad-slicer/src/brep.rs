@@ -0,0 +1,409 @@
+//! Direct B-rep slicing — section faces analytically instead of a mesh.
+//!
+//! [`slice`](crate::slice()) tessellates the whole solid into one triangle
+//! mesh before slicing; on large models that intermediate copy dominates
+//! memory, and the facets show up as chord error in every contour. This
+//! module walks the faces one at a time instead: full vertical cylinder
+//! bands contribute exact circles (contour vertices on the true radius),
+//! planar faces slice exactly through their triangulation, and the
+//! remaining curved faces fall back to per-face tessellation whose
+//! triangles are kept only long enough to extract section segments.
+
+use std::f64::consts::{PI, TAU};
+
+use rayon::prelude::*;
+use vcad_kernel_geom::{CylinderSurface, Surface, SurfaceKind};
+use vcad_kernel_math::Point2;
+use vcad_kernel_primitives::BRepSolid;
+use vcad_kernel_tessellate::{tessellate_face, tessellate_solid, TessellationParams};
+use vcad_kernel_topo::{FaceId, Orientation, Topology};
+
+use crate::error::{Result, SlicerError};
+use crate::path::Polygon;
+use crate::slice::{
+    chain_segments, extract_triangles, generate_layer_heights, triangles_section_segments,
+    SliceLayer, Triangle,
+};
+use crate::support::{detect_overhangs, SupportSettings};
+use crate::{assemble_result, SliceResult, SliceSettings};
+
+/// How one face contributes to horizontal cross-sections.
+enum FaceSection {
+    /// An untrimmed vertical cylinder band: every interior Z cuts it in an
+    /// exact circle.
+    Circle {
+        /// Circle center in the XY plane (the axis, which is vertical).
+        center: Point2,
+        /// True cylinder radius.
+        radius: f64,
+        /// Height of the lower rim.
+        z_min: f64,
+        /// Height of the upper rim.
+        z_max: f64,
+        /// Reversed faces bound bores, which wind clockwise as holes.
+        hole: bool,
+    },
+    /// Anything else, sectioned through the face's own tessellation
+    /// (exact for planar faces, approximate for other curved surfaces).
+    Triangles(Vec<Triangle>),
+}
+
+/// Slice a B-rep solid at the given Z heights without building a
+/// whole-solid mesh.
+///
+/// Faces are sectioned independently: vertical cylinder bands yield exact
+/// circles, planar faces slice exactly, and other curved faces fall back
+/// to per-face tessellation at `params` resolution. Returns layers in the
+/// same order as `layer_heights`.
+pub fn slice_brep(
+    solid: &BRepSolid,
+    layer_heights: &[f64],
+    params: &TessellationParams,
+) -> Result<Vec<SliceLayer>> {
+    let sections = face_sections(solid, params);
+    if sections.is_empty() {
+        return Err(SlicerError::EmptyMesh);
+    }
+    Ok(slice_sections(&sections, layer_heights))
+}
+
+/// Slice a B-rep solid end to end, producing toolpaths.
+///
+/// The direct-B-rep analog of [`slice`](crate::slice()): contours come
+/// from [`slice_brep`] rather than a whole-solid mesh. Support detection
+/// (when enabled) still tessellates the solid at `params` resolution,
+/// since overhang analysis works on triangle normals; the contours stay
+/// exact either way.
+pub fn slice_solid(
+    solid: &BRepSolid,
+    settings: &SliceSettings,
+    params: &TessellationParams,
+) -> Result<SliceResult> {
+    settings.validate()?;
+
+    let sections = face_sections(solid, params);
+    let (bounds_min, bounds_max) = section_bounds(&sections).ok_or(SlicerError::EmptyMesh)?;
+
+    let layer_heights = generate_layer_heights(
+        bounds_min[2],
+        bounds_max[2],
+        settings.first_layer_height,
+        settings.layer_height,
+    );
+    if layer_heights.is_empty() {
+        return Err(SlicerError::SliceFailed("model too thin to slice".into()));
+    }
+
+    let mut slice_layers = slice_sections(&sections, &layer_heights);
+    if let Some(tolerance) = settings.contour_smoothing {
+        crate::smooth::smooth_layers(&mut slice_layers, tolerance);
+    }
+
+    let support_layers = if settings.support_enabled {
+        let mesh = tessellate_solid(solid, params, None);
+        let support_settings = SupportSettings {
+            overhang_angle: settings.support_angle,
+            density: 0.15,
+            ..Default::default()
+        };
+        Some(detect_overhangs(&mesh, &slice_layers, &support_settings))
+    } else {
+        None
+    };
+
+    Ok(assemble_result(
+        &slice_layers,
+        support_layers,
+        settings,
+        bounds_min,
+        bounds_max,
+    ))
+}
+
+/// Classify each shell face of the solid into its section representation.
+fn face_sections(solid: &BRepSolid, params: &TessellationParams) -> Vec<FaceSection> {
+    let topo = &solid.topology;
+    let solid_entity = &topo.solids[solid.solid_id];
+    let mut faces: Vec<FaceId> = topo.shells[solid_entity.outer_shell].faces.clone();
+    for &shell_id in &solid_entity.void_shells {
+        faces.extend(&topo.shells[shell_id].faces);
+    }
+
+    let mut sections = Vec::with_capacity(faces.len());
+    for face_id in faces {
+        let face = &topo.faces[face_id];
+        let surface = &solid.geometry.surfaces[face.surface_index];
+
+        // Horizontal planar faces (caps) never cross an interior layer
+        // plane transversally; they contribute no perimeter.
+        if surface.surface_type() == SurfaceKind::Plane {
+            let normal = surface.normal(Point2::new(0.0, 0.0));
+            if normal.as_ref().z.abs() > 1.0 - 1e-9 {
+                continue;
+            }
+        }
+
+        if let Some(circle) = full_vertical_band(topo, surface.as_ref(), face_id) {
+            sections.push(circle);
+            continue;
+        }
+
+        let mesh = tessellate_face(topo, &solid.geometry, face_id, params);
+        if let Ok(triangles) = extract_triangles(&mesh) {
+            if !triangles.is_empty() {
+                sections.push(FaceSection::Triangles(triangles));
+            }
+        }
+    }
+    sections
+}
+
+/// Recognize a cylindrical face that wraps the full circumference between
+/// two flat rims, with a vertical axis.
+///
+/// Full-wrap detection mirrors the tessellator: the loop either carries
+/// both halves of a seam edge, or all boundary vertices sit at a single
+/// angle. Oblique bands from boolean splits (rims not at constant height)
+/// and trimmed arcs fall back to the sampled path.
+fn full_vertical_band(
+    topo: &Topology,
+    surface: &dyn Surface,
+    face_id: FaceId,
+) -> Option<FaceSection> {
+    let face = &topo.faces[face_id];
+    let cyl = surface.as_any().downcast_ref::<CylinderSurface>()?;
+    if cyl.axis.as_ref().z.abs() < 1.0 - 1e-9 {
+        return None;
+    }
+    if !face.inner_loops.is_empty() {
+        return None;
+    }
+
+    let loop_hes: Vec<_> = topo.loop_half_edges(face.outer_loop).collect();
+    let verts: Vec<_> = loop_hes
+        .iter()
+        .map(|&he| topo.vertices[topo.half_edges[he].origin].point)
+        .collect();
+    if verts.is_empty() {
+        return None;
+    }
+
+    let has_seam = loop_hes.iter().any(|&he| {
+        topo.half_edges[he]
+            .twin
+            .is_some_and(|t| loop_hes.contains(&t))
+    });
+    if !has_seam {
+        // All vertices must share one angle (a seamless pair of rim circles)
+        let ref_dir = cyl.ref_dir.as_ref();
+        let y_dir = cyl.axis.as_ref().cross(ref_dir);
+        let mut first_angle = None;
+        for pt in &verts {
+            let d = pt - cyl.center;
+            let u = d.dot(&y_dir).atan2(d.dot(ref_dir));
+            match first_angle {
+                None => first_angle = Some(u),
+                Some(a) if (a - u).abs() > 0.01 => return None,
+                Some(_) => {}
+            }
+        }
+    }
+
+    let z_min = verts.iter().map(|p| p.z).fold(f64::MAX, f64::min);
+    let z_max = verts.iter().map(|p| p.z).fold(f64::MIN, f64::max);
+    if z_max - z_min < 1e-9 {
+        return None;
+    }
+    // Flat rims only: every boundary vertex on one of the two rim circles
+    if verts
+        .iter()
+        .any(|p| p.z - z_min > 1e-6 && z_max - p.z > 1e-6)
+    {
+        return None;
+    }
+
+    Some(FaceSection::Circle {
+        center: Point2::new(cyl.center.x, cyl.center.y),
+        radius: cyl.radius.abs(),
+        z_min,
+        z_max,
+        hole: face.orientation == Orientation::Reversed,
+    })
+}
+
+/// Slice classified face sections at each Z height in parallel.
+fn slice_sections(sections: &[FaceSection], layer_heights: &[f64]) -> Vec<SliceLayer> {
+    layer_heights
+        .par_iter()
+        .enumerate()
+        .map(|(index, &z)| {
+            let mut contours: Vec<Polygon> = Vec::new();
+            let mut segments: Vec<([f64; 2], [f64; 2])> = Vec::new();
+            for section in sections {
+                match section {
+                    FaceSection::Circle {
+                        center,
+                        radius,
+                        z_min,
+                        z_max,
+                        hole,
+                    } => {
+                        if z > z_min + 1e-9 && z < z_max - 1e-9 {
+                            contours.push(circle_polygon(*center, *radius, *hole));
+                        }
+                    }
+                    FaceSection::Triangles(triangles) => {
+                        segments.extend(triangles_section_segments(triangles, z));
+                    }
+                }
+            }
+            contours.extend(chain_segments(segments));
+            // Keep the outer-contours-first order chain_segments establishes
+            contours.sort_by(|a, b| {
+                b.signed_area()
+                    .abs()
+                    .partial_cmp(&a.signed_area().abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            SliceLayer { z, index, contours }
+        })
+        .collect()
+}
+
+/// Sample an exact circle into a polygon whose vertices all lie on the
+/// true radius. The segment count comes from a 1µm chord-sag bound, so
+/// the perimeter error stays far below any printable tolerance.
+fn circle_polygon(center: Point2, radius: f64, hole: bool) -> Polygon {
+    const SAG: f64 = 1e-3;
+    let n = if radius <= SAG {
+        16
+    } else {
+        (PI / (1.0 - SAG / radius).acos()).ceil() as usize
+    }
+    .clamp(16, 1024);
+
+    let mut points = Vec::with_capacity(n);
+    for i in 0..n {
+        let t = TAU * i as f64 / n as f64;
+        points.push(Point2::new(
+            center.x + radius * t.cos(),
+            center.y + radius * t.sin(),
+        ));
+    }
+    if hole {
+        // Holes wind clockwise, matching the mesh slicer's convention
+        points.reverse();
+    }
+    Polygon::new(points)
+}
+
+/// Bounding box over the classified sections.
+///
+/// Circle bands contribute their full swept extent (a seam vertex alone
+/// would understate the XY bounds); triangle sections contribute their
+/// vertices, which is exact for planar faces.
+fn section_bounds(sections: &[FaceSection]) -> Option<([f64; 3], [f64; 3])> {
+    let mut min = [f64::MAX; 3];
+    let mut max = [f64::MIN; 3];
+    let mut any = false;
+
+    let mut include = |p: [f64; 3]| {
+        for i in 0..3 {
+            min[i] = min[i].min(p[i]);
+            max[i] = max[i].max(p[i]);
+        }
+    };
+
+    for section in sections {
+        match section {
+            FaceSection::Circle {
+                center,
+                radius,
+                z_min,
+                z_max,
+                ..
+            } => {
+                include([center.x - radius, center.y - radius, *z_min]);
+                include([center.x + radius, center.y + radius, *z_max]);
+                any = true;
+            }
+            FaceSection::Triangles(triangles) => {
+                for tri in triangles {
+                    include(tri.v0);
+                    include(tri.v1);
+                    include(tri.v2);
+                    any = true;
+                }
+            }
+        }
+    }
+
+    any.then_some((min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vcad_kernel_primitives::{make_cube, make_cylinder};
+
+    #[test]
+    fn test_slice_brep_cylinder_true_circle() {
+        let cyl = make_cylinder(5.0, 10.0, 32);
+        let layers = slice_brep(&cyl, &[2.0, 5.0, 8.0], &TessellationParams::default()).unwrap();
+        assert_eq!(layers.len(), 3);
+
+        // Perimeter of the 32-segment mesh polygon the old path would give
+        let faceted = 2.0 * 32.0 * 5.0 * (PI / 32.0).sin();
+        let true_perimeter = TAU * 5.0;
+
+        for layer in &layers {
+            assert_eq!(layer.contours.len(), 1, "layer at z={}", layer.z);
+            let contour = &layer.contours[0];
+            assert!(contour.is_ccw());
+            // Every vertex on the true radius — not chord-shrunk facets
+            for pt in &contour.points {
+                let r = (pt.x * pt.x + pt.y * pt.y).sqrt();
+                assert!((r - 5.0).abs() < 1e-9, "vertex at radius {r}");
+            }
+            let perimeter = contour.perimeter();
+            assert!(
+                perimeter > faceted + 0.01 && perimeter <= true_perimeter + 1e-9,
+                "perimeter {perimeter} vs faceted {faceted}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_slice_brep_cube_exact_square() {
+        let cube = make_cube(10.0, 10.0, 10.0);
+        let layers = slice_brep(&cube, &[5.0], &TessellationParams::default()).unwrap();
+        assert_eq!(layers[0].contours.len(), 1);
+        let contour = &layers[0].contours[0];
+        assert!((contour.perimeter() - 40.0).abs() < 1e-9);
+        assert!((contour.signed_area().abs() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_slice_solid_cylinder_pipeline() {
+        let cyl = make_cylinder(5.0, 10.0, 32);
+        let settings = SliceSettings {
+            layer_height: 0.5,
+            first_layer_height: 0.5,
+            infill_density: 0.05,
+            wall_count: 1,
+            ..Default::default()
+        };
+        let result = slice_solid(&cyl, &settings, &TessellationParams::default()).unwrap();
+        assert!(!result.layers.is_empty());
+        assert_eq!(result.stats.layer_count, result.layers.len());
+        // Bounds cover the full swept circle, not just the seam vertex
+        assert!(result.stats.bounds_min[0] < -4.9 && result.stats.bounds_max[0] > 4.9);
+        for layer in &result.layers {
+            assert!(
+                !layer.outer_perimeters.is_empty(),
+                "layer {} has no perimeter",
+                layer.index
+            );
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Ironing (top-surface smoothing) pass generation.
+//!
+//! Ironing re-traces exposed top surfaces with closely-spaced, very low flow
+//! passes so the hot nozzle flattens the ridges left by normal infill. A
+//! surface counts as "top" where the layer's solid region is not covered by
+//! the layer above.
+
+use serde::{Deserialize, Serialize};
+
+use crate::infill::generate_parallel_lines;
+use crate::path::{Polygon, Polyline};
+use crate::perimeter::point_in_polygon;
+use crate::slice::SliceLayer;
+
+/// Settings for the ironing pass.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct IroningSettings {
+    /// Extrusion flow factor relative to normal printing (0.0 to 1.0).
+    /// Ironing deposits just enough material to fill surface ridges.
+    pub flow: f64,
+    /// Spacing between ironing passes (mm). Much tighter than the line
+    /// width so passes overlap.
+    pub spacing: f64,
+}
+
+impl Default for IroningSettings {
+    fn default() -> Self {
+        Self {
+            flow: 0.1,
+            spacing: 0.1,
+        }
+    }
+}
+
+/// Ironing paths for one layer, plus the flow factor to print them with.
+#[derive(Debug, Clone)]
+pub struct LayerIroning {
+    /// Extrusion flow factor relative to normal printing.
+    pub flow: f64,
+    /// Smoothing passes over the exposed top region.
+    pub paths: Vec<Polyline>,
+}
+
+/// Generate ironing passes for a layer's infill region.
+///
+/// `boundary` is the innermost perimeter region of the layer and
+/// `layer_above` the sliced contours one layer up (`None` for the topmost
+/// layer). Passes are kept only where the layer above does not cover them,
+/// judged by each pass segment's midpoint — partial coverage at region edges
+/// is resolved at the granularity of whole segments.
+pub fn generate_ironing(
+    boundary: &[Polygon],
+    layer_above: Option<&SliceLayer>,
+    settings: &IroningSettings,
+) -> Vec<Polyline> {
+    if boundary.is_empty() || settings.spacing <= 0.0 {
+        return Vec::new();
+    }
+
+    let lines = generate_parallel_lines(boundary, settings.spacing, 0.0);
+    let Some(above) = layer_above else {
+        // Topmost layer: everything is exposed
+        return lines.paths;
+    };
+
+    lines
+        .paths
+        .into_iter()
+        .filter(|path| {
+            let [p0, p1] = [&path.points[0], &path.points[path.points.len() - 1]];
+            let mid = vcad_kernel_math::Point2::new((p0.x + p1.x) / 2.0, (p0.y + p1.y) / 2.0);
+            !covered_by(&mid, &above.contours)
+        })
+        .collect()
+}
+
+/// Whether a point lies in the solid region described by `contours`
+/// (inside an outer CCW contour and outside every CW hole).
+pub(crate) fn covered_by(point: &vcad_kernel_math::Point2, contours: &[Polygon]) -> bool {
+    let mut inside_outer = false;
+    for poly in contours {
+        if point_in_polygon(point, poly) {
+            if poly.is_ccw() {
+                inside_outer = true;
+            } else {
+                return false;
+            }
+        }
+    }
+    inside_outer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vcad_kernel_math::Point2;
+
+    fn square(size: f64) -> Polygon {
+        Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(size, 0.0),
+            Point2::new(size, size),
+            Point2::new(0.0, size),
+        ])
+    }
+
+    #[test]
+    fn test_topmost_layer_fully_ironed() {
+        let paths = generate_ironing(&[square(10.0)], None, &IroningSettings::default());
+        assert!(
+            paths.len() > 50,
+            "expected dense passes, got {}",
+            paths.len()
+        );
+    }
+
+    #[test]
+    fn test_covered_layer_not_ironed() {
+        let above = SliceLayer {
+            z: 1.0,
+            index: 1,
+            contours: vec![square(10.0)],
+        };
+        let paths = generate_ironing(&[square(10.0)], Some(&above), &IroningSettings::default());
+        assert!(
+            paths.is_empty(),
+            "covered region grew {} passes",
+            paths.len()
+        );
+    }
+}
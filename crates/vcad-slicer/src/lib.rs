@@ -19,18 +19,24 @@
 //! println!("Print time: {:.0}s", result.stats.print_time_seconds);
 //! ```
 
+pub mod brep;
 pub mod error;
 pub mod infill;
+pub mod ironing;
 pub mod path;
 pub mod perimeter;
 pub mod slice;
+pub mod smooth;
 pub mod support;
 
+pub use brep::{slice_brep, slice_solid};
 pub use error::{Result, SlicerError};
 pub use infill::{generate_infill, InfillPattern, InfillResult, InfillSettings};
+pub use ironing::{generate_ironing, IroningSettings, LayerIroning};
 pub use path::{Polygon, Polyline};
 pub use perimeter::{generate_perimeters, LayerPerimeters, PerimeterSettings};
 pub use slice::{generate_layer_heights, mesh_bounds, slice_mesh, SliceLayer};
+pub use smooth::{smooth_layers, smooth_polygon};
 pub use support::{detect_overhangs, LayerSupport, SupportSettings};
 
 use serde::{Deserialize, Serialize};
@@ -57,6 +63,26 @@ pub struct SliceSettings {
     pub support_enabled: bool,
     /// Support overhang angle threshold (degrees).
     pub support_angle: f64,
+    /// Number of solid layers under exposed top surfaces.
+    #[serde(default = "default_shell_layers")]
+    pub top_layers: u32,
+    /// Number of solid layers above exposed bottom surfaces.
+    #[serde(default = "default_shell_layers")]
+    pub bottom_layers: u32,
+    /// Ironing pass over exposed top surfaces (disabled when `None`).
+    #[serde(default)]
+    pub ironing: Option<IroningSettings>,
+    /// Fit smooth contours to layer perimeters before wall generation,
+    /// within this tolerance in mm (disabled when `None`). Reduces the
+    /// faceting a coarse mesh leaves in every contour.
+    #[serde(default)]
+    pub contour_smoothing: Option<f64>,
+}
+
+/// Serde fallback for [`SliceSettings::top_layers`] / `bottom_layers` on
+/// settings payloads that predate them.
+fn default_shell_layers() -> u32 {
+    3
 }
 
 impl Default for SliceSettings {
@@ -71,6 +97,10 @@ impl Default for SliceSettings {
             infill_pattern: InfillPattern::Grid,
             support_enabled: false,
             support_angle: 45.0,
+            top_layers: 3,
+            bottom_layers: 3,
+            ironing: None,
+            contour_smoothing: None,
         }
     }
 }
@@ -98,6 +128,25 @@ impl SliceSettings {
                 "infill_density must be between 0 and 1".into(),
             ));
         }
+        if let Some(tol) = self.contour_smoothing {
+            if tol <= 0.0 {
+                return Err(SlicerError::InvalidSettings(
+                    "contour_smoothing tolerance must be positive".into(),
+                ));
+            }
+        }
+        if let Some(ironing) = &self.ironing {
+            if ironing.flow <= 0.0 || ironing.flow > 1.0 {
+                return Err(SlicerError::InvalidSettings(
+                    "ironing flow must be between 0 and 1".into(),
+                ));
+            }
+            if ironing.spacing <= 0.0 {
+                return Err(SlicerError::InvalidSettings(
+                    "ironing spacing must be positive".into(),
+                ));
+            }
+        }
         Ok(())
     }
 }
@@ -117,6 +166,8 @@ pub struct PrintLayer {
     pub inner_perimeters: Vec<Polygon>,
     /// Infill paths.
     pub infill: Vec<Polyline>,
+    /// Ironing passes over exposed top surfaces (if enabled).
+    pub ironing: Option<LayerIroning>,
     /// Support structures (if enabled).
     pub support: Option<Vec<Polygon>>,
 }
@@ -159,8 +210,7 @@ pub fn slice(mesh: &TriangleMesh, settings: &SliceSettings) -> Result<SliceResul
     settings.validate()?;
 
     // Get mesh bounds
-    let (bounds_min, bounds_max) =
-        mesh_bounds(mesh).ok_or(SlicerError::EmptyMesh)?;
+    let (bounds_min, bounds_max) = mesh_bounds(mesh).ok_or(SlicerError::EmptyMesh)?;
 
     // Generate layer heights
     let layer_heights = generate_layer_heights(
@@ -175,7 +225,10 @@ pub fn slice(mesh: &TriangleMesh, settings: &SliceSettings) -> Result<SliceResul
     }
 
     // Slice mesh
-    let slice_layers = slice_mesh(mesh, &layer_heights)?;
+    let mut slice_layers = slice_mesh(mesh, &layer_heights)?;
+    if let Some(tolerance) = settings.contour_smoothing {
+        smooth_layers(&mut slice_layers, tolerance);
+    }
 
     // Detect support if enabled
     let support_layers = if settings.support_enabled {
@@ -189,6 +242,26 @@ pub fn slice(mesh: &TriangleMesh, settings: &SliceSettings) -> Result<SliceResul
         None
     };
 
+    Ok(assemble_result(
+        &slice_layers,
+        support_layers,
+        settings,
+        bounds_min,
+        bounds_max,
+    ))
+}
+
+/// Turn sliced contours into print layers with toolpaths and statistics.
+///
+/// Shared tail of the pipeline between [`slice`] (mesh contours) and
+/// [`slice_solid`] (direct B-rep contours).
+fn assemble_result(
+    slice_layers: &[SliceLayer],
+    support_layers: Option<Vec<LayerSupport>>,
+    settings: &SliceSettings,
+    bounds_min: [f64; 3],
+    bounds_max: [f64; 3],
+) -> SliceResult {
     // Process each layer
     let perimeter_settings = PerimeterSettings {
         wall_count: settings.wall_count,
@@ -210,14 +283,31 @@ pub fn slice(mesh: &TriangleMesh, settings: &SliceSettings) -> Result<SliceResul
         let perimeters = generate_perimeters(slice_layer, &perimeter_settings);
 
         // Generate infill
+        // Top/bottom shell layers get 100% solid infill; only the interior
+        // uses the sparse pattern
+        let solid_shell = is_shell_layer(idx, &perimeters.infill_boundary, slice_layers, settings);
         let infill_settings = InfillSettings {
             pattern: settings.infill_pattern,
-            density: settings.infill_density,
+            density: if solid_shell {
+                1.0
+            } else {
+                settings.infill_density
+            },
             line_width: settings.line_width,
             layer_index: idx,
         };
         let infill = generate_infill(&perimeters.infill_boundary, &infill_settings);
 
+        // Generate ironing passes where the layer above leaves this one exposed
+        let layer_ironing = settings.ironing.and_then(|cfg| {
+            let paths =
+                generate_ironing(&perimeters.infill_boundary, slice_layers.get(idx + 1), &cfg);
+            (!paths.is_empty()).then_some(LayerIroning {
+                flow: cfg.flow,
+                paths,
+            })
+        });
+
         // Calculate path length for this layer
         for poly in &perimeters.outer {
             total_path_length += poly.perimeter();
@@ -228,6 +318,11 @@ pub fn slice(mesh: &TriangleMesh, settings: &SliceSettings) -> Result<SliceResul
         for path in &infill.paths {
             total_path_length += path.length();
         }
+        if let Some(ironing) = &layer_ironing {
+            for path in &ironing.paths {
+                total_path_length += path.length();
+            }
+        }
 
         // Get support for this layer
         let support = support_layers
@@ -243,6 +338,7 @@ pub fn slice(mesh: &TriangleMesh, settings: &SliceSettings) -> Result<SliceResul
             outer_perimeters: perimeters.outer,
             inner_perimeters: perimeters.inner,
             infill: infill.paths,
+            ironing: layer_ironing,
             support,
         });
     }
@@ -273,10 +369,36 @@ pub fn slice(mesh: &TriangleMesh, settings: &SliceSettings) -> Result<SliceResul
         bounds_max,
     };
 
-    Ok(SliceResult {
+    SliceResult {
         layers: print_layers,
         stats,
-    })
+    }
+}
+
+/// Whether layer `idx` belongs to the top or bottom solid shell: it is
+/// within `top_layers` of air above or `bottom_layers` of air below. "Air"
+/// means the neighbouring layer at that distance is past the model's ends or
+/// fails to cover this layer's infill region (sampled at its boundary
+/// vertices).
+fn is_shell_layer(
+    idx: usize,
+    boundary: &[Polygon],
+    slice_layers: &[SliceLayer],
+    settings: &SliceSettings,
+) -> bool {
+    let covered_at = |other: Option<&SliceLayer>| {
+        other.is_some_and(|layer| {
+            boundary
+                .iter()
+                .flat_map(|poly| &poly.points)
+                .all(|pt| ironing::covered_by(pt, &layer.contours))
+        })
+    };
+    let top_exposed =
+        (1..=settings.top_layers as usize).any(|d| !covered_at(slice_layers.get(idx + d)));
+    let bottom_exposed = (1..=settings.bottom_layers as usize)
+        .any(|d| idx < d || !covered_at(slice_layers.get(idx - d)));
+    top_exposed || bottom_exposed
 }
 
 #[cfg(test)]
@@ -286,16 +408,12 @@ mod tests {
     fn make_cube_mesh() -> TriangleMesh {
         let size = 10.0f32;
         let vertices = vec![
-            0.0, 0.0, 0.0, size, 0.0, 0.0, size, size, 0.0, 0.0, size, 0.0,
-            0.0, 0.0, size, size, 0.0, size, size, size, size, 0.0, size, size,
+            0.0, 0.0, 0.0, size, 0.0, 0.0, size, size, 0.0, 0.0, size, 0.0, 0.0, 0.0, size, size,
+            0.0, size, size, size, size, 0.0, size, size,
         ];
         let indices = vec![
-            0, 2, 1, 0, 3, 2,
-            4, 5, 6, 4, 6, 7,
-            0, 1, 5, 0, 5, 4,
-            2, 3, 7, 2, 7, 6,
-            0, 4, 7, 0, 7, 3,
-            1, 2, 6, 1, 6, 5,
+            0, 2, 1, 0, 3, 2, 4, 5, 6, 4, 6, 7, 0, 1, 5, 0, 5, 4, 2, 3, 7, 2, 7, 6, 0, 4, 7, 0, 7,
+            3, 1, 2, 6, 1, 6, 5,
         ];
         TriangleMesh {
             vertices,
@@ -311,7 +429,7 @@ mod tests {
             layer_height: 0.5, // Large layers for fast test
             first_layer_height: 0.5,
             infill_density: 0.05, // Low density for fast test
-            wall_count: 1, // Minimal walls
+            wall_count: 1,        // Minimal walls
             ..Default::default()
         };
         let result = slice(&mesh, &settings).unwrap();
@@ -321,6 +439,167 @@ mod tests {
         assert!(result.stats.layer_count <= 30); // ~20 layers for 10mm cube at 0.5mm
     }
 
+    #[test]
+    fn test_ironing_on_flat_top() {
+        // make_cube_mesh's winding slices to CW contours (treated as holes),
+        // so flip it to get CCW solid regions that receive infill and ironing
+        let mut mesh = make_cube_mesh();
+        for t in mesh.indices.chunks_exact_mut(3) {
+            t.swap(1, 2);
+        }
+        let settings = SliceSettings {
+            layer_height: 0.5,
+            first_layer_height: 0.5,
+            infill_density: 0.05,
+            wall_count: 1,
+            ironing: Some(IroningSettings::default()),
+            ..Default::default()
+        };
+        let result = slice(&mesh, &settings).unwrap();
+
+        // Only the topmost layer is exposed on a flat-topped box
+        let (top, rest) = result.layers.split_last().unwrap();
+        for layer in rest {
+            assert!(layer.ironing.is_none(), "layer {} ironed", layer.index);
+        }
+
+        let ironing = top.ironing.as_ref().expect("top layer should be ironed");
+        assert!((ironing.flow - 0.1).abs() < 1e-12);
+        // Passes at 0.1mm spacing across a ~9mm-wide infill region, all
+        // inside the top face
+        assert!(
+            ironing.paths.len() > 50,
+            "only {} passes",
+            ironing.paths.len()
+        );
+        for path in &ironing.paths {
+            for pt in &path.points {
+                assert!(pt.x > -0.01 && pt.x < 10.01 && pt.y > -0.01 && pt.y < 10.01);
+            }
+        }
+    }
+
+    #[test]
+    fn test_top_layers_get_solid_infill() {
+        // Flip the winding so contours slice as CCW solid regions
+        let mut mesh = make_cube_mesh();
+        for t in mesh.indices.chunks_exact_mut(3) {
+            t.swap(1, 2);
+        }
+        let settings = SliceSettings {
+            layer_height: 0.5,
+            first_layer_height: 0.5,
+            infill_density: 0.05,
+            wall_count: 1,
+            top_layers: 3,
+            bottom_layers: 0,
+            ..Default::default()
+        };
+        let result = slice(&mesh, &settings).unwrap();
+        let n = result.layers.len();
+        assert!(n > 6, "expected enough layers, got {n}");
+
+        // Solid infill at line_width spacing puts ~20 lines across the ~9mm
+        // region; 5% sparse infill fits at most a couple
+        for layer in &result.layers {
+            let dense = layer.infill.len() > 15;
+            if layer.index + 3 >= n {
+                assert!(
+                    dense,
+                    "top layer {} has {} paths",
+                    layer.index,
+                    layer.infill.len()
+                );
+            } else {
+                assert!(
+                    layer.infill.len() < 5,
+                    "interior layer {} has {} paths",
+                    layer.index,
+                    layer.infill.len()
+                );
+            }
+        }
+    }
+
+    fn make_cylinder_mesh(radius: f32, height: f32, segments: u32) -> TriangleMesh {
+        let n = segments as usize;
+        let mut vertices: Vec<f32> = Vec::new();
+        for i in 0..n {
+            let t = std::f64::consts::TAU * i as f64 / n as f64;
+            let (x, y) = (
+                (radius as f64 * t.cos()) as f32,
+                (radius as f64 * t.sin()) as f32,
+            );
+            vertices.extend_from_slice(&[x, y, 0.0, x, y, height]);
+        }
+        let mut indices: Vec<u32> = Vec::new();
+        for i in 0..n as u32 {
+            let j = (i + 1) % n as u32;
+            let (b0, t0, b1, t1) = (2 * i, 2 * i + 1, 2 * j, 2 * j + 1);
+            indices.extend_from_slice(&[b0, b1, t1, b0, t1, t0]);
+        }
+        // Cap fans
+        for i in 1..(n as u32 - 1) {
+            indices.extend_from_slice(&[0, 2 * (i + 1), 2 * i]);
+            indices.extend_from_slice(&[1, 2 * i + 1, 2 * (i + 1) + 1]);
+        }
+        TriangleMesh {
+            vertices,
+            indices,
+            normals: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_contour_smoothing_reduces_faceting() {
+        let mesh = make_cylinder_mesh(5.0, 10.0, 32);
+
+        // Contour level: smoothing refits the true circle with fewer,
+        // longer segments, every vertex within tolerance of radius 5
+        let plain = slice_mesh(&mesh, &[5.0]).unwrap();
+        let mut smoothed = plain.clone();
+        smooth_layers(&mut smoothed, 0.05);
+        let before = &plain[0].contours[0];
+        let after = &smoothed[0].contours[0];
+        assert!(
+            after.len() < before.len(),
+            "{} segments, expected fewer than {}",
+            after.len(),
+            before.len()
+        );
+        assert!(after.perimeter() / after.len() as f64 > before.perimeter() / before.len() as f64);
+        for pt in &after.points {
+            let r = pt.coords.norm();
+            assert!((r - 5.0).abs() <= 0.05 + 1e-6, "vertex at radius {r}");
+        }
+
+        // Pipeline level: the setting plumbs through slice()
+        let base = SliceSettings {
+            layer_height: 0.5,
+            first_layer_height: 0.5,
+            infill_density: 0.05,
+            wall_count: 1,
+            ..Default::default()
+        };
+        let with_smoothing = SliceSettings {
+            contour_smoothing: Some(0.05),
+            ..base.clone()
+        };
+        let count = |r: &SliceResult| {
+            r.layers
+                .iter()
+                .flat_map(|l| &l.outer_perimeters)
+                .map(|p| p.len())
+                .sum::<usize>()
+        };
+        let plain_count = count(&slice(&mesh, &base).unwrap());
+        let smooth_count = count(&slice(&mesh, &with_smoothing).unwrap());
+        assert!(
+            smooth_count < plain_count,
+            "{smooth_count} perimeter points with smoothing, {plain_count} without"
+        );
+    }
+
     #[test]
     fn test_invalid_settings() {
         let settings = SliceSettings {
@@ -328,5 +607,11 @@ mod tests {
             ..Default::default()
         };
         assert!(settings.validate().is_err());
+
+        let settings = SliceSettings {
+            contour_smoothing: Some(0.0),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
     }
 }
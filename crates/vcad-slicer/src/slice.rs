@@ -53,16 +53,16 @@ pub fn slice_mesh(mesh: &TriangleMesh, layer_heights: &[f64]) -> Result<Vec<Slic
 
 /// A triangle with its vertices and bounding Z range.
 #[derive(Debug, Clone, Copy)]
-struct Triangle {
-    v0: [f64; 3],
-    v1: [f64; 3],
-    v2: [f64; 3],
+pub(crate) struct Triangle {
+    pub(crate) v0: [f64; 3],
+    pub(crate) v1: [f64; 3],
+    pub(crate) v2: [f64; 3],
     z_min: f64,
     z_max: f64,
 }
 
 /// Extract triangles from mesh for slicing.
-fn extract_triangles(mesh: &TriangleMesh) -> Result<Vec<Triangle>> {
+pub(crate) fn extract_triangles(mesh: &TriangleMesh) -> Result<Vec<Triangle>> {
     let num_triangles = mesh.indices.len() / 3;
     let mut triangles = Vec::with_capacity(num_triangles);
 
@@ -104,6 +104,22 @@ fn extract_triangles(mesh: &TriangleMesh) -> Result<Vec<Triangle>> {
 
 /// Slice mesh at a single Z height.
 fn slice_at_z(triangles: &[Triangle], z: f64, index: usize) -> SliceLayer {
+    let segments = triangles_section_segments(triangles, z);
+
+    // Chain segments into contours
+    let contours = chain_segments(segments);
+
+    SliceLayer { z, index, contours }
+}
+
+/// Unchained section segments of a triangle soup at height `z`.
+///
+/// Split out of [`slice_at_z`] so the direct B-rep slicer can section one
+/// face's triangles at a time and chain the combined segments itself.
+pub(crate) fn triangles_section_segments(
+    triangles: &[Triangle],
+    z: f64,
+) -> Vec<([f64; 2], [f64; 2])> {
     let mut segments: Vec<([f64; 2], [f64; 2])> = Vec::new();
 
     for tri in triangles {
@@ -118,14 +134,7 @@ fn slice_at_z(triangles: &[Triangle], z: f64, index: usize) -> SliceLayer {
         }
     }
 
-    // Chain segments into contours
-    let contours = chain_segments(segments);
-
-    SliceLayer {
-        z,
-        index,
-        contours,
-    }
+    segments
 }
 
 /// Intersect a triangle with a horizontal plane at Z.
@@ -193,7 +202,7 @@ fn triangle_plane_intersection(tri: &Triangle, z: f64) -> Option<([f64; 2], [f64
 }
 
 /// Chain line segments into closed polygons.
-fn chain_segments(segments: Vec<([f64; 2], [f64; 2])>) -> Vec<Polygon> {
+pub(crate) fn chain_segments(segments: Vec<([f64; 2], [f64; 2])>) -> Vec<Polygon> {
     if segments.is_empty() {
         return Vec::new();
     }
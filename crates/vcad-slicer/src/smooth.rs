@@ -0,0 +1,250 @@
+//! Contour smoothing — fit smooth shapes to faceted slice contours.
+//!
+//! Mesh slicing reproduces the tessellation's facets in every contour.
+//! This module reduces that faceting before perimeter generation: a
+//! contour whose points all sit within tolerance of a fitted circle is
+//! resampled on that circle with the chord error pinned at the tolerance,
+//! and everything else is simplified with Douglas–Peucker so collinear
+//! facet runs collapse into single long segments. Enabled via
+//! [`SliceSettings::contour_smoothing`](crate::SliceSettings::contour_smoothing),
+//! and pairs with arc-fitting in G-code generation downstream.
+
+use std::f64::consts::{PI, TAU};
+
+use vcad_kernel_math::Point2;
+
+use crate::path::Polygon;
+use crate::slice::SliceLayer;
+
+/// Smooth every contour of every layer in place with the given fitting
+/// tolerance (mm).
+pub fn smooth_layers(layers: &mut [SliceLayer], tolerance: f64) {
+    for layer in layers {
+        for contour in &mut layer.contours {
+            *contour = smooth_polygon(contour, tolerance);
+        }
+    }
+}
+
+/// Smooth one contour.
+///
+/// Tries a least-squares circle fit first; if every point lies within
+/// `tolerance` of the fitted circle and resampling it would use fewer
+/// segments, the contour becomes that resampled circle (same winding,
+/// vertices on the true fitted radius). Otherwise the contour is
+/// simplified with Douglas–Peucker at `tolerance`, which leaves corners
+/// in place and merges facet runs.
+pub fn smooth_polygon(poly: &Polygon, tolerance: f64) -> Polygon {
+    let points = &poly.points;
+    if points.len() < 4 || tolerance <= 0.0 {
+        return poly.clone();
+    }
+
+    if points.len() >= 8 {
+        if let Some((center, radius)) = fit_circle(points) {
+            let on_circle = points
+                .iter()
+                .all(|p| ((p - center).norm() - radius).abs() <= tolerance);
+            if on_circle {
+                let n = circle_segments(radius, tolerance);
+                if n < points.len() {
+                    return resample_circle(center, radius, n, points[0], poly.is_ccw());
+                }
+            }
+        }
+    }
+
+    Polygon::new(simplify_closed(points, tolerance))
+}
+
+/// Segment count that keeps the chord sag of an inscribed polygon at or
+/// below `tolerance`.
+fn circle_segments(radius: f64, tolerance: f64) -> usize {
+    if tolerance >= radius {
+        return 8;
+    }
+    ((PI / (1.0 - tolerance / radius).acos()).ceil() as usize).max(8)
+}
+
+/// Least-squares (Kåsa) circle fit through a point set.
+///
+/// Solves the linear system for `x² + y² + Dx + Ey + F = 0`; returns
+/// `None` when the points are degenerate (collinear or coincident).
+fn fit_circle(points: &[Point2]) -> Option<(Point2, f64)> {
+    let n = points.len() as f64;
+    let (mut sx, mut sy, mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    let (mut sxz, mut syz, mut sz) = (0.0, 0.0, 0.0);
+    for p in points {
+        let z = p.x * p.x + p.y * p.y;
+        sx += p.x;
+        sy += p.y;
+        sxx += p.x * p.x;
+        syy += p.y * p.y;
+        sxy += p.x * p.y;
+        sxz += p.x * z;
+        syz += p.y * z;
+        sz += z;
+    }
+
+    // Normal equations for [D, E, F]
+    let m = [[sxx, sxy, sx], [sxy, syy, sy], [sx, sy, n]];
+    let rhs = [-sxz, -syz, -sz];
+    let det = |m: &[[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let d0 = det(&m);
+    if d0.abs() < 1e-12 {
+        return None;
+    }
+    let solve = |col: usize| {
+        let mut mc = m;
+        for (row, &r) in rhs.iter().enumerate() {
+            mc[row][col] = r;
+        }
+        det(&mc) / d0
+    };
+    let (d, e, f) = (solve(0), solve(1), solve(2));
+
+    let center = Point2::new(-d / 2.0, -e / 2.0);
+    let r2 = (d * d + e * e) / 4.0 - f;
+    (r2 > 0.0).then(|| (center, r2.sqrt()))
+}
+
+/// Resample a circle into `n` segments, starting at the angle of `start`
+/// so the contour stays anchored near its original seam.
+fn resample_circle(center: Point2, radius: f64, n: usize, start: Point2, ccw: bool) -> Polygon {
+    let t0 = (start.y - center.y).atan2(start.x - center.x);
+    let mut points = Vec::with_capacity(n);
+    for i in 0..n {
+        let step = TAU * i as f64 / n as f64;
+        let t = if ccw { t0 + step } else { t0 - step };
+        points.push(Point2::new(
+            center.x + radius * t.cos(),
+            center.y + radius * t.sin(),
+        ));
+    }
+    Polygon::new(points)
+}
+
+/// Douglas–Peucker simplification of a closed ring.
+///
+/// The ring is split at its first vertex and the vertex farthest from it
+/// (two anchors that survive simplification), and each open half is
+/// simplified independently.
+fn simplify_closed(points: &[Point2], tolerance: f64) -> Vec<Point2> {
+    if points.len() <= 4 {
+        return points.to_vec();
+    }
+
+    let far = points
+        .iter()
+        .enumerate()
+        .skip(1)
+        .max_by(|(_, a), (_, b)| {
+            let da = (*a - points[0]).norm_squared();
+            let db = (*b - points[0]).norm_squared();
+            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(points.len() / 2);
+
+    // Each half pushes its kept vertices except its final endpoint, so the
+    // two halves concatenate into the full ring with no duplicates
+    let mut out = Vec::new();
+    simplify_open(&points[..=far], tolerance, &mut out);
+    let mut back: Vec<Point2> = points[far..].to_vec();
+    back.push(points[0]);
+    simplify_open(&back, tolerance, &mut out);
+    out
+}
+
+/// Recursive Douglas–Peucker on an open polyline; pushes every kept
+/// vertex except the final endpoint.
+fn simplify_open(points: &[Point2], tolerance: f64, out: &mut Vec<Point2>) {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    if points.len() <= 2 {
+        out.push(first);
+        return;
+    }
+
+    let chord = last - first;
+    let len = chord.norm();
+    let mut max_dist = 0.0;
+    let mut max_idx = 0;
+    for (i, p) in points.iter().enumerate().take(points.len() - 1).skip(1) {
+        let dist = if len < 1e-12 {
+            (p - first).norm()
+        } else {
+            // Perpendicular distance to the chord
+            (chord.x * (p.y - first.y) - chord.y * (p.x - first.x)).abs() / len
+        };
+        if dist > max_dist {
+            max_dist = dist;
+            max_idx = i;
+        }
+    }
+
+    if max_dist > tolerance {
+        simplify_open(&points[..=max_idx], tolerance, out);
+        simplify_open(&points[max_idx..], tolerance, out);
+    } else {
+        out.push(first);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circle_polygon(radius: f64, n: usize) -> Polygon {
+        let points = (0..n)
+            .map(|i| {
+                let t = TAU * i as f64 / n as f64;
+                Point2::new(radius * t.cos(), radius * t.sin())
+            })
+            .collect();
+        Polygon::new(points)
+    }
+
+    #[test]
+    fn test_smooth_circle_fewer_longer_segments() {
+        let faceted = circle_polygon(5.0, 64);
+        let smoothed = smooth_polygon(&faceted, 0.05);
+
+        assert!(
+            smoothed.points.len() < faceted.points.len(),
+            "{} segments, expected fewer than {}",
+            smoothed.points.len(),
+            faceted.points.len()
+        );
+        assert!(smoothed.perimeter() / smoothed.len() as f64 > faceted.perimeter() / 64.0);
+        // Vertices sit on the fitted (true) radius, chords sag within tolerance
+        for p in &smoothed.points {
+            assert!((p.coords.norm() - 5.0).abs() < 1e-9);
+        }
+        assert!(smoothed.is_ccw());
+    }
+
+    #[test]
+    fn test_smooth_square_keeps_corners() {
+        // A square with collinear midpoints: simplification should collapse
+        // the facet runs but never round off the corners
+        let mut points = Vec::new();
+        for (a, b) in [
+            ((0.0, 0.0), (10.0, 0.0)),
+            ((10.0, 0.0), (10.0, 10.0)),
+            ((10.0, 10.0), (0.0, 10.0)),
+            ((0.0, 10.0), (0.0, 0.0)),
+        ] {
+            for i in 0..4 {
+                let t = i as f64 / 4.0;
+                points.push(Point2::new(a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1)));
+            }
+        }
+        let smoothed = smooth_polygon(&Polygon::new(points), 0.01);
+        assert_eq!(smoothed.points.len(), 4);
+        assert!((smoothed.perimeter() - 40.0).abs() < 1e-9);
+    }
+}
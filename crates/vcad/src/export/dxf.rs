@@ -9,6 +9,8 @@ use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
+use super::ExportPrecision;
+
 /// A 2D point for DXF export.
 #[derive(Debug, Clone, Copy)]
 pub struct Point2D {
@@ -99,12 +101,22 @@ pub enum Shape2D {
 /// Accumulates 2D shapes and exports them as DXF R12 for laser cutting services.
 pub struct DxfDocument {
     shapes: Vec<Shape2D>,
+    precision: ExportPrecision,
 }
 
 impl DxfDocument {
     /// Create a new empty DXF document.
     pub fn new() -> Self {
-        Self { shapes: Vec::new() }
+        Self {
+            shapes: Vec::new(),
+            precision: ExportPrecision::default(),
+        }
+    }
+
+    /// Set the coordinate precision used when writing the file.
+    pub fn with_precision(mut self, precision: ExportPrecision) -> Self {
+        self.precision = precision;
+        self
     }
 
     /// Add an arbitrary [`Shape2D`] to the document.
@@ -376,27 +388,27 @@ impl DxfDocument {
 
         // Vertex 1 (bottom-left)
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x1)?;
+        writeln!(writer, "{}", self.precision.fmt(x1))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y1)?;
+        writeln!(writer, "{}", self.precision.fmt(y1))?;
 
         // Vertex 2 (bottom-right)
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x2)?;
+        writeln!(writer, "{}", self.precision.fmt(x2))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y1)?;
+        writeln!(writer, "{}", self.precision.fmt(y1))?;
 
         // Vertex 3 (top-right)
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x2)?;
+        writeln!(writer, "{}", self.precision.fmt(x2))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y2)?;
+        writeln!(writer, "{}", self.precision.fmt(y2))?;
 
         // Vertex 4 (top-left)
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x1)?;
+        writeln!(writer, "{}", self.precision.fmt(x1))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y2)?;
+        writeln!(writer, "{}", self.precision.fmt(y2))?;
 
         Ok(())
     }
@@ -412,11 +424,11 @@ impl DxfDocument {
         writeln!(writer, "8")?;
         writeln!(writer, "0")?; // Layer 0
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", center.x)?;
+        writeln!(writer, "{}", self.precision.fmt(center.x))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", center.y)?;
+        writeln!(writer, "{}", self.precision.fmt(center.y))?;
         writeln!(writer, "40")?;
-        writeln!(writer, "{:.6}", radius)?;
+        writeln!(writer, "{}", self.precision.fmt(radius))?;
 
         Ok(())
     }
@@ -453,55 +465,55 @@ impl DxfDocument {
 
         // Bottom edge
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x1 + r)?;
+        writeln!(writer, "{}", self.precision.fmt(x1 + r))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y1)?;
+        writeln!(writer, "{}", self.precision.fmt(y1))?;
 
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x2 - r)?;
+        writeln!(writer, "{}", self.precision.fmt(x2 - r))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y1)?;
+        writeln!(writer, "{}", self.precision.fmt(y1))?;
         writeln!(writer, "42")?;
-        writeln!(writer, "{:.6}", bulge)?; // Bulge for corner arc
+        writeln!(writer, "{}", self.precision.fmt(bulge))?; // Bulge for corner arc
 
         // Right edge
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x2)?;
+        writeln!(writer, "{}", self.precision.fmt(x2))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y1 + r)?;
+        writeln!(writer, "{}", self.precision.fmt(y1 + r))?;
 
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x2)?;
+        writeln!(writer, "{}", self.precision.fmt(x2))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y2 - r)?;
+        writeln!(writer, "{}", self.precision.fmt(y2 - r))?;
         writeln!(writer, "42")?;
-        writeln!(writer, "{:.6}", bulge)?;
+        writeln!(writer, "{}", self.precision.fmt(bulge))?;
 
         // Top edge
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x2 - r)?;
+        writeln!(writer, "{}", self.precision.fmt(x2 - r))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y2)?;
+        writeln!(writer, "{}", self.precision.fmt(y2))?;
 
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x1 + r)?;
+        writeln!(writer, "{}", self.precision.fmt(x1 + r))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y2)?;
+        writeln!(writer, "{}", self.precision.fmt(y2))?;
         writeln!(writer, "42")?;
-        writeln!(writer, "{:.6}", bulge)?;
+        writeln!(writer, "{}", self.precision.fmt(bulge))?;
 
         // Left edge
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x1)?;
+        writeln!(writer, "{}", self.precision.fmt(x1))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y2 - r)?;
+        writeln!(writer, "{}", self.precision.fmt(y2 - r))?;
 
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", x1)?;
+        writeln!(writer, "{}", self.precision.fmt(x1))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", y1 + r)?;
+        writeln!(writer, "{}", self.precision.fmt(y1 + r))?;
         writeln!(writer, "42")?;
-        writeln!(writer, "{:.6}", bulge)?;
+        writeln!(writer, "{}", self.precision.fmt(bulge))?;
 
         Ok(())
     }
@@ -518,13 +530,13 @@ impl DxfDocument {
         writeln!(writer, "8")?;
         writeln!(writer, "{}", layer)?; // Layer name
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", start.x)?;
+        writeln!(writer, "{}", self.precision.fmt(start.x))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", start.y)?;
+        writeln!(writer, "{}", self.precision.fmt(start.y))?;
         writeln!(writer, "11")?;
-        writeln!(writer, "{:.6}", end.x)?;
+        writeln!(writer, "{}", self.precision.fmt(end.x))?;
         writeln!(writer, "21")?;
-        writeln!(writer, "{:.6}", end.y)?;
+        writeln!(writer, "{}", self.precision.fmt(end.y))?;
 
         Ok(())
     }
@@ -564,31 +576,31 @@ impl DxfDocument {
 
             // Bottom-left (start of left semicircle)
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", x1)?;
+            writeln!(writer, "{}", self.precision.fmt(x1))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", center.y - r)?;
+            writeln!(writer, "{}", self.precision.fmt(center.y - r))?;
             writeln!(writer, "42")?;
             writeln!(writer, "1.0")?; // Bulge for 180° arc (semicircle)
 
             // Top-left (end of left semicircle)
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", x1)?;
+            writeln!(writer, "{}", self.precision.fmt(x1))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", center.y + r)?;
+            writeln!(writer, "{}", self.precision.fmt(center.y + r))?;
 
             // Top-right (start of right semicircle)
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", x2)?;
+            writeln!(writer, "{}", self.precision.fmt(x2))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", center.y + r)?;
+            writeln!(writer, "{}", self.precision.fmt(center.y + r))?;
             writeln!(writer, "42")?;
             writeln!(writer, "1.0")?; // Bulge for 180° arc
 
             // Bottom-right (end of right semicircle)
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", x2)?;
+            writeln!(writer, "{}", self.precision.fmt(x2))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", center.y - r)?;
+            writeln!(writer, "{}", self.precision.fmt(center.y - r))?;
         } else {
             // Vertical slot
             let y1 = center.y - straight / 2.0;
@@ -605,31 +617,31 @@ impl DxfDocument {
 
             // Left-bottom
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", center.x - r)?;
+            writeln!(writer, "{}", self.precision.fmt(center.x - r))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", y1)?;
+            writeln!(writer, "{}", self.precision.fmt(y1))?;
             writeln!(writer, "42")?;
             writeln!(writer, "1.0")?;
 
             // Right-bottom
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", center.x + r)?;
+            writeln!(writer, "{}", self.precision.fmt(center.x + r))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", y1)?;
+            writeln!(writer, "{}", self.precision.fmt(y1))?;
 
             // Right-top
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", center.x + r)?;
+            writeln!(writer, "{}", self.precision.fmt(center.x + r))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", y2)?;
+            writeln!(writer, "{}", self.precision.fmt(y2))?;
             writeln!(writer, "42")?;
             writeln!(writer, "1.0")?;
 
             // Left-top
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", center.x - r)?;
+            writeln!(writer, "{}", self.precision.fmt(center.x - r))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", y2)?;
+            writeln!(writer, "{}", self.precision.fmt(y2))?;
         }
 
         Ok(())
@@ -656,9 +668,9 @@ impl DxfDocument {
 
         for point in points {
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", point.x)?;
+            writeln!(writer, "{}", self.precision.fmt(point.x))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", point.y)?;
+            writeln!(writer, "{}", self.precision.fmt(point.y))?;
         }
 
         Ok(())
@@ -677,15 +689,15 @@ impl DxfDocument {
         writeln!(writer, "8")?;
         writeln!(writer, "0")?; // Layer 0
         writeln!(writer, "10")?;
-        writeln!(writer, "{:.6}", center.x)?;
+        writeln!(writer, "{}", self.precision.fmt(center.x))?;
         writeln!(writer, "20")?;
-        writeln!(writer, "{:.6}", center.y)?;
+        writeln!(writer, "{}", self.precision.fmt(center.y))?;
         writeln!(writer, "40")?;
-        writeln!(writer, "{:.6}", radius)?;
+        writeln!(writer, "{}", self.precision.fmt(radius))?;
         writeln!(writer, "50")?;
-        writeln!(writer, "{:.6}", start_angle)?;
+        writeln!(writer, "{}", self.precision.fmt(start_angle))?;
         writeln!(writer, "51")?;
-        writeln!(writer, "{:.6}", end_angle)?;
+        writeln!(writer, "{}", self.precision.fmt(end_angle))?;
 
         Ok(())
     }
@@ -704,6 +716,7 @@ impl Default for DxfDocument {
 /// - HIDDEN layer: dashed lines for hidden edges
 pub struct DxfDraftingDocument {
     lines: Vec<DraftingLine>,
+    precision: ExportPrecision,
 }
 
 /// A line in a drafting document with visibility information.
@@ -718,7 +731,16 @@ struct DraftingLine {
 impl DxfDraftingDocument {
     /// Create a new empty drafting document.
     pub fn new() -> Self {
-        Self { lines: Vec::new() }
+        Self {
+            lines: Vec::new(),
+            precision: ExportPrecision::default(),
+        }
+    }
+
+    /// Set the coordinate precision used when writing the file.
+    pub fn with_precision(mut self, precision: ExportPrecision) -> Self {
+        self.precision = precision;
+        self
     }
 
     /// Add a visible line (continuous).
@@ -920,13 +942,13 @@ impl DxfDraftingDocument {
                 if line.visible { "CONTINUOUS" } else { "HIDDEN" }
             )?;
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", line.x1)?;
+            writeln!(writer, "{}", self.precision.fmt(line.x1))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", line.y1)?;
+            writeln!(writer, "{}", self.precision.fmt(line.y1))?;
             writeln!(writer, "11")?;
-            writeln!(writer, "{:.6}", line.x2)?;
+            writeln!(writer, "{}", self.precision.fmt(line.x2))?;
             writeln!(writer, "21")?;
-            writeln!(writer, "{:.6}", line.y2)?;
+            writeln!(writer, "{}", self.precision.fmt(line.y2))?;
         }
 
         writeln!(writer, "0")?;
@@ -964,6 +986,7 @@ impl Default for DxfDraftingDocument {
 pub struct DxfSectionDocument {
     section_lines: Vec<SectionLine>,
     hatch_lines: Vec<HatchLine>,
+    precision: ExportPrecision,
 }
 
 /// A section curve line.
@@ -988,9 +1011,16 @@ impl DxfSectionDocument {
         Self {
             section_lines: Vec::new(),
             hatch_lines: Vec::new(),
+            precision: ExportPrecision::default(),
         }
     }
 
+    /// Set the coordinate precision used when writing the file.
+    pub fn with_precision(mut self, precision: ExportPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+
     /// Add a section curve line.
     pub fn add_section_line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
         self.section_lines.push(SectionLine { x1, y1, x2, y2 });
@@ -1179,13 +1209,13 @@ impl DxfSectionDocument {
             writeln!(writer, "370")?;
             writeln!(writer, "50")?; // 0.50mm
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", line.x1)?;
+            writeln!(writer, "{}", self.precision.fmt(line.x1))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", line.y1)?;
+            writeln!(writer, "{}", self.precision.fmt(line.y1))?;
             writeln!(writer, "11")?;
-            writeln!(writer, "{:.6}", line.x2)?;
+            writeln!(writer, "{}", self.precision.fmt(line.x2))?;
             writeln!(writer, "21")?;
-            writeln!(writer, "{:.6}", line.y2)?;
+            writeln!(writer, "{}", self.precision.fmt(line.y2))?;
         }
 
         // Hatch lines
@@ -1200,13 +1230,13 @@ impl DxfSectionDocument {
             writeln!(writer, "370")?;
             writeln!(writer, "13")?; // 0.13mm
             writeln!(writer, "10")?;
-            writeln!(writer, "{:.6}", line.x1)?;
+            writeln!(writer, "{}", self.precision.fmt(line.x1))?;
             writeln!(writer, "20")?;
-            writeln!(writer, "{:.6}", line.y1)?;
+            writeln!(writer, "{}", self.precision.fmt(line.y1))?;
             writeln!(writer, "11")?;
-            writeln!(writer, "{:.6}", line.x2)?;
+            writeln!(writer, "{}", self.precision.fmt(line.x2))?;
             writeln!(writer, "21")?;
-            writeln!(writer, "{:.6}", line.y2)?;
+            writeln!(writer, "{}", self.precision.fmt(line.y2))?;
         }
 
         writeln!(writer, "0")?;
@@ -1408,4 +1438,21 @@ mod tests {
         // Check layers
         assert!(content.contains("VISIBLE"));
     }
+
+    #[test]
+    fn test_dxf_export_precision() {
+        for decimals in [2usize, 6] {
+            let mut doc = DxfDraftingDocument::new().with_precision(ExportPrecision::new(decimals));
+            doc.add_visible_line(0.0, 0.0, 10.5, 7.25);
+
+            let mut buffer = Vec::new();
+            doc.export_to_writer(&mut buffer).unwrap();
+            let content = String::from_utf8(buffer).unwrap();
+
+            // Coordinates carry exactly the requested number of decimals
+            let expected = format!("{:.*}", decimals, 10.5);
+            assert!(content.contains(&expected), "missing {expected}");
+            assert!(!content.contains(&format!("{:.*}", decimals + 1, 10.5)));
+        }
+    }
 }
@@ -5,10 +5,12 @@
 //! - glTF/GLB: PBR materials for visualization
 //! - USD: Articulated robots for Isaac Sim
 //! - DXF: 2D profiles for laser cutting
+//! - SVG: 2D drawings for web display
 
 pub mod dxf;
 pub mod materials;
 pub mod stl;
+pub mod svg;
 pub mod usd;
 
 #[cfg(feature = "gltf")]
@@ -18,7 +20,37 @@ pub mod gltf_export;
 pub use dxf::DxfDocument;
 pub use materials::{Material, Materials};
 pub use stl::export_stl;
+pub use svg::SvgDraftingDocument;
 pub use usd::{export_robot_usd, export_usd, RobotPhysics, WheelConfig};
 
 #[cfg(feature = "gltf")]
 pub use gltf_export::{export_glb, export_scene_glb};
+
+/// Coordinate precision for text-based 2D exports (DXF, SVG).
+///
+/// Controls how many decimal places are written for each coordinate.
+/// Fewer decimals keep files small for large models; more decimals
+/// preserve detail for very small ones. Defaults to 6.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportPrecision {
+    /// Number of decimal places written for each coordinate.
+    pub decimals: usize,
+}
+
+impl ExportPrecision {
+    /// Create a precision with the given number of decimal places.
+    pub fn new(decimals: usize) -> Self {
+        Self { decimals }
+    }
+
+    /// Format a coordinate value at this precision.
+    pub fn fmt(&self, value: f64) -> String {
+        format!("{:.*}", self.decimals, value)
+    }
+}
+
+impl Default for ExportPrecision {
+    fn default() -> Self {
+        Self { decimals: 6 }
+    }
+}
@@ -0,0 +1,225 @@
+//! SVG export for 2D technical drawings.
+//!
+//! Exports projected views as SVG for web display and documentation:
+//! - Visible edges: solid black strokes
+//! - Hidden edges: dashed gray strokes
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use super::ExportPrecision;
+
+/// SVG document builder for technical drawings with visible/hidden line support.
+///
+/// Mirrors [`DxfDraftingDocument`](super::dxf::DxfDraftingDocument) but writes
+/// SVG instead of DXF. The Y axis is flipped on output so drawings keep the
+/// conventional Y-up orientation.
+pub struct SvgDraftingDocument {
+    lines: Vec<SvgLine>,
+    precision: ExportPrecision,
+}
+
+/// A line in an SVG drawing with visibility information.
+struct SvgLine {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    visible: bool,
+}
+
+impl SvgDraftingDocument {
+    /// Create a new empty SVG document.
+    pub fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            precision: ExportPrecision::default(),
+        }
+    }
+
+    /// Set the coordinate precision used when writing the file.
+    pub fn with_precision(mut self, precision: ExportPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Add a visible line (solid stroke).
+    pub fn add_visible_line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        self.lines.push(SvgLine {
+            x1,
+            y1,
+            x2,
+            y2,
+            visible: true,
+        });
+    }
+
+    /// Add a hidden line (dashed stroke).
+    pub fn add_hidden_line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        self.lines.push(SvgLine {
+            x1,
+            y1,
+            x2,
+            y2,
+            visible: false,
+        });
+    }
+
+    /// Number of visible lines.
+    pub fn num_visible(&self) -> usize {
+        self.lines.iter().filter(|l| l.visible).count()
+    }
+
+    /// Number of hidden lines.
+    pub fn num_hidden(&self) -> usize {
+        self.lines.iter().filter(|l| !l.visible).count()
+    }
+
+    /// Export to an SVG file.
+    pub fn export(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let file = File::create(path)?;
+        let writer = BufWriter::new(file);
+        self.export_to_writer(writer)
+    }
+
+    /// Export to a writer.
+    pub fn export_to_writer(&self, mut writer: impl Write) -> std::io::Result<()> {
+        let (min_x, min_y, max_x, max_y) = self.bounds();
+        let margin = 0.05 * ((max_x - min_x).max(max_y - min_y)).max(1.0);
+        let p = &self.precision;
+
+        writeln!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            p.fmt(min_x - margin),
+            p.fmt(-(max_y + margin)),
+            p.fmt(max_x - min_x + 2.0 * margin),
+            p.fmt(max_y - min_y + 2.0 * margin)
+        )?;
+        // Flip Y so model space stays Y-up
+        writeln!(writer, r#"<g transform="scale(1 -1)">"#)?;
+
+        for line in &self.lines {
+            let style = if line.visible {
+                r#"stroke="black""#
+            } else {
+                r#"stroke="gray" stroke-dasharray="4 2""#
+            };
+            writeln!(
+                writer,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" {} stroke-width="0.5" vector-effect="non-scaling-stroke"/>"#,
+                p.fmt(line.x1),
+                p.fmt(line.y1),
+                p.fmt(line.x2),
+                p.fmt(line.y2),
+                style
+            )?;
+        }
+
+        writeln!(writer, "</g>")?;
+        writeln!(writer, "</svg>")?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        let mut min_x = f64::INFINITY;
+        let mut min_y = f64::INFINITY;
+        let mut max_x = f64::NEG_INFINITY;
+        let mut max_y = f64::NEG_INFINITY;
+
+        for line in &self.lines {
+            for (x, y) in [(line.x1, line.y1), (line.x2, line.y2)] {
+                min_x = min_x.min(x);
+                min_y = min_y.min(y);
+                max_x = max_x.max(x);
+                max_y = max_y.max(y);
+            }
+        }
+
+        if self.lines.is_empty() {
+            (0.0, 0.0, 0.0, 0.0)
+        } else {
+            (min_x, min_y, max_x, max_y)
+        }
+    }
+}
+
+impl Default for SvgDraftingDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Export a projected view to an SVG byte buffer.
+///
+/// This function takes a ProjectedView from the drafting crate and
+/// returns the SVG content as bytes for use in WASM or other contexts.
+#[cfg(feature = "drafting")]
+pub fn export_projected_view_to_svg_buffer(
+    view: &vcad_kernel_drafting::ProjectedView,
+    precision: ExportPrecision,
+) -> std::io::Result<Vec<u8>> {
+    use vcad_kernel_drafting::Visibility;
+
+    let mut doc = SvgDraftingDocument::new().with_precision(precision);
+
+    for edge in &view.edges {
+        let (x1, y1) = (edge.start.x, edge.start.y);
+        let (x2, y2) = (edge.end.x, edge.end.y);
+
+        match edge.visibility {
+            Visibility::Visible => doc.add_visible_line(x1, y1, x2, y2),
+            Visibility::Hidden => doc.add_hidden_line(x1, y1, x2, y2),
+        }
+    }
+
+    let mut buffer = Vec::new();
+    doc.export_to_writer(&mut buffer)?;
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_doc(precision: ExportPrecision) -> SvgDraftingDocument {
+        let mut doc = SvgDraftingDocument::new().with_precision(precision);
+        doc.add_visible_line(0.0, 0.0, 10.5, 0.0);
+        doc.add_visible_line(10.5, 0.0, 10.5, 7.25);
+        doc.add_hidden_line(2.0, 2.0, 8.0, 2.0);
+        doc
+    }
+
+    #[test]
+    fn test_svg_structure() {
+        let doc = sample_doc(ExportPrecision::default());
+        assert_eq!(doc.num_visible(), 2);
+        assert_eq!(doc.num_hidden(), 1);
+
+        let mut buffer = Vec::new();
+        doc.export_to_writer(&mut buffer).unwrap();
+        let content = String::from_utf8(buffer).unwrap();
+
+        assert!(content.contains("<svg"));
+        assert!(content.contains("viewBox"));
+        assert!(content.contains("stroke-dasharray"));
+        assert!(content.contains("</svg>"));
+    }
+
+    #[test]
+    fn test_svg_export_precision() {
+        for decimals in [2, 6] {
+            let doc = sample_doc(ExportPrecision::new(decimals));
+            let mut buffer = Vec::new();
+            doc.export_to_writer(&mut buffer).unwrap();
+            let content = String::from_utf8(buffer).unwrap();
+
+            let expected = format!(r#"x2="{:.*}""#, decimals, 10.5);
+            assert!(content.contains(&expected), "missing {expected}");
+            // No coordinate should carry more decimals than requested
+            assert!(!content.contains(&format!(r#"x2="{:.*}""#, decimals + 1, 10.5)));
+        }
+    }
+}
@@ -123,6 +123,7 @@ impl Part {
                 id,
                 name: Some(name.to_string()),
                 op,
+                material: None,
             },
         );
         (id, nodes)
@@ -144,6 +145,7 @@ impl Part {
                 id,
                 name: Some(name.to_string()),
                 op: op_fn(left.ir_node_id, right.ir_node_id),
+                material: None,
             },
         );
         (id, nodes)
@@ -163,6 +165,7 @@ impl Part {
                 id,
                 name: Some(name.to_string()),
                 op: op_fn(child.ir_node_id),
+                material: None,
             },
         );
         (id, nodes)
@@ -180,6 +183,8 @@ impl Part {
     }
 
     /// Create a cube/box centered at origin.
+    ///
+    /// Degenerate dimensions (zero, negative, or NaN) produce an empty part.
     pub fn cube(name: impl Into<String>, x: f64, y: f64, z: f64) -> Self {
         let name = name.into();
         let (id, nodes) = Self::make_leaf(
@@ -188,10 +193,14 @@ impl Part {
                 size: IrVec3::new(x, y, z),
             },
         );
-        Self::with_ir(name, vcad_kernel::Solid::cube(x, y, z), id, nodes)
+        let solid =
+            vcad_kernel::Solid::cube(x, y, z).unwrap_or_else(|_| vcad_kernel::Solid::empty());
+        Self::with_ir(name, solid, id, nodes)
     }
 
     /// Create a cylinder along Z axis, centered at origin.
+    ///
+    /// Degenerate dimensions (zero, negative, or NaN) produce an empty part.
     pub fn cylinder(name: impl Into<String>, radius: f64, height: f64, segments: u32) -> Self {
         let name = name.into();
         let (id, nodes) = Self::make_leaf(
@@ -202,15 +211,14 @@ impl Part {
                 segments,
             },
         );
-        Self::with_ir(
-            name,
-            vcad_kernel::Solid::cylinder(radius, height, segments),
-            id,
-            nodes,
-        )
+        let solid = vcad_kernel::Solid::cylinder(radius, height, segments)
+            .unwrap_or_else(|_| vcad_kernel::Solid::empty());
+        Self::with_ir(name, solid, id, nodes)
     }
 
     /// Create a cone/tapered cylinder.
+    ///
+    /// Degenerate dimensions (zero, negative, or NaN) produce an empty part.
     pub fn cone(
         name: impl Into<String>,
         radius_bottom: f64,
@@ -228,24 +236,20 @@ impl Part {
                 segments,
             },
         );
-        Self::with_ir(
-            name,
-            vcad_kernel::Solid::cone(radius_bottom, radius_top, height, segments),
-            id,
-            nodes,
-        )
+        let solid = vcad_kernel::Solid::cone(radius_bottom, radius_top, height, segments)
+            .unwrap_or_else(|_| vcad_kernel::Solid::empty());
+        Self::with_ir(name, solid, id, nodes)
     }
 
     /// Create a sphere centered at origin.
+    ///
+    /// Degenerate dimensions (zero, negative, or NaN) produce an empty part.
     pub fn sphere(name: impl Into<String>, radius: f64, segments: u32) -> Self {
         let name = name.into();
         let (id, nodes) = Self::make_leaf(&name, CsgOp::Sphere { radius, segments });
-        Self::with_ir(
-            name,
-            vcad_kernel::Solid::sphere(radius, segments),
-            id,
-            nodes,
-        )
+        let solid = vcad_kernel::Solid::sphere(radius, segments)
+            .unwrap_or_else(|_| vcad_kernel::Solid::empty());
+        Self::with_ir(name, solid, id, nodes)
     }
 
     // =========================================================================
@@ -445,6 +445,17 @@ function evaluateOp(
         op.segments || undefined,
       );
 
+    case "PolygonPrism":
+    case "Pyramid":
+      // Solid.polygonPrism / Solid.pyramid are not present in the checked-in
+      // @vcad/kernel-wasm build; return an empty solid as a placeholder until
+      // the package is regenerated with the new bindings
+      if (DEBUG_EVAL) {
+        const indent = "  ".repeat(depth);
+        console.warn(`${indent}  -> ${op.type} (requires a rebuilt @vcad/kernel-wasm)`);
+      }
+      return Solid.empty();
+
     case "Empty":
       return Solid.empty();
 
@@ -186,6 +186,20 @@ export interface ConeOp {
   segments: number;
 }
 
+export interface PolygonPrismOp {
+  type: "PolygonPrism";
+  sides: number;
+  circumradius: number;
+  height: number;
+}
+
+export interface PyramidOp {
+  type: "Pyramid";
+  base_sides: number;
+  circumradius: number;
+  height: number;
+}
+
 export interface EmptyOp {
   type: "Empty";
 }
@@ -381,6 +395,8 @@ export type CsgOp =
   | CylinderOp
   | SphereOp
   | ConeOp
+  | PolygonPrismOp
+  | PyramidOp
   | EmptyOp
   | UnionOp
   | DifferenceOp
@@ -406,6 +422,8 @@ export interface Node {
   id: NodeId;
   name: string | null;
   op: CsgOp;
+  /** Optional material id tag, used for per-material volume breakdowns. */
+  material?: string;
 }
 
 /** PBR material definition. */
@@ -1145,6 +1163,10 @@ function formatOp(op: CsgOp, idMap: Map<number, number>, name?: string): string
       return `S ${op.radius}${nameSuffix}`;
     case 'Cone':
       return `K ${op.radius_bottom} ${op.radius_top} ${op.height}${nameSuffix}`;
+    case 'PolygonPrism':
+      return `PP ${op.sides} ${op.circumradius} ${op.height}${nameSuffix}`;
+    case 'Pyramid':
+      return `PY ${op.base_sides} ${op.circumradius} ${op.height}${nameSuffix}`;
     case 'Empty':
       return `C 0 0 0${nameSuffix}`;
     case 'Union':
@@ -1193,6 +1215,7 @@ function formatOp(op: CsgOp, idMap: Map<number, number>, name?: string): string
 
 /** Format a joint. */
 function formatJoint(joint: Joint): string {
+  const nameSuffix = joint.name ? ` ${formatQuotedString(joint.name)}` : '';
   const parent = joint.parentInstanceId ? escapeId(joint.parentInstanceId) : '_';
   const child = escapeId(joint.childInstanceId);
   const pa = joint.parentAnchor;
@@ -1200,25 +1223,25 @@ function formatJoint(joint: Joint): string {
 
   switch (joint.kind.type) {
     case 'Fixed':
-      return `JFIX ${escapeId(joint.id)} ${parent} ${child} ${pa.x} ${pa.y} ${pa.z} ${ca.x} ${ca.y} ${ca.z}`;
+      return `JFIX ${escapeId(joint.id)} ${parent} ${child} ${pa.x} ${pa.y} ${pa.z} ${ca.x} ${ca.y} ${ca.z}${nameSuffix}`;
     case 'Revolute': {
       let line = `JREV ${escapeId(joint.id)} ${parent} ${child} ${pa.x} ${pa.y} ${pa.z} ${ca.x} ${ca.y} ${ca.z} ${joint.kind.axis.x} ${joint.kind.axis.y} ${joint.kind.axis.z}`;
       if (joint.kind.limits) {
         line += ` ${joint.kind.limits[0]} ${joint.kind.limits[1]}`;
       }
-      return line;
+      return line + nameSuffix;
     }
     case 'Slider': {
       let line = `JSLD ${escapeId(joint.id)} ${parent} ${child} ${pa.x} ${pa.y} ${pa.z} ${ca.x} ${ca.y} ${ca.z} ${joint.kind.axis.x} ${joint.kind.axis.y} ${joint.kind.axis.z}`;
       if (joint.kind.limits) {
         line += ` ${joint.kind.limits[0]} ${joint.kind.limits[1]}`;
       }
-      return line;
+      return line + nameSuffix;
     }
     case 'Cylindrical':
-      return `JCYL ${escapeId(joint.id)} ${parent} ${child} ${pa.x} ${pa.y} ${pa.z} ${ca.x} ${ca.y} ${ca.z} ${joint.kind.axis.x} ${joint.kind.axis.y} ${joint.kind.axis.z}`;
+      return `JCYL ${escapeId(joint.id)} ${parent} ${child} ${pa.x} ${pa.y} ${pa.z} ${ca.x} ${ca.y} ${ca.z} ${joint.kind.axis.x} ${joint.kind.axis.y} ${joint.kind.axis.z}${nameSuffix}`;
     case 'Ball':
-      return `JBAL ${escapeId(joint.id)} ${parent} ${child} ${pa.x} ${pa.y} ${pa.z} ${ca.x} ${ca.y} ${ca.z}`;
+      return `JBAL ${escapeId(joint.id)} ${parent} ${child} ${pa.x} ${pa.y} ${pa.z} ${ca.x} ${ca.y} ${ca.z}${nameSuffix}`;
   }
 }
 
@@ -1355,10 +1378,13 @@ function parsePartDef(doc: Document, parts: string[], line: number): void {
   }
 
   const id = parseStringArg(parts[1]);
-  const name = parseStringArg(parts[2]);
+  const rawName = parseStringArg(parts[2]);
   const root = parseInt(parts[3]);
   const defaultMaterial = parts[4] ? parseStringArg(parts[4]) : undefined;
 
+  // The writer falls back to the id when there is no name, so a name
+  // equal to the id round-trips back to undefined
+  const name = rawName !== id ? rawName : undefined;
   if (!doc.partDefs) doc.partDefs = {};
   doc.partDefs[id] = { id, name, root, defaultMaterial };
 }
@@ -1370,7 +1396,9 @@ function parseInstance(doc: Document, parts: string[], line: number): void {
 
   const id = parseStringArg(parts[1]);
   const partDefId = parseStringArg(parts[2]);
-  const name = parseStringArg(parts[3]);
+  const rawName = parseStringArg(parts[3]);
+  // Same fallback convention as PDEF: name equal to the id means unnamed
+  const name = rawName !== id ? rawName : undefined;
   const transform: Transform3D = {
     translation: { x: parseFloat(parts[4]), y: parseFloat(parts[5]), z: parseFloat(parts[6]) },
     rotation: { x: parseFloat(parts[7]), y: parseFloat(parts[8]), z: parseFloat(parts[9]) },
@@ -1387,6 +1415,14 @@ function parseJoint(doc: Document, opcode: string, parts: string[], line: number
 
   const parseOptionalParent = (s: string): string | null => s === '_' ? null : parseStringArg(s);
 
+  // Optional trailing quoted name (after any optional limits)
+  let name: string | undefined;
+  const last = parts[parts.length - 1];
+  if (last.startsWith('"') && last.endsWith('"')) {
+    name = parseStringArg(last);
+    parts = parts.slice(0, -1);
+  }
+
   switch (opcode) {
     case 'JFIX': {
       if (parts.length < 10) {
@@ -1394,6 +1430,7 @@ function parseJoint(doc: Document, opcode: string, parts: string[], line: number
       }
       doc.joints.push({
         id: parseStringArg(parts[1]),
+        name,
         parentInstanceId: parseOptionalParent(parts[2]),
         childInstanceId: parseStringArg(parts[3]),
         parentAnchor: { x: parseFloat(parts[4]), y: parseFloat(parts[5]), z: parseFloat(parts[6]) },
@@ -1412,6 +1449,7 @@ function parseJoint(doc: Document, opcode: string, parts: string[], line: number
         : undefined;
       doc.joints.push({
         id: parseStringArg(parts[1]),
+        name,
         parentInstanceId: parseOptionalParent(parts[2]),
         childInstanceId: parseStringArg(parts[3]),
         parentAnchor: { x: parseFloat(parts[4]), y: parseFloat(parts[5]), z: parseFloat(parts[6]) },
@@ -1434,6 +1472,7 @@ function parseJoint(doc: Document, opcode: string, parts: string[], line: number
         : undefined;
       doc.joints.push({
         id: parseStringArg(parts[1]),
+        name,
         parentInstanceId: parseOptionalParent(parts[2]),
         childInstanceId: parseStringArg(parts[3]),
         parentAnchor: { x: parseFloat(parts[4]), y: parseFloat(parts[5]), z: parseFloat(parts[6]) },
@@ -1453,6 +1492,7 @@ function parseJoint(doc: Document, opcode: string, parts: string[], line: number
       }
       doc.joints.push({
         id: parseStringArg(parts[1]),
+        name,
         parentInstanceId: parseOptionalParent(parts[2]),
         childInstanceId: parseStringArg(parts[3]),
         parentAnchor: { x: parseFloat(parts[4]), y: parseFloat(parts[5]), z: parseFloat(parts[6]) },
@@ -1471,6 +1511,7 @@ function parseJoint(doc: Document, opcode: string, parts: string[], line: number
       }
       doc.joints.push({
         id: parseStringArg(parts[1]),
+        name,
         parentInstanceId: parseOptionalParent(parts[2]),
         childInstanceId: parseStringArg(parts[3]),
         parentAnchor: { x: parseFloat(parts[4]), y: parseFloat(parts[5]), z: parseFloat(parts[6]) },
@@ -1756,6 +1797,14 @@ function parseGeometryOpcode(opcode: string, parts: string[], lineNum: number, l
       if (parts.length !== 4) throw new CompactParseError(lineNum, `K requires 3 args, got ${parts.length - 1}`);
       return { type: 'Cone', radius_bottom: parseFloat(parts[1]), radius_top: parseFloat(parts[2]), height: parseFloat(parts[3]), segments: 0 };
 
+    case 'PP':
+      if (parts.length !== 4) throw new CompactParseError(lineNum, `PP requires 3 args, got ${parts.length - 1}`);
+      return { type: 'PolygonPrism', sides: parseInt(parts[1]), circumradius: parseFloat(parts[2]), height: parseFloat(parts[3]) };
+
+    case 'PY':
+      if (parts.length !== 4) throw new CompactParseError(lineNum, `PY requires 3 args, got ${parts.length - 1}`);
+      return { type: 'Pyramid', base_sides: parseInt(parts[1]), circumradius: parseFloat(parts[2]), height: parseFloat(parts[3]) };
+
     case 'U':
       if (parts.length !== 3) throw new CompactParseError(lineNum, `U requires 2 args, got ${parts.length - 1}`);
       return { type: 'Union', left: parseInt(parts[1]), right: parseInt(parts[2]) };